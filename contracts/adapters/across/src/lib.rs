@@ -0,0 +1,458 @@
+//! AcrossAdapter Contract
+//!
+//! Fast-fill bridge adapter built on Across V3. The intent is posted as a
+//! `depositV3` on the source SpokePool so third-party fillers can front the
+//! output on the destination chain; Across repays them from the deposit. The
+//! fill is sent to the peer adapter with the intent payload as message, so
+//! `handle_v3_across_message` can forward the filled tokens to the recipient
+//! and report the delivery to SettlementVerifier. Payloads flagged
+//! `unwrapNative` are delivered as ETH by unwrapping the filled WETH.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use alloy_sol_types::sol;
+use stylus_sdk::{
+    alloy_primitives::{Address, Bytes, FixedBytes, U256},
+    call::Call,
+    crypto::keccak,
+    prelude::*,
+    storage::{StorageAddress, StorageBool, StorageMap, StorageU256},
+};
+
+use swoosh_common::bridge_adapter::ISettlementVerifier;
+use swoosh_common::encoding::encode_bridge_payload;
+use swoosh_common::erc165;
+use swoosh_common::payload_codec::{self, PayloadError};
+
+// Events and errors
+sol! {
+    event LaneConfigured(uint256 indexed chainId, address peer, uint256 relayerFeeBps);
+    event OutputTokenSet(uint256 indexed chainId, address indexed inputToken, address outputToken);
+    event FillWindowSet(uint256 fillWindow);
+    event AcrossDepositPosted(
+        uint256 indexed intentId,
+        uint256 indexed destinationChain,
+        uint256 inputAmount,
+        uint256 outputAmount,
+        uint32 fillDeadline
+    );
+    event AcrossFillReceived(
+        uint256 indexed intentId,
+        address indexed relayer,
+        address indexed recipient,
+        uint256 amount
+    );
+    event NativeDelivered(uint256 indexed intentId, address indexed recipient, uint256 amount);
+
+    error Unauthorized();
+    error InvalidAddress();
+    error InvalidAmount();
+    error UnsupportedLane();
+    error UnsupportedToken();
+    error InvalidPayload();
+    error MalformedPayload(uint8 reason);
+    error FillMismatch();
+    error TransferFailed();
+    error SpokePoolCallFailed();
+}
+
+/// Error types for AcrossAdapter
+#[derive(SolidityError)]
+pub enum AcrossAdapterError {
+    Unauthorized(Unauthorized),
+    InvalidAddress(InvalidAddress),
+    InvalidAmount(InvalidAmount),
+    UnsupportedLane(UnsupportedLane),
+    UnsupportedToken(UnsupportedToken),
+    InvalidPayload(InvalidPayload),
+    MalformedPayload(MalformedPayload),
+    FillMismatch(FillMismatch),
+    TransferFailed(TransferFailed),
+    SpokePoolCallFailed(SpokePoolCallFailed),
+}
+
+impl From<PayloadError> for AcrossAdapterError {
+    fn from(err: PayloadError) -> Self {
+        AcrossAdapterError::MalformedPayload(MalformedPayload { reason: err.code() })
+    }
+}
+
+// ERC20 interface
+sol_interface! {
+    interface IERC20 {
+        function transferFrom(address from, address to, uint256 amount) external returns (bool);
+        function transfer(address to, uint256 amount) external returns (bool);
+        function approve(address spender, uint256 amount) external returns (bool);
+    }
+}
+
+// Wrapped native token interface
+sol_interface! {
+    interface IWETH {
+        function withdraw(uint256 amount) external;
+    }
+}
+
+// Across V3 SpokePool interface
+sol_interface! {
+    interface ISpokePool {
+        function depositV3(
+            address depositor,
+            address recipient,
+            address inputToken,
+            address outputToken,
+            uint256 inputAmount,
+            uint256 outputAmount,
+            uint256 destinationChainId,
+            address exclusiveRelayer,
+            uint32 quoteTimestamp,
+            uint32 fillDeadline,
+            uint32 exclusivityDeadline,
+            bytes calldata message
+        ) external payable;
+    }
+}
+
+/// Basis point denominator
+const BPS_DENOMINATOR: u64 = 10_000;
+/// Maximum relayer fee a lane may be configured with (5%)
+const MAX_RELAYER_FEE_BPS: u64 = 500;
+/// Default time fillers have to fill a deposit (10 minutes)
+const DEFAULT_FILL_WINDOW: u64 = 600;
+
+#[entrypoint]
+#[storage]
+pub struct AcrossAdapter {
+    /// Contract owner
+    owner: StorageAddress,
+    /// Across SpokePool on this chain
+    spoke_pool: StorageAddress,
+    /// RouteExecutor contract (only caller allowed to bridge)
+    route_executor: StorageAddress,
+    /// SettlementVerifier notified on inbound fills
+    settlement_verifier: StorageAddress,
+    /// Seconds fillers have before a deposit expires
+    fill_window: StorageU256,
+    /// Mapping of chain IDs to peer adapters receiving fills
+    peers: StorageMap<U256, StorageAddress>,
+    /// Mapping of chain IDs to relayer fee (bps of input amount)
+    relayer_fee_bps: StorageMap<U256, StorageU256>,
+    /// Mapping of chain ID -> input token -> output token on that chain
+    output_tokens: StorageMap<U256, StorageMap<Address, StorageAddress>>,
+    /// Processed fill messages (by payload hash)
+    processed: StorageMap<FixedBytes<32>, StorageBool>,
+}
+
+#[public]
+impl AcrossAdapter {
+    /// Initialize the adapter
+    pub fn init(
+        &mut self,
+        spoke_pool_address: Address,
+        route_executor_address: Address,
+        settlement_verifier_address: Address,
+    ) -> Result<(), AcrossAdapterError> {
+        if spoke_pool_address == Address::ZERO
+            || route_executor_address == Address::ZERO
+            || settlement_verifier_address == Address::ZERO
+        {
+            return Err(AcrossAdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.owner.set(self.vm().msg_sender());
+        self.spoke_pool.set(spoke_pool_address);
+        self.route_executor.set(route_executor_address);
+        self.settlement_verifier.set(settlement_verifier_address);
+        self.fill_window.set(U256::from(DEFAULT_FILL_WINDOW));
+
+        Ok(())
+    }
+
+    /// Configure a lane's peer adapter and relayer fee (admin only)
+    pub fn set_lane(
+        &mut self,
+        chain_id: U256,
+        peer: Address,
+        relayer_fee_bps: U256,
+    ) -> Result<(), AcrossAdapterError> {
+        self.only_owner()?;
+
+        if chain_id == U256::ZERO || peer == Address::ZERO {
+            return Err(AcrossAdapterError::UnsupportedLane(UnsupportedLane {}));
+        }
+
+        if relayer_fee_bps > U256::from(MAX_RELAYER_FEE_BPS) {
+            return Err(AcrossAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        self.peers.setter(chain_id).set(peer);
+        self.relayer_fee_bps.setter(chain_id).set(relayer_fee_bps);
+
+        self.vm().log(LaneConfigured {
+            chainId: chain_id,
+            peer,
+            relayerFeeBps: relayer_fee_bps,
+        });
+
+        Ok(())
+    }
+
+    /// Map an input token to its counterpart on a destination chain (admin only)
+    pub fn set_output_token(
+        &mut self,
+        chain_id: U256,
+        input_token: Address,
+        output_token: Address,
+    ) -> Result<(), AcrossAdapterError> {
+        self.only_owner()?;
+
+        if input_token == Address::ZERO {
+            return Err(AcrossAdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.output_tokens.setter(chain_id).setter(input_token).set(output_token);
+
+        self.vm().log(OutputTokenSet {
+            chainId: chain_id,
+            inputToken: input_token,
+            outputToken: output_token,
+        });
+
+        Ok(())
+    }
+
+    /// Set how long fillers have to fill a deposit (admin only)
+    pub fn set_fill_window(&mut self, fill_window: U256) -> Result<(), AcrossAdapterError> {
+        self.only_owner()?;
+
+        if fill_window == U256::ZERO || fill_window > U256::from(u32::MAX) {
+            return Err(AcrossAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        self.fill_window.set(fill_window);
+
+        self.vm().log(FillWindowSet { fillWindow: fill_window });
+
+        Ok(())
+    }
+
+    /// Across fees are taken from the deposit, not paid in native
+    pub fn quote_fee(
+        &self,
+        destination_chain: U256,
+        token: Address,
+        _amount: U256,
+        _recipient: Address,
+    ) -> Result<U256, AcrossAdapterError> {
+        self.lane(destination_chain, token)?;
+        Ok(U256::ZERO)
+    }
+
+    /// Amount fillers deliver on the destination for a given input
+    pub fn quote_output(&self, destination_chain: U256, amount: U256) -> U256 {
+        let fee_bps = self.relayer_fee_bps.get(destination_chain);
+        amount - amount * fee_bps / U256::from(BPS_DENOMINATOR)
+    }
+
+    /// Post the intent as an Across deposit for fillers
+    ///
+    /// Returns the keccak of the payload, which the destination side reports
+    /// to SettlementVerifier as the message ID.
+    #[payable]
+    pub fn bridge(
+        &mut self,
+        intent_id: U256,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+        unwrap_native: bool,
+    ) -> Result<FixedBytes<32>, AcrossAdapterError> {
+        let executor = self.vm().msg_sender();
+        if executor != self.route_executor.get() {
+            return Err(AcrossAdapterError::Unauthorized(Unauthorized {}));
+        }
+
+        if amount == U256::ZERO {
+            return Err(AcrossAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let (peer, output_token) = self.lane(destination_chain, token)?;
+        let output_amount = self.quote_output(destination_chain, amount);
+
+        // Pull tokens from the executor and approve the SpokePool
+        let erc20 = IERC20::new(token);
+        let this = self.vm().contract_address();
+        let config = Call::new_mutating(self);
+        let pulled = erc20
+            .transfer_from(self.vm(), config, executor, this, amount)
+            .map_err(|_| AcrossAdapterError::TransferFailed(TransferFailed {}))?;
+        if !pulled {
+            return Err(AcrossAdapterError::TransferFailed(TransferFailed {}));
+        }
+
+        let spoke_pool = self.spoke_pool.get();
+        let config = Call::new_mutating(self);
+        erc20
+            .approve(self.vm(), config, spoke_pool, amount)
+            .map_err(|_| AcrossAdapterError::TransferFailed(TransferFailed {}))?;
+
+        // Fill goes to the peer adapter, which unpacks the payload
+        let payload = encode_bridge_payload(intent_id, output_token, output_amount, recipient, unwrap_native);
+        let message_id = keccak(&payload);
+        let now = self.vm().block_timestamp() as u32;
+        let fill_deadline = now.saturating_add(self.fill_window.get().to::<u32>());
+
+        let config = Call::new_mutating(self);
+        ISpokePool::new(spoke_pool)
+            .deposit_v3(
+                self.vm(),
+                config,
+                this,
+                peer,
+                token,
+                output_token,
+                amount,
+                output_amount,
+                destination_chain,
+                Address::ZERO,
+                now,
+                fill_deadline,
+                0,
+                payload.into(),
+            )
+            .map_err(|_| AcrossAdapterError::SpokePoolCallFailed(SpokePoolCallFailed {}))?;
+
+        self.vm().log(AcrossDepositPosted {
+            intentId: intent_id,
+            destinationChain: destination_chain,
+            inputAmount: amount,
+            outputAmount: output_amount,
+            fillDeadline: fill_deadline,
+        });
+
+        Ok(message_id)
+    }
+
+    /// Check whether a destination chain has a configured peer
+    pub fn supports_lane(&self, destination_chain: U256) -> bool {
+        self.peers.get(destination_chain) != Address::ZERO
+    }
+
+    /// SpokePool callback after a filler delivers to this adapter (destination side)
+    #[selector(name = "handleV3AcrossMessage")]
+    pub fn handle_v3_across_message(
+        &mut self,
+        token_sent: Address,
+        amount: U256,
+        relayer: Address,
+        message: Bytes,
+    ) -> Result<(), AcrossAdapterError> {
+        if self.vm().msg_sender() != self.spoke_pool.get() {
+            return Err(AcrossAdapterError::Unauthorized(Unauthorized {}));
+        }
+
+        let message_id = keccak(&message);
+        if self.processed.get(message_id) {
+            return Err(AcrossAdapterError::InvalidPayload(InvalidPayload {}));
+        }
+        self.processed.setter(message_id).set(true);
+
+        let payload = payload_codec::decode_bridge(&message).map_err(AcrossAdapterError::from)?;
+
+        // The filler must have delivered the promised token and amount
+        if token_sent != payload.token || amount < payload.amount {
+            return Err(AcrossAdapterError::FillMismatch(FillMismatch {}));
+        }
+
+        if payload.unwrapNative {
+            // Filled token is the destination WETH; unwrap and pay out ETH
+            let config = Call::new_mutating(self);
+            IWETH::new(token_sent)
+                .withdraw(self.vm(), config, amount)
+                .map_err(|_| AcrossAdapterError::TransferFailed(TransferFailed {}))?;
+            self.vm()
+                .transfer_eth(payload.recipient, amount)
+                .map_err(|_| AcrossAdapterError::TransferFailed(TransferFailed {}))?;
+
+            self.vm().log(NativeDelivered {
+                intentId: payload.intentId,
+                recipient: payload.recipient,
+                amount,
+            });
+        } else {
+            let erc20 = IERC20::new(token_sent);
+            let config = Call::new_mutating(self);
+            let sent = erc20
+                .transfer(self.vm(), config, payload.recipient, amount)
+                .map_err(|_| AcrossAdapterError::TransferFailed(TransferFailed {}))?;
+            if !sent {
+                return Err(AcrossAdapterError::TransferFailed(TransferFailed {}));
+            }
+        }
+
+        let verifier = ISettlementVerifier::new(self.settlement_verifier.get());
+        let config = Call::new_mutating(self);
+        verifier
+            .verify_adapter_message(self.vm(), config, message_id, payload.intentId)
+            .map_err(|_| AcrossAdapterError::SpokePoolCallFailed(SpokePoolCallFailed {}))?;
+
+        self.vm().log(AcrossFillReceived {
+            intentId: payload.intentId,
+            relayer,
+            recipient: payload.recipient,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    /// Accept ETH from WETH withdrawals during native delivery
+    #[receive]
+    #[payable]
+    pub fn receive(&mut self) -> Result<(), Vec<u8>> {
+        Ok(())
+    }
+
+    /// Get the fill window in seconds
+    pub fn fill_window(&self) -> U256 {
+        self.fill_window.get()
+    }
+
+    /// ERC-165: whether this contract implements `interface_id`
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        erc165::supports(interface_id.0, &[erc165::BRIDGE_ADAPTER_ID])
+    }
+
+    /// Get contract owner
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Internal: Resolve a lane to its peer adapter and output token
+    fn lane(&self, destination_chain: U256, token: Address) -> Result<(Address, Address), AcrossAdapterError> {
+        let peer = self.peers.get(destination_chain);
+        if peer == Address::ZERO {
+            return Err(AcrossAdapterError::UnsupportedLane(UnsupportedLane {}));
+        }
+
+        let output_token = self.output_tokens.getter(destination_chain).get(token);
+        if output_token == Address::ZERO {
+            return Err(AcrossAdapterError::UnsupportedToken(UnsupportedToken {}));
+        }
+
+        Ok((peer, output_token))
+    }
+
+    /// Internal: Check if caller is owner
+    fn only_owner(&self) -> Result<(), AcrossAdapterError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(AcrossAdapterError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,228 @@
+//! CamelotAdapter Contract
+//!
+//! Swap adapter routing through the Camelot V2 router. Camelot only exposes the
+//! fee-on-transfer-supporting swap, which returns nothing, so the output is
+//! measured as the recipient's balance delta. The adapter `data` is an
+//! optional ABI-encoded `address[]` path; empty data swaps through the direct pair.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use alloy_sol_types::sol;
+use stylus_sdk::{
+    alloy_primitives::{Address, Bytes, FixedBytes, U256},
+    call::Call,
+    prelude::*,
+    storage::StorageAddress,
+};
+
+use swoosh_common::encoding::v2_swap_path;
+use swoosh_common::erc165;
+
+// Events and errors
+sol! {
+    event RouterSet(address indexed router);
+    event ReferrerSet(address indexed referrer);
+    event CamelotSwap(
+        address indexed tokenIn,
+        address indexed tokenOut,
+        uint256 amountIn,
+        uint256 amountOut,
+        address recipient
+    );
+
+    error Unauthorized();
+    error InvalidAddress();
+    error InvalidAmount();
+    error InvalidPath();
+    error TransferFailed();
+    error SwapFailed();
+}
+
+/// Error types for CamelotAdapter
+#[derive(SolidityError)]
+pub enum CamelotAdapterError {
+    Unauthorized(Unauthorized),
+    InvalidAddress(InvalidAddress),
+    InvalidAmount(InvalidAmount),
+    InvalidPath(InvalidPath),
+    TransferFailed(TransferFailed),
+    SwapFailed(SwapFailed),
+}
+
+// ERC20 interface
+sol_interface! {
+    interface IERC20 {
+        function transferFrom(address from, address to, uint256 amount) external returns (bool);
+        function approve(address spender, uint256 amount) external returns (bool);
+        function balanceOf(address account) external view returns (uint256);
+    }
+}
+
+// Camelot V2 router interface
+sol_interface! {
+    interface ICamelotRouter {
+        function swapExactTokensForTokensSupportingFeeOnTransferTokens(
+            uint256 amountIn,
+            uint256 amountOutMin,
+            address[] calldata path,
+            address to,
+            address referrer,
+            uint256 deadline
+        ) external;
+    }
+}
+
+#[entrypoint]
+#[storage]
+pub struct CamelotAdapter {
+    /// Contract owner
+    owner: StorageAddress,
+    /// Camelot V2 router
+    router: StorageAddress,
+    /// Referrer credited on Camelot swaps
+    referrer: StorageAddress,
+}
+
+#[public]
+impl CamelotAdapter {
+    /// Initialize the adapter
+    pub fn init(&mut self, router_address: Address) -> Result<(), CamelotAdapterError> {
+        if router_address == Address::ZERO {
+            return Err(CamelotAdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.owner.set(self.vm().msg_sender());
+        self.router.set(router_address);
+
+        Ok(())
+    }
+
+    /// Update the swap router (admin only)
+    pub fn set_router(&mut self, router_address: Address) -> Result<(), CamelotAdapterError> {
+        self.only_owner()?;
+
+        if router_address == Address::ZERO {
+            return Err(CamelotAdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.router.set(router_address);
+
+        self.vm().log(RouterSet { router: router_address });
+
+        Ok(())
+    }
+
+    /// Set the referrer credited on swaps (admin only)
+    pub fn set_referrer(&mut self, referrer: Address) -> Result<(), CamelotAdapterError> {
+        self.only_owner()?;
+        self.referrer.set(referrer);
+
+        self.vm().log(ReferrerSet { referrer });
+
+        Ok(())
+    }
+
+    /// Swap `amount_in` of `token_in` for `token_out`
+    pub fn swap(
+        &mut self,
+        token_in: Address,
+        token_out: Address,
+        amount_in: U256,
+        min_amount_out: U256,
+        recipient: Address,
+        data: Bytes,
+    ) -> Result<U256, CamelotAdapterError> {
+        if amount_in == U256::ZERO {
+            return Err(CamelotAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        if recipient == Address::ZERO {
+            return Err(CamelotAdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        let path = v2_swap_path(&data, token_in, token_out)
+            .ok_or(CamelotAdapterError::InvalidPath(InvalidPath {}))?;
+
+        // Pull input from the caller and approve the router
+        let erc20 = IERC20::new(token_in);
+        let caller = self.vm().msg_sender();
+        let this = self.vm().contract_address();
+        let config = Call::new_mutating(self);
+        let pulled = erc20
+            .transfer_from(self.vm(), config, caller, this, amount_in)
+            .map_err(|_| CamelotAdapterError::TransferFailed(TransferFailed {}))?;
+        if !pulled {
+            return Err(CamelotAdapterError::TransferFailed(TransferFailed {}));
+        }
+
+        let router = self.router.get();
+        let config = Call::new_mutating(self);
+        erc20
+            .approve(self.vm(), config, router, amount_in)
+            .map_err(|_| CamelotAdapterError::TransferFailed(TransferFailed {}))?;
+
+        // Router returns nothing, so measure the recipient's balance delta
+        let output = IERC20::new(token_out);
+        let balance_before = output
+            .balance_of(self.vm(), Call::new(), recipient)
+            .map_err(|_| CamelotAdapterError::SwapFailed(SwapFailed {}))?;
+
+        // Deadline is enforced by the executor; the router sees the current block
+        let deadline = U256::from(self.vm().block_timestamp());
+        let referrer = self.referrer.get();
+        let config = Call::new_mutating(self);
+        ICamelotRouter::new(router)
+            .swap_exact_tokens_for_tokens_supporting_fee_on_transfer_tokens(
+                self.vm(),
+                config,
+                amount_in,
+                min_amount_out,
+                path,
+                recipient,
+                referrer,
+                deadline,
+            )
+            .map_err(|_| CamelotAdapterError::SwapFailed(SwapFailed {}))?;
+
+        let balance_after = output
+            .balance_of(self.vm(), Call::new(), recipient)
+            .map_err(|_| CamelotAdapterError::SwapFailed(SwapFailed {}))?;
+        let amount_out = balance_after.saturating_sub(balance_before);
+
+        self.vm().log(CamelotSwap {
+            tokenIn: token_in,
+            tokenOut: token_out,
+            amountIn: amount_in,
+            amountOut: amount_out,
+            recipient,
+        });
+
+        Ok(amount_out)
+    }
+
+    /// Get the swap router
+    pub fn router(&self) -> Address {
+        self.router.get()
+    }
+
+    /// ERC-165: whether this contract implements `interface_id`
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        erc165::supports(interface_id.0, &[erc165::SWAP_ADAPTER_ID])
+    }
+
+    /// Get contract owner
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Internal: Check if caller is owner
+    fn only_owner(&self) -> Result<(), CamelotAdapterError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(CamelotAdapterError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+}
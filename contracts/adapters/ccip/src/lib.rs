@@ -0,0 +1,553 @@
+//! CcipAdapter Contract
+//!
+//! Bridge adapter that sends intents through a Chainlink CCIP router. The
+//! bridged tokens travel as CCIP token amounts alongside the intent payload,
+//! and the peer adapter on the destination chain forwards them to the
+//! recipient before reporting the delivery to SettlementVerifier.
+//!
+//! Each lane pays CCIP fees either in native, forwarded by RouteExecutor as
+//! msg.value like every other adapter, or in LINK held by this adapter. LINK
+//! lanes quote zero native to the executor; the adapter approves the router
+//! for exactly the fee of each message, so no standing allowance is left
+//! behind. The treasury funds the adapter with LINK and can withdraw it.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+use alloy_sol_types::{sol, SolCall, SolValue};
+use stylus_sdk::{
+    alloy_primitives::{Address, Bytes, FixedBytes, U256},
+    call::{call, static_call, Call},
+    prelude::*,
+    storage::{StorageAddress, StorageBool, StorageMap, StorageU256},
+};
+
+use swoosh_common::bridge_adapter::ISettlementVerifier;
+use swoosh_common::encoding::{ccip_extra_args, encode_bridge_payload};
+use swoosh_common::erc165;
+use swoosh_common::payload_codec::{self, PayloadError};
+
+// CCIP router ABI
+sol! {
+    struct EVMTokenAmount {
+        address token;
+        uint256 amount;
+    }
+
+    struct EVM2AnyMessage {
+        bytes receiver;
+        bytes data;
+        EVMTokenAmount[] tokenAmounts;
+        address feeToken;
+        bytes extraArgs;
+    }
+
+    function getFee(uint64 destinationChainSelector, EVM2AnyMessage message) external view returns (uint256 fee);
+
+    function ccipSend(
+        uint64 destinationChainSelector,
+        EVM2AnyMessage message
+    ) external payable returns (bytes32 messageId);
+}
+
+// Events and errors
+sol! {
+    event LaneSet(uint256 indexed chainId, uint64 chainSelector, address peer);
+    event DestinationGasSet(uint256 indexed chainId, uint256 gas);
+    event FeeTokenSet(uint256 indexed chainId, address feeToken);
+    event LinkWithdrawn(address indexed to, uint256 amount);
+    event CcipMessageSent(
+        uint256 indexed intentId,
+        uint64 indexed destinationChainSelector,
+        bytes32 messageId,
+        address feeToken,
+        uint256 fee
+    );
+    event CcipMessageReceived(
+        uint256 indexed intentId,
+        uint64 indexed sourceChainSelector,
+        bytes32 messageId,
+        address recipient,
+        uint256 amount
+    );
+
+    error Unauthorized();
+    error InvalidAddress();
+    error InvalidAmount();
+    error UnsupportedLane();
+    error UntrustedPeer();
+    error InvalidPayload();
+    error MalformedPayload(uint8 reason);
+    error InsufficientFee();
+    error FeeTokenMismatch();
+    error TransferFailed();
+    error RouterCallFailed();
+}
+
+/// Error types for CcipAdapter
+#[derive(SolidityError)]
+pub enum CcipAdapterError {
+    Unauthorized(Unauthorized),
+    InvalidAddress(InvalidAddress),
+    InvalidAmount(InvalidAmount),
+    UnsupportedLane(UnsupportedLane),
+    UntrustedPeer(UntrustedPeer),
+    InvalidPayload(InvalidPayload),
+    MalformedPayload(MalformedPayload),
+    InsufficientFee(InsufficientFee),
+    FeeTokenMismatch(FeeTokenMismatch),
+    TransferFailed(TransferFailed),
+    RouterCallFailed(RouterCallFailed),
+}
+
+impl From<PayloadError> for CcipAdapterError {
+    fn from(err: PayloadError) -> Self {
+        CcipAdapterError::MalformedPayload(MalformedPayload { reason: err.code() })
+    }
+}
+
+// ERC20 interface
+sol_interface! {
+    interface IERC20 {
+        function transferFrom(address from, address to, uint256 amount) external returns (bool);
+        function transfer(address to, uint256 amount) external returns (bool);
+        function approve(address spender, uint256 amount) external returns (bool);
+        function balanceOf(address account) external view returns (uint256);
+    }
+}
+
+/// Destination ccipReceive gas used when no per-lane value is set
+const DEFAULT_DESTINATION_GAS: u64 = 200_000;
+/// ERC-165 interface ID of IAny2EVMMessageReceiver (the `ccipReceive` selector)
+const CCIP_RECEIVER_INTERFACE_ID: [u8; 4] = [0x85, 0x57, 0x2f, 0xfb];
+
+#[entrypoint]
+#[storage]
+pub struct CcipAdapter {
+    /// Contract owner
+    owner: StorageAddress,
+    /// CCIP router on this chain
+    router: StorageAddress,
+    /// LINK token on this chain (zero = native fees only)
+    link_token: StorageAddress,
+    /// RouteExecutor contract (only caller allowed to bridge)
+    route_executor: StorageAddress,
+    /// SettlementVerifier notified on inbound deliveries
+    settlement_verifier: StorageAddress,
+    /// Mapping of EVM chain IDs to CCIP chain selectors
+    chain_selectors: StorageMap<U256, StorageU256>,
+    /// Mapping of CCIP chain selectors to peer adapters
+    peers: StorageMap<U256, StorageAddress>,
+    /// Mapping of chain IDs to destination gas limits
+    destination_gas: StorageMap<U256, StorageU256>,
+    /// Mapping of chain IDs to whether the lane pays fees in LINK
+    pay_in_link: StorageMap<U256, StorageBool>,
+}
+
+#[public]
+impl CcipAdapter {
+    /// Initialize the adapter
+    ///
+    /// `link_token` may be zero on chains where every lane pays in native.
+    pub fn init(
+        &mut self,
+        router_address: Address,
+        link_token: Address,
+        route_executor_address: Address,
+        settlement_verifier_address: Address,
+    ) -> Result<(), CcipAdapterError> {
+        if router_address == Address::ZERO
+            || route_executor_address == Address::ZERO
+            || settlement_verifier_address == Address::ZERO
+        {
+            return Err(CcipAdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.owner.set(self.vm().msg_sender());
+        self.router.set(router_address);
+        self.link_token.set(link_token);
+        self.route_executor.set(route_executor_address);
+        self.settlement_verifier.set(settlement_verifier_address);
+
+        Ok(())
+    }
+
+    /// Configure a lane: chain ID to CCIP chain selector and peer adapter (admin only)
+    pub fn set_lane(&mut self, chain_id: U256, chain_selector: u64, peer: Address) -> Result<(), CcipAdapterError> {
+        self.only_owner()?;
+
+        if chain_id == U256::ZERO || chain_selector == 0 {
+            return Err(CcipAdapterError::UnsupportedLane(UnsupportedLane {}));
+        }
+
+        self.chain_selectors.setter(chain_id).set(U256::from(chain_selector));
+        self.peers.setter(U256::from(chain_selector)).set(peer);
+
+        self.vm().log(LaneSet {
+            chainId: chain_id,
+            chainSelector: chain_selector,
+            peer,
+        });
+
+        Ok(())
+    }
+
+    /// Set the destination ccipReceive gas limit for a chain (admin only)
+    pub fn set_destination_gas(&mut self, chain_id: U256, gas: U256) -> Result<(), CcipAdapterError> {
+        self.only_owner()?;
+
+        if gas == U256::ZERO {
+            return Err(CcipAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        self.destination_gas.setter(chain_id).set(gas);
+
+        self.vm().log(DestinationGasSet { chainId: chain_id, gas });
+
+        Ok(())
+    }
+
+    /// Choose whether a lane pays CCIP fees in LINK or native (admin only)
+    pub fn set_fee_token(&mut self, chain_id: U256, pay_in_link: bool) -> Result<(), CcipAdapterError> {
+        self.only_owner()?;
+
+        if pay_in_link && self.link_token.get() == Address::ZERO {
+            return Err(CcipAdapterError::FeeTokenMismatch(FeeTokenMismatch {}));
+        }
+
+        self.pay_in_link.setter(chain_id).set(pay_in_link);
+
+        self.vm().log(FeeTokenSet {
+            chainId: chain_id,
+            feeToken: self.fee_token(chain_id),
+        });
+
+        Ok(())
+    }
+
+    /// Withdraw LINK held for fees (admin only)
+    pub fn withdraw_link(&mut self, to: Address, amount: U256) -> Result<(), CcipAdapterError> {
+        self.only_owner()?;
+
+        let link = self.link_token.get();
+        if link == Address::ZERO || to == Address::ZERO {
+            return Err(CcipAdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        let config = Call::new_mutating(self);
+        let sent = IERC20::new(link)
+            .transfer(self.vm(), config, to, amount)
+            .map_err(|_| CcipAdapterError::TransferFailed(TransferFailed {}))?;
+        if !sent {
+            return Err(CcipAdapterError::TransferFailed(TransferFailed {}));
+        }
+
+        self.vm().log(LinkWithdrawn { to, amount });
+
+        Ok(())
+    }
+
+    /// Quote the native fee RouteExecutor must forward
+    ///
+    /// Zero on lanes that pay in LINK; `quote_fee_in_fee_token` gives the
+    /// LINK amount the adapter will spend.
+    pub fn quote_fee(
+        &self,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+    ) -> Result<U256, CcipAdapterError> {
+        let (fee_token, fee) = self.quote_fee_in_fee_token(destination_chain, token, amount, recipient)?;
+        if fee_token != Address::ZERO {
+            return Ok(U256::ZERO);
+        }
+        Ok(fee)
+    }
+
+    /// Quote the CCIP fee in the lane's fee token (zero address = native)
+    pub fn quote_fee_in_fee_token(
+        &self,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+    ) -> Result<(Address, U256), CcipAdapterError> {
+        let (selector, peer) = self.lane(destination_chain)?;
+        let fee_token = self.fee_token(destination_chain);
+        let message = self.build_message(
+            destination_chain,
+            peer,
+            encode_bridge_payload(U256::ZERO, token, amount, recipient, false),
+            token,
+            amount,
+            fee_token,
+        );
+
+        let fee = self.router_fee(selector, message)?;
+        Ok((fee_token, fee))
+    }
+
+    /// Lock tokens and send them with the intent payload to the peer adapter
+    ///
+    /// Called by RouteExecutor, which approves this adapter for `amount` and
+    /// forwards `quote_fee` as msg.value. On LINK lanes the fee comes from
+    /// the adapter's own LINK balance instead.
+    #[payable]
+    pub fn bridge(
+        &mut self,
+        intent_id: U256,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+        unwrap_native: bool,
+    ) -> Result<FixedBytes<32>, CcipAdapterError> {
+        let executor = self.vm().msg_sender();
+        if executor != self.route_executor.get() {
+            return Err(CcipAdapterError::Unauthorized(Unauthorized {}));
+        }
+
+        if amount == U256::ZERO {
+            return Err(CcipAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        // CCIP token pools deliver ERC-20s; there is no native payout
+        if unwrap_native {
+            return Err(CcipAdapterError::UnsupportedLane(UnsupportedLane {}));
+        }
+
+        let (selector, peer) = self.lane(destination_chain)?;
+        let fee_token = self.fee_token(destination_chain);
+        let message = self.build_message(
+            destination_chain,
+            peer,
+            encode_bridge_payload(intent_id, token, amount, recipient, false),
+            token,
+            amount,
+            fee_token,
+        );
+        let fee = self.router_fee(selector, message.clone())?;
+
+        let value = self.vm().msg_value();
+        if fee_token == Address::ZERO {
+            if value < fee {
+                return Err(CcipAdapterError::InsufficientFee(InsufficientFee {}));
+            }
+        } else if value != U256::ZERO {
+            // Native sent to a LINK lane would be stranded in the adapter
+            return Err(CcipAdapterError::FeeTokenMismatch(FeeTokenMismatch {}));
+        }
+
+        // LINK lanes pay from the adapter's own balance
+        let this = self.vm().contract_address();
+        if fee_token != Address::ZERO {
+            let balance = IERC20::new(fee_token)
+                .balance_of(self.vm(), Call::new(), this)
+                .map_err(|_| CcipAdapterError::TransferFailed(TransferFailed {}))?;
+            if balance < fee {
+                return Err(CcipAdapterError::InsufficientFee(InsufficientFee {}));
+            }
+        }
+
+        // Pull tokens from the executor
+        let erc20 = IERC20::new(token);
+        let config = Call::new_mutating(self);
+        let pulled = erc20
+            .transfer_from(self.vm(), config, executor, this, amount)
+            .map_err(|_| CcipAdapterError::TransferFailed(TransferFailed {}))?;
+        if !pulled {
+            return Err(CcipAdapterError::TransferFailed(TransferFailed {}));
+        }
+
+        // Approve the router for exactly this message: the tokens, and the fee
+        // on LINK lanes (one allowance when LINK is also the bridged token)
+        let router = self.router.get();
+        if fee_token == token {
+            self.approve(token, router, amount + fee)?;
+        } else {
+            self.approve(token, router, amount)?;
+            if fee_token != Address::ZERO {
+                self.approve(fee_token, router, fee)?;
+            }
+        }
+
+        let calldata = ccipSendCall {
+            destinationChainSelector: selector,
+            message,
+        }
+        .abi_encode();
+        let config = Call::new_payable(self, value);
+        let result = call(self.vm(), config, router, &calldata)
+            .map_err(|_| CcipAdapterError::RouterCallFailed(RouterCallFailed {}))?;
+        let message_id = ccipSendCall::abi_decode_returns(&result)
+            .map_err(|_| CcipAdapterError::RouterCallFailed(RouterCallFailed {}))?;
+
+        self.vm().log(CcipMessageSent {
+            intentId: intent_id,
+            destinationChainSelector: selector,
+            messageId: message_id,
+            feeToken: fee_token,
+            fee,
+        });
+
+        Ok(message_id)
+    }
+
+    /// Check whether a destination chain has a configured peer
+    pub fn supports_lane(&self, destination_chain: U256) -> bool {
+        self.lane(destination_chain).is_ok()
+    }
+
+    /// Receive a message from the router (destination side)
+    ///
+    /// `message` is CCIP's Any2EVMMessage: message ID, source chain selector,
+    /// abi-encoded sender, data and the delivered token amounts. The sender
+    /// must be the peer configured for the source selector. The single
+    /// delivered token is forwarded to the recipient and the delivery is
+    /// reported to SettlementVerifier keyed by the CCIP message ID.
+    pub fn ccip_receive(
+        &mut self,
+        message: (FixedBytes<32>, u64, Bytes, Bytes, Vec<(Address, U256)>),
+    ) -> Result<(), CcipAdapterError> {
+        if self.vm().msg_sender() != self.router.get() {
+            return Err(CcipAdapterError::Unauthorized(Unauthorized {}));
+        }
+
+        let (message_id, source_selector, sender, data, token_amounts) = message;
+        let peer = self.peers.get(U256::from(source_selector));
+        if peer == Address::ZERO || sender.as_ref() != peer.abi_encode().as_slice() {
+            return Err(CcipAdapterError::UntrustedPeer(UntrustedPeer {}));
+        }
+
+        let payload = payload_codec::decode_bridge(&data).map_err(CcipAdapterError::from)?;
+        let [(token, amount)] = token_amounts.as_slice() else {
+            return Err(CcipAdapterError::InvalidPayload(InvalidPayload {}));
+        };
+        let (token, amount) = (*token, *amount);
+
+        let config = Call::new_mutating(self);
+        let sent = IERC20::new(token)
+            .transfer(self.vm(), config, payload.recipient, amount)
+            .map_err(|_| CcipAdapterError::TransferFailed(TransferFailed {}))?;
+        if !sent {
+            return Err(CcipAdapterError::TransferFailed(TransferFailed {}));
+        }
+
+        let verifier = ISettlementVerifier::new(self.settlement_verifier.get());
+        let config = Call::new_mutating(self);
+        verifier
+            .verify_adapter_message(self.vm(), config, message_id, payload.intentId)
+            .map_err(|_| CcipAdapterError::RouterCallFailed(RouterCallFailed {}))?;
+
+        self.vm().log(CcipMessageReceived {
+            intentId: payload.intentId,
+            sourceChainSelector: source_selector,
+            messageId: message_id,
+            recipient: payload.recipient,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    /// ERC-165 check the router makes before calling `ccip_receive`, also
+    /// answering for `IBridgeAdapter`
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        erc165::supports(interface_id.0, &[erc165::BRIDGE_ADAPTER_ID, CCIP_RECEIVER_INTERFACE_ID])
+    }
+
+    /// Get the CCIP chain selector configured for a chain
+    pub fn get_chain_selector(&self, chain_id: U256) -> U256 {
+        self.chain_selectors.get(chain_id)
+    }
+
+    /// Get the peer adapter for a CCIP chain selector
+    pub fn get_peer(&self, chain_selector: u64) -> Address {
+        self.peers.get(U256::from(chain_selector))
+    }
+
+    /// Get the token a lane pays fees in (zero address = native)
+    pub fn fee_token(&self, chain_id: U256) -> Address {
+        if self.pay_in_link.get(chain_id) {
+            self.link_token.get()
+        } else {
+            Address::ZERO
+        }
+    }
+
+    /// Get contract owner
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Internal: Resolve a destination chain to its selector and peer adapter
+    fn lane(&self, destination_chain: U256) -> Result<(u64, Address), CcipAdapterError> {
+        let selector = self.chain_selectors.get(destination_chain);
+        let peer = self.peers.get(selector);
+        if selector == U256::ZERO || peer == Address::ZERO {
+            return Err(CcipAdapterError::UnsupportedLane(UnsupportedLane {}));
+        }
+        Ok((selector.to::<u64>(), peer))
+    }
+
+    /// Internal: Build the CCIP message carrying a payload and one token amount
+    fn build_message(
+        &self,
+        destination_chain: U256,
+        peer: Address,
+        payload: Vec<u8>,
+        token: Address,
+        amount: U256,
+        fee_token: Address,
+    ) -> EVM2AnyMessage {
+        let mut gas = self.destination_gas.get(destination_chain);
+        if gas == U256::ZERO {
+            gas = U256::from(DEFAULT_DESTINATION_GAS);
+        }
+
+        EVM2AnyMessage {
+            receiver: peer.abi_encode().into(),
+            data: payload.into(),
+            tokenAmounts: vec![EVMTokenAmount { token, amount }],
+            feeToken: fee_token,
+            extraArgs: ccip_extra_args(gas).into(),
+        }
+    }
+
+    /// Internal: Router fee for a message, in the message's fee token
+    fn router_fee(&self, selector: u64, message: EVM2AnyMessage) -> Result<U256, CcipAdapterError> {
+        let calldata = getFeeCall {
+            destinationChainSelector: selector,
+            message,
+        }
+        .abi_encode();
+        let result = static_call(self.vm(), Call::new(), self.router.get(), &calldata)
+            .map_err(|_| CcipAdapterError::RouterCallFailed(RouterCallFailed {}))?;
+        getFeeCall::abi_decode_returns(&result).map_err(|_| CcipAdapterError::RouterCallFailed(RouterCallFailed {}))
+    }
+
+    /// Internal: Approve the router to pull `amount` of a token
+    fn approve(&mut self, token: Address, spender: Address, amount: U256) -> Result<(), CcipAdapterError> {
+        let config = Call::new_mutating(self);
+        let approved = IERC20::new(token)
+            .approve(self.vm(), config, spender, amount)
+            .map_err(|_| CcipAdapterError::TransferFailed(TransferFailed {}))?;
+        if !approved {
+            return Err(CcipAdapterError::TransferFailed(TransferFailed {}));
+        }
+        Ok(())
+    }
+
+    /// Internal: Check if caller is owner
+    fn only_owner(&self) -> Result<(), CcipAdapterError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(CcipAdapterError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,515 @@
+//! CctpAdapter Contract
+//!
+//! Bridge adapter for native USDC over Circle CCTP. On the source chain USDC is
+//! burned with `depositForBurnWithCaller` and the intent payload is sent as a
+//! companion CCTP message, both restricted to the peer adapter as destination
+//! caller. On the destination chain `relay` submits both attested messages to the
+//! MessageTransmitter: the burn mints USDC to this adapter, and the payload
+//! message is delivered to `handle_receive_message`, which forwards the minted
+//! USDC to the recipient and reports the delivery to SettlementVerifier.
+//! Intents for Noble and Solana, CCTP's non-EVM domains, mint straight to the
+//! recipient's 32-byte address; their payload goes to the peer program as a
+//! `ChainAddressPayload`.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use alloy_sol_types::sol;
+use stylus_sdk::{
+    alloy_primitives::{Address, Bytes, FixedBytes, U256},
+    call::Call,
+    crypto::keccak,
+    prelude::*,
+    storage::{StorageAddress, StorageBool, StorageFixedBytes, StorageMap, StorageU256},
+};
+
+use swoosh_common::bridge_adapter::ISettlementVerifier;
+use swoosh_common::chain_address::{encode_chain_address_payload, to_bytes32, validate_chain_address, ChainFamily};
+use swoosh_common::encoding::encode_bridge_payload;
+use swoosh_common::erc165;
+use swoosh_common::payload_codec::{self, PayloadError};
+
+/// CCTP domain of Noble, the Cosmos chain USDC is minted on
+const NOBLE_DOMAIN: u32 = 4;
+
+/// CCTP domain of Solana
+const SOLANA_DOMAIN: u32 = 5;
+
+// Events and errors
+sol! {
+    event DomainConfigured(uint256 indexed chainId, uint32 domain, bytes32 peer);
+    event UsdcBurned(
+        uint256 indexed intentId,
+        uint32 indexed destinationDomain,
+        uint64 burnNonce,
+        uint64 messageNonce,
+        uint256 amount
+    );
+    event UsdcDelivered(
+        uint256 indexed intentId,
+        uint32 indexed sourceDomain,
+        address indexed recipient,
+        uint256 amount
+    );
+
+    error Unauthorized();
+    error InvalidAddress();
+    error InvalidAmount();
+    error UnsupportedLane();
+    error UnsupportedToken();
+    error UntrustedPeer();
+    error InvalidPayload();
+    error MalformedPayload(uint8 reason);
+    error TransferFailed();
+    error CctpCallFailed();
+}
+
+/// Error types for CctpAdapter
+#[derive(SolidityError)]
+pub enum CctpAdapterError {
+    Unauthorized(Unauthorized),
+    InvalidAddress(InvalidAddress),
+    InvalidAmount(InvalidAmount),
+    UnsupportedLane(UnsupportedLane),
+    UnsupportedToken(UnsupportedToken),
+    UntrustedPeer(UntrustedPeer),
+    InvalidPayload(InvalidPayload),
+    MalformedPayload(MalformedPayload),
+    TransferFailed(TransferFailed),
+    CctpCallFailed(CctpCallFailed),
+}
+
+impl From<PayloadError> for CctpAdapterError {
+    fn from(err: PayloadError) -> Self {
+        CctpAdapterError::MalformedPayload(MalformedPayload { reason: err.code() })
+    }
+}
+
+// ERC20 interface
+sol_interface! {
+    interface IERC20 {
+        function transferFrom(address from, address to, uint256 amount) external returns (bool);
+        function transfer(address to, uint256 amount) external returns (bool);
+        function approve(address spender, uint256 amount) external returns (bool);
+    }
+}
+
+// CCTP TokenMessenger interface
+sol_interface! {
+    interface ITokenMessenger {
+        function depositForBurnWithCaller(
+            uint256 amount,
+            uint32 destinationDomain,
+            bytes32 mintRecipient,
+            address burnToken,
+            bytes32 destinationCaller
+        ) external returns (uint64);
+    }
+}
+
+// CCTP MessageTransmitter interface
+sol_interface! {
+    interface IMessageTransmitter {
+        function sendMessageWithCaller(
+            uint32 destinationDomain,
+            bytes32 recipient,
+            bytes32 destinationCaller,
+            bytes calldata messageBody
+        ) external returns (uint64);
+
+        function receiveMessage(bytes calldata message, bytes calldata attestation) external returns (bool);
+    }
+}
+
+#[entrypoint]
+#[storage]
+pub struct CctpAdapter {
+    /// Contract owner
+    owner: StorageAddress,
+    /// CCTP TokenMessenger on this chain
+    token_messenger: StorageAddress,
+    /// CCTP MessageTransmitter on this chain
+    message_transmitter: StorageAddress,
+    /// Native USDC on this chain
+    usdc: StorageAddress,
+    /// RouteExecutor contract (only caller allowed to bridge)
+    route_executor: StorageAddress,
+    /// SettlementVerifier notified on inbound deliveries
+    settlement_verifier: StorageAddress,
+    /// Mapping of EVM chain IDs to CCTP domains (stored as domain + 1, zero = unset)
+    chain_domains: StorageMap<U256, StorageU256>,
+    /// Mapping of CCTP domains to peer adapters
+    peers: StorageMap<U256, StorageFixedBytes<32>>,
+    /// Processed payload messages (by body hash)
+    processed: StorageMap<FixedBytes<32>, StorageBool>,
+}
+
+#[public]
+impl CctpAdapter {
+    /// Initialize the adapter
+    pub fn init(
+        &mut self,
+        token_messenger_address: Address,
+        message_transmitter_address: Address,
+        usdc_address: Address,
+        route_executor_address: Address,
+        settlement_verifier_address: Address,
+    ) -> Result<(), CctpAdapterError> {
+        if token_messenger_address == Address::ZERO
+            || message_transmitter_address == Address::ZERO
+            || usdc_address == Address::ZERO
+            || route_executor_address == Address::ZERO
+            || settlement_verifier_address == Address::ZERO
+        {
+            return Err(CctpAdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.owner.set(self.vm().msg_sender());
+        self.token_messenger.set(token_messenger_address);
+        self.message_transmitter.set(message_transmitter_address);
+        self.usdc.set(usdc_address);
+        self.route_executor.set(route_executor_address);
+        self.settlement_verifier.set(settlement_verifier_address);
+
+        Ok(())
+    }
+
+    /// Configure a chain's CCTP domain and peer adapter (admin only)
+    ///
+    /// Domain 0 (Ethereum) is valid in CCTP, so domains are stored offset by one.
+    pub fn set_domain(
+        &mut self,
+        chain_id: U256,
+        domain: u32,
+        peer: FixedBytes<32>,
+    ) -> Result<(), CctpAdapterError> {
+        self.only_owner()?;
+
+        if chain_id == U256::ZERO || peer == FixedBytes::<32>::ZERO {
+            return Err(CctpAdapterError::UnsupportedLane(UnsupportedLane {}));
+        }
+
+        self.chain_domains.setter(chain_id).set(U256::from(domain) + U256::from(1));
+        self.peers.setter(U256::from(domain)).set(peer);
+
+        self.vm().log(DomainConfigured {
+            chainId: chain_id,
+            domain,
+            peer,
+        });
+
+        Ok(())
+    }
+
+    /// CCTP charges no messaging fee
+    pub fn quote_fee(
+        &self,
+        destination_chain: U256,
+        token: Address,
+        _amount: U256,
+        _recipient: Address,
+    ) -> Result<U256, CctpAdapterError> {
+        if token != self.usdc.get() {
+            return Err(CctpAdapterError::UnsupportedToken(UnsupportedToken {}));
+        }
+        self.lane(destination_chain)?;
+        Ok(U256::ZERO)
+    }
+
+    /// Burn USDC and send the intent payload to the peer adapter
+    ///
+    /// Returns the keccak of the payload, which the destination side reports
+    /// to SettlementVerifier as the message ID.
+    #[payable]
+    pub fn bridge(
+        &mut self,
+        intent_id: U256,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+        unwrap_native: bool,
+    ) -> Result<FixedBytes<32>, CctpAdapterError> {
+        let executor = self.vm().msg_sender();
+        if executor != self.route_executor.get() {
+            return Err(CctpAdapterError::Unauthorized(Unauthorized {}));
+        }
+
+        // USDC only, so there is never a wrapped-native token to unwrap
+        if token != self.usdc.get() || unwrap_native {
+            return Err(CctpAdapterError::UnsupportedToken(UnsupportedToken {}));
+        }
+
+        if amount == U256::ZERO {
+            return Err(CctpAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let (domain, peer) = self.lane(destination_chain)?;
+        if domain_family(domain) != ChainFamily::Evm {
+            return Err(CctpAdapterError::UnsupportedLane(UnsupportedLane {}));
+        }
+
+        // Mint to the peer adapter, which alone may relay the message
+        let payload = encode_bridge_payload(intent_id, token, amount, recipient, false);
+        self.burn_and_send(executor, intent_id, amount, domain, peer, peer, payload)
+    }
+
+    /// CCTP charges no messaging fee; the recipient must be an address of
+    /// the destination domain's chain family
+    pub fn quote_chain_address_fee(
+        &self,
+        destination_chain: U256,
+        token: Address,
+        _amount: U256,
+        recipient_family: u8,
+        recipient: Bytes,
+    ) -> Result<U256, CctpAdapterError> {
+        if token != self.usdc.get() {
+            return Err(CctpAdapterError::UnsupportedToken(UnsupportedToken {}));
+        }
+        self.chain_address_lane(destination_chain, recipient_family, &recipient)?;
+        Ok(U256::ZERO)
+    }
+
+    /// Burn USDC straight to a Noble or Solana recipient and send the intent
+    /// payload to the peer program
+    ///
+    /// `recipient` is the raw Noble account or Solana USDC token account,
+    /// minted to as its 32-byte universal address.
+    #[payable]
+    pub fn bridge_to_chain_address(
+        &mut self,
+        intent_id: U256,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient_family: u8,
+        recipient: Bytes,
+    ) -> Result<FixedBytes<32>, CctpAdapterError> {
+        let executor = self.vm().msg_sender();
+        if executor != self.route_executor.get() {
+            return Err(CctpAdapterError::Unauthorized(Unauthorized {}));
+        }
+
+        if token != self.usdc.get() {
+            return Err(CctpAdapterError::UnsupportedToken(UnsupportedToken {}));
+        }
+
+        if amount == U256::ZERO {
+            return Err(CctpAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let (domain, peer, family) = self.chain_address_lane(destination_chain, recipient_family, &recipient)?;
+        let mint_recipient = to_bytes32(&recipient).ok_or(CctpAdapterError::InvalidAddress(InvalidAddress {}))?;
+        let payload = encode_chain_address_payload(intent_id, token, amount, family, recipient);
+        self.burn_and_send(executor, intent_id, amount, domain, mint_recipient, peer, payload)
+    }
+
+    /// Check whether a destination chain has a configured CCTP domain
+    pub fn supports_lane(&self, destination_chain: U256) -> bool {
+        self.lane(destination_chain).is_ok()
+    }
+
+    /// Relay attested burn and payload messages (destination side)
+    ///
+    /// Permissionless: the attestations authenticate both messages, and only
+    /// this adapter is allowed as their destination caller.
+    pub fn relay(
+        &mut self,
+        burn_message: Bytes,
+        burn_attestation: Bytes,
+        payload_message: Bytes,
+        payload_attestation: Bytes,
+    ) -> Result<(), CctpAdapterError> {
+        let transmitter = IMessageTransmitter::new(self.message_transmitter.get());
+
+        // Mint USDC to this adapter
+        let config = Call::new_mutating(self);
+        let minted = transmitter
+            .receive_message(self.vm(), config, burn_message, burn_attestation)
+            .map_err(|_| CctpAdapterError::CctpCallFailed(CctpCallFailed {}))?;
+        if !minted {
+            return Err(CctpAdapterError::CctpCallFailed(CctpCallFailed {}));
+        }
+
+        // Deliver the payload, which calls back into handle_receive_message
+        let config = Call::new_mutating(self);
+        let delivered = transmitter
+            .receive_message(self.vm(), config, payload_message, payload_attestation)
+            .map_err(|_| CctpAdapterError::CctpCallFailed(CctpCallFailed {}))?;
+        if !delivered {
+            return Err(CctpAdapterError::CctpCallFailed(CctpCallFailed {}));
+        }
+
+        Ok(())
+    }
+
+    /// MessageTransmitter callback for the payload message
+    pub fn handle_receive_message(
+        &mut self,
+        source_domain: u32,
+        sender: FixedBytes<32>,
+        message_body: Bytes,
+    ) -> Result<bool, CctpAdapterError> {
+        if self.vm().msg_sender() != self.message_transmitter.get() {
+            return Err(CctpAdapterError::Unauthorized(Unauthorized {}));
+        }
+
+        let peer = self.peers.get(U256::from(source_domain));
+        if peer == FixedBytes::<32>::ZERO || peer != sender {
+            return Err(CctpAdapterError::UntrustedPeer(UntrustedPeer {}));
+        }
+
+        let message_id = keccak(&message_body);
+        if self.processed.get(message_id) {
+            return Err(CctpAdapterError::InvalidPayload(InvalidPayload {}));
+        }
+        self.processed.setter(message_id).set(true);
+
+        let payload = payload_codec::decode_bridge(&message_body).map_err(CctpAdapterError::from)?;
+
+        // Forward the minted USDC to the recipient
+        let usdc = IERC20::new(self.usdc.get());
+        let config = Call::new_mutating(self);
+        let sent = usdc
+            .transfer(self.vm(), config, payload.recipient, payload.amount)
+            .map_err(|_| CctpAdapterError::TransferFailed(TransferFailed {}))?;
+        if !sent {
+            return Err(CctpAdapterError::TransferFailed(TransferFailed {}));
+        }
+
+        let verifier = ISettlementVerifier::new(self.settlement_verifier.get());
+        let config = Call::new_mutating(self);
+        verifier
+            .verify_adapter_message(self.vm(), config, message_id, payload.intentId)
+            .map_err(|_| CctpAdapterError::CctpCallFailed(CctpCallFailed {}))?;
+
+        self.vm().log(UsdcDelivered {
+            intentId: payload.intentId,
+            sourceDomain: source_domain,
+            recipient: payload.recipient,
+            amount: payload.amount,
+        });
+
+        Ok(true)
+    }
+
+    /// Get native USDC on this chain
+    pub fn usdc(&self) -> Address {
+        self.usdc.get()
+    }
+
+    /// ERC-165: whether this contract implements `interface_id`
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        erc165::supports(interface_id.0, &[erc165::BRIDGE_ADAPTER_ID, erc165::CHAIN_ADDRESS_ADAPTER_ID])
+    }
+
+    /// Get contract owner
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Internal: Resolve a destination chain to its CCTP domain and peer
+    fn lane(&self, destination_chain: U256) -> Result<(u32, FixedBytes<32>), CctpAdapterError> {
+        let stored = self.chain_domains.get(destination_chain);
+        if stored == U256::ZERO {
+            return Err(CctpAdapterError::UnsupportedLane(UnsupportedLane {}));
+        }
+        let domain = stored - U256::from(1);
+        Ok((domain.to::<u32>(), self.peers.get(domain)))
+    }
+
+    /// Internal: Resolve a non-EVM destination chain to its CCTP domain and
+    /// peer, checking the recipient is an address of the domain's family
+    fn chain_address_lane(
+        &self,
+        destination_chain: U256,
+        recipient_family: u8,
+        recipient: &[u8],
+    ) -> Result<(u32, FixedBytes<32>, ChainFamily), CctpAdapterError> {
+        let (domain, peer) = self.lane(destination_chain)?;
+        let family = domain_family(domain);
+        if family == ChainFamily::Evm || recipient_family != family as u8 {
+            return Err(CctpAdapterError::UnsupportedLane(UnsupportedLane {}));
+        }
+        validate_chain_address(recipient_family, recipient)
+            .map_err(|_| CctpAdapterError::InvalidAddress(InvalidAddress {}))?;
+        Ok((domain, peer, family))
+    }
+
+    /// Internal: Pull USDC from the executor, burn it for `mint_recipient`
+    /// and send `payload` to the peer, both only relayable by the peer
+    ///
+    /// Returns the keccak of the payload as the message ID.
+    #[allow(clippy::too_many_arguments)]
+    fn burn_and_send(
+        &mut self,
+        executor: Address,
+        intent_id: U256,
+        amount: U256,
+        domain: u32,
+        mint_recipient: FixedBytes<32>,
+        peer: FixedBytes<32>,
+        payload: Vec<u8>,
+    ) -> Result<FixedBytes<32>, CctpAdapterError> {
+        // Pull USDC from the executor
+        let token = self.usdc.get();
+        let erc20 = IERC20::new(token);
+        let this = self.vm().contract_address();
+        let config = Call::new_mutating(self);
+        let pulled = erc20
+            .transfer_from(self.vm(), config, executor, this, amount)
+            .map_err(|_| CctpAdapterError::TransferFailed(TransferFailed {}))?;
+        if !pulled {
+            return Err(CctpAdapterError::TransferFailed(TransferFailed {}));
+        }
+
+        let messenger = self.token_messenger.get();
+        let config = Call::new_mutating(self);
+        erc20
+            .approve(self.vm(), config, messenger, amount)
+            .map_err(|_| CctpAdapterError::TransferFailed(TransferFailed {}))?;
+
+        let config = Call::new_mutating(self);
+        let burn_nonce = ITokenMessenger::new(messenger)
+            .deposit_for_burn_with_caller(self.vm(), config, amount, domain, mint_recipient, token, peer)
+            .map_err(|_| CctpAdapterError::CctpCallFailed(CctpCallFailed {}))?;
+
+        // Companion message carrying the intent payload
+        let message_id = keccak(&payload);
+        let config = Call::new_mutating(self);
+        let message_nonce = IMessageTransmitter::new(self.message_transmitter.get())
+            .send_message_with_caller(self.vm(), config, domain, peer, peer, payload.into())
+            .map_err(|_| CctpAdapterError::CctpCallFailed(CctpCallFailed {}))?;
+
+        self.vm().log(UsdcBurned {
+            intentId: intent_id,
+            destinationDomain: domain,
+            burnNonce: burn_nonce,
+            messageNonce: message_nonce,
+            amount,
+        });
+
+        Ok(message_id)
+    }
+
+    /// Internal: Check if caller is owner
+    fn only_owner(&self) -> Result<(), CctpAdapterError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(CctpAdapterError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+}
+
+/// Chain family of the addresses a CCTP domain mints to
+fn domain_family(domain: u32) -> ChainFamily {
+    match domain {
+        NOBLE_DOMAIN => ChainFamily::Cosmos,
+        SOLANA_DOMAIN => ChainFamily::Solana,
+        _ => ChainFamily::Evm,
+    }
+}
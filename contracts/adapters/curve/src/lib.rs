@@ -0,0 +1,293 @@
+//! CurveAdapter Contract
+//!
+//! Swap adapter for stable↔stable legs through Curve stable-swap pools
+//! (`exchange`/`get_dy` with int128 coin indices). Each token pair is
+//! registered with the pool that serves it; the coin indices are read from
+//! the pool on registration. The adapter `data` must be empty.
+//!
+//! Older pools don't return the output from `exchange`, so the output is
+//! measured as the adapter's balance delta before it is sent on.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::{
+    alloy_primitives::{Address, Bytes, FixedBytes, U256},
+    call::{call, static_call, Call},
+    prelude::*,
+    storage::{StorageAddress, StorageMap, StorageU256},
+};
+
+use swoosh_common::encoding::sort_tokens;
+use swoosh_common::erc165;
+
+// Curve stable-swap pool ABI
+sol! {
+    function coins(uint256 i) external view returns (address);
+    function get_dy(int128 i, int128 j, uint256 dx) external view returns (uint256);
+    function exchange(int128 i, int128 j, uint256 dx, uint256 min_dy) external;
+}
+
+// Events and errors
+sol! {
+    event PoolSet(address indexed token0, address indexed token1, address pool);
+    event CurveSwap(
+        address indexed tokenIn,
+        address indexed tokenOut,
+        address indexed pool,
+        uint256 amountIn,
+        uint256 amountOut,
+        address recipient
+    );
+
+    error Unauthorized();
+    error InvalidAddress();
+    error InvalidAmount();
+    error InvalidPath();
+    error TransferFailed();
+    error SwapFailed();
+}
+
+/// Error types for CurveAdapter
+#[derive(SolidityError)]
+pub enum CurveAdapterError {
+    Unauthorized(Unauthorized),
+    InvalidAddress(InvalidAddress),
+    InvalidAmount(InvalidAmount),
+    InvalidPath(InvalidPath),
+    TransferFailed(TransferFailed),
+    SwapFailed(SwapFailed),
+}
+
+// ERC20 interface
+sol_interface! {
+    interface IERC20 {
+        function transferFrom(address from, address to, uint256 amount) external returns (bool);
+        function transfer(address to, uint256 amount) external returns (bool);
+        function approve(address spender, uint256 amount) external returns (bool);
+        function balanceOf(address account) external view returns (uint256);
+    }
+}
+
+/// Most coins a stable-swap pool holds
+const MAX_POOL_COINS: u64 = 8;
+
+#[entrypoint]
+#[storage]
+pub struct CurveAdapter {
+    /// Contract owner
+    owner: StorageAddress,
+    /// Pool registered per token pair (token0 -> token1 -> pool)
+    pools: StorageMap<Address, StorageMap<Address, StorageAddress>>,
+    /// Coin index plus one of each token in a registered pool (zero = absent)
+    coin_indices: StorageMap<Address, StorageMap<Address, StorageU256>>,
+}
+
+#[public]
+impl CurveAdapter {
+    /// Initialize the adapter
+    pub fn init(&mut self) -> Result<(), CurveAdapterError> {
+        if self.owner.get() != Address::ZERO {
+            return Err(CurveAdapterError::Unauthorized(Unauthorized {}));
+        }
+
+        self.owner.set(self.vm().msg_sender());
+
+        Ok(())
+    }
+
+    /// Register the pool serving a token pair (admin only)
+    ///
+    /// Both tokens must be coins of the pool. A zero pool removes the pair.
+    pub fn set_pool(&mut self, token_a: Address, token_b: Address, pool: Address) -> Result<(), CurveAdapterError> {
+        self.only_owner()?;
+
+        if token_a == Address::ZERO || token_b == Address::ZERO || token_a == token_b {
+            return Err(CurveAdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        if pool != Address::ZERO {
+            let index_a = self.find_coin(pool, token_a).ok_or(CurveAdapterError::InvalidPath(InvalidPath {}))?;
+            let index_b = self.find_coin(pool, token_b).ok_or(CurveAdapterError::InvalidPath(InvalidPath {}))?;
+            self.coin_indices.setter(pool).setter(token_a).set(U256::from(index_a + 1));
+            self.coin_indices.setter(pool).setter(token_b).set(U256::from(index_b + 1));
+        }
+
+        let (token0, token1) = sort_tokens(token_a, token_b);
+        self.pools.setter(token0).setter(token1).set(pool);
+
+        self.vm().log(PoolSet { token0, token1, pool });
+
+        Ok(())
+    }
+
+    /// Swap `amount_in` of `token_in` for `token_out` through the pair's pool
+    pub fn swap(
+        &mut self,
+        token_in: Address,
+        token_out: Address,
+        amount_in: U256,
+        min_amount_out: U256,
+        recipient: Address,
+        data: Bytes,
+    ) -> Result<U256, CurveAdapterError> {
+        if amount_in == U256::ZERO {
+            return Err(CurveAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        if recipient == Address::ZERO {
+            return Err(CurveAdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        if !data.is_empty() {
+            return Err(CurveAdapterError::InvalidPath(InvalidPath {}));
+        }
+
+        let (pool, i, j) = self
+            .route(token_in, token_out)
+            .ok_or(CurveAdapterError::InvalidPath(InvalidPath {}))?;
+
+        // Pull input from the caller and approve the pool
+        let input = IERC20::new(token_in);
+        let caller = self.vm().msg_sender();
+        let this = self.vm().contract_address();
+        let config = Call::new_mutating(self);
+        let pulled = input
+            .transfer_from(self.vm(), config, caller, this, amount_in)
+            .map_err(|_| CurveAdapterError::TransferFailed(TransferFailed {}))?;
+        if !pulled {
+            return Err(CurveAdapterError::TransferFailed(TransferFailed {}));
+        }
+
+        let config = Call::new_mutating(self);
+        input
+            .approve(self.vm(), config, pool, amount_in)
+            .map_err(|_| CurveAdapterError::TransferFailed(TransferFailed {}))?;
+
+        let output = IERC20::new(token_out);
+        let before = output
+            .balance_of(self.vm(), Call::new(), this)
+            .map_err(|_| CurveAdapterError::SwapFailed(SwapFailed {}))?;
+
+        let calldata = exchangeCall {
+            i,
+            j,
+            dx: amount_in,
+            min_dy: min_amount_out,
+        }
+        .abi_encode();
+        let config = Call::new_mutating(self);
+        call(self.vm(), config, pool, &calldata).map_err(|_| CurveAdapterError::SwapFailed(SwapFailed {}))?;
+
+        let after = output
+            .balance_of(self.vm(), Call::new(), this)
+            .map_err(|_| CurveAdapterError::SwapFailed(SwapFailed {}))?;
+        let amount_out = after.saturating_sub(before);
+        if amount_out < min_amount_out || amount_out == U256::ZERO {
+            return Err(CurveAdapterError::SwapFailed(SwapFailed {}));
+        }
+
+        let config = Call::new_mutating(self);
+        let sent = output
+            .transfer(self.vm(), config, recipient, amount_out)
+            .map_err(|_| CurveAdapterError::TransferFailed(TransferFailed {}))?;
+        if !sent {
+            return Err(CurveAdapterError::TransferFailed(TransferFailed {}));
+        }
+
+        self.vm().log(CurveSwap {
+            tokenIn: token_in,
+            tokenOut: token_out,
+            pool,
+            amountIn: amount_in,
+            amountOut: amount_out,
+            recipient,
+        });
+
+        Ok(amount_out)
+    }
+
+    /// Estimate the output of a swap with the pool's `get_dy`
+    ///
+    /// Returns zero for an unregistered pair or a pool that fails to quote.
+    pub fn quote(
+        &mut self,
+        token_in: Address,
+        token_out: Address,
+        amount_in: U256,
+        data: Bytes,
+    ) -> Result<U256, CurveAdapterError> {
+        if amount_in == U256::ZERO {
+            return Err(CurveAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        if !data.is_empty() {
+            return Err(CurveAdapterError::InvalidPath(InvalidPath {}));
+        }
+
+        let Some((pool, i, j)) = self.route(token_in, token_out) else {
+            return Ok(U256::ZERO);
+        };
+
+        let calldata = get_dyCall { i, j, dx: amount_in }.abi_encode();
+        let quoted = static_call(self.vm(), Call::new(), pool, &calldata)
+            .ok()
+            .and_then(|result| get_dyCall::abi_decode_returns(&result).ok());
+
+        Ok(quoted.unwrap_or(U256::ZERO))
+    }
+
+    /// Get the pool registered for a token pair
+    pub fn get_pool(&self, token_a: Address, token_b: Address) -> Address {
+        let (token0, token1) = sort_tokens(token_a, token_b);
+        self.pools.getter(token0).get(token1)
+    }
+
+    /// ERC-165: whether this contract implements `interface_id`
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        erc165::supports(interface_id.0, &[erc165::SWAP_ADAPTER_ID, erc165::SWAP_QUOTER_ID])
+    }
+
+    /// Get contract owner
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Internal: Pool and coin indices for a swap, if the pair is registered
+    fn route(&self, token_in: Address, token_out: Address) -> Option<(Address, i128, i128)> {
+        let pool = self.get_pool(token_in, token_out);
+        if pool == Address::ZERO {
+            return None;
+        }
+
+        let i = self.coin_indices.getter(pool).get(token_in);
+        let j = self.coin_indices.getter(pool).get(token_out);
+        if i == U256::ZERO || j == U256::ZERO {
+            return None;
+        }
+
+        Some((pool, i.to::<i128>() - 1, j.to::<i128>() - 1))
+    }
+
+    /// Internal: Index of a token among the pool's coins
+    fn find_coin(&self, pool: Address, token: Address) -> Option<u64> {
+        (0..MAX_POOL_COINS).find(|index| {
+            let calldata = coinsCall { i: U256::from(*index) }.abi_encode();
+            static_call(self.vm(), Call::new(), pool, &calldata)
+                .ok()
+                .and_then(|result| coinsCall::abi_decode_returns(&result).ok())
+                == Some(token)
+        })
+    }
+
+    /// Internal: Check if caller is owner
+    fn only_owner(&self) -> Result<(), CurveAdapterError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(CurveAdapterError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+}
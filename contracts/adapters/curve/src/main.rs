@@ -0,0 +1,14 @@
+//! Binary for exporting ABI
+//!
+//! This is required by cargo-stylus for constructor detection.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+
+#[cfg(not(any(test, feature = "export-abi")))]
+#[no_mangle]
+pub extern "C" fn main() {}
+
+#[cfg(feature = "export-abi")]
+fn main() {
+    swoosh_curve_adapter::print_from_args();
+}
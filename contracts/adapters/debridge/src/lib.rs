@@ -0,0 +1,369 @@
+//! DlnAdapter Contract
+//!
+//! Bridge adapter that posts intents as deBridge DLN orders. External takers
+//! fill the order on the destination chain from their own liquidity, paying
+//! the recipient directly, and later unlock the locked input on this chain.
+//! Each order is registered with SettlementVerifier on this chain, which
+//! confirms the intent once DlnSource reports the order's unlock, the proof
+//! that a taker fulfilled it.
+//!
+//! Takers are paid through the spread between the locked input and the
+//! requested output, set per lane as a margin in basis points.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::{
+    alloy_primitives::{Address, Bytes, FixedBytes, U256},
+    call::{call, static_call, Call},
+    prelude::*,
+    storage::{StorageAddress, StorageBool, StorageMap, StorageU256},
+};
+
+use swoosh_common::encoding::NATIVE_TOKEN;
+use swoosh_common::erc165;
+
+// deBridge DlnSource ABI
+sol! {
+    struct OrderCreation {
+        address giveTokenAddress;
+        uint256 giveAmount;
+        bytes takeTokenAddress;
+        uint256 takeAmount;
+        uint256 takeChainId;
+        bytes receiverDst;
+        address givePatchAuthoritySrc;
+        bytes orderAuthorityAddressDst;
+        bytes allowedTakerDst;
+        bytes externalCall;
+        bytes allowedCancelBeneficiarySrc;
+    }
+
+    function createOrder(
+        OrderCreation orderCreation,
+        bytes affiliateFee,
+        uint32 referralCode,
+        bytes permitEnvelope
+    ) external payable returns (bytes32 orderId);
+
+    function globalFixedNativeFee() external view returns (uint256);
+}
+
+// Events and errors
+sol! {
+    event LaneConfigured(uint256 indexed chainId, bool enabled, uint256 takerMarginBps);
+    event OutputTokenSet(uint256 indexed chainId, address indexed inputToken, address outputToken);
+    event DlnOrderPosted(
+        uint256 indexed intentId,
+        bytes32 indexed orderId,
+        uint256 indexed destinationChain,
+        uint256 giveAmount,
+        uint256 takeAmount
+    );
+
+    error Unauthorized();
+    error InvalidAddress();
+    error InvalidAmount();
+    error UnsupportedLane();
+    error UnsupportedToken();
+    error InsufficientFee();
+    error TransferFailed();
+    error DlnCallFailed();
+}
+
+/// Error types for DlnAdapter
+#[derive(SolidityError)]
+pub enum DlnAdapterError {
+    Unauthorized(Unauthorized),
+    InvalidAddress(InvalidAddress),
+    InvalidAmount(InvalidAmount),
+    UnsupportedLane(UnsupportedLane),
+    UnsupportedToken(UnsupportedToken),
+    InsufficientFee(InsufficientFee),
+    TransferFailed(TransferFailed),
+    DlnCallFailed(DlnCallFailed),
+}
+
+// ERC20 interface
+sol_interface! {
+    interface IERC20 {
+        function transferFrom(address from, address to, uint256 amount) external returns (bool);
+        function approve(address spender, uint256 amount) external returns (bool);
+    }
+}
+
+// SettlementVerifier interface for order registration
+sol_interface! {
+    interface ISettlementVerifier {
+        function registerDlnOrder(bytes32 order_id, uint256 intent_id) external;
+    }
+}
+
+/// Basis point denominator
+const BPS_DENOMINATOR: u64 = 10_000;
+/// Maximum taker margin a lane may be configured with (5%)
+const MAX_TAKER_MARGIN_BPS: u64 = 500;
+
+#[entrypoint]
+#[storage]
+pub struct DlnAdapter {
+    /// Contract owner
+    owner: StorageAddress,
+    /// deBridge DlnSource on this chain
+    dln_source: StorageAddress,
+    /// RouteExecutor contract (only caller allowed to bridge)
+    route_executor: StorageAddress,
+    /// SettlementVerifier that tracks posted orders
+    settlement_verifier: StorageAddress,
+    /// Mapping of chain IDs to whether DLN orders may target them
+    lanes: StorageMap<U256, StorageBool>,
+    /// Mapping of chain IDs to the taker margin (bps of the input amount)
+    taker_margin_bps: StorageMap<U256, StorageU256>,
+    /// Mapping of chain ID -> input token -> token takers deliver on that chain
+    output_tokens: StorageMap<U256, StorageMap<Address, StorageAddress>>,
+}
+
+#[public]
+impl DlnAdapter {
+    /// Initialize the adapter
+    pub fn init(
+        &mut self,
+        dln_source_address: Address,
+        route_executor_address: Address,
+        settlement_verifier_address: Address,
+    ) -> Result<(), DlnAdapterError> {
+        if dln_source_address == Address::ZERO
+            || route_executor_address == Address::ZERO
+            || settlement_verifier_address == Address::ZERO
+        {
+            return Err(DlnAdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.owner.set(self.vm().msg_sender());
+        self.dln_source.set(dln_source_address);
+        self.route_executor.set(route_executor_address);
+        self.settlement_verifier.set(settlement_verifier_address);
+
+        Ok(())
+    }
+
+    /// Enable or disable a lane and set its taker margin (admin only)
+    pub fn set_lane(
+        &mut self,
+        chain_id: U256,
+        enabled: bool,
+        taker_margin_bps: U256,
+    ) -> Result<(), DlnAdapterError> {
+        self.only_owner()?;
+
+        if chain_id == U256::ZERO {
+            return Err(DlnAdapterError::UnsupportedLane(UnsupportedLane {}));
+        }
+
+        if taker_margin_bps > U256::from(MAX_TAKER_MARGIN_BPS) {
+            return Err(DlnAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        self.lanes.setter(chain_id).set(enabled);
+        self.taker_margin_bps.setter(chain_id).set(taker_margin_bps);
+
+        self.vm().log(LaneConfigured {
+            chainId: chain_id,
+            enabled,
+            takerMarginBps: taker_margin_bps,
+        });
+
+        Ok(())
+    }
+
+    /// Map an input token to the token takers deliver on a chain (admin only)
+    pub fn set_output_token(
+        &mut self,
+        chain_id: U256,
+        input_token: Address,
+        output_token: Address,
+    ) -> Result<(), DlnAdapterError> {
+        self.only_owner()?;
+
+        if input_token == Address::ZERO {
+            return Err(DlnAdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.output_tokens.setter(chain_id).setter(input_token).set(output_token);
+
+        self.vm().log(OutputTokenSet {
+            chainId: chain_id,
+            inputToken: input_token,
+            outputToken: output_token,
+        });
+
+        Ok(())
+    }
+
+    /// DlnSource's fixed native fee per order
+    pub fn quote_fee(
+        &self,
+        destination_chain: U256,
+        token: Address,
+        _amount: U256,
+        _recipient: Address,
+    ) -> Result<U256, DlnAdapterError> {
+        self.lane(destination_chain, token)?;
+        self.fixed_fee()
+    }
+
+    /// Amount takers deliver on the destination for a given input
+    pub fn quote_output(&self, destination_chain: U256, amount: U256) -> U256 {
+        let margin_bps = self.taker_margin_bps.get(destination_chain);
+        amount - amount * margin_bps / U256::from(BPS_DENOMINATOR)
+    }
+
+    /// Post the intent as a DLN order for external takers
+    ///
+    /// Called by RouteExecutor, which approves this adapter for `amount` and
+    /// forwards the quoted fee as msg.value. Returns the DLN order ID, which
+    /// SettlementVerifier uses to confirm the intent once the order unlocks.
+    #[payable]
+    pub fn bridge(
+        &mut self,
+        intent_id: U256,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+        unwrap_native: bool,
+    ) -> Result<FixedBytes<32>, DlnAdapterError> {
+        let executor = self.vm().msg_sender();
+        if executor != self.route_executor.get() {
+            return Err(DlnAdapterError::Unauthorized(Unauthorized {}));
+        }
+
+        if amount == U256::ZERO || recipient == Address::ZERO {
+            return Err(DlnAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let output_token = self.lane(destination_chain, token)?;
+        // DLN takers can pay out the native asset directly
+        let take_token = if unwrap_native { NATIVE_TOKEN } else { output_token };
+        let take_amount = self.quote_output(destination_chain, amount);
+
+        let fee = self.vm().msg_value();
+        if fee < self.fixed_fee()? {
+            return Err(DlnAdapterError::InsufficientFee(InsufficientFee {}));
+        }
+
+        // Pull tokens from the executor and approve DlnSource
+        let erc20 = IERC20::new(token);
+        let this = self.vm().contract_address();
+        let config = Call::new_mutating(self);
+        let pulled = erc20
+            .transfer_from(self.vm(), config, executor, this, amount)
+            .map_err(|_| DlnAdapterError::TransferFailed(TransferFailed {}))?;
+        if !pulled {
+            return Err(DlnAdapterError::TransferFailed(TransferFailed {}));
+        }
+
+        let dln_source = self.dln_source.get();
+        let config = Call::new_mutating(self);
+        erc20
+            .approve(self.vm(), config, dln_source, amount)
+            .map_err(|_| DlnAdapterError::TransferFailed(TransferFailed {}))?;
+
+        // Any taker may fill; only this adapter may patch or cancel on this side
+        let order = OrderCreation {
+            giveTokenAddress: token,
+            giveAmount: amount,
+            takeTokenAddress: Bytes::copy_from_slice(take_token.as_slice()),
+            takeAmount: take_amount,
+            takeChainId: destination_chain,
+            receiverDst: Bytes::copy_from_slice(recipient.as_slice()),
+            givePatchAuthoritySrc: this,
+            orderAuthorityAddressDst: Bytes::copy_from_slice(recipient.as_slice()),
+            allowedTakerDst: Bytes::new(),
+            externalCall: Bytes::new(),
+            allowedCancelBeneficiarySrc: Bytes::new(),
+        };
+        let calldata = createOrderCall {
+            orderCreation: order,
+            affiliateFee: Bytes::new(),
+            referralCode: 0,
+            permitEnvelope: Bytes::new(),
+        }
+        .abi_encode();
+        let config = Call::new_payable(self, fee);
+        let result = call(self.vm(), config, dln_source, &calldata)
+            .map_err(|_| DlnAdapterError::DlnCallFailed(DlnCallFailed {}))?;
+        let order_id = createOrderCall::abi_decode_returns(&result)
+            .map_err(|_| DlnAdapterError::DlnCallFailed(DlnCallFailed {}))?;
+
+        let verifier = ISettlementVerifier::new(self.settlement_verifier.get());
+        let config = Call::new_mutating(self);
+        verifier
+            .register_dln_order(self.vm(), config, order_id, intent_id)
+            .map_err(|_| DlnAdapterError::DlnCallFailed(DlnCallFailed {}))?;
+
+        self.vm().log(DlnOrderPosted {
+            intentId: intent_id,
+            orderId: order_id,
+            destinationChain: destination_chain,
+            giveAmount: amount,
+            takeAmount: take_amount,
+        });
+
+        Ok(order_id)
+    }
+
+    /// Check whether DLN orders may target a destination chain
+    pub fn supports_lane(&self, destination_chain: U256) -> bool {
+        self.lanes.get(destination_chain)
+    }
+
+    /// Get the token takers deliver on a chain for an input token
+    pub fn get_output_token(&self, chain_id: U256, input_token: Address) -> Address {
+        self.output_tokens.getter(chain_id).get(input_token)
+    }
+
+    /// ERC-165: whether this contract implements `interface_id`
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        erc165::supports(interface_id.0, &[erc165::BRIDGE_ADAPTER_ID])
+    }
+
+    /// Get contract owner
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Internal: Resolve a lane to the token takers deliver
+    fn lane(&self, destination_chain: U256, token: Address) -> Result<Address, DlnAdapterError> {
+        if !self.lanes.get(destination_chain) {
+            return Err(DlnAdapterError::UnsupportedLane(UnsupportedLane {}));
+        }
+
+        let output_token = self.output_tokens.getter(destination_chain).get(token);
+        if output_token == Address::ZERO {
+            return Err(DlnAdapterError::UnsupportedToken(UnsupportedToken {}));
+        }
+
+        Ok(output_token)
+    }
+
+    /// Internal: DlnSource's fixed native fee per order
+    fn fixed_fee(&self) -> Result<U256, DlnAdapterError> {
+        let calldata = globalFixedNativeFeeCall {}.abi_encode();
+        let result = static_call(self.vm(), Call::new(), self.dln_source.get(), &calldata)
+            .map_err(|_| DlnAdapterError::DlnCallFailed(DlnCallFailed {}))?;
+        globalFixedNativeFeeCall::abi_decode_returns(&result)
+            .map_err(|_| DlnAdapterError::DlnCallFailed(DlnCallFailed {}))
+    }
+
+    /// Internal: Check if caller is owner
+    fn only_owner(&self) -> Result<(), DlnAdapterError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(DlnAdapterError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+}
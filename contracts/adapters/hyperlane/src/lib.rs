@@ -0,0 +1,607 @@
+//! HyperlaneAdapter Contract
+//!
+//! Bridge adapter that dispatches intents through a Hyperlane v3 mailbox. The
+//! destination gas limit is passed to the mailbox's default hook (the IGP) via
+//! StandardHookMetadata, so the quoted dispatch fee covers relaying. On the
+//! destination side the mailbox verifies inbound messages against the
+//! interchain security module exposed here before `handle` reports the delivery
+//! to SettlementVerifier.
+//!
+//! The adapter also carries config-sync messages between IntentValidator
+//! deployments: the local config sender dispatches them with `sendConfig`, and
+//! inbound ones from enrolled routers are applied to the local config target.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::{
+    alloy_primitives::{Address, Bytes, FixedBytes, U256},
+    call::{call, static_call, Call},
+    crypto::keccak,
+    prelude::*,
+    storage::{StorageAddress, StorageFixedBytes, StorageMap, StorageU256},
+};
+
+use swoosh_common::bridge_adapter::{IConfigSyncTarget, ISettlementVerifier};
+use swoosh_common::encoding::{address_to_bytes32, encode_bridge_payload, hyperlane_hook_metadata, is_config_sync};
+use swoosh_common::erc165::{self, IERC165};
+use swoosh_common::payload_codec::{self, PayloadError};
+
+// Hyperlane v3 mailbox ABI
+sol! {
+    function dispatch(
+        uint32 destinationDomain,
+        bytes32 recipientAddress,
+        bytes messageBody,
+        bytes hookMetadata
+    ) external payable returns (bytes32 messageId);
+
+    function quoteDispatch(
+        uint32 destinationDomain,
+        bytes32 recipientAddress,
+        bytes messageBody,
+        bytes hookMetadata
+    ) external view returns (uint256 fee);
+}
+
+// Events and errors
+sol! {
+    event RemoteRouterEnrolled(uint256 indexed chainId, uint32 domain, bytes32 router);
+    event DestinationGasSet(uint256 indexed chainId, uint256 gas);
+    event InterchainSecurityModuleSet(address indexed module);
+    event HyperlaneMessageDispatched(
+        uint256 indexed intentId,
+        uint32 indexed destinationDomain,
+        bytes32 messageId,
+        uint256 fee
+    );
+    event HyperlaneMessageHandled(
+        uint256 indexed intentId,
+        uint32 indexed originDomain,
+        bytes32 messageId
+    );
+    event ConfigSyncSet(address indexed sender, address indexed target);
+    event ConfigMessageDispatched(uint32 indexed destinationDomain, bytes32 messageId, uint256 fee);
+    event ConfigMessageHandled(uint32 indexed originDomain, bytes32 messageId, uint256 nonce);
+
+    error Unauthorized();
+    error InvalidAddress();
+    error InvalidAmount();
+    error UnsupportedLane();
+    error UntrustedRouter();
+    error InvalidPayload();
+    error MalformedPayload(uint8 reason);
+    error InsufficientFee();
+    error TransferFailed();
+    error MailboxCallFailed();
+    error ConfigSyncFailed();
+    error InterfaceNotSupported(address target, bytes4 interfaceId);
+}
+
+/// Error types for HyperlaneAdapter
+#[derive(SolidityError)]
+pub enum HyperlaneAdapterError {
+    Unauthorized(Unauthorized),
+    InvalidAddress(InvalidAddress),
+    InvalidAmount(InvalidAmount),
+    UnsupportedLane(UnsupportedLane),
+    UntrustedRouter(UntrustedRouter),
+    InvalidPayload(InvalidPayload),
+    MalformedPayload(MalformedPayload),
+    InsufficientFee(InsufficientFee),
+    TransferFailed(TransferFailed),
+    MailboxCallFailed(MailboxCallFailed),
+    ConfigSyncFailed(ConfigSyncFailed),
+    InterfaceNotSupported(InterfaceNotSupported),
+}
+
+impl From<PayloadError> for HyperlaneAdapterError {
+    fn from(err: PayloadError) -> Self {
+        HyperlaneAdapterError::MalformedPayload(MalformedPayload { reason: err.code() })
+    }
+}
+
+// ERC20 interface
+sol_interface! {
+    interface IERC20 {
+        function transferFrom(address from, address to, uint256 amount) external returns (bool);
+    }
+}
+
+/// Destination handle() gas used when no per-lane value is set
+const DEFAULT_DESTINATION_GAS: u64 = 200_000;
+
+#[entrypoint]
+#[storage]
+pub struct HyperlaneAdapter {
+    /// Contract owner
+    owner: StorageAddress,
+    /// Hyperlane mailbox on this chain
+    mailbox: StorageAddress,
+    /// RouteExecutor contract (only caller allowed to bridge)
+    route_executor: StorageAddress,
+    /// SettlementVerifier notified on inbound deliveries
+    settlement_verifier: StorageAddress,
+    /// Interchain security module (zero = mailbox default ISM)
+    interchain_security_module: StorageAddress,
+    /// Mapping of EVM chain IDs to Hyperlane domains
+    chain_domains: StorageMap<U256, StorageU256>,
+    /// Mapping of Hyperlane domains to enrolled remote adapters
+    remote_routers: StorageMap<U256, StorageFixedBytes<32>>,
+    /// Mapping of chain IDs to destination gas limits
+    destination_gas: StorageMap<U256, StorageU256>,
+    /// Local contract allowed to send config-sync messages (zero = disabled)
+    config_sender: StorageAddress,
+    /// Local contract inbound config-sync messages are applied to (zero = disabled)
+    config_target: StorageAddress,
+    /// Mapping of Hyperlane domains back to EVM chain IDs
+    domain_chains: StorageMap<U256, StorageU256>,
+}
+
+#[public]
+impl HyperlaneAdapter {
+    /// Initialize the adapter
+    pub fn init(
+        &mut self,
+        mailbox_address: Address,
+        route_executor_address: Address,
+        settlement_verifier_address: Address,
+    ) -> Result<(), HyperlaneAdapterError> {
+        if mailbox_address == Address::ZERO
+            || route_executor_address == Address::ZERO
+            || settlement_verifier_address == Address::ZERO
+        {
+            return Err(HyperlaneAdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.owner.set(self.vm().msg_sender());
+        self.mailbox.set(mailbox_address);
+        self.route_executor.set(route_executor_address);
+        self.settlement_verifier.set(settlement_verifier_address);
+
+        Ok(())
+    }
+
+    /// Enroll the remote adapter for a chain (admin only)
+    pub fn enroll_remote_router(
+        &mut self,
+        chain_id: U256,
+        domain: u32,
+        router: FixedBytes<32>,
+    ) -> Result<(), HyperlaneAdapterError> {
+        self.only_owner()?;
+
+        if chain_id == U256::ZERO || domain == 0 {
+            return Err(HyperlaneAdapterError::UnsupportedLane(UnsupportedLane {}));
+        }
+
+        self.chain_domains.setter(chain_id).set(U256::from(domain));
+        self.remote_routers.setter(U256::from(domain)).set(router);
+        self.domain_chains.setter(U256::from(domain)).set(chain_id);
+
+        self.vm().log(RemoteRouterEnrolled {
+            chainId: chain_id,
+            domain,
+            router,
+        });
+
+        Ok(())
+    }
+
+    /// Set the destination gas limit paid to the IGP for a chain (admin only)
+    pub fn set_destination_gas(&mut self, chain_id: U256, gas: U256) -> Result<(), HyperlaneAdapterError> {
+        self.only_owner()?;
+
+        if gas == U256::ZERO {
+            return Err(HyperlaneAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        self.destination_gas.setter(chain_id).set(gas);
+
+        self.vm().log(DestinationGasSet { chainId: chain_id, gas });
+
+        Ok(())
+    }
+
+    /// Set the interchain security module used for inbound messages (admin only)
+    ///
+    /// The zero address falls back to the mailbox's default ISM.
+    pub fn set_interchain_security_module(&mut self, module: Address) -> Result<(), HyperlaneAdapterError> {
+        self.only_owner()?;
+        self.interchain_security_module.set(module);
+
+        self.vm().log(InterchainSecurityModuleSet { module });
+
+        Ok(())
+    }
+
+    /// Set the local config-sync sender and target (admin only)
+    ///
+    /// Typically both are this chain's IntentValidator. Zero disables that
+    /// direction; a non-zero target must advertise `IConfigSyncTarget`.
+    pub fn set_config_sync(&mut self, sender: Address, target: Address) -> Result<(), HyperlaneAdapterError> {
+        self.only_owner()?;
+        if target != Address::ZERO {
+            self.require_interface(target, erc165::CONFIG_SYNC_TARGET_ID)?;
+        }
+
+        self.config_sender.set(sender);
+        self.config_target.set(target);
+
+        self.vm().log(ConfigSyncSet { sender, target });
+
+        Ok(())
+    }
+
+    /// ISM hook queried by the mailbox before calling `handle`
+    pub fn interchain_security_module(&self) -> Address {
+        self.interchain_security_module.get()
+    }
+
+    /// Quote the dispatch fee (protocol fee plus IGP gas payment)
+    pub fn quote_fee(
+        &self,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+    ) -> Result<U256, HyperlaneAdapterError> {
+        self.internal_quote_fee(destination_chain, token, amount, recipient, U256::ZERO)
+    }
+
+    /// Quote the dispatch fee with the destination gas raised to `gas_limit`
+    /// (never below the lane's configured gas)
+    pub fn quote_fee_with_gas_limit(
+        &self,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+        gas_limit: U256,
+    ) -> Result<U256, HyperlaneAdapterError> {
+        self.internal_quote_fee(destination_chain, token, amount, recipient, gas_limit)
+    }
+
+    /// Lock tokens and dispatch the intent payload to the remote adapter
+    ///
+    /// Called by RouteExecutor, which approves this adapter for `amount` and
+    /// forwards the quoted dispatch fee as msg.value.
+    #[payable]
+    pub fn bridge(
+        &mut self,
+        intent_id: U256,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+        unwrap_native: bool,
+    ) -> Result<FixedBytes<32>, HyperlaneAdapterError> {
+        self.internal_bridge(intent_id, destination_chain, token, amount, recipient, unwrap_native, U256::ZERO)
+    }
+
+    /// Bridge as `bridge` does, with the destination gas paid to the IGP
+    /// raised to `gas_limit`
+    ///
+    /// msg.value must cover `quote_fee_with_gas_limit`.
+    #[payable]
+    #[allow(clippy::too_many_arguments)]
+    pub fn bridge_with_gas_limit(
+        &mut self,
+        intent_id: U256,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+        unwrap_native: bool,
+        gas_limit: U256,
+    ) -> Result<FixedBytes<32>, HyperlaneAdapterError> {
+        self.internal_bridge(intent_id, destination_chain, token, amount, recipient, unwrap_native, gas_limit)
+    }
+
+    /// Quote the dispatch fee for a config-sync message
+    pub fn quote_config_fee(&self, destination_chain: U256, message: Bytes) -> Result<U256, HyperlaneAdapterError> {
+        let (domain, router) = self.lane(destination_chain)?;
+        let calldata = quoteDispatchCall {
+            destinationDomain: domain,
+            recipientAddress: router,
+            messageBody: message,
+            hookMetadata: self.hook_metadata(destination_chain, self.vm().contract_address(), U256::ZERO).into(),
+        }
+        .abi_encode();
+
+        let result = static_call(self.vm(), Call::new(), self.mailbox.get(), &calldata)
+            .map_err(|_| HyperlaneAdapterError::MailboxCallFailed(MailboxCallFailed {}))?;
+        let fee = quoteDispatchCall::abi_decode_returns(&result)
+            .map_err(|_| HyperlaneAdapterError::MailboxCallFailed(MailboxCallFailed {}))?;
+
+        Ok(fee)
+    }
+
+    /// Dispatch a config-sync message to the remote adapter (config sender only)
+    ///
+    /// msg.value pays the dispatch fee; any excess is refunded to the sender.
+    #[payable]
+    pub fn send_config(
+        &mut self,
+        destination_chain: U256,
+        message: Bytes,
+    ) -> Result<FixedBytes<32>, HyperlaneAdapterError> {
+        let sender = self.vm().msg_sender();
+        if sender == Address::ZERO || sender != self.config_sender.get() {
+            return Err(HyperlaneAdapterError::Unauthorized(Unauthorized {}));
+        }
+
+        if !is_config_sync(&message) {
+            return Err(HyperlaneAdapterError::InvalidPayload(InvalidPayload {}));
+        }
+
+        let (domain, router) = self.lane(destination_chain)?;
+
+        let fee = self.vm().msg_value();
+        let calldata = dispatchCall {
+            destinationDomain: domain,
+            recipientAddress: router,
+            messageBody: message,
+            hookMetadata: self.hook_metadata(destination_chain, sender, U256::ZERO).into(),
+        }
+        .abi_encode();
+        let mailbox = self.mailbox.get();
+        let config = Call::new_payable(self, fee);
+        let result = call(self.vm(), config, mailbox, &calldata)
+            .map_err(|_| HyperlaneAdapterError::InsufficientFee(InsufficientFee {}))?;
+        let message_id = dispatchCall::abi_decode_returns(&result)
+            .map_err(|_| HyperlaneAdapterError::MailboxCallFailed(MailboxCallFailed {}))?;
+
+        self.vm().log(ConfigMessageDispatched {
+            destinationDomain: domain,
+            messageId: message_id,
+            fee,
+        });
+
+        Ok(message_id)
+    }
+
+    /// Check whether a destination chain has an enrolled remote adapter
+    pub fn supports_lane(&self, destination_chain: U256) -> bool {
+        self.lane(destination_chain).is_ok()
+    }
+
+    /// Handle an inbound message (destination side)
+    ///
+    /// Only the mailbox may call this, after the ISM has verified the message.
+    /// The sender must be the remote adapter enrolled for the origin domain.
+    #[payable]
+    pub fn handle(
+        &mut self,
+        origin: u32,
+        sender: FixedBytes<32>,
+        message: Bytes,
+    ) -> Result<(), HyperlaneAdapterError> {
+        if self.vm().msg_sender() != self.mailbox.get() {
+            return Err(HyperlaneAdapterError::Unauthorized(Unauthorized {}));
+        }
+
+        let router = self.remote_routers.get(U256::from(origin));
+        if router == FixedBytes::<32>::ZERO || router != sender {
+            return Err(HyperlaneAdapterError::UntrustedRouter(UntrustedRouter {}));
+        }
+
+        if is_config_sync(&message) {
+            return self.handle_config(origin, message);
+        }
+
+        let payload = payload_codec::decode_bridge(&message).map_err(HyperlaneAdapterError::from)?;
+
+        // Hyperlane message IDs are the keccak of the full message; the body hash
+        // is unique per intent and is what the source side indexes on
+        let message_id = keccak(&message);
+
+        let verifier = ISettlementVerifier::new(self.settlement_verifier.get());
+        let config = Call::new_mutating(self);
+        verifier
+            .verify_adapter_message(self.vm(), config, message_id, payload.intentId)
+            .map_err(|_| HyperlaneAdapterError::MailboxCallFailed(MailboxCallFailed {}))?;
+
+        self.vm().log(HyperlaneMessageHandled {
+            intentId: payload.intentId,
+            originDomain: origin,
+            messageId: message_id,
+        });
+
+        Ok(())
+    }
+
+    /// Get the Hyperlane domain configured for a chain
+    pub fn get_domain(&self, chain_id: U256) -> U256 {
+        self.chain_domains.get(chain_id)
+    }
+
+    /// Get the enrolled remote adapter for a domain
+    pub fn get_remote_router(&self, domain: u32) -> FixedBytes<32> {
+        self.remote_routers.get(U256::from(domain))
+    }
+
+    /// Get this adapter as a bytes32 recipient (for enrolling on remote chains)
+    pub fn router_id(&self) -> FixedBytes<32> {
+        address_to_bytes32(self.vm().contract_address())
+    }
+
+    /// ERC-165: whether this contract implements `interface_id`
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        erc165::supports(
+            interface_id.0,
+            &[erc165::BRIDGE_ADAPTER_ID, erc165::GAS_LIMIT_ADAPTER_ID, erc165::CONFIG_SYNC_ADAPTER_ID],
+        )
+    }
+
+    /// Get contract owner
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Get the local config-sync sender and target
+    pub fn config_sync(&self) -> (Address, Address) {
+        (self.config_sender.get(), self.config_target.get())
+    }
+
+    /// Internal: Apply an inbound config-sync message from an enrolled router
+    ///
+    /// The target checks the message came from the origin chain and hasn't
+    /// been applied before.
+    fn handle_config(&mut self, origin: u32, message: Bytes) -> Result<(), HyperlaneAdapterError> {
+        let target = self.config_target.get();
+        let source_chain = self.domain_chains.get(U256::from(origin));
+        if target == Address::ZERO || source_chain == U256::ZERO {
+            return Err(HyperlaneAdapterError::ConfigSyncFailed(ConfigSyncFailed {}));
+        }
+
+        let message_id = keccak(&message);
+
+        let config = Call::new_mutating(self);
+        let nonce = IConfigSyncTarget::new(target)
+            .apply_config_sync(self.vm(), config, source_chain, message)
+            .map_err(|_| HyperlaneAdapterError::ConfigSyncFailed(ConfigSyncFailed {}))?;
+
+        self.vm().log(ConfigMessageHandled {
+            originDomain: origin,
+            messageId: message_id,
+            nonce,
+        });
+
+        Ok(())
+    }
+
+    /// Internal: Resolve a destination chain to its domain and remote adapter
+    fn lane(&self, destination_chain: U256) -> Result<(u32, FixedBytes<32>), HyperlaneAdapterError> {
+        let domain = self.chain_domains.get(destination_chain);
+        let router = self.remote_routers.get(domain);
+        if domain == U256::ZERO || router == FixedBytes::<32>::ZERO {
+            return Err(HyperlaneAdapterError::UnsupportedLane(UnsupportedLane {}));
+        }
+        Ok((domain.to::<u32>(), router))
+    }
+
+    /// Internal: Quote the dispatch fee for a bridge payload
+    fn internal_quote_fee(
+        &self,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+        gas_limit: U256,
+    ) -> Result<U256, HyperlaneAdapterError> {
+        let (domain, router) = self.lane(destination_chain)?;
+        let calldata = quoteDispatchCall {
+            destinationDomain: domain,
+            recipientAddress: router,
+            messageBody: encode_bridge_payload(U256::ZERO, token, amount, recipient, false).into(),
+            hookMetadata: self.hook_metadata(destination_chain, self.vm().contract_address(), gas_limit).into(),
+        }
+        .abi_encode();
+
+        let result = static_call(self.vm(), Call::new(), self.mailbox.get(), &calldata)
+            .map_err(|_| HyperlaneAdapterError::MailboxCallFailed(MailboxCallFailed {}))?;
+        let fee = quoteDispatchCall::abi_decode_returns(&result)
+            .map_err(|_| HyperlaneAdapterError::MailboxCallFailed(MailboxCallFailed {}))?;
+
+        Ok(fee)
+    }
+
+    /// Internal: Lock tokens and dispatch the intent payload, with an
+    /// optional destination gas override
+    #[allow(clippy::too_many_arguments)]
+    fn internal_bridge(
+        &mut self,
+        intent_id: U256,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+        unwrap_native: bool,
+        gas_limit: U256,
+    ) -> Result<FixedBytes<32>, HyperlaneAdapterError> {
+        let executor = self.vm().msg_sender();
+        if executor != self.route_executor.get() {
+            return Err(HyperlaneAdapterError::Unauthorized(Unauthorized {}));
+        }
+
+        if amount == U256::ZERO {
+            return Err(HyperlaneAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let (domain, router) = self.lane(destination_chain)?;
+
+        // Lock the bridged tokens in the adapter
+        let erc20 = IERC20::new(token);
+        let this = self.vm().contract_address();
+        let config = Call::new_mutating(self);
+        let pulled = erc20
+            .transfer_from(self.vm(), config, executor, this, amount)
+            .map_err(|_| HyperlaneAdapterError::TransferFailed(TransferFailed {}))?;
+        if !pulled {
+            return Err(HyperlaneAdapterError::TransferFailed(TransferFailed {}));
+        }
+
+        // Dispatch, paying the IGP through the default hook; excess is refunded to the executor
+        let fee = self.vm().msg_value();
+        let calldata = dispatchCall {
+            destinationDomain: domain,
+            recipientAddress: router,
+            messageBody: encode_bridge_payload(intent_id, token, amount, recipient, unwrap_native).into(),
+            hookMetadata: self.hook_metadata(destination_chain, executor, gas_limit).into(),
+        }
+        .abi_encode();
+        let mailbox = self.mailbox.get();
+        let config = Call::new_payable(self, fee);
+        let result = call(self.vm(), config, mailbox, &calldata)
+            .map_err(|_| HyperlaneAdapterError::InsufficientFee(InsufficientFee {}))?;
+        let message_id = dispatchCall::abi_decode_returns(&result)
+            .map_err(|_| HyperlaneAdapterError::MailboxCallFailed(MailboxCallFailed {}))?;
+
+        self.vm().log(HyperlaneMessageDispatched {
+            intentId: intent_id,
+            destinationDomain: domain,
+            messageId: message_id,
+            fee,
+        });
+
+        Ok(message_id)
+    }
+
+    /// Internal: Hook metadata carrying the destination gas limit for the IGP
+    ///
+    /// A `gas_limit` above the lane's destination gas replaces it.
+    fn hook_metadata(&self, destination_chain: U256, refund_address: Address, gas_limit: U256) -> alloc::vec::Vec<u8> {
+        let mut gas = self.destination_gas.get(destination_chain);
+        if gas == U256::ZERO {
+            gas = U256::from(DEFAULT_DESTINATION_GAS);
+        }
+        hyperlane_hook_metadata(U256::ZERO, gas.max(gas_limit), refund_address)
+    }
+
+    /// Internal: Check if caller is owner
+    fn only_owner(&self) -> Result<(), HyperlaneAdapterError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(HyperlaneAdapterError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+
+    /// Internal: Reject a config-sync target unless ERC-165 detection finds `interface_id`
+    fn require_interface(&self, target: Address, interface_id: [u8; 4]) -> Result<(), HyperlaneAdapterError> {
+        let erc165_target = IERC165::new(target);
+        let supported = erc165::detect(interface_id, |id| {
+            erc165_target.supports_interface(self.vm(), Call::new(), FixedBytes(id)).unwrap_or(false)
+        });
+        if !supported {
+            return Err(HyperlaneAdapterError::InterfaceNotSupported(InterfaceNotSupported {
+                target,
+                interfaceId: FixedBytes(interface_id),
+            }));
+        }
+        Ok(())
+    }
+}
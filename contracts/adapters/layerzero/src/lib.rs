@@ -0,0 +1,655 @@
+//! LayerZeroAdapter Contract
+//!
+//! OApp-style bridge adapter that carries intents over LayerZero v2 lanes where
+//! CCIP has no coverage. On the source chain it locks the bridged tokens and
+//! sends the intent payload to the configured peer; on the destination chain it
+//! receives the payload from the endpoint and reports delivery to SettlementVerifier.
+//! Gas drops ride along as an executor native-drop option paid to the recipient.
+//! ERC-721 and ERC-1155 tokens are locked the same way and carried in an NFT payload.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::{
+    alloy_primitives::{Address, Bytes, FixedBytes, U256},
+    call::{call, static_call, Call},
+    prelude::*,
+    storage::{StorageAddress, StorageFixedBytes, StorageMap, StorageU256},
+};
+
+use swoosh_common::bridge_adapter::ISettlementVerifier;
+use swoosh_common::encoding::{
+    append_lz_native_drop, encode_bridge_payload, encode_nft_bridge_payload, lz_receive_options, AssetType,
+};
+use swoosh_common::erc165;
+use swoosh_common::payload_codec::{self, PayloadError};
+
+// LayerZero v2 endpoint ABI
+sol! {
+    struct MessagingParams {
+        uint32 dstEid;
+        bytes32 receiver;
+        bytes message;
+        bytes options;
+        bool payInLzToken;
+    }
+
+    struct MessagingFee {
+        uint256 nativeFee;
+        uint256 lzTokenFee;
+    }
+
+    struct MessagingReceipt {
+        bytes32 guid;
+        uint64 nonce;
+        MessagingFee fee;
+    }
+
+    function quote(MessagingParams params, address sender) external view returns (MessagingFee fee);
+    function send(MessagingParams params, address refundAddress) external payable returns (MessagingReceipt receipt);
+}
+
+// Events and errors
+sol! {
+    event LaneConfigured(uint256 indexed chainId, uint32 eid, bytes32 peer);
+    event ReceiveGasSet(uint256 indexed chainId, uint256 gas);
+    event LayerZeroMessageSent(
+        uint256 indexed intentId,
+        uint32 indexed dstEid,
+        bytes32 guid,
+        uint256 nativeFee
+    );
+    event LayerZeroMessageReceived(
+        uint256 indexed intentId,
+        uint32 indexed srcEid,
+        bytes32 guid
+    );
+
+    error Unauthorized();
+    error InvalidAddress();
+    error InvalidAmount();
+    error UnsupportedLane();
+    error UntrustedPeer();
+    error MalformedPayload(uint8 reason);
+    error InsufficientFee();
+    error TransferFailed();
+    error EndpointCallFailed();
+    error UnsupportedAsset(uint8 assetType);
+}
+
+/// Error types for LayerZeroAdapter
+#[derive(SolidityError)]
+pub enum LayerZeroAdapterError {
+    Unauthorized(Unauthorized),
+    InvalidAddress(InvalidAddress),
+    InvalidAmount(InvalidAmount),
+    UnsupportedLane(UnsupportedLane),
+    UntrustedPeer(UntrustedPeer),
+    MalformedPayload(MalformedPayload),
+    InsufficientFee(InsufficientFee),
+    TransferFailed(TransferFailed),
+    EndpointCallFailed(EndpointCallFailed),
+    UnsupportedAsset(UnsupportedAsset),
+}
+
+impl From<PayloadError> for LayerZeroAdapterError {
+    fn from(err: PayloadError) -> Self {
+        LayerZeroAdapterError::MalformedPayload(MalformedPayload { reason: err.code() })
+    }
+}
+
+// ERC20 interface
+sol_interface! {
+    interface IERC20 {
+        function transferFrom(address from, address to, uint256 amount) external returns (bool);
+    }
+}
+
+// ERC-721 interface
+sol_interface! {
+    interface IERC721 {
+        function transferFrom(address from, address to, uint256 token_id) external;
+    }
+}
+
+// ERC-1155 interface
+sol_interface! {
+    interface IERC1155 {
+        function safeTransferFrom(address from, address to, uint256 id, uint256 amount, bytes data) external;
+    }
+}
+
+/// `onERC1155Received` selector, returned to accept a transfer
+const ERC1155_RECEIVED: [u8; 4] = [0xf2, 0x3a, 0x6e, 0x61];
+
+/// Gas forwarded to lzReceive on the destination when no per-lane value is set
+const DEFAULT_RECEIVE_GAS: u64 = 200_000;
+
+#[entrypoint]
+#[storage]
+pub struct LayerZeroAdapter {
+    /// Contract owner
+    owner: StorageAddress,
+    /// LayerZero v2 endpoint on this chain
+    endpoint: StorageAddress,
+    /// RouteExecutor contract (only caller allowed to bridge)
+    route_executor: StorageAddress,
+    /// SettlementVerifier notified on inbound deliveries
+    settlement_verifier: StorageAddress,
+    /// Mapping of EVM chain IDs to LayerZero endpoint IDs
+    chain_eids: StorageMap<U256, StorageU256>,
+    /// Mapping of LayerZero endpoint IDs to trusted peer adapters
+    peers: StorageMap<U256, StorageFixedBytes<32>>,
+    /// Mapping of chain IDs to lzReceive gas limits
+    receive_gas: StorageMap<U256, StorageU256>,
+}
+
+#[public]
+impl LayerZeroAdapter {
+    /// Initialize the adapter
+    pub fn init(
+        &mut self,
+        endpoint_address: Address,
+        route_executor_address: Address,
+        settlement_verifier_address: Address,
+    ) -> Result<(), LayerZeroAdapterError> {
+        if endpoint_address == Address::ZERO
+            || route_executor_address == Address::ZERO
+            || settlement_verifier_address == Address::ZERO
+        {
+            return Err(LayerZeroAdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.owner.set(self.vm().msg_sender());
+        self.endpoint.set(endpoint_address);
+        self.route_executor.set(route_executor_address);
+        self.settlement_verifier.set(settlement_verifier_address);
+
+        Ok(())
+    }
+
+    /// Configure a lane: chain ID to endpoint ID and trusted peer (admin only)
+    pub fn set_lane(
+        &mut self,
+        chain_id: U256,
+        eid: u32,
+        peer: FixedBytes<32>,
+    ) -> Result<(), LayerZeroAdapterError> {
+        self.only_owner()?;
+
+        if chain_id == U256::ZERO || eid == 0 {
+            return Err(LayerZeroAdapterError::UnsupportedLane(UnsupportedLane {}));
+        }
+
+        self.chain_eids.setter(chain_id).set(U256::from(eid));
+        self.peers.setter(U256::from(eid)).set(peer);
+
+        self.vm().log(LaneConfigured {
+            chainId: chain_id,
+            eid,
+            peer,
+        });
+
+        Ok(())
+    }
+
+    /// Set the lzReceive gas limit for a destination chain (admin only)
+    pub fn set_receive_gas(&mut self, chain_id: U256, gas: U256) -> Result<(), LayerZeroAdapterError> {
+        self.only_owner()?;
+
+        if gas == U256::ZERO || gas > U256::from(u128::MAX) {
+            return Err(LayerZeroAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        self.receive_gas.setter(chain_id).set(gas);
+
+        self.vm().log(ReceiveGasSet { chainId: chain_id, gas });
+
+        Ok(())
+    }
+
+    /// Quote the native messaging fee for bridging to a destination chain
+    pub fn quote_fee(
+        &self,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+    ) -> Result<U256, LayerZeroAdapterError> {
+        self.quote_fee_with_gas_drop(destination_chain, token, amount, recipient, U256::ZERO)
+    }
+
+    /// Quote the native messaging fee including a gas drop to the recipient
+    ///
+    /// `gas_drop` is in destination native units; the executor's price for
+    /// delivering it is included in the fee.
+    pub fn quote_fee_with_gas_drop(
+        &self,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+        gas_drop: U256,
+    ) -> Result<U256, LayerZeroAdapterError> {
+        let message = encode_bridge_payload(U256::ZERO, token, amount, recipient, false);
+        let params = self.build_params(destination_chain, message, recipient, gas_drop, U256::ZERO)?;
+        self.quote_params(params)
+    }
+
+    /// Quote the native messaging fee with the destination receive gas raised
+    /// to `gas_limit` (never below the lane's configured gas)
+    pub fn quote_fee_with_gas_limit(
+        &self,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+        gas_limit: U256,
+    ) -> Result<U256, LayerZeroAdapterError> {
+        let message = encode_bridge_payload(U256::ZERO, token, amount, recipient, false);
+        let params = self.build_params(destination_chain, message, recipient, U256::ZERO, gas_limit)?;
+        self.quote_params(params)
+    }
+
+    /// Quote the native messaging fee for bridging an NFT
+    ///
+    /// `asset_type` follows `AssetType` and must be ERC-721 or ERC-1155.
+    pub fn quote_nft_fee(
+        &self,
+        destination_chain: U256,
+        asset_type: u8,
+        token: Address,
+        token_id: U256,
+        amount: U256,
+        recipient: Address,
+    ) -> Result<U256, LayerZeroAdapterError> {
+        let asset = Self::nft_asset(asset_type)?;
+        let message = encode_nft_bridge_payload(U256::ZERO, asset, token, token_id, amount, recipient);
+        let params = self.build_params(destination_chain, message, recipient, U256::ZERO, U256::ZERO)?;
+        self.quote_params(params)
+    }
+
+    /// Lock tokens and send the intent payload to the destination peer
+    ///
+    /// Called by RouteExecutor, which approves this adapter for `amount` and
+    /// forwards the quoted messaging fee as msg.value.
+    #[payable]
+    pub fn bridge(
+        &mut self,
+        intent_id: U256,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+        unwrap_native: bool,
+    ) -> Result<FixedBytes<32>, LayerZeroAdapterError> {
+        self.internal_bridge(
+            intent_id,
+            destination_chain,
+            token,
+            amount,
+            recipient,
+            unwrap_native,
+            U256::ZERO,
+            U256::ZERO,
+        )
+    }
+
+    /// Bridge as `bridge` does, also delivering `gas_drop` of destination
+    /// native to the recipient
+    ///
+    /// msg.value must cover `quote_fee_with_gas_drop`.
+    #[payable]
+    #[allow(clippy::too_many_arguments)]
+    pub fn bridge_with_gas_drop(
+        &mut self,
+        intent_id: U256,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+        unwrap_native: bool,
+        gas_drop: U256,
+    ) -> Result<FixedBytes<32>, LayerZeroAdapterError> {
+        self.internal_bridge(
+            intent_id,
+            destination_chain,
+            token,
+            amount,
+            recipient,
+            unwrap_native,
+            gas_drop,
+            U256::ZERO,
+        )
+    }
+
+    /// Bridge as `bridge` does, with the destination receive gas raised to
+    /// `gas_limit`
+    ///
+    /// msg.value must cover `quote_fee_with_gas_limit`.
+    #[payable]
+    #[allow(clippy::too_many_arguments)]
+    pub fn bridge_with_gas_limit(
+        &mut self,
+        intent_id: U256,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+        unwrap_native: bool,
+        gas_limit: U256,
+    ) -> Result<FixedBytes<32>, LayerZeroAdapterError> {
+        self.internal_bridge(
+            intent_id,
+            destination_chain,
+            token,
+            amount,
+            recipient,
+            unwrap_native,
+            U256::ZERO,
+            gas_limit,
+        )
+    }
+
+    /// Lock an ERC-721 or ERC-1155 token and send the NFT payload to the
+    /// destination peer
+    ///
+    /// Called by RouteExecutor, which approves this adapter for the token
+    /// and forwards `quote_nft_fee` as msg.value.
+    #[payable]
+    #[allow(clippy::too_many_arguments)]
+    pub fn bridge_nft(
+        &mut self,
+        intent_id: U256,
+        destination_chain: U256,
+        asset_type: u8,
+        token: Address,
+        token_id: U256,
+        amount: U256,
+        recipient: Address,
+    ) -> Result<FixedBytes<32>, LayerZeroAdapterError> {
+        let executor = self.vm().msg_sender();
+        if executor != self.route_executor.get() {
+            return Err(LayerZeroAdapterError::Unauthorized(Unauthorized {}));
+        }
+
+        let asset = Self::nft_asset(asset_type)?;
+        if amount == U256::ZERO || (asset == AssetType::Erc721 && amount != U256::from(1)) {
+            return Err(LayerZeroAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let message = encode_nft_bridge_payload(intent_id, asset, token, token_id, amount, recipient);
+        let params = self.build_params(destination_chain, message, recipient, U256::ZERO, U256::ZERO)?;
+
+        // Lock the token in the adapter
+        let this = self.vm().contract_address();
+        let config = Call::new_mutating(self);
+        if asset == AssetType::Erc721 {
+            IERC721::new(token)
+                .transfer_from(self.vm(), config, executor, this, token_id)
+                .map_err(|_| LayerZeroAdapterError::TransferFailed(TransferFailed {}))?;
+        } else {
+            IERC1155::new(token)
+                .safe_transfer_from(self.vm(), config, executor, this, token_id, amount, Bytes::new())
+                .map_err(|_| LayerZeroAdapterError::TransferFailed(TransferFailed {}))?;
+        }
+
+        self.send_params(intent_id, executor, params)
+    }
+
+    /// Accept ERC-1155 tokens locked by `bridge_nft`
+    #[selector(name = "onERC1155Received")]
+    pub fn on_erc1155_received(
+        &mut self,
+        operator: Address,
+        _from: Address,
+        _id: U256,
+        _value: U256,
+        _data: Bytes,
+    ) -> Result<FixedBytes<4>, LayerZeroAdapterError> {
+        if operator != self.vm().contract_address() {
+            return Err(LayerZeroAdapterError::Unauthorized(Unauthorized {}));
+        }
+        Ok(FixedBytes::from(ERC1155_RECEIVED))
+    }
+
+    /// Check whether a destination chain has a configured lane
+    pub fn supports_lane(&self, destination_chain: U256) -> bool {
+        let eid = self.chain_eids.get(destination_chain);
+        eid != U256::ZERO && self.peers.get(eid) != FixedBytes::<32>::ZERO
+    }
+
+    /// Receive a message from the endpoint (destination side)
+    ///
+    /// Verifies the endpoint caller and the source peer, then reports the
+    /// delivery to SettlementVerifier keyed by the LayerZero GUID. Both
+    /// fungible and NFT payloads are accepted.
+    #[payable]
+    pub fn lz_receive(
+        &mut self,
+        origin: (u32, FixedBytes<32>, u64),
+        guid: FixedBytes<32>,
+        message: Bytes,
+        _executor: Address,
+        _extra_data: Bytes,
+    ) -> Result<(), LayerZeroAdapterError> {
+        if self.vm().msg_sender() != self.endpoint.get() {
+            return Err(LayerZeroAdapterError::Unauthorized(Unauthorized {}));
+        }
+
+        let (src_eid, sender, _nonce) = origin;
+        if !self.is_trusted_peer(src_eid, sender) {
+            return Err(LayerZeroAdapterError::UntrustedPeer(UntrustedPeer {}));
+        }
+
+        let intent_id = match payload_codec::decode_nft(&message) {
+            Ok(payload) => payload.intentId,
+            Err(_) => payload_codec::decode_bridge(&message).map_err(LayerZeroAdapterError::from)?.intentId,
+        };
+
+        let verifier = ISettlementVerifier::new(self.settlement_verifier.get());
+        let config = Call::new_mutating(self);
+        verifier
+            .verify_adapter_message(self.vm(), config, guid, intent_id)
+            .map_err(|_| LayerZeroAdapterError::EndpointCallFailed(EndpointCallFailed {}))?;
+
+        self.vm().log(LayerZeroMessageReceived {
+            intentId: intent_id,
+            srcEid: src_eid,
+            guid,
+        });
+
+        Ok(())
+    }
+
+    /// OApp hook: only trusted peers may initialize a messaging path
+    pub fn allow_initialize_path(&self, origin: (u32, FixedBytes<32>, u64)) -> bool {
+        self.is_trusted_peer(origin.0, origin.1)
+    }
+
+    /// OApp hook: unordered delivery, so no enforced nonce
+    pub fn next_nonce(&self, _src_eid: u32, _sender: FixedBytes<32>) -> u64 {
+        0
+    }
+
+    /// Get the endpoint ID configured for a chain
+    pub fn get_eid(&self, chain_id: U256) -> U256 {
+        self.chain_eids.get(chain_id)
+    }
+
+    /// Get the trusted peer for an endpoint ID
+    pub fn get_peer(&self, eid: u32) -> FixedBytes<32> {
+        self.peers.get(U256::from(eid))
+    }
+
+    /// ERC-165: whether this contract implements `interface_id`
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        erc165::supports(
+            interface_id.0,
+            &[
+                erc165::BRIDGE_ADAPTER_ID,
+                erc165::GAS_DROP_ADAPTER_ID,
+                erc165::GAS_LIMIT_ADAPTER_ID,
+                erc165::NFT_BRIDGE_ADAPTER_ID,
+            ],
+        )
+    }
+
+    /// Get contract owner
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Internal: Lock tokens and send the payload, with an optional gas drop
+    /// and receive gas override
+    #[allow(clippy::too_many_arguments)]
+    fn internal_bridge(
+        &mut self,
+        intent_id: U256,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+        unwrap_native: bool,
+        gas_drop: U256,
+        gas_limit: U256,
+    ) -> Result<FixedBytes<32>, LayerZeroAdapterError> {
+        let executor = self.vm().msg_sender();
+        if executor != self.route_executor.get() {
+            return Err(LayerZeroAdapterError::Unauthorized(Unauthorized {}));
+        }
+
+        if amount == U256::ZERO {
+            return Err(LayerZeroAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let message = encode_bridge_payload(intent_id, token, amount, recipient, unwrap_native);
+        let params = self.build_params(destination_chain, message, recipient, gas_drop, gas_limit)?;
+
+        // Lock the bridged tokens in the adapter
+        let erc20 = IERC20::new(token);
+        let this = self.vm().contract_address();
+        let config = Call::new_mutating(self);
+        let pulled = erc20
+            .transfer_from(self.vm(), config, executor, this, amount)
+            .map_err(|_| LayerZeroAdapterError::TransferFailed(TransferFailed {}))?;
+        if !pulled {
+            return Err(LayerZeroAdapterError::TransferFailed(TransferFailed {}));
+        }
+
+        self.send_params(intent_id, executor, params)
+    }
+
+    /// Internal: Send a message, refunding excess fee to the executor
+    fn send_params(
+        &mut self,
+        intent_id: U256,
+        executor: Address,
+        params: MessagingParams,
+    ) -> Result<FixedBytes<32>, LayerZeroAdapterError> {
+        let dst_eid = params.dstEid;
+        let fee = self.vm().msg_value();
+        let calldata = sendCall {
+            params,
+            refundAddress: executor,
+        }
+        .abi_encode();
+        let endpoint = self.endpoint.get();
+        let config = Call::new_payable(self, fee);
+        let result = call(self.vm(), config, endpoint, &calldata)
+            .map_err(|_| LayerZeroAdapterError::InsufficientFee(InsufficientFee {}))?;
+        let receipt = sendCall::abi_decode_returns(&result)
+            .map_err(|_| LayerZeroAdapterError::EndpointCallFailed(EndpointCallFailed {}))?;
+
+        self.vm().log(LayerZeroMessageSent {
+            intentId: intent_id,
+            dstEid: dst_eid,
+            guid: receipt.guid,
+            nativeFee: receipt.fee.nativeFee,
+        });
+
+        Ok(receipt.guid)
+    }
+
+    /// Internal: Quote the native fee for sending a message
+    fn quote_params(&self, params: MessagingParams) -> Result<U256, LayerZeroAdapterError> {
+        let calldata = quoteCall {
+            params,
+            sender: self.vm().contract_address(),
+        }
+        .abi_encode();
+
+        let result = static_call(self.vm(), Call::new(), self.endpoint.get(), &calldata)
+            .map_err(|_| LayerZeroAdapterError::EndpointCallFailed(EndpointCallFailed {}))?;
+        let fee = quoteCall::abi_decode_returns(&result)
+            .map_err(|_| LayerZeroAdapterError::EndpointCallFailed(EndpointCallFailed {}))?;
+
+        Ok(fee.nativeFee)
+    }
+
+    /// Internal: Build the endpoint messaging parameters for a lane
+    ///
+    /// A `gas_limit` above the lane's receive gas replaces it.
+    fn build_params(
+        &self,
+        destination_chain: U256,
+        message: Vec<u8>,
+        recipient: Address,
+        gas_drop: U256,
+        gas_limit: U256,
+    ) -> Result<MessagingParams, LayerZeroAdapterError> {
+        let eid = self.chain_eids.get(destination_chain);
+        let peer = self.peers.get(eid);
+        if eid == U256::ZERO || peer == FixedBytes::<32>::ZERO {
+            return Err(LayerZeroAdapterError::UnsupportedLane(UnsupportedLane {}));
+        }
+
+        let mut gas = self.receive_gas.get(destination_chain);
+        if gas == U256::ZERO {
+            gas = U256::from(DEFAULT_RECEIVE_GAS);
+        }
+        let gas = gas.max(gas_limit);
+
+        if gas > U256::from(u128::MAX) || gas_drop > U256::from(u128::MAX) {
+            return Err(LayerZeroAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let mut options = lz_receive_options(gas.to::<u128>(), 0);
+        if gas_drop != U256::ZERO {
+            append_lz_native_drop(&mut options, gas_drop.to::<u128>(), recipient);
+        }
+
+        Ok(MessagingParams {
+            dstEid: eid.to::<u32>(),
+            receiver: peer,
+            message: message.into(),
+            options: options.into(),
+            payInLzToken: false,
+        })
+    }
+
+    /// Internal: Parse an NFT asset type
+    fn nft_asset(asset_type: u8) -> Result<AssetType, LayerZeroAdapterError> {
+        AssetType::from_u8(asset_type)
+            .filter(|asset| asset.is_nft())
+            .ok_or(LayerZeroAdapterError::UnsupportedAsset(UnsupportedAsset { assetType: asset_type }))
+    }
+
+    /// Internal: Check that a sender is the configured peer for an endpoint ID
+    fn is_trusted_peer(&self, eid: u32, sender: FixedBytes<32>) -> bool {
+        let peer = self.peers.get(U256::from(eid));
+        peer != FixedBytes::<32>::ZERO && peer == sender
+    }
+
+    /// Internal: Check if caller is owner
+    fn only_owner(&self) -> Result<(), LayerZeroAdapterError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(LayerZeroAdapterError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,619 @@
+//! StargateAdapter Contract
+//!
+//! Bridge adapter for Stargate v2 pools and generic LayerZero OFTs, used on
+//! high-liquidity stablecoin lanes. Tokens are sent through the token's pool
+//! (or OFT) in taxi mode with the intent payload as the compose message, and
+//! the minimum amount credited on the destination is bounded by a per-lane
+//! slippage limit. On the destination chain the endpoint delivers the compose
+//! message to the peer adapter once the pool has credited it; the adapter
+//! forwards the tokens to the recipient and reports the delivery to
+//! SettlementVerifier under the LayerZero GUID.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::{
+    alloy_primitives::{Address, Bytes, FixedBytes, U256},
+    call::{call, static_call, Call},
+    prelude::*,
+    storage::{StorageAddress, StorageFixedBytes, StorageMap, StorageU256},
+};
+
+use swoosh_common::bridge_adapter::ISettlementVerifier;
+use swoosh_common::encoding::{encode_bridge_payload, lz_compose_options, parse_oft_compose_msg};
+use swoosh_common::erc165;
+use swoosh_common::payload_codec::{self, PayloadError};
+
+// Stargate v2 / OFT ABI
+sol! {
+    struct SendParam {
+        uint32 dstEid;
+        bytes32 to;
+        uint256 amountLD;
+        uint256 minAmountLD;
+        bytes extraOptions;
+        bytes composeMsg;
+        bytes oftCmd;
+    }
+
+    struct MessagingFee {
+        uint256 nativeFee;
+        uint256 lzTokenFee;
+    }
+
+    struct MessagingReceipt {
+        bytes32 guid;
+        uint64 nonce;
+        MessagingFee fee;
+    }
+
+    struct OFTLimit {
+        uint256 minAmountLD;
+        uint256 maxAmountLD;
+    }
+
+    struct OFTFeeDetail {
+        int256 feeAmountLD;
+        string description;
+    }
+
+    struct OFTReceipt {
+        uint256 amountSentLD;
+        uint256 amountReceivedLD;
+    }
+
+    function quoteOFT(SendParam sendParam)
+        external
+        view
+        returns (OFTLimit limit, OFTFeeDetail[] oftFeeDetails, OFTReceipt receipt);
+
+    function quoteSend(SendParam sendParam, bool payInLzToken) external view returns (MessagingFee fee);
+
+    function send(SendParam sendParam, MessagingFee fee, address refundAddress)
+        external
+        payable
+        returns (MessagingReceipt msgReceipt, OFTReceipt oftReceipt);
+}
+
+// Events and errors
+sol! {
+    event LaneConfigured(uint256 indexed chainId, uint32 eid, bytes32 peer);
+    event OftSet(address indexed token, address oft);
+    event OutputTokenSet(uint256 indexed chainId, address indexed inputToken, address outputToken);
+    event MaxSlippageSet(uint256 indexed chainId, uint256 slippageBps);
+    event ComposeGasSet(uint256 indexed chainId, uint256 gas);
+    event StargateTransferSent(
+        uint256 indexed intentId,
+        uint32 indexed dstEid,
+        bytes32 guid,
+        uint256 amountSent,
+        uint256 minAmountReceived
+    );
+    event StargateTransferReceived(
+        uint256 indexed intentId,
+        uint32 indexed srcEid,
+        bytes32 guid,
+        address recipient,
+        uint256 amount
+    );
+
+    error Unauthorized();
+    error InvalidAddress();
+    error InvalidAmount();
+    error UnsupportedLane();
+    error UntrustedPeer();
+    error InvalidPayload();
+    error MalformedPayload(uint8 reason);
+    error InsufficientFee();
+    error SlippageExceeded(uint256 minAmountOut, uint256 actualAmountOut);
+    error TransferFailed();
+    error StargateCallFailed();
+}
+
+/// Error types for StargateAdapter
+#[derive(SolidityError)]
+pub enum StargateAdapterError {
+    Unauthorized(Unauthorized),
+    InvalidAddress(InvalidAddress),
+    InvalidAmount(InvalidAmount),
+    UnsupportedLane(UnsupportedLane),
+    UntrustedPeer(UntrustedPeer),
+    InvalidPayload(InvalidPayload),
+    MalformedPayload(MalformedPayload),
+    InsufficientFee(InsufficientFee),
+    SlippageExceeded(SlippageExceeded),
+    TransferFailed(TransferFailed),
+    StargateCallFailed(StargateCallFailed),
+}
+
+impl From<PayloadError> for StargateAdapterError {
+    fn from(err: PayloadError) -> Self {
+        StargateAdapterError::MalformedPayload(MalformedPayload { reason: err.code() })
+    }
+}
+
+// ERC20 interface
+sol_interface! {
+    interface IERC20 {
+        function transferFrom(address from, address to, uint256 amount) external returns (bool);
+        function transfer(address to, uint256 amount) external returns (bool);
+        function approve(address spender, uint256 amount) external returns (bool);
+    }
+}
+
+/// Basis point denominator
+const BPS_DENOMINATOR: u64 = 10_000;
+/// Pool slippage accepted when no per-lane value is set (0.5%)
+const DEFAULT_MAX_SLIPPAGE_BPS: u64 = 50;
+/// Highest slippage a lane may be configured with (3%)
+const MAX_SLIPPAGE_BPS: u64 = 300;
+/// Gas forwarded to lzCompose on the destination when no per-lane value is set
+const DEFAULT_COMPOSE_GAS: u64 = 200_000;
+
+#[entrypoint]
+#[storage]
+pub struct StargateAdapter {
+    /// Contract owner
+    owner: StorageAddress,
+    /// LayerZero v2 endpoint on this chain (delivers compose messages)
+    endpoint: StorageAddress,
+    /// RouteExecutor contract (only caller allowed to bridge)
+    route_executor: StorageAddress,
+    /// SettlementVerifier notified on inbound deliveries
+    settlement_verifier: StorageAddress,
+    /// Mapping of EVM chain IDs to LayerZero endpoint IDs
+    chain_eids: StorageMap<U256, StorageU256>,
+    /// Mapping of LayerZero endpoint IDs to peer adapters
+    peers: StorageMap<U256, StorageFixedBytes<32>>,
+    /// Mapping of local tokens to the Stargate pool or OFT that moves them
+    ofts: StorageMap<Address, StorageAddress>,
+    /// Mapping of chain ID -> input token -> token credited on that chain
+    output_tokens: StorageMap<U256, StorageMap<Address, StorageAddress>>,
+    /// Mapping of chain IDs to the maximum pool slippage (bps)
+    max_slippage_bps: StorageMap<U256, StorageU256>,
+    /// Mapping of chain IDs to lzCompose gas limits
+    compose_gas: StorageMap<U256, StorageU256>,
+}
+
+#[public]
+impl StargateAdapter {
+    /// Initialize the adapter
+    pub fn init(
+        &mut self,
+        endpoint_address: Address,
+        route_executor_address: Address,
+        settlement_verifier_address: Address,
+    ) -> Result<(), StargateAdapterError> {
+        if endpoint_address == Address::ZERO
+            || route_executor_address == Address::ZERO
+            || settlement_verifier_address == Address::ZERO
+        {
+            return Err(StargateAdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.owner.set(self.vm().msg_sender());
+        self.endpoint.set(endpoint_address);
+        self.route_executor.set(route_executor_address);
+        self.settlement_verifier.set(settlement_verifier_address);
+
+        Ok(())
+    }
+
+    /// Configure a lane: chain ID to endpoint ID and peer adapter (admin only)
+    pub fn set_lane(
+        &mut self,
+        chain_id: U256,
+        eid: u32,
+        peer: FixedBytes<32>,
+    ) -> Result<(), StargateAdapterError> {
+        self.only_owner()?;
+
+        if chain_id == U256::ZERO || eid == 0 {
+            return Err(StargateAdapterError::UnsupportedLane(UnsupportedLane {}));
+        }
+
+        self.chain_eids.setter(chain_id).set(U256::from(eid));
+        self.peers.setter(U256::from(eid)).set(peer);
+
+        self.vm().log(LaneConfigured {
+            chainId: chain_id,
+            eid,
+            peer,
+        });
+
+        Ok(())
+    }
+
+    /// Set the Stargate pool or OFT that moves a local token (admin only)
+    ///
+    /// A zero OFT removes the token. Inbound compose messages are only
+    /// accepted from the OFT registered for the delivered token.
+    pub fn set_oft(&mut self, token: Address, oft: Address) -> Result<(), StargateAdapterError> {
+        self.only_owner()?;
+
+        if token == Address::ZERO {
+            return Err(StargateAdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.ofts.setter(token).set(oft);
+
+        self.vm().log(OftSet { token, oft });
+
+        Ok(())
+    }
+
+    /// Map an input token to the token the pool credits on a chain (admin only)
+    pub fn set_output_token(
+        &mut self,
+        chain_id: U256,
+        input_token: Address,
+        output_token: Address,
+    ) -> Result<(), StargateAdapterError> {
+        self.only_owner()?;
+
+        if input_token == Address::ZERO {
+            return Err(StargateAdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.output_tokens.setter(chain_id).setter(input_token).set(output_token);
+
+        self.vm().log(OutputTokenSet {
+            chainId: chain_id,
+            inputToken: input_token,
+            outputToken: output_token,
+        });
+
+        Ok(())
+    }
+
+    /// Set the maximum pool slippage accepted on a lane (admin only)
+    ///
+    /// Zero restores the default bound.
+    pub fn set_max_slippage(&mut self, chain_id: U256, slippage_bps: U256) -> Result<(), StargateAdapterError> {
+        self.only_owner()?;
+
+        if slippage_bps > U256::from(MAX_SLIPPAGE_BPS) {
+            return Err(StargateAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        self.max_slippage_bps.setter(chain_id).set(slippage_bps);
+
+        self.vm().log(MaxSlippageSet {
+            chainId: chain_id,
+            slippageBps: slippage_bps,
+        });
+
+        Ok(())
+    }
+
+    /// Set the lzCompose gas limit for a destination chain (admin only)
+    pub fn set_compose_gas(&mut self, chain_id: U256, gas: U256) -> Result<(), StargateAdapterError> {
+        self.only_owner()?;
+
+        if gas == U256::ZERO || gas > U256::from(u128::MAX) {
+            return Err(StargateAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        self.compose_gas.setter(chain_id).set(gas);
+
+        self.vm().log(ComposeGasSet { chainId: chain_id, gas });
+
+        Ok(())
+    }
+
+    /// Quote the native LayerZero fee for bridging to a destination chain
+    pub fn quote_fee(
+        &self,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+    ) -> Result<U256, StargateAdapterError> {
+        let (oft, send_param) = self.build_send_param(U256::ZERO, destination_chain, token, amount, recipient)?;
+        let fee = self.quote_send(oft, send_param)?;
+        Ok(fee.nativeFee)
+    }
+
+    /// Quote the amount the destination pool credits for `amount`
+    pub fn quote_output(
+        &self,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+    ) -> Result<U256, StargateAdapterError> {
+        let (oft, send_param) =
+            self.build_send_param(U256::ZERO, destination_chain, token, amount, Address::ZERO)?;
+        self.quote_received(oft, send_param)
+    }
+
+    /// Send tokens through the pool with the intent payload as compose message
+    ///
+    /// Called by RouteExecutor, which approves this adapter for `amount` and
+    /// forwards the quoted fee as msg.value. Reverts if the pool would credit
+    /// less than the lane's slippage bound. Returns the LayerZero GUID, which
+    /// the destination side reports to SettlementVerifier as the message ID.
+    #[payable]
+    pub fn bridge(
+        &mut self,
+        intent_id: U256,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+        unwrap_native: bool,
+    ) -> Result<FixedBytes<32>, StargateAdapterError> {
+        let executor = self.vm().msg_sender();
+        if executor != self.route_executor.get() {
+            return Err(StargateAdapterError::Unauthorized(Unauthorized {}));
+        }
+
+        if amount == U256::ZERO || recipient == Address::ZERO {
+            return Err(StargateAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        // Pools credit the pooled token; there is no native payout
+        if unwrap_native {
+            return Err(StargateAdapterError::UnsupportedLane(UnsupportedLane {}));
+        }
+
+        let (oft, send_param) = self.build_send_param(intent_id, destination_chain, token, amount, recipient)?;
+        let dst_eid = send_param.dstEid;
+        let min_amount = send_param.minAmountLD;
+
+        // Check the pool fee against the slippage bound before moving funds
+        let received = self.quote_received(oft, send_param.clone())?;
+        if received < min_amount {
+            return Err(StargateAdapterError::SlippageExceeded(SlippageExceeded {
+                minAmountOut: min_amount,
+                actualAmountOut: received,
+            }));
+        }
+
+        let fee = self.quote_send(oft, send_param.clone())?;
+        let value = self.vm().msg_value();
+        if value < fee.nativeFee {
+            return Err(StargateAdapterError::InsufficientFee(InsufficientFee {}));
+        }
+
+        // Pull tokens from the executor and approve the pool
+        let erc20 = IERC20::new(token);
+        let this = self.vm().contract_address();
+        let config = Call::new_mutating(self);
+        let pulled = erc20
+            .transfer_from(self.vm(), config, executor, this, amount)
+            .map_err(|_| StargateAdapterError::TransferFailed(TransferFailed {}))?;
+        if !pulled {
+            return Err(StargateAdapterError::TransferFailed(TransferFailed {}));
+        }
+
+        let config = Call::new_mutating(self);
+        erc20
+            .approve(self.vm(), config, oft, amount)
+            .map_err(|_| StargateAdapterError::TransferFailed(TransferFailed {}))?;
+
+        // Send, refunding excess fee to the executor
+        let calldata = sendCall {
+            sendParam: send_param,
+            fee: MessagingFee {
+                nativeFee: value,
+                lzTokenFee: U256::ZERO,
+            },
+            refundAddress: executor,
+        }
+        .abi_encode();
+        let config = Call::new_payable(self, value);
+        let result = call(self.vm(), config, oft, &calldata)
+            .map_err(|_| StargateAdapterError::StargateCallFailed(StargateCallFailed {}))?;
+        let receipt = sendCall::abi_decode_returns(&result)
+            .map_err(|_| StargateAdapterError::StargateCallFailed(StargateCallFailed {}))?;
+
+        self.vm().log(StargateTransferSent {
+            intentId: intent_id,
+            dstEid: dst_eid,
+            guid: receipt.msgReceipt.guid,
+            amountSent: receipt.oftReceipt.amountSentLD,
+            minAmountReceived: min_amount,
+        });
+
+        Ok(receipt.msgReceipt.guid)
+    }
+
+    /// Check whether a destination chain has a configured lane
+    pub fn supports_lane(&self, destination_chain: U256) -> bool {
+        let eid = self.chain_eids.get(destination_chain);
+        eid != U256::ZERO && self.peers.get(eid) != FixedBytes::<32>::ZERO
+    }
+
+    /// Receive the compose message for a credited transfer (destination side)
+    ///
+    /// Only the endpoint may call this, for a transfer the registered pool of
+    /// the delivered token credited to this adapter on behalf of a peer. The
+    /// credited amount is forwarded to the recipient and the delivery is
+    /// reported to SettlementVerifier keyed by the LayerZero GUID.
+    #[payable]
+    pub fn lz_compose(
+        &mut self,
+        from: Address,
+        guid: FixedBytes<32>,
+        message: Bytes,
+        _executor: Address,
+        _extra_data: Bytes,
+    ) -> Result<(), StargateAdapterError> {
+        if self.vm().msg_sender() != self.endpoint.get() {
+            return Err(StargateAdapterError::Unauthorized(Unauthorized {}));
+        }
+
+        let compose =
+            parse_oft_compose_msg(&message).ok_or(StargateAdapterError::InvalidPayload(InvalidPayload {}))?;
+
+        let peer = self.peers.get(U256::from(compose.src_eid));
+        if peer == FixedBytes::<32>::ZERO || peer != compose.compose_from {
+            return Err(StargateAdapterError::UntrustedPeer(UntrustedPeer {}));
+        }
+
+        let payload = payload_codec::decode_bridge(compose.message).map_err(StargateAdapterError::from)?;
+
+        // The payload names the token; only its pool may vouch for the credit
+        let oft = self.ofts.get(payload.token);
+        if oft == Address::ZERO || oft != from {
+            return Err(StargateAdapterError::Unauthorized(Unauthorized {}));
+        }
+
+        if compose.amount < payload.amount {
+            return Err(StargateAdapterError::SlippageExceeded(SlippageExceeded {
+                minAmountOut: payload.amount,
+                actualAmountOut: compose.amount,
+            }));
+        }
+
+        let erc20 = IERC20::new(payload.token);
+        let config = Call::new_mutating(self);
+        let sent = erc20
+            .transfer(self.vm(), config, payload.recipient, compose.amount)
+            .map_err(|_| StargateAdapterError::TransferFailed(TransferFailed {}))?;
+        if !sent {
+            return Err(StargateAdapterError::TransferFailed(TransferFailed {}));
+        }
+
+        let verifier = ISettlementVerifier::new(self.settlement_verifier.get());
+        let config = Call::new_mutating(self);
+        verifier
+            .verify_adapter_message(self.vm(), config, guid, payload.intentId)
+            .map_err(|_| StargateAdapterError::StargateCallFailed(StargateCallFailed {}))?;
+
+        self.vm().log(StargateTransferReceived {
+            intentId: payload.intentId,
+            srcEid: compose.src_eid,
+            guid,
+            recipient: payload.recipient,
+            amount: compose.amount,
+        });
+
+        Ok(())
+    }
+
+    /// Get the endpoint ID configured for a chain
+    pub fn get_eid(&self, chain_id: U256) -> U256 {
+        self.chain_eids.get(chain_id)
+    }
+
+    /// Get the peer adapter for an endpoint ID
+    pub fn get_peer(&self, eid: u32) -> FixedBytes<32> {
+        self.peers.get(U256::from(eid))
+    }
+
+    /// Get the Stargate pool or OFT for a local token
+    pub fn get_oft(&self, token: Address) -> Address {
+        self.ofts.get(token)
+    }
+
+    /// Get the token credited on a chain for an input token
+    pub fn get_output_token(&self, chain_id: U256, input_token: Address) -> Address {
+        self.output_tokens.getter(chain_id).get(input_token)
+    }
+
+    /// Get the maximum pool slippage accepted on a lane (bps)
+    pub fn max_slippage(&self, chain_id: U256) -> U256 {
+        self.slippage_bps(chain_id)
+    }
+
+    /// ERC-165: whether this contract implements `interface_id`
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        erc165::supports(interface_id.0, &[erc165::BRIDGE_ADAPTER_ID])
+    }
+
+    /// Get contract owner
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Internal: Build the pool send parameters for a lane
+    ///
+    /// The compose message carries the payload with the slippage-bounded
+    /// minimum, which the destination adapter checks against the credit.
+    fn build_send_param(
+        &self,
+        intent_id: U256,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+    ) -> Result<(Address, SendParam), StargateAdapterError> {
+        let eid = self.chain_eids.get(destination_chain);
+        let peer = self.peers.get(eid);
+        let oft = self.ofts.get(token);
+        let output_token = self.output_tokens.getter(destination_chain).get(token);
+        if eid == U256::ZERO || peer == FixedBytes::<32>::ZERO || oft == Address::ZERO || output_token == Address::ZERO
+        {
+            return Err(StargateAdapterError::UnsupportedLane(UnsupportedLane {}));
+        }
+
+        let slippage = self.slippage_bps(destination_chain);
+        let min_amount = amount - amount * slippage / U256::from(BPS_DENOMINATOR);
+
+        let mut gas = self.compose_gas.get(destination_chain);
+        if gas == U256::ZERO {
+            gas = U256::from(DEFAULT_COMPOSE_GAS);
+        }
+
+        let payload = encode_bridge_payload(intent_id, output_token, min_amount, recipient, false);
+
+        Ok((
+            oft,
+            SendParam {
+                dstEid: eid.to::<u32>(),
+                to: peer,
+                amountLD: amount,
+                minAmountLD: min_amount,
+                extraOptions: lz_compose_options(0, gas.to::<u128>(), 0).into(),
+                composeMsg: payload.into(),
+                // Empty command selects taxi mode: sent immediately, not batched
+                oftCmd: Bytes::new(),
+            },
+        ))
+    }
+
+    /// Internal: Amount the destination would credit for a send
+    fn quote_received(&self, oft: Address, send_param: SendParam) -> Result<U256, StargateAdapterError> {
+        let calldata = quoteOFTCall { sendParam: send_param }.abi_encode();
+        let result = static_call(self.vm(), Call::new(), oft, &calldata)
+            .map_err(|_| StargateAdapterError::StargateCallFailed(StargateCallFailed {}))?;
+        let quote = quoteOFTCall::abi_decode_returns(&result)
+            .map_err(|_| StargateAdapterError::StargateCallFailed(StargateCallFailed {}))?;
+        Ok(quote.receipt.amountReceivedLD)
+    }
+
+    /// Internal: LayerZero fee for a send
+    fn quote_send(&self, oft: Address, send_param: SendParam) -> Result<MessagingFee, StargateAdapterError> {
+        let calldata = quoteSendCall {
+            sendParam: send_param,
+            payInLzToken: false,
+        }
+        .abi_encode();
+        let result = static_call(self.vm(), Call::new(), oft, &calldata)
+            .map_err(|_| StargateAdapterError::StargateCallFailed(StargateCallFailed {}))?;
+        quoteSendCall::abi_decode_returns(&result)
+            .map_err(|_| StargateAdapterError::StargateCallFailed(StargateCallFailed {}))
+    }
+
+    /// Internal: Slippage bound for a lane
+    fn slippage_bps(&self, chain_id: U256) -> U256 {
+        let slippage = self.max_slippage_bps.get(chain_id);
+        if slippage == U256::ZERO {
+            U256::from(DEFAULT_MAX_SLIPPAGE_BPS)
+        } else {
+            slippage
+        }
+    }
+
+    /// Internal: Check if caller is owner
+    fn only_owner(&self) -> Result<(), StargateAdapterError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(StargateAdapterError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+}
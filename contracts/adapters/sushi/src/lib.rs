@@ -0,0 +1,195 @@
+//! SushiAdapter Contract
+//!
+//! Swap adapter routing through a SushiSwap (Uniswap V2-style) router with
+//! `swapExactTokensForTokens`. The adapter `data` is an optional ABI-encoded
+//! `address[]` path; empty data swaps through the direct pair.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use alloy_sol_types::sol;
+use stylus_sdk::{
+    alloy_primitives::{Address, Bytes, FixedBytes, U256},
+    call::Call,
+    prelude::*,
+    storage::StorageAddress,
+};
+
+use swoosh_common::encoding::v2_swap_path;
+use swoosh_common::erc165;
+
+// Events and errors
+sol! {
+    event RouterSet(address indexed router);
+    event SushiSwap(
+        address indexed tokenIn,
+        address indexed tokenOut,
+        uint256 amountIn,
+        uint256 amountOut,
+        address recipient
+    );
+
+    error Unauthorized();
+    error InvalidAddress();
+    error InvalidAmount();
+    error InvalidPath();
+    error TransferFailed();
+    error SwapFailed();
+}
+
+/// Error types for SushiAdapter
+#[derive(SolidityError)]
+pub enum SushiAdapterError {
+    Unauthorized(Unauthorized),
+    InvalidAddress(InvalidAddress),
+    InvalidAmount(InvalidAmount),
+    InvalidPath(InvalidPath),
+    TransferFailed(TransferFailed),
+    SwapFailed(SwapFailed),
+}
+
+// ERC20 interface
+sol_interface! {
+    interface IERC20 {
+        function transferFrom(address from, address to, uint256 amount) external returns (bool);
+        function approve(address spender, uint256 amount) external returns (bool);
+    }
+}
+
+// Uniswap V2-style router interface
+sol_interface! {
+    interface IUniswapV2Router {
+        function swapExactTokensForTokens(
+            uint256 amountIn,
+            uint256 amountOutMin,
+            address[] calldata path,
+            address to,
+            uint256 deadline
+        ) external returns (uint256[] memory);
+    }
+}
+
+#[entrypoint]
+#[storage]
+pub struct SushiAdapter {
+    /// Contract owner
+    owner: StorageAddress,
+    /// SushiSwap router
+    router: StorageAddress,
+}
+
+#[public]
+impl SushiAdapter {
+    /// Initialize the adapter
+    pub fn init(&mut self, router_address: Address) -> Result<(), SushiAdapterError> {
+        if router_address == Address::ZERO {
+            return Err(SushiAdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.owner.set(self.vm().msg_sender());
+        self.router.set(router_address);
+
+        Ok(())
+    }
+
+    /// Update the swap router (admin only)
+    pub fn set_router(&mut self, router_address: Address) -> Result<(), SushiAdapterError> {
+        self.only_owner()?;
+
+        if router_address == Address::ZERO {
+            return Err(SushiAdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.router.set(router_address);
+
+        self.vm().log(RouterSet { router: router_address });
+
+        Ok(())
+    }
+
+    /// Swap `amount_in` of `token_in` for `token_out`
+    pub fn swap(
+        &mut self,
+        token_in: Address,
+        token_out: Address,
+        amount_in: U256,
+        min_amount_out: U256,
+        recipient: Address,
+        data: Bytes,
+    ) -> Result<U256, SushiAdapterError> {
+        if amount_in == U256::ZERO {
+            return Err(SushiAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        if recipient == Address::ZERO {
+            return Err(SushiAdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        let path = v2_swap_path(&data, token_in, token_out)
+            .ok_or(SushiAdapterError::InvalidPath(InvalidPath {}))?;
+
+        // Pull input from the caller and approve the router
+        let erc20 = IERC20::new(token_in);
+        let caller = self.vm().msg_sender();
+        let this = self.vm().contract_address();
+        let config = Call::new_mutating(self);
+        let pulled = erc20
+            .transfer_from(self.vm(), config, caller, this, amount_in)
+            .map_err(|_| SushiAdapterError::TransferFailed(TransferFailed {}))?;
+        if !pulled {
+            return Err(SushiAdapterError::TransferFailed(TransferFailed {}));
+        }
+
+        let router = self.router.get();
+        let config = Call::new_mutating(self);
+        erc20
+            .approve(self.vm(), config, router, amount_in)
+            .map_err(|_| SushiAdapterError::TransferFailed(TransferFailed {}))?;
+
+        // Deadline is enforced by the executor; the router sees the current block
+        let deadline = U256::from(self.vm().block_timestamp());
+        let config = Call::new_mutating(self);
+        let amounts = IUniswapV2Router::new(router)
+            .swap_exact_tokens_for_tokens(self.vm(), config, amount_in, min_amount_out, path, recipient, deadline)
+            .map_err(|_| SushiAdapterError::SwapFailed(SwapFailed {}))?;
+        let amount_out = amounts
+            .last()
+            .copied()
+            .ok_or(SushiAdapterError::SwapFailed(SwapFailed {}))?;
+
+        self.vm().log(SushiSwap {
+            tokenIn: token_in,
+            tokenOut: token_out,
+            amountIn: amount_in,
+            amountOut: amount_out,
+            recipient,
+        });
+
+        Ok(amount_out)
+    }
+
+    /// Get the swap router
+    pub fn router(&self) -> Address {
+        self.router.get()
+    }
+
+    /// ERC-165: whether this contract implements `interface_id`
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        erc165::supports(interface_id.0, &[erc165::SWAP_ADAPTER_ID])
+    }
+
+    /// Get contract owner
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Internal: Check if caller is owner
+    fn only_owner(&self) -> Result<(), SushiAdapterError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(SushiAdapterError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+}
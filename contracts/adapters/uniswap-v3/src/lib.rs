@@ -0,0 +1,437 @@
+//! UniswapV3Adapter Contract
+//!
+//! Swap adapter routing through a Uniswap V3 SwapRouter02 with `exactInput`.
+//! The adapter `data` is the packed multi-hop path, which must start at
+//! `token_in` and end at `token_out`.
+//!
+//! Output estimates come from the QuoterV2. Pool addresses are looked up
+//! through the factory once and cached; a path crossing a pool that doesn't
+//! exist quotes zero instead of reverting. With empty `data`, `quote` tries
+//! the fee tiers configured for the pair and returns the best.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::{
+    alloy_primitives::{aliases::{U160, U24}, Address, Bytes, FixedBytes, U256},
+    call::{call, static_call, Call},
+    prelude::*,
+    storage::{StorageAddress, StorageMap, StorageU256},
+};
+
+use swoosh_common::encoding::{
+    pack_v3_fee_tiers, sort_tokens, unpack_v3_fee_tiers, v3_path_endpoints, v3_path_hops,
+};
+use swoosh_common::erc165;
+
+// SwapRouter02 ABI
+sol! {
+    struct ExactInputParams {
+        bytes path;
+        address recipient;
+        uint256 amountIn;
+        uint256 amountOutMinimum;
+    }
+
+    function exactInput(ExactInputParams params) external payable returns (uint256 amountOut);
+}
+
+// QuoterV2 and factory ABI
+sol! {
+    struct QuoteExactInputSingleParams {
+        address tokenIn;
+        address tokenOut;
+        uint256 amountIn;
+        uint24 fee;
+        uint160 sqrtPriceLimitX96;
+    }
+
+    function quoteExactInputSingle(QuoteExactInputSingleParams params)
+        external
+        returns (uint256 amountOut, uint160 sqrtPriceX96After, uint32 initializedTicksCrossed, uint256 gasEstimate);
+
+    function quoteExactInput(bytes path, uint256 amountIn)
+        external
+        returns (
+            uint256 amountOut,
+            uint160[] sqrtPriceX96AfterList,
+            uint32[] initializedTicksCrossedList,
+            uint256 gasEstimate
+        );
+
+    function getPool(address tokenA, address tokenB, uint24 fee) external view returns (address pool);
+}
+
+// Events and errors
+sol! {
+    event RouterSet(address indexed router);
+    event QuoterSet(address indexed quoter, address indexed factory);
+    event FeeTiersSet(address indexed token0, address indexed token1, uint32[] fees);
+    event UniswapV3Swap(
+        address indexed tokenIn,
+        address indexed tokenOut,
+        uint256 amountIn,
+        uint256 amountOut,
+        address recipient
+    );
+
+    error Unauthorized();
+    error InvalidAddress();
+    error InvalidAmount();
+    error InvalidPath();
+    error InvalidFeeTiers();
+    error TransferFailed();
+    error SwapFailed();
+}
+
+/// Error types for UniswapV3Adapter
+#[derive(SolidityError)]
+pub enum UniswapV3AdapterError {
+    Unauthorized(Unauthorized),
+    InvalidAddress(InvalidAddress),
+    InvalidAmount(InvalidAmount),
+    InvalidPath(InvalidPath),
+    InvalidFeeTiers(InvalidFeeTiers),
+    TransferFailed(TransferFailed),
+    SwapFailed(SwapFailed),
+}
+
+// ERC20 interface
+sol_interface! {
+    interface IERC20 {
+        function transferFrom(address from, address to, uint256 amount) external returns (bool);
+        function approve(address spender, uint256 amount) external returns (bool);
+    }
+}
+
+#[entrypoint]
+#[storage]
+pub struct UniswapV3Adapter {
+    /// Contract owner
+    owner: StorageAddress,
+    /// Uniswap V3 SwapRouter02
+    router: StorageAddress,
+    /// Uniswap V3 QuoterV2 used for output estimates
+    quoter: StorageAddress,
+    /// Uniswap V3 factory used to look up pools
+    factory: StorageAddress,
+    /// Packed fee tiers to try per pair (token0 -> token1 -> tiers)
+    fee_tiers: StorageMap<Address, StorageMap<Address, StorageU256>>,
+    /// Pools found through the factory (token0 -> token1 -> fee -> pool)
+    pools: StorageMap<Address, StorageMap<Address, StorageMap<U256, StorageAddress>>>,
+}
+
+#[public]
+impl UniswapV3Adapter {
+    /// Initialize the adapter
+    pub fn init(&mut self, router_address: Address) -> Result<(), UniswapV3AdapterError> {
+        if router_address == Address::ZERO {
+            return Err(UniswapV3AdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.owner.set(self.vm().msg_sender());
+        self.router.set(router_address);
+
+        Ok(())
+    }
+
+    /// Update the swap router (admin only)
+    pub fn set_router(&mut self, router_address: Address) -> Result<(), UniswapV3AdapterError> {
+        self.only_owner()?;
+
+        if router_address == Address::ZERO {
+            return Err(UniswapV3AdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.router.set(router_address);
+
+        self.vm().log(RouterSet { router: router_address });
+
+        Ok(())
+    }
+
+    /// Set the QuoterV2 and factory used for estimates (admin only)
+    pub fn set_quoter(
+        &mut self,
+        quoter_address: Address,
+        factory_address: Address,
+    ) -> Result<(), UniswapV3AdapterError> {
+        self.only_owner()?;
+
+        if quoter_address == Address::ZERO || factory_address == Address::ZERO {
+            return Err(UniswapV3AdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.quoter.set(quoter_address);
+        self.factory.set(factory_address);
+
+        self.vm().log(QuoterSet {
+            quoter: quoter_address,
+            factory: factory_address,
+        });
+
+        Ok(())
+    }
+
+    /// Set the fee tiers `quote` tries for a pair, in order (admin only)
+    pub fn set_fee_tiers(
+        &mut self,
+        token_a: Address,
+        token_b: Address,
+        fees: Vec<u32>,
+    ) -> Result<(), UniswapV3AdapterError> {
+        self.only_owner()?;
+
+        if token_a == Address::ZERO || token_b == Address::ZERO || token_a == token_b {
+            return Err(UniswapV3AdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        let packed = pack_v3_fee_tiers(&fees).ok_or(UniswapV3AdapterError::InvalidFeeTiers(InvalidFeeTiers {}))?;
+        let (token0, token1) = sort_tokens(token_a, token_b);
+        self.fee_tiers.setter(token0).setter(token1).set(packed);
+
+        self.vm().log(FeeTiersSet { token0, token1, fees });
+
+        Ok(())
+    }
+
+    /// Estimate the output of swapping `amount_in` with the same `data` as `swap`
+    ///
+    /// Empty `data` quotes the pair directly over its configured fee tiers
+    /// and returns the best. Returns zero when nothing can be quoted: no
+    /// quoter, no tier with a pool, or a path through a missing pool.
+    pub fn quote(
+        &mut self,
+        token_in: Address,
+        token_out: Address,
+        amount_in: U256,
+        data: Bytes,
+    ) -> Result<U256, UniswapV3AdapterError> {
+        if amount_in == U256::ZERO {
+            return Err(UniswapV3AdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        if self.quoter.get() == Address::ZERO {
+            return Ok(U256::ZERO);
+        }
+
+        if data.is_empty() {
+            let (_, amount_out) = self.best_fee_tier(token_in, token_out, amount_in)?;
+            return Ok(amount_out);
+        }
+
+        let hops = match v3_path_hops(&data) {
+            Some(hops) if hops[0].0 == token_in && hops[hops.len() - 1].2 == token_out => hops,
+            _ => return Err(UniswapV3AdapterError::InvalidPath(InvalidPath {})),
+        };
+
+        // The quoter reverts on a missing pool; check each hop first
+        for (hop_in, fee, hop_out) in hops {
+            if self.resolve_pool(hop_in, hop_out, fee)? == Address::ZERO {
+                return Ok(U256::ZERO);
+            }
+        }
+
+        let calldata = quoteExactInputCall {
+            path: data,
+            amountIn: amount_in,
+        }
+        .abi_encode();
+        let quoter = self.quoter.get();
+        let config = Call::new_mutating(self);
+        let quoted = call(self.vm(), config, quoter, &calldata)
+            .ok()
+            .and_then(|result| quoteExactInputCall::abi_decode_returns(&result).ok());
+
+        Ok(quoted.map_or(U256::ZERO, |quote| quote.amountOut))
+    }
+
+    /// Find the configured fee tier with the best direct output for a pair
+    ///
+    /// Returns the tier and its output, or zeros if no tier has a pool.
+    pub fn best_fee_tier(
+        &mut self,
+        token_in: Address,
+        token_out: Address,
+        amount_in: U256,
+    ) -> Result<(u32, U256), UniswapV3AdapterError> {
+        let quoter = self.quoter.get();
+        let mut best = (0u32, U256::ZERO);
+        if quoter == Address::ZERO {
+            return Ok(best);
+        }
+
+        for fee in self.get_fee_tiers(token_in, token_out) {
+            if self.resolve_pool(token_in, token_out, fee)? == Address::ZERO {
+                continue;
+            }
+
+            let calldata = quoteExactInputSingleCall {
+                params: QuoteExactInputSingleParams {
+                    tokenIn: token_in,
+                    tokenOut: token_out,
+                    amountIn: amount_in,
+                    fee: U24::from(fee),
+                    sqrtPriceLimitX96: U160::ZERO,
+                },
+            }
+            .abi_encode();
+            let config = Call::new_mutating(self);
+            let quoted = call(self.vm(), config, quoter, &calldata)
+                .ok()
+                .and_then(|result| quoteExactInputSingleCall::abi_decode_returns(&result).ok());
+
+            if let Some(quote) = quoted {
+                if quote.amountOut > best.1 {
+                    best = (fee, quote.amountOut);
+                }
+            }
+        }
+
+        Ok(best)
+    }
+
+    /// Swap `amount_in` of `token_in` along the packed path in `data`
+    pub fn swap(
+        &mut self,
+        token_in: Address,
+        token_out: Address,
+        amount_in: U256,
+        min_amount_out: U256,
+        recipient: Address,
+        data: Bytes,
+    ) -> Result<U256, UniswapV3AdapterError> {
+        if amount_in == U256::ZERO {
+            return Err(UniswapV3AdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        if recipient == Address::ZERO {
+            return Err(UniswapV3AdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        match v3_path_endpoints(&data) {
+            Some((first, last)) if first == token_in && last == token_out => {}
+            _ => return Err(UniswapV3AdapterError::InvalidPath(InvalidPath {})),
+        }
+
+        // Pull input from the caller and approve the router
+        let erc20 = IERC20::new(token_in);
+        let caller = self.vm().msg_sender();
+        let this = self.vm().contract_address();
+        let config = Call::new_mutating(self);
+        let pulled = erc20
+            .transfer_from(self.vm(), config, caller, this, amount_in)
+            .map_err(|_| UniswapV3AdapterError::TransferFailed(TransferFailed {}))?;
+        if !pulled {
+            return Err(UniswapV3AdapterError::TransferFailed(TransferFailed {}));
+        }
+
+        let router = self.router.get();
+        let config = Call::new_mutating(self);
+        erc20
+            .approve(self.vm(), config, router, amount_in)
+            .map_err(|_| UniswapV3AdapterError::TransferFailed(TransferFailed {}))?;
+
+        let calldata = exactInputCall {
+            params: ExactInputParams {
+                path: data,
+                recipient,
+                amountIn: amount_in,
+                amountOutMinimum: min_amount_out,
+            },
+        }
+        .abi_encode();
+        let config = Call::new_mutating(self);
+        let result = call(self.vm(), config, router, &calldata)
+            .map_err(|_| UniswapV3AdapterError::SwapFailed(SwapFailed {}))?;
+        let amount_out = exactInputCall::abi_decode_returns(&result)
+            .map_err(|_| UniswapV3AdapterError::SwapFailed(SwapFailed {}))?;
+
+        self.vm().log(UniswapV3Swap {
+            tokenIn: token_in,
+            tokenOut: token_out,
+            amountIn: amount_in,
+            amountOut: amount_out,
+            recipient,
+        });
+
+        Ok(amount_out)
+    }
+
+    /// Get the swap router
+    pub fn router(&self) -> Address {
+        self.router.get()
+    }
+
+    /// Get the QuoterV2 and factory used for estimates
+    pub fn quoter(&self) -> (Address, Address) {
+        (self.quoter.get(), self.factory.get())
+    }
+
+    /// Get the fee tiers configured for a pair
+    pub fn get_fee_tiers(&self, token_a: Address, token_b: Address) -> Vec<u32> {
+        let (token0, token1) = sort_tokens(token_a, token_b);
+        unpack_v3_fee_tiers(self.fee_tiers.getter(token0).getter(token1).get())
+    }
+
+    /// Get the cached pool for a pair and fee (zero if not looked up yet)
+    pub fn cached_pool(&self, token_a: Address, token_b: Address, fee: u32) -> Address {
+        let (token0, token1) = sort_tokens(token_a, token_b);
+        self.pools.getter(token0).getter(token1).get(U256::from(fee))
+    }
+
+    /// ERC-165: whether this contract implements `interface_id`
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        erc165::supports(interface_id.0, &[erc165::SWAP_ADAPTER_ID, erc165::SWAP_QUOTER_ID])
+    }
+
+    /// Get contract owner
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Internal: Pool for a pair and fee, looked up once through the factory
+    ///
+    /// Only existing pools are cached, so a pool created later is still found.
+    fn resolve_pool(
+        &mut self,
+        token_a: Address,
+        token_b: Address,
+        fee: u32,
+    ) -> Result<Address, UniswapV3AdapterError> {
+        let (token0, token1) = sort_tokens(token_a, token_b);
+        let cached = self.pools.getter(token0).getter(token1).get(U256::from(fee));
+        if cached != Address::ZERO {
+            return Ok(cached);
+        }
+
+        let calldata = getPoolCall {
+            tokenA: token0,
+            tokenB: token1,
+            fee: U24::from(fee),
+        }
+        .abi_encode();
+        let result = static_call(self.vm(), Call::new(), self.factory.get(), &calldata)
+            .map_err(|_| UniswapV3AdapterError::SwapFailed(SwapFailed {}))?;
+        let pool = getPoolCall::abi_decode_returns(&result)
+            .map_err(|_| UniswapV3AdapterError::SwapFailed(SwapFailed {}))?;
+
+        if pool != Address::ZERO {
+            self.pools.setter(token0).setter(token1).setter(U256::from(fee)).set(pool);
+        }
+
+        Ok(pool)
+    }
+
+    /// Internal: Check if caller is owner
+    fn only_owner(&self) -> Result<(), UniswapV3AdapterError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(UniswapV3AdapterError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+}
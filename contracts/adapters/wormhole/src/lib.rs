@@ -0,0 +1,700 @@
+//! WormholeAdapter Contract
+//!
+//! Bridge adapter built on the Wormhole Token Bridge and the standard
+//! automatic relayer. Tokens are sent with `transferTokensWithPayload` to the
+//! peer adapter, and the relayer is paid to deliver the resulting transfer VAA
+//! to it. The peer redeems the VAA with the Token Bridge, which verifies the
+//! guardian signatures, forwards the tokens to the recipient and reports the
+//! delivery to SettlementVerifier. If relaying fails, anyone can redeem the
+//! VAA directly.
+//!
+//! When the source side pays a confirmation value, the destination adapter
+//! also publishes the payload as a Wormhole message so the source chain's
+//! SettlementVerifier can confirm the intent from that VAA.
+//!
+//! Peers are bytes32 Wormhole addresses, leaving room for non-EVM
+//! destinations; this adapter delivers to EVM peers only.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::{
+    alloy_primitives::{Address, Bytes, FixedBytes, U256},
+    call::{call, static_call, Call},
+    crypto::keccak,
+    prelude::*,
+    storage::{StorageAddress, StorageFixedBytes, StorageMap, StorageU256},
+};
+
+use swoosh_common::bridge_adapter::ISettlementVerifier;
+use swoosh_common::encoding::{
+    address_to_bytes32, bytes32_to_address, encode_bridge_payload, parse_vaa_body, parse_wormhole_transfer,
+};
+use swoosh_common::erc165;
+use swoosh_common::payload_codec::{self, PayloadError};
+
+// Wormhole Token Bridge, core and relayer ABI
+sol! {
+    function transferTokensWithPayload(
+        address token,
+        uint256 amount,
+        uint16 recipientChain,
+        bytes32 recipient,
+        uint32 nonce,
+        bytes payload
+    ) external payable returns (uint64 sequence);
+
+    function completeTransferWithPayload(bytes encodedVm) external returns (bytes memory);
+
+    function messageFee() external view returns (uint256);
+
+    function publishMessage(
+        uint32 nonce,
+        bytes payload,
+        uint8 consistencyLevel
+    ) external payable returns (uint64 sequence);
+
+    struct VaaKey {
+        uint16 chainId;
+        bytes32 emitterAddress;
+        uint64 sequence;
+    }
+
+    function sendVaasToEvm(
+        uint16 targetChain,
+        address targetAddress,
+        bytes payload,
+        uint256 receiverValue,
+        uint256 gasLimit,
+        VaaKey[] vaaKeys
+    ) external payable returns (uint64 sequence);
+
+    function quoteEVMDeliveryPrice(
+        uint16 targetChain,
+        uint256 receiverValue,
+        uint256 gasLimit
+    ) external view returns (uint256 nativePriceQuote, uint256 targetChainRefundPerGasUnused);
+}
+
+// Events and errors
+sol! {
+    event PeerSet(uint256 indexed chainId, uint16 wormholeChain, bytes32 peer);
+    event OutputTokenSet(uint256 indexed chainId, address indexed inputToken, address outputToken);
+    event DestinationGasSet(uint256 indexed chainId, uint256 gas);
+    event ConfirmationValueSet(uint256 indexed chainId, uint256 value);
+    event WormholeTransferSent(
+        uint256 indexed intentId,
+        uint16 indexed targetChain,
+        uint64 sequence,
+        uint256 fee
+    );
+    event WormholeTransferRedeemed(
+        uint256 indexed intentId,
+        uint16 indexed sourceChain,
+        address recipient,
+        uint256 amount
+    );
+    event DeliveryConfirmationPublished(uint256 indexed intentId, uint64 sequence);
+
+    error Unauthorized();
+    error InvalidAddress();
+    error InvalidAmount();
+    error UnsupportedLane();
+    error UntrustedPeer();
+    error InvalidPayload();
+    error MalformedPayload(uint8 reason);
+    error InsufficientFee();
+    error TransferFailed();
+    error WormholeCallFailed();
+}
+
+/// Error types for WormholeAdapter
+#[derive(SolidityError)]
+pub enum WormholeAdapterError {
+    Unauthorized(Unauthorized),
+    InvalidAddress(InvalidAddress),
+    InvalidAmount(InvalidAmount),
+    UnsupportedLane(UnsupportedLane),
+    UntrustedPeer(UntrustedPeer),
+    InvalidPayload(InvalidPayload),
+    MalformedPayload(MalformedPayload),
+    InsufficientFee(InsufficientFee),
+    TransferFailed(TransferFailed),
+    WormholeCallFailed(WormholeCallFailed),
+}
+
+impl From<PayloadError> for WormholeAdapterError {
+    fn from(err: PayloadError) -> Self {
+        WormholeAdapterError::MalformedPayload(MalformedPayload { reason: err.code() })
+    }
+}
+
+// ERC20 interface
+sol_interface! {
+    interface IERC20 {
+        function transferFrom(address from, address to, uint256 amount) external returns (bool);
+        function transfer(address to, uint256 amount) external returns (bool);
+        function approve(address spender, uint256 amount) external returns (bool);
+        function balanceOf(address account) external view returns (uint256);
+    }
+}
+
+/// Destination receive gas used when no per-lane value is set
+const DEFAULT_DESTINATION_GAS: u64 = 300_000;
+/// Consistency level for delivery confirmations (finalized)
+const CONFIRMATION_CONSISTENCY: u8 = 1;
+
+#[entrypoint]
+#[storage]
+pub struct WormholeAdapter {
+    /// Contract owner
+    owner: StorageAddress,
+    /// Wormhole Token Bridge on this chain
+    token_bridge: StorageAddress,
+    /// Wormhole core contract on this chain
+    wormhole: StorageAddress,
+    /// Wormhole standard relayer on this chain
+    wormhole_relayer: StorageAddress,
+    /// RouteExecutor contract (only caller allowed to bridge)
+    route_executor: StorageAddress,
+    /// SettlementVerifier notified on inbound deliveries
+    settlement_verifier: StorageAddress,
+    /// Wormhole chain ID of this chain
+    wormhole_chain: StorageU256,
+    /// Mapping of EVM chain IDs to Wormhole chain IDs
+    wormhole_chains: StorageMap<U256, StorageU256>,
+    /// Mapping of Wormhole chain IDs to peer adapters
+    peers: StorageMap<U256, StorageFixedBytes<32>>,
+    /// Mapping of chain ID -> input token -> token delivered on that chain
+    output_tokens: StorageMap<U256, StorageMap<Address, StorageAddress>>,
+    /// Mapping of chain IDs to destination gas limits
+    destination_gas: StorageMap<U256, StorageU256>,
+    /// Mapping of chain IDs to destination wei paid for a delivery confirmation
+    confirmation_value: StorageMap<U256, StorageU256>,
+}
+
+#[public]
+impl WormholeAdapter {
+    /// Initialize the adapter
+    pub fn init(
+        &mut self,
+        token_bridge_address: Address,
+        wormhole_address: Address,
+        wormhole_relayer_address: Address,
+        route_executor_address: Address,
+        settlement_verifier_address: Address,
+        wormhole_chain: u16,
+    ) -> Result<(), WormholeAdapterError> {
+        if token_bridge_address == Address::ZERO
+            || wormhole_address == Address::ZERO
+            || wormhole_relayer_address == Address::ZERO
+            || route_executor_address == Address::ZERO
+            || settlement_verifier_address == Address::ZERO
+        {
+            return Err(WormholeAdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        if wormhole_chain == 0 {
+            return Err(WormholeAdapterError::UnsupportedLane(UnsupportedLane {}));
+        }
+
+        self.owner.set(self.vm().msg_sender());
+        self.token_bridge.set(token_bridge_address);
+        self.wormhole.set(wormhole_address);
+        self.wormhole_relayer.set(wormhole_relayer_address);
+        self.route_executor.set(route_executor_address);
+        self.settlement_verifier.set(settlement_verifier_address);
+        self.wormhole_chain.set(U256::from(wormhole_chain));
+
+        Ok(())
+    }
+
+    /// Set the Wormhole chain and peer adapter for a chain (admin only)
+    pub fn set_peer(
+        &mut self,
+        chain_id: U256,
+        wormhole_chain: u16,
+        peer: FixedBytes<32>,
+    ) -> Result<(), WormholeAdapterError> {
+        self.only_owner()?;
+
+        if chain_id == U256::ZERO || wormhole_chain == 0 || peer == FixedBytes::<32>::ZERO {
+            return Err(WormholeAdapterError::UnsupportedLane(UnsupportedLane {}));
+        }
+
+        self.wormhole_chains.setter(chain_id).set(U256::from(wormhole_chain));
+        self.peers.setter(U256::from(wormhole_chain)).set(peer);
+
+        self.vm().log(PeerSet {
+            chainId: chain_id,
+            wormholeChain: wormhole_chain,
+            peer,
+        });
+
+        Ok(())
+    }
+
+    /// Map an input token to the token the Token Bridge delivers on a chain (admin only)
+    ///
+    /// For tokens native to this chain that is the Token Bridge's wrapped asset.
+    pub fn set_output_token(
+        &mut self,
+        chain_id: U256,
+        input_token: Address,
+        output_token: Address,
+    ) -> Result<(), WormholeAdapterError> {
+        self.only_owner()?;
+
+        if input_token == Address::ZERO {
+            return Err(WormholeAdapterError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.output_tokens.setter(chain_id).setter(input_token).set(output_token);
+
+        self.vm().log(OutputTokenSet {
+            chainId: chain_id,
+            inputToken: input_token,
+            outputToken: output_token,
+        });
+
+        Ok(())
+    }
+
+    /// Set the destination gas limit paid to the relayer for a chain (admin only)
+    pub fn set_destination_gas(&mut self, chain_id: U256, gas: U256) -> Result<(), WormholeAdapterError> {
+        self.only_owner()?;
+
+        if gas == U256::ZERO {
+            return Err(WormholeAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        self.destination_gas.setter(chain_id).set(gas);
+
+        self.vm().log(DestinationGasSet { chainId: chain_id, gas });
+
+        Ok(())
+    }
+
+    /// Set the destination wei forwarded to pay for a delivery confirmation (admin only)
+    ///
+    /// Should cover the destination core contract's message fee; zero turns
+    /// confirmations off for the lane.
+    pub fn set_confirmation_value(&mut self, chain_id: U256, value: U256) -> Result<(), WormholeAdapterError> {
+        self.only_owner()?;
+        self.confirmation_value.setter(chain_id).set(value);
+
+        self.vm().log(ConfirmationValueSet { chainId: chain_id, value });
+
+        Ok(())
+    }
+
+    /// Quote the Wormhole message fee plus the relayer delivery price
+    pub fn quote_fee(
+        &self,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+    ) -> Result<U256, WormholeAdapterError> {
+        self.quote_fee_with_gas_limit(destination_chain, token, amount, recipient, U256::ZERO)
+    }
+
+    /// Quote the fee with the relayer paid for `gas_limit` of destination gas
+    /// (never below the lane's configured gas)
+    pub fn quote_fee_with_gas_limit(
+        &self,
+        destination_chain: U256,
+        token: Address,
+        _amount: U256,
+        _recipient: Address,
+        gas_limit: U256,
+    ) -> Result<U256, WormholeAdapterError> {
+        let (target_chain, _, _) = self.lane(destination_chain, token)?;
+        let message_fee = self.message_fee()?;
+        let delivery_price = self.delivery_price(destination_chain, target_chain, gas_limit)?;
+
+        Ok(message_fee + delivery_price)
+    }
+
+    /// Send tokens through the Token Bridge and pay the relayer to deliver them
+    ///
+    /// Called by RouteExecutor, which approves this adapter for `amount` and
+    /// forwards the quoted fee as msg.value. Returns the keccak of the payload,
+    /// which the destination side reports to SettlementVerifier as the message ID.
+    #[payable]
+    pub fn bridge(
+        &mut self,
+        intent_id: U256,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+        unwrap_native: bool,
+    ) -> Result<FixedBytes<32>, WormholeAdapterError> {
+        self.internal_bridge(intent_id, destination_chain, token, amount, recipient, unwrap_native, U256::ZERO)
+    }
+
+    /// Bridge as `bridge` does, with the relayer paid for `gas_limit` of
+    /// destination gas
+    ///
+    /// msg.value must cover `quote_fee_with_gas_limit`.
+    #[payable]
+    #[allow(clippy::too_many_arguments)]
+    pub fn bridge_with_gas_limit(
+        &mut self,
+        intent_id: U256,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+        unwrap_native: bool,
+        gas_limit: U256,
+    ) -> Result<FixedBytes<32>, WormholeAdapterError> {
+        self.internal_bridge(intent_id, destination_chain, token, amount, recipient, unwrap_native, gas_limit)
+    }
+
+    /// Check whether a destination chain has a configured peer
+    pub fn supports_lane(&self, destination_chain: U256) -> bool {
+        let target_chain = self.wormhole_chains.get(destination_chain);
+        target_chain != U256::ZERO && self.peers.get(target_chain) != FixedBytes::<32>::ZERO
+    }
+
+    /// Relayer delivery of a transfer VAA (destination side)
+    ///
+    /// Only the Wormhole relayer may call this, for a request sent by the peer
+    /// adapter of `source_chain`. `additional_vaas` holds the transfer VAA.
+    #[payable]
+    #[selector(name = "receiveWormholeMessages")]
+    pub fn receive_wormhole_messages(
+        &mut self,
+        _payload: Bytes,
+        additional_vaas: Vec<Bytes>,
+        source_address: FixedBytes<32>,
+        source_chain: u16,
+        _delivery_hash: FixedBytes<32>,
+    ) -> Result<(), WormholeAdapterError> {
+        if self.vm().msg_sender() != self.wormhole_relayer.get() {
+            return Err(WormholeAdapterError::Unauthorized(Unauthorized {}));
+        }
+
+        let peer = self.peers.get(U256::from(source_chain));
+        if peer == FixedBytes::<32>::ZERO || peer != source_address {
+            return Err(WormholeAdapterError::UntrustedPeer(UntrustedPeer {}));
+        }
+
+        if additional_vaas.len() != 1 {
+            return Err(WormholeAdapterError::InvalidPayload(InvalidPayload {}));
+        }
+
+        self.redeem_vaa(&additional_vaas[0])
+    }
+
+    /// Redeem a transfer VAA directly (destination side)
+    ///
+    /// Fallback when automatic relaying fails; anyone may call it. The Token
+    /// Bridge rejects VAAs that were already redeemed. msg.value, if any, pays
+    /// for a delivery confirmation.
+    #[payable]
+    pub fn redeem(&mut self, vaa: Bytes) -> Result<(), WormholeAdapterError> {
+        self.redeem_vaa(&vaa)
+    }
+
+    /// Get the Wormhole chain ID configured for a chain
+    pub fn get_wormhole_chain(&self, chain_id: U256) -> U256 {
+        self.wormhole_chains.get(chain_id)
+    }
+
+    /// Get the peer adapter for a Wormhole chain
+    pub fn get_peer(&self, wormhole_chain: u16) -> FixedBytes<32> {
+        self.peers.get(U256::from(wormhole_chain))
+    }
+
+    /// Get the token delivered on a chain for an input token
+    pub fn get_output_token(&self, chain_id: U256, input_token: Address) -> Address {
+        self.output_tokens.getter(chain_id).get(input_token)
+    }
+
+    /// Get this adapter as a bytes32 Wormhole address (for peering on remote chains)
+    pub fn peer_id(&self) -> FixedBytes<32> {
+        address_to_bytes32(self.vm().contract_address())
+    }
+
+    /// ERC-165: whether this contract implements `interface_id`
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        erc165::supports(interface_id.0, &[erc165::BRIDGE_ADAPTER_ID, erc165::GAS_LIMIT_ADAPTER_ID])
+    }
+
+    /// Get contract owner
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Internal: Redeem a transfer VAA from a peer and deliver it
+    fn redeem_vaa(&mut self, vaa: &[u8]) -> Result<(), WormholeAdapterError> {
+        // Parsed before redemption only to route the tokens; the Token Bridge
+        // verifies the signatures and that this adapter is the recipient
+        let body = parse_vaa_body(vaa).ok_or(WormholeAdapterError::InvalidPayload(InvalidPayload {}))?;
+        let source_chain = body.emitter_chain;
+        let (sender, inner) =
+            parse_wormhole_transfer(body.payload).ok_or(WormholeAdapterError::InvalidPayload(InvalidPayload {}))?;
+
+        let peer = self.peers.get(U256::from(source_chain));
+        if peer == FixedBytes::<32>::ZERO || peer != sender {
+            return Err(WormholeAdapterError::UntrustedPeer(UntrustedPeer {}));
+        }
+
+        let payload = payload_codec::decode_bridge(inner).map_err(WormholeAdapterError::from)?;
+        let message_id = keccak(inner);
+
+        // Measure what arrived; the Token Bridge truncates amounts to 8 decimals
+        let erc20 = IERC20::new(payload.token);
+        let this = self.vm().contract_address();
+        let before = erc20
+            .balance_of(self.vm(), Call::new(), this)
+            .map_err(|_| WormholeAdapterError::TransferFailed(TransferFailed {}))?;
+
+        let calldata = completeTransferWithPayloadCall {
+            encodedVm: Bytes::copy_from_slice(vaa),
+        }
+        .abi_encode();
+        let token_bridge = self.token_bridge.get();
+        let config = Call::new_mutating(self);
+        call(self.vm(), config, token_bridge, &calldata)
+            .map_err(|_| WormholeAdapterError::WormholeCallFailed(WormholeCallFailed {}))?;
+
+        let after = erc20
+            .balance_of(self.vm(), Call::new(), this)
+            .map_err(|_| WormholeAdapterError::TransferFailed(TransferFailed {}))?;
+        let amount = after.saturating_sub(before);
+        if amount == U256::ZERO {
+            return Err(WormholeAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let config = Call::new_mutating(self);
+        let sent = erc20
+            .transfer(self.vm(), config, payload.recipient, amount)
+            .map_err(|_| WormholeAdapterError::TransferFailed(TransferFailed {}))?;
+        if !sent {
+            return Err(WormholeAdapterError::TransferFailed(TransferFailed {}));
+        }
+
+        let verifier = ISettlementVerifier::new(self.settlement_verifier.get());
+        let config = Call::new_mutating(self);
+        verifier
+            .verify_adapter_message(self.vm(), config, message_id, payload.intentId)
+            .map_err(|_| WormholeAdapterError::WormholeCallFailed(WormholeCallFailed {}))?;
+
+        self.vm().log(WormholeTransferRedeemed {
+            intentId: payload.intentId,
+            sourceChain: source_chain,
+            recipient: payload.recipient,
+            amount,
+        });
+
+        self.publish_confirmation(payload.intentId, inner)
+    }
+
+    /// Internal: Publish the delivered payload for the source chain's verifier
+    ///
+    /// Skipped when msg.value doesn't cover the core message fee, so a missing
+    /// confirmation never blocks delivery.
+    fn publish_confirmation(&mut self, intent_id: U256, payload: &[u8]) -> Result<(), WormholeAdapterError> {
+        let value = self.vm().msg_value();
+        if value == U256::ZERO {
+            return Ok(());
+        }
+
+        let message_fee = self.message_fee()?;
+        if value < message_fee {
+            return Ok(());
+        }
+
+        let calldata = publishMessageCall {
+            nonce: 0,
+            payload: Bytes::copy_from_slice(payload),
+            consistencyLevel: CONFIRMATION_CONSISTENCY,
+        }
+        .abi_encode();
+        let wormhole = self.wormhole.get();
+        let config = Call::new_payable(self, message_fee);
+        let result = call(self.vm(), config, wormhole, &calldata)
+            .map_err(|_| WormholeAdapterError::WormholeCallFailed(WormholeCallFailed {}))?;
+        let sequence = publishMessageCall::abi_decode_returns(&result)
+            .map_err(|_| WormholeAdapterError::WormholeCallFailed(WormholeCallFailed {}))?;
+
+        self.vm().log(DeliveryConfirmationPublished {
+            intentId: intent_id,
+            sequence,
+        });
+
+        Ok(())
+    }
+
+    /// Internal: Resolve a lane to its Wormhole chain, peer and output token
+    fn lane(
+        &self,
+        destination_chain: U256,
+        token: Address,
+    ) -> Result<(u16, FixedBytes<32>, Address), WormholeAdapterError> {
+        let target_chain = self.wormhole_chains.get(destination_chain);
+        let peer = self.peers.get(target_chain);
+        let output_token = self.output_tokens.getter(destination_chain).get(token);
+        if target_chain == U256::ZERO || peer == FixedBytes::<32>::ZERO || output_token == Address::ZERO {
+            return Err(WormholeAdapterError::UnsupportedLane(UnsupportedLane {}));
+        }
+        Ok((target_chain.to::<u16>(), peer, output_token))
+    }
+
+    /// Internal: Core contract fee for publishing one message
+    fn message_fee(&self) -> Result<U256, WormholeAdapterError> {
+        let calldata = messageFeeCall {}.abi_encode();
+        let result = static_call(self.vm(), Call::new(), self.wormhole.get(), &calldata)
+            .map_err(|_| WormholeAdapterError::WormholeCallFailed(WormholeCallFailed {}))?;
+        messageFeeCall::abi_decode_returns(&result)
+            .map_err(|_| WormholeAdapterError::WormholeCallFailed(WormholeCallFailed {}))
+    }
+
+    /// Internal: Lock tokens and request delivery, with an optional
+    /// destination gas override
+    #[allow(clippy::too_many_arguments)]
+    fn internal_bridge(
+        &mut self,
+        intent_id: U256,
+        destination_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+        unwrap_native: bool,
+        gas_limit: U256,
+    ) -> Result<FixedBytes<32>, WormholeAdapterError> {
+        let executor = self.vm().msg_sender();
+        if executor != self.route_executor.get() {
+            return Err(WormholeAdapterError::Unauthorized(Unauthorized {}));
+        }
+
+        if amount == U256::ZERO {
+            return Err(WormholeAdapterError::InvalidAmount(InvalidAmount {}));
+        }
+
+        // The Token Bridge delivers wrapped assets; there is no native payout
+        if unwrap_native {
+            return Err(WormholeAdapterError::UnsupportedLane(UnsupportedLane {}));
+        }
+
+        let (target_chain, peer, output_token) = self.lane(destination_chain, token)?;
+
+        let message_fee = self.message_fee()?;
+        let delivery_price = self.delivery_price(destination_chain, target_chain, gas_limit)?;
+        let fee = self.vm().msg_value();
+        if fee < message_fee + delivery_price {
+            return Err(WormholeAdapterError::InsufficientFee(InsufficientFee {}));
+        }
+
+        // Pull tokens from the executor and approve the Token Bridge
+        let erc20 = IERC20::new(token);
+        let this = self.vm().contract_address();
+        let config = Call::new_mutating(self);
+        let pulled = erc20
+            .transfer_from(self.vm(), config, executor, this, amount)
+            .map_err(|_| WormholeAdapterError::TransferFailed(TransferFailed {}))?;
+        if !pulled {
+            return Err(WormholeAdapterError::TransferFailed(TransferFailed {}));
+        }
+
+        let token_bridge = self.token_bridge.get();
+        let config = Call::new_mutating(self);
+        erc20
+            .approve(self.vm(), config, token_bridge, amount)
+            .map_err(|_| WormholeAdapterError::TransferFailed(TransferFailed {}))?;
+
+        // The same payload rides in the transfer (authenticated) and the relay request
+        let payload = encode_bridge_payload(intent_id, output_token, amount, recipient, false);
+        let message_id = keccak(&payload);
+
+        let calldata = transferTokensWithPayloadCall {
+            token,
+            amount,
+            recipientChain: target_chain,
+            recipient: peer,
+            nonce: 0,
+            payload: payload.clone().into(),
+        }
+        .abi_encode();
+        let config = Call::new_payable(self, message_fee);
+        let result = call(self.vm(), config, token_bridge, &calldata)
+            .map_err(|_| WormholeAdapterError::WormholeCallFailed(WormholeCallFailed {}))?;
+        let sequence = transferTokensWithPayloadCall::abi_decode_returns(&result)
+            .map_err(|_| WormholeAdapterError::WormholeCallFailed(WormholeCallFailed {}))?;
+
+        let calldata = sendVaasToEvmCall {
+            targetChain: target_chain,
+            targetAddress: bytes32_to_address(peer),
+            payload: payload.into(),
+            receiverValue: self.confirmation_value.get(destination_chain),
+            gasLimit: self.gas_limit(destination_chain, gas_limit),
+            vaaKeys: vec![VaaKey {
+                chainId: self.wormhole_chain.get().to::<u16>(),
+                emitterAddress: address_to_bytes32(token_bridge),
+                sequence,
+            }],
+        }
+        .abi_encode();
+        let relayer = self.wormhole_relayer.get();
+        let config = Call::new_payable(self, fee - message_fee);
+        call(self.vm(), config, relayer, &calldata)
+            .map_err(|_| WormholeAdapterError::InsufficientFee(InsufficientFee {}))?;
+
+        self.vm().log(WormholeTransferSent {
+            intentId: intent_id,
+            targetChain: target_chain,
+            sequence,
+            fee,
+        });
+
+        Ok(message_id)
+    }
+
+    /// Internal: Relayer price for delivering to a lane with its gas and confirmation value
+    fn delivery_price(
+        &self,
+        destination_chain: U256,
+        target_chain: u16,
+        gas_limit: U256,
+    ) -> Result<U256, WormholeAdapterError> {
+        let calldata = quoteEVMDeliveryPriceCall {
+            targetChain: target_chain,
+            receiverValue: self.confirmation_value.get(destination_chain),
+            gasLimit: self.gas_limit(destination_chain, gas_limit),
+        }
+        .abi_encode();
+        let result = static_call(self.vm(), Call::new(), self.wormhole_relayer.get(), &calldata)
+            .map_err(|_| WormholeAdapterError::WormholeCallFailed(WormholeCallFailed {}))?;
+        let quote = quoteEVMDeliveryPriceCall::abi_decode_returns(&result)
+            .map_err(|_| WormholeAdapterError::WormholeCallFailed(WormholeCallFailed {}))?;
+        Ok(quote.nativePriceQuote)
+    }
+
+    /// Internal: Destination gas limit for a lane, raised to `gas_limit` if
+    /// that is higher
+    fn gas_limit(&self, destination_chain: U256, gas_limit: U256) -> U256 {
+        let gas = self.destination_gas.get(destination_chain);
+        if gas == U256::ZERO {
+            U256::from(DEFAULT_DESTINATION_GAS).max(gas_limit)
+        } else {
+            gas.max(gas_limit)
+        }
+    }
+
+    /// Internal: Check if caller is owner
+    fn only_owner(&self) -> Result<(), WormholeAdapterError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(WormholeAdapterError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,332 @@
+//! swoosh-admin
+//!
+//! Usage:
+//!   swoosh-admin [--dry-run | --multisig] COMMAND [ARGS...]
+//!
+//! Commands:
+//!   add-chain CHAIN_ID
+//!   add-token TOKEN
+//!   pause | unpause
+//!   set-timeout SECONDS
+//!   set-adapter bridge CHAIN_ID ADAPTER
+//!   set-adapter swap|settlement ADAPTER on|off
+//!   transfer-ownership validator|executor|verifier NEW_OWNER
+//!   inspect-intent INTENT_ID (the intent hash, as hex or a number)
+//!   export-config validator|executor (prints one hex page per line)
+//!   import-config validator|executor PAGE
+//!   seal-config validator|executor
+//!
+//! Contract addresses are read from INTENT_VALIDATOR_ADDRESS,
+//! ROUTE_EXECUTOR_ADDRESS and SETTLEMENT_VERIFIER_ADDRESS, the endpoint from
+//! ARBITRUM_SEPOLIA_RPC. Transactions are signed with PRIVATE_KEY.
+//!
+//! `--dry-run` simulates the call from the contract's current owner and
+//! prints the calldata without sending. `--multisig` prints a Safe
+//! Transaction Builder batch for an owner that is a multisig.
+
+use std::env;
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use alloy_primitives::{Address, Bytes, B256, U256};
+use alloy_sol_types::SolCall;
+use ethers::middleware::SignerMiddleware;
+use ethers::providers::{Http, Middleware, MiddlewareError, Provider};
+use ethers::signers::{LocalWallet, Signer};
+use ethers::types::transaction::eip2718::TypedTransaction;
+use ethers::types::{TransactionRequest, H160};
+use swoosh_client::admin::{
+    exportConfigCall, getIntentRecordCall, getIntentSolverCall, getIntentStatusCall, getSettlementStatusCall,
+    getSettlementTimestampCall, hasSettlementTimedOutCall, intent_status_name, ownerCall, safe_batch,
+    settlement_status_name, AdminCall,
+};
+use swoosh_client::decode_error;
+
+const DEFAULT_RPC: &str = "https://sepolia-rollup.arbitrum.io/rpc";
+
+/// Config entries read per `exportConfig` call
+const EXPORT_PAGE_SIZE: u64 = 50;
+
+/// What to do with a built admin call
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Send,
+    DryRun,
+    Multisig,
+}
+
+/// Address of a core contract from its environment variable
+fn contract(name: &str) -> Result<Address, String> {
+    let var = match name {
+        "validator" => "INTENT_VALIDATOR_ADDRESS",
+        "executor" => "ROUTE_EXECUTOR_ADDRESS",
+        "verifier" => "SETTLEMENT_VERIFIER_ADDRESS",
+        _ => return Err(format!("unknown contract: {name} (expected validator, executor or verifier)")),
+    };
+    let value = env::var(var).map_err(|_| format!("{var} is not set"))?;
+    parse_address(&value).map_err(|err| format!("{var}: {err}"))
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+
+    let dry_run = take_flag(&mut args, "--dry-run");
+    let multisig = take_flag(&mut args, "--multisig");
+    let mode = match (dry_run, multisig) {
+        (false, false) => Some(Mode::Send),
+        (true, false) => Some(Mode::DryRun),
+        (false, true) => Some(Mode::Multisig),
+        (true, true) => None,
+    };
+
+    let result = match mode {
+        Some(mode) => run(mode, &args).await,
+        None => Err("--dry-run and --multisig are mutually exclusive".to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(mode: Mode, args: &[String]) -> Result<(), String> {
+    let arg = |index: usize, name: &str| -> Result<&str, String> {
+        args.get(index)
+            .map(String::as_str)
+            .ok_or_else(|| format!("missing {name}; usage: swoosh-admin {} ...", args[0]))
+    };
+
+    let call = match args.first().map(String::as_str) {
+        Some("add-chain") => AdminCall::add_chain(contract("validator")?, parse_u256(arg(1, "CHAIN_ID")?)?),
+        Some("add-token") => AdminCall::add_token(contract("validator")?, parse_address(arg(1, "TOKEN")?)?),
+        Some("pause") => AdminCall::set_paused(contract("executor")?, true),
+        Some("unpause") => AdminCall::set_paused(contract("executor")?, false),
+        Some("set-timeout") => AdminCall::set_timeout(contract("verifier")?, parse_u256(arg(1, "SECONDS")?)?),
+        Some("set-adapter") => match arg(1, "KIND")? {
+            "bridge" => AdminCall::set_bridge_adapter(
+                contract("executor")?,
+                parse_u256(arg(2, "CHAIN_ID")?)?,
+                parse_address(arg(3, "ADAPTER")?)?,
+            ),
+            "swap" => AdminCall::set_swap_adapter(
+                contract("executor")?,
+                parse_address(arg(2, "ADAPTER")?)?,
+                parse_switch(arg(3, "on|off")?)?,
+            ),
+            "settlement" => AdminCall::set_settlement_adapter(
+                contract("verifier")?,
+                parse_address(arg(2, "ADAPTER")?)?,
+                parse_switch(arg(3, "on|off")?)?,
+            ),
+            kind => return Err(format!("unknown adapter kind: {kind} (expected bridge, swap or settlement)")),
+        },
+        Some("transfer-ownership") => {
+            AdminCall::transfer_ownership(contract(arg(1, "CONTRACT")?)?, parse_address(arg(2, "NEW_OWNER")?)?)
+        }
+        Some("inspect-intent") => return inspect_intent(&provider()?, parse_u256(arg(1, "INTENT_ID")?)?).await,
+        Some("export-config") => return export_config(&provider()?, contract(arg(1, "CONTRACT")?)?).await,
+        Some("import-config") => {
+            AdminCall::import_config(contract(arg(1, "CONTRACT")?)?, parse_bytes(arg(2, "PAGE")?)?)
+        }
+        Some("seal-config") => AdminCall::seal_config_import(contract(arg(1, "CONTRACT")?)?),
+        _ => {
+            return Err("usage: swoosh-admin [--dry-run | --multisig] <add-chain|add-token|pause|unpause|set-timeout|\
+                        set-adapter|transfer-ownership|inspect-intent|export-config|import-config|seal-config> \
+                        [ARGS...]"
+                .to_string())
+        }
+    };
+
+    match mode {
+        Mode::Send => send(&provider()?, &call).await,
+        Mode::DryRun => dry_run(&provider()?, &call).await,
+        Mode::Multisig => {
+            let chain_id = match env::var("CHAIN_ID") {
+                Ok(id) => id.parse().map_err(|_| format!("invalid CHAIN_ID: {id}"))?,
+                Err(_) => provider()?
+                    .get_chainid()
+                    .await
+                    .map_err(|err| format!("provider error: {err}"))?
+                    .as_u64(),
+            };
+            let batch = safe_batch(chain_id, std::slice::from_ref(&call));
+            println!("{}", serde_json::to_string_pretty(&batch).map_err(|err| err.to_string())?);
+            Ok(())
+        }
+    }
+}
+
+/// Simulate from the owner's key, then sign and send
+async fn send(provider: &Provider<Http>, call: &AdminCall) -> Result<(), String> {
+    let key = env::var("PRIVATE_KEY").map_err(|_| "PRIVATE_KEY is not set".to_string())?;
+    let chain_id = provider
+        .get_chainid()
+        .await
+        .map_err(|err| format!("provider error: {err}"))?
+        .as_u64();
+    let wallet = LocalWallet::from_str(&key)
+        .map_err(|err| format!("invalid PRIVATE_KEY: {err}"))?
+        .with_chain_id(chain_id);
+
+    simulate(provider, call, Address::from(wallet.address().0)).await?;
+
+    let client = SignerMiddleware::new(provider.clone(), wallet);
+    let pending = client
+        .send_transaction(transaction(call, None), None)
+        .await
+        .map_err(|err| format!("send failed: {err}"))?;
+    eprintln!("{}: sent {:?}", call.description, pending.tx_hash());
+
+    let receipt = pending
+        .await
+        .map_err(|err| format!("provider error: {err}"))?
+        .ok_or("transaction dropped from the mempool")?;
+    match receipt.status.map(|status| status.as_u64()) {
+        Some(1) => {
+            println!("{:?}", receipt.transaction_hash);
+            Ok(())
+        }
+        _ => Err(format!("transaction {:?} reverted", receipt.transaction_hash)),
+    }
+}
+
+/// Print the call and check it would succeed when sent by the current owner
+async fn dry_run(provider: &Provider<Http>, call: &AdminCall) -> Result<(), String> {
+    let owner = read(provider, call.to, ownerCall {}).await?;
+
+    println!("description: {}", call.description);
+    println!("to:          {}", call.to);
+    println!("from:        {owner}");
+    println!("data:        {}", call.data);
+
+    simulate(provider, call, owner).await?;
+    println!("simulation:  ok");
+    Ok(())
+}
+
+/// Print executor and verifier state for one intent
+async fn inspect_intent(provider: &Provider<Http>, intent_id: U256) -> Result<(), String> {
+    let executor = contract("executor")?;
+    let verifier = contract("verifier")?;
+
+    let record = read(provider, executor, getIntentRecordCall { intentHash: B256::from(intent_id) }).await?;
+    let status = read(provider, executor, getIntentStatusCall { intentId: intent_id }).await?;
+    let settlement = read(provider, verifier, getSettlementStatusCall { intentId: intent_id }).await?;
+    let settled_at = read(provider, verifier, getSettlementTimestampCall { intentId: intent_id }).await?;
+    let timed_out = read(provider, verifier, hasSettlementTimedOutCall { intentId: intent_id }).await?;
+    let solver = read(provider, verifier, getIntentSolverCall { intentId: intent_id }).await?;
+
+    println!("intent:      {}", B256::from(intent_id));
+    if record.index.is_zero() {
+        println!("local index: not executed on this chain");
+    } else {
+        println!("local index: {}", record.index);
+    }
+    println!("execution:   {} ({status})", intent_status_name(status));
+    println!("settlement:  {} ({settlement})", settlement_status_name(settlement));
+    println!("timestamp:   {settled_at}");
+    println!("timed out:   {timed_out}");
+    if solver.is_zero() {
+        println!("solver:      none");
+    } else {
+        println!("solver:      {solver}");
+    }
+    Ok(())
+}
+
+/// Print every config page of a contract, one per line, ready for `import-config`
+async fn export_config(provider: &Provider<Http>, contract: Address) -> Result<(), String> {
+    let limit = U256::from(EXPORT_PAGE_SIZE);
+    let mut offset = U256::ZERO;
+    loop {
+        let page = read(provider, contract, exportConfigCall { offset, limit }).await?;
+        println!("{}", page.page);
+        offset += limit;
+        if offset >= page.total {
+            return Ok(());
+        }
+    }
+}
+
+/// eth_call the admin call from `from`, decoding a revert into its contract error
+async fn simulate(provider: &Provider<Http>, call: &AdminCall, from: Address) -> Result<(), String> {
+    match provider.call(&transaction(call, Some(from)), None).await {
+        Ok(_) => Ok(()),
+        Err(err) => {
+            let reason = err
+                .as_error_response()
+                .and_then(|response| response.as_revert_data())
+                .and_then(|data| decode_error(&data))
+                .map(|error| error.to_string())
+                .unwrap_or_else(|| err.to_string());
+            Err(format!("{} would revert: {reason}", call.description))
+        }
+    }
+}
+
+/// eth_call a view and decode its return value
+async fn read<C: SolCall>(provider: &Provider<Http>, to: Address, call: C) -> Result<C::Return, String> {
+    let request = AdminCall {
+        to,
+        data: call.abi_encode().into(),
+        description: String::new(),
+    };
+    let output = provider
+        .call(&transaction(&request, None), None)
+        .await
+        .map_err(|err| format!("call to {to} failed: {err}"))?;
+    C::abi_decode_returns(&output).map_err(|err| format!("bad return data from {to}: {err}"))
+}
+
+fn transaction(call: &AdminCall, from: Option<Address>) -> TypedTransaction {
+    let mut request = TransactionRequest::new()
+        .to(to_h160(call.to))
+        .data(call.data.to_vec());
+    if let Some(from) = from {
+        request = request.from(to_h160(from));
+    }
+    request.into()
+}
+
+fn provider() -> Result<Provider<Http>, String> {
+    let endpoint = env::var("ARBITRUM_SEPOLIA_RPC").unwrap_or_else(|_| DEFAULT_RPC.to_string());
+    Provider::<Http>::try_from(endpoint.as_str()).map_err(|err| format!("invalid RPC url {endpoint}: {err}"))
+}
+
+/// Remove a flag wherever it appears, reporting whether it was present
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    let before = args.len();
+    args.retain(|arg| arg != flag);
+    args.len() != before
+}
+
+fn parse_address(value: &str) -> Result<Address, String> {
+    value.parse().map_err(|_| format!("invalid address: {value}"))
+}
+
+/// Decimal or 0x-prefixed hex
+fn parse_u256(value: &str) -> Result<U256, String> {
+    value.parse().map_err(|_| format!("invalid number: {value}"))
+}
+
+/// 0x-prefixed hex
+fn parse_bytes(value: &str) -> Result<Bytes, String> {
+    value.parse().map_err(|_| format!("invalid hex: {value}"))
+}
+
+fn parse_switch(value: &str) -> Result<bool, String> {
+    match value {
+        "on" | "true" => Ok(true),
+        "off" | "false" => Ok(false),
+        _ => Err(format!("expected on or off, got {value}")),
+    }
+}
+
+fn to_h160(address: Address) -> H160 {
+    H160::from_slice(address.as_slice())
+}
@@ -0,0 +1,174 @@
+//! Admin Calls
+//!
+//! Calldata builders for the owner-only operations on IntentValidator,
+//! RouteExecutor and SettlementVerifier, plus the read calls used to inspect
+//! an intent. Calls can be sent directly or exported as a Safe transaction
+//! batch for multisig owners.
+
+use alloy_primitives::{Address, Bytes, U256};
+use alloy_sol_types::{sol, SolCall};
+use serde_json::{json, Value};
+
+// Stylus exports the contracts' snake_case methods under camelCase names
+sol! {
+    function addSupportedChain(uint256 chainId);
+    function addSupportedToken(address token);
+    function setLaneHalted(uint256 chainId, bool halted);
+    function pause();
+    function unpause();
+    function setTimeoutPeriod(uint256 newTimeout);
+    function setBridgeAdapter(uint256 destinationChain, address adapter);
+    function setSwapAdapter(address adapter, bool allowed);
+    function setSettlementAdapter(address adapter, bool enabled);
+    function transferOwnership(address newOwner);
+    function importConfig(bytes page);
+    function sealConfigImport();
+
+    function owner() external view returns (address);
+    function getIntentStatus(uint256 intentId) external view returns (uint256);
+    function getSettlementStatus(uint256 intentId) external view returns (uint256);
+    function getSettlementTimestamp(uint256 intentId) external view returns (uint256);
+    function hasSettlementTimedOut(uint256 intentId) external view returns (bool);
+    function getIntentSolver(uint256 intentId) external view returns (uint256);
+    function getIntentRecord(bytes32 intentHash)
+        external
+        view
+        returns (uint256 intentId, uint256 index, uint256 status);
+    function exportConfig(uint256 offset, uint256 limit) external view returns (bytes page, uint256 total);
+}
+
+/// One admin transaction: target contract, calldata and what it does
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AdminCall {
+    pub to: Address,
+    pub data: Bytes,
+    pub description: String,
+}
+
+impl AdminCall {
+    fn new(to: Address, call: impl SolCall, description: String) -> Self {
+        Self {
+            to,
+            data: call.abi_encode().into(),
+            description,
+        }
+    }
+
+    /// IntentValidator: allow a destination chain
+    pub fn add_chain(validator: Address, chain_id: U256) -> Self {
+        Self::new(validator, addSupportedChainCall { chainId: chain_id }, format!("add supported chain {chain_id}"))
+    }
+
+    /// IntentValidator: allow an input token
+    pub fn add_token(validator: Address, token: Address) -> Self {
+        Self::new(validator, addSupportedTokenCall { token }, format!("add supported token {token}"))
+    }
+
+    /// IntentValidator: halt or restore new intents toward a destination chain
+    /// (the guardian may send this too)
+    pub fn set_lane_halted(validator: Address, chain_id: U256, halted: bool) -> Self {
+        let action = if halted { "halt" } else { "restore" };
+        Self::new(
+            validator,
+            setLaneHaltedCall { chainId: chain_id, halted },
+            format!("{action} lane to chain {chain_id}"),
+        )
+    }
+
+    /// RouteExecutor: pause or resume execution
+    pub fn set_paused(executor: Address, paused: bool) -> Self {
+        if paused {
+            Self::new(executor, pauseCall {}, "pause RouteExecutor".to_string())
+        } else {
+            Self::new(executor, unpauseCall {}, "unpause RouteExecutor".to_string())
+        }
+    }
+
+    /// SettlementVerifier: set the settlement timeout in seconds
+    pub fn set_timeout(verifier: Address, seconds: U256) -> Self {
+        Self::new(verifier, setTimeoutPeriodCall { newTimeout: seconds }, format!("set settlement timeout to {seconds}s"))
+    }
+
+    /// RouteExecutor: route a destination chain through a bridge adapter
+    /// (zero address falls back to CCIP)
+    pub fn set_bridge_adapter(executor: Address, destination_chain: U256, adapter: Address) -> Self {
+        Self::new(
+            executor,
+            setBridgeAdapterCall { destinationChain: destination_chain, adapter },
+            format!("set bridge adapter for chain {destination_chain} to {adapter}"),
+        )
+    }
+
+    /// RouteExecutor: allow or remove a swap adapter
+    pub fn set_swap_adapter(executor: Address, adapter: Address, allowed: bool) -> Self {
+        let action = if allowed { "allow" } else { "remove" };
+        Self::new(executor, setSwapAdapterCall { adapter, allowed }, format!("{action} swap adapter {adapter}"))
+    }
+
+    /// SettlementVerifier: authorize or revoke an adapter as delivery reporter
+    pub fn set_settlement_adapter(verifier: Address, adapter: Address, enabled: bool) -> Self {
+        let action = if enabled { "authorize" } else { "revoke" };
+        Self::new(
+            verifier,
+            setSettlementAdapterCall { adapter, enabled },
+            format!("{action} settlement adapter {adapter}"),
+        )
+    }
+
+    /// Any core contract: hand ownership to a new owner
+    pub fn transfer_ownership(contract: Address, new_owner: Address) -> Self {
+        Self::new(contract, transferOwnershipCall { newOwner: new_owner }, format!("transfer ownership of {contract} to {new_owner}"))
+    }
+
+    /// IntentValidator or RouteExecutor: restore a page from `exportConfig`
+    pub fn import_config(contract: Address, page: Bytes) -> Self {
+        let description = format!("import a {}-byte config page into {contract}", page.len());
+        Self::new(contract, importConfigCall { page }, description)
+    }
+
+    /// IntentValidator or RouteExecutor: close config import for good
+    pub fn seal_config_import(contract: Address) -> Self {
+        Self::new(contract, sealConfigImportCall {}, format!("seal config import on {contract}"))
+    }
+}
+
+/// Safe Transaction Builder batch for a multisig owner to import
+pub fn safe_batch(chain_id: u64, calls: &[AdminCall]) -> Value {
+    json!({
+        "version": "1.0",
+        "chainId": chain_id.to_string(),
+        "meta": {
+            "name": "Swoosh admin",
+            "description": calls.iter().map(|call| call.description.as_str()).collect::<Vec<_>>().join("; "),
+        },
+        "transactions": calls.iter().map(|call| json!({
+            "to": call.to.to_checksum(None),
+            "value": "0",
+            "data": call.data.to_string(),
+        })).collect::<Vec<_>>(),
+    })
+}
+
+/// RouteExecutor `IntentStatus` name
+pub fn intent_status_name(status: U256) -> &'static str {
+    match status.saturating_to::<u64>() {
+        0 => "Pending",
+        1 => "Executing",
+        2 => "Completed",
+        3 => "Failed",
+        _ => "Unknown",
+    }
+}
+
+/// SettlementVerifier `SettlementStatus` name
+pub fn settlement_status_name(status: U256) -> &'static str {
+    match status.saturating_to::<u64>() {
+        0 => "Pending",
+        1 => "Confirmed",
+        2 => "Failed",
+        3 => "Refunded",
+        4 => "PartialDelivery",
+        5 => "DeliveredToClaim",
+        _ => "Unknown",
+    }
+}
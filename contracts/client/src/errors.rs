@@ -0,0 +1,858 @@
+//! Revert Decoding
+//!
+//! Maps the raw revert bytes returned by IntentValidator, RouteExecutor,
+//! SettlementVerifier and SwooshReceiver to a typed error with a human-readable message. Errors
+//! that share a signature across contracts share a selector, so they decode to
+//! the same variant regardless of which contract reverted.
+
+use std::fmt;
+
+use alloy_primitives::{Address, FixedBytes, B256, U256};
+use alloy_sol_types::{Panic, Revert, SolError};
+
+/// Solidity error definitions mirrored from the contracts
+pub mod abi {
+    use alloy_sol_types::sol;
+
+    sol! {
+        // Shared
+        error Unauthorized();
+        error InvalidAddress();
+        error InvalidAmount();
+
+        // IntentValidator
+        error UnsupportedChain();
+        error UnsupportedToken();
+        error InsufficientBalance();
+        error InsufficientAllowance();
+        error LaneHalted(uint256 chainId);
+        error IntentBlocked(bytes32 intentHash);
+        error UntrustedConfigSource(uint256 sourceChain, address messenger);
+        error InvalidConfigMessage();
+        error ConfigSyncReplayed(uint256 sourceChain, uint256 nonce);
+        error ConfigSyncFailed();
+        error InvalidConfigPage();
+        error ConfigImportClosed();
+        error ConfigEpochMismatch(uint256 epoch, uint256 compatibleEpoch, uint256 currentEpoch);
+        error InvalidListingMode();
+        error TokenCapExceeded(address token, uint256 cap);
+        error ListingRejected(address token, uint8 reason);
+        error DelegationExceeded(address delegate, address token, uint256 amount, uint256 remaining);
+        error ReportsDisabled();
+        error AlreadyReported(bytes32 intentHash);
+        error NoWatchReport(bytes32 intentHash);
+        error IntentHeld(bytes32 intentHash, uint256 heldUntil);
+        error SignerMismatch(address signer, address user);
+        error NoPriceSource(address token);
+        error InvalidNonce(address user, uint256 nonce, uint256 expected);
+        error UnknownIntent(bytes32 intentHash);
+        error IntentNotPending(bytes32 intentHash, uint8 status);
+        error IntentOutOfRange();
+
+        // RouteExecutor
+        error ValidationFailed();
+        error SwapFailed();
+        error BridgeFailed();
+        error ContractPaused();
+        error ReentrancyGuard();
+        error AdapterNotAllowed();
+        error TransferFailed();
+        error SlippageExceeded(uint256 amountOut, uint256 minAmountOut);
+        error DeadlineExpired(uint256 deadline, uint256 timestamp);
+        error InvalidRoute(uint8 reason);
+        error UnsupportedStep();
+        error FeeTooHigh(uint256 feeBps, uint256 maxFeeBps);
+        error SolverOnly();
+        error NotSolver();
+        error InvalidSignature();
+        error IntentAlreadyUsed(bytes32 intentHash);
+        error CancelledIntent(bytes32 intentHash);
+        error RouteQuoteRequired();
+        error InvalidRouteQuote();
+        error UnknownRouteSigner(address signer);
+        error InvalidMatch();
+        error NotSameAsset(address token, uint256 destinationChain);
+        error DeadlineTooFar(uint256 deadline, uint256 latest);
+        error GasDropTooHigh(uint256 gasDrop, uint256 maxGasDrop);
+        error UnsupportedAsset(uint8 assetType);
+        error InvalidRecipientReveal();
+        error SolverFeeTooHigh(uint256 solverFee, uint256 maxSolverFee);
+        error EscrowLocked(bytes32 intentHash);
+        error EmergencyModeLocked(uint256 availableAt);
+        error IncompatibleValidator(address validator, uint8 reason);
+        error InsufficientLiquidity(uint256 stepIndex, uint256 expectedOut, uint256 minOut);
+        error GasLimitTooHigh(uint256 gasLimit, uint256 maxGasLimit);
+        error StaleBlock(uint256 blockNumber, uint256 oldestBlock);
+        error BlockHashMismatch(uint256 blockNumber, bytes32 blockHash);
+        error SolverAtCapacity(uint256 solverId);
+        error ClaimsDisabled();
+        error IntentNotClaimable(bytes32 intentHash);
+        error ClaimActive(bytes32 intentHash, uint256 solverId, uint256 expiresAt);
+        error InterfaceNotSupported(address target, bytes4 interfaceId);
+        error InvalidFillAmount(address token, uint256 amount, uint256 minFill, uint256 granularity);
+        error LaneCapExceeded(uint256 chainId, address token, uint256 amount, uint256 available);
+        error InvalidApprovalPolicy();
+        error SimulationResult(
+            uint256 amountOut,
+            uint256 gasUsed,
+            uint256 protocolFee,
+            uint256 integratorFee,
+            uint256 bridgeFee,
+            uint256 gasDropCost
+        );
+
+        // SettlementVerifier
+        error InvalidMessageId();
+        error InvalidIntentId();
+        error SettlementTimeout();
+        error AlreadyProcessed();
+        error RefundFailed();
+        error InvalidVaa();
+        error OrderNotFulfilled();
+        error InvalidDeliveryStatus(uint8 status);
+        error InvalidBatch();
+        error InFlightLimitExceeded(uint256 solverId, uint256 limit);
+        error InFlightNotionalExceeded(uint256 solverId, address token, uint256 limit);
+        error AttestationFailed();
+        error InvalidCallback();
+        error InvalidLanePolicy();
+        error ReporterNotAccepted(uint8 reporterType);
+        error NoPendingDelivery();
+        error ChallengeWindowOpen(uint256 finalAt);
+        error InsuranceUnavailable();
+        error NotClaimable(uint256 intentId, uint256 status);
+        error ClaimFailed();
+        error InvalidRetryPolicy();
+        error DrillsDisabled();
+
+        // SwooshReceiver
+        error UntrustedLane(uint256 sourceChain, address messenger);
+        error InvalidPayload();
+        error MalformedPayload(uint8 reason);
+        error VerifierCallFailed();
+        error NothingToClaim();
+        error ClaimNotExpired(uint256 expiresAt);
+        error DustBelowThreshold(uint256 dust, uint256 threshold);
+
+        // Any contract with bounded parameters
+        error ParameterOutOfBounds(uint8 param, uint256 value, uint256 minValue, uint256 maxValue);
+    }
+}
+
+/// Typed Swoosh revert
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SwooshError {
+    Unauthorized,
+    InvalidAddress,
+    InvalidAmount,
+    UnsupportedChain,
+    UnsupportedToken,
+    InsufficientBalance,
+    InsufficientAllowance,
+    ValidationFailed,
+    SwapFailed,
+    BridgeFailed,
+    ContractPaused,
+    ReentrancyGuard,
+    AdapterNotAllowed,
+    TransferFailed,
+    SlippageExceeded { amount_out: U256, min_amount_out: U256 },
+    DeadlineExpired { deadline: U256, timestamp: U256 },
+    InvalidRoute { reason: u8 },
+    UnsupportedStep,
+    FeeTooHigh { fee_bps: U256, max_fee_bps: U256 },
+    SolverOnly,
+    NotSolver,
+    InvalidSignature,
+    IntentAlreadyUsed { intent_hash: B256 },
+    CancelledIntent { intent_hash: B256 },
+    RouteQuoteRequired,
+    InvalidRouteQuote,
+    UnknownRouteSigner { signer: Address },
+    InvalidMatch,
+    NotSameAsset { token: Address, destination_chain: U256 },
+    DeadlineTooFar { deadline: U256, latest: U256 },
+    GasDropTooHigh { gas_drop: U256, max_gas_drop: U256 },
+    UnsupportedAsset { asset_type: u8 },
+    InvalidRecipientReveal,
+    SolverFeeTooHigh { solver_fee: U256, max_solver_fee: U256 },
+    EscrowLocked { intent_hash: B256 },
+    EmergencyModeLocked { available_at: U256 },
+    LaneHalted { chain_id: U256 },
+    IntentBlocked { intent_hash: B256 },
+    UntrustedConfigSource { source_chain: U256, messenger: Address },
+    InvalidConfigMessage,
+    ConfigSyncReplayed { source_chain: U256, nonce: U256 },
+    ConfigSyncFailed,
+    InvalidConfigPage,
+    ConfigImportClosed,
+    ConfigEpochMismatch { epoch: U256, compatible_epoch: U256, current_epoch: U256 },
+    InvalidListingMode,
+    TokenCapExceeded { token: Address, cap: U256 },
+    ListingRejected { token: Address, reason: u8 },
+    DelegationExceeded { delegate: Address, token: Address, amount: U256, remaining: U256 },
+    ReportsDisabled,
+    AlreadyReported { intent_hash: B256 },
+    NoWatchReport { intent_hash: B256 },
+    IntentHeld { intent_hash: B256, held_until: U256 },
+    SignerMismatch { signer: Address, user: Address },
+    NoPriceSource { token: Address },
+    InvalidNonce { user: Address, nonce: U256, expected: U256 },
+    UnknownIntent { intent_hash: B256 },
+    IntentNotPending { intent_hash: B256, status: u8 },
+    IntentOutOfRange,
+    IncompatibleValidator { validator: Address, reason: u8 },
+    InsufficientLiquidity { step_index: U256, expected_out: U256, min_out: U256 },
+    GasLimitTooHigh { gas_limit: U256, max_gas_limit: U256 },
+    StaleBlock { block_number: U256, oldest_block: U256 },
+    BlockHashMismatch { block_number: U256, block_hash: B256 },
+    SolverAtCapacity { solver_id: U256 },
+    ClaimsDisabled,
+    IntentNotClaimable { intent_hash: B256 },
+    ClaimActive { intent_hash: B256, solver_id: U256, expires_at: U256 },
+    InterfaceNotSupported { target: Address, interface_id: FixedBytes<4> },
+    InvalidFillAmount { token: Address, amount: U256, min_fill: U256, granularity: U256 },
+    LaneCapExceeded { chain_id: U256, token: Address, amount: U256, available: U256 },
+    InvalidApprovalPolicy,
+    /// `simulateRoute` result; the route would have succeeded
+    SimulationResult {
+        amount_out: U256,
+        gas_used: U256,
+        protocol_fee: U256,
+        integrator_fee: U256,
+        bridge_fee: U256,
+        gas_drop_cost: U256,
+    },
+    InvalidMessageId,
+    InvalidIntentId,
+    SettlementTimeout,
+    AlreadyProcessed,
+    RefundFailed,
+    InvalidVaa,
+    OrderNotFulfilled,
+    InvalidDeliveryStatus { status: u8 },
+    InvalidBatch,
+    InFlightLimitExceeded { solver_id: U256, limit: U256 },
+    InFlightNotionalExceeded { solver_id: U256, token: Address, limit: U256 },
+    AttestationFailed,
+    InvalidCallback,
+    InvalidLanePolicy,
+    ReporterNotAccepted { reporter_type: u8 },
+    NoPendingDelivery,
+    ChallengeWindowOpen { final_at: U256 },
+    InsuranceUnavailable,
+    NotClaimable { intent_id: U256, status: U256 },
+    ClaimFailed,
+    InvalidRetryPolicy,
+    DrillsDisabled,
+    UntrustedLane { source_chain: U256, messenger: Address },
+    InvalidPayload,
+    MalformedPayload { reason: u8 },
+    VerifierCallFailed,
+    NothingToClaim,
+    ClaimNotExpired { expires_at: U256 },
+    DustBelowThreshold { dust: U256, threshold: U256 },
+    ParameterOutOfBounds { param: u8, value: U256, min_value: U256, max_value: U256 },
+    /// Standard `Error(string)` revert, e.g. from a token or router
+    Revert(String),
+    /// Standard `Panic(uint256)` revert
+    Panic(U256),
+}
+
+/// Decode raw revert bytes from any Swoosh contract
+///
+/// Returns `None` for empty data, unknown selectors, or malformed arguments.
+pub fn decode_error(data: &[u8]) -> Option<SwooshError> {
+    let selector: [u8; 4] = data.get(..4)?.try_into().ok()?;
+
+    // Errors without arguments only need a selector match
+    macro_rules! unit_errors {
+        ($($name:ident),* $(,)?) => {
+            $(
+                if selector == abi::$name::SELECTOR {
+                    return Some(SwooshError::$name);
+                }
+            )*
+        };
+    }
+
+    unit_errors!(
+        Unauthorized,
+        InvalidAddress,
+        InvalidAmount,
+        UnsupportedChain,
+        UnsupportedToken,
+        InsufficientBalance,
+        InsufficientAllowance,
+        InvalidConfigMessage,
+        ConfigSyncFailed,
+        InvalidConfigPage,
+        ConfigImportClosed,
+        InvalidListingMode,
+        ReportsDisabled,
+        IntentOutOfRange,
+        ValidationFailed,
+        SwapFailed,
+        BridgeFailed,
+        ContractPaused,
+        ReentrancyGuard,
+        AdapterNotAllowed,
+        TransferFailed,
+        UnsupportedStep,
+        SolverOnly,
+        NotSolver,
+        InvalidSignature,
+        RouteQuoteRequired,
+        InvalidRouteQuote,
+        InvalidMatch,
+        InvalidRecipientReveal,
+        ClaimsDisabled,
+        InvalidApprovalPolicy,
+        InvalidMessageId,
+        InvalidIntentId,
+        SettlementTimeout,
+        AlreadyProcessed,
+        RefundFailed,
+        InvalidVaa,
+        OrderNotFulfilled,
+        InvalidBatch,
+        AttestationFailed,
+        InvalidCallback,
+        InvalidLanePolicy,
+        NoPendingDelivery,
+        InsuranceUnavailable,
+        ClaimFailed,
+        InvalidRetryPolicy,
+        DrillsDisabled,
+        InvalidPayload,
+        VerifierCallFailed,
+        NothingToClaim,
+    );
+
+    if selector == abi::SlippageExceeded::SELECTOR {
+        let err = abi::SlippageExceeded::abi_decode(data).ok()?;
+        Some(SwooshError::SlippageExceeded {
+            amount_out: err.amountOut,
+            min_amount_out: err.minAmountOut,
+        })
+    } else if selector == abi::DeadlineExpired::SELECTOR {
+        let err = abi::DeadlineExpired::abi_decode(data).ok()?;
+        Some(SwooshError::DeadlineExpired {
+            deadline: err.deadline,
+            timestamp: err.timestamp,
+        })
+    } else if selector == abi::InvalidRoute::SELECTOR {
+        let err = abi::InvalidRoute::abi_decode(data).ok()?;
+        Some(SwooshError::InvalidRoute { reason: err.reason })
+    } else if selector == abi::FeeTooHigh::SELECTOR {
+        let err = abi::FeeTooHigh::abi_decode(data).ok()?;
+        Some(SwooshError::FeeTooHigh {
+            fee_bps: err.feeBps,
+            max_fee_bps: err.maxFeeBps,
+        })
+    } else if selector == abi::IntentAlreadyUsed::SELECTOR {
+        let err = abi::IntentAlreadyUsed::abi_decode(data).ok()?;
+        Some(SwooshError::IntentAlreadyUsed { intent_hash: err.intentHash })
+    } else if selector == abi::CancelledIntent::SELECTOR {
+        let err = abi::CancelledIntent::abi_decode(data).ok()?;
+        Some(SwooshError::CancelledIntent { intent_hash: err.intentHash })
+    } else if selector == abi::UnknownRouteSigner::SELECTOR {
+        let err = abi::UnknownRouteSigner::abi_decode(data).ok()?;
+        Some(SwooshError::UnknownRouteSigner { signer: err.signer })
+    } else if selector == abi::NotSameAsset::SELECTOR {
+        let err = abi::NotSameAsset::abi_decode(data).ok()?;
+        Some(SwooshError::NotSameAsset {
+            token: err.token,
+            destination_chain: err.destinationChain,
+        })
+    } else if selector == abi::DeadlineTooFar::SELECTOR {
+        let err = abi::DeadlineTooFar::abi_decode(data).ok()?;
+        Some(SwooshError::DeadlineTooFar {
+            deadline: err.deadline,
+            latest: err.latest,
+        })
+    } else if selector == abi::GasDropTooHigh::SELECTOR {
+        let err = abi::GasDropTooHigh::abi_decode(data).ok()?;
+        Some(SwooshError::GasDropTooHigh {
+            gas_drop: err.gasDrop,
+            max_gas_drop: err.maxGasDrop,
+        })
+    } else if selector == abi::UnsupportedAsset::SELECTOR {
+        let err = abi::UnsupportedAsset::abi_decode(data).ok()?;
+        Some(SwooshError::UnsupportedAsset { asset_type: err.assetType })
+    } else if selector == abi::SolverFeeTooHigh::SELECTOR {
+        let err = abi::SolverFeeTooHigh::abi_decode(data).ok()?;
+        Some(SwooshError::SolverFeeTooHigh {
+            solver_fee: err.solverFee,
+            max_solver_fee: err.maxSolverFee,
+        })
+    } else if selector == abi::EscrowLocked::SELECTOR {
+        let err = abi::EscrowLocked::abi_decode(data).ok()?;
+        Some(SwooshError::EscrowLocked { intent_hash: err.intentHash })
+    } else if selector == abi::EmergencyModeLocked::SELECTOR {
+        let err = abi::EmergencyModeLocked::abi_decode(data).ok()?;
+        Some(SwooshError::EmergencyModeLocked { available_at: err.availableAt })
+    } else if selector == abi::LaneHalted::SELECTOR {
+        let err = abi::LaneHalted::abi_decode(data).ok()?;
+        Some(SwooshError::LaneHalted { chain_id: err.chainId })
+    } else if selector == abi::IntentBlocked::SELECTOR {
+        let err = abi::IntentBlocked::abi_decode(data).ok()?;
+        Some(SwooshError::IntentBlocked { intent_hash: err.intentHash })
+    } else if selector == abi::UntrustedConfigSource::SELECTOR {
+        let err = abi::UntrustedConfigSource::abi_decode(data).ok()?;
+        Some(SwooshError::UntrustedConfigSource {
+            source_chain: err.sourceChain,
+            messenger: err.messenger,
+        })
+    } else if selector == abi::ConfigSyncReplayed::SELECTOR {
+        let err = abi::ConfigSyncReplayed::abi_decode(data).ok()?;
+        Some(SwooshError::ConfigSyncReplayed {
+            source_chain: err.sourceChain,
+            nonce: err.nonce,
+        })
+    } else if selector == abi::ConfigEpochMismatch::SELECTOR {
+        let err = abi::ConfigEpochMismatch::abi_decode(data).ok()?;
+        Some(SwooshError::ConfigEpochMismatch {
+            epoch: err.epoch,
+            compatible_epoch: err.compatibleEpoch,
+            current_epoch: err.currentEpoch,
+        })
+    } else if selector == abi::TokenCapExceeded::SELECTOR {
+        let err = abi::TokenCapExceeded::abi_decode(data).ok()?;
+        Some(SwooshError::TokenCapExceeded { token: err.token, cap: err.cap })
+    } else if selector == abi::ListingRejected::SELECTOR {
+        let err = abi::ListingRejected::abi_decode(data).ok()?;
+        Some(SwooshError::ListingRejected { token: err.token, reason: err.reason })
+    } else if selector == abi::AlreadyReported::SELECTOR {
+        let err = abi::AlreadyReported::abi_decode(data).ok()?;
+        Some(SwooshError::AlreadyReported { intent_hash: err.intentHash })
+    } else if selector == abi::NoWatchReport::SELECTOR {
+        let err = abi::NoWatchReport::abi_decode(data).ok()?;
+        Some(SwooshError::NoWatchReport { intent_hash: err.intentHash })
+    } else if selector == abi::IntentHeld::SELECTOR {
+        let err = abi::IntentHeld::abi_decode(data).ok()?;
+        Some(SwooshError::IntentHeld {
+            intent_hash: err.intentHash,
+            held_until: err.heldUntil,
+        })
+    } else if selector == abi::SignerMismatch::SELECTOR {
+        let err = abi::SignerMismatch::abi_decode(data).ok()?;
+        Some(SwooshError::SignerMismatch { signer: err.signer, user: err.user })
+    } else if selector == abi::NoPriceSource::SELECTOR {
+        let err = abi::NoPriceSource::abi_decode(data).ok()?;
+        Some(SwooshError::NoPriceSource { token: err.token })
+    } else if selector == abi::InvalidNonce::SELECTOR {
+        let err = abi::InvalidNonce::abi_decode(data).ok()?;
+        Some(SwooshError::InvalidNonce { user: err.user, nonce: err.nonce, expected: err.expected })
+    } else if selector == abi::UnknownIntent::SELECTOR {
+        let err = abi::UnknownIntent::abi_decode(data).ok()?;
+        Some(SwooshError::UnknownIntent { intent_hash: err.intentHash })
+    } else if selector == abi::IntentNotPending::SELECTOR {
+        let err = abi::IntentNotPending::abi_decode(data).ok()?;
+        Some(SwooshError::IntentNotPending { intent_hash: err.intentHash, status: err.status })
+    } else if selector == abi::DelegationExceeded::SELECTOR {
+        let err = abi::DelegationExceeded::abi_decode(data).ok()?;
+        Some(SwooshError::DelegationExceeded {
+            delegate: err.delegate,
+            token: err.token,
+            amount: err.amount,
+            remaining: err.remaining,
+        })
+    } else if selector == abi::IncompatibleValidator::SELECTOR {
+        let err = abi::IncompatibleValidator::abi_decode(data).ok()?;
+        Some(SwooshError::IncompatibleValidator {
+            validator: err.validator,
+            reason: err.reason,
+        })
+    } else if selector == abi::InsufficientLiquidity::SELECTOR {
+        let err = abi::InsufficientLiquidity::abi_decode(data).ok()?;
+        Some(SwooshError::InsufficientLiquidity {
+            step_index: err.stepIndex,
+            expected_out: err.expectedOut,
+            min_out: err.minOut,
+        })
+    } else if selector == abi::GasLimitTooHigh::SELECTOR {
+        let err = abi::GasLimitTooHigh::abi_decode(data).ok()?;
+        Some(SwooshError::GasLimitTooHigh {
+            gas_limit: err.gasLimit,
+            max_gas_limit: err.maxGasLimit,
+        })
+    } else if selector == abi::StaleBlock::SELECTOR {
+        let err = abi::StaleBlock::abi_decode(data).ok()?;
+        Some(SwooshError::StaleBlock {
+            block_number: err.blockNumber,
+            oldest_block: err.oldestBlock,
+        })
+    } else if selector == abi::BlockHashMismatch::SELECTOR {
+        let err = abi::BlockHashMismatch::abi_decode(data).ok()?;
+        Some(SwooshError::BlockHashMismatch {
+            block_number: err.blockNumber,
+            block_hash: err.blockHash,
+        })
+    } else if selector == abi::SolverAtCapacity::SELECTOR {
+        let err = abi::SolverAtCapacity::abi_decode(data).ok()?;
+        Some(SwooshError::SolverAtCapacity { solver_id: err.solverId })
+    } else if selector == abi::IntentNotClaimable::SELECTOR {
+        let err = abi::IntentNotClaimable::abi_decode(data).ok()?;
+        Some(SwooshError::IntentNotClaimable { intent_hash: err.intentHash })
+    } else if selector == abi::ClaimActive::SELECTOR {
+        let err = abi::ClaimActive::abi_decode(data).ok()?;
+        Some(SwooshError::ClaimActive {
+            intent_hash: err.intentHash,
+            solver_id: err.solverId,
+            expires_at: err.expiresAt,
+        })
+    } else if selector == abi::InterfaceNotSupported::SELECTOR {
+        let err = abi::InterfaceNotSupported::abi_decode(data).ok()?;
+        Some(SwooshError::InterfaceNotSupported {
+            target: err.target,
+            interface_id: err.interfaceId,
+        })
+    } else if selector == abi::InvalidFillAmount::SELECTOR {
+        let err = abi::InvalidFillAmount::abi_decode(data).ok()?;
+        Some(SwooshError::InvalidFillAmount {
+            token: err.token,
+            amount: err.amount,
+            min_fill: err.minFill,
+            granularity: err.granularity,
+        })
+    } else if selector == abi::LaneCapExceeded::SELECTOR {
+        let err = abi::LaneCapExceeded::abi_decode(data).ok()?;
+        Some(SwooshError::LaneCapExceeded {
+            chain_id: err.chainId,
+            token: err.token,
+            amount: err.amount,
+            available: err.available,
+        })
+    } else if selector == abi::SimulationResult::SELECTOR {
+        let err = abi::SimulationResult::abi_decode(data).ok()?;
+        Some(SwooshError::SimulationResult {
+            amount_out: err.amountOut,
+            gas_used: err.gasUsed,
+            protocol_fee: err.protocolFee,
+            integrator_fee: err.integratorFee,
+            bridge_fee: err.bridgeFee,
+            gas_drop_cost: err.gasDropCost,
+        })
+    } else if selector == abi::InvalidDeliveryStatus::SELECTOR {
+        let err = abi::InvalidDeliveryStatus::abi_decode(data).ok()?;
+        Some(SwooshError::InvalidDeliveryStatus { status: err.status })
+    } else if selector == abi::InFlightLimitExceeded::SELECTOR {
+        let err = abi::InFlightLimitExceeded::abi_decode(data).ok()?;
+        Some(SwooshError::InFlightLimitExceeded {
+            solver_id: err.solverId,
+            limit: err.limit,
+        })
+    } else if selector == abi::InFlightNotionalExceeded::SELECTOR {
+        let err = abi::InFlightNotionalExceeded::abi_decode(data).ok()?;
+        Some(SwooshError::InFlightNotionalExceeded {
+            solver_id: err.solverId,
+            token: err.token,
+            limit: err.limit,
+        })
+    } else if selector == abi::ReporterNotAccepted::SELECTOR {
+        let err = abi::ReporterNotAccepted::abi_decode(data).ok()?;
+        Some(SwooshError::ReporterNotAccepted { reporter_type: err.reporterType })
+    } else if selector == abi::ChallengeWindowOpen::SELECTOR {
+        let err = abi::ChallengeWindowOpen::abi_decode(data).ok()?;
+        Some(SwooshError::ChallengeWindowOpen { final_at: err.finalAt })
+    } else if selector == abi::NotClaimable::SELECTOR {
+        let err = abi::NotClaimable::abi_decode(data).ok()?;
+        Some(SwooshError::NotClaimable { intent_id: err.intentId, status: err.status })
+    } else if selector == abi::UntrustedLane::SELECTOR {
+        let err = abi::UntrustedLane::abi_decode(data).ok()?;
+        Some(SwooshError::UntrustedLane {
+            source_chain: err.sourceChain,
+            messenger: err.messenger,
+        })
+    } else if selector == abi::MalformedPayload::SELECTOR {
+        let err = abi::MalformedPayload::abi_decode(data).ok()?;
+        Some(SwooshError::MalformedPayload { reason: err.reason })
+    } else if selector == abi::ClaimNotExpired::SELECTOR {
+        let err = abi::ClaimNotExpired::abi_decode(data).ok()?;
+        Some(SwooshError::ClaimNotExpired { expires_at: err.expiresAt })
+    } else if selector == abi::DustBelowThreshold::SELECTOR {
+        let err = abi::DustBelowThreshold::abi_decode(data).ok()?;
+        Some(SwooshError::DustBelowThreshold {
+            dust: err.dust,
+            threshold: err.threshold,
+        })
+    } else if selector == abi::ParameterOutOfBounds::SELECTOR {
+        let err = abi::ParameterOutOfBounds::abi_decode(data).ok()?;
+        Some(SwooshError::ParameterOutOfBounds {
+            param: err.param,
+            value: err.value,
+            min_value: err.minValue,
+            max_value: err.maxValue,
+        })
+    } else if selector == Revert::SELECTOR {
+        Some(SwooshError::Revert(Revert::abi_decode(data).ok()?.reason))
+    } else if selector == Panic::SELECTOR {
+        Some(SwooshError::Panic(Panic::abi_decode(data).ok()?.code))
+    } else {
+        None
+    }
+}
+
+/// Human-readable reason for an `InvalidRoute` code
+///
+/// Codes match `RouteError` in the contracts crate's route codec.
+pub fn route_reason(code: u8) -> &'static str {
+    match code {
+        1 => "route failed to decode",
+        2 => "route has no steps",
+        3 => "route has too many steps",
+        4 => "unknown step type",
+        5 => "step input does not match previous output",
+        6 => "bridge or transfer must be the last step",
+        7 => "swap adapter is not allowlisted",
+        8 => "swap output token is invalid",
+        9 => "amounts are inconsistent with the intent",
+        10 => "step deadline is later than the intent deadline",
+        11 => "native ETH used in an invalid step",
+        12 => "wrap/unwrap does not use the configured WETH, or the destination has no wrapped native",
+        13 => "bridge adapter allowlist is invalid",
+        14 => "NFT intents can't be routed",
+        15 => "hidden-recipient intents must end in a token bridge without a gas drop",
+        16 => "bridge options are invalid or combine a gas limit with a gas drop",
+        17 => "recipient doesn't match its chain family, or a non-EVM recipient's route isn't a plain token bridge",
+        _ => "unknown reason",
+    }
+}
+
+/// Human-readable reason for a `MalformedPayload` code, see `PayloadError`
+pub fn payload_reason(code: u8) -> &'static str {
+    match code {
+        1 => "payload is empty",
+        2 => "payload version is not supported",
+        3 => "payload has the wrong length",
+        4 => "payload is not a canonical encoding",
+        5 => "payload is the wrong kind for this receiver",
+        _ => "unknown reason",
+    }
+}
+
+impl fmt::Display for SwooshError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SwooshError::Unauthorized => write!(f, "caller is not authorized"),
+            SwooshError::InvalidAddress => write!(f, "invalid address"),
+            SwooshError::InvalidAmount => write!(f, "invalid amount"),
+            SwooshError::UnsupportedChain => write!(f, "destination chain is not supported"),
+            SwooshError::UnsupportedToken => write!(f, "token is not supported"),
+            SwooshError::InsufficientBalance => write!(f, "insufficient token balance"),
+            SwooshError::InsufficientAllowance => write!(f, "insufficient token allowance"),
+            SwooshError::ValidationFailed => write!(f, "intent validation failed"),
+            SwooshError::SwapFailed => write!(f, "swap failed"),
+            SwooshError::BridgeFailed => write!(f, "bridge failed"),
+            SwooshError::ContractPaused => write!(f, "contract is paused"),
+            SwooshError::ReentrancyGuard => write!(f, "reentrant call"),
+            SwooshError::AdapterNotAllowed => write!(f, "adapter is not allowlisted"),
+            SwooshError::TransferFailed => write!(f, "token transfer failed"),
+            SwooshError::SlippageExceeded { amount_out, min_amount_out } => {
+                write!(f, "slippage exceeded: got {amount_out}, minimum {min_amount_out}")
+            }
+            SwooshError::DeadlineExpired { deadline, timestamp } => {
+                write!(f, "deadline {deadline} expired at {timestamp}")
+            }
+            SwooshError::InvalidRoute { reason } => write!(f, "invalid route: {}", route_reason(*reason)),
+            SwooshError::UnsupportedStep => write!(f, "route step is not supported"),
+            SwooshError::FeeTooHigh { fee_bps, max_fee_bps } => {
+                write!(f, "fee of {fee_bps} bps exceeds the {max_fee_bps} bps cap")
+            }
+            SwooshError::SolverOnly => write!(f, "only registered solvers may execute"),
+            SwooshError::NotSolver => write!(f, "caller is not an active solver"),
+            SwooshError::InvalidSignature => write!(f, "invalid intent signature"),
+            SwooshError::IntentAlreadyUsed { intent_hash } => write!(f, "intent {intent_hash} already executed"),
+            SwooshError::CancelledIntent { intent_hash } => write!(f, "intent {intent_hash} was cancelled"),
+            SwooshError::RouteQuoteRequired => write!(f, "route needs a signed quote"),
+            SwooshError::InvalidRouteQuote => write!(f, "route quote does not match the intent or route"),
+            SwooshError::UnknownRouteSigner { signer } => write!(f, "{signer} is not a registered route oracle"),
+            SwooshError::InvalidMatch => write!(f, "intents cannot be matched against each other"),
+            SwooshError::NotSameAsset { token, destination_chain } => {
+                write!(f, "{token} has no same-asset counterpart on chain {destination_chain}")
+            }
+            SwooshError::DeadlineTooFar { deadline, latest } => {
+                write!(f, "deadline {deadline} is past the latest allowed {latest}")
+            }
+            SwooshError::GasDropTooHigh { gas_drop, max_gas_drop } => {
+                write!(f, "gas drop of {gas_drop} exceeds the {max_gas_drop} cap for the destination")
+            }
+            SwooshError::UnsupportedAsset { asset_type } => {
+                write!(f, "asset type {asset_type} is not supported by this entry point")
+            }
+            SwooshError::InvalidRecipientReveal => {
+                write!(f, "revealed recipient does not match the intent's commitment")
+            }
+            SwooshError::SolverFeeTooHigh { solver_fee, max_solver_fee } => {
+                write!(f, "solver fee of {solver_fee} exceeds the intent's {max_solver_fee} cap")
+            }
+            SwooshError::EscrowLocked { intent_hash } => {
+                write!(f, "escrow for intent {intent_hash} cannot be withdrawn yet")
+            }
+            SwooshError::EmergencyModeLocked { available_at } => {
+                write!(f, "emergency mode requires a pause lasting until {available_at}")
+            }
+            SwooshError::LaneHalted { chain_id } => write!(f, "lane to chain {chain_id} is halted"),
+            SwooshError::IntentBlocked { intent_hash } => write!(f, "intent {intent_hash} is blocked"),
+            SwooshError::UntrustedConfigSource { source_chain, messenger } => {
+                write!(f, "{messenger} may not deliver config sync from chain {source_chain}")
+            }
+            SwooshError::InvalidConfigMessage => write!(f, "config-sync message could not be decoded"),
+            SwooshError::ConfigSyncReplayed { source_chain, nonce } => {
+                write!(f, "config sync {nonce} from chain {source_chain} was already applied")
+            }
+            SwooshError::ConfigSyncFailed => write!(f, "sending or applying a config-sync message failed"),
+            SwooshError::InvalidConfigPage => write!(f, "config page could not be decoded or has an unknown entry"),
+            SwooshError::ConfigImportClosed => write!(f, "config import is sealed on this deployment"),
+            SwooshError::ConfigEpochMismatch { epoch, compatible_epoch, current_epoch } => {
+                write!(f, "config epoch {epoch} is outside the accepted {compatible_epoch} to {current_epoch}")
+            }
+            SwooshError::InvalidListingMode => write!(f, "token listing mode is unknown"),
+            SwooshError::TokenCapExceeded { token, cap } => {
+                write!(f, "intents in permissionless-listed token {token} are capped at {cap}")
+            }
+            SwooshError::ListingRejected { token, reason } => {
+                let why = match reason {
+                    1 => "it is blocked",
+                    2 => "it doesn't behave like an ERC-20",
+                    3 => "it has more than 18 decimals",
+                    4 => "no price oracle or TWAP pool prices it",
+                    _ => "it fails the listing criteria",
+                };
+                write!(f, "token {token} can't be listed: {why}")
+            }
+            SwooshError::DelegationExceeded { delegate, token, amount, remaining } => {
+                write!(f, "delegate {delegate} may submit only {remaining} more of {token}, not {amount}")
+            }
+            SwooshError::ReportsDisabled => write!(f, "watchtower reports are turned off"),
+            SwooshError::AlreadyReported { intent_hash } => write!(f, "intent {intent_hash} was already reported"),
+            SwooshError::NoWatchReport { intent_hash } => {
+                write!(f, "intent {intent_hash} has no open watchtower report")
+            }
+            SwooshError::IntentHeld { intent_hash, held_until } => {
+                write!(f, "intent {intent_hash} is held by a watchtower report until {held_until}")
+            }
+            SwooshError::SignerMismatch { signer, user } => {
+                write!(f, "intent is signed by {signer}, not its user {user}")
+            }
+            SwooshError::NoPriceSource { token } => {
+                write!(f, "no feed, TWAP or static bound can price token {token}")
+            }
+            SwooshError::InvalidNonce { user, nonce, expected } => {
+                write!(f, "registry nonce {nonce} for {user} is not the expected {expected}")
+            }
+            SwooshError::UnknownIntent { intent_hash } => write!(f, "intent {intent_hash} is not registered"),
+            SwooshError::IntentNotPending { intent_hash, status } => {
+                let state = match status {
+                    2 => "executed",
+                    3 => "cancelled",
+                    4 => "expired",
+                    _ => "not pending",
+                };
+                write!(f, "registered intent {intent_hash} is {state}")
+            }
+            SwooshError::IntentOutOfRange => {
+                write!(f, "intent nonce, deadline or destination chain doesn't fit 64 bits")
+            }
+            SwooshError::IncompatibleValidator { validator, reason } => {
+                let why = match reason {
+                    1 => "interface version differs",
+                    2 => "it reads intent fields the executor does not pass",
+                    3 => "it lacks checks the executor calls",
+                    _ => "self_check failed",
+                };
+                write!(f, "validator {validator} is incompatible: {why}")
+            }
+            SwooshError::InsufficientLiquidity { step_index, expected_out, min_out } => {
+                write!(f, "swap step {step_index} quotes {expected_out}, short of the {min_out} required")
+            }
+            SwooshError::GasLimitTooHigh { gas_limit, max_gas_limit } => {
+                write!(f, "gas limit of {gas_limit} exceeds the {max_gas_limit} cap for the destination")
+            }
+            SwooshError::StaleBlock { block_number, oldest_block } => {
+                write!(f, "intent is bound to block {block_number}, outside the window starting at {oldest_block}")
+            }
+            SwooshError::BlockHashMismatch { block_number, block_hash } => {
+                write!(f, "block {block_number} no longer has hash {block_hash}; the intent was signed before a reorg")
+            }
+            SwooshError::SolverAtCapacity { solver_id } => {
+                write!(f, "solver {solver_id} has no room for another in-flight intent")
+            }
+            SwooshError::ClaimsDisabled => write!(f, "intent claims are turned off"),
+            SwooshError::IntentNotClaimable { intent_hash } => {
+                write!(f, "intent {intent_hash} is not queued or past its deadline")
+            }
+            SwooshError::ClaimActive { intent_hash, solver_id, expires_at } => {
+                write!(f, "intent {intent_hash} is claimed by solver {solver_id} until {expires_at}")
+            }
+            SwooshError::InterfaceNotSupported { target, interface_id } => {
+                write!(f, "{target} does not advertise interface {interface_id} through ERC-165")
+            }
+            SwooshError::InvalidFillAmount { token, amount, min_fill, granularity } => {
+                write!(f, "fill of {amount} {token} is below {min_fill} or not a multiple of {granularity}")
+            }
+            SwooshError::LaneCapExceeded { chain_id, token, amount, available } => {
+                write!(f, "lane to chain {chain_id} has room for {available} of {token}, not {amount}")
+            }
+            SwooshError::InvalidApprovalPolicy => write!(f, "unknown adapter approval policy"),
+            SwooshError::SimulationResult { amount_out, gas_used, protocol_fee, integrator_fee, bridge_fee, .. } => {
+                write!(
+                    f,
+                    "simulated route delivers {amount_out} using {gas_used} gas \
+                     (protocol fee {protocol_fee}, integrator fee {integrator_fee}, bridge fee {bridge_fee})"
+                )
+            }
+            SwooshError::InvalidMessageId => write!(f, "invalid bridge message ID"),
+            SwooshError::InvalidIntentId => write!(f, "unknown intent ID"),
+            SwooshError::SettlementTimeout => write!(f, "settlement timed out"),
+            SwooshError::AlreadyProcessed => write!(f, "settlement already processed"),
+            SwooshError::RefundFailed => write!(f, "refund failed"),
+            SwooshError::InvalidVaa => write!(f, "Wormhole VAA failed verification"),
+            SwooshError::OrderNotFulfilled => write!(f, "DLN order has not been fulfilled"),
+            SwooshError::InvalidDeliveryStatus { status } => write!(f, "{status} is not a delivery status"),
+            SwooshError::InvalidBatch => write!(f, "delivery batch entries are mismatched or too many"),
+            SwooshError::InFlightLimitExceeded { solver_id, limit } => {
+                write!(f, "solver {solver_id} already has {limit} intents in flight")
+            }
+            SwooshError::InFlightNotionalExceeded { solver_id, token, limit } => {
+                write!(f, "solver {solver_id} would exceed its {limit} in-flight cap for {token}")
+            }
+            SwooshError::AttestationFailed => write!(f, "attestations are off or EAS rejected the attestation"),
+            SwooshError::InvalidCallback => write!(f, "intent callback is invalid or its data is too long"),
+            SwooshError::InvalidLanePolicy => write!(f, "lane finality policy is invalid"),
+            SwooshError::ReporterNotAccepted { reporter_type } => {
+                write!(f, "reporter type {reporter_type} is not accepted on this lane")
+            }
+            SwooshError::NoPendingDelivery => write!(f, "intent has no pending delivery"),
+            SwooshError::ChallengeWindowOpen { final_at } => {
+                write!(f, "delivery can't be finalized before {final_at}")
+            }
+            SwooshError::InsuranceUnavailable => write!(f, "insurance is off or the intent can't be insured"),
+            SwooshError::NotClaimable { intent_id, status } => {
+                write!(f, "intent {intent_id} is not claimable in settlement status {status}")
+            }
+            SwooshError::ClaimFailed => write!(f, "insurance fund couldn't pay the claim"),
+            SwooshError::InvalidRetryPolicy => write!(f, "retry policy is malformed or the route can't be retried"),
+            SwooshError::DrillsDisabled => write!(f, "drill hooks are only available in testnet builds"),
+            SwooshError::UntrustedLane { source_chain, messenger } => {
+                write!(f, "{messenger} is not an allowed lane from chain {source_chain}")
+            }
+            SwooshError::InvalidPayload => write!(f, "bridge payload could not be decoded"),
+            SwooshError::MalformedPayload { reason } => write!(f, "malformed payload: {}", payload_reason(*reason)),
+            SwooshError::VerifierCallFailed => write!(f, "reporting the delivery to the verifier failed"),
+            SwooshError::NothingToClaim => write!(f, "nothing to claim for this token"),
+            SwooshError::ClaimNotExpired { expires_at } => write!(f, "claim can't be swept before {expires_at}"),
+            SwooshError::DustBelowThreshold { dust, threshold } => {
+                write!(f, "dust of {dust} is under the {threshold} sweep threshold")
+            }
+            SwooshError::ParameterOutOfBounds { param, value, min_value, max_value } => {
+                write!(f, "parameter {param} can't be {value}; it must be between {min_value} and {max_value}")
+            }
+            SwooshError::Revert(reason) => write!(f, "reverted: {reason}"),
+            SwooshError::Panic(code) => write!(f, "panicked with code {code}"),
+        }
+    }
+}
+
+impl std::error::Error for SwooshError {}
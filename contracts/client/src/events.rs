@@ -0,0 +1,209 @@
+//! Intent Lifecycle Events
+//!
+//! Typed mirrors of the RouteExecutor and SettlementVerifier events that track
+//! an intent from execution to settlement or refund, and decoding of raw logs
+//! into a single lifecycle enum. Per-step logs from RouteExecutor and
+//! SwooshReceiver are included so a route can be traced hop by hop.
+
+use alloy_primitives::{Address, B256, U256};
+use alloy_sol_types::{sol, SolEvent};
+
+// Event signatures as defined in `swoosh_common::events`, which RouteExecutor,
+// SwooshReceiver and SettlementVerifier all emit from
+sol! {
+    event IntentExecuted(
+        uint256 indexed intentId,
+        address indexed user,
+        uint256 indexed destinationChain,
+        address tokenIn,
+        uint256 timestamp
+    );
+
+    event IntentMemo(uint256 indexed intentId, bytes32 indexed memo);
+
+    event RouteStepExecuted(
+        uint256 indexed intentId,
+        uint256 stepIndex,
+        uint8 stepType,
+        address indexed adapter,
+        address tokenIn,
+        address tokenOut,
+        uint256 amountIn,
+        uint256 amountOut,
+        bool success
+    );
+
+    event BridgeInitiated(
+        uint256 indexed intentId,
+        address indexed token,
+        uint256 amount,
+        uint256 indexed destinationChain,
+        address recipient
+    );
+
+    event SettlementConfirmed(
+        uint256 indexed intentId,
+        bytes32 indexed messageId,
+        uint256 timestamp
+    );
+
+    event SettlementRetried(uint256 indexed intentId, uint256 attempt, uint256 timestamp);
+
+    event RefundInitiated(
+        uint256 indexed intentId,
+        address indexed user,
+        address token,
+        uint256 amount
+    );
+}
+
+/// Stage an intent reached, with the stage-specific fields
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IntentEventKind {
+    /// Route executed on the source chain
+    Executed {
+        user: Address,
+        token_in: Address,
+        destination_chain: U256,
+        timestamp: U256,
+    },
+    /// Memo the intent was signed with, logged alongside its execution
+    Memo { memo: B256 },
+    /// One route step ran (`success` is false for a destination swap that
+    /// fell back to delivering the bridged token)
+    StepExecuted {
+        step_index: U256,
+        step_type: u8,
+        adapter: Address,
+        token_in: Address,
+        token_out: Address,
+        amount_in: U256,
+        amount_out: U256,
+        success: bool,
+    },
+    /// Funds handed to a bridge lane
+    BridgeInitiated {
+        token: Address,
+        amount: U256,
+        destination_chain: U256,
+        recipient: Address,
+    },
+    /// Delivery confirmed on the destination chain
+    SettlementConfirmed { message_id: B256, timestamp: U256 },
+    /// Delivery timed out and the intent's retry policy opened another
+    /// attempt, restarting the settlement clock at `timestamp`
+    SettlementRetried { attempt: U256, timestamp: U256 },
+    /// Settlement failed and the user is being refunded
+    RefundInitiated { user: Address, token: Address, amount: U256 },
+}
+
+/// Where a log was found on chain
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LogMeta {
+    pub address: Address,
+    pub block_number: u64,
+    pub block_hash: B256,
+    pub transaction_hash: B256,
+    pub log_index: u64,
+}
+
+/// Decoded lifecycle event for one intent
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntentEvent {
+    pub intent_id: U256,
+    pub kind: IntentEventKind,
+    pub meta: LogMeta,
+}
+
+/// Topic0 of every lifecycle event, for log filters
+pub fn lifecycle_topics() -> [B256; 7] {
+    [
+        IntentExecuted::SIGNATURE_HASH,
+        IntentMemo::SIGNATURE_HASH,
+        RouteStepExecuted::SIGNATURE_HASH,
+        BridgeInitiated::SIGNATURE_HASH,
+        SettlementConfirmed::SIGNATURE_HASH,
+        SettlementRetried::SIGNATURE_HASH,
+        RefundInitiated::SIGNATURE_HASH,
+    ]
+}
+
+/// Decode a raw log into its intent ID and lifecycle stage
+///
+/// Returns `None` for logs that are not lifecycle events or fail to decode.
+pub fn decode_lifecycle_log(topics: &[B256], data: &[u8]) -> Option<(U256, IntentEventKind)> {
+    let topic0 = *topics.first()?;
+    let words = topics.iter().copied();
+
+    if topic0 == IntentExecuted::SIGNATURE_HASH {
+        let event = IntentExecuted::decode_raw_log(words, data).ok()?;
+        Some((
+            event.intentId,
+            IntentEventKind::Executed {
+                user: event.user,
+                token_in: event.tokenIn,
+                destination_chain: event.destinationChain,
+                timestamp: event.timestamp,
+            },
+        ))
+    } else if topic0 == IntentMemo::SIGNATURE_HASH {
+        let event = IntentMemo::decode_raw_log(words, data).ok()?;
+        Some((event.intentId, IntentEventKind::Memo { memo: event.memo }))
+    } else if topic0 == RouteStepExecuted::SIGNATURE_HASH {
+        let event = RouteStepExecuted::decode_raw_log(words, data).ok()?;
+        Some((
+            event.intentId,
+            IntentEventKind::StepExecuted {
+                step_index: event.stepIndex,
+                step_type: event.stepType,
+                adapter: event.adapter,
+                token_in: event.tokenIn,
+                token_out: event.tokenOut,
+                amount_in: event.amountIn,
+                amount_out: event.amountOut,
+                success: event.success,
+            },
+        ))
+    } else if topic0 == BridgeInitiated::SIGNATURE_HASH {
+        let event = BridgeInitiated::decode_raw_log(words, data).ok()?;
+        Some((
+            event.intentId,
+            IntentEventKind::BridgeInitiated {
+                token: event.token,
+                amount: event.amount,
+                destination_chain: event.destinationChain,
+                recipient: event.recipient,
+            },
+        ))
+    } else if topic0 == SettlementConfirmed::SIGNATURE_HASH {
+        let event = SettlementConfirmed::decode_raw_log(words, data).ok()?;
+        Some((
+            event.intentId,
+            IntentEventKind::SettlementConfirmed {
+                message_id: event.messageId,
+                timestamp: event.timestamp,
+            },
+        ))
+    } else if topic0 == SettlementRetried::SIGNATURE_HASH {
+        let event = SettlementRetried::decode_raw_log(words, data).ok()?;
+        Some((
+            event.intentId,
+            IntentEventKind::SettlementRetried {
+                attempt: event.attempt,
+                timestamp: event.timestamp,
+            },
+        ))
+    } else if topic0 == RefundInitiated::SIGNATURE_HASH {
+        let event = RefundInitiated::decode_raw_log(words, data).ok()?;
+        Some((
+            event.intentId,
+            IntentEventKind::RefundInitiated {
+                user: event.user,
+                token: event.token,
+                amount: event.amount,
+            },
+        ))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,278 @@
+//! Event Indexer
+//!
+//! Follows RouteExecutor and SettlementVerifier logs from a block checkpoint
+//! and yields a unified intent-lifecycle stream. Logs are only read up to
+//! `head - confirmations`; the hashes of processed batch boundaries are kept
+//! so a reorg below that depth is detected on the next poll, reported as a
+//! `LifecycleUpdate::Reorg`, and the affected range is re-read.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use alloy_primitives::{Address, B256};
+use ethers::providers::Middleware;
+use ethers::types::{Filter, Log, H160, H256};
+use futures::stream::{self, Stream};
+
+use crate::events::{decode_lifecycle_log, lifecycle_topics, IntentEvent, LogMeta};
+
+/// Default blocks to wait before reading a block's logs
+pub const DEFAULT_CONFIRMATIONS: u64 = 12;
+/// Default blocks per `eth_getLogs` request
+pub const DEFAULT_BATCH_SIZE: u64 = 2_000;
+/// Default delay between polls once caught up
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// Default number of batch boundaries kept for reorg detection
+pub const DEFAULT_REORG_WINDOW: usize = 64;
+
+/// Indexer settings
+#[derive(Clone, Debug)]
+pub struct IndexerConfig {
+    pub route_executor: Address,
+    pub settlement_verifier: Address,
+    /// First block to read when no checkpoint is given
+    pub start_block: u64,
+    pub confirmations: u64,
+    pub batch_size: u64,
+    pub poll_interval: Duration,
+    pub reorg_window: usize,
+}
+
+impl IndexerConfig {
+    /// Config with default confirmations, batching and polling
+    pub fn new(route_executor: Address, settlement_verifier: Address, start_block: u64) -> Self {
+        Self {
+            route_executor,
+            settlement_verifier,
+            start_block,
+            confirmations: DEFAULT_CONFIRMATIONS,
+            batch_size: DEFAULT_BATCH_SIZE,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            reorg_window: DEFAULT_REORG_WINDOW,
+        }
+    }
+}
+
+/// Last block fully processed, for resuming after a restart
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Checkpoint {
+    pub block_number: u64,
+    pub block_hash: B256,
+}
+
+/// Item yielded by the lifecycle stream
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LifecycleUpdate {
+    /// A decoded lifecycle event
+    Event(Box<IntentEvent>),
+    /// Chain reorganized; discard events from blocks above `fork_block`,
+    /// they will be re-emitted from the canonical chain
+    Reorg { fork_block: u64 },
+}
+
+/// Indexer failures
+#[derive(Debug)]
+pub enum IndexerError {
+    /// RPC request failed
+    Provider(String),
+    /// Node returned no block (or no hash) for a number it should know
+    MissingBlock(u64),
+}
+
+impl fmt::Display for IndexerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IndexerError::Provider(err) => write!(f, "provider error: {err}"),
+            IndexerError::MissingBlock(number) => write!(f, "missing block {number}"),
+        }
+    }
+}
+
+impl std::error::Error for IndexerError {}
+
+/// Highest recorded block whose hash is still canonical
+///
+/// `canonical` holds the node's current hash for each recorded block number
+/// (absent if the node no longer has it).
+pub fn fork_point(recorded: &BTreeMap<u64, B256>, canonical: &BTreeMap<u64, B256>) -> Option<u64> {
+    recorded
+        .iter()
+        .rev()
+        .find(|(number, hash)| canonical.get(number) == Some(hash))
+        .map(|(number, _)| *number)
+}
+
+/// Polling log follower for one Swoosh deployment
+pub struct EventIndexer<M> {
+    provider: Arc<M>,
+    config: IndexerConfig,
+    next_block: u64,
+    recent: BTreeMap<u64, B256>,
+}
+
+impl<M: Middleware + 'static> EventIndexer<M> {
+    /// Start indexing at `config.start_block`
+    pub fn new(provider: Arc<M>, config: IndexerConfig) -> Self {
+        let next_block = config.start_block;
+        Self {
+            provider,
+            config,
+            next_block,
+            recent: BTreeMap::new(),
+        }
+    }
+
+    /// Resume after a previously saved checkpoint
+    ///
+    /// A reorg of the checkpoint block itself is detected on the first poll.
+    pub fn resume(provider: Arc<M>, config: IndexerConfig, checkpoint: Checkpoint) -> Self {
+        let mut indexer = Self::new(provider, config);
+        indexer.next_block = checkpoint.block_number + 1;
+        indexer.recent.insert(checkpoint.block_number, checkpoint.block_hash);
+        indexer
+    }
+
+    /// Last fully processed block
+    pub fn checkpoint(&self) -> Option<Checkpoint> {
+        self.recent.iter().next_back().map(|(number, hash)| Checkpoint {
+            block_number: *number,
+            block_hash: *hash,
+        })
+    }
+
+    /// Check for reorgs, then read every confirmed block not yet processed
+    pub async fn poll(&mut self) -> Result<Vec<LifecycleUpdate>, IndexerError> {
+        let mut updates = Vec::new();
+
+        if let Some(fork_block) = self.detect_reorg().await? {
+            updates.push(LifecycleUpdate::Reorg { fork_block });
+        }
+
+        let head = self
+            .provider
+            .get_block_number()
+            .await
+            .map_err(|err| IndexerError::Provider(err.to_string()))?
+            .as_u64();
+        let safe_head = head.saturating_sub(self.config.confirmations);
+        let batch_size = self.config.batch_size.max(1);
+
+        while self.next_block <= safe_head {
+            let to_block = (self.next_block + batch_size - 1).min(safe_head);
+            let filter = Filter::new()
+                .address(vec![to_h160(self.config.route_executor), to_h160(self.config.settlement_verifier)])
+                .topic0(lifecycle_topics().iter().map(|topic| H256(topic.0)).collect::<Vec<_>>())
+                .from_block(self.next_block)
+                .to_block(to_block);
+
+            let logs = self
+                .provider
+                .get_logs(&filter)
+                .await
+                .map_err(|err| IndexerError::Provider(err.to_string()))?;
+            updates.extend(logs.iter().filter_map(to_event).map(|event| LifecycleUpdate::Event(Box::new(event))));
+
+            let hash = self
+                .block_hash(to_block)
+                .await?
+                .ok_or(IndexerError::MissingBlock(to_block))?;
+            self.record(to_block, hash);
+            self.next_block = to_block + 1;
+        }
+
+        Ok(updates)
+    }
+
+    /// Turn the indexer into an endless lifecycle stream
+    ///
+    /// Sleeps `poll_interval` whenever it is caught up. Errors are yielded and
+    /// polling continues, so callers decide whether to stop.
+    pub fn into_stream(self) -> impl Stream<Item = Result<LifecycleUpdate, IndexerError>> {
+        stream::unfold((self, VecDeque::new()), |(mut indexer, mut pending)| async move {
+            loop {
+                if let Some(update) = pending.pop_front() {
+                    return Some((Ok(update), (indexer, pending)));
+                }
+
+                match indexer.poll().await {
+                    Ok(updates) if updates.is_empty() => tokio::time::sleep(indexer.config.poll_interval).await,
+                    Ok(updates) => pending.extend(updates),
+                    Err(err) => return Some((Err(err), (indexer, pending))),
+                }
+            }
+        })
+    }
+
+    /// Internal: Compare recorded block hashes with the chain, rewinding on mismatch
+    async fn detect_reorg(&mut self) -> Result<Option<u64>, IndexerError> {
+        let Some((&last, &last_hash)) = self.recent.iter().next_back() else {
+            return Ok(None);
+        };
+
+        if self.block_hash(last).await? == Some(last_hash) {
+            return Ok(None);
+        }
+
+        let mut canonical = BTreeMap::new();
+        for number in self.recent.keys().copied().collect::<Vec<_>>() {
+            if let Some(hash) = self.block_hash(number).await? {
+                canonical.insert(number, hash);
+            }
+        }
+
+        // Deeper than the window: re-read everything from the start block
+        let fork_block = fork_point(&self.recent, &canonical)
+            .unwrap_or_else(|| self.config.start_block.saturating_sub(1));
+        self.recent.retain(|number, _| *number <= fork_block);
+        self.next_block = fork_block + 1;
+
+        Ok(Some(fork_block))
+    }
+
+    /// Internal: Current hash of a block, if the node has it
+    async fn block_hash(&self, number: u64) -> Result<Option<B256>, IndexerError> {
+        let block = self
+            .provider
+            .get_block(number)
+            .await
+            .map_err(|err| IndexerError::Provider(err.to_string()))?;
+        Ok(block.and_then(|block| block.hash).map(|hash| B256::from(hash.0)))
+    }
+
+    /// Internal: Remember a processed batch boundary, pruning the window
+    fn record(&mut self, number: u64, hash: B256) {
+        self.recent.insert(number, hash);
+        while self.recent.len() > self.config.reorg_window.max(1) {
+            self.recent.pop_first();
+        }
+    }
+}
+
+/// Convert an ethers log into a lifecycle event, skipping removed or foreign logs
+fn to_event(log: &Log) -> Option<IntentEvent> {
+    if log.removed == Some(true) {
+        return None;
+    }
+
+    let topics: Vec<B256> = log.topics.iter().map(|topic| B256::from(topic.0)).collect();
+    let (intent_id, kind) = decode_lifecycle_log(&topics, &log.data)?;
+
+    Some(IntentEvent {
+        intent_id,
+        kind,
+        meta: LogMeta {
+            address: Address::from(log.address.0),
+            block_number: log.block_number?.as_u64(),
+            block_hash: B256::from(log.block_hash?.0),
+            transaction_hash: B256::from(log.transaction_hash?.0),
+            log_index: log.log_index?.as_u64(),
+        },
+    })
+}
+
+/// Convert an alloy address to the ethers type
+fn to_h160(address: Address) -> H160 {
+    H160::from_slice(address.as_slice())
+}
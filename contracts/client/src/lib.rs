@@ -0,0 +1,17 @@
+//! Swoosh Client
+//!
+//! Off-chain helpers for solver bots, relayers and frontends talking to the
+//! Swoosh contracts, including static validation of routes before they are
+//! sent.
+
+pub mod admin;
+pub mod errors;
+pub mod events;
+pub mod indexer;
+pub mod route;
+
+pub use admin::AdminCall;
+pub use errors::{decode_error, SwooshError};
+pub use events::{IntentEvent, IntentEventKind, LogMeta};
+pub use indexer::{Checkpoint, EventIndexer, IndexerConfig, IndexerError, LifecycleUpdate};
+pub use route::{validate_route, RouteError};
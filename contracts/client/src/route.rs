@@ -0,0 +1,326 @@
+//! Route Validation
+//!
+//! Off-chain copy of the static route checks RouteExecutor runs before it
+//! pulls any funds (`route_codec` in swoosh-common), so routers and solver
+//! bots can reject a bad `RouteStep[]` before paying gas for the revert.
+//! Reasons carry the same codes as the on-chain `InvalidRoute` revert and
+//! read the same through `route_reason`.
+//!
+//! The checks are static: whether the lane has an adapter, the validator
+//! accepts the token or the swaps quote enough is only known on-chain.
+
+use std::fmt;
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use alloy_sol_types::{sol, SolValue};
+
+use crate::errors::route_reason;
+
+// Types as defined in `swoosh_common::encoding` and `route_codec`
+sol! {
+    #[derive(Debug, PartialEq, Eq)]
+    struct Intent {
+        address user;
+        address tokenIn;
+        uint256 amountIn;
+        uint256 destinationChain;
+        address recipient;
+        uint256 minAmountOut;
+        uint256 deadline;
+        uint256 nonce;
+        uint256 gasDrop;
+        uint8 assetType;
+        uint256 tokenId;
+        bytes32 recipientHash;
+        uint256 maxSolverFee;
+        uint256 priorityFee;
+        uint256 blockNumber;
+        bytes32 blockHash;
+        uint256 configEpoch;
+        address callback;
+        bytes callbackData;
+        uint8 recipientFamily;
+        bytes recipientAddress;
+        uint256 insurancePremiumBps;
+        uint256 retryPolicy;
+        bytes32 memo;
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct RouteStep {
+        uint8 stepType;
+        address adapter;
+        address tokenIn;
+        address tokenOut;
+        uint256 minAmountOut;
+        uint256 deadline;
+        address recipient;
+        bytes data;
+    }
+
+    struct BridgeOptions {
+        address[] adapters;
+        uint256 gasLimit;
+    }
+}
+
+/// Native ETH, as a token address
+pub const NATIVE_TOKEN: Address = Address::ZERO;
+
+/// Maximum number of steps a route may contain
+pub const MAX_ROUTE_STEPS: usize = 8;
+
+/// Maximum number of bridge adapters a Bridge step may ask to compare
+pub const MAX_LANE_CANDIDATES: usize = 4;
+
+/// Prefix telling `BridgeOptions` apart from a bare adapter allowlist
+pub const BRIDGE_OPTIONS_TAG: [u8; 4] = *b"SWbo";
+
+// `stepType` discriminators
+pub const STEP_SWAP: u8 = 0;
+pub const STEP_BRIDGE: u8 = 1;
+pub const STEP_WRAP: u8 = 2;
+pub const STEP_UNWRAP: u8 = 3;
+pub const STEP_TRANSFER: u8 = 4;
+
+/// `assetType` of an ERC-20 (or native) intent
+pub const ASSET_ERC20: u8 = 0;
+
+/// `recipientFamily` of an EVM recipient
+pub const FAMILY_EVM: u8 = 0;
+
+/// Reasons a route is rejected, with the on-chain reason codes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RouteError {
+    Malformed = 1,
+    Empty = 2,
+    TooManySteps = 3,
+    UnknownStep = 4,
+    BrokenPath = 5,
+    BadTerminal = 6,
+    AdapterNotAllowed = 7,
+    BadSwapTokens = 8,
+    InconsistentAmounts = 9,
+    InconsistentDeadline = 10,
+    BadNativeStep = 11,
+    WrongWrappedNative = 12,
+    BadAdapterAllowlist = 13,
+    UnsupportedAsset = 14,
+    HiddenRecipient = 15,
+    BadBridgeOptions = 16,
+    BadRecipient = 17,
+}
+
+impl RouteError {
+    /// Reason code RouteExecutor reverts with in `InvalidRoute`
+    pub fn code(self) -> u8 {
+        self as u8
+    }
+}
+
+impl fmt::Display for RouteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(route_reason(self.code()))
+    }
+}
+
+impl std::error::Error for RouteError {}
+
+/// Decode an ABI-encoded `Intent`
+pub fn decode_intent(data: &[u8]) -> Result<Intent, RouteError> {
+    Intent::abi_decode(data).map_err(|_| RouteError::Malformed)
+}
+
+/// Decode an ABI-encoded `RouteStep[]`
+pub fn decode_route(data: &[u8]) -> Result<Vec<RouteStep>, RouteError> {
+    Vec::<RouteStep>::abi_decode(data).map_err(|_| RouteError::Malformed)
+}
+
+/// Decode a Bridge step's `data` into its adapter allowlist (empty for none)
+/// and destination gas limit (zero for the lane default)
+pub fn decode_bridge_step_data(data: &[u8]) -> Result<(Vec<Address>, U256), RouteError> {
+    if data.is_empty() {
+        return Ok((Vec::new(), U256::ZERO));
+    }
+
+    let Some(encoded) = data.strip_prefix(&BRIDGE_OPTIONS_TAG) else {
+        let adapters = Vec::<Address>::abi_decode(data).map_err(|_| RouteError::BadAdapterAllowlist)?;
+        if adapters.is_empty() || adapters.len() > MAX_LANE_CANDIDATES {
+            return Err(RouteError::BadAdapterAllowlist);
+        }
+        return Ok((adapters, U256::ZERO));
+    };
+    let options = BridgeOptions::abi_decode(encoded).map_err(|_| RouteError::BadBridgeOptions)?;
+    if options.adapters.len() > MAX_LANE_CANDIDATES {
+        return Err(RouteError::BadAdapterAllowlist);
+    }
+    Ok((options.adapters, options.gasLimit))
+}
+
+/// Check an intent's recipient fields against its chain family, returning
+/// whether the recipient is on an EVM chain
+pub fn check_recipient(intent: &Intent) -> Result<bool, RouteError> {
+    let address = &intent.recipientAddress;
+    match intent.recipientFamily {
+        FAMILY_EVM => {
+            return if address.is_empty() { Ok(true) } else { Err(RouteError::BadRecipient) };
+        }
+        // Solana takes 32 bytes, Cosmos 20 or 32
+        1 if address.len() == 32 => {}
+        2 if address.len() == 20 || address.len() == 32 => {}
+        _ => return Err(RouteError::BadRecipient),
+    }
+    if address.iter().all(|byte| *byte == 0)
+        || intent.recipient != Address::ZERO
+        || intent.recipientHash != FixedBytes::ZERO
+    {
+        return Err(RouteError::BadRecipient);
+    }
+    Ok(false)
+}
+
+/// Statically validate a route against its intent, as RouteExecutor does
+///
+/// `weth` is the executor's wrapped native token and `is_adapter_allowed`
+/// says whether a swap adapter is on its allowlist. Checks run in the
+/// contract's order, so the first failure is the reason the contract would
+/// revert with.
+pub fn validate_route<F>(
+    intent: &Intent,
+    steps: &[RouteStep],
+    weth: Address,
+    is_adapter_allowed: F,
+) -> Result<(), RouteError>
+where
+    F: Fn(Address) -> bool,
+{
+    if intent.assetType != ASSET_ERC20 {
+        return Err(RouteError::UnsupportedAsset);
+    }
+    if intent.amountIn == U256::ZERO {
+        return Err(RouteError::InconsistentAmounts);
+    }
+    if steps.is_empty() {
+        return Err(RouteError::Empty);
+    }
+    if steps.len() > MAX_ROUTE_STEPS {
+        return Err(RouteError::TooManySteps);
+    }
+
+    let last_index = steps.len() - 1;
+    let mut current_token = intent.tokenIn;
+    let mut last_swap_min_out = None;
+
+    for (index, step) in steps.iter().enumerate() {
+        if step.stepType > STEP_TRANSFER {
+            return Err(RouteError::UnknownStep);
+        }
+        if step.tokenIn != current_token {
+            return Err(RouteError::BrokenPath);
+        }
+        let terminal = step.stepType == STEP_BRIDGE || step.stepType == STEP_TRANSFER;
+        if terminal != (index == last_index) {
+            return Err(RouteError::BadTerminal);
+        }
+        if step.deadline != U256::ZERO && step.deadline > intent.deadline {
+            return Err(RouteError::InconsistentDeadline);
+        }
+
+        match step.stepType {
+            STEP_SWAP => {
+                if !is_adapter_allowed(step.adapter) {
+                    return Err(RouteError::AdapterNotAllowed);
+                }
+                if step.tokenIn == NATIVE_TOKEN {
+                    return Err(RouteError::BadNativeStep);
+                }
+                if step.tokenOut == NATIVE_TOKEN || step.tokenOut == step.tokenIn {
+                    return Err(RouteError::BadSwapTokens);
+                }
+                last_swap_min_out = Some(step.minAmountOut);
+            }
+            STEP_WRAP => {
+                if step.tokenIn != NATIVE_TOKEN {
+                    return Err(RouteError::BadNativeStep);
+                }
+                if weth == Address::ZERO || step.tokenOut != weth {
+                    return Err(RouteError::WrongWrappedNative);
+                }
+            }
+            STEP_UNWRAP => {
+                if step.tokenOut != NATIVE_TOKEN {
+                    return Err(RouteError::BadNativeStep);
+                }
+                if weth == Address::ZERO || step.tokenIn != weth {
+                    return Err(RouteError::WrongWrappedNative);
+                }
+            }
+            STEP_BRIDGE => {
+                if step.tokenIn == NATIVE_TOKEN {
+                    return Err(RouteError::BadNativeStep);
+                }
+                if step.tokenOut == NATIVE_TOKEN {
+                    if weth == Address::ZERO || step.tokenIn != weth {
+                        return Err(RouteError::WrongWrappedNative);
+                    }
+                } else if step.tokenOut != step.tokenIn {
+                    return Err(RouteError::BrokenPath);
+                }
+                let (adapters, gas_limit) = decode_bridge_step_data(&step.data)?;
+                if !adapters.is_empty() && step.adapter != Address::ZERO {
+                    return Err(RouteError::BadAdapterAllowlist);
+                }
+                if gas_limit != U256::ZERO && intent.gasDrop != U256::ZERO {
+                    return Err(RouteError::BadBridgeOptions);
+                }
+            }
+            _ => {
+                if step.tokenOut != step.tokenIn {
+                    return Err(RouteError::BrokenPath);
+                }
+            }
+        }
+
+        current_token = step.tokenOut;
+    }
+
+    if let Some(min_out) = last_swap_min_out {
+        if min_out < intent.minAmountOut {
+            return Err(RouteError::InconsistentAmounts);
+        }
+    }
+
+    let last = &steps[last_index];
+    if intent.recipientHash != FixedBytes::ZERO
+        && (last.stepType != STEP_BRIDGE || last.tokenOut == NATIVE_TOKEN || intent.gasDrop != U256::ZERO)
+    {
+        return Err(RouteError::HiddenRecipient);
+    }
+
+    if !check_recipient(intent)?
+        && (last.stepType != STEP_BRIDGE
+            || last.tokenOut == NATIVE_TOKEN
+            || !last.data.is_empty()
+            || intent.gasDrop != U256::ZERO)
+    {
+        return Err(RouteError::BadRecipient);
+    }
+
+    Ok(())
+}
+
+/// Decode an ABI-encoded intent and route and validate them together
+pub fn validate_encoded_route<F>(
+    intent: &[u8],
+    steps: &[u8],
+    weth: Address,
+    is_adapter_allowed: F,
+) -> Result<(Intent, Vec<RouteStep>), RouteError>
+where
+    F: Fn(Address) -> bool,
+{
+    let intent = decode_intent(intent)?;
+    let steps = decode_route(steps)?;
+    validate_route(&intent, &steps, weth, is_adapter_allowed)?;
+    Ok((intent, steps))
+}
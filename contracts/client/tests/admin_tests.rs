@@ -0,0 +1,98 @@
+use alloy_primitives::{Address, Bytes, U256};
+use alloy_sol_types::SolCall;
+use swoosh_client::admin::*;
+
+#[cfg(test)]
+mod admin_tests {
+    use super::*;
+
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    #[test]
+    fn test_calldata_targets_camel_case_selectors() {
+        // Stylus exports add_supported_chain as addSupportedChain(uint256)
+        let call = AdminCall::add_chain(test_address(1), U256::from(84532u64));
+        assert_eq!(call.to, test_address(1), "Target");
+        assert_eq!(&call.data[..4], &addSupportedChainCall::SELECTOR, "Selector");
+        assert_eq!(
+            addSupportedChainCall::abi_decode(&call.data).unwrap().chainId,
+            U256::from(84532u64),
+            "Argument"
+        );
+    }
+
+    #[test]
+    fn test_pause_switch() {
+        // One builder covers pause and unpause
+        let executor = test_address(2);
+        assert_eq!(&AdminCall::set_paused(executor, true).data[..], &pauseCall {}.abi_encode()[..], "Pause");
+        assert_eq!(&AdminCall::set_paused(executor, false).data[..], &unpauseCall {}.abi_encode()[..], "Unpause");
+    }
+
+    #[test]
+    fn test_lane_halt_switch() {
+        // Halting and restoring share one validator call
+        let halt = AdminCall::set_lane_halted(test_address(1), U256::from(10u64), true);
+        let restore = AdminCall::set_lane_halted(test_address(1), U256::from(10u64), false);
+        let decoded = setLaneHaltedCall::abi_decode(&halt.data).unwrap();
+
+        assert_eq!((decoded.chainId, decoded.halted), (U256::from(10u64), true), "Halt");
+        assert_eq!(halt.description, "halt lane to chain 10", "Description");
+        assert!(!setLaneHaltedCall::abi_decode(&restore.data).unwrap().halted, "Restore");
+    }
+
+    #[test]
+    fn test_adapter_calls() {
+        // Each adapter kind goes to the contract that owns it
+        let bridge = AdminCall::set_bridge_adapter(test_address(2), U256::from(10u64), test_address(9));
+        let swap = AdminCall::set_swap_adapter(test_address(2), test_address(9), false);
+        let settlement = AdminCall::set_settlement_adapter(test_address(3), test_address(9), true);
+
+        let decoded = setBridgeAdapterCall::abi_decode(&bridge.data).unwrap();
+        assert_eq!((decoded.destinationChain, decoded.adapter), (U256::from(10u64), test_address(9)), "Bridge");
+        assert!(!setSwapAdapterCall::abi_decode(&swap.data).unwrap().allowed, "Swap removal");
+        assert!(setSettlementAdapterCall::abi_decode(&settlement.data).unwrap().enabled, "Settlement");
+        assert_eq!(settlement.to, test_address(3), "Verifier target");
+    }
+
+    #[test]
+    fn test_config_import_calls() {
+        // Exported pages are passed through untouched
+        let page = Bytes::from(vec![0xab; 64]);
+        let import = AdminCall::import_config(test_address(1), page.clone());
+        let seal = AdminCall::seal_config_import(test_address(1));
+
+        assert_eq!(importConfigCall::abi_decode(&import.data).unwrap().page, page, "Page");
+        assert_eq!(import.description, format!("import a 64-byte config page into {}", test_address(1)), "Description");
+        assert_eq!(&seal.data[..], &sealConfigImportCall::SELECTOR[..], "Seal selector");
+    }
+
+    #[test]
+    fn test_safe_batch_format() {
+        // Transaction Builder expects string chain id, checksummed targets and 0x calldata
+        let calls = [
+            AdminCall::set_timeout(test_address(3), U256::from(3600u64)),
+            AdminCall::transfer_ownership(test_address(3), test_address(7)),
+        ];
+        let batch = safe_batch(421614, &calls);
+
+        assert_eq!(batch["chainId"], "421614", "Chain id");
+        assert_eq!(batch["transactions"].as_array().unwrap().len(), 2, "One entry per call");
+        assert_eq!(batch["transactions"][0]["to"], test_address(3).to_checksum(None), "Target");
+        assert_eq!(batch["transactions"][0]["value"], "0", "No value");
+        assert_eq!(batch["transactions"][1]["data"], calls[1].data.to_string(), "Calldata");
+        assert!(batch["meta"]["description"].as_str().unwrap().contains("set settlement timeout to 3600s"), "Description");
+    }
+
+    #[test]
+    fn test_status_names() {
+        // Numeric statuses map to the contracts' enums
+        assert_eq!(intent_status_name(U256::from(2u64)), "Completed", "Executor");
+        assert_eq!(settlement_status_name(U256::from(3u64)), "Refunded", "Verifier");
+        assert_eq!(settlement_status_name(U256::from(4u64)), "PartialDelivery", "Receiver fallback");
+        assert_eq!(settlement_status_name(U256::from(5u64)), "DeliveredToClaim", "Receiver claim");
+        assert_eq!(settlement_status_name(U256::MAX), "Unknown", "Out of range");
+    }
+}
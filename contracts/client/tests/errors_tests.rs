@@ -0,0 +1,561 @@
+use alloy_primitives::{Address, FixedBytes, B256, U256};
+use alloy_sol_types::{Revert, SolError};
+use swoosh_client::errors::*;
+
+#[cfg(test)]
+mod errors_tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_unit_errors() {
+        // Selector alone identifies argument-less errors from every contract
+        assert_eq!(decode_error(&abi::Unauthorized {}.abi_encode()), Some(SwooshError::Unauthorized), "Shared");
+        assert_eq!(decode_error(&abi::UnsupportedChain {}.abi_encode()), Some(SwooshError::UnsupportedChain), "Validator");
+        assert_eq!(decode_error(&abi::ContractPaused {}.abi_encode()), Some(SwooshError::ContractPaused), "Executor");
+        assert_eq!(decode_error(&abi::AlreadyProcessed {}.abi_encode()), Some(SwooshError::AlreadyProcessed), "Verifier");
+        assert_eq!(decode_error(&abi::InvalidBatch {}.abi_encode()), Some(SwooshError::InvalidBatch), "Delivery batch");
+        assert_eq!(
+            decode_error(&abi::AttestationFailed {}.abi_encode()),
+            Some(SwooshError::AttestationFailed),
+            "Settlement attestation"
+        );
+        assert_eq!(
+            decode_error(&abi::InvalidCallback {}.abi_encode()),
+            Some(SwooshError::InvalidCallback),
+            "Settlement callback"
+        );
+        assert_eq!(
+            decode_error(&abi::NoPendingDelivery {}.abi_encode()),
+            Some(SwooshError::NoPendingDelivery),
+            "Lane finality"
+        );
+        assert_eq!(
+            decode_error(&abi::InvalidListingMode {}.abi_encode()),
+            Some(SwooshError::InvalidListingMode),
+            "Listing mode"
+        );
+        assert_eq!(
+            decode_error(&abi::InvalidRetryPolicy {}.abi_encode()),
+            Some(SwooshError::InvalidRetryPolicy),
+            "Retry policy"
+        );
+        assert_eq!(
+            decode_error(&abi::InvalidApprovalPolicy {}.abi_encode()),
+            Some(SwooshError::InvalidApprovalPolicy),
+            "Approval policy"
+        );
+        assert_eq!(
+            decode_error(&abi::DrillsDisabled {}.abi_encode()),
+            Some(SwooshError::DrillsDisabled),
+            "Testnet drills"
+        );
+    }
+
+    #[test]
+    fn test_decode_errors_with_arguments() {
+        // Arguments are decoded into the typed variant
+        let slippage = abi::SlippageExceeded { amountOut: U256::from(90u64), minAmountOut: U256::from(100u64) };
+        let used = abi::IntentAlreadyUsed { intentHash: B256::from([3u8; 32]) };
+
+        assert_eq!(
+            decode_error(&slippage.abi_encode()),
+            Some(SwooshError::SlippageExceeded { amount_out: U256::from(90u64), min_amount_out: U256::from(100u64) }),
+            "Slippage"
+        );
+        assert_eq!(
+            decode_error(&used.abi_encode()),
+            Some(SwooshError::IntentAlreadyUsed { intent_hash: B256::from([3u8; 32]) }),
+            "Intent hash"
+        );
+        assert_eq!(
+            decode_error(&abi::InvalidRoute { reason: 5 }.abi_encode()),
+            Some(SwooshError::InvalidRoute { reason: 5 }),
+            "Route reason"
+        );
+        assert_eq!(
+            decode_error(&abi::UnknownRouteSigner { signer: Address::from([7u8; 20]) }.abi_encode()),
+            Some(SwooshError::UnknownRouteSigner { signer: Address::from([7u8; 20]) }),
+            "Route signer"
+        );
+        assert_eq!(
+            decode_error(
+                &abi::DeadlineTooFar {
+                    deadline: U256::from(2_000u64),
+                    latest: U256::from(1_600u64),
+                }
+                .abi_encode()
+            ),
+            Some(SwooshError::DeadlineTooFar {
+                deadline: U256::from(2_000u64),
+                latest: U256::from(1_600u64),
+            }),
+            "Stable deadline window"
+        );
+        assert_eq!(
+            decode_error(
+                &abi::GasDropTooHigh {
+                    gasDrop: U256::from(5u64),
+                    maxGasDrop: U256::from(2u64),
+                }
+                .abi_encode()
+            ),
+            Some(SwooshError::GasDropTooHigh {
+                gas_drop: U256::from(5u64),
+                max_gas_drop: U256::from(2u64),
+            }),
+            "Gas drop cap"
+        );
+        assert_eq!(
+            decode_error(&abi::UnsupportedAsset { assetType: 1 }.abi_encode()),
+            Some(SwooshError::UnsupportedAsset { asset_type: 1 }),
+            "Asset type"
+        );
+        assert_eq!(
+            decode_error(
+                &abi::SolverFeeTooHigh {
+                    solverFee: U256::from(30u64),
+                    maxSolverFee: U256::from(20u64),
+                }
+                .abi_encode()
+            ),
+            Some(SwooshError::SolverFeeTooHigh {
+                solver_fee: U256::from(30u64),
+                max_solver_fee: U256::from(20u64),
+            }),
+            "Solver fee cap"
+        );
+        assert_eq!(
+            decode_error(&abi::EmergencyModeLocked { availableAt: U256::from(259_200u64) }.abi_encode()),
+            Some(SwooshError::EmergencyModeLocked { available_at: U256::from(259_200u64) }),
+            "Emergency mode delay"
+        );
+        assert_eq!(
+            decode_error(&abi::LaneHalted { chainId: U256::from(10u64) }.abi_encode()),
+            Some(SwooshError::LaneHalted { chain_id: U256::from(10u64) }),
+            "Halted lane"
+        );
+        assert_eq!(
+            decode_error(&abi::IntentBlocked { intentHash: B256::repeat_byte(0xbb) }.abi_encode()),
+            Some(SwooshError::IntentBlocked { intent_hash: B256::repeat_byte(0xbb) }),
+            "Blocked intent"
+        );
+        assert_eq!(
+            decode_error(&abi::SolverAtCapacity { solverId: U256::from(7u64) }.abi_encode()),
+            Some(SwooshError::SolverAtCapacity { solver_id: U256::from(7u64) }),
+            "Solver at capacity"
+        );
+        assert_eq!(
+            decode_error(
+                &abi::ClaimActive {
+                    intentHash: B256::repeat_byte(0xcc),
+                    solverId: U256::from(7u64),
+                    expiresAt: U256::from(1_000u64),
+                }
+                .abi_encode()
+            ),
+            Some(SwooshError::ClaimActive {
+                intent_hash: B256::repeat_byte(0xcc),
+                solver_id: U256::from(7u64),
+                expires_at: U256::from(1_000u64),
+            }),
+            "Claimed intent"
+        );
+        assert_eq!(
+            decode_error(
+                &abi::InterfaceNotSupported {
+                    target: Address::from([9u8; 20]),
+                    interfaceId: FixedBytes([0x01, 0xff, 0xc9, 0xa7]),
+                }
+                .abi_encode()
+            ),
+            Some(SwooshError::InterfaceNotSupported {
+                target: Address::from([9u8; 20]),
+                interface_id: FixedBytes([0x01, 0xff, 0xc9, 0xa7]),
+            }),
+            "Adapter without the interface"
+        );
+        assert_eq!(
+            decode_error(
+                &abi::InvalidFillAmount {
+                    token: Address::from([9u8; 20]),
+                    amount: U256::from(1u64),
+                    minFill: U256::from(1_000_000u64),
+                    granularity: U256::from(10_000u64),
+                }
+                .abi_encode()
+            ),
+            Some(SwooshError::InvalidFillAmount {
+                token: Address::from([9u8; 20]),
+                amount: U256::from(1u64),
+                min_fill: U256::from(1_000_000u64),
+                granularity: U256::from(10_000u64),
+            }),
+            "Dust fill"
+        );
+        assert_eq!(
+            decode_error(
+                &abi::LaneCapExceeded {
+                    chainId: U256::from(10u64),
+                    token: Address::from([9u8; 20]),
+                    amount: U256::from(5_000u64),
+                    available: U256::from(1_000u64),
+                }
+                .abi_encode()
+            ),
+            Some(SwooshError::LaneCapExceeded {
+                chain_id: U256::from(10u64),
+                token: Address::from([9u8; 20]),
+                amount: U256::from(5_000u64),
+                available: U256::from(1_000u64),
+            }),
+            "Lane over its cap"
+        );
+        assert_eq!(
+            decode_error(
+                &abi::IntentHeld {
+                    intentHash: B256::repeat_byte(0xdd),
+                    heldUntil: U256::from(1_600u64),
+                }
+                .abi_encode()
+            ),
+            Some(SwooshError::IntentHeld {
+                intent_hash: B256::repeat_byte(0xdd),
+                held_until: U256::from(1_600u64),
+            }),
+            "Reported intent"
+        );
+        assert_eq!(
+            decode_error(
+                &abi::SignerMismatch {
+                    signer: Address::from([3u8; 20]),
+                    user: Address::from([4u8; 20]),
+                }
+                .abi_encode()
+            ),
+            Some(SwooshError::SignerMismatch {
+                signer: Address::from([3u8; 20]),
+                user: Address::from([4u8; 20]),
+            }),
+            "Signature from someone else"
+        );
+        assert_eq!(
+            decode_error(
+                &abi::InvalidNonce {
+                    user: Address::from([4u8; 20]),
+                    nonce: U256::from(3u64),
+                    expected: U256::from(2u64),
+                }
+                .abi_encode()
+            ),
+            Some(SwooshError::InvalidNonce {
+                user: Address::from([4u8; 20]),
+                nonce: U256::from(3u64),
+                expected: U256::from(2u64),
+            }),
+            "Registry nonce out of order"
+        );
+        assert_eq!(
+            decode_error(&abi::UnknownIntent { intentHash: B256::repeat_byte(0xee) }.abi_encode()),
+            Some(SwooshError::UnknownIntent { intent_hash: B256::repeat_byte(0xee) }),
+            "Unregistered intent"
+        );
+        let cancelled = abi::IntentNotPending { intentHash: B256::repeat_byte(0xee), status: 3 };
+        assert_eq!(
+            decode_error(&cancelled.abi_encode()),
+            Some(SwooshError::IntentNotPending { intent_hash: B256::repeat_byte(0xee), status: 3 }),
+            "Cancelled intent"
+        );
+        assert_eq!(
+            SwooshError::IntentNotPending { intent_hash: B256::ZERO, status: 4 }.to_string(),
+            format!("registered intent {} is expired", B256::ZERO),
+            "Status in message"
+        );
+        assert_eq!(
+            decode_error(&abi::IntentOutOfRange {}.abi_encode()),
+            Some(SwooshError::IntentOutOfRange),
+            "Registered field too wide"
+        );
+        assert_eq!(
+            decode_error(
+                &abi::InFlightLimitExceeded {
+                    solverId: U256::from(7u64),
+                    limit: U256::from(4u64),
+                }
+                .abi_encode()
+            ),
+            Some(SwooshError::InFlightLimitExceeded { solver_id: U256::from(7u64), limit: U256::from(4u64) }),
+            "In-flight count cap"
+        );
+        assert_eq!(
+            decode_error(
+                &abi::InFlightNotionalExceeded {
+                    solverId: U256::from(7u64),
+                    token: Address::from([5u8; 20]),
+                    limit: U256::from(1_000_000u64),
+                }
+                .abi_encode()
+            ),
+            Some(SwooshError::InFlightNotionalExceeded {
+                solver_id: U256::from(7u64),
+                token: Address::from([5u8; 20]),
+                limit: U256::from(1_000_000u64),
+            }),
+            "In-flight notional cap"
+        );
+        assert_eq!(
+            decode_error(
+                &abi::ConfigSyncReplayed {
+                    sourceChain: U256::from(421_614u64),
+                    nonce: U256::from(3u64),
+                }
+                .abi_encode()
+            ),
+            Some(SwooshError::ConfigSyncReplayed {
+                source_chain: U256::from(421_614u64),
+                nonce: U256::from(3u64),
+            }),
+            "Config sync replay"
+        );
+        assert_eq!(
+            decode_error(
+                &abi::ConfigEpochMismatch {
+                    epoch: U256::from(3u64),
+                    compatibleEpoch: U256::from(5u64),
+                    currentEpoch: U256::from(6u64),
+                }
+                .abi_encode()
+            ),
+            Some(SwooshError::ConfigEpochMismatch {
+                epoch: U256::from(3u64),
+                compatible_epoch: U256::from(5u64),
+                current_epoch: U256::from(6u64),
+            }),
+            "Retired config epoch"
+        );
+        let capped = abi::TokenCapExceeded { token: Address::from([5u8; 20]), cap: U256::from(10_000u64) };
+        assert_eq!(
+            decode_error(&capped.abi_encode()),
+            Some(SwooshError::TokenCapExceeded { token: Address::from([5u8; 20]), cap: U256::from(10_000u64) }),
+            "Listing cap"
+        );
+        let rejected = abi::ListingRejected { token: Address::from([5u8; 20]), reason: 3 };
+        assert_eq!(
+            decode_error(&rejected.abi_encode()),
+            Some(SwooshError::ListingRejected { token: Address::from([5u8; 20]), reason: 3 }),
+            "Listing criteria"
+        );
+        assert_eq!(
+            decode_error(&abi::NoPriceSource { token: Address::from([5u8; 20]) }.abi_encode()),
+            Some(SwooshError::NoPriceSource { token: Address::from([5u8; 20]) }),
+            "Every price source failed"
+        );
+        let delegated = abi::DelegationExceeded {
+            delegate: Address::from([6u8; 20]),
+            token: Address::from([5u8; 20]),
+            amount: U256::from(600u64),
+            remaining: U256::from(400u64),
+        };
+        assert_eq!(
+            decode_error(&delegated.abi_encode()),
+            Some(SwooshError::DelegationExceeded {
+                delegate: Address::from([6u8; 20]),
+                token: Address::from([5u8; 20]),
+                amount: U256::from(600u64),
+                remaining: U256::from(400u64),
+            }),
+            "Delegation allowance"
+        );
+        assert_eq!(
+            decode_error(&abi::IncompatibleValidator { validator: Address::from([4u8; 20]), reason: 3 }.abi_encode()),
+            Some(SwooshError::IncompatibleValidator { validator: Address::from([4u8; 20]), reason: 3 }),
+            "Validator compatibility"
+        );
+        assert_eq!(
+            decode_error(
+                &abi::UntrustedLane {
+                    sourceChain: U256::from(84_532u64),
+                    messenger: Address::from([6u8; 20]),
+                }
+                .abi_encode()
+            ),
+            Some(SwooshError::UntrustedLane {
+                source_chain: U256::from(84_532u64),
+                messenger: Address::from([6u8; 20]),
+            }),
+            "Receiver lane"
+        );
+        assert_eq!(
+            decode_error(&abi::MalformedPayload { reason: 3 }.abi_encode()),
+            Some(SwooshError::MalformedPayload { reason: 3 }),
+            "Payload codec failure"
+        );
+        assert_eq!(
+            decode_error(&abi::InvalidDeliveryStatus { status: 2 }.abi_encode()),
+            Some(SwooshError::InvalidDeliveryStatus { status: 2 }),
+            "Delivery status"
+        );
+        assert_eq!(
+            decode_error(&abi::ReporterNotAccepted { reporterType: 16 }.abi_encode()),
+            Some(SwooshError::ReporterNotAccepted { reporter_type: 16 }),
+            "Lane reporter"
+        );
+        assert_eq!(
+            decode_error(&abi::ChallengeWindowOpen { finalAt: U256::from(1_700_003_600u64) }.abi_encode()),
+            Some(SwooshError::ChallengeWindowOpen { final_at: U256::from(1_700_003_600u64) }),
+            "Challenge window"
+        );
+        assert_eq!(
+            decode_error(&abi::NotClaimable { intentId: U256::from(7u64), status: U256::from(3u64) }.abi_encode()),
+            Some(SwooshError::NotClaimable { intent_id: U256::from(7u64), status: U256::from(3u64) }),
+            "Insurance claim"
+        );
+        assert_eq!(
+            decode_error(&abi::ClaimNotExpired { expiresAt: U256::from(1_700_000_000u64) }.abi_encode()),
+            Some(SwooshError::ClaimNotExpired { expires_at: U256::from(1_700_000_000u64) }),
+            "Claim expiry"
+        );
+        assert_eq!(
+            decode_error(
+                &abi::DustBelowThreshold {
+                    dust: U256::from(40u64),
+                    threshold: U256::from(100u64),
+                }
+                .abi_encode()
+            ),
+            Some(SwooshError::DustBelowThreshold {
+                dust: U256::from(40u64),
+                threshold: U256::from(100u64),
+            }),
+            "Dust threshold"
+        );
+        assert_eq!(
+            decode_error(
+                &abi::InsufficientLiquidity {
+                    stepIndex: U256::from(1u64),
+                    expectedOut: U256::ZERO,
+                    minOut: U256::from(95u64),
+                }
+                .abi_encode()
+            ),
+            Some(SwooshError::InsufficientLiquidity {
+                step_index: U256::from(1u64),
+                expected_out: U256::ZERO,
+                min_out: U256::from(95u64),
+            }),
+            "Liquidity pre-check"
+        );
+        assert_eq!(
+            decode_error(
+                &abi::GasLimitTooHigh {
+                    gasLimit: U256::from(900_000u64),
+                    maxGasLimit: U256::from(500_000u64),
+                }
+                .abi_encode()
+            ),
+            Some(SwooshError::GasLimitTooHigh {
+                gas_limit: U256::from(900_000u64),
+                max_gas_limit: U256::from(500_000u64),
+            }),
+            "Gas limit cap"
+        );
+        assert_eq!(
+            decode_error(
+                &abi::BlockHashMismatch {
+                    blockNumber: U256::from(1_000u64),
+                    blockHash: B256::repeat_byte(0x11),
+                }
+                .abi_encode()
+            ),
+            Some(SwooshError::BlockHashMismatch {
+                block_number: U256::from(1_000u64),
+                block_hash: B256::repeat_byte(0x11),
+            }),
+            "Reorged block"
+        );
+        assert_eq!(
+            decode_error(
+                &abi::ParameterOutOfBounds {
+                    param: 20,
+                    value: U256::ZERO,
+                    minValue: U256::from(60u64),
+                    maxValue: U256::from(604_800u64),
+                }
+                .abi_encode()
+            ),
+            Some(SwooshError::ParameterOutOfBounds {
+                param: 20,
+                value: U256::ZERO,
+                min_value: U256::from(60u64),
+                max_value: U256::from(604_800u64),
+            }),
+            "Parameter bounds"
+        );
+    }
+
+    #[test]
+    fn test_decode_simulation_result() {
+        // simulateRoute always reverts; a successful route reports its result
+        let result = abi::SimulationResult {
+            amountOut: U256::from(990u64),
+            gasUsed: U256::from(250_000u64),
+            protocolFee: U256::from(10u64),
+            integratorFee: U256::ZERO,
+            bridgeFee: U256::from(5u64),
+            gasDropCost: U256::ZERO,
+        };
+        let decoded = decode_error(&result.abi_encode()).unwrap();
+
+        assert_eq!(
+            decoded,
+            SwooshError::SimulationResult {
+                amount_out: U256::from(990u64),
+                gas_used: U256::from(250_000u64),
+                protocol_fee: U256::from(10u64),
+                integrator_fee: U256::ZERO,
+                bridge_fee: U256::from(5u64),
+                gas_drop_cost: U256::ZERO,
+            },
+            "Fields"
+        );
+        assert!(decoded.to_string().starts_with("simulated route delivers 990 using 250000 gas"), "Message");
+    }
+
+    #[test]
+    fn test_decode_standard_reverts() {
+        // Plain string reverts from tokens or routers are passed through
+        let revert = Revert { reason: "STF".into() };
+
+        assert_eq!(decode_error(&revert.abi_encode()), Some(SwooshError::Revert("STF".into())), "Error(string)");
+    }
+
+    #[test]
+    fn test_decode_unknown_data() {
+        // Empty, short, unknown or truncated data is not decoded
+        assert_eq!(decode_error(&[]), None, "Empty");
+        assert_eq!(decode_error(&[1, 2]), None, "Short");
+        assert_eq!(decode_error(&[0xde, 0xad, 0xbe, 0xef]), None, "Unknown selector");
+
+        let truncated = abi::FeeTooHigh { feeBps: U256::from(500u64), maxFeeBps: U256::from(300u64) }.abi_encode();
+        assert_eq!(decode_error(&truncated[..20]), None, "Truncated");
+    }
+
+    #[test]
+    fn test_human_readable_messages() {
+        // Display includes decoded arguments and route reasons
+        let fee = SwooshError::FeeTooHigh { fee_bps: U256::from(500u64), max_fee_bps: U256::from(300u64) };
+
+        assert_eq!(fee.to_string(), "fee of 500 bps exceeds the 300 bps cap", "Fee message");
+        assert_eq!(
+            SwooshError::InvalidRoute { reason: 7 }.to_string(),
+            "invalid route: swap adapter is not allowlisted",
+            "Route message"
+        );
+        assert!(route_reason(17).contains("chain family"), "Recipient family code");
+        assert_eq!(route_reason(99), "unknown reason", "Unknown code");
+        assert_eq!(
+            SwooshError::MalformedPayload { reason: 2 }.to_string(),
+            "malformed payload: payload version is not supported",
+            "Payload message"
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::BTreeMap;
+
+use alloy_primitives::{Address, B256, U256};
+use alloy_sol_types::SolEvent;
+use swoosh_client::events::*;
+use swoosh_client::indexer::fork_point;
+
+#[cfg(test)]
+mod events_tests {
+    use super::*;
+
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    fn decode<E: SolEvent>(event: &E) -> Option<(U256, IntentEventKind)> {
+        let log = event.encode_log_data();
+        decode_lifecycle_log(log.topics(), &log.data)
+    }
+
+    #[test]
+    fn test_decode_intent_executed() {
+        // Indexed user and destination chain come back out of the topics
+        let event = IntentExecuted {
+            intentId: U256::from(7u64),
+            user: test_address(1),
+            destinationChain: U256::from(10u64),
+            tokenIn: test_address(2),
+            timestamp: U256::from(1_700_000_000u64),
+        };
+
+        let log = event.encode_log_data();
+        assert_eq!(log.topics()[3], B256::from(U256::from(10u64)), "Destination chain topic");
+
+        let (intent_id, kind) = decode(&event).expect("Decoded");
+        assert_eq!(intent_id, U256::from(7u64), "Intent id");
+        assert_eq!(
+            kind,
+            IntentEventKind::Executed {
+                user: test_address(1),
+                token_in: test_address(2),
+                destination_chain: U256::from(10u64),
+                timestamp: U256::from(1_700_000_000u64),
+            },
+            "Executed fields"
+        );
+    }
+
+    #[test]
+    fn test_decode_bridge_initiated() {
+        // Token and destination chain are topics; the rest comes from the data section
+        let event = BridgeInitiated {
+            intentId: U256::from(8u64),
+            token: test_address(2),
+            amount: U256::from(1_000u64),
+            destinationChain: U256::from(10u64),
+            recipient: test_address(3),
+        };
+
+        let (intent_id, kind) = decode(&event).expect("Decoded");
+        assert_eq!(intent_id, U256::from(8u64), "Intent id");
+        assert_eq!(
+            kind,
+            IntentEventKind::BridgeInitiated {
+                token: test_address(2),
+                amount: U256::from(1_000u64),
+                destination_chain: U256::from(10u64),
+                recipient: test_address(3),
+            },
+            "Bridge fields"
+        );
+    }
+
+    #[test]
+    fn test_decode_route_step() {
+        // Each hop carries its own amounts and outcome
+        let event = RouteStepExecuted {
+            intentId: U256::from(8u64),
+            stepIndex: U256::from(1u64),
+            stepType: 0,
+            adapter: test_address(4),
+            tokenIn: test_address(2),
+            tokenOut: test_address(5),
+            amountIn: U256::from(1_000u64),
+            amountOut: U256::from(990u64),
+            success: true,
+        };
+
+        let (intent_id, kind) = decode(&event).expect("Decoded");
+        assert_eq!(intent_id, U256::from(8u64), "Intent id");
+        assert_eq!(
+            kind,
+            IntentEventKind::StepExecuted {
+                step_index: U256::from(1u64),
+                step_type: 0,
+                adapter: test_address(4),
+                token_in: test_address(2),
+                token_out: test_address(5),
+                amount_in: U256::from(1_000u64),
+                amount_out: U256::from(990u64),
+                success: true,
+            },
+            "Step fields"
+        );
+        assert!(lifecycle_topics().contains(&RouteStepExecuted::SIGNATURE_HASH), "Filtered by the indexer");
+    }
+
+    #[test]
+    fn test_decode_settlement_and_refund() {
+        // Verifier-side events decode into the same enum
+        let confirmed = SettlementConfirmed {
+            intentId: U256::from(9u64),
+            messageId: B256::from([4u8; 32]),
+            timestamp: U256::from(5u64),
+        };
+        let refund = RefundInitiated {
+            intentId: U256::from(9u64),
+            user: test_address(1),
+            token: test_address(2),
+            amount: U256::from(50u64),
+        };
+
+        assert_eq!(
+            decode(&confirmed).map(|(_, kind)| kind),
+            Some(IntentEventKind::SettlementConfirmed { message_id: B256::from([4u8; 32]), timestamp: U256::from(5u64) }),
+            "Settlement fields"
+        );
+        assert_eq!(
+            decode(&refund).map(|(_, kind)| kind),
+            Some(IntentEventKind::RefundInitiated { user: test_address(1), token: test_address(2), amount: U256::from(50u64) }),
+            "Refund fields"
+        );
+    }
+
+    #[test]
+    fn test_decode_settlement_retried() {
+        // A retried delivery restarts the clock the keeper schedules from
+        let retried = SettlementRetried {
+            intentId: U256::from(9u64),
+            attempt: U256::from(2u64),
+            timestamp: U256::from(7_000u64),
+        };
+
+        assert_eq!(
+            decode(&retried),
+            Some((
+                U256::from(9u64),
+                IntentEventKind::SettlementRetried { attempt: U256::from(2u64), timestamp: U256::from(7_000u64) }
+            )),
+            "Retry fields"
+        );
+        assert!(lifecycle_topics().contains(&SettlementRetried::SIGNATURE_HASH), "Filtered by the indexer");
+    }
+
+    #[test]
+    fn test_decode_memo() {
+        // Integrators look their intents up by the memo topic
+        let memo = IntentMemo { intentId: U256::from(9u64), memo: B256::from([0x0d; 32]) };
+
+        assert_eq!(
+            decode(&memo),
+            Some((U256::from(9u64), IntentEventKind::Memo { memo: B256::from([0x0d; 32]) })),
+            "Memo fields"
+        );
+        assert!(lifecycle_topics().contains(&IntentMemo::SIGNATURE_HASH), "Filtered by the indexer");
+    }
+
+    #[test]
+    fn test_ignores_foreign_logs() {
+        // Unknown topic0 or no topics at all is not a lifecycle event
+        assert!(decode_lifecycle_log(&[B256::from([1u8; 32])], &[]).is_none(), "Unknown topic");
+        assert!(decode_lifecycle_log(&[], &[]).is_none(), "No topics");
+        assert_eq!(lifecycle_topics()[0], IntentExecuted::SIGNATURE_HASH, "Filter topics");
+    }
+
+    #[test]
+    fn test_fork_point() {
+        // Latest block whose hash still matches is the fork point
+        let recorded: BTreeMap<u64, B256> =
+            [(10, B256::from([1u8; 32])), (20, B256::from([2u8; 32])), (30, B256::from([3u8; 32]))].into();
+        let mut canonical = recorded.clone();
+
+        assert_eq!(fork_point(&recorded, &canonical), Some(30), "No reorg");
+
+        canonical.insert(30, B256::from([9u8; 32]));
+        canonical.remove(&20);
+        assert_eq!(fork_point(&recorded, &canonical), Some(10), "Reorged past 20");
+
+        canonical.insert(10, B256::from([9u8; 32]));
+        assert_eq!(fork_point(&recorded, &canonical), None, "Deeper than window");
+    }
+}
@@ -0,0 +1,162 @@
+use alloy_primitives::{Address, Bytes, FixedBytes, U256};
+use alloy_sol_types::SolValue;
+use swoosh_client::route::*;
+
+#[cfg(test)]
+mod route_tests {
+    use super::*;
+
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    fn intent() -> Intent {
+        Intent {
+            user: test_address(1),
+            tokenIn: test_address(2),
+            amountIn: U256::from(1_000u64),
+            destinationChain: U256::from(10u64),
+            recipient: test_address(3),
+            minAmountOut: U256::from(900u64),
+            deadline: U256::from(2_000u64),
+            nonce: U256::ZERO,
+            gasDrop: U256::ZERO,
+            assetType: ASSET_ERC20,
+            tokenId: U256::ZERO,
+            recipientHash: FixedBytes::ZERO,
+            maxSolverFee: U256::ZERO,
+            priorityFee: U256::ZERO,
+            blockNumber: U256::ZERO,
+            blockHash: FixedBytes::ZERO,
+            configEpoch: U256::ZERO,
+            callback: Address::ZERO,
+            callbackData: Bytes::new(),
+            recipientFamily: FAMILY_EVM,
+            recipientAddress: Bytes::new(),
+            insurancePremiumBps: U256::ZERO,
+            retryPolicy: U256::ZERO,
+            memo: FixedBytes::ZERO,
+        }
+    }
+
+    fn step(step_type: u8, token_in: Address, token_out: Address) -> RouteStep {
+        RouteStep {
+            stepType: step_type,
+            adapter: test_address(9),
+            tokenIn: token_in,
+            tokenOut: token_out,
+            minAmountOut: U256::from(950u64),
+            deadline: U256::ZERO,
+            recipient: Address::ZERO,
+            data: Bytes::new(),
+        }
+    }
+
+    // Swap token 2 into token 4 through allowlisted adapter 9, then bridge
+    fn swap_then_bridge() -> Vec<RouteStep> {
+        let mut bridge = step(STEP_BRIDGE, test_address(4), test_address(4));
+        bridge.adapter = Address::ZERO;
+        vec![step(STEP_SWAP, test_address(2), test_address(4)), bridge]
+    }
+
+    fn allowed(adapter: Address) -> bool {
+        adapter == test_address(9)
+    }
+
+    #[test]
+    fn test_valid_route_passes() {
+        // A connected swap-then-bridge route clears every check
+        assert_eq!(validate_route(&intent(), &swap_then_bridge(), test_address(8), allowed), Ok(()), "Valid");
+    }
+
+    #[test]
+    fn test_route_shape_rejected() {
+        // Empty, broken and badly terminated routes fail with the on-chain code
+        let mut broken = swap_then_bridge();
+        broken[1].tokenIn = test_address(5);
+        let mut early_bridge = swap_then_bridge();
+        early_bridge.reverse();
+        let mut unknown = swap_then_bridge();
+        unknown[0].stepType = 9;
+
+        assert_eq!(validate_route(&intent(), &[], test_address(8), allowed), Err(RouteError::Empty), "Empty");
+        assert_eq!(validate_route(&intent(), &broken, test_address(8), allowed), Err(RouteError::BrokenPath), "Path");
+        assert_eq!(
+            validate_route(&intent(), &early_bridge, test_address(8), allowed),
+            Err(RouteError::BrokenPath),
+            "Bridge first"
+        );
+        assert_eq!(validate_route(&intent(), &unknown, test_address(8), allowed), Err(RouteError::UnknownStep), "Type");
+        assert_eq!(RouteError::BadTerminal.code(), 6, "Reason code");
+    }
+
+    #[test]
+    fn test_allowlist_and_amounts_rejected() {
+        // Unlisted adapters and loose minimums never reach the chain
+        let mut loose = swap_then_bridge();
+        loose[0].minAmountOut = U256::from(800u64);
+        let mut late = swap_then_bridge();
+        late[0].deadline = U256::from(3_000u64);
+
+        assert_eq!(
+            validate_route(&intent(), &swap_then_bridge(), test_address(8), |_| false),
+            Err(RouteError::AdapterNotAllowed),
+            "Adapter"
+        );
+        assert_eq!(
+            validate_route(&intent(), &loose, test_address(8), allowed),
+            Err(RouteError::InconsistentAmounts),
+            "Minimum"
+        );
+        assert_eq!(
+            validate_route(&intent(), &late, test_address(8), allowed),
+            Err(RouteError::InconsistentDeadline),
+            "Deadline"
+        );
+        assert_eq!(
+            RouteError::AdapterNotAllowed.to_string(),
+            "swap adapter is not allowlisted",
+            "Same message as the revert"
+        );
+    }
+
+    #[test]
+    fn test_bridge_data_and_recipient_checks() {
+        // Bridge allowlists and non-EVM recipients follow the contract's rules
+        let mut pinned = swap_then_bridge();
+        pinned[1].adapter = test_address(6);
+        pinned[1].data = vec![test_address(6)].abi_encode().into();
+        let mut solana = intent();
+        solana.recipient = Address::ZERO;
+        solana.recipientFamily = 1;
+        solana.recipientAddress = Bytes::from(vec![7u8; 32]);
+        let mut short = solana.clone();
+        short.recipientAddress = Bytes::from(vec![7u8; 20]);
+
+        assert_eq!(
+            validate_route(&intent(), &pinned, test_address(8), allowed),
+            Err(RouteError::BadAdapterAllowlist),
+            "Allowlist with pinned adapter"
+        );
+        assert_eq!(validate_route(&solana, &swap_then_bridge(), test_address(8), allowed), Ok(()), "Solana");
+        assert_eq!(
+            validate_route(&short, &swap_then_bridge(), test_address(8), allowed),
+            Err(RouteError::BadRecipient),
+            "Solana address length"
+        );
+    }
+
+    #[test]
+    fn test_encoded_route_round_trip() {
+        // ABI bytes from a router decode and validate in one call
+        let steps = swap_then_bridge();
+        let decoded = validate_encoded_route(&intent().abi_encode(), &steps.abi_encode(), test_address(8), allowed);
+
+        assert_eq!(decoded.map(|(_, decoded)| decoded), Ok(steps), "Round trip");
+        assert_eq!(
+            validate_encoded_route(&[1, 2, 3], &[], test_address(8), allowed).map(|_| ()),
+            Err(RouteError::Malformed),
+            "Garbage"
+        );
+    }
+}
@@ -0,0 +1,43 @@
+//! Adapter Approval Policies
+//!
+//! What RouteExecutor does with the ERC-20 allowance it gives a swap or
+//! bridge adapter to pull a step's input. It never approves more than the
+//! step's amount, but an adapter that pulls less (an exact-output swap, a
+//! router that refunds dust, a failed bridge call that didn't revert) leaves
+//! the rest approved until the next execution overwrites it. That leftover is
+//! what a compromised adapter or router behind it could still take.
+//!
+//! - `Exact`: approve exactly the step's amount before each call and leave
+//!   whatever the adapter didn't pull. This is how every adapter behaved
+//!   before policies existed.
+//! - `Revoke`: approve exactly the step's amount, then reset the allowance
+//!   to zero once the adapter returns. Also the policy for tokens that, like
+//!   USDT, refuse to change a non-zero allowance to another non-zero one.
+//!
+//! Revoking costs one more token call per step, a few thousand gas on a
+//! typical ERC-20, part of it refunded when the call clears a leftover. It
+//! suits adapters fronting third-party routers; `Exact` is cheaper for ones
+//! that always pull the whole amount.
+
+/// Allowance handling for one adapter
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ApprovalPolicy {
+    #[default]
+    Exact = 0,
+    Revoke = 1,
+}
+
+impl ApprovalPolicy {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Exact),
+            1 => Some(Self::Revoke),
+            _ => None,
+        }
+    }
+
+    /// Whether the allowance is reset to zero after the adapter call
+    pub fn revokes(self) -> bool {
+        self == Self::Revoke
+    }
+}
@@ -0,0 +1,62 @@
+//! Intent Attestations
+//!
+//! Schema of the Ethereum Attestation Service (EAS) attestations
+//! SettlementVerifier issues when an intent settles. Each one is a portable
+//! receipt that the intent executed on this chain and was delivered on its
+//! destination. Third parties verify it through EAS without trusting Swoosh's
+//! indexers. The schema is registered once per chain with the EAS
+//! SchemaRegistry; `schema_uid` derives the UID the registry assigns to it.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use alloy_sol_types::{sol, SolValue};
+use stylus_sdk::alloy_primitives::{keccak256, Address, FixedBytes};
+
+sol! {
+    /// Data of an intent attestation, as `abi.encode` of its fields
+    ///
+    /// `status` follows SettlementVerifier's settlement status; timestamps
+    /// are unix seconds. `memo` is the integrator memo the intent was signed
+    /// with, zero if it had none.
+    struct IntentAttestation {
+        bytes32 intentHash;
+        address user;
+        uint256 amountIn;
+        uint256 amountOut;
+        uint256 sourceChain;
+        uint256 destinationChain;
+        uint64 executedAt;
+        uint64 settledAt;
+        uint8 status;
+        bytes32 memo;
+    }
+}
+
+/// Schema string registered with the EAS SchemaRegistry
+pub const INTENT_ATTESTATION_SCHEMA: &str = concat!(
+    "bytes32 intentHash,address user,uint256 amountIn,uint256 amountOut,uint256 sourceChain,",
+    "uint256 destinationChain,uint64 executedAt,uint64 settledAt,uint8 status,bytes32 memo"
+);
+
+/// UID the SchemaRegistry assigns to the intent schema
+///
+/// `keccak256(abi.encodePacked(schema, resolver, revocable))`; Swoosh
+/// registers it without a resolver and as irrevocable.
+pub fn schema_uid(resolver: Address, revocable: bool) -> FixedBytes<32> {
+    let mut packed = Vec::with_capacity(INTENT_ATTESTATION_SCHEMA.len() + 21);
+    packed.extend_from_slice(INTENT_ATTESTATION_SCHEMA.as_bytes());
+    packed.extend_from_slice(resolver.as_slice());
+    packed.push(revocable as u8);
+    keccak256(packed)
+}
+
+/// Encode an attestation's data as EAS stores it
+pub fn encode_intent_attestation(attestation: &IntentAttestation) -> Vec<u8> {
+    attestation.abi_encode_params()
+}
+
+/// Decode an attestation's data, returning `None` if malformed
+pub fn decode_intent_attestation(data: &[u8]) -> Option<IntentAttestation> {
+    IntentAttestation::abi_decode_params(data).ok()
+}
@@ -0,0 +1,168 @@
+//! Bridge Adapter Interface
+//!
+//! Interfaces shared between RouteExecutor, the bridge adapters, and
+//! SettlementVerifier. Every adapter exposes the same entry points so the
+//! executor can dispatch a lane to whichever messaging layer serves it.
+
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use stylus_sdk::prelude::*;
+
+// Bridge adapter interface (implemented by every adapter contract)
+sol_interface! {
+    interface IBridgeAdapter {
+        function quoteFee(
+            uint256 destination_chain,
+            address token,
+            uint256 amount,
+            address recipient
+        ) external view returns (uint256);
+
+        function bridge(
+            uint256 intent_id,
+            uint256 destination_chain,
+            address token,
+            uint256 amount,
+            address recipient,
+            bool unwrap_native
+        ) external payable returns (bytes32);
+
+        function supportsLane(uint256 destination_chain) external view returns (bool);
+    }
+}
+
+// Optional gas-drop extension, for adapters whose messaging layer can deliver
+// destination native alongside the message
+sol_interface! {
+    interface IGasDropAdapter {
+        function quoteFeeWithGasDrop(
+            uint256 destination_chain,
+            address token,
+            uint256 amount,
+            address recipient,
+            uint256 gas_drop
+        ) external view returns (uint256);
+
+        function bridgeWithGasDrop(
+            uint256 intent_id,
+            uint256 destination_chain,
+            address token,
+            uint256 amount,
+            address recipient,
+            bool unwrap_native,
+            uint256 gas_drop
+        ) external payable returns (bytes32);
+    }
+}
+
+// Optional gas-limit extension, for adapters that let one message raise the
+// destination gas limit configured for its lane
+sol_interface! {
+    interface IGasLimitAdapter {
+        function quoteFeeWithGasLimit(
+            uint256 destination_chain,
+            address token,
+            uint256 amount,
+            address recipient,
+            uint256 gas_limit
+        ) external view returns (uint256);
+
+        function bridgeWithGasLimit(
+            uint256 intent_id,
+            uint256 destination_chain,
+            address token,
+            uint256 amount,
+            address recipient,
+            bool unwrap_native,
+            uint256 gas_limit
+        ) external payable returns (bytes32);
+    }
+}
+
+// Optional NFT extension, for adapters that can carry ERC-721 and ERC-1155
+// tokens. `asset_type` follows `encoding::AssetType`.
+sol_interface! {
+    interface INftBridgeAdapter {
+        function quoteNftFee(
+            uint256 destination_chain,
+            uint8 asset_type,
+            address token,
+            uint256 token_id,
+            uint256 amount,
+            address recipient
+        ) external view returns (uint256);
+
+        function bridgeNft(
+            uint256 intent_id,
+            uint256 destination_chain,
+            uint8 asset_type,
+            address token,
+            uint256 token_id,
+            uint256 amount,
+            address recipient
+        ) external payable returns (bytes32);
+    }
+}
+
+// Optional non-EVM extension, for adapters whose messaging layer reaches
+// chains outside the EVM. `recipient_family` follows
+// `chain_address::ChainFamily` and `recipient` is the raw address.
+sol_interface! {
+    interface IChainAddressAdapter {
+        function quoteChainAddressFee(
+            uint256 destination_chain,
+            address token,
+            uint256 amount,
+            uint8 recipient_family,
+            bytes recipient
+        ) external view returns (uint256);
+
+        function bridgeToChainAddress(
+            uint256 intent_id,
+            uint256 destination_chain,
+            address token,
+            uint256 amount,
+            uint8 recipient_family,
+            bytes recipient
+        ) external payable returns (bytes32);
+    }
+}
+
+// SettlementVerifier interface used by adapters on the destination side.
+// `status` follows `encoding::DeliveryStatus`; a batch report returns the
+// bitmap of the entries it recorded.
+sol_interface! {
+    interface ISettlementVerifier {
+        function verifyAdapterMessage(bytes32 message_id, uint256 intent_id) external returns (bool);
+
+        function verifyAdapterDelivery(bytes32 message_id, uint256 intent_id, uint8 status) external returns (bool);
+
+        function verifyAdapterDeliveries(
+            bytes32[] message_ids,
+            uint256[] intent_ids,
+            uint8[] statuses
+        ) external returns (uint256);
+
+        function getSettlementStatus(uint256 intent_id) external view returns (uint256);
+    }
+}
+
+// Optional config-sync extension, for adapters that can carry governance
+// messages built by `encoding::encode_config_sync` between deployments
+sol_interface! {
+    interface IConfigSyncAdapter {
+        function quoteConfigFee(uint256 destination_chain, bytes message) external view returns (uint256);
+
+        function sendConfig(uint256 destination_chain, bytes message) external payable returns (bytes32);
+    }
+}
+
+// Contract an adapter applies inbound config-sync messages to. Returns the
+// applied message's nonce.
+sol_interface! {
+    interface IConfigSyncTarget {
+        function applyConfigSync(uint256 source_chain, bytes message) external returns (uint256);
+    }
+}
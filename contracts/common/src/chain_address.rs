@@ -0,0 +1,123 @@
+//! Chain-Agnostic Addresses
+//!
+//! Recipients on chains outside the EVM, held as a chain family plus the
+//! address's raw bytes. An intent bound for an EVM chain keeps its 20-byte
+//! `recipient`; one bound for Solana or a Cosmos chain sets
+//! `recipientFamily` and carries the address in `recipientAddress` instead.
+//! `validate_chain_address` checks those bytes against the family's address
+//! format. Adapters able to reach such chains implement
+//! `IChainAddressAdapter`, encode the address the way their messaging layer
+//! expects (usually the 32-byte universal address from `to_bytes32`) and send
+//! a `ChainAddressPayload` to their destination peer.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use alloy_sol_types::{sol, SolValue};
+use stylus_sdk::alloy_primitives::{Address, Bytes, FixedBytes, U256};
+
+sol! {
+    /// Payload carried to a non-EVM destination in place of `BridgePayload`
+    ///
+    /// `recipient` holds the raw address bytes of `recipientFamily`.
+    struct ChainAddressPayload {
+        uint256 intentId;
+        address token;
+        uint256 amount;
+        uint8 recipientFamily;
+        bytes recipient;
+    }
+}
+
+/// Address formats a recipient can be given in
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ChainFamily {
+    /// 20-byte account, given in the intent's `recipient`
+    Evm = 0,
+    /// 32-byte ed25519 public key or token account
+    Solana = 1,
+    /// Bech32 account's 20-byte payload, or 32 bytes for module and
+    /// interchain accounts
+    Cosmos = 2,
+}
+
+impl ChainFamily {
+    /// Convert from the raw discriminator
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(ChainFamily::Evm),
+            1 => Some(ChainFamily::Solana),
+            2 => Some(ChainFamily::Cosmos),
+            _ => None,
+        }
+    }
+
+    /// Whether `len` bytes is a valid address length for this family
+    pub fn accepts_length(self, len: usize) -> bool {
+        match self {
+            ChainFamily::Evm => len == 20,
+            ChainFamily::Solana => len == 32,
+            ChainFamily::Cosmos => len == 20 || len == 32,
+        }
+    }
+}
+
+/// Reasons a chain address is rejected
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ChainAddressError {
+    /// Family discriminator is unknown
+    UnknownFamily = 1,
+    /// Address length doesn't match the family's format
+    BadLength = 2,
+    /// Address is all zero bytes
+    ZeroAddress = 3,
+}
+
+/// Check an address's bytes against its family's format
+pub fn validate_chain_address(family: u8, address: &[u8]) -> Result<ChainFamily, ChainAddressError> {
+    let family = ChainFamily::from_u8(family).ok_or(ChainAddressError::UnknownFamily)?;
+    if !family.accepts_length(address.len()) {
+        return Err(ChainAddressError::BadLength);
+    }
+    if address.iter().all(|byte| *byte == 0) {
+        return Err(ChainAddressError::ZeroAddress);
+    }
+    Ok(family)
+}
+
+/// Address as a 32-byte universal address, left-padded with zeros
+///
+/// The form Wormhole, CCTP and LayerZero take recipients in. `None` for
+/// addresses longer than 32 bytes.
+pub fn to_bytes32(address: &[u8]) -> Option<FixedBytes<32>> {
+    let padding = 32usize.checked_sub(address.len())?;
+    let mut word = [0u8; 32];
+    word[padding..].copy_from_slice(address);
+    Some(FixedBytes::from(word))
+}
+
+/// ABI-encode the payload sent across a lane to a non-EVM recipient
+pub fn encode_chain_address_payload(
+    intent_id: U256,
+    token: Address,
+    amount: U256,
+    family: ChainFamily,
+    recipient: Bytes,
+) -> Vec<u8> {
+    ChainAddressPayload {
+        intentId: intent_id,
+        token,
+        amount,
+        recipientFamily: family as u8,
+        recipient,
+    }
+    .abi_encode()
+}
+
+/// Decode a non-EVM payload, returning `None` if it is malformed or its
+/// recipient isn't a valid address of its family
+pub fn decode_chain_address_payload(data: &[u8]) -> Option<ChainAddressPayload> {
+    let payload = ChainAddressPayload::abi_decode(data).ok()?;
+    validate_chain_address(payload.recipientFamily, &payload.recipient).ok()?;
+    Some(payload)
+}
@@ -0,0 +1,893 @@
+//! Encoding Helpers
+//!
+//! Pure encoding routines shared by the contracts and bridge adapters. Nothing in
+//! here touches storage or the host, so the helpers can be exercised directly
+//! from the integration tests. With the `serde` feature, `Intent`, `RouteStep`
+//! and `IntentReceipt` also (de)serialize to the JSON described in
+//! `schema/intent.schema.json`.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use alloy_sol_types::{sol, SolCall, SolValue};
+use stylus_sdk::alloy_primitives::{keccak256, Address, Bytes, FixedBytes, U256};
+
+use crate::payload_codec;
+
+sol! {
+    /// Payload carried by every bridge adapter to its destination peer
+    struct BridgePayload {
+        uint256 intentId;
+        address token;
+        uint256 amount;
+        address recipient;
+        bool unwrapNative;
+    }
+
+    /// Payload SwooshReceiver takes from an inbound lane
+    ///
+    /// A zero `swapAdapter` delivers `token` as bridged; otherwise `amount` is
+    /// swapped into `tokenOut` through the adapter with `swapData`.
+    struct ReceiverPayload {
+        uint256 intentId;
+        address token;
+        uint256 amount;
+        address recipient;
+        address swapAdapter;
+        address tokenOut;
+        uint256 minAmountOut;
+        bytes swapData;
+    }
+
+    /// Deliveries for several intents carried in one lane message
+    ///
+    /// Amortizes the lane's messaging fee across its entries. Each entry is
+    /// delivered and settled on its own, so one bad entry doesn't fail the
+    /// rest; see `BatchEntryFailure`.
+    struct SettlementBatch {
+        ReceiverPayload[] entries;
+    }
+
+    /// Governance message syncing a registry change from the hub to a spoke
+    ///
+    /// `nonce` is unique per source chain and is what spokes replay-protect
+    /// on; `chainId` or `token` is set depending on `action`.
+    struct ConfigSyncMessage {
+        uint256 sourceChain;
+        uint256 nonce;
+        uint8 action;
+        uint256 chainId;
+        address token;
+    }
+
+    /// One setting in a configuration export, see `ConfigEntryKind`
+    ///
+    /// Chains and flags go in `key`, addresses in `account`, amounts in
+    /// `value`. A token fee sets `key` to 1 when its override is enabled.
+    struct ConfigEntry {
+        uint8 kind;
+        uint256 key;
+        address account;
+        uint256 value;
+    }
+
+    /// Payload carried by adapters that bridge an ERC-721 or ERC-1155 token
+    struct NftBridgePayload {
+        uint256 intentId;
+        uint8 assetType;
+        address token;
+        uint256 tokenId;
+        uint256 amount;
+        address recipient;
+    }
+
+    /// User intent executed by RouteExecutor
+    ///
+    /// A non-zero `blockNumber` binds the intent to a recent source-chain
+    /// block: it only executes while that block's hash is still `blockHash`,
+    /// so an intent signed against a reorged-out chain can't be replayed.
+    ///
+    /// A non-zero `configEpoch` pins the intent to the validator's config
+    /// epoch it was validated under; it only executes while that epoch is
+    /// still compatible with the current fee and limit config.
+    ///
+    /// A non-zero `callback` is called with `onIntentSettled` and
+    /// `callbackData` once the intent settles or fails on this chain.
+    ///
+    /// A non-EVM `recipientFamily` delivers to `recipientAddress`, given in
+    /// that family's format, and leaves `recipient` zero; see `chain_address`.
+    ///
+    /// A non-zero `insurancePremiumBps` opts the intent into insurance and is
+    /// the most premium the user accepts; RouteExecutor charges its own rate.
+    ///
+    /// A non-zero `retryPolicy` asks SettlementVerifier to retry a delivery
+    /// that times out before failing the intent; see `retry_policy`.
+    ///
+    /// `memo` is the integrator's own reference, e.g. an order ID. Nothing
+    /// acts on it; RouteExecutor logs a non-zero memo with `IntentMemo` and
+    /// keeps it with the intent's record.
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    struct Intent {
+        address user;
+        address tokenIn;
+        uint256 amountIn;
+        uint256 destinationChain;
+        address recipient;
+        uint256 minAmountOut;
+        uint256 deadline;
+        uint256 nonce;
+        uint256 gasDrop;
+        uint8 assetType;
+        uint256 tokenId;
+        bytes32 recipientHash;
+        uint256 maxSolverFee;
+        uint256 priorityFee;
+        uint256 blockNumber;
+        bytes32 blockHash;
+        uint256 configEpoch;
+        address callback;
+        bytes callbackData;
+        uint8 recipientFamily;
+        bytes recipientAddress;
+        uint256 insurancePremiumBps;
+        uint256 retryPolicy;
+        bytes32 memo;
+    }
+
+    /// Single typed step of a route
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    struct RouteStep {
+        uint8 stepType;
+        address adapter;
+        address tokenIn;
+        address tokenOut;
+        uint256 minAmountOut;
+        uint256 deadline;
+        address recipient;
+        bytes data;
+    }
+
+    /// Outcome of an executed intent, as exchanged with off-chain services
+    ///
+    /// `intentHash` and `solver` are zero for intents the user executed
+    /// directly; `status` follows SettlementVerifier's settlement status.
+    /// `solverFee` is how far a solver's quoted route fell short of its quote.
+    /// `memo` carries the intent's memo through to settlement.
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    struct IntentReceipt {
+        uint256 intentId;
+        bytes32 intentHash;
+        address user;
+        address solver;
+        address tokenIn;
+        uint256 amountIn;
+        uint256 feeAmount;
+        uint256 solverFee;
+        uint256 destinationChain;
+        bytes32 messageId;
+        uint8 status;
+        uint256 executedAt;
+        uint256 settledAt;
+        bytes32 memo;
+    }
+
+    /// Route oracle's signed commitment to the output of a route for one intent
+    ///
+    /// `routeHash` is `keccak256(abi.encode(RouteStep[]))`; an `expiry` of
+    /// zero never expires.
+    struct RouteQuote {
+        bytes32 intentHash;
+        bytes32 routeHash;
+        uint256 quotedAmountOut;
+        uint256 expiry;
+    }
+
+    /// Intent a user signs for IntentValidator's `validate_signed_intent`
+    ///
+    /// The fields the validator checks, signed under the Swoosh domain with
+    /// the validator as verifying contract so a solver can get an intent
+    /// validated before the user sends any transaction. `nonce` keeps
+    /// otherwise identical intents apart.
+    struct ValidatorIntent {
+        address user;
+        address token;
+        uint256 amount;
+        uint256 destinationChain;
+        uint256 nonce;
+        uint256 deadline;
+    }
+
+    /// Intent recorded in IntentValidator's intent registry
+    ///
+    /// Keyed by its EIP-712 digest under the Swoosh domain with the
+    /// validator as verifying contract (`signing::registered_intent_digest`),
+    /// which is also what the user signs for someone else to register it.
+    /// `nonce` must be the user's next registry nonce, so each registration
+    /// happens once.
+    struct RegisteredIntent {
+        address user;
+        address token;
+        uint256 amount;
+        uint256 destinationChain;
+        address recipient;
+        uint256 deadline;
+        uint256 nonce;
+    }
+
+    /// Called on an intent's callback contract when it settles or fails
+    ///
+    /// `status` follows SettlementVerifier's settlement status and `data` is
+    /// the intent's `callbackData`.
+    function onIntentSettled(uint256 intentId, uint8 status, bytes data) external;
+}
+
+/// Sentinel token address standing for native ETH in intents and route steps
+pub const NATIVE_TOKEN: Address = Address::ZERO;
+
+/// Route step types
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StepType {
+    Swap = 0,
+    Bridge = 1,
+    Wrap = 2,
+    Unwrap = 3,
+    Transfer = 4,
+}
+
+impl StepType {
+    /// Parse the `stepType` discriminator of a route step
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(StepType::Swap),
+            1 => Some(StepType::Bridge),
+            2 => Some(StepType::Wrap),
+            3 => Some(StepType::Unwrap),
+            4 => Some(StepType::Transfer),
+            _ => None,
+        }
+    }
+
+    /// Whether the step hands funds off and must end the route
+    pub fn is_terminal(self) -> bool {
+        matches!(self, StepType::Bridge | StepType::Transfer)
+    }
+}
+
+/// Kind of asset an intent moves
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AssetType {
+    Erc20 = 0,
+    Erc721 = 1,
+    Erc1155 = 2,
+}
+
+impl AssetType {
+    /// Parse the `assetType` discriminator of an intent
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(AssetType::Erc20),
+            1 => Some(AssetType::Erc721),
+            2 => Some(AssetType::Erc1155),
+            _ => None,
+        }
+    }
+
+    /// Whether the asset is a non-fungible token identified by `tokenId`
+    pub fn is_nft(self) -> bool {
+        matches!(self, AssetType::Erc721 | AssetType::Erc1155)
+    }
+}
+
+/// Outcome of a destination delivery, recorded by SettlementVerifier as the
+/// intent's settlement status
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DeliveryStatus {
+    /// Delivered as the intent asked
+    Delivered = 1,
+    /// Destination swap failed; the bridged token was delivered instead
+    PartialDelivery = 4,
+    /// Push delivery failed; the recipient was credited a claimable balance
+    DeliveredToClaim = 5,
+}
+
+impl DeliveryStatus {
+    /// Parse a reported delivery status
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(DeliveryStatus::Delivered),
+            4 => Some(DeliveryStatus::PartialDelivery),
+            5 => Some(DeliveryStatus::DeliveredToClaim),
+            _ => None,
+        }
+    }
+}
+
+/// Why an entry of a settlement batch was skipped
+///
+/// A skipped entry's tokens are never pulled from the lane, so it can be
+/// retried on its own or refunded on the source chain.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BatchEntryFailure {
+    /// Zero recipient or amount
+    InvalidEntry = 1,
+    /// Same intent as an earlier entry of the batch
+    DuplicateIntent = 2,
+    /// SettlementVerifier already settled the intent
+    AlreadySettled = 3,
+    /// The lane didn't hand over the entry's tokens
+    TransferFailed = 4,
+}
+
+impl BatchEntryFailure {
+    /// Parse a reported batch entry failure
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(BatchEntryFailure::InvalidEntry),
+            2 => Some(BatchEntryFailure::DuplicateIntent),
+            3 => Some(BatchEntryFailure::AlreadySettled),
+            4 => Some(BatchEntryFailure::TransferFailed),
+            _ => None,
+        }
+    }
+}
+
+/// Status of an intent in IntentValidator's intent registry
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RegisteredIntentStatus {
+    /// Never registered
+    None = 0,
+    /// Registered and waiting to execute
+    Pending = 1,
+    /// Marked executed by a consumer contract
+    Executed = 2,
+    /// Cancelled by its user
+    Cancelled = 3,
+    /// Still pending when its deadline passed
+    Expired = 4,
+}
+
+impl RegisteredIntentStatus {
+    /// Parse a registry status
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(RegisteredIntentStatus::None),
+            1 => Some(RegisteredIntentStatus::Pending),
+            2 => Some(RegisteredIntentStatus::Executed),
+            3 => Some(RegisteredIntentStatus::Cancelled),
+            4 => Some(RegisteredIntentStatus::Expired),
+            _ => None,
+        }
+    }
+
+    /// Status as of `now`: a pending intent past its deadline has expired
+    ///
+    /// Expiry is never stored, so it takes effect without a transaction.
+    pub fn at(self, deadline: U256, now: U256) -> Self {
+        if self == RegisteredIntentStatus::Pending && now > deadline {
+            RegisteredIntentStatus::Expired
+        } else {
+            self
+        }
+    }
+}
+
+/// Registry change carried by a config-sync message
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConfigAction {
+    /// Support `chainId` as a destination chain
+    AddChain = 1,
+    /// Support `token` as an input token
+    AddToken = 2,
+}
+
+impl ConfigAction {
+    /// Parse the `action` discriminator of a config-sync message
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(ConfigAction::AddChain),
+            2 => Some(ConfigAction::AddToken),
+            _ => None,
+        }
+    }
+}
+
+/// Setting a `ConfigEntry` restores
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConfigEntryKind {
+    /// IntentValidator supported destination chain (`key`)
+    SupportedChain = 1,
+    /// IntentValidator supported token (`account`)
+    SupportedToken = 2,
+    /// RouteExecutor bridge adapter (`account`) for a destination chain (`key`)
+    BridgeAdapter = 3,
+    /// RouteExecutor swap adapter (`account`), allowed if `value` is non-zero
+    SwapAdapter = 4,
+    /// RouteExecutor fee override of `value` bps for a token (`account`)
+    TokenFee = 5,
+    /// RouteExecutor default protocol fee in bps (`value`)
+    ProtocolFee = 6,
+    /// RouteExecutor fee recipient (`account`)
+    FeeRecipient = 7,
+}
+
+impl ConfigEntryKind {
+    /// Parse the `kind` discriminator of a config entry
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(ConfigEntryKind::SupportedChain),
+            2 => Some(ConfigEntryKind::SupportedToken),
+            3 => Some(ConfigEntryKind::BridgeAdapter),
+            4 => Some(ConfigEntryKind::SwapAdapter),
+            5 => Some(ConfigEntryKind::TokenFee),
+            6 => Some(ConfigEntryKind::ProtocolFee),
+            7 => Some(ConfigEntryKind::FeeRecipient),
+            _ => None,
+        }
+    }
+}
+
+/// Pack a config entry's kind and key into one word for an enumerable index
+///
+/// The kind takes the top byte; chain IDs and addresses fit below it.
+pub fn pack_config_key(kind: ConfigEntryKind, key: U256) -> U256 {
+    (U256::from(kind as u8) << 248) | (key & (U256::MAX >> 8))
+}
+
+/// Pack the kind and address of an address-keyed config entry
+pub fn pack_config_address(kind: ConfigEntryKind, account: Address) -> U256 {
+    pack_config_key(kind, U256::from_be_slice(account.as_slice()))
+}
+
+/// Address held in the key of an address-keyed config entry
+pub fn config_key_address(key: U256) -> Address {
+    Address::from_slice(&key.to_be_bytes::<32>()[12..])
+}
+
+/// Split a packed config key back into its kind and key
+pub fn unpack_config_key(packed: U256) -> (Option<ConfigEntryKind>, U256) {
+    let kind = ConfigEntryKind::from_u8(packed.byte(31));
+    (kind, packed & (U256::MAX >> 8))
+}
+
+/// ABI-encode a page of config entries
+pub fn encode_config_page(entries: &[ConfigEntry]) -> Vec<u8> {
+    entries.abi_encode()
+}
+
+/// Decode a page of config entries, returning `None` for malformed input
+pub fn decode_config_page(data: &[u8]) -> Option<Vec<ConfigEntry>> {
+    Vec::<ConfigEntry>::abi_decode(data).ok()
+}
+
+/// Prefix marking a lane message as config sync rather than a bridge payload
+///
+/// Bridge payloads start with a 32-byte intent ID whose high bytes are zero,
+/// so the two can't be confused.
+pub const CONFIG_SYNC_TAG: [u8; 4] = *b"SWcs";
+
+/// Prefix marking a lane message as a settlement batch
+pub const SETTLEMENT_BATCH_TAG: [u8; 4] = *b"SWsb";
+
+/// Most entries a settlement batch may carry, bounding the receiver's gas
+pub const MAX_SETTLEMENT_BATCH: usize = 64;
+
+/// LayerZero v2 options container type
+pub const LZ_OPTIONS_TYPE_3: u16 = 3;
+/// LayerZero v2 executor worker id
+pub const LZ_EXECUTOR_WORKER_ID: u8 = 1;
+/// LayerZero v2 executor option: gas (and value) for lzReceive
+pub const LZ_OPTION_TYPE_LZRECEIVE: u8 = 1;
+/// LayerZero v2 executor option: native drop to an address on the destination
+pub const LZ_OPTION_TYPE_NATIVE_DROP: u8 = 2;
+/// LayerZero v2 executor option: gas (and value) for lzCompose
+pub const LZ_OPTION_TYPE_LZCOMPOSE: u8 = 3;
+
+/// Encode LayerZero v2 type-3 options with a single executor lzReceive option
+///
+/// Layout: `0x0003 | workerId(1) | optionLength(2) | optionType(1) | gas(16) [| value(16)]`.
+/// The value is only appended when non-zero, matching OptionsBuilder.
+pub fn lz_receive_options(gas: u128, value: u128) -> Vec<u8> {
+    let option_len: u16 = if value == 0 { 16 } else { 32 };
+
+    let mut options = Vec::with_capacity(6 + option_len as usize);
+    options.extend_from_slice(&LZ_OPTIONS_TYPE_3.to_be_bytes());
+    options.push(LZ_EXECUTOR_WORKER_ID);
+    options.extend_from_slice(&(option_len + 1).to_be_bytes());
+    options.push(LZ_OPTION_TYPE_LZRECEIVE);
+    options.extend_from_slice(&gas.to_be_bytes());
+    if value != 0 {
+        options.extend_from_slice(&value.to_be_bytes());
+    }
+    options
+}
+
+/// Encode LayerZero v2 type-3 options with a single executor lzCompose option
+///
+/// Layout: `0x0003 | workerId(1) | optionLength(2) | optionType(1) | index(2) | gas(16) [| value(16)]`.
+pub fn lz_compose_options(index: u16, gas: u128, value: u128) -> Vec<u8> {
+    let option_len: u16 = if value == 0 { 18 } else { 34 };
+
+    let mut options = Vec::with_capacity(6 + option_len as usize);
+    options.extend_from_slice(&LZ_OPTIONS_TYPE_3.to_be_bytes());
+    options.push(LZ_EXECUTOR_WORKER_ID);
+    options.extend_from_slice(&(option_len + 1).to_be_bytes());
+    options.push(LZ_OPTION_TYPE_LZCOMPOSE);
+    options.extend_from_slice(&index.to_be_bytes());
+    options.extend_from_slice(&gas.to_be_bytes());
+    if value != 0 {
+        options.extend_from_slice(&value.to_be_bytes());
+    }
+    options
+}
+
+/// Append an executor native-drop option to LayerZero v2 type-3 options
+///
+/// Layout: `workerId(1) | optionLength(2) | optionType(1) | amount(16) | receiver(32)`.
+/// The executor pays `amount` of destination native to `receiver` on delivery.
+pub fn append_lz_native_drop(options: &mut Vec<u8>, amount: u128, receiver: Address) {
+    options.push(LZ_EXECUTOR_WORKER_ID);
+    options.extend_from_slice(&49u16.to_be_bytes());
+    options.push(LZ_OPTION_TYPE_NATIVE_DROP);
+    options.extend_from_slice(&amount.to_be_bytes());
+    options.extend_from_slice(address_to_bytes32(receiver).as_slice());
+}
+
+/// Header bytes of an OFT compose message before the app payload
+const OFT_COMPOSE_HEADER_SIZE: usize = 76;
+
+/// Fields of an OFT compose message delivered to lzCompose
+pub struct OftComposeMsg<'a> {
+    pub nonce: u64,
+    pub src_eid: u32,
+    pub amount: U256,
+    pub compose_from: FixedBytes<32>,
+    pub message: &'a [u8],
+}
+
+/// Split an OFT (or Stargate) compose message into its fields
+///
+/// Layout: `nonce(8) | srcEid(4) | amountLD(32) | composeFrom(32) | composeMsg`,
+/// as built by OFTComposeMsgCodec. `amountLD` is what the OFT credited.
+pub fn parse_oft_compose_msg(data: &[u8]) -> Option<OftComposeMsg<'_>> {
+    if data.len() < OFT_COMPOSE_HEADER_SIZE {
+        return None;
+    }
+
+    Some(OftComposeMsg {
+        nonce: u64::from_be_bytes(data[0..8].try_into().ok()?),
+        src_eid: u32::from_be_bytes(data[8..12].try_into().ok()?),
+        amount: U256::from_be_slice(&data[12..44]),
+        compose_from: FixedBytes::<32>::from_slice(&data[44..76]),
+        message: &data[OFT_COMPOSE_HEADER_SIZE..],
+    })
+}
+
+/// Left-pad an EVM address into a bytes32 peer identifier
+pub fn address_to_bytes32(address: Address) -> FixedBytes<32> {
+    let mut out = [0u8; 32];
+    out[12..].copy_from_slice(address.as_slice());
+    FixedBytes::<32>::from(out)
+}
+
+/// Take the low 20 bytes of a bytes32 peer identifier as an EVM address
+pub fn bytes32_to_address(value: FixedBytes<32>) -> Address {
+    Address::from_slice(&value[12..])
+}
+
+/// Encode the payload sent across a bridge lane, see `payload_codec`
+///
+/// `unwrap_native` asks the destination adapter to unwrap the delivered
+/// wrapped-native token and pay the recipient in ETH.
+pub fn encode_bridge_payload(
+    intent_id: U256,
+    token: Address,
+    amount: U256,
+    recipient: Address,
+    unwrap_native: bool,
+) -> Vec<u8> {
+    payload_codec::encode_bridge(&BridgePayload {
+        intentId: intent_id,
+        token,
+        amount,
+        recipient,
+        unwrapNative: unwrap_native,
+    })
+}
+
+/// Decode a bridge payload, returning `None` for malformed input
+///
+/// Contracts use `payload_codec::decode_bridge` to learn why a payload was rejected.
+pub fn decode_bridge_payload(data: &[u8]) -> Option<BridgePayload> {
+    payload_codec::decode_bridge(data).ok()
+}
+
+/// Encode a config-sync message, tagged so adapters can route it
+pub fn encode_config_sync(
+    source_chain: U256,
+    nonce: U256,
+    action: ConfigAction,
+    chain_id: U256,
+    token: Address,
+) -> Vec<u8> {
+    let mut message = CONFIG_SYNC_TAG.to_vec();
+    message.extend_from_slice(
+        &ConfigSyncMessage {
+            sourceChain: source_chain,
+            nonce,
+            action: action as u8,
+            chainId: chain_id,
+            token,
+        }
+        .abi_encode(),
+    );
+    message
+}
+
+/// Whether a lane message is a config-sync message
+pub fn is_config_sync(data: &[u8]) -> bool {
+    data.starts_with(&CONFIG_SYNC_TAG)
+}
+
+/// Decode a tagged config-sync message, returning `None` for untagged or
+/// malformed input
+pub fn decode_config_sync(data: &[u8]) -> Option<ConfigSyncMessage> {
+    ConfigSyncMessage::abi_decode(data.strip_prefix(&CONFIG_SYNC_TAG)?).ok()
+}
+
+/// Decode a SwooshReceiver payload, returning `None` for malformed input
+pub fn decode_receiver_payload(data: &[u8]) -> Option<ReceiverPayload> {
+    payload_codec::decode_receiver(data).ok()
+}
+
+/// Encode a settlement batch, tagged so it is never mistaken for a single
+/// `ReceiverPayload`
+pub fn encode_settlement_batch(entries: Vec<ReceiverPayload>) -> Vec<u8> {
+    let mut message = SETTLEMENT_BATCH_TAG.to_vec();
+    message.extend_from_slice(&SettlementBatch { entries }.abi_encode());
+    message
+}
+
+/// Whether a lane message is a settlement batch
+pub fn is_settlement_batch(data: &[u8]) -> bool {
+    data.starts_with(&SETTLEMENT_BATCH_TAG)
+}
+
+/// Decode a tagged settlement batch
+///
+/// Returns `None` for untagged or malformed input, and for an empty batch or
+/// one over `MAX_SETTLEMENT_BATCH` entries.
+pub fn decode_settlement_batch(data: &[u8]) -> Option<Vec<ReceiverPayload>> {
+    let batch = SettlementBatch::abi_decode(data.strip_prefix(&SETTLEMENT_BATCH_TAG)?).ok()?;
+    if batch.entries.is_empty() || batch.entries.len() > MAX_SETTLEMENT_BATCH {
+        return None;
+    }
+    Some(batch.entries)
+}
+
+/// Message ID reported to SettlementVerifier for entry `index` of a batch
+///
+/// `keccak256(abi.encode(batchId, index))`, where the batch ID is the hash of
+/// the whole lane message.
+pub fn settlement_batch_entry_id(batch_id: FixedBytes<32>, index: usize) -> FixedBytes<32> {
+    keccak256((batch_id, U256::from(index)).abi_encode())
+}
+
+/// Gas an intent's settlement callback may use
+pub const CALLBACK_GAS_LIMIT: u64 = 200_000;
+
+/// Most bytes of `callbackData` an intent may carry
+pub const MAX_CALLBACK_DATA: usize = 1024;
+
+/// Calldata for an intent's settlement callback
+pub fn encode_settlement_callback(intent_id: U256, status: u8, data: Bytes) -> Vec<u8> {
+    onIntentSettledCall {
+        intentId: intent_id,
+        status,
+        data,
+    }
+    .abi_encode()
+}
+
+/// Encode the payload sent across a lane for an NFT intent, see `payload_codec`
+pub fn encode_nft_bridge_payload(
+    intent_id: U256,
+    asset_type: AssetType,
+    token: Address,
+    token_id: U256,
+    amount: U256,
+    recipient: Address,
+) -> Vec<u8> {
+    payload_codec::encode_nft(&NftBridgePayload {
+        intentId: intent_id,
+        assetType: asset_type as u8,
+        token,
+        tokenId: token_id,
+        amount,
+        recipient,
+    })
+}
+
+/// Decode an NFT bridge payload, returning `None` for anything else
+///
+/// Only a payload naming an NFT asset type is accepted, so a fungible
+/// `BridgePayload` is never mistaken for one.
+pub fn decode_nft_bridge_payload(data: &[u8]) -> Option<NftBridgePayload> {
+    payload_codec::decode_nft(data).ok()
+}
+
+/// Hyperlane StandardHookMetadata variant
+pub const HYPERLANE_HOOK_METADATA_VARIANT: u16 = 1;
+
+/// Encode Hyperlane StandardHookMetadata
+///
+/// Layout: `variant(2) | msgValue(32) | gasLimit(32) | refundAddress(20)`.
+pub fn hyperlane_hook_metadata(msg_value: U256, gas_limit: U256, refund_address: Address) -> Vec<u8> {
+    let mut metadata = Vec::with_capacity(86);
+    metadata.extend_from_slice(&HYPERLANE_HOOK_METADATA_VARIANT.to_be_bytes());
+    metadata.extend_from_slice(&msg_value.to_be_bytes::<32>());
+    metadata.extend_from_slice(&gas_limit.to_be_bytes::<32>());
+    metadata.extend_from_slice(refund_address.as_slice());
+    metadata
+}
+
+/// CCIP `EVMExtraArgsV1` tag, `bytes4(keccak256("CCIP EVMExtraArgsV1"))`
+pub const CCIP_EVM_EXTRA_ARGS_V1_TAG: [u8; 4] = [0x97, 0xa6, 0x57, 0xc9];
+
+/// Encode CCIP `EVMExtraArgsV1` with the destination gas limit
+///
+/// Layout: `tag(4) | gasLimit(32)`.
+pub fn ccip_extra_args(gas_limit: U256) -> Vec<u8> {
+    let mut args = Vec::with_capacity(36);
+    args.extend_from_slice(&CCIP_EVM_EXTRA_ARGS_V1_TAG);
+    args.extend_from_slice(&gas_limit.to_be_bytes::<32>());
+    args
+}
+
+/// Wormhole Token Bridge payload ID of a transfer carrying a payload
+pub const WORMHOLE_TRANSFER_WITH_PAYLOAD: u8 = 3;
+/// Length of one guardian signature in a VAA
+const WORMHOLE_SIGNATURE_SIZE: usize = 66;
+/// VAA header bytes before the signatures (version, guardian set, count)
+const WORMHOLE_HEADER_SIZE: usize = 6;
+/// Body bytes before the payload (timestamp, nonce, emitter, sequence, consistency)
+const WORMHOLE_BODY_PREFIX_SIZE: usize = 51;
+/// Offset of `fromAddress` in a TransferWithPayload
+const WORMHOLE_TRANSFER_FROM_OFFSET: usize = 101;
+
+/// Emitter and payload of a Wormhole VAA
+pub struct VaaBody<'a> {
+    pub emitter_chain: u16,
+    pub emitter_address: FixedBytes<32>,
+    pub sequence: u64,
+    pub payload: &'a [u8],
+}
+
+/// Split an encoded VAA into its emitter and payload, without verifying it
+///
+/// Layout: `version(1) | guardianSet(4) | sigCount(1) | sigs(66 each) |
+/// timestamp(4) | nonce(4) | emitterChain(2) | emitter(32) | sequence(8) |
+/// consistency(1) | payload`. Signatures are checked by the Wormhole core
+/// contract (or the Token Bridge redeeming it), never here.
+pub fn parse_vaa_body(vaa: &[u8]) -> Option<VaaBody<'_>> {
+    let signatures = *vaa.get(WORMHOLE_HEADER_SIZE - 1)? as usize;
+    let body = vaa.get(WORMHOLE_HEADER_SIZE + signatures * WORMHOLE_SIGNATURE_SIZE..)?;
+    if body.len() < WORMHOLE_BODY_PREFIX_SIZE {
+        return None;
+    }
+
+    Some(VaaBody {
+        emitter_chain: u16::from_be_bytes([body[8], body[9]]),
+        emitter_address: FixedBytes::<32>::from_slice(&body[10..42]),
+        sequence: u64::from_be_bytes(body[42..50].try_into().ok()?),
+        payload: &body[WORMHOLE_BODY_PREFIX_SIZE..],
+    })
+}
+
+/// Sender and inner payload of a Token Bridge TransferWithPayload
+///
+/// Layout: `payloadId(1) | amount(32) | token(32) | tokenChain(2) | to(32) |
+/// toChain(2) | fromAddress(32) | payload`.
+pub fn parse_wormhole_transfer(transfer: &[u8]) -> Option<(FixedBytes<32>, &[u8])> {
+    if transfer.first() != Some(&WORMHOLE_TRANSFER_WITH_PAYLOAD) {
+        return None;
+    }
+    let from = transfer.get(WORMHOLE_TRANSFER_FROM_OFFSET..WORMHOLE_TRANSFER_FROM_OFFSET + 32)?;
+    Some((FixedBytes::<32>::from_slice(from), &transfer[WORMHOLE_TRANSFER_FROM_OFFSET + 32..]))
+}
+
+/// Size of an address in a packed Uniswap V3 path
+const V3_PATH_ADDRESS_SIZE: usize = 20;
+/// Size of an address plus fee tier in a packed Uniswap V3 path
+const V3_PATH_HOP_SIZE: usize = 23;
+
+/// Return the first and last token of a packed Uniswap V3 path
+///
+/// Path layout: `token(20) | fee(3) | token(20) [| fee(3) | token(20) ...]`.
+/// Returns `None` if the length is not a whole number of hops.
+pub fn v3_path_endpoints(path: &[u8]) -> Option<(Address, Address)> {
+    if path.len() < V3_PATH_ADDRESS_SIZE + V3_PATH_HOP_SIZE
+        || (path.len() - V3_PATH_ADDRESS_SIZE) % V3_PATH_HOP_SIZE != 0
+    {
+        return None;
+    }
+
+    let first = Address::from_slice(&path[..V3_PATH_ADDRESS_SIZE]);
+    let last = Address::from_slice(&path[path.len() - V3_PATH_ADDRESS_SIZE..]);
+    Some((first, last))
+}
+
+/// Split a packed Uniswap V3 path into `(token_in, fee, token_out)` hops
+///
+/// Returns `None` for the same malformed paths `v3_path_endpoints` rejects.
+pub fn v3_path_hops(path: &[u8]) -> Option<Vec<(Address, u32, Address)>> {
+    v3_path_endpoints(path)?;
+
+    let hop_count = (path.len() - V3_PATH_ADDRESS_SIZE) / V3_PATH_HOP_SIZE;
+    let mut hops = Vec::with_capacity(hop_count);
+    for hop in 0..hop_count {
+        let start = hop * V3_PATH_HOP_SIZE;
+        let token_in = Address::from_slice(&path[start..start + V3_PATH_ADDRESS_SIZE]);
+        let fee = u32::from_be_bytes([0, path[start + 20], path[start + 21], path[start + 22]]);
+        let next = start + V3_PATH_HOP_SIZE;
+        let token_out = Address::from_slice(&path[next..next + V3_PATH_ADDRESS_SIZE]);
+        hops.push((token_in, fee, token_out));
+    }
+    Some(hops)
+}
+
+/// Maximum number of Uniswap V3 fee tiers configurable per pair
+pub const MAX_V3_FEE_TIERS: usize = 4;
+/// Largest Uniswap V3 fee (100% in hundredths of a bip)
+const V3_MAX_FEE: u32 = 1_000_000;
+
+/// Pack per-pair Uniswap V3 fee tiers into one word, 24 bits each
+///
+/// Returns `None` for an empty or oversized list, or a zero or out-of-range
+/// fee (zero marks the end of the list when unpacking).
+pub fn pack_v3_fee_tiers(fees: &[u32]) -> Option<U256> {
+    if fees.is_empty() || fees.len() > MAX_V3_FEE_TIERS {
+        return None;
+    }
+
+    let mut packed = U256::ZERO;
+    for (index, fee) in fees.iter().enumerate() {
+        if *fee == 0 || *fee >= V3_MAX_FEE {
+            return None;
+        }
+        packed |= U256::from(*fee) << (24 * index);
+    }
+    Some(packed)
+}
+
+/// Unpack fee tiers stored with `pack_v3_fee_tiers`
+pub fn unpack_v3_fee_tiers(packed: U256) -> Vec<u32> {
+    (0..MAX_V3_FEE_TIERS)
+        .map(|index| ((packed >> (24 * index)) & U256::from(0x00FF_FFFFu32)).to::<u32>())
+        .take_while(|fee| *fee != 0)
+        .collect()
+}
+
+/// Order two tokens the way Uniswap pools do (lower address first)
+pub fn sort_tokens(token_a: Address, token_b: Address) -> (Address, Address) {
+    if token_a < token_b {
+        (token_a, token_b)
+    } else {
+        (token_b, token_a)
+    }
+}
+
+/// Resolve the token path for a Uniswap V2-style router
+///
+/// Empty `data` means the direct pair; otherwise `data` is an ABI-encoded
+/// `address[]` that must start at `token_in` and end at `token_out`.
+pub fn v2_swap_path(data: &[u8], token_in: Address, token_out: Address) -> Option<Vec<Address>> {
+    if data.is_empty() {
+        return Some(alloc::vec![token_in, token_out]);
+    }
+
+    let path = Vec::<Address>::abi_decode(data).ok()?;
+    if path.len() < 2 || path[0] != token_in || path[path.len() - 1] != token_out {
+        return None;
+    }
+    Some(path)
+}
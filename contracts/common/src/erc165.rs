@@ -0,0 +1,205 @@
+//! ERC-165 Interface Detection
+//!
+//! Interface ids for the Swoosh extension points, derived from the function
+//! selectors of the interfaces in `bridge_adapter`, `swap_adapter` and
+//! `encoding` rather than written out by hand, so an id can't drift from the
+//! interface it names. Every contract answers `supportsInterface` for the ids
+//! it implements, and the registration setters run the standard ERC-165
+//! detection on third-party adapters and callbacks, so a misconfigured
+//! address is rejected when it is registered instead of when an intent first
+//! routes through it.
+//!
+//! An id is the XOR of the selectors of the functions it covers. Optional
+//! functions get their own id: an adapter advertising `BRIDGE_ADAPTER_ID`
+//! only promises `quoteFee`, `bridge` and `supportsLane`, and the gas-drop,
+//! gas-limit, NFT, non-EVM and config-sync extensions are detected on top.
+
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use alloy_sol_types::SolCall;
+use stylus_sdk::prelude::*;
+
+use crate::encoding::onIntentSettledCall;
+use selectors::*;
+
+// ERC-165 interface (queried on every address passed to a registration setter)
+sol_interface! {
+    interface IERC165 {
+        function supportsInterface(bytes4 interface_id) external view returns (bool);
+    }
+}
+
+// Selectors of the interfaces below, matching their `sol_interface!` definitions
+mod selectors {
+    alloy_sol_types::sol! {
+        function supportsInterface(bytes4 interface_id) external view returns (bool);
+
+        function quoteFee(uint256 destination_chain, address token, uint256 amount, address recipient)
+            external view returns (uint256);
+        function bridge(
+            uint256 intent_id,
+            uint256 destination_chain,
+            address token,
+            uint256 amount,
+            address recipient,
+            bool unwrap_native
+        ) external payable returns (bytes32);
+        function supportsLane(uint256 destination_chain) external view returns (bool);
+
+        function quoteFeeWithGasDrop(
+            uint256 destination_chain,
+            address token,
+            uint256 amount,
+            address recipient,
+            uint256 gas_drop
+        ) external view returns (uint256);
+        function bridgeWithGasDrop(
+            uint256 intent_id,
+            uint256 destination_chain,
+            address token,
+            uint256 amount,
+            address recipient,
+            bool unwrap_native,
+            uint256 gas_drop
+        ) external payable returns (bytes32);
+
+        function quoteFeeWithGasLimit(
+            uint256 destination_chain,
+            address token,
+            uint256 amount,
+            address recipient,
+            uint256 gas_limit
+        ) external view returns (uint256);
+        function bridgeWithGasLimit(
+            uint256 intent_id,
+            uint256 destination_chain,
+            address token,
+            uint256 amount,
+            address recipient,
+            bool unwrap_native,
+            uint256 gas_limit
+        ) external payable returns (bytes32);
+
+        function quoteNftFee(
+            uint256 destination_chain,
+            uint8 asset_type,
+            address token,
+            uint256 token_id,
+            uint256 amount,
+            address recipient
+        ) external view returns (uint256);
+        function bridgeNft(
+            uint256 intent_id,
+            uint256 destination_chain,
+            uint8 asset_type,
+            address token,
+            uint256 token_id,
+            uint256 amount,
+            address recipient
+        ) external payable returns (bytes32);
+
+        function quoteChainAddressFee(
+            uint256 destination_chain,
+            address token,
+            uint256 amount,
+            uint8 recipient_family,
+            bytes recipient
+        ) external view returns (uint256);
+        function bridgeToChainAddress(
+            uint256 intent_id,
+            uint256 destination_chain,
+            address token,
+            uint256 amount,
+            uint8 recipient_family,
+            bytes recipient
+        ) external payable returns (bytes32);
+
+        function quoteConfigFee(uint256 destination_chain, bytes message) external view returns (uint256);
+        function sendConfig(uint256 destination_chain, bytes message) external payable returns (bytes32);
+        function applyConfigSync(uint256 source_chain, bytes message) external returns (uint256);
+
+        function swap(
+            address token_in,
+            address token_out,
+            uint256 amount_in,
+            uint256 min_amount_out,
+            address recipient,
+            bytes data
+        ) external returns (uint256);
+        function quote(address token_in, address token_out, uint256 amount_in, bytes data)
+            external returns (uint256);
+    }
+}
+
+/// `supportsInterface` itself
+pub const ERC165_ID: [u8; 4] = supportsInterfaceCall::SELECTOR;
+
+/// Id no contract may claim, used to catch contracts answering true to everything
+pub const INVALID_ID: [u8; 4] = [0xff; 4];
+
+/// `IBridgeAdapter`, required of every bridge adapter
+pub const BRIDGE_ADAPTER_ID: [u8; 4] =
+    interface_id(&[quoteFeeCall::SELECTOR, bridgeCall::SELECTOR, supportsLaneCall::SELECTOR]);
+
+/// `IGasDropAdapter`
+pub const GAS_DROP_ADAPTER_ID: [u8; 4] =
+    interface_id(&[quoteFeeWithGasDropCall::SELECTOR, bridgeWithGasDropCall::SELECTOR]);
+
+/// `IGasLimitAdapter`
+pub const GAS_LIMIT_ADAPTER_ID: [u8; 4] =
+    interface_id(&[quoteFeeWithGasLimitCall::SELECTOR, bridgeWithGasLimitCall::SELECTOR]);
+
+/// `INftBridgeAdapter`
+pub const NFT_BRIDGE_ADAPTER_ID: [u8; 4] = interface_id(&[quoteNftFeeCall::SELECTOR, bridgeNftCall::SELECTOR]);
+
+/// `IChainAddressAdapter`
+pub const CHAIN_ADDRESS_ADAPTER_ID: [u8; 4] =
+    interface_id(&[quoteChainAddressFeeCall::SELECTOR, bridgeToChainAddressCall::SELECTOR]);
+
+/// `IConfigSyncAdapter`
+pub const CONFIG_SYNC_ADAPTER_ID: [u8; 4] = interface_id(&[quoteConfigFeeCall::SELECTOR, sendConfigCall::SELECTOR]);
+
+/// `IConfigSyncTarget`
+pub const CONFIG_SYNC_TARGET_ID: [u8; 4] = applyConfigSyncCall::SELECTOR;
+
+/// `ISwapAdapter::swap`, required of every swap adapter
+pub const SWAP_ADAPTER_ID: [u8; 4] = swapCall::SELECTOR;
+
+/// `ISwapAdapter::quote`, for swap adapters that can estimate output
+pub const SWAP_QUOTER_ID: [u8; 4] = quoteCall::SELECTOR;
+
+/// `onIntentSettled`, required of every intent callback
+pub const INTENT_CALLBACK_ID: [u8; 4] = onIntentSettledCall::SELECTOR;
+
+/// Interface id of a set of functions: the XOR of their selectors
+pub const fn interface_id(selectors: &[[u8; 4]]) -> [u8; 4] {
+    let mut id = [0u8; 4];
+    let mut i = 0;
+    while i < selectors.len() {
+        let mut byte = 0;
+        while byte < 4 {
+            id[byte] ^= selectors[i][byte];
+            byte += 1;
+        }
+        i += 1;
+    }
+    id
+}
+
+/// `supportsInterface` answer of a contract implementing `implemented`
+///
+/// ERC-165 itself is always supported and `INVALID_ID` never is.
+pub fn supports(interface_id: [u8; 4], implemented: &[[u8; 4]]) -> bool {
+    interface_id == ERC165_ID || (interface_id != INVALID_ID && implemented.contains(&interface_id))
+}
+
+/// Standard ERC-165 detection of `interface_id`
+///
+/// `query` performs the `supportsInterface` call and reports false when it
+/// reverts or returns garbage. The contract must claim ERC-165 itself and
+/// refuse `INVALID_ID` before its claim to `interface_id` is believed.
+pub fn detect(interface_id: [u8; 4], mut query: impl FnMut([u8; 4]) -> bool) -> bool {
+    query(ERC165_ID) && !query(INVALID_ID) && query(interface_id)
+}
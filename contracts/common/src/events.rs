@@ -0,0 +1,171 @@
+//! Lifecycle Events
+//!
+//! Events that follow an intent from execution on the source chain through
+//! delivery and settlement. RouteExecutor, SwooshReceiver and
+//! SettlementVerifier all emit them from these definitions, so a log means
+//! the same thing whichever contract wrote it and indexers decode one schema.
+//! `LIFECYCLE_TOPICS` lists each event's topic0; the integration tests pin
+//! the signatures so a changed field shows up as a failing test rather than
+//! a silently missed log.
+
+use alloy_sol_types::{sol, SolEvent};
+use stylus_sdk::alloy_primitives::FixedBytes;
+
+sol! {
+    /// Route executed on the source chain (RouteExecutor)
+    event IntentExecuted(
+        uint256 indexed intentId,
+        address indexed user,
+        uint256 indexed destinationChain,
+        address tokenIn,
+        uint256 timestamp
+    );
+
+    /// Signed intent executed by a registered solver (RouteExecutor)
+    event IntentExecutedBySolver(
+        uint256 indexed intentId,
+        uint256 indexed solverId,
+        address indexed solver,
+        bytes32 intentHash
+    );
+
+    /// Memo an intent was signed with, logged when non-zero (RouteExecutor)
+    ///
+    /// Indexed so an integrator can find its intents by its own reference.
+    event IntentMemo(uint256 indexed intentId, bytes32 indexed memo);
+
+    /// Intent could not be executed (RouteExecutor)
+    event IntentFailed(
+        uint256 indexed intentId,
+        string reason
+    );
+
+    /// Swap within a route (RouteExecutor)
+    event SwapExecuted(
+        uint256 indexed intentId,
+        address indexed tokenIn,
+        address indexed tokenOut,
+        uint256 amountIn,
+        uint256 amountOut
+    );
+
+    /// One route step ran (RouteExecutor and SwooshReceiver)
+    ///
+    /// `success` is false for a destination swap that fell back to
+    /// delivering the bridged token.
+    event RouteStepExecuted(
+        uint256 indexed intentId,
+        uint256 stepIndex,
+        uint8 stepType,
+        address indexed adapter,
+        address tokenIn,
+        address tokenOut,
+        uint256 amountIn,
+        uint256 amountOut,
+        bool success
+    );
+
+    /// Tokens handed to a bridge lane (RouteExecutor)
+    event BridgeInitiated(
+        uint256 indexed intentId,
+        address indexed token,
+        uint256 amount,
+        uint256 indexed destinationChain,
+        address recipient
+    );
+
+    /// ERC-721 or ERC-1155 token handed to an NFT bridge lane (RouteExecutor)
+    event NftBridgeInitiated(
+        uint256 indexed intentId,
+        uint8 assetType,
+        address indexed token,
+        uint256 tokenId,
+        uint256 amount,
+        uint256 indexed destinationChain,
+        address recipient
+    );
+
+    /// Bridged funds delivered, or parked as a claim, on the destination (SwooshReceiver)
+    event DeliveryCompleted(
+        uint256 indexed intentId,
+        address indexed recipient,
+        address indexed token,
+        uint256 amount,
+        bytes32 messageId,
+        uint8 status
+    );
+
+    /// Destination swap failed and the bridged token was delivered instead (SwooshReceiver)
+    event SwapFallback(uint256 indexed intentId, address indexed swapAdapter, address tokenOut);
+
+    /// Delivery the recipient couldn't take was credited as a claim (SwooshReceiver)
+    event ClaimCredited(
+        uint256 indexed intentId,
+        address indexed recipient,
+        address indexed token,
+        uint256 amount,
+        uint256 expiresAt
+    );
+
+    /// Delivery status reported back to the source chain (SettlementVerifier)
+    event DeliveryStatusReported(uint256 indexed intentId, bytes32 indexed messageId, uint8 status);
+
+    /// Delivery confirmed (SettlementVerifier)
+    event SettlementConfirmed(
+        uint256 indexed intentId,
+        bytes32 indexed messageId,
+        uint256 timestamp
+    );
+
+    /// Settlement failed (SettlementVerifier)
+    event SettlementFailed(
+        uint256 indexed intentId,
+        bytes32 indexed messageId,
+        string reason
+    );
+
+    /// Timed-out delivery given another attempt under the intent's retry
+    /// policy; `timestamp` restarts the settlement clock (SettlementVerifier)
+    event SettlementRetried(uint256 indexed intentId, uint256 attempt, uint256 timestamp);
+
+    /// User refunded after a failed settlement (SettlementVerifier)
+    event RefundInitiated(
+        uint256 indexed intentId,
+        address indexed user,
+        address token,
+        uint256 amount
+    );
+}
+
+/// Signature and topic0 of every lifecycle event
+pub const LIFECYCLE_TOPICS: [(&str, FixedBytes<32>); 16] = [
+    topic::<IntentExecuted>(),
+    topic::<IntentExecutedBySolver>(),
+    topic::<IntentMemo>(),
+    topic::<IntentFailed>(),
+    topic::<SwapExecuted>(),
+    topic::<RouteStepExecuted>(),
+    topic::<BridgeInitiated>(),
+    topic::<NftBridgeInitiated>(),
+    topic::<DeliveryCompleted>(),
+    topic::<SwapFallback>(),
+    topic::<ClaimCredited>(),
+    topic::<DeliveryStatusReported>(),
+    topic::<SettlementConfirmed>(),
+    topic::<SettlementFailed>(),
+    topic::<SettlementRetried>(),
+    topic::<RefundInitiated>(),
+];
+
+/// Signature and topic0 of an event
+const fn topic<E: SolEvent>() -> (&'static str, FixedBytes<32>) {
+    (E::SIGNATURE, E::SIGNATURE_HASH)
+}
+
+/// Signature of the lifecycle event logged under `topic0`, if any
+pub fn lifecycle_event(topic0: FixedBytes<32>) -> Option<&'static str> {
+    LIFECYCLE_TOPICS
+        .iter()
+        .find(|(_, hash)| *hash == topic0)
+        .map(|(signature, _)| *signature)
+}
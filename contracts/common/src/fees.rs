@@ -0,0 +1,163 @@
+//! Fee Math
+//!
+//! Basis-point fee helpers shared by RouteExecutor, LiquidityPool and their
+//! tests. Fees are always rounded down, so the user is never charged more
+//! than the schedule. Integrators move down a per-token schedule of protocol
+//! fee tiers as their cumulative volume grows. When execution costs are
+//! priced in, the protocol fee rises to cover them within admin bounds.
+//!
+//! RouteExecutor keeps a fee ledger per token, in lifetime totals and in
+//! weekly fee epochs, so fees collected and paid out can be reconciled
+//! against its balances without replaying events.
+
+use stylus_sdk::alloy_primitives::U256;
+
+/// Basis point denominator
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Maximum protocol fee the admin may configure (1%)
+pub const MAX_PROTOCOL_FEE_BPS: u64 = 100;
+
+/// Hard ceiling on the integrator fee cap the admin may configure (3%)
+pub const MAX_INTEGRATOR_FEE_BPS: u64 = 300;
+
+/// Maximum insurance premium the admin may configure (5%)
+pub const MAX_INSURANCE_PREMIUM_BPS: u64 = 500;
+
+/// Maximum number of volume tiers in a token's schedule
+pub const MAX_VOLUME_TIERS: usize = 8;
+
+/// Length of a fee ledger epoch in seconds (one week)
+pub const FEE_EPOCH_LENGTH: u64 = 7 * 24 * 60 * 60;
+
+/// What a fee ledger entry records
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LedgerEntry {
+    /// Protocol fees credited to the fee recipient
+    Collected = 0,
+    /// Protocol fees sent to the fee recipient
+    Withdrawn = 1,
+    /// Integrator fee shares credited to integrators
+    Rebated = 2,
+    /// Integrator fee shares claimed
+    RebatesClaimed = 3,
+    /// Priority fees credited to solvers
+    Rewarded = 4,
+    /// Priority fees claimed
+    RewardsClaimed = 5,
+}
+
+/// Every ledger entry, in the order fee ledger views return them
+pub const LEDGER_ENTRIES: [LedgerEntry; 6] = [
+    LedgerEntry::Collected,
+    LedgerEntry::Withdrawn,
+    LedgerEntry::Rebated,
+    LedgerEntry::RebatesClaimed,
+    LedgerEntry::Rewarded,
+    LedgerEntry::RewardsClaimed,
+];
+
+/// Fee owed on `amount` at `fee_bps`
+///
+/// Split into quotient and remainder so large amounts can't overflow.
+pub fn fee_for(amount: U256, fee_bps: U256) -> U256 {
+    let denominator = U256::from(BPS_DENOMINATOR);
+    (amount / denominator) * fee_bps + (amount % denominator) * fee_bps / denominator
+}
+
+/// Split `amount` into `(net, fee)` at `fee_bps`
+pub fn take_fee(amount: U256, fee_bps: U256) -> (U256, U256) {
+    let fee = fee_for(amount, fee_bps);
+    (amount - fee, fee)
+}
+
+/// Whether a protocol fee is within the admin cap
+pub fn is_valid_protocol_fee(fee_bps: U256) -> bool {
+    fee_bps <= U256::from(MAX_PROTOCOL_FEE_BPS)
+}
+
+/// Split an integrator fee into `(integrator, protocol)` parts
+///
+/// `protocol_share_bps` is the protocol's cut of the integrator fee; the
+/// integrator keeps the rest, including any rounding dust.
+pub fn split_integrator_fee(fee: U256, protocol_share_bps: U256) -> (U256, U256) {
+    let protocol = fee_for(fee, protocol_share_bps);
+    (fee - protocol, protocol)
+}
+
+/// Whether a volume tier schedule is well formed
+///
+/// Tier `i` applies `fee_bps[i]` once volume reaches `thresholds[i]`.
+/// Thresholds must be non-zero and strictly increasing, and fees must stay
+/// within the protocol cap and never rise from one tier to the next.
+pub fn is_valid_tier_schedule(thresholds: &[U256], fee_bps: &[U256]) -> bool {
+    if thresholds.len() != fee_bps.len() || thresholds.len() > MAX_VOLUME_TIERS {
+        return false;
+    }
+    if thresholds.first().is_some_and(|threshold| *threshold == U256::ZERO) {
+        return false;
+    }
+    if !fee_bps.iter().all(|bps| is_valid_protocol_fee(*bps)) {
+        return false;
+    }
+    thresholds.windows(2).all(|pair| pair[0] < pair[1]) && fee_bps.windows(2).all(|pair| pair[0] >= pair[1])
+}
+
+/// Tier reached by `volume`: the number of thresholds it meets (zero = base fee)
+pub fn volume_tier(volume: U256, thresholds: &[U256]) -> usize {
+    thresholds.iter().take_while(|threshold| volume >= **threshold).count()
+}
+
+/// Volume still needed to reach the next tier (zero at the top tier)
+pub fn volume_to_next_tier(volume: U256, thresholds: &[U256]) -> U256 {
+    thresholds
+        .get(volume_tier(volume, thresholds))
+        .map_or(U256::ZERO, |next| *next - volume)
+}
+
+/// Share of `total` that is lent out, in basis points (zero for an empty pool)
+pub fn utilization_bps(borrowed: U256, total: U256) -> U256 {
+    if total == U256::ZERO {
+        return U256::ZERO;
+    }
+    borrowed * U256::from(BPS_DENOMINATOR) / total
+}
+
+/// Borrow fee on a linear utilization curve
+///
+/// `base_bps` at zero utilization, rising by `slope_bps` at full utilization.
+pub fn utilization_fee_bps(base_bps: U256, slope_bps: U256, utilization_bps: U256) -> U256 {
+    base_bps + slope_bps * utilization_bps / U256::from(BPS_DENOMINATOR)
+}
+
+/// Fee epoch a timestamp falls in
+pub fn fee_epoch(timestamp: U256) -> U256 {
+    timestamp / U256::from(FEE_EPOCH_LENGTH)
+}
+
+/// Storage key of one ledger entry within an epoch
+pub fn ledger_key(epoch: U256, entry: LedgerEntry) -> U256 {
+    (epoch << 8) | U256::from(entry as u8)
+}
+
+/// Whether cost-adjusted fee bounds are usable: `min_bps <= max_bps <= cap`
+pub fn is_valid_cost_fee_bounds(min_bps: U256, max_bps: U256) -> bool {
+    min_bps <= max_bps && is_valid_protocol_fee(max_bps)
+}
+
+/// Protocol fee once the route's execution cost is priced in
+///
+/// Charges the higher of the schedule's `base_bps` and the rate that
+/// recovers `cost` on `amount`, kept within `[min_bps, max_bps]`. The cost
+/// rate rounds up so a covered leg is never short by a unit; a zero amount
+/// charges `max_bps`.
+pub fn cost_adjusted_fee_bps(base_bps: U256, cost: U256, amount: U256, min_bps: U256, max_bps: U256) -> U256 {
+    let cost_bps = if amount == U256::ZERO {
+        max_bps
+    } else {
+        let scaled = cost.saturating_mul(U256::from(BPS_DENOMINATOR));
+        let rounding = if scaled % amount == U256::ZERO { U256::ZERO } else { U256::from(1) };
+        scaled / amount + rounding
+    };
+    base_bps.max(cost_bps).max(min_bps).min(max_bps)
+}
@@ -0,0 +1,53 @@
+//! Fill Granularity
+//!
+//! Bounds on the size of a partial fill, i.e. the part of an intent a solver
+//! settles against an opposing intent instead of bridging. Every fill opens a
+//! matched leg in SettlementVerifier with the solver's bond behind it, so
+//! fills of a few wei would cost settlement far more than they move. A fill
+//! must be at least `min_fill` and a whole multiple of `granularity`.
+//!
+//! The owner can set a token's rule outright; otherwise it is derived from
+//! the token's decimals: fills of at least one whole token, in steps of
+//! `10^-FILL_PRECISION` of a token.
+
+use stylus_sdk::alloy_primitives::U256;
+
+/// Decimal places of a token a fill may use under the default rule
+pub const FILL_PRECISION: u8 = 2;
+
+/// Minimum fill size and step for one token
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FillRule {
+    pub min_fill: U256,
+    pub granularity: U256,
+}
+
+impl FillRule {
+    /// Rule allowing any non-zero fill, for tokens whose decimals are unknown
+    pub const ANY: Self = Self {
+        min_fill: U256::from_limbs([1, 0, 0, 0]),
+        granularity: U256::from_limbs([1, 0, 0, 0]),
+    };
+
+    /// Default rule for a token with `decimals` decimals
+    pub fn from_decimals(decimals: u8) -> Self {
+        let ten = U256::from(10u64);
+        Self {
+            min_fill: ten.saturating_pow(U256::from(decimals)),
+            granularity: ten.saturating_pow(U256::from(decimals.saturating_sub(FILL_PRECISION))),
+        }
+    }
+
+    /// Whether an owner-set rule is usable: a non-zero step the minimum is a multiple of
+    pub fn is_valid(&self) -> bool {
+        self.granularity != U256::ZERO && self.min_fill % self.granularity == U256::ZERO
+    }
+
+    /// Whether `amount` is an acceptable fill
+    pub fn allows(&self, amount: U256) -> bool {
+        amount != U256::ZERO
+            && amount >= self.min_fill
+            && self.granularity != U256::ZERO
+            && amount % self.granularity == U256::ZERO
+    }
+}
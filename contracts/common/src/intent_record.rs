@@ -0,0 +1,107 @@
+//! Intent Records
+//!
+//! Packed storage encoding for RouteExecutor's per-intent execution record.
+//! Laid out as separate fields, status, two timestamps, the destination chain
+//! and both amounts would take six slots. Here the small fields share one
+//! header word and only the amounts get words of their own, so a record costs
+//! three slots, and a status change rewrites only the header.
+//!
+//! Header layout, from the least significant bit:
+//!
+//! | bits     | field               |
+//! |----------|---------------------|
+//! | 0..8     | status              |
+//! | 8..72    | created at (unix s) |
+//! | 72..136  | updated at (unix s) |
+//! | 136..200 | destination chain   |
+//! | 200..256 | reserved, zero      |
+
+use stylus_sdk::alloy_primitives::U256;
+
+/// Bit offset of the creation timestamp in the header
+pub const CREATED_AT_SHIFT: usize = 8;
+
+/// Bit offset of the last update timestamp in the header
+pub const UPDATED_AT_SHIFT: usize = 72;
+
+/// Bit offset of the destination chain in the header
+pub const DESTINATION_CHAIN_SHIFT: usize = 136;
+
+/// Storage words per record: header, amount in, amount out
+pub const RECORD_WORDS: usize = 3;
+
+/// One intent's execution record, as RouteExecutor stores it
+///
+/// Timestamps and chain IDs wider than 64 bits are clamped when packed; no
+/// block timestamp or chain ID in use comes near that.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct IntentRecord {
+    pub status: u8,
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub destination_chain: u64,
+    pub amount_in: U256,
+    pub amount_out: U256,
+}
+
+impl IntentRecord {
+    /// Record for an intent that starts executing at `now`
+    pub fn open(status: u8, destination_chain: U256, amount_in: U256, now: U256) -> Self {
+        let now = clamp_u64(now);
+        Self {
+            status,
+            created_at: now,
+            updated_at: now,
+            destination_chain: clamp_u64(destination_chain),
+            amount_in,
+            amount_out: U256::ZERO,
+        }
+    }
+
+    /// Header word holding everything but the amounts
+    pub fn header(&self) -> U256 {
+        U256::from(self.status)
+            | (U256::from(self.created_at) << CREATED_AT_SHIFT)
+            | (U256::from(self.updated_at) << UPDATED_AT_SHIFT)
+            | (U256::from(self.destination_chain) << DESTINATION_CHAIN_SHIFT)
+    }
+
+    /// Storage words in order: header, amount in, amount out
+    pub fn encode(&self) -> [U256; RECORD_WORDS] {
+        [self.header(), self.amount_in, self.amount_out]
+    }
+
+    /// Record from its storage words; an unused record decodes to the default
+    pub fn decode(words: [U256; RECORD_WORDS]) -> Self {
+        let [header, amount_in, amount_out] = words;
+        Self {
+            status: header_status(header),
+            created_at: header_field(header, CREATED_AT_SHIFT),
+            updated_at: header_field(header, UPDATED_AT_SHIFT),
+            destination_chain: header_field(header, DESTINATION_CHAIN_SHIFT),
+            amount_in,
+            amount_out,
+        }
+    }
+}
+
+/// Status held in a header word
+pub fn header_status(header: U256) -> u8 {
+    header.byte(0)
+}
+
+/// Header with a new status and last update time, other fields kept
+pub fn update_header_status(header: U256, status: u8, now: U256) -> U256 {
+    let kept = header & !(U256::from(u8::MAX) | (U256::from(u64::MAX) << UPDATED_AT_SHIFT));
+    kept | U256::from(status) | (U256::from(clamp_u64(now)) << UPDATED_AT_SHIFT)
+}
+
+/// 64-bit field of a header word starting at `shift`
+fn header_field(header: U256, shift: usize) -> u64 {
+    ((header >> shift) & U256::from(u64::MAX)).to::<u64>()
+}
+
+/// `value` as a u64, saturating at the maximum
+fn clamp_u64(value: U256) -> u64 {
+    u64::try_from(value).unwrap_or(u64::MAX)
+}
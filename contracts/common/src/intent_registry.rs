@@ -0,0 +1,18 @@
+//! Intent Registry Interface
+//!
+//! Interface of IntentRegistry as seen by RouteExecutor, which consumes each
+//! intent's key there before executing it so that no other executor on the
+//! chain can execute the same intent.
+
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use stylus_sdk::prelude::*;
+
+sol_interface! {
+    interface IIntentRegistry {
+        function consume(bytes32 intent_key) external returns (bool);
+        function isConsumed(bytes32 intent_key) external view returns (bool);
+    }
+}
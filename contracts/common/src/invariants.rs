@@ -0,0 +1,53 @@
+//! Debug Invariants
+//!
+//! Properties the contracts must never break: RouteExecutor holds every
+//! token it owes, fees stay within their caps, and intent and settlement
+//! statuses only move forward. Contracts assert them with `invariant!`,
+//! which checks nothing unless the contract crate is built with its
+//! `invariants` feature. Fuzzing and devnet builds then panic on the first
+//! violation, while production wasm compiles the checks away entirely.
+
+use stylus_sdk::alloy_primitives::U256;
+
+use crate::fees::fee_for;
+
+/// Panic if an invariant doesn't hold, in `invariants` builds only
+///
+/// The condition isn't evaluated otherwise, so it may read storage or call
+/// other contracts without costing production gas.
+#[macro_export]
+macro_rules! invariant {
+    ($holds:expr, $what:literal) => {
+        if cfg!(feature = "invariants") && !$holds {
+            panic!(concat!("invariant violated: ", $what));
+        }
+    };
+}
+
+/// Whether RouteExecutor may move an intent record from `from` to `to`
+///
+/// Codes are RouteExecutor's `IntentStatus`: Pending 0, Executing 1,
+/// Completed 2, Failed 3.
+pub fn is_intent_transition(from: u8, to: u8) -> bool {
+    matches!((from, to), (0, 1) | (1, 2) | (1, 3))
+}
+
+/// Whether SettlementVerifier may move a settlement from `from` to `to`
+///
+/// Codes are SettlementVerifier's `SettlementStatus`: Pending 0, Confirmed 1,
+/// Failed 2, Refunded 3, PartialDelivery 4, DeliveredToClaim 5. A confirmed
+/// delivery may be narrowed to a fallback status; a failed intent may only
+/// be refunded.
+pub fn is_settlement_transition(from: u8, to: u8) -> bool {
+    matches!((from, to), (0, 1) | (0, 2) | (0, 3) | (1, 4) | (1, 5) | (2, 3))
+}
+
+/// Whether `fee` on `amount` is no more than the `cap_bps` rate allows
+pub fn is_fee_within_cap(fee: U256, amount: U256, cap_bps: u64) -> bool {
+    fee <= fee_for(amount, U256::from(cap_bps))
+}
+
+/// Whether a `balance` covers everything `owed` out of it
+pub fn is_solvent(balance: U256, owed: U256) -> bool {
+    balance >= owed
+}
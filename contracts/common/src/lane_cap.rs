@@ -0,0 +1,78 @@
+//! Lane TVL Caps
+//!
+//! How much of a token RouteExecutor may have in flight toward one
+//! destination chain. A newly opened lane can start at a small cap that
+//! grows linearly to its full cap over a ramp period, so exposure to a new
+//! bridge or chain builds up only as the lane proves itself. A lane without
+//! a cap is unlimited.
+//!
+//! The ramp is stored as one word, from the least significant bit:
+//!
+//! | bits     | field                      |
+//! |----------|----------------------------|
+//! | 0..64    | ramp start (unix seconds)  |
+//! | 64..128  | ramp duration (seconds)    |
+//! | 128..256 | reserved, zero             |
+
+use stylus_sdk::alloy_primitives::U256;
+
+/// Bit offset of the ramp duration in a ramp word
+const RAMP_DURATION_SHIFT: usize = 64;
+
+/// TVL cap of one token on one lane
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LaneCap {
+    /// Cap once the ramp is over (zero = uncapped)
+    pub cap: U256,
+    /// Cap when the ramp starts
+    pub initial_cap: U256,
+    /// When the ramp started
+    pub ramp_start: u64,
+    /// Seconds the cap takes to grow from `initial_cap` to `cap`
+    pub ramp_duration: u64,
+}
+
+impl LaneCap {
+    /// Whether the lane is capped at all
+    pub fn is_capped(&self) -> bool {
+        self.cap != U256::ZERO
+    }
+
+    /// Whether the cap can be set: the ramp starts no higher than it ends
+    pub fn is_valid(&self) -> bool {
+        self.initial_cap <= self.cap
+    }
+
+    /// Cap in force at `now`, `U256::MAX` if uncapped
+    pub fn current(&self, now: u64) -> U256 {
+        if !self.is_capped() {
+            return U256::MAX;
+        }
+        let elapsed = now.saturating_sub(self.ramp_start);
+        if self.ramp_duration == 0 || elapsed >= self.ramp_duration || self.initial_cap >= self.cap {
+            return self.cap;
+        }
+        let growth = (self.cap - self.initial_cap) * U256::from(elapsed) / U256::from(self.ramp_duration);
+        self.initial_cap + growth
+    }
+
+    /// Room left under the cap at `now` with `tvl` already in flight
+    pub fn available(&self, tvl: U256, now: u64) -> U256 {
+        self.current(now).saturating_sub(tvl)
+    }
+
+    /// Pack the ramp start and duration into one word
+    pub fn encode_ramp(&self) -> U256 {
+        U256::from(self.ramp_start) | (U256::from(self.ramp_duration) << RAMP_DURATION_SHIFT)
+    }
+
+    /// Cap from its stored parts
+    pub fn decode(cap: U256, initial_cap: U256, ramp: U256) -> Self {
+        Self {
+            cap,
+            initial_cap,
+            ramp_start: (ramp & U256::from(u64::MAX)).to::<u64>(),
+            ramp_duration: ((ramp >> RAMP_DURATION_SHIFT) & U256::from(u64::MAX)).to::<u64>(),
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Lane Finality Policies
+//!
+//! How SettlementVerifier decides that a delivery on a lane is final. Each
+//! destination chain can name the reporter types it trusts, how many
+//! distinct reporters must agree on a delivery, and a challenge window that
+//! must pass before an agreed delivery settles. A lane without a policy
+//! accepts the first report from any reporter, which is how every lane
+//! behaved before policies existed.
+//!
+//! A policy is stored as one word, from the least significant bit:
+//!
+//! | bits    | field                       |
+//! |---------|-----------------------------|
+//! | 0..8    | accepted reporter types     |
+//! | 8..16   | quorum                      |
+//! | 16..80  | challenge window (seconds)  |
+//! | 80..256 | reserved, zero              |
+
+use stylus_sdk::alloy_primitives::U256;
+
+/// CCIP router reporting through `verify_ccip_message`
+pub const REPORTER_CCIP: u8 = 1 << 0;
+
+/// Registered settlement adapter or destination receiver
+pub const REPORTER_ADAPTER: u8 = 1 << 1;
+
+/// Wormhole VAA from a registered emitter
+pub const REPORTER_WORMHOLE: u8 = 1 << 2;
+
+/// deBridge DlnSource order state read on this chain
+pub const REPORTER_DLN: u8 = 1 << 3;
+
+/// Registered off-chain attester
+pub const REPORTER_ATTESTER: u8 = 1 << 4;
+
+/// Every reporter type
+pub const ALL_REPORTERS: u8 = REPORTER_CCIP | REPORTER_ADAPTER | REPORTER_WORMHOLE | REPORTER_DLN | REPORTER_ATTESTER;
+
+/// Bit offset of the quorum in a policy word
+const QUORUM_SHIFT: usize = 8;
+
+/// Bit offset of the challenge window in a policy word
+const CHALLENGE_WINDOW_SHIFT: usize = 16;
+
+/// Finality policy of one lane
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LanePolicy {
+    /// Reporter types whose reports count, as `REPORTER_*` bits
+    pub reporters: u8,
+    /// Distinct reporters that must agree before a delivery is final
+    pub quorum: u8,
+    /// Seconds an agreed delivery can still be challenged before it settles
+    pub challenge_window: u64,
+}
+
+impl Default for LanePolicy {
+    /// Any reporter, first report settles at once
+    fn default() -> Self {
+        Self {
+            reporters: ALL_REPORTERS,
+            quorum: 1,
+            challenge_window: 0,
+        }
+    }
+}
+
+impl LanePolicy {
+    /// Policy from its storage word; an unset word is the default policy
+    pub fn decode(word: U256) -> Self {
+        if word == U256::ZERO {
+            return Self::default();
+        }
+        Self {
+            reporters: word.byte(0),
+            quorum: word.byte(1),
+            challenge_window: ((word >> CHALLENGE_WINDOW_SHIFT) & U256::from(u64::MAX)).to::<u64>(),
+        }
+    }
+
+    /// Storage word holding the policy
+    pub fn encode(&self) -> U256 {
+        U256::from(self.reporters)
+            | (U256::from(self.quorum) << QUORUM_SHIFT)
+            | (U256::from(self.challenge_window) << CHALLENGE_WINDOW_SHIFT)
+    }
+
+    /// Whether reports of `reporter` type count toward this lane's quorum
+    pub fn accepts(&self, reporter: u8) -> bool {
+        self.reporters & reporter != 0
+    }
+
+    /// Whether the first accepted report settles the delivery right away
+    pub fn is_immediate(&self) -> bool {
+        self.quorum <= 1 && self.challenge_window == 0
+    }
+
+    /// Whether the policy names only known reporter types, at least one of
+    /// them, and a quorum of at least one
+    pub fn is_valid(&self) -> bool {
+        self.reporters != 0 && self.reporters & !ALL_REPORTERS == 0 && self.quorum != 0
+    }
+}
@@ -0,0 +1,130 @@
+//! Lens Views
+//!
+//! Composite read models returned by the SwooshLens contract, so a frontend
+//! can fill a page from one `eth_call` instead of a dozen reads across the
+//! executor, validator and verifier. The lens returns each view
+//! ABI-encoded; `decode_*` turns it back into the struct.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use alloy_sol_types::{sol, SolValue};
+use stylus_sdk::alloy_primitives::{Address, U256};
+
+sol! {
+    /// Whether a user can execute an intent for a token and lane right now
+    ///
+    /// `blocker` is the first `NotReady` reason, zero when ready. Native ETH
+    /// reports an unlimited allowance.
+    struct UserReadiness {
+        uint256 balance;
+        uint256 allowance;
+        bool tokenSupported;
+        bool chainSupported;
+        bool laneHalted;
+        bool executorPaused;
+        bool solverOnly;
+        address bridgeAdapter;
+        uint8 blocker;
+    }
+
+    /// One of a user's open intents with its source and settlement state
+    ///
+    /// `status` follows RouteExecutor's intent status and
+    /// `settlementStatus` SettlementVerifier's. `timedOut` means the
+    /// settlement window has passed, so the intent can be failed and
+    /// refunded; `finalAt` is when an agreed delivery can be finalized, zero
+    /// if none is pending. `memo` is the memo the intent was signed with.
+    struct IntentView {
+        uint256 intentId;
+        bytes32 intentHash;
+        uint8 status;
+        uint256 destinationChain;
+        uint256 amountIn;
+        uint256 amountOut;
+        uint64 updatedAt;
+        uint8 settlementStatus;
+        bool timedOut;
+        uint256 finalAt;
+        bytes32 memo;
+    }
+
+    /// Fees an intent would pay on the source chain
+    ///
+    /// `bridgeFee` is the lane adapter's native fee, zero if no adapter
+    /// serves the lane or it couldn't quote.
+    struct FeeQuote {
+        uint256 protocolFeeBps;
+        uint256 protocolFee;
+        uint256 netAmount;
+        address bridgeAdapter;
+        uint256 bridgeFee;
+    }
+}
+
+/// Most executor indexes the lens scans in one `get_user_intents` call
+pub const MAX_LENS_SCAN: usize = 200;
+
+/// Reasons a user isn't ready to execute, in the order they are checked
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NotReady {
+    /// Executor is paused
+    Paused = 1,
+    /// Only solvers can execute; the user must sign the intent instead
+    SolverOnly = 2,
+    /// Validator doesn't support the token
+    UnsupportedToken = 3,
+    /// Validator doesn't support the destination chain
+    UnsupportedChain = 4,
+    /// Lane is halted by its reporters
+    LaneHalted = 5,
+    /// No bridge adapter serves the token and lane
+    NoBridgeAdapter = 6,
+    /// Balance is below the amount
+    InsufficientBalance = 7,
+    /// Executor allowance is below the amount
+    InsufficientAllowance = 8,
+}
+
+/// First reason `readiness` can't execute `amount`, or `None` when ready
+pub fn readiness_blocker(readiness: &UserReadiness, amount: U256) -> Option<NotReady> {
+    if readiness.executorPaused {
+        Some(NotReady::Paused)
+    } else if readiness.solverOnly {
+        Some(NotReady::SolverOnly)
+    } else if !readiness.tokenSupported {
+        Some(NotReady::UnsupportedToken)
+    } else if !readiness.chainSupported {
+        Some(NotReady::UnsupportedChain)
+    } else if readiness.laneHalted {
+        Some(NotReady::LaneHalted)
+    } else if readiness.bridgeAdapter == Address::ZERO {
+        Some(NotReady::NoBridgeAdapter)
+    } else if readiness.balance < amount {
+        Some(NotReady::InsufficientBalance)
+    } else if readiness.allowance < amount {
+        Some(NotReady::InsufficientAllowance)
+    } else {
+        None
+    }
+}
+
+/// Decode a `get_user_readiness` result, returning `None` if malformed
+pub fn decode_user_readiness(data: &[u8]) -> Option<UserReadiness> {
+    UserReadiness::abi_decode(data).ok()
+}
+
+/// ABI-encode a page of intent views
+pub fn encode_intent_views(views: &[IntentView]) -> Vec<u8> {
+    views.abi_encode()
+}
+
+/// Decode a `get_user_intents` page, returning `None` if malformed
+pub fn decode_intent_views(data: &[u8]) -> Option<Vec<IntentView>> {
+    Vec::<IntentView>::abi_decode(data).ok()
+}
+
+/// Decode a `quote_fees` result, returning `None` if malformed
+pub fn decode_fee_quote(data: &[u8]) -> Option<FeeQuote> {
+    FeeQuote::abi_decode(data).ok()
+}
@@ -0,0 +1,43 @@
+//! Swoosh Common
+//!
+//! Code shared by the Swoosh contract crates: pure encoding, fee and route
+//! helpers, the destination payload codec, partial fill sizes, the packed
+//! intent and registry records, the intent lifecycle events, the EAS intent attestation
+//! schema, non-EVM recipient addresses, lane TVL caps and finality policies,
+//! adapter approval policies, the lens views, the protocol status snapshot,
+//! token listing modes, price source fallback, intent retry policies, the
+//! bounds on governance parameters and the debug invariants, plus the
+//! interfaces the contracts use to call each other and their ERC-165 ids.
+//! Nothing here has storage or an entrypoint.
+
+#![cfg_attr(not(test), no_std)]
+
+extern crate alloc;
+
+pub mod approval_policy;
+pub mod attestation;
+pub mod bridge_adapter;
+pub mod chain_address;
+pub mod encoding;
+pub mod erc165;
+pub mod events;
+pub mod fees;
+pub mod fill_rule;
+pub mod intent_record;
+pub mod intent_registry;
+pub mod invariants;
+pub mod lane_cap;
+pub mod lane_policy;
+pub mod lens;
+pub mod params;
+pub mod payload_codec;
+pub mod price_source;
+pub mod registry_record;
+pub mod retry_policy;
+pub mod route_codec;
+pub mod signing;
+pub mod solver_registry;
+pub mod status;
+pub mod swap_adapter;
+pub mod token_listing;
+pub mod validator_compat;
@@ -0,0 +1,205 @@
+//! Governance Parameters
+//!
+//! Hard bounds on the scalar tunables of the Swoosh contracts: timeouts,
+//! fee rates, caps and windows. Setters check every value against its
+//! `Param` range, so even the owner can't set a zero settlement timeout or a
+//! bond lock measured in years. Bounds are compiled in; widening one takes
+//! an upgrade, not a transaction.
+//!
+//! Every accepted change also logs `ParameterChanged` with the parameter's
+//! id, next to the contract's own event. Ids are unique across contracts,
+//! so one indexer query follows every governance change. Parameters set per
+//! token or chain carry it as the key (a token address is its low 20
+//! bytes); global ones use zero.
+
+use alloy_sol_types::sol;
+use stylus_sdk::alloy_primitives::{Address, U256};
+
+use crate::fees::{BPS_DENOMINATOR, MAX_INSURANCE_PREMIUM_BPS, MAX_INTEGRATOR_FEE_BPS, MAX_PROTOCOL_FEE_BPS};
+
+sol! {
+    /// A bounded parameter changed (any contract)
+    event ParameterChanged(uint8 indexed param, uint256 indexed key, uint256 oldValue, uint256 newValue);
+
+    /// A parameter was set outside its bounds
+    error ParameterOutOfBounds(uint8 param, uint256 value, uint256 minValue, uint256 maxValue);
+}
+
+const HOUR: u64 = 60 * 60;
+const DAY: u64 = 24 * HOUR;
+
+/// One ether, in wei
+const ETHER: u128 = 1_000_000_000_000_000_000;
+
+/// Bounded parameter, by id
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Param {
+    // RouteExecutor
+    ProtocolFeeBps = 1,
+    TokenFeeBps = 2,
+    StableDeadlineWindow = 3,
+    MaxBlockAge = 4,
+    MaxGasDrop = 5,
+    MaxGasLimit = 6,
+    LiquidityCheckBps = 7,
+    MaxIntegratorFeeBps = 8,
+    IntegratorProtocolShareBps = 9,
+    InsurancePremiumBps = 10,
+    CostFeeMinBps = 11,
+    CostFeeMaxBps = 12,
+    CostCalldataBytes = 13,
+    ClaimWindow = 14,
+    ClaimPenalty = 15,
+    LaneCapRamp = 16,
+    RotationWindow = 17,
+    // SettlementVerifier
+    TimeoutPeriod = 20,
+    SlashAmount = 21,
+    MaxInFlightIntents = 22,
+    // SolverRegistry
+    WithdrawalDelay = 30,
+    MinBond = 31,
+    // IntentValidator
+    LaneReportQuorum = 40,
+    WatchReportBond = 41,
+    WatchHoldPeriod = 42,
+    WatchBounty = 43,
+    PriceFeedMaxAge = 44,
+    // SwooshReceiver
+    ClaimPeriod = 50,
+    // LiquidityPool
+    BorrowBaseFeeBps = 60,
+    BorrowSlopeFeeBps = 61,
+    MaxUtilizationBps = 62,
+}
+
+/// Every parameter, in id order
+pub const ALL_PARAMS: [Param; 31] = [
+    Param::ProtocolFeeBps,
+    Param::TokenFeeBps,
+    Param::StableDeadlineWindow,
+    Param::MaxBlockAge,
+    Param::MaxGasDrop,
+    Param::MaxGasLimit,
+    Param::LiquidityCheckBps,
+    Param::MaxIntegratorFeeBps,
+    Param::IntegratorProtocolShareBps,
+    Param::InsurancePremiumBps,
+    Param::CostFeeMinBps,
+    Param::CostFeeMaxBps,
+    Param::CostCalldataBytes,
+    Param::ClaimWindow,
+    Param::ClaimPenalty,
+    Param::LaneCapRamp,
+    Param::RotationWindow,
+    Param::TimeoutPeriod,
+    Param::SlashAmount,
+    Param::MaxInFlightIntents,
+    Param::WithdrawalDelay,
+    Param::MinBond,
+    Param::LaneReportQuorum,
+    Param::WatchReportBond,
+    Param::WatchHoldPeriod,
+    Param::WatchBounty,
+    Param::PriceFeedMaxAge,
+    Param::ClaimPeriod,
+    Param::BorrowBaseFeeBps,
+    Param::BorrowSlopeFeeBps,
+    Param::MaxUtilizationBps,
+];
+
+impl Param {
+    /// Parameter with the given id
+    pub fn from_id(id: u8) -> Option<Self> {
+        ALL_PARAMS.into_iter().find(|param| param.id() == id)
+    }
+
+    /// Id logged in `ParameterChanged`
+    pub fn id(self) -> u8 {
+        self as u8
+    }
+
+    /// Smallest accepted value
+    pub fn min(self) -> U256 {
+        self.bounds().0
+    }
+
+    /// Largest accepted value
+    pub fn max(self) -> U256 {
+        self.bounds().1
+    }
+
+    /// Accepted range as (min, max), both inclusive
+    pub fn bounds(self) -> (U256, U256) {
+        let (min, max): (u128, u128) = match self {
+            Param::ProtocolFeeBps | Param::TokenFeeBps => (0, MAX_PROTOCOL_FEE_BPS as u128),
+            Param::CostFeeMinBps | Param::CostFeeMaxBps => (0, MAX_PROTOCOL_FEE_BPS as u128),
+            // Zero leaves the window unlimited
+            Param::StableDeadlineWindow => (0, DAY as u128),
+            // Blocks ArbSys keeps hashes for
+            Param::MaxBlockAge => (0, 256),
+            Param::MaxGasDrop => (0, 10 * ETHER),
+            Param::MaxGasLimit => (0, 30_000_000),
+            Param::LiquidityCheckBps | Param::IntegratorProtocolShareBps | Param::MaxUtilizationBps => {
+                (0, BPS_DENOMINATOR as u128)
+            }
+            Param::MaxIntegratorFeeBps => (0, MAX_INTEGRATOR_FEE_BPS as u128),
+            Param::InsurancePremiumBps => (0, MAX_INSURANCE_PREMIUM_BPS as u128),
+            Param::CostCalldataBytes => (0, 128 * 1024),
+            // Zero turns claims off
+            Param::ClaimWindow => (0, HOUR as u128),
+            // Zero applies the full cap at once
+            Param::LaneCapRamp => (0, 365 * DAY as u128),
+            // Zero turns rotation off; a rotation only breaks races, so it stays short
+            Param::RotationWindow => (0, 5 * 60),
+            Param::TimeoutPeriod => (60, 7 * DAY as u128),
+            Param::SlashAmount | Param::MinBond | Param::ClaimPenalty => (0, 1_000 * ETHER),
+            Param::MaxInFlightIntents => (0, 10_000),
+            Param::WithdrawalDelay => (HOUR as u128, 30 * DAY as u128),
+            Param::LaneReportQuorum => (0, 32),
+            Param::WatchReportBond | Param::WatchBounty => (0, 100 * ETHER),
+            // Zero turns watchtower reports off
+            Param::WatchHoldPeriod => (0, DAY as u128),
+            // Chainlink heartbeats run from minutes to a day
+            Param::PriceFeedMaxAge => (60, DAY as u128),
+            Param::ClaimPeriod => (HOUR as u128, 365 * DAY as u128),
+            // LiquidityPool also caps their sum at its MAX_BORROW_FEE_BPS
+            Param::BorrowBaseFeeBps | Param::BorrowSlopeFeeBps => (0, 500),
+        };
+        (U256::from(min), U256::from(max))
+    }
+
+    /// Check a value against the bounds
+    pub fn check(self, value: U256) -> Result<(), ParameterOutOfBounds> {
+        let (min, max) = self.bounds();
+        if value < min || value > max {
+            return Err(ParameterOutOfBounds {
+                param: self.id(),
+                value,
+                minValue: min,
+                maxValue: max,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// `ParameterChanged` for a global parameter
+pub fn changed(param: Param, old_value: U256, new_value: U256) -> ParameterChanged {
+    changed_at(param, U256::ZERO, old_value, new_value)
+}
+
+/// `ParameterChanged` for a parameter set per token or chain
+pub fn changed_at(param: Param, key: U256, old_value: U256, new_value: U256) -> ParameterChanged {
+    ParameterChanged {
+        param: param.id(),
+        key,
+        oldValue: old_value,
+        newValue: new_value,
+    }
+}
+
+/// Key of a parameter set per token
+pub fn token_key(token: Address) -> U256 {
+    U256::from_be_slice(token.as_slice())
+}
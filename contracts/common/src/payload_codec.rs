@@ -0,0 +1,162 @@
+//! Destination Payload Codec
+//!
+//! The one encoder and decoder for what crosses a lane: the `BridgePayload`
+//! every bridge adapter sends its destination peer, the `NftBridgePayload`
+//! of NFT intents and the `ReceiverPayload` SwooshReceiver takes. Source and
+//! destination contracts both go through here, so the two ends can't drift
+//! apart and strand a message.
+//!
+//! A payload is a 4-byte header followed by the ABI encoding of its struct:
+//!
+//! | bytes | field                          |
+//! |-------|--------------------------------|
+//! | 0..2  | `PAYLOAD_MAGIC`                |
+//! | 2     | version, `PAYLOAD_VERSION`     |
+//! | 3     | `PayloadKind`                  |
+//!
+//! ABI encodings are whole 32-byte words, so a headered payload is never a
+//! multiple of 32 bytes long. Decoders use that to keep accepting unheadered
+//! payloads sent before the header existed; upgrade destinations before
+//! sources. Every payload must be exactly its canonical encoding, so a
+//! truncated, padded or dirty-bit message is rejected with a `PayloadError`
+//! instead of being half-read.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use alloy_sol_types::{SolType, SolValue};
+
+use crate::encoding::{AssetType, BridgePayload, NftBridgePayload, ReceiverPayload};
+
+/// First two bytes of every headered payload
+pub const PAYLOAD_MAGIC: [u8; 2] = *b"SW";
+
+/// Payload layout this codec writes
+pub const PAYLOAD_VERSION: u8 = 1;
+
+/// Size of the header in front of the ABI body
+pub const PAYLOAD_HEADER_SIZE: usize = 4;
+
+/// Encoded size of a `BridgePayload` body, which is all static words
+const BRIDGE_BODY_SIZE: usize = 5 * 32;
+
+/// Encoded size of an `NftBridgePayload` body, which is all static words
+const NFT_BODY_SIZE: usize = 6 * 32;
+
+/// What a payload carries, byte 3 of its header
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PayloadKind {
+    Bridge = 1,
+    Nft = 2,
+    Receiver = 3,
+}
+
+/// Reasons a payload is rejected, surfaced on-chain as `MalformedPayload(reason)`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PayloadError {
+    /// No bytes at all
+    Empty = 1,
+    /// Header names a version this codec can't read
+    UnsupportedVersion = 2,
+    /// Body is not the size its kind encodes to
+    BadLength = 3,
+    /// Header magic is wrong, or the body fails to ABI-decode canonically
+    Malformed = 4,
+    /// Payload is another kind, or an NFT payload names a fungible asset
+    WrongKind = 5,
+}
+
+impl PayloadError {
+    /// Reason code carried in `MalformedPayload`
+    pub fn code(self) -> u8 {
+        self as u8
+    }
+}
+
+/// Header and ABI body of a payload
+fn encode(kind: PayloadKind, body: Vec<u8>) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(PAYLOAD_HEADER_SIZE + body.len());
+    payload.extend_from_slice(&PAYLOAD_MAGIC);
+    payload.push(PAYLOAD_VERSION);
+    payload.push(kind as u8);
+    payload.extend_from_slice(&body);
+    payload
+}
+
+/// ABI body of a payload of `kind`, headered or not
+fn body(data: &[u8], kind: PayloadKind) -> Result<&[u8], PayloadError> {
+    if data.is_empty() {
+        return Err(PayloadError::Empty);
+    }
+    match data.len() % 32 {
+        0 => Ok(data),
+        PAYLOAD_HEADER_SIZE => {
+            let (header, body) = data.split_at(PAYLOAD_HEADER_SIZE);
+            if header[..2] != PAYLOAD_MAGIC {
+                return Err(PayloadError::Malformed);
+            }
+            if header[2] != PAYLOAD_VERSION {
+                return Err(PayloadError::UnsupportedVersion);
+            }
+            if header[3] != kind as u8 {
+                return Err(PayloadError::WrongKind);
+            }
+            Ok(body)
+        }
+        _ => Err(PayloadError::BadLength),
+    }
+}
+
+/// ABI-decode a body, requiring it to be the value's canonical encoding
+fn decode_canonical<T>(body: &[u8]) -> Result<T, PayloadError>
+where
+    T: SolValue + From<<T::SolType as SolType>::RustType>,
+{
+    let value = T::abi_decode(body).map_err(|_| PayloadError::Malformed)?;
+    if value.abi_encode() != body {
+        return Err(PayloadError::Malformed);
+    }
+    Ok(value)
+}
+
+/// Encode a bridge payload
+pub fn encode_bridge(payload: &BridgePayload) -> Vec<u8> {
+    encode(PayloadKind::Bridge, payload.abi_encode())
+}
+
+/// Decode a bridge payload
+pub fn decode_bridge(data: &[u8]) -> Result<BridgePayload, PayloadError> {
+    let body = body(data, PayloadKind::Bridge)?;
+    if body.len() != BRIDGE_BODY_SIZE {
+        return Err(PayloadError::BadLength);
+    }
+    decode_canonical(body)
+}
+
+/// Encode an NFT bridge payload
+pub fn encode_nft(payload: &NftBridgePayload) -> Vec<u8> {
+    encode(PayloadKind::Nft, payload.abi_encode())
+}
+
+/// Decode an NFT bridge payload, which must name an NFT asset type
+pub fn decode_nft(data: &[u8]) -> Result<NftBridgePayload, PayloadError> {
+    let body = body(data, PayloadKind::Nft)?;
+    if body.len() != NFT_BODY_SIZE {
+        return Err(PayloadError::BadLength);
+    }
+    let payload: NftBridgePayload = decode_canonical(body)?;
+    if !AssetType::from_u8(payload.assetType).is_some_and(AssetType::is_nft) {
+        return Err(PayloadError::WrongKind);
+    }
+    Ok(payload)
+}
+
+/// Encode a SwooshReceiver payload
+pub fn encode_receiver(payload: &ReceiverPayload) -> Vec<u8> {
+    encode(PayloadKind::Receiver, payload.abi_encode())
+}
+
+/// Decode a SwooshReceiver payload
+pub fn decode_receiver(data: &[u8]) -> Result<ReceiverPayload, PayloadError> {
+    decode_canonical(body(data, PayloadKind::Receiver)?)
+}
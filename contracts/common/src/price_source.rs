@@ -0,0 +1,102 @@
+//! Price Source Fallback
+//!
+//! Where IntentValidator takes a token's price from. Each token has an
+//! ordered chain of sources, and the first one that answers wins:
+//!
+//! 1. `Chainlink`: the token's aggregator, if its latest answer is positive
+//!    and no older than the max age set with the feed
+//! 2. `Twap`: the validator's TWAP source, if it prices the token. It is
+//!    only asked once the feed has failed, so a slow or reverting TWAP
+//!    source costs nothing while the feed answers
+//! 3. `StaticBound`: a fixed, conservative price the owner set for the token
+//!
+//! A stale or broken feed falls through to the TWAP instead of forcing the
+//! validator to either reject the intent or skip its price check, and the
+//! static bound covers a token when both are out. The source that answered
+//! is returned with the price, so callers can weigh a fallback price
+//! accordingly. Prices carry `PRICE_DECIMALS` decimals whatever the source.
+
+use stylus_sdk::alloy_primitives::{I256, U256};
+
+/// Decimals of every resolved price
+pub const PRICE_DECIMALS: u8 = 18;
+
+/// Most decimals a feed may report; more can't be scaled without losing the price
+const MAX_FEED_DECIMALS: u8 = 36;
+
+/// Source a token's price came from
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PriceSource {
+    /// No source could price the token
+    Unavailable = 0,
+    Chainlink = 1,
+    Twap = 2,
+    StaticBound = 3,
+}
+
+impl PriceSource {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Unavailable),
+            1 => Some(Self::Chainlink),
+            2 => Some(Self::Twap),
+            3 => Some(Self::StaticBound),
+            _ => None,
+        }
+    }
+}
+
+/// Scale a price with `decimals` decimals to `PRICE_DECIMALS`
+///
+/// Returns `None` for more than 36 decimals or if scaling overflows.
+pub fn normalize_price(price: U256, decimals: u8) -> Option<U256> {
+    if decimals > MAX_FEED_DECIMALS {
+        return None;
+    }
+    if decimals <= PRICE_DECIMALS {
+        let scale = U256::from(10).pow(U256::from(PRICE_DECIMALS - decimals));
+        price.checked_mul(scale)
+    } else {
+        Some(price / U256::from(10).pow(U256::from(decimals - PRICE_DECIMALS)))
+    }
+}
+
+/// Price from a Chainlink round, or `None` if the round can't be trusted
+///
+/// The answer must be positive and `updated_at` neither in the future nor
+/// more than `max_age` seconds before `now`.
+pub fn chainlink_price(answer: I256, decimals: u8, updated_at: U256, now: U256, max_age: U256) -> Option<U256> {
+    if answer <= I256::ZERO || updated_at > now || now - updated_at > max_age {
+        return None;
+    }
+    normalize_price(answer.into_raw(), decimals).filter(|price| *price != U256::ZERO)
+}
+
+/// Price from a TWAP quote with `decimals` decimals, or `None` if it is zero
+/// or can't be scaled
+pub fn twap_price(price: U256, decimals: u8) -> Option<U256> {
+    normalize_price(price, decimals).filter(|price| *price != U256::ZERO)
+}
+
+/// First price in the fallback chain, with the source it came from
+///
+/// `chainlink` is `None` when the feed is unset or failed. `twap` is only
+/// called if it did, and returns `None` when the TWAP source is unset or
+/// failed; both must already be normalized. A zero TWAP price or static
+/// bound counts as no price.
+pub fn resolve_price(
+    chainlink: Option<U256>,
+    twap: impl FnOnce() -> Option<U256>,
+    static_bound: U256,
+) -> (PriceSource, U256) {
+    if let Some(price) = chainlink {
+        return (PriceSource::Chainlink, price);
+    }
+    if let Some(price) = twap().filter(|price| *price != U256::ZERO) {
+        return (PriceSource::Twap, price);
+    }
+    if static_bound != U256::ZERO {
+        return (PriceSource::StaticBound, static_bound);
+    }
+    (PriceSource::Unavailable, U256::ZERO)
+}
@@ -0,0 +1,168 @@
+//! Registry Records
+//!
+//! Packed storage encoding for the intents IntentValidator registers. Field
+//! by field, an intent with its status, timestamps and consumer would take
+//! eleven slots. Here addresses share their word with a small field, so a
+//! record costs five slots, and a status change rewrites only the header.
+//!
+//! | word | bits     | field                              |
+//! |------|----------|------------------------------------|
+//! | 0    | 0..8     | status (`RegisteredIntentStatus`)  |
+//! | 0    | 8..48    | registered at (unix s)             |
+//! | 0    | 48..88   | updated at (unix s)                |
+//! | 0    | 88..248  | consumer that marked it executed   |
+//! | 1    | 0..160   | user                               |
+//! | 1    | 160..224 | nonce                              |
+//! | 2    | 0..160   | token                              |
+//! | 2    | 160..224 | deadline (unix s)                  |
+//! | 3    | 0..160   | recipient                          |
+//! | 3    | 160..224 | destination chain                  |
+//! | 4    | 0..256   | amount                             |
+//!
+//! Unused bits are zero. An intent whose nonce, deadline or destination
+//! chain doesn't fit 64 bits has no record, since reading it back would give
+//! values other than the ones its digest hashed. Timestamps are the block's
+//! and are clamped to 40 bits.
+
+use stylus_sdk::alloy_primitives::{Address, U256};
+
+use crate::encoding::{RegisteredIntent, RegisteredIntentStatus};
+
+/// Bit offset of the registration timestamp in the header
+pub const REGISTERED_AT_SHIFT: usize = 8;
+
+/// Bit offset of the last update timestamp in the header
+pub const UPDATED_AT_SHIFT: usize = 48;
+
+/// Bit offset of the consumer address in the header
+pub const CONSUMER_SHIFT: usize = 88;
+
+/// Bit offset of the small field sharing a word with an address
+pub const PACKED_FIELD_SHIFT: usize = 160;
+
+/// Storage words per record: header, user, token, recipient, amount
+pub const REGISTRY_RECORD_WORDS: usize = 5;
+
+/// Largest timestamp the header holds
+const MAX_TIMESTAMP: u64 = (1 << 40) - 1;
+
+/// One registered intent, as IntentValidator stores it
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RegistryRecord {
+    pub status: u8,
+    pub registered_at: u64,
+    pub updated_at: u64,
+    pub consumer: Address,
+    pub user: Address,
+    pub nonce: u64,
+    pub token: Address,
+    pub deadline: u64,
+    pub recipient: Address,
+    pub destination_chain: u64,
+    pub amount: U256,
+}
+
+impl RegistryRecord {
+    /// Pending record for an intent registered at `now`, or `None` if a
+    /// field is wider than 64 bits
+    pub fn open(intent: &RegisteredIntent, now: U256) -> Option<Self> {
+        let now = clamp_u64(now).min(MAX_TIMESTAMP);
+        Some(Self {
+            status: RegisteredIntentStatus::Pending as u8,
+            registered_at: now,
+            updated_at: now,
+            consumer: Address::ZERO,
+            user: intent.user,
+            nonce: u64::try_from(intent.nonce).ok()?,
+            token: intent.token,
+            deadline: u64::try_from(intent.deadline).ok()?,
+            recipient: intent.recipient,
+            destination_chain: u64::try_from(intent.destinationChain).ok()?,
+            amount: intent.amount,
+        })
+    }
+
+    /// Header word: status, timestamps and consumer
+    pub fn header(&self) -> U256 {
+        U256::from(self.status)
+            | (U256::from(self.registered_at.min(MAX_TIMESTAMP)) << REGISTERED_AT_SHIFT)
+            | (U256::from(self.updated_at.min(MAX_TIMESTAMP)) << UPDATED_AT_SHIFT)
+            | (address_word(self.consumer) << CONSUMER_SHIFT)
+    }
+
+    /// Storage words in order: header, user, token, recipient, amount
+    pub fn encode(&self) -> [U256; REGISTRY_RECORD_WORDS] {
+        [
+            self.header(),
+            pack_address(self.user, self.nonce),
+            pack_address(self.token, self.deadline),
+            pack_address(self.recipient, self.destination_chain),
+            self.amount,
+        ]
+    }
+
+    /// Record from its storage words; an unused record decodes to the default
+    pub fn decode(words: [U256; REGISTRY_RECORD_WORDS]) -> Self {
+        let [header, user, token, recipient, amount] = words;
+        let (user, nonce) = unpack_address(user);
+        let (token, deadline) = unpack_address(token);
+        let (recipient, destination_chain) = unpack_address(recipient);
+        Self {
+            status: registry_header_status(header),
+            registered_at: timestamp_field(header, REGISTERED_AT_SHIFT),
+            updated_at: timestamp_field(header, UPDATED_AT_SHIFT),
+            consumer: word_address(header >> CONSUMER_SHIFT),
+            user,
+            nonce,
+            token,
+            deadline,
+            recipient,
+            destination_chain,
+            amount,
+        }
+    }
+}
+
+/// Status held in a header word
+pub fn registry_header_status(header: U256) -> u8 {
+    header.byte(0)
+}
+
+/// Header with a new status, consumer and last update time, registration time kept
+pub fn update_registry_header(header: U256, status: u8, consumer: Address, now: U256) -> U256 {
+    let kept = header & (U256::from(MAX_TIMESTAMP) << REGISTERED_AT_SHIFT);
+    kept | U256::from(status)
+        | (U256::from(clamp_u64(now).min(MAX_TIMESTAMP)) << UPDATED_AT_SHIFT)
+        | (address_word(consumer) << CONSUMER_SHIFT)
+}
+
+/// An address in the low bits of a word with a 64-bit field above it
+fn pack_address(address: Address, field: u64) -> U256 {
+    address_word(address) | (U256::from(field) << PACKED_FIELD_SHIFT)
+}
+
+/// Address and 64-bit field of a word built by `pack_address`
+fn unpack_address(word: U256) -> (Address, u64) {
+    let field = ((word >> PACKED_FIELD_SHIFT) & U256::from(u64::MAX)).to::<u64>();
+    (word_address(word), field)
+}
+
+/// An address as the low 160 bits of a word
+fn address_word(address: Address) -> U256 {
+    U256::from_be_slice(address.as_slice())
+}
+
+/// Address in the low 160 bits of a word
+fn word_address(word: U256) -> Address {
+    Address::from_slice(&word.to_be_bytes::<32>()[12..])
+}
+
+/// 40-bit timestamp of a header word starting at `shift`
+fn timestamp_field(header: U256, shift: usize) -> u64 {
+    ((header >> shift) & U256::from(MAX_TIMESTAMP)).to::<u64>()
+}
+
+/// `value` as a u64, saturating at the maximum
+fn clamp_u64(value: U256) -> u64 {
+    u64::try_from(value).unwrap_or(u64::MAX)
+}
@@ -0,0 +1,89 @@
+//! Intent Retry Policies
+//!
+//! What SettlementVerifier does when an intent's delivery doesn't arrive
+//! within the settlement timeout. By default the intent fails and its user
+//! is refunded. An intent can instead carry a retry policy, signed with the
+//! rest of the intent: each time the timeout passes, the verifier opens a
+//! new delivery attempt and restarts the clock, until the policy runs out
+//! of retries or of total delay, and only then fails the intent.
+//!
+//! A retried attempt can still be delivered through the bridge adapter that
+//! carried the intent. Adapter reports from any other registered adapter
+//! only count when the policy allows alternate bridges.
+//!
+//! A policy is one word, from the least significant bit:
+//!
+//! | bits    | field                                      |
+//! |---------|--------------------------------------------|
+//! | 0..8    | max retries                                |
+//! | 8..72   | max total delay (seconds after execution)  |
+//! | 72      | alternate bridges allowed                  |
+//! | 73..256 | reserved, zero                             |
+
+use stylus_sdk::alloy_primitives::U256;
+
+/// Most retries a policy may ask for
+pub const MAX_RETRIES: u8 = 5;
+
+/// Bit offset of the max total delay in a policy word
+const MAX_DELAY_SHIFT: usize = 8;
+
+/// Bit of the alternate bridges flag in a policy word
+const ALTERNATE_BRIDGES_BIT: usize = 72;
+
+/// Retry policy of one intent
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Delivery attempts opened after the first one times out
+    pub max_retries: u8,
+    /// Seconds after execution past which no new attempt is opened
+    pub max_delay: u64,
+    /// Whether adapters other than the one that carried the intent may
+    /// report a retried attempt
+    pub alternate_bridges: bool,
+}
+
+impl RetryPolicy {
+    /// Policy from its intent word
+    ///
+    /// Zero is no policy. Returns `None` for a word with reserved bits set,
+    /// more than `MAX_RETRIES` retries, or retries with no delay to use.
+    pub fn decode(word: U256) -> Option<Self> {
+        if word >> (ALTERNATE_BRIDGES_BIT + 1) != U256::ZERO {
+            return None;
+        }
+        let policy = Self {
+            max_retries: word.byte(0),
+            max_delay: ((word >> MAX_DELAY_SHIFT) & U256::from(u64::MAX)).to::<u64>(),
+            alternate_bridges: word.bit(ALTERNATE_BRIDGES_BIT),
+        };
+        policy.is_valid().then_some(policy)
+    }
+
+    /// Intent word holding the policy
+    pub fn encode(&self) -> U256 {
+        U256::from(self.max_retries)
+            | (U256::from(self.max_delay) << MAX_DELAY_SHIFT)
+            | (U256::from(self.alternate_bridges) << ALTERNATE_BRIDGES_BIT)
+    }
+
+    /// Whether the policy retries at all
+    pub fn retries(&self) -> bool {
+        self.max_retries != 0
+    }
+
+    /// Whether an intent executed at `executed_at` and retried `retries`
+    /// times may open another attempt at `now`
+    pub fn allows_retry(&self, retries: u8, executed_at: u64, now: u64) -> bool {
+        retries < self.max_retries && now.saturating_sub(executed_at) <= self.max_delay
+    }
+
+    /// Whether the policy is no policy, or retries within its limits with
+    /// some delay to spend
+    pub fn is_valid(&self) -> bool {
+        if !self.retries() {
+            return self.max_delay == 0 && !self.alternate_bridges;
+        }
+        self.max_retries <= MAX_RETRIES && self.max_delay != 0
+    }
+}
@@ -0,0 +1,403 @@
+//! Route Codec
+//!
+//! Decoding and static validation of the routes solvers submit. RouteExecutor
+//! runs `validate_route` before pulling any user funds, so a malformed route
+//! reverts with a specific reason instead of failing halfway through execution.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use alloy_sol_types::{sol, SolValue};
+use stylus_sdk::alloy_primitives::{Address, Bytes, FixedBytes, U256};
+
+use crate::chain_address::{validate_chain_address, ChainFamily};
+use crate::encoding::{AssetType, Intent, RouteQuote, RouteStep, StepType, NATIVE_TOKEN};
+
+sol! {
+    /// Parameters of the optional swap in `execute_full_route`'s `swap_data`
+    struct SwapParams {
+        address adapter;
+        address tokenOut;
+        uint256 minAmountOut;
+        uint256 deadline;
+        bytes data;
+    }
+
+    /// Bridge step `data` carrying a gas limit, after `BRIDGE_OPTIONS_TAG`
+    ///
+    /// `adapters` is an allowlist as in `encode_adapter_allowlist`, or empty
+    /// to use the step's adapter; a zero `gasLimit` keeps the lane's default.
+    struct BridgeOptions {
+        address[] adapters;
+        uint256 gasLimit;
+    }
+}
+
+/// Maximum number of steps a route may contain
+pub const MAX_ROUTE_STEPS: usize = 8;
+
+/// Maximum number of bridge adapters a Bridge step may ask to compare
+pub const MAX_LANE_CANDIDATES: usize = 4;
+
+/// Prefix telling `BridgeOptions` apart from a bare adapter allowlist
+pub const BRIDGE_OPTIONS_TAG: [u8; 4] = *b"SWbo";
+
+/// Reasons a route is rejected
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RouteError {
+    /// Intent, steps, or swap params failed to ABI-decode
+    Malformed = 1,
+    /// Route has no steps
+    Empty = 2,
+    /// Route exceeds `MAX_ROUTE_STEPS`
+    TooManySteps = 3,
+    /// Step type discriminator is unknown
+    UnknownStep = 4,
+    /// Step input does not match the previous step's output
+    BrokenPath = 5,
+    /// Bridge/Transfer step is not last, or the last step is not Bridge/Transfer
+    BadTerminal = 6,
+    /// Swap targets an adapter that is not allowlisted
+    AdapterNotAllowed = 7,
+    /// Swap output token is zero or equal to its input
+    BadSwapTokens = 8,
+    /// Intent amount is zero or a step minimum is looser than the intent's
+    InconsistentAmounts = 9,
+    /// Step deadline is later than the intent deadline
+    InconsistentDeadline = 10,
+    /// Native ETH used where an ERC20 is required, or a wrap/unwrap on the wrong side
+    BadNativeStep = 11,
+    /// Wrap/Unwrap step names a token other than the configured WETH, or native
+    /// delivery targets a chain without a registered wrapped native
+    WrongWrappedNative = 12,
+    /// Bridge step allowlist is malformed, empty, too long, or set alongside a pinned adapter
+    BadAdapterAllowlist = 13,
+    /// Intent moves an NFT, which routes can't carry
+    UnsupportedAsset = 14,
+    /// Intent hides its recipient but the route doesn't end in a plain token
+    /// bridge, or it asks for a gas drop
+    HiddenRecipient = 15,
+    /// Bridge options are malformed, or set a gas limit alongside a gas drop
+    BadBridgeOptions = 16,
+    /// Intent's recipient doesn't match its chain family's address format, or
+    /// a non-EVM recipient's route doesn't end in a plain token bridge
+    BadRecipient = 17,
+}
+
+impl RouteError {
+    /// Numeric reason code surfaced in the `InvalidRoute` revert
+    pub fn code(self) -> u8 {
+        self as u8
+    }
+}
+
+/// Decode an ABI-encoded `Intent`
+pub fn decode_intent(data: &[u8]) -> Result<Intent, RouteError> {
+    Intent::abi_decode(data).map_err(|_| RouteError::Malformed)
+}
+
+/// Decode an ABI-encoded `RouteStep[]`
+pub fn decode_route(data: &[u8]) -> Result<Vec<RouteStep>, RouteError> {
+    Vec::<RouteStep>::abi_decode(data).map_err(|_| RouteError::Malformed)
+}
+
+/// Decode ABI-encoded `SwapParams`
+pub fn decode_swap_params(data: &[u8]) -> Result<SwapParams, RouteError> {
+    SwapParams::abi_decode(data).map_err(|_| RouteError::Malformed)
+}
+
+/// Decode an ABI-encoded `RouteQuote`
+pub fn decode_route_quote(data: &[u8]) -> Result<RouteQuote, RouteError> {
+    RouteQuote::abi_decode(data).map_err(|_| RouteError::Malformed)
+}
+
+/// Encode the bridge adapters a Bridge step may choose the cheapest of
+///
+/// The list travels in the Bridge step's `data` as `abi.encode(address[])`.
+pub fn encode_adapter_allowlist(adapters: &[Address]) -> Bytes {
+    adapters.to_vec().abi_encode().into()
+}
+
+/// Decode a Bridge step's adapter allowlist
+///
+/// Rejects lists that are empty or longer than `MAX_LANE_CANDIDATES`.
+pub fn decode_adapter_allowlist(data: &[u8]) -> Result<Vec<Address>, RouteError> {
+    let adapters = Vec::<Address>::abi_decode(data).map_err(|_| RouteError::BadAdapterAllowlist)?;
+    if adapters.is_empty() || adapters.len() > MAX_LANE_CANDIDATES {
+        return Err(RouteError::BadAdapterAllowlist);
+    }
+    Ok(adapters)
+}
+
+/// Encode Bridge step `data` overriding the lane's destination gas limit
+///
+/// `adapters` may be empty to keep the step's own adapter.
+pub fn encode_bridge_options(adapters: &[Address], gas_limit: U256) -> Bytes {
+    let options = BridgeOptions {
+        adapters: adapters.to_vec(),
+        gasLimit: gas_limit,
+    };
+    let mut data = BRIDGE_OPTIONS_TAG.to_vec();
+    data.extend_from_slice(&options.abi_encode());
+    data.into()
+}
+
+/// Decode a Bridge step's `data` into its adapter allowlist (empty for none)
+/// and destination gas limit (zero for the lane default)
+///
+/// Accepts empty data, a bare allowlist, or tagged `BridgeOptions`.
+pub fn decode_bridge_step_data(data: &[u8]) -> Result<(Vec<Address>, U256), RouteError> {
+    if data.is_empty() {
+        return Ok((Vec::new(), U256::ZERO));
+    }
+
+    let Some(encoded) = data.strip_prefix(&BRIDGE_OPTIONS_TAG) else {
+        return Ok((decode_adapter_allowlist(data)?, U256::ZERO));
+    };
+    let options = BridgeOptions::abi_decode(encoded).map_err(|_| RouteError::BadBridgeOptions)?;
+    if options.adapters.len() > MAX_LANE_CANDIDATES {
+        return Err(RouteError::BadAdapterAllowlist);
+    }
+    Ok((options.adapters, options.gasLimit))
+}
+
+/// Pick the lowest fee among quoted adapters, keeping the earliest on ties
+pub fn cheapest_quote(quotes: &[(Address, U256)]) -> Option<(Address, U256)> {
+    quotes
+        .iter()
+        .copied()
+        .reduce(|best, quote| if quote.1 < best.1 { quote } else { best })
+}
+
+/// Build the swap-then-bridge route expressed by `execute_full_route`
+///
+/// A native `token_in` is wrapped into `weth` first.
+pub fn full_route_steps(token_in: Address, weth: Address, swap: Option<SwapParams>) -> Vec<RouteStep> {
+    let mut steps = Vec::with_capacity(3);
+    let mut current_token = token_in;
+
+    if token_in == NATIVE_TOKEN {
+        current_token = weth;
+        steps.push(RouteStep {
+            stepType: StepType::Wrap as u8,
+            adapter: Address::ZERO,
+            tokenIn: NATIVE_TOKEN,
+            tokenOut: weth,
+            minAmountOut: U256::ZERO,
+            deadline: U256::ZERO,
+            recipient: Address::ZERO,
+            data: Bytes::new(),
+        });
+    }
+
+    if let Some(swap) = swap {
+        let swap_in = current_token;
+        current_token = swap.tokenOut;
+        steps.push(RouteStep {
+            stepType: StepType::Swap as u8,
+            adapter: swap.adapter,
+            tokenIn: swap_in,
+            tokenOut: swap.tokenOut,
+            minAmountOut: swap.minAmountOut,
+            deadline: swap.deadline,
+            recipient: Address::ZERO,
+            data: swap.data,
+        });
+    }
+
+    steps.push(RouteStep {
+        stepType: StepType::Bridge as u8,
+        adapter: Address::ZERO,
+        tokenIn: current_token,
+        tokenOut: current_token,
+        minAmountOut: U256::ZERO,
+        deadline: U256::ZERO,
+        recipient: Address::ZERO,
+        data: Bytes::new(),
+    });
+
+    steps
+}
+
+/// Ask for native delivery when a route's Bridge step carries `weth`
+///
+/// Returns whether the step's `tokenOut` was switched to `NATIVE_TOKEN`.
+pub fn request_native_delivery(steps: &mut [RouteStep], weth: Address) -> bool {
+    match steps.last_mut() {
+        Some(step)
+            if weth != Address::ZERO && step.stepType == StepType::Bridge as u8 && step.tokenIn == weth =>
+        {
+            step.tokenOut = NATIVE_TOKEN;
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Check an intent's recipient fields against its chain family
+///
+/// An EVM intent leaves `recipientAddress` empty. Any other family needs a
+/// valid `recipientAddress` and no `recipient` or `recipientHash`, since a
+/// hidden recipient is revealed to an EVM RouteExecutor.
+pub fn check_recipient(intent: &Intent) -> Result<ChainFamily, RouteError> {
+    if intent.recipientFamily == ChainFamily::Evm as u8 {
+        if !intent.recipientAddress.is_empty() {
+            return Err(RouteError::BadRecipient);
+        }
+        return Ok(ChainFamily::Evm);
+    }
+
+    let family = validate_chain_address(intent.recipientFamily, &intent.recipientAddress)
+        .map_err(|_| RouteError::BadRecipient)?;
+    if intent.recipient != Address::ZERO || intent.recipientHash != FixedBytes::ZERO {
+        return Err(RouteError::BadRecipient);
+    }
+    Ok(family)
+}
+
+/// Statically validate a route against its intent
+///
+/// Checks that step types are known, the token path is connected from the
+/// intent's input, only the last step hands funds off, swaps target allowed
+/// adapters, native ETH only enters or leaves through `weth` wrap/unwrap
+/// steps, and step minimums/deadlines are consistent with the intent.
+/// A Bridge step whose `tokenOut` is `NATIVE_TOKEN` requests native delivery
+/// on the destination chain; one with non-empty `data` carries an adapter
+/// allowlist to pick the cheapest lane from, and must not pin an adapter.
+/// Tagged `BridgeOptions` may also raise the destination gas limit, which
+/// can't be combined with a gas drop.
+/// Only ERC-20 (and native) intents can be routed. An intent with a
+/// `recipientHash` must end in a Bridge step delivering the token itself.
+/// So must one for a non-EVM recipient, on the lane's own adapter and
+/// without a gas drop or bridge options.
+pub fn validate_route<F>(
+    intent: &Intent,
+    steps: &[RouteStep],
+    weth: Address,
+    is_adapter_allowed: F,
+) -> Result<(), RouteError>
+where
+    F: Fn(Address) -> bool,
+{
+    if intent.assetType != AssetType::Erc20 as u8 {
+        return Err(RouteError::UnsupportedAsset);
+    }
+
+    if intent.amountIn == U256::ZERO {
+        return Err(RouteError::InconsistentAmounts);
+    }
+
+    if steps.is_empty() {
+        return Err(RouteError::Empty);
+    }
+
+    if steps.len() > MAX_ROUTE_STEPS {
+        return Err(RouteError::TooManySteps);
+    }
+
+    let last_index = steps.len() - 1;
+    let mut current_token = intent.tokenIn;
+    let mut last_swap_min_out = None;
+
+    for (index, step) in steps.iter().enumerate() {
+        let step_type = StepType::from_u8(step.stepType).ok_or(RouteError::UnknownStep)?;
+
+        if step.tokenIn != current_token {
+            return Err(RouteError::BrokenPath);
+        }
+
+        if step_type.is_terminal() != (index == last_index) {
+            return Err(RouteError::BadTerminal);
+        }
+
+        if step.deadline != U256::ZERO && step.deadline > intent.deadline {
+            return Err(RouteError::InconsistentDeadline);
+        }
+
+        match step_type {
+            StepType::Swap => {
+                if !is_adapter_allowed(step.adapter) {
+                    return Err(RouteError::AdapterNotAllowed);
+                }
+                if step.tokenIn == NATIVE_TOKEN {
+                    return Err(RouteError::BadNativeStep);
+                }
+                if step.tokenOut == NATIVE_TOKEN || step.tokenOut == step.tokenIn {
+                    return Err(RouteError::BadSwapTokens);
+                }
+                last_swap_min_out = Some(step.minAmountOut);
+            }
+            StepType::Wrap => {
+                if step.tokenIn != NATIVE_TOKEN {
+                    return Err(RouteError::BadNativeStep);
+                }
+                if weth == Address::ZERO || step.tokenOut != weth {
+                    return Err(RouteError::WrongWrappedNative);
+                }
+            }
+            StepType::Unwrap => {
+                if step.tokenOut != NATIVE_TOKEN {
+                    return Err(RouteError::BadNativeStep);
+                }
+                if weth == Address::ZERO || step.tokenIn != weth {
+                    return Err(RouteError::WrongWrappedNative);
+                }
+            }
+            StepType::Bridge => {
+                // Adapters only carry ERC20s; native delivery unwraps WETH remotely
+                if step.tokenIn == NATIVE_TOKEN {
+                    return Err(RouteError::BadNativeStep);
+                }
+                if step.tokenOut == NATIVE_TOKEN {
+                    if weth == Address::ZERO || step.tokenIn != weth {
+                        return Err(RouteError::WrongWrappedNative);
+                    }
+                } else if step.tokenOut != step.tokenIn {
+                    return Err(RouteError::BrokenPath);
+                }
+                let (adapters, gas_limit) = decode_bridge_step_data(&step.data)?;
+                if !adapters.is_empty() && step.adapter != Address::ZERO {
+                    return Err(RouteError::BadAdapterAllowlist);
+                }
+                if gas_limit != U256::ZERO && intent.gasDrop != U256::ZERO {
+                    return Err(RouteError::BadBridgeOptions);
+                }
+            }
+            StepType::Transfer => {
+                if step.tokenOut != step.tokenIn {
+                    return Err(RouteError::BrokenPath);
+                }
+            }
+        }
+
+        current_token = step.tokenOut;
+    }
+
+    // The final swap must not tolerate less than the intent itself accepts
+    if let Some(min_out) = last_swap_min_out {
+        if min_out < intent.minAmountOut {
+            return Err(RouteError::InconsistentAmounts);
+        }
+    }
+
+    // A hidden recipient is only revealed on the destination, after a bridge
+    if intent.recipientHash != FixedBytes::ZERO {
+        let last = &steps[last_index];
+        if last.stepType != StepType::Bridge as u8 || last.tokenOut == NATIVE_TOKEN || intent.gasDrop != U256::ZERO {
+            return Err(RouteError::HiddenRecipient);
+        }
+    }
+
+    if check_recipient(intent)? != ChainFamily::Evm {
+        let last = &steps[last_index];
+        if last.stepType != StepType::Bridge as u8
+            || last.tokenOut == NATIVE_TOKEN
+            || !last.data.is_empty()
+            || intent.gasDrop != U256::ZERO
+        {
+            return Err(RouteError::BadRecipient);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,154 @@
+//! Intent Signing
+//!
+//! EIP-712 hashing of user intents and signature handling for solver
+//! execution. Users sign an `Intent` against the RouteExecutor domain; a
+//! registered solver submits it together with the route it found. Route
+//! oracles sign a `RouteQuote` against the same domain to vouch for a route,
+//! and a user can sign a `ValidatorIntent` for IntentValidator to check, or
+//! a `RegisteredIntent` for it to record, with the validator as verifying
+//! contract.
+//! An intent may hide its recipient behind a salted commitment, revealed on
+//! the destination chain at settlement.
+
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloy_sol_types::{Eip712Domain, SolStruct, SolValue};
+use stylus_sdk::alloy_primitives::{keccak256, Address, FixedBytes, U256};
+
+use crate::encoding::{Intent, RegisteredIntent, RouteQuote, RouteStep, ValidatorIntent};
+
+/// EIP-712 domain name shared by every Swoosh deployment
+pub const DOMAIN_NAME: &str = "Swoosh";
+/// EIP-712 domain version
+pub const DOMAIN_VERSION: &str = "1";
+
+/// ecrecover precompile
+pub const ECRECOVER: Address = Address::new([
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+]);
+
+/// secp256k1 group order divided by two; larger `s` values are malleable
+const SECP256K1N_HALF: U256 = U256::from_be_bytes([
+    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+]);
+
+/// EIP-712 domain of the RouteExecutor at `verifying_contract`
+pub fn intent_domain(chain_id: u64, verifying_contract: Address) -> Eip712Domain {
+    Eip712Domain::new(
+        Some(Cow::Borrowed(DOMAIN_NAME)),
+        Some(Cow::Borrowed(DOMAIN_VERSION)),
+        Some(U256::from(chain_id)),
+        Some(verifying_contract),
+        None,
+    )
+}
+
+/// Digest a user signs to authorize an intent
+///
+/// Also serves as the intent hash used for replay protection and cancellation.
+pub fn intent_digest(intent: &Intent, chain_id: u64, verifying_contract: Address) -> FixedBytes<32> {
+    intent.eip712_signing_hash(&intent_domain(chain_id, verifying_contract))
+}
+
+/// Key of an intent in the chain's IntentRegistry: its EIP-712 struct hash
+///
+/// Unlike the digest it leaves out the domain, so every executor on a chain
+/// derives the same key for the same intent.
+pub fn intent_key(intent: &Intent) -> FixedBytes<32> {
+    intent.eip712_hash_struct()
+}
+
+/// Intent ID used across chains: the intent hash read as a uint256
+///
+/// The hash commits to the source chain and executor through the domain, so
+/// IDs from different deployments can't collide the way local counters do.
+pub fn global_intent_id(intent_hash: FixedBytes<32>) -> U256 {
+    U256::from_be_bytes(intent_hash.0)
+}
+
+/// Digest a user signs to have IntentValidator at `verifying_contract` validate an intent
+pub fn validator_intent_digest(intent: &ValidatorIntent, chain_id: u64, verifying_contract: Address) -> FixedBytes<32> {
+    intent.eip712_signing_hash(&intent_domain(chain_id, verifying_contract))
+}
+
+/// Key of an intent in the registry of IntentValidator at
+/// `verifying_contract`, and the digest a user signs to let someone else
+/// register it for them
+///
+/// The domain binds the key to one validator on one chain, so the same
+/// intent registered elsewhere gets a different key.
+pub fn registered_intent_digest(
+    intent: &RegisteredIntent,
+    chain_id: u64,
+    verifying_contract: Address,
+) -> FixedBytes<32> {
+    intent.eip712_signing_hash(&intent_domain(chain_id, verifying_contract))
+}
+
+/// Hash of a route's steps as committed to in a `RouteQuote`
+pub fn route_hash(steps: &[RouteStep]) -> FixedBytes<32> {
+    keccak256(steps.abi_encode())
+}
+
+/// Digest a route oracle signs to commit to a quote
+pub fn route_quote_digest(quote: &RouteQuote, chain_id: u64, verifying_contract: Address) -> FixedBytes<32> {
+    quote.eip712_signing_hash(&intent_domain(chain_id, verifying_contract))
+}
+
+/// Commitment to a hidden recipient, carried as an intent's `recipientHash`
+///
+/// `keccak256(abi.encode(recipient, salt))`; the salt keeps a known set of
+/// recipients from being tried against it.
+pub fn recipient_commitment(recipient: Address, salt: FixedBytes<32>) -> FixedBytes<32> {
+    keccak256((recipient, salt).abi_encode())
+}
+
+/// Identifier of a matched pair of opposing intents
+///
+/// Order-independent, so the executors on both chains derive the same ID
+/// from the two intent hashes and the matched amount.
+pub fn match_id(intent_hash: FixedBytes<32>, counterparty_hash: FixedBytes<32>, matched_amount: U256) -> FixedBytes<32> {
+    let (low, high) = if intent_hash <= counterparty_hash {
+        (intent_hash, counterparty_hash)
+    } else {
+        (counterparty_hash, intent_hash)
+    };
+    keccak256((low, high, matched_amount).abi_encode())
+}
+
+/// Build ecrecover precompile input from a digest and a 65-byte `r | s | v` signature
+///
+/// Returns `None` for malformed signatures, an invalid `v`, or a high `s`.
+pub fn ecrecover_input(digest: FixedBytes<32>, signature: &[u8]) -> Option<[u8; 128]> {
+    if signature.len() != 65 {
+        return None;
+    }
+
+    let s = U256::from_be_slice(&signature[32..64]);
+    if s > SECP256K1N_HALF {
+        return None;
+    }
+
+    let v = match signature[64] {
+        0 | 1 => signature[64] + 27,
+        27 | 28 => signature[64],
+        _ => return None,
+    };
+
+    let mut input = [0u8; 128];
+    input[..32].copy_from_slice(digest.as_slice());
+    input[63] = v;
+    input[64..128].copy_from_slice(&signature[..64]);
+    Some(input)
+}
+
+/// Parse the ecrecover precompile output, returning `None` on failure
+pub fn recovered_address(output: &[u8]) -> Option<Address> {
+    if output.len() != 32 {
+        return None;
+    }
+    let signer = Address::from_slice(&output[12..]);
+    (signer != Address::ZERO).then_some(signer)
+}
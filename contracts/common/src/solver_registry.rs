@@ -0,0 +1,65 @@
+//! Solver Registry Interface
+//!
+//! Interface of SolverRegistry as seen by RouteExecutor, which resolves the
+//! solver behind each caller before executing signed intents, LiquidityPool,
+//! which lends only to active solvers against their locked bond, and
+//! SettlementVerifier, which slashes solvers whose intents fail to settle.
+//! RouteExecutor also penalizes solvers whose claim on an escrowed intent lapses.
+//!
+//! Solvers can delegate to operator keys, each with an expiry and a set of
+//! `SCOPE_*` permissions, so their bonded key need not sign every execution.
+//!
+//! RouteExecutor gives each newly queued intent a short rotation window in
+//! which only one active solver may take it, picked by `rotation_index` from
+//! the intent hash and the block it was queued in, so solvers take turns
+//! instead of racing for every intent.
+
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use stylus_sdk::alloy_primitives::{keccak256, FixedBytes, U256};
+use stylus_sdk::prelude::*;
+
+/// Operator key may execute signed intents
+pub const SCOPE_EXECUTE: u8 = 1 << 0;
+
+/// Operator key may settle matched legs
+pub const SCOPE_MATCH: u8 = 1 << 1;
+
+/// Operator key may reveal hidden recipients
+pub const SCOPE_SETTLE: u8 = 1 << 2;
+
+/// Every operator scope
+pub const ALL_SCOPES: u8 = SCOPE_EXECUTE | SCOPE_MATCH | SCOPE_SETTLE;
+
+sol_interface! {
+    interface ISolverRegistry {
+        function getSolverId(address solver) external view returns (uint256);
+        function isActiveSolver(address solver) external view returns (bool);
+        function resolveSolver(address account, uint8 scope) external view returns (uint256, address);
+        function slash(uint256 solver_id, uint256 amount, uint256 intent_id) external returns (uint256);
+        function penalize(uint256 solver_id, uint256 amount, uint256 intent_id, address user) external returns (uint256);
+        function lockBond(uint256 solver_id, uint256 amount, uint256 intent_id) external;
+        function unlockBond(uint256 solver_id, uint256 amount, uint256 intent_id) external;
+        function seizeBond(uint256 solver_id, uint256 amount, uint256 intent_id, address to) external returns (uint256);
+        function activeSolverCount() external view returns (uint256);
+        function activeSolverAt(uint256 index) external view returns (uint256);
+    }
+}
+
+/// Position in the active solver set of the solver an intent rotates to
+///
+/// Hashes the intent hash with the block number and timestamp it was queued
+/// at, so the pick is fixed once queued but not known before. `eligible` is
+/// the size of the active set; zero picks no one.
+pub fn rotation_index(intent_hash: FixedBytes<32>, block_number: u64, timestamp: u64, eligible: U256) -> Option<U256> {
+    if eligible == U256::ZERO {
+        return None;
+    }
+    let mut seed = [0u8; 48];
+    seed[..32].copy_from_slice(intent_hash.as_slice());
+    seed[32..40].copy_from_slice(&block_number.to_be_bytes());
+    seed[40..].copy_from_slice(&timestamp.to_be_bytes());
+    Some(U256::from_be_bytes(keccak256(seed).0) % eligible)
+}
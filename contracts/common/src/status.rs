@@ -0,0 +1,101 @@
+//! Protocol Status
+//!
+//! Health snapshot every core contract returns from `get_protocol_status`,
+//! so monitoring polls one `eth_call` per contract instead of a dozen
+//! getters. The contract returns it ABI-encoded; `decode_protocol_status`
+//! turns it back into the struct.
+//!
+//! `counters` depends on the contract:
+//!
+//! | contract           | counters                                    |
+//! |--------------------|---------------------------------------------|
+//! | IntentValidator    | config keys, last config-sync nonce         |
+//! | RouteExecutor      | intents, queued intents, config keys        |
+//! | SettlementVerifier | none                                        |
+//! | SwooshReceiver     | none                                        |
+//! | SolverRegistry     | solvers, active solvers                     |
+//! | InsuranceFund      | total paid out                              |
+//! | IntentRegistry     | consumed intent keys                        |
+//! | LiquidityPool      | supported tokens, open loans                |
+//!
+//! Bridge and swap adapters, the factory and the lens report nothing: they
+//! hold no pausable state or counters worth polling.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use alloy_sol_types::{sol, SolValue};
+use stylus_sdk::alloy_primitives::{Address, U256};
+
+sol! {
+    /// One contract's status, see `StatusContract`
+    ///
+    /// `version` is the contract crate's version. `pendingOwner` is zero
+    /// while ownership transfers in one step. `configEpoch` is the fee and
+    /// limit config epoch for IntentValidator and zero for the others.
+    struct ProtocolStatus {
+        uint8 kind;
+        string version;
+        address owner;
+        address pendingOwner;
+        bool paused;
+        bool emergencyMode;
+        uint256 configEpoch;
+        uint256[] counters;
+    }
+}
+
+/// Contract a `ProtocolStatus` describes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StatusContract {
+    IntentValidator = 1,
+    RouteExecutor = 2,
+    SettlementVerifier = 3,
+    SwooshReceiver = 4,
+    SolverRegistry = 5,
+    InsuranceFund = 6,
+    IntentRegistry = 7,
+    LiquidityPool = 8,
+}
+
+impl StatusContract {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(Self::IntentValidator),
+            2 => Some(Self::RouteExecutor),
+            3 => Some(Self::SettlementVerifier),
+            4 => Some(Self::SwooshReceiver),
+            5 => Some(Self::SolverRegistry),
+            6 => Some(Self::InsuranceFund),
+            7 => Some(Self::IntentRegistry),
+            8 => Some(Self::LiquidityPool),
+            _ => None,
+        }
+    }
+}
+
+impl ProtocolStatus {
+    /// Status of an unpaused contract with no config epoch or counters yet
+    pub fn new(kind: StatusContract, version: &str, owner: Address) -> Self {
+        Self {
+            kind: kind as u8,
+            version: version.into(),
+            owner,
+            pendingOwner: Address::ZERO,
+            paused: false,
+            emergencyMode: false,
+            configEpoch: U256::ZERO,
+            counters: Vec::new(),
+        }
+    }
+}
+
+/// ABI-encode a contract's status
+pub fn encode_protocol_status(status: &ProtocolStatus) -> Vec<u8> {
+    status.abi_encode()
+}
+
+/// Decode a `get_protocol_status` result, returning `None` if malformed
+pub fn decode_protocol_status(data: &[u8]) -> Option<ProtocolStatus> {
+    ProtocolStatus::abi_decode(data).ok()
+}
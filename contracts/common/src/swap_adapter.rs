@@ -0,0 +1,37 @@
+//! Swap Adapter Interface
+//!
+//! Interface implemented by every DEX adapter. RouteExecutor approves the
+//! adapter for `amountIn`; the adapter pulls the input, swaps through its DEX,
+//! and sends the output to `recipient`. The executor never trusts the returned
+//! amount on its own and verifies the output through its balance delta.
+//!
+//! Adapters that can estimate output also expose `quote`, which returns zero
+//! when no estimate is possible. It is not a view: on-chain quoters simulate
+//! the swap, so off-chain callers should use `eth_call`.
+
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use stylus_sdk::prelude::*;
+
+// Swap adapter interface (implemented by every DEX adapter contract)
+sol_interface! {
+    interface ISwapAdapter {
+        function swap(
+            address token_in,
+            address token_out,
+            uint256 amount_in,
+            uint256 min_amount_out,
+            address recipient,
+            bytes calldata data
+        ) external returns (uint256);
+
+        function quote(
+            address token_in,
+            address token_out,
+            uint256 amount_in,
+            bytes calldata data
+        ) external returns (uint256);
+    }
+}
@@ -0,0 +1,96 @@
+//! Token Listing Modes
+//!
+//! How IntentValidator decides which input tokens it accepts. A deployment
+//! runs in one mode at a time, switched by its owner:
+//!
+//! - `Allowlist`: only tokens the owner added are supported. This is how
+//!   every deployment behaved before listing modes existed.
+//! - `Permissionless`: tokens anyone has listed are supported too, but each
+//!   intent in one is capped at the amount cap it was listed with. Listing
+//!   checks on-chain that the token behaves like an ERC-20, has at most
+//!   `MAX_LISTING_DECIMALS` decimals, isn't blocked, and can be priced by
+//!   the price oracle or a TWAP pool.
+//! - `Blocklist`: every token is supported unless blocked.
+//!
+//! Blocked tokens are rejected in every mode, even when allowlisted, and
+//! allowlisted tokens are never capped.
+
+/// Listing mode of a validator deployment
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenListingMode {
+    Allowlist = 0,
+    Permissionless = 1,
+    Blocklist = 2,
+}
+
+impl TokenListingMode {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Allowlist),
+            1 => Some(Self::Permissionless),
+            2 => Some(Self::Blocklist),
+            _ => None,
+        }
+    }
+}
+
+/// Most decimals a permissionless listing may have
+pub const MAX_LISTING_DECIMALS: u8 = 18;
+
+/// Why a token can't be listed permissionlessly
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ListingRejection {
+    /// Token is blocked
+    Blocked = 1,
+    /// An ERC-20 view reverted or returned garbage
+    NotErc20 = 2,
+    /// Token has more than `MAX_LISTING_DECIMALS` decimals
+    TooManyDecimals = 3,
+    /// Neither the price oracle nor the TWAP source can price it
+    NoPriceSource = 4,
+}
+
+/// Check the listing criteria, in order
+///
+/// `decimals` is `None` when the ERC-20 views failed.
+pub fn check_listing(blocked: bool, decimals: Option<u8>, priced: bool) -> Result<(), ListingRejection> {
+    if blocked {
+        return Err(ListingRejection::Blocked);
+    }
+    match decimals {
+        None => Err(ListingRejection::NotErc20),
+        Some(decimals) if decimals > MAX_LISTING_DECIMALS => Err(ListingRejection::TooManyDecimals),
+        Some(_) if !priced => Err(ListingRejection::NoPriceSource),
+        Some(_) => Ok(()),
+    }
+}
+
+/// How a token is admitted under a listing mode
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenAdmission {
+    /// Not supported
+    Rejected,
+    /// Supported without a cap
+    Full,
+    /// Supported up to the token's listing cap per intent
+    Capped,
+}
+
+/// Admission of a token under `mode`
+///
+/// `listed` is whether the token was listed permissionlessly; only
+/// permissionless mode reads it.
+pub fn token_admission(mode: TokenListingMode, allowlisted: bool, blocked: bool, listed: bool) -> TokenAdmission {
+    if blocked {
+        return TokenAdmission::Rejected;
+    }
+    if allowlisted {
+        return TokenAdmission::Full;
+    }
+    match mode {
+        TokenListingMode::Allowlist => TokenAdmission::Rejected,
+        TokenListingMode::Permissionless if listed => TokenAdmission::Capped,
+        TokenListingMode::Permissionless => TokenAdmission::Rejected,
+        TokenListingMode::Blocklist => TokenAdmission::Full,
+    }
+}
@@ -0,0 +1,85 @@
+//! Validator Compatibility
+//!
+//! What IntentValidator reports from `self_check` and what RouteExecutor
+//! accepts before wiring a validator in. A validator advertises its interface
+//! version, the intent fields it reads and the checks it implements; the
+//! executor takes it if the major version matches, it reads no field the
+//! executor doesn't pass, and it has every check the executor calls.
+
+use stylus_sdk::alloy_primitives::U256;
+
+/// Validator interface version; bumped whenever a validator entry point changes
+pub const VALIDATOR_VERSION: u64 = 1;
+
+/// Intent fields, one bit each in `Intent` field order
+pub const FIELD_USER: u64 = 1 << 0;
+pub const FIELD_TOKEN_IN: u64 = 1 << 1;
+pub const FIELD_AMOUNT_IN: u64 = 1 << 2;
+pub const FIELD_DESTINATION_CHAIN: u64 = 1 << 3;
+pub const FIELD_RECIPIENT: u64 = 1 << 4;
+pub const FIELD_MIN_AMOUNT_OUT: u64 = 1 << 5;
+pub const FIELD_DEADLINE: u64 = 1 << 6;
+pub const FIELD_NONCE: u64 = 1 << 7;
+pub const FIELD_GAS_DROP: u64 = 1 << 8;
+pub const FIELD_ASSET_TYPE: u64 = 1 << 9;
+pub const FIELD_TOKEN_ID: u64 = 1 << 10;
+pub const FIELD_RECIPIENT_HASH: u64 = 1 << 11;
+pub const FIELD_MAX_SOLVER_FEE: u64 = 1 << 12;
+pub const FIELD_PRIORITY_FEE: u64 = 1 << 13;
+pub const FIELD_BLOCK_NUMBER: u64 = 1 << 14;
+pub const FIELD_BLOCK_HASH: u64 = 1 << 15;
+pub const FIELD_CONFIG_EPOCH: u64 = 1 << 16;
+
+/// Fields RouteExecutor passes to the validator
+pub const EXECUTOR_SUPPLIED_FIELDS: u64 = FIELD_USER
+    | FIELD_TOKEN_IN
+    | FIELD_AMOUNT_IN
+    | FIELD_DESTINATION_CHAIN
+    | FIELD_ASSET_TYPE
+    | FIELD_TOKEN_ID
+    | FIELD_CONFIG_EPOCH;
+
+/// Checks a validator may implement
+pub const FEATURE_ERC20: u64 = 1 << 0;
+pub const FEATURE_NFT: u64 = 1 << 1;
+pub const FEATURE_LANE_HEALTH: u64 = 1 << 2;
+pub const FEATURE_INTENT_BLOCKLIST: u64 = 1 << 3;
+pub const FEATURE_CONFIG_EPOCH: u64 = 1 << 4;
+pub const FEATURE_DELEGATION: u64 = 1 << 5;
+pub const FEATURE_WATCHTOWER: u64 = 1 << 6;
+pub const FEATURE_SIGNED_INTENT: u64 = 1 << 7;
+pub const FEATURE_INTENT_REGISTRY: u64 = 1 << 8;
+pub const FEATURE_EXECUTION_CHECK: u64 = 1 << 9;
+
+/// Checks RouteExecutor calls on its validator
+pub const EXECUTOR_REQUIRED_FEATURES: u64 = FEATURE_NFT
+    | FEATURE_LANE_HEALTH
+    | FEATURE_INTENT_BLOCKLIST
+    | FEATURE_CONFIG_EPOCH
+    | FEATURE_DELEGATION
+    | FEATURE_WATCHTOWER
+    | FEATURE_EXECUTION_CHECK;
+
+/// Reason a validator is rejected, as carried by `IncompatibleValidator`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Incompatibility {
+    Version = 1,
+    Fields = 2,
+    Features = 3,
+}
+
+/// Check a validator's `self_check` report against what the executor needs
+pub fn check_validator(version: U256, required_fields: U256, features: U256) -> Result<(), Incompatibility> {
+    if version != U256::from(VALIDATOR_VERSION) {
+        return Err(Incompatibility::Version);
+    }
+    if required_fields & !U256::from(EXECUTOR_SUPPLIED_FIELDS) != U256::ZERO {
+        return Err(Incompatibility::Fields);
+    }
+    let needed = U256::from(EXECUTOR_REQUIRED_FEATURES);
+    if features & needed != needed {
+        return Err(Incompatibility::Features);
+    }
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use swoosh_common::approval_policy::*;
+
+#[cfg(test)]
+mod approval_policy_tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_from_u8() {
+        assert_eq!(ApprovalPolicy::from_u8(0), Some(ApprovalPolicy::Exact), "Exact");
+        assert_eq!(ApprovalPolicy::from_u8(1), Some(ApprovalPolicy::Revoke), "Revoke");
+        assert_eq!(ApprovalPolicy::from_u8(2), None, "Unknown policy");
+        assert_eq!(ApprovalPolicy::default(), ApprovalPolicy::Exact, "Unset adapters keep exact approvals");
+    }
+
+    #[test]
+    fn test_revokes() {
+        assert!(ApprovalPolicy::Revoke.revokes(), "Revoke");
+        assert!(!ApprovalPolicy::Exact.revokes(), "Exact");
+    }
+}
@@ -0,0 +1,60 @@
+use alloy_sol_types::SolStruct;
+use stylus_sdk::alloy_primitives::{keccak256, Address, FixedBytes, U256};
+use swoosh_common::attestation::*;
+
+#[cfg(test)]
+mod attestation_tests {
+    use super::*;
+
+    fn sample() -> IntentAttestation {
+        IntentAttestation {
+            intentHash: FixedBytes::repeat_byte(0xab),
+            user: Address::from([1u8; 20]),
+            amountIn: U256::from(1_000_000u64),
+            amountOut: U256::from(997_000u64),
+            sourceChain: U256::from(42_161u64),
+            destinationChain: U256::from(8_453u64),
+            executedAt: 1_700_000_000,
+            settledAt: 1_700_000_090,
+            status: 1,
+            memo: FixedBytes::repeat_byte(0x4d),
+        }
+    }
+
+    #[test]
+    fn test_schema_matches_struct() {
+        // The registered schema lists the struct's fields in order
+        let encoded_type = IntentAttestation::eip712_encode_type();
+
+        assert_eq!(encoded_type, format!("IntentAttestation({INTENT_ATTESTATION_SCHEMA})"), "Schema fields");
+    }
+
+    #[test]
+    fn test_attestation_round_trip() {
+        // Data is abi.encode of the fields: one word each
+        let attestation = sample();
+        let data = encode_intent_attestation(&attestation);
+
+        assert_eq!(data.len(), 10 * 32, "Static fields only");
+        assert_eq!(&data[..32], attestation.intentHash.as_slice(), "Intent hash first");
+        let decoded = decode_intent_attestation(&data).expect("Attestation decodes");
+        assert_eq!(decoded.user, attestation.user, "User");
+        assert_eq!(decoded.amountOut, attestation.amountOut, "Amount out");
+        assert_eq!((decoded.executedAt, decoded.settledAt), (1_700_000_000, 1_700_000_090), "Timestamps");
+        assert_eq!(decoded.status, 1, "Status");
+        assert_eq!(&data[9 * 32..], attestation.memo.as_slice(), "Memo last");
+        assert_eq!(decoded.memo, FixedBytes::repeat_byte(0x4d), "Memo survives the receipt");
+        assert!(decode_intent_attestation(&data[..64]).is_none(), "Truncated");
+    }
+
+    #[test]
+    fn test_schema_uid() {
+        // Matches the SchemaRegistry's keccak256(abi.encodePacked(schema, resolver, revocable))
+        let mut packed = INTENT_ATTESTATION_SCHEMA.as_bytes().to_vec();
+        packed.extend_from_slice(&[0u8; 20]);
+        packed.push(0);
+
+        assert_eq!(schema_uid(Address::ZERO, false), keccak256(&packed), "Irrevocable without resolver");
+        assert_ne!(schema_uid(Address::ZERO, true), schema_uid(Address::ZERO, false), "Revocability counts");
+    }
+}
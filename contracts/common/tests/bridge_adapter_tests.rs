@@ -0,0 +1,425 @@
+use stylus_sdk::alloy_primitives::{keccak256, Address, Bytes, FixedBytes, U256};
+use alloy_sol_types::{SolCall, SolValue};
+use swoosh_common::encoding::*;
+
+#[cfg(test)]
+mod bridge_adapter_tests {
+    use super::*;
+
+    // Helper function to create test addresses
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    #[test]
+    fn test_lz_receive_options_gas_only() {
+        // Type-3 header, executor worker, length 17, lzReceive option, 16-byte gas
+        let options = lz_receive_options(200_000, 0);
+
+        assert_eq!(options.len(), 22, "Header plus gas-only option");
+        assert_eq!(&options[0..2], &[0x00, 0x03], "Options type 3");
+        assert_eq!(options[2], LZ_EXECUTOR_WORKER_ID, "Executor worker");
+        assert_eq!(&options[3..5], &[0x00, 17], "Option length includes type byte");
+        assert_eq!(options[5], LZ_OPTION_TYPE_LZRECEIVE, "lzReceive option");
+        assert_eq!(&options[6..22], &200_000u128.to_be_bytes(), "Gas limit");
+    }
+
+    #[test]
+    fn test_lz_receive_options_with_value() {
+        // Native value is appended only when non-zero
+        let options = lz_receive_options(150_000, 1_000);
+
+        assert_eq!(options.len(), 38, "Header plus gas and value");
+        assert_eq!(&options[3..5], &[0x00, 33], "Option length with value");
+        assert_eq!(&options[22..38], &1_000u128.to_be_bytes(), "Native value");
+    }
+
+    #[test]
+    fn test_lz_compose_options_layout() {
+        // lzCompose option carries the compose index before the gas limit
+        let options = lz_compose_options(0, 250_000, 0);
+
+        assert_eq!(options.len(), 24, "Header plus index and gas");
+        assert_eq!(&options[3..5], &[0x00, 19], "Option length includes type byte");
+        assert_eq!(options[5], LZ_OPTION_TYPE_LZCOMPOSE, "lzCompose option");
+        assert_eq!(&options[6..8], &[0x00, 0x00], "Compose index");
+        assert_eq!(&options[8..24], &250_000u128.to_be_bytes(), "Gas limit");
+        assert_eq!(lz_compose_options(0, 250_000, 5).len(), 40, "Value appended");
+    }
+
+    #[test]
+    fn test_lz_native_drop_appended() {
+        // Native drop follows lzReceive as a second executor option
+        let mut options = lz_receive_options(200_000, 0);
+        append_lz_native_drop(&mut options, 5_000, test_address(8));
+
+        assert_eq!(options.len(), 22 + 52, "lzReceive plus native drop");
+        assert_eq!(options[22], LZ_EXECUTOR_WORKER_ID, "Executor worker");
+        assert_eq!(&options[23..25], &[0x00, 49], "Option length includes type byte");
+        assert_eq!(options[25], LZ_OPTION_TYPE_NATIVE_DROP, "Native drop option");
+        assert_eq!(&options[26..42], &5_000u128.to_be_bytes(), "Drop amount");
+        assert_eq!(&options[42..74], address_to_bytes32(test_address(8)).as_slice(), "Drop receiver");
+    }
+
+    #[test]
+    fn test_peer_bytes32_round_trip() {
+        // Peers are stored as left-padded bytes32
+        let adapter = test_address(7);
+        let peer = address_to_bytes32(adapter);
+
+        assert_eq!(&peer[..12], &[0u8; 12], "Left padding");
+        assert_eq!(bytes32_to_address(peer), adapter, "Round trip");
+        assert_eq!(address_to_bytes32(Address::ZERO), FixedBytes::<32>::ZERO, "Zero peer");
+    }
+
+    #[test]
+    fn test_bridge_payload_round_trip() {
+        // Payload decodes back to the encoded fields
+        let encoded = encode_bridge_payload(U256::from(42), test_address(1), U256::from(1000), test_address(2), false);
+        let payload = decode_bridge_payload(&encoded).expect("Payload decodes");
+
+        assert_eq!(payload.intentId, U256::from(42), "Intent ID");
+        assert_eq!(payload.token, test_address(1), "Token");
+        assert_eq!(payload.amount, U256::from(1000), "Amount");
+        assert_eq!(payload.recipient, test_address(2), "Recipient");
+        assert!(!payload.unwrapNative, "Token delivery");
+    }
+
+    #[test]
+    fn test_bridge_payload_native_delivery() {
+        // Native delivery flag survives the round trip
+        let encoded = encode_bridge_payload(U256::from(7), test_address(1), U256::from(5), test_address(2), true);
+        let payload = decode_bridge_payload(&encoded).expect("Payload decodes");
+
+        assert!(payload.unwrapNative, "Native delivery requested");
+    }
+
+    #[test]
+    fn test_bridge_payload_malformed() {
+        // Truncated payloads are rejected
+        let encoded = encode_bridge_payload(U256::from(1), test_address(1), U256::from(1), test_address(2), false);
+
+        assert!(decode_bridge_payload(&encoded[..64]).is_none(), "Truncated payload rejected");
+        assert!(decode_bridge_payload(&[]).is_none(), "Empty payload rejected");
+    }
+
+    #[test]
+    fn test_receiver_payload_round_trip() {
+        // Swap parameters and data survive the round trip; bridge payloads do not decode
+        let encoded = ReceiverPayload {
+            intentId: U256::from(9),
+            token: test_address(1),
+            amount: U256::from(500),
+            recipient: test_address(2),
+            swapAdapter: test_address(3),
+            tokenOut: test_address(4),
+            minAmountOut: U256::from(450),
+            swapData: vec![0xab, 0xcd].into(),
+        }
+        .abi_encode();
+        let payload = decode_receiver_payload(&encoded).expect("Payload decodes");
+
+        assert_eq!(payload.swapAdapter, test_address(3), "Swap adapter");
+        assert_eq!(payload.tokenOut, test_address(4), "Output token");
+        assert_eq!(payload.minAmountOut, U256::from(450), "Minimum output");
+        assert_eq!(payload.swapData.as_ref(), &[0xab, 0xcd], "Swap data");
+
+        let bridged = encode_bridge_payload(U256::from(1), test_address(1), U256::from(1), test_address(2), false);
+        assert!(decode_receiver_payload(&bridged).is_none(), "Bridge payload rejected");
+    }
+
+    fn receiver_payload(intent_id: u64) -> ReceiverPayload {
+        ReceiverPayload {
+            intentId: U256::from(intent_id),
+            token: test_address(1),
+            amount: U256::from(500),
+            recipient: test_address(2),
+            swapAdapter: Address::ZERO,
+            tokenOut: Address::ZERO,
+            minAmountOut: U256::ZERO,
+            swapData: vec![].into(),
+        }
+    }
+
+    #[test]
+    fn test_settlement_batch_round_trip() {
+        // Entries come back in order; the tag keeps batches apart from single payloads
+        let message = encode_settlement_batch(vec![receiver_payload(1), receiver_payload(2)]);
+        assert!(is_settlement_batch(&message), "Tagged");
+
+        let entries = decode_settlement_batch(&message).expect("Batch decodes");
+        assert_eq!(entries.len(), 2, "Entry count");
+        assert_eq!(entries[1].intentId, U256::from(2), "Entry order");
+
+        let single = receiver_payload(1).abi_encode();
+        assert!(!is_settlement_batch(&single), "Single payload untagged");
+        assert!(decode_settlement_batch(&single).is_none(), "Single payload rejected");
+        assert!(decode_receiver_payload(&message).is_none(), "Batch is not a single payload");
+    }
+
+    #[test]
+    fn test_settlement_batch_size_bounds() {
+        // Empty and oversized batches are rejected
+        let full = (0..MAX_SETTLEMENT_BATCH as u64).map(receiver_payload).collect();
+        let oversized = (0..=MAX_SETTLEMENT_BATCH as u64).map(receiver_payload).collect();
+
+        assert!(decode_settlement_batch(&encode_settlement_batch(vec![])).is_none(), "Empty batch");
+        assert!(decode_settlement_batch(&encode_settlement_batch(full)).is_some(), "Full batch");
+        assert!(decode_settlement_batch(&encode_settlement_batch(oversized)).is_none(), "Oversized batch");
+    }
+
+    #[test]
+    fn test_settlement_batch_entry_ids() {
+        // Each entry gets its own message ID derived from the batch
+        let batch_id = FixedBytes::from([5u8; 32]);
+
+        assert_eq!(
+            settlement_batch_entry_id(batch_id, 3),
+            keccak256((batch_id, U256::from(3)).abi_encode()),
+            "abi.encode(batchId, index)"
+        );
+        assert_ne!(settlement_batch_entry_id(batch_id, 0), settlement_batch_entry_id(batch_id, 1), "Per entry");
+        assert_eq!(BatchEntryFailure::from_u8(3), Some(BatchEntryFailure::AlreadySettled), "Failure code");
+        assert_eq!(BatchEntryFailure::from_u8(0), None, "Unknown failure");
+    }
+
+    #[test]
+    fn test_delivery_status_codes() {
+        // Delivery outcomes reuse SettlementVerifier's status codes; others are rejected
+        assert_eq!(DeliveryStatus::from_u8(1), Some(DeliveryStatus::Delivered), "Confirmed");
+        assert_eq!(DeliveryStatus::from_u8(4), Some(DeliveryStatus::PartialDelivery), "Partial");
+        assert_eq!(DeliveryStatus::from_u8(5), Some(DeliveryStatus::DeliveredToClaim), "Claimable");
+        assert_eq!(DeliveryStatus::from_u8(0), None, "Pending is not an outcome");
+        assert_eq!(DeliveryStatus::from_u8(2), None, "Failed is not an outcome");
+    }
+
+    #[test]
+    fn test_config_sync_round_trip() {
+        // Config-sync messages decode back to what the hub sent
+        let message = encode_config_sync(
+            U256::from(421_614u64),
+            U256::from(3u64),
+            ConfigAction::AddToken,
+            U256::ZERO,
+            test_address(4),
+        );
+        assert!(is_config_sync(&message), "Tagged");
+
+        let decoded = decode_config_sync(&message).expect("decodes");
+        assert_eq!(decoded.sourceChain, U256::from(421_614u64), "Source chain");
+        assert_eq!(decoded.nonce, U256::from(3u64), "Nonce");
+        assert_eq!(ConfigAction::from_u8(decoded.action), Some(ConfigAction::AddToken), "Action");
+        assert_eq!(decoded.token, test_address(4), "Token");
+        assert_eq!(ConfigAction::from_u8(0), None, "Unknown action");
+    }
+
+    #[test]
+    fn test_config_sync_distinct_from_bridge_payload() {
+        // Bridge payloads are never mistaken for config sync, nor the reverse
+        let payload =
+            encode_bridge_payload(U256::from(7u64), test_address(1), U256::from(1u64), test_address(2), false);
+        assert!(!is_config_sync(&payload), "Bridge payload untagged");
+        assert!(decode_config_sync(&payload).is_none(), "Bridge payload rejected");
+
+        let message = encode_config_sync(
+            U256::from(1u64),
+            U256::from(1u64),
+            ConfigAction::AddChain,
+            U256::from(10u64),
+            Address::ZERO,
+        );
+        assert!(decode_config_sync(&message[4..]).is_none(), "Untagged message rejected");
+    }
+
+    #[test]
+    fn test_config_key_packing() {
+        // Kinds and keys survive packing, and kinds keep equal keys apart
+        let token = U256::from_be_slice(test_address(7).as_slice());
+        let packed = pack_config_key(ConfigEntryKind::SwapAdapter, token);
+
+        assert_eq!(unpack_config_key(packed), (Some(ConfigEntryKind::SwapAdapter), token), "Round trip");
+        assert_ne!(
+            pack_config_key(ConfigEntryKind::SupportedChain, U256::from(10u64)),
+            pack_config_key(ConfigEntryKind::BridgeAdapter, U256::from(10u64)),
+            "Kind is part of the key"
+        );
+        assert_eq!(unpack_config_key(U256::from(10u64)).0, None, "Unpacked key has no kind");
+
+        let (kind, key) = unpack_config_key(pack_config_address(ConfigEntryKind::SupportedToken, test_address(7)));
+        assert_eq!(kind, Some(ConfigEntryKind::SupportedToken), "Address kind");
+        assert_eq!(config_key_address(key), test_address(7), "Address round trip");
+    }
+
+    #[test]
+    fn test_config_page_round_trip() {
+        // Export pages decode back to the same entries
+        let entries = vec![
+            ConfigEntry {
+                kind: ConfigEntryKind::BridgeAdapter as u8,
+                key: U256::from(8453u64),
+                account: test_address(3),
+                value: U256::ZERO,
+            },
+            ConfigEntry {
+                kind: ConfigEntryKind::TokenFee as u8,
+                key: U256::from(1u64),
+                account: test_address(4),
+                value: U256::from(25u64),
+            },
+        ];
+
+        let decoded = decode_config_page(&encode_config_page(&entries)).expect("decodes");
+        assert_eq!(decoded.len(), 2, "Entry count");
+        assert_eq!(decoded[0].key, U256::from(8453u64), "Chain key");
+        assert_eq!(decoded[1].value, U256::from(25u64), "Fee value");
+        assert!(decode_config_page(&[1, 2, 3]).is_none(), "Malformed page rejected");
+    }
+
+    #[test]
+    fn test_nft_bridge_payload_round_trip() {
+        // NFT payloads carry the asset type and token ID
+        let encoded = encode_nft_bridge_payload(
+            U256::from(3),
+            AssetType::Erc1155,
+            test_address(1),
+            U256::from(77),
+            U256::from(4),
+            test_address(2),
+        );
+        let payload = decode_nft_bridge_payload(&encoded).expect("Payload decodes");
+
+        assert_eq!(payload.intentId, U256::from(3), "Intent ID");
+        assert_eq!(payload.assetType, AssetType::Erc1155 as u8, "Asset type");
+        assert_eq!(payload.tokenId, U256::from(77), "Token ID");
+        assert_eq!(payload.amount, U256::from(4), "Amount");
+        assert_eq!(payload.recipient, test_address(2), "Recipient");
+    }
+
+    #[test]
+    fn test_nft_bridge_payload_distinct_from_fungible() {
+        // Fungible payloads and fungible asset types never decode as NFTs
+        let fungible = encode_bridge_payload(U256::from(1), test_address(1), U256::from(1), test_address(2), false);
+        let erc20 = encode_nft_bridge_payload(
+            U256::from(1),
+            AssetType::Erc20,
+            test_address(1),
+            U256::ZERO,
+            U256::from(1),
+            test_address(2),
+        );
+
+        assert!(decode_nft_bridge_payload(&fungible).is_none(), "Fungible payload rejected");
+        assert!(decode_nft_bridge_payload(&erc20).is_none(), "ERC20 asset type rejected");
+        assert_eq!(AssetType::from_u8(3), None, "Unknown asset type");
+        assert!(!AssetType::Erc20.is_nft() && AssetType::Erc721.is_nft(), "NFT discriminator");
+    }
+
+    #[test]
+    fn test_hyperlane_hook_metadata_layout() {
+        // Variant 1, msgValue, gasLimit, refund address
+        let refund = test_address(9);
+        let metadata = hyperlane_hook_metadata(U256::ZERO, U256::from(300_000), refund);
+
+        assert_eq!(metadata.len(), 86, "Standard hook metadata size");
+        assert_eq!(&metadata[0..2], &[0x00, 0x01], "Variant 1");
+        assert_eq!(&metadata[2..34], &[0u8; 32], "No msg value");
+        assert_eq!(U256::from_be_slice(&metadata[34..66]), U256::from(300_000), "Gas limit");
+        assert_eq!(&metadata[66..86], refund.as_slice(), "Refund address");
+    }
+
+    #[test]
+    fn test_ccip_extra_args_layout() {
+        // EVMExtraArgsV1 tag followed by the abi-encoded gas limit
+        let args = ccip_extra_args(U256::from(200_000));
+
+        assert_eq!(args.len(), 36, "Tag plus one word");
+        assert_eq!(
+            &args[0..4],
+            &keccak256("CCIP EVMExtraArgsV1")[0..4],
+            "EVMExtraArgsV1 tag"
+        );
+        assert_eq!(&args[4..], U256::from(200_000).abi_encode().as_slice(), "Gas limit");
+    }
+
+    // VAA with `signatures` dummy signatures, emitter chain 2 and the given payload
+    fn test_vaa(signatures: u8, payload: &[u8]) -> Vec<u8> {
+        let mut vaa = vec![1u8, 0, 0, 0, 4, signatures];
+        vaa.extend(std::iter::repeat_n(0xAA, 66 * signatures as usize));
+        vaa.extend_from_slice(&[0u8; 8]);
+        vaa.extend_from_slice(&2u16.to_be_bytes());
+        vaa.extend_from_slice(&[7u8; 32]);
+        vaa.extend_from_slice(&42u64.to_be_bytes());
+        vaa.push(1);
+        vaa.extend_from_slice(payload);
+        vaa
+    }
+
+    #[test]
+    fn test_parse_vaa_body_skips_signatures() {
+        // Emitter and payload are found after every guardian signature
+        let vaa = test_vaa(13, b"hello");
+        let body = parse_vaa_body(&vaa).expect("VAA parses");
+
+        assert_eq!(body.emitter_chain, 2, "Emitter chain");
+        assert_eq!(body.emitter_address, FixedBytes::<32>::from([7u8; 32]), "Emitter address");
+        assert_eq!(body.sequence, 42, "Sequence");
+        assert_eq!(body.payload, b"hello", "Payload");
+    }
+
+    #[test]
+    fn test_parse_vaa_body_truncated() {
+        // A VAA cut inside the signatures or body prefix is rejected
+        let vaa = test_vaa(2, &[]);
+
+        assert!(parse_vaa_body(&vaa[..100]).is_none(), "Cut in signatures");
+        assert!(parse_vaa_body(&vaa[..vaa.len() - 1]).is_none(), "Cut in body");
+        assert!(parse_vaa_body(&[]).is_none(), "Empty");
+    }
+
+    #[test]
+    fn test_parse_wormhole_transfer() {
+        // fromAddress sits after amount, token, chains and recipient
+        let mut transfer = vec![WORMHOLE_TRANSFER_WITH_PAYLOAD];
+        transfer.extend_from_slice(&[0u8; 100]);
+        transfer.extend_from_slice(&[9u8; 32]);
+        transfer.extend_from_slice(b"inner");
+
+        let (from, payload) = parse_wormhole_transfer(&transfer).expect("Transfer parses");
+        assert_eq!(from, FixedBytes::<32>::from([9u8; 32]), "Sender");
+        assert_eq!(payload, b"inner", "Inner payload");
+
+        transfer[0] = 1;
+        assert!(parse_wormhole_transfer(&transfer).is_none(), "Plain transfer rejected");
+    }
+
+    #[test]
+    fn test_parse_oft_compose_msg() {
+        // Header fields precede the app payload sent as composeMsg
+        let mut data = 5u64.to_be_bytes().to_vec();
+        data.extend_from_slice(&30_110u32.to_be_bytes());
+        data.extend_from_slice(&U256::from(999_000u64).to_be_bytes::<32>());
+        data.extend_from_slice(&[4u8; 32]);
+        data.extend_from_slice(b"payload");
+
+        let compose = parse_oft_compose_msg(&data).expect("Compose message parses");
+        assert_eq!(compose.nonce, 5, "Nonce");
+        assert_eq!(compose.src_eid, 30_110, "Source endpoint");
+        assert_eq!(compose.amount, U256::from(999_000u64), "Credited amount");
+        assert_eq!(compose.compose_from, FixedBytes::<32>::from([4u8; 32]), "Compose sender");
+        assert_eq!(compose.message, b"payload", "App payload");
+
+        assert!(parse_oft_compose_msg(&data[..75]).is_none(), "Truncated header");
+    }
+
+    #[test]
+    fn test_settlement_callback_encoding() {
+        // Selector of onIntentSettled(uint256,uint8,bytes), then the ABI-encoded arguments
+        let calldata = encode_settlement_callback(U256::from(7u64), 2, Bytes::from_static(b"hello"));
+
+        assert_eq!(&calldata[..4], &keccak256(b"onIntentSettled(uint256,uint8,bytes)")[..4], "Selector");
+        let call = onIntentSettledCall::abi_decode(&calldata).expect("Calldata decodes");
+        assert_eq!(call.intentId, U256::from(7u64), "Intent ID");
+        assert_eq!(call.status, 2, "Status");
+        assert_eq!(call.data.as_ref(), b"hello", "Callback data");
+    }
+}
@@ -0,0 +1,60 @@
+use stylus_sdk::alloy_primitives::{Address, Bytes, FixedBytes, U256};
+use swoosh_common::chain_address::*;
+
+#[cfg(test)]
+mod chain_address_tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_by_family() {
+        // Each family takes its own address lengths, never all zeros
+        assert_eq!(validate_chain_address(0, &[1u8; 20]), Ok(ChainFamily::Evm), "EVM");
+        assert_eq!(validate_chain_address(1, &[1u8; 32]), Ok(ChainFamily::Solana), "Solana");
+        assert_eq!(validate_chain_address(2, &[1u8; 20]), Ok(ChainFamily::Cosmos), "Cosmos account");
+        assert_eq!(validate_chain_address(2, &[1u8; 32]), Ok(ChainFamily::Cosmos), "Cosmos module account");
+
+        assert_eq!(validate_chain_address(1, &[1u8; 20]), Err(ChainAddressError::BadLength), "Short Solana key");
+        assert_eq!(validate_chain_address(0, &[1u8; 32]), Err(ChainAddressError::BadLength), "Long EVM address");
+        assert_eq!(validate_chain_address(1, &[0u8; 32]), Err(ChainAddressError::ZeroAddress), "Zero key");
+        assert_eq!(validate_chain_address(3, &[1u8; 32]), Err(ChainAddressError::UnknownFamily), "Unknown family");
+    }
+
+    #[test]
+    fn test_to_bytes32() {
+        // Shorter addresses are left-padded; longer ones don't fit
+        let evm = to_bytes32(Address::from([4u8; 20]).as_slice()).expect("Fits");
+        assert_eq!(&evm[..12], &[0u8; 12], "Padding");
+        assert_eq!(&evm[12..], &[4u8; 20], "Address bytes");
+
+        assert_eq!(to_bytes32(&[5u8; 32]), Some(FixedBytes::from([5u8; 32])), "Full word kept");
+        assert!(to_bytes32(&[5u8; 33]).is_none(), "Too long");
+    }
+
+    #[test]
+    fn test_chain_address_payload_round_trip() {
+        // The payload carries the raw recipient and rejects invalid ones
+        let recipient = Bytes::from(vec![9u8; 32]);
+        let data = encode_chain_address_payload(
+            U256::from(3u64),
+            Address::from([1u8; 20]),
+            U256::from(50u64),
+            ChainFamily::Solana,
+            recipient.clone(),
+        );
+
+        let payload = decode_chain_address_payload(&data).expect("Payload decodes");
+        assert_eq!(payload.intentId, U256::from(3u64), "Intent ID");
+        assert_eq!(payload.amount, U256::from(50u64), "Amount");
+        assert_eq!(payload.recipientFamily, ChainFamily::Solana as u8, "Family");
+        assert_eq!(payload.recipient, recipient, "Recipient");
+
+        let short = encode_chain_address_payload(
+            U256::from(3u64),
+            Address::from([1u8; 20]),
+            U256::from(50u64),
+            ChainFamily::Solana,
+            Bytes::from(vec![9u8; 20]),
+        );
+        assert!(decode_chain_address_payload(&short).is_none(), "Invalid recipient rejected");
+    }
+}
@@ -0,0 +1,65 @@
+use stylus_sdk::alloy_primitives::keccak256;
+use swoosh_common::erc165::*;
+
+#[cfg(test)]
+mod erc165_tests {
+    use super::*;
+
+    fn selector(signature: &str) -> [u8; 4] {
+        keccak256(signature.as_bytes())[..4].try_into().unwrap()
+    }
+
+    #[test]
+    fn test_ids_match_signatures() {
+        // Ids are the XOR of the canonical selectors, never hand-written
+        let bridge = interface_id(&[
+            selector("quoteFee(uint256,address,uint256,address)"),
+            selector("bridge(uint256,uint256,address,uint256,address,bool)"),
+            selector("supportsLane(uint256)"),
+        ]);
+
+        assert_eq!(ERC165_ID, [0x01, 0xff, 0xc9, 0xa7], "ERC-165 itself");
+        assert_eq!(BRIDGE_ADAPTER_ID, bridge, "Bridge adapter");
+        assert_eq!(SWAP_ADAPTER_ID, selector("swap(address,address,uint256,uint256,address,bytes)"), "Swap");
+        assert_eq!(INTENT_CALLBACK_ID, selector("onIntentSettled(uint256,uint8,bytes)"), "Callback");
+        assert_eq!(CONFIG_SYNC_TARGET_ID, selector("applyConfigSync(uint256,bytes)"), "Config target");
+    }
+
+    #[test]
+    fn test_ids_are_distinct() {
+        // No extension can be mistaken for another
+        let ids = [
+            ERC165_ID,
+            BRIDGE_ADAPTER_ID,
+            GAS_DROP_ADAPTER_ID,
+            GAS_LIMIT_ADAPTER_ID,
+            NFT_BRIDGE_ADAPTER_ID,
+            CHAIN_ADDRESS_ADAPTER_ID,
+            CONFIG_SYNC_ADAPTER_ID,
+            CONFIG_SYNC_TARGET_ID,
+            SWAP_ADAPTER_ID,
+            SWAP_QUOTER_ID,
+            INTENT_CALLBACK_ID,
+        ];
+
+        for (i, id) in ids.iter().enumerate() {
+            assert!(!ids[i + 1..].contains(id), "Duplicate id {id:?}");
+            assert_ne!(*id, INVALID_ID, "Invalid id claimed");
+        }
+    }
+
+    #[test]
+    fn test_supports_and_detect() {
+        // ERC-165 is always claimed, the invalid id never, the rest as listed
+        let implemented = [SWAP_ADAPTER_ID];
+        let answer = |id: [u8; 4]| supports(id, &implemented);
+        let everything = |_: [u8; 4]| true;
+
+        assert!(supports(ERC165_ID, &[]), "ERC-165");
+        assert!(!supports(INVALID_ID, &[INVALID_ID]), "Invalid id");
+        assert!(detect(SWAP_ADAPTER_ID, answer), "Implemented");
+        assert!(!detect(BRIDGE_ADAPTER_ID, answer), "Not implemented");
+        assert!(!detect(SWAP_ADAPTER_ID, everything), "Answers true to everything");
+        assert!(!detect(SWAP_ADAPTER_ID, |_| false), "No ERC-165");
+    }
+}
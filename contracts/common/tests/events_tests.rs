@@ -0,0 +1,61 @@
+use alloy_sol_types::SolEvent;
+use stylus_sdk::alloy_primitives::{keccak256, FixedBytes};
+use swoosh_common::events::*;
+
+#[cfg(test)]
+mod events_tests {
+    use super::*;
+
+    #[test]
+    fn test_lifecycle_signatures_stable() {
+        // Indexers filter on these topics; changing a field breaks them
+        let expected = [
+            "IntentExecuted(uint256,address,uint256,address,uint256)",
+            "IntentExecutedBySolver(uint256,uint256,address,bytes32)",
+            "IntentMemo(uint256,bytes32)",
+            "IntentFailed(uint256,string)",
+            "SwapExecuted(uint256,address,address,uint256,uint256)",
+            "RouteStepExecuted(uint256,uint256,uint8,address,address,address,uint256,uint256,bool)",
+            "BridgeInitiated(uint256,address,uint256,uint256,address)",
+            "NftBridgeInitiated(uint256,uint8,address,uint256,uint256,uint256,address)",
+            "DeliveryCompleted(uint256,address,address,uint256,bytes32,uint8)",
+            "SwapFallback(uint256,address,address)",
+            "ClaimCredited(uint256,address,address,uint256,uint256)",
+            "DeliveryStatusReported(uint256,bytes32,uint8)",
+            "SettlementConfirmed(uint256,bytes32,uint256)",
+            "SettlementFailed(uint256,bytes32,string)",
+            "SettlementRetried(uint256,uint256,uint256)",
+            "RefundInitiated(uint256,address,address,uint256)",
+        ];
+
+        assert_eq!(LIFECYCLE_TOPICS.len(), expected.len(), "Registry size");
+        for ((signature, topic), expected) in LIFECYCLE_TOPICS.iter().zip(expected) {
+            assert_eq!(*signature, expected, "Signature");
+            assert_eq!(*topic, keccak256(expected.as_bytes()), "Topic of {expected}");
+        }
+        assert_eq!(
+            IntentExecuted::SIGNATURE_HASH,
+            "0xb11599bf7658a908f364f9a3438b632cbb47148c03c966c4d69c27bb6da1d608".parse::<FixedBytes<32>>().unwrap(),
+            "Pinned topic"
+        );
+    }
+
+    #[test]
+    fn test_topics_unique() {
+        // Each topic0 identifies exactly one lifecycle event
+        for (i, (_, topic)) in LIFECYCLE_TOPICS.iter().enumerate() {
+            assert!(!LIFECYCLE_TOPICS[i + 1..].iter().any(|(_, other)| other == topic), "Duplicate topic {topic}");
+        }
+    }
+
+    #[test]
+    fn test_lifecycle_event_lookup() {
+        // topic0 resolves back to its event; anything else is not a lifecycle event
+        assert_eq!(
+            lifecycle_event(SettlementConfirmed::SIGNATURE_HASH),
+            Some("SettlementConfirmed(uint256,bytes32,uint256)"),
+            "Known topic"
+        );
+        assert_eq!(lifecycle_event(FixedBytes::ZERO), None, "Unknown topic");
+    }
+}
@@ -0,0 +1,148 @@
+use stylus_sdk::alloy_primitives::U256;
+use swoosh_common::fees::*;
+
+#[cfg(test)]
+mod fee_tests {
+    use super::*;
+
+    #[test]
+    fn test_fee_rounds_down() {
+        // 0.3% of 999 is 2.997, charged as 2
+        assert_eq!(fee_for(U256::from(999u64), U256::from(30u64)), U256::from(2u64), "Rounded down");
+        assert_eq!(fee_for(U256::from(1_000_000u64), U256::from(30u64)), U256::from(3_000u64), "Exact fee");
+    }
+
+    #[test]
+    fn test_take_fee_splits_amount() {
+        // Net and fee always add back up to the input
+        let amount = U256::from(123_456_789u64);
+        let (net, fee) = take_fee(amount, U256::from(25u64));
+
+        assert_eq!(net + fee, amount, "No value lost");
+        assert_eq!(take_fee(amount, U256::ZERO), (amount, U256::ZERO), "Zero fee");
+    }
+
+    #[test]
+    fn test_fee_on_max_amount() {
+        // Huge amounts don't overflow
+        let fee = fee_for(U256::MAX, U256::from(MAX_PROTOCOL_FEE_BPS));
+
+        assert!(fee > U256::ZERO, "Fee computed");
+        assert!(fee < U256::MAX / U256::from(99u64), "About 1% of max");
+    }
+
+    #[test]
+    fn test_protocol_fee_cap() {
+        // Admin cannot configure more than the cap
+        assert!(is_valid_protocol_fee(U256::from(MAX_PROTOCOL_FEE_BPS)), "Cap allowed");
+        assert!(!is_valid_protocol_fee(U256::from(MAX_PROTOCOL_FEE_BPS + 1)), "Above cap rejected");
+    }
+
+    #[test]
+    fn test_integrator_fee_split() {
+        // Protocol takes its share, integrator keeps the rest
+        let (integrator, protocol) = split_integrator_fee(U256::from(1_000u64), U256::from(2_000u64));
+
+        assert_eq!(protocol, U256::from(200u64), "20% protocol share");
+        assert_eq!(integrator, U256::from(800u64), "Integrator remainder");
+    }
+
+    #[test]
+    fn test_integrator_fee_split_dust() {
+        // Rounding dust stays with the integrator
+        let fee = U256::from(7u64);
+        let (integrator, protocol) = split_integrator_fee(fee, U256::from(1_500u64));
+
+        assert_eq!(protocol, U256::from(1u64), "Protocol rounded down");
+        assert_eq!(integrator + protocol, fee, "No value lost");
+        assert_eq!(split_integrator_fee(fee, U256::ZERO), (fee, U256::ZERO), "No protocol share");
+    }
+
+    #[test]
+    fn test_tier_schedule_validation() {
+        // Thresholds rise, fees fall, and both lists line up
+        let thresholds = [U256::from(1_000u64), U256::from(10_000u64)];
+        let fee_bps = [U256::from(20u64), U256::from(10u64)];
+
+        assert!(is_valid_tier_schedule(&thresholds, &fee_bps), "Valid schedule");
+        assert!(is_valid_tier_schedule(&[], &[]), "Empty schedule clears tiers");
+        assert!(!is_valid_tier_schedule(&thresholds, &fee_bps[..1]), "Length mismatch");
+        assert!(!is_valid_tier_schedule(&[thresholds[1], thresholds[0]], &fee_bps), "Falling threshold");
+        assert!(!is_valid_tier_schedule(&thresholds, &[fee_bps[1], fee_bps[0]]), "Rising fee");
+        assert!(!is_valid_tier_schedule(&[U256::ZERO], &[fee_bps[0]]), "Zero threshold");
+        assert!(
+            !is_valid_tier_schedule(&thresholds[..1], &[U256::from(MAX_PROTOCOL_FEE_BPS + 1)]),
+            "Fee above cap"
+        );
+    }
+
+    #[test]
+    fn test_volume_tier_progress() {
+        // Tier counts thresholds met; distance is to the next one
+        let thresholds = [U256::from(1_000u64), U256::from(10_000u64)];
+
+        assert_eq!(volume_tier(U256::from(999u64), &thresholds), 0, "Base tier");
+        assert_eq!(volume_tier(U256::from(1_000u64), &thresholds), 1, "Threshold met exactly");
+        assert_eq!(volume_tier(U256::from(50_000u64), &thresholds), 2, "Top tier");
+        assert_eq!(volume_to_next_tier(U256::from(400u64), &thresholds), U256::from(600u64), "To first tier");
+        assert_eq!(volume_to_next_tier(U256::from(1_000u64), &thresholds), U256::from(9_000u64), "To second tier");
+        assert_eq!(volume_to_next_tier(U256::from(50_000u64), &thresholds), U256::ZERO, "Nothing above top");
+    }
+
+    #[test]
+    fn test_utilization_fee_curve() {
+        // 5 bps base plus 45 bps at full utilization
+        let base = U256::from(5u64);
+        let slope = U256::from(45u64);
+
+        assert_eq!(utilization_fee_bps(base, slope, U256::ZERO), base, "Idle pool");
+        assert_eq!(utilization_fee_bps(base, slope, U256::from(5_000u64)), U256::from(27u64), "Half used, rounded down");
+        assert_eq!(utilization_fee_bps(base, slope, U256::from(BPS_DENOMINATOR)), U256::from(50u64), "Fully used");
+    }
+
+    #[test]
+    fn test_utilization_of_empty_pool() {
+        // No liquidity means nothing is lent out
+        assert_eq!(utilization_bps(U256::ZERO, U256::ZERO), U256::ZERO, "Empty pool");
+        assert_eq!(utilization_bps(U256::from(25u64), U256::from(100u64)), U256::from(2_500u64), "Quarter used");
+    }
+
+    #[test]
+    fn test_cost_adjusted_fee() {
+        // 5 bps schedule, bounded to 2..=30 bps
+        let (base, min, max) = (U256::from(5u64), U256::from(2u64), U256::from(30u64));
+        let amount = U256::from(1_000_000u64);
+
+        assert_eq!(cost_adjusted_fee_bps(base, U256::ZERO, amount, min, max), base, "Free leg keeps the schedule");
+        let covering = cost_adjusted_fee_bps(base, U256::from(1_001u64), amount, min, max);
+        assert_eq!(covering, U256::from(11u64), "Rounded up");
+        assert_eq!(cost_adjusted_fee_bps(base, U256::from(50_000u64), amount, min, max), max, "Capped");
+        assert_eq!(cost_adjusted_fee_bps(U256::ZERO, U256::ZERO, amount, min, max), min, "Floor");
+        assert_eq!(cost_adjusted_fee_bps(base, U256::MAX, amount, min, max), max, "No overflow");
+        assert_eq!(cost_adjusted_fee_bps(base, U256::ZERO, U256::ZERO, min, max), max, "Zero amount");
+    }
+
+    #[test]
+    fn test_cost_fee_bounds() {
+        // Bounds must be ordered and within the protocol cap
+        assert!(is_valid_cost_fee_bounds(U256::from(2u64), U256::from(30u64)), "Ordered");
+        assert!(!is_valid_cost_fee_bounds(U256::from(30u64), U256::from(2u64)), "Inverted");
+        assert!(!is_valid_cost_fee_bounds(U256::ZERO, U256::from(MAX_PROTOCOL_FEE_BPS + 1)), "Above cap");
+    }
+
+    #[test]
+    fn test_fee_ledger_keys() {
+        // Epochs are whole weeks and every entry of every epoch has its own key
+        let week = U256::from(FEE_EPOCH_LENGTH);
+        assert_eq!(fee_epoch(week - U256::from(1u64)), U256::ZERO, "First week");
+        assert_eq!(fee_epoch(week * U256::from(3u64)), U256::from(3u64), "Fourth week");
+
+        let mut keys: Vec<U256> = [U256::ZERO, U256::from(1u64)]
+            .into_iter()
+            .flat_map(|epoch| LEDGER_ENTRIES.map(|entry| ledger_key(epoch, entry)))
+            .collect();
+        keys.sort();
+        keys.dedup();
+        assert_eq!(keys.len(), 2 * LEDGER_ENTRIES.len(), "Distinct keys");
+    }
+}
@@ -0,0 +1,42 @@
+use stylus_sdk::alloy_primitives::U256;
+use swoosh_common::fill_rule::*;
+
+#[cfg(test)]
+mod fill_rule_tests {
+    use super::*;
+
+    #[test]
+    fn test_rule_from_decimals() {
+        // One whole token minimum, in hundredths of a token
+        let usdc = FillRule::from_decimals(6);
+        assert_eq!(usdc.min_fill, U256::from(1_000_000u64), "USDC minimum");
+        assert_eq!(usdc.granularity, U256::from(10_000u64), "USDC step");
+
+        let coarse = FillRule::from_decimals(1);
+        assert_eq!(coarse.granularity, U256::from(1u64), "Fewer decimals than the precision");
+        assert!(FillRule::from_decimals(255).is_valid(), "Absurd decimals saturate");
+    }
+
+    #[test]
+    fn test_allows() {
+        let usdc = FillRule::from_decimals(6);
+        assert!(usdc.allows(U256::from(1_000_000u64)), "Minimum");
+        assert!(usdc.allows(U256::from(2_340_000u64)), "Whole cents");
+        assert!(!usdc.allows(U256::from(999_999u64)), "Below minimum");
+        assert!(!usdc.allows(U256::from(1_000_001u64)), "Off step");
+        assert!(!FillRule::ANY.allows(U256::ZERO), "Zero");
+        assert!(FillRule::ANY.allows(U256::from(1u64)), "Any");
+    }
+
+    #[test]
+    fn test_is_valid() {
+        let rule = |min_fill: u64, granularity: u64| FillRule {
+            min_fill: U256::from(min_fill),
+            granularity: U256::from(granularity),
+        };
+        assert!(rule(500, 100).is_valid(), "Minimum on a step");
+        assert!(rule(0, 100).is_valid(), "No minimum");
+        assert!(!rule(550, 100).is_valid(), "Minimum off step");
+        assert!(!rule(500, 0).is_valid(), "Zero step");
+    }
+}
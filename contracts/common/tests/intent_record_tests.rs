@@ -0,0 +1,79 @@
+use stylus_sdk::alloy_primitives::U256;
+use swoosh_common::intent_record::*;
+
+#[cfg(test)]
+mod intent_record_tests {
+    use super::*;
+
+    fn sample() -> IntentRecord {
+        IntentRecord {
+            status: 2,
+            created_at: 1_700_000_000,
+            updated_at: 1_700_000_042,
+            destination_chain: 8_453,
+            amount_in: U256::from(1_000_000u64),
+            amount_out: U256::from(997_000u64),
+        }
+    }
+
+    #[test]
+    fn test_record_round_trip() {
+        // Encoding then decoding gives back the same record
+        let record = sample();
+
+        assert_eq!(IntentRecord::decode(record.encode()), record, "Round trip");
+        assert_eq!(
+            IntentRecord::decode([U256::ZERO; RECORD_WORDS]),
+            IntentRecord::default(),
+            "Unused record"
+        );
+    }
+
+    #[test]
+    fn test_header_fields_isolated() {
+        // Each field sits in its own bits; maximum values don't spill over
+        let record = IntentRecord {
+            status: u8::MAX,
+            created_at: 0,
+            updated_at: u64::MAX,
+            destination_chain: 0,
+            amount_in: U256::MAX,
+            amount_out: U256::MAX,
+        };
+        let decoded = IntentRecord::decode(record.encode());
+
+        assert_eq!(decoded, record, "Maximum values");
+        assert_eq!(record.header() >> 200, U256::ZERO, "Reserved bits clear");
+        assert_eq!(header_status(sample().header()), 2, "Status byte");
+    }
+
+    #[test]
+    fn test_open_record() {
+        // A new record starts with both timestamps at now and no output
+        let record = IntentRecord::open(1, U256::from(42_161u64), U256::from(500u64), U256::from(1_000u64));
+
+        assert_eq!((record.created_at, record.updated_at), (1_000, 1_000), "Timestamps");
+        assert_eq!(record.destination_chain, 42_161, "Chain");
+        assert_eq!(record.amount_out, U256::ZERO, "No output yet");
+
+        let clamped = IntentRecord::open(1, U256::MAX, U256::ZERO, U256::MAX);
+        assert_eq!(clamped.destination_chain, u64::MAX, "Chain clamped");
+        assert_eq!(clamped.created_at, u64::MAX, "Timestamp clamped");
+    }
+
+    #[test]
+    fn test_update_header_status() {
+        // Only the status and update time change
+        let record = sample();
+        let updated = IntentRecord::decode([
+            update_header_status(record.header(), 3, U256::from(1_800_000_000u64)),
+            record.amount_in,
+            record.amount_out,
+        ]);
+
+        assert_eq!(updated.status, 3, "Status");
+        assert_eq!(updated.updated_at, 1_800_000_000, "Updated at");
+        assert_eq!(updated.created_at, record.created_at, "Created at kept");
+        assert_eq!(updated.destination_chain, record.destination_chain, "Chain kept");
+    }
+}
@@ -0,0 +1,40 @@
+use stylus_sdk::alloy_primitives::U256;
+use swoosh_common::fees::MAX_PROTOCOL_FEE_BPS;
+use swoosh_common::invariants::*;
+
+#[cfg(test)]
+mod invariants_tests {
+    use super::*;
+
+    #[test]
+    fn test_intent_statuses_move_forward() {
+        // Pending -> Executing -> Completed or Failed, never back
+        assert!(is_intent_transition(0, 1), "Opened");
+        assert!(is_intent_transition(1, 2), "Completed");
+        assert!(is_intent_transition(1, 3), "Failed");
+        assert!(!is_intent_transition(2, 1), "Reopened");
+        assert!(!is_intent_transition(0, 2), "Skipped execution");
+    }
+
+    #[test]
+    fn test_settlement_statuses_move_forward() {
+        // Fallback deliveries narrow a confirmation; failures end refunded
+        assert!(is_settlement_transition(0, 1), "Confirmed");
+        assert!(is_settlement_transition(1, 4), "Partial delivery");
+        assert!(is_settlement_transition(2, 3), "Refunded");
+        assert!(!is_settlement_transition(2, 1), "Failed then confirmed");
+        assert!(!is_settlement_transition(3, 0), "Refund undone");
+    }
+
+    #[test]
+    fn test_fee_cap_and_solvency() {
+        // Rounded-down fees at the cap pass; one unit more doesn't
+        let amount = U256::from(1_234_567u64);
+        let at_cap = U256::from(12_345u64);
+
+        assert!(is_fee_within_cap(at_cap, amount, MAX_PROTOCOL_FEE_BPS), "At cap");
+        assert!(!is_fee_within_cap(at_cap + U256::from(1), amount, MAX_PROTOCOL_FEE_BPS), "Over cap");
+        assert!(is_solvent(U256::from(10u64), U256::from(10u64)), "Exactly covered");
+        assert!(!is_solvent(U256::from(9u64), U256::from(10u64)), "Short");
+    }
+}
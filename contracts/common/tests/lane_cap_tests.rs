@@ -0,0 +1,53 @@
+use stylus_sdk::alloy_primitives::U256;
+use swoosh_common::lane_cap::*;
+
+#[cfg(test)]
+mod lane_cap_tests {
+    use super::*;
+
+    // 1,000 growing to 10,000 over 100 seconds from t = 500
+    fn ramped() -> LaneCap {
+        LaneCap {
+            cap: U256::from(10_000u64),
+            initial_cap: U256::from(1_000u64),
+            ramp_start: 500,
+            ramp_duration: 100,
+        }
+    }
+
+    #[test]
+    fn test_linear_ramp() {
+        let cap = ramped();
+        assert_eq!(cap.current(400), U256::from(1_000u64), "Before the ramp");
+        assert_eq!(cap.current(500), U256::from(1_000u64), "Ramp start");
+        assert_eq!(cap.current(550), U256::from(5_500u64), "Halfway");
+        assert_eq!(cap.current(600), U256::from(10_000u64), "Ramp end");
+        assert_eq!(cap.current(u64::MAX), U256::from(10_000u64), "Long after");
+    }
+
+    #[test]
+    fn test_uncapped_and_flat() {
+        let uncapped = LaneCap::default();
+        assert!(!uncapped.is_capped(), "Uncapped");
+        assert_eq!(uncapped.current(0), U256::MAX, "No limit");
+
+        let flat = LaneCap { ramp_duration: 0, ..ramped() };
+        assert_eq!(flat.current(500), U256::from(10_000u64), "No ramp");
+    }
+
+    #[test]
+    fn test_available() {
+        let cap = ramped();
+        assert_eq!(cap.available(U256::from(400u64), 500), U256::from(600u64), "Room left");
+        assert_eq!(cap.available(U256::from(2_000u64), 500), U256::ZERO, "Over a lowered cap");
+    }
+
+    #[test]
+    fn test_validity_and_round_trip() {
+        assert!(ramped().is_valid(), "Ramp up");
+        assert!(!LaneCap { initial_cap: U256::from(20_000u64), ..ramped() }.is_valid(), "Ramp down");
+
+        let cap = LaneCap { ramp_start: u64::MAX, ramp_duration: u64::MAX, ..ramped() };
+        assert_eq!(LaneCap::decode(cap.cap, cap.initial_cap, cap.encode_ramp()), cap, "Round trip");
+    }
+}
@@ -0,0 +1,48 @@
+use stylus_sdk::alloy_primitives::U256;
+use swoosh_common::lane_policy::*;
+
+#[cfg(test)]
+mod lane_policy_tests {
+    use super::*;
+
+    #[test]
+    fn test_unset_lane_is_default() {
+        // A lane nobody configured takes any single report at once
+        let policy = LanePolicy::decode(U256::ZERO);
+
+        assert_eq!(policy, LanePolicy::default(), "Default policy");
+        assert!(policy.is_immediate(), "Settles on first report");
+        assert!(policy.accepts(REPORTER_CCIP) && policy.accepts(REPORTER_ATTESTER), "Any reporter");
+    }
+
+    #[test]
+    fn test_policy_round_trip() {
+        // Fields come back unchanged, including the widest window
+        let policy = LanePolicy {
+            reporters: REPORTER_ADAPTER | REPORTER_ATTESTER,
+            quorum: 2,
+            challenge_window: u64::MAX,
+        };
+        let decoded = LanePolicy::decode(policy.encode());
+
+        assert_eq!(decoded, policy, "Round trip");
+        assert_eq!(policy.encode() >> 80, U256::ZERO, "Reserved bits clear");
+        assert!(decoded.accepts(REPORTER_ATTESTER), "Attester accepted");
+        assert!(!decoded.accepts(REPORTER_CCIP), "CCIP not accepted");
+        assert!(!decoded.is_immediate(), "Needs quorum and window");
+    }
+
+    #[test]
+    fn test_policy_validity() {
+        // A policy must accept some known reporter and need at least one report
+        let valid = LanePolicy {
+            reporters: REPORTER_WORMHOLE,
+            quorum: 1,
+            challenge_window: 600,
+        };
+        assert!(valid.is_valid(), "Valid");
+        assert!(!LanePolicy { reporters: 0, ..valid }.is_valid(), "No reporters");
+        assert!(!LanePolicy { reporters: 1 << 7, ..valid }.is_valid(), "Unknown reporter");
+        assert!(!LanePolicy { quorum: 0, ..valid }.is_valid(), "Zero quorum");
+    }
+}
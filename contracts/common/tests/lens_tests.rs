@@ -0,0 +1,70 @@
+use alloy_sol_types::SolValue;
+use stylus_sdk::alloy_primitives::{Address, FixedBytes, U256};
+use swoosh_common::lens::*;
+
+#[cfg(test)]
+mod lens_tests {
+    use super::*;
+
+    fn ready() -> UserReadiness {
+        UserReadiness {
+            balance: U256::from(1_000u64),
+            allowance: U256::from(500u64),
+            tokenSupported: true,
+            chainSupported: true,
+            laneHalted: false,
+            executorPaused: false,
+            solverOnly: false,
+            bridgeAdapter: Address::from([7u8; 20]),
+            blocker: 0,
+        }
+    }
+
+    #[test]
+    fn test_readiness_blocker_order() {
+        // Protocol-wide blockers come before the user's own funds
+        let amount = U256::from(500u64);
+        assert_eq!(readiness_blocker(&ready(), amount), None, "Ready");
+
+        let mut readiness = ready();
+        readiness.allowance = U256::from(499u64);
+        assert_eq!(readiness_blocker(&readiness, amount), Some(NotReady::InsufficientAllowance), "Allowance");
+        readiness.balance = U256::ZERO;
+        assert_eq!(readiness_blocker(&readiness, amount), Some(NotReady::InsufficientBalance), "Balance first");
+        readiness.laneHalted = true;
+        assert_eq!(readiness_blocker(&readiness, amount), Some(NotReady::LaneHalted), "Lane");
+        readiness.executorPaused = true;
+        assert_eq!(readiness_blocker(&readiness, amount), Some(NotReady::Paused), "Paused first");
+
+        let mut readiness = ready();
+        readiness.bridgeAdapter = Address::ZERO;
+        assert_eq!(readiness_blocker(&readiness, amount), Some(NotReady::NoBridgeAdapter), "No adapter");
+    }
+
+    #[test]
+    fn test_views_round_trip() {
+        // Each view decodes from the bytes the lens returns
+        let view = IntentView {
+            intentId: U256::from(42u64),
+            intentHash: FixedBytes::repeat_byte(0x42),
+            status: 2,
+            destinationChain: U256::from(8_453u64),
+            amountIn: U256::from(1_000_000u64),
+            amountOut: U256::from(997_000u64),
+            updatedAt: 1_700_000_042,
+            settlementStatus: 0,
+            timedOut: false,
+            finalAt: U256::from(1_700_003_600u64),
+            memo: FixedBytes::repeat_byte(0x0d),
+        };
+        let decoded = decode_intent_views(&encode_intent_views(&[view.clone(), view.clone()])).unwrap();
+
+        assert_eq!(decoded.len(), 2, "Page length");
+        assert_eq!(decoded[1].finalAt, view.finalAt, "Settlement state");
+        assert_eq!(decoded[1].memo, view.memo, "Memo");
+        assert_eq!(decode_intent_views(&encode_intent_views(&[])).map(|views| views.len()), Some(0), "Empty page");
+        let readiness = decode_user_readiness(&ready().abi_encode()).unwrap();
+        assert_eq!(readiness.allowance, U256::from(500u64), "Readiness");
+        assert!(decode_fee_quote(&[1u8; 7]).is_none(), "Malformed quote");
+    }
+}
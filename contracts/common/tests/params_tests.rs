@@ -0,0 +1,51 @@
+use stylus_sdk::alloy_primitives::{Address, U256};
+use swoosh_common::fees::MAX_PROTOCOL_FEE_BPS;
+use swoosh_common::params::*;
+
+#[cfg(test)]
+mod params_tests {
+    use super::*;
+
+    #[test]
+    fn test_ids_round_trip() {
+        // Every parameter has a unique id that decodes back to it
+        for param in ALL_PARAMS {
+            assert_eq!(Param::from_id(param.id()), Some(param), "Round trip");
+            assert!(param.min() <= param.max(), "Ordered bounds");
+        }
+        assert!(ALL_PARAMS.windows(2).all(|pair| pair[0].id() < pair[1].id()), "Id order");
+        assert_eq!(Param::from_id(0), None, "No id zero");
+    }
+
+    #[test]
+    fn test_bounds_are_inclusive() {
+        // Both ends are accepted; one past either end is rejected
+        let (min, max) = Param::TimeoutPeriod.bounds();
+
+        assert!(Param::TimeoutPeriod.check(min).is_ok(), "Min");
+        assert!(Param::TimeoutPeriod.check(max).is_ok(), "Max");
+        assert!(Param::TimeoutPeriod.check(U256::ZERO).is_err(), "Zero timeout");
+        assert!(Param::TimeoutPeriod.check(max + U256::from(1)).is_err(), "Past max");
+    }
+
+    #[test]
+    fn test_out_of_bounds_error() {
+        // The revert carries the parameter and its range
+        let err = Param::ProtocolFeeBps.check(U256::from(101u64)).unwrap_err();
+
+        assert_eq!(err.param, Param::ProtocolFeeBps.id(), "Param");
+        assert_eq!(err.value, U256::from(101u64), "Value");
+        assert_eq!(err.maxValue, U256::from(MAX_PROTOCOL_FEE_BPS), "Same cap as the fee module");
+    }
+
+    #[test]
+    fn test_changed_event() {
+        // Token-keyed changes carry the address as the key
+        let token = Address::from([7u8; 20]);
+        let event = changed_at(Param::TokenFeeBps, token_key(token), U256::from(5u64), U256::from(10u64));
+
+        assert_eq!(event.param, Param::TokenFeeBps.id(), "Param");
+        assert_eq!(Address::from_word(event.key.into()), token, "Key");
+        assert_eq!(changed(Param::MinBond, U256::ZERO, U256::from(1)).key, U256::ZERO, "Global key");
+    }
+}
@@ -0,0 +1,175 @@
+use alloy_sol_types::SolValue;
+use stylus_sdk::alloy_primitives::{Address, U256};
+use swoosh_common::encoding::{AssetType, BridgePayload, NftBridgePayload, ReceiverPayload};
+use swoosh_common::payload_codec::*;
+
+#[cfg(test)]
+mod payload_codec_tests {
+    use super::*;
+
+    fn bridge_payload() -> BridgePayload {
+        BridgePayload {
+            intentId: U256::from(42u64),
+            token: Address::from([1u8; 20]),
+            amount: U256::from(1_000u64),
+            recipient: Address::from([2u8; 20]),
+            unwrapNative: true,
+        }
+    }
+
+    fn nft_payload() -> NftBridgePayload {
+        NftBridgePayload {
+            intentId: U256::from(3u64),
+            assetType: AssetType::Erc721 as u8,
+            token: Address::from([1u8; 20]),
+            tokenId: U256::from(77u64),
+            amount: U256::from(1u64),
+            recipient: Address::from([2u8; 20]),
+        }
+    }
+
+    fn receiver_payload() -> ReceiverPayload {
+        ReceiverPayload {
+            intentId: U256::from(9u64),
+            token: Address::from([1u8; 20]),
+            amount: U256::from(500u64),
+            recipient: Address::from([2u8; 20]),
+            swapAdapter: Address::from([3u8; 20]),
+            tokenOut: Address::from([4u8; 20]),
+            minAmountOut: U256::from(450u64),
+            swapData: vec![0xab, 0xcd, 0xef].into(),
+        }
+    }
+
+    // The sol! structs have no PartialEq, so decoded payloads compare by encoding
+    fn encoded<T: SolValue>(decoded: Result<T, PayloadError>) -> Result<Vec<u8>, PayloadError> {
+        decoded.map(|payload| payload.abi_encode())
+    }
+
+    // xorshift64, so the fuzz cases are the same on every run
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, bound: usize) -> usize {
+            (self.next() % bound as u64) as usize
+        }
+    }
+
+    #[test]
+    fn test_header_layout() {
+        let encoded = encode_bridge(&bridge_payload());
+        assert_eq!(&encoded[..2], &PAYLOAD_MAGIC, "Magic");
+        assert_eq!(encoded[2], PAYLOAD_VERSION, "Version");
+        assert_eq!(encoded[3], PayloadKind::Bridge as u8, "Kind");
+        assert_eq!(&encoded[PAYLOAD_HEADER_SIZE..], bridge_payload().abi_encode().as_slice(), "ABI body");
+    }
+
+    #[test]
+    fn test_round_trips() {
+        let bridge = encode_bridge(&bridge_payload());
+        assert_eq!(encoded(decode_bridge(&bridge)), Ok(bridge_payload().abi_encode()), "Bridge");
+        let nft = encode_nft(&nft_payload());
+        assert_eq!(encoded(decode_nft(&nft)), Ok(nft_payload().abi_encode()), "NFT");
+        let receiver = encode_receiver(&receiver_payload());
+        assert_eq!(encoded(decode_receiver(&receiver)), Ok(receiver_payload().abi_encode()), "Receiver");
+    }
+
+    #[test]
+    fn test_unheadered_payloads_accepted() {
+        // Payloads sent before the header existed still decode
+        let bridge = bridge_payload().abi_encode();
+        assert_eq!(encoded(decode_bridge(&bridge)), Ok(bridge.clone()), "Bridge");
+        let nft = nft_payload().abi_encode();
+        assert_eq!(encoded(decode_nft(&nft)), Ok(nft.clone()), "NFT");
+        let receiver = receiver_payload().abi_encode();
+        assert_eq!(encoded(decode_receiver(&receiver)), Ok(receiver.clone()), "Receiver");
+    }
+
+    #[test]
+    fn test_typed_failures() {
+        let encoded = encode_bridge(&bridge_payload());
+
+        assert_eq!(decode_bridge(&[]).err(), Some(PayloadError::Empty), "Empty");
+        assert_eq!(decode_bridge(&encoded[..encoded.len() - 1]).err(), Some(PayloadError::BadLength), "Truncated");
+        assert_eq!(decode_bridge(&encoded[..encoded.len() - 32]).err(), Some(PayloadError::BadLength), "Short body");
+
+        let mut future = encoded.clone();
+        future[2] = PAYLOAD_VERSION + 1;
+        assert_eq!(decode_bridge(&future).err(), Some(PayloadError::UnsupportedVersion), "Newer version");
+
+        let mut foreign = encoded.clone();
+        foreign[0] = b'X';
+        assert_eq!(decode_bridge(&foreign).err(), Some(PayloadError::Malformed), "Bad magic");
+
+        // An address with its high bytes set is not a canonical encoding
+        let mut dirty = encoded.clone();
+        dirty[PAYLOAD_HEADER_SIZE + 32] = 0xff;
+        assert_eq!(decode_bridge(&dirty).err(), Some(PayloadError::Malformed), "Dirty address");
+
+        assert_eq!(decode_receiver(&encoded).err(), Some(PayloadError::WrongKind), "Bridge is not a receiver payload");
+        assert_eq!(decode_nft(&encoded).err(), Some(PayloadError::WrongKind), "Bridge is not an NFT payload");
+
+        let erc20 = NftBridgePayload { assetType: AssetType::Erc20 as u8, ..nft_payload() };
+        assert_eq!(decode_nft(&encode_nft(&erc20)).err(), Some(PayloadError::WrongKind), "Fungible asset type");
+        assert_eq!(PayloadError::WrongKind.code(), 5, "Reason code");
+    }
+
+    // Whatever decodes is exactly its canonical encoding, headered or not
+    fn assert_canonical(data: &[u8]) {
+        if let Ok(payload) = decode_bridge(data) {
+            assert!(data == encode_bridge(&payload) || data == payload.abi_encode(), "Canonical bridge");
+        }
+        if let Ok(payload) = decode_nft(data) {
+            assert!(data == encode_nft(&payload) || data == payload.abi_encode(), "Canonical NFT");
+        }
+        if let Ok(payload) = decode_receiver(data) {
+            assert!(data == encode_receiver(&payload) || data == payload.abi_encode(), "Canonical receiver");
+        }
+    }
+
+    #[test]
+    fn test_fuzz_random_bytes() {
+        // Arbitrary input, half of it behind a valid header, never panics
+        let mut rng = Rng(0x5eed_cafe_f00d_d00d);
+        for _ in 0..2_000 {
+            let len = rng.below(400);
+            let mut data: Vec<u8> = (0..len).map(|_| rng.next() as u8).collect();
+            if len >= PAYLOAD_HEADER_SIZE && rng.below(2) == 0 {
+                data[..2].copy_from_slice(&PAYLOAD_MAGIC);
+                data[2] = PAYLOAD_VERSION;
+                data[3] = 1 + rng.below(3) as u8;
+            }
+            assert_canonical(&data);
+        }
+    }
+
+    #[test]
+    fn test_fuzz_mutations() {
+        // Flipped bits, truncation and trailing garbage are caught or decode to what they encode
+        let mut rng = Rng(0x0dd_ba11);
+        let originals = [
+            encode_bridge(&bridge_payload()),
+            encode_nft(&nft_payload()),
+            encode_receiver(&receiver_payload()),
+        ];
+        for _ in 0..2_000 {
+            let mut data = originals[rng.below(originals.len())].clone();
+            match rng.below(3) {
+                0 => {
+                    let at = rng.below(data.len());
+                    data[at] ^= 1 << rng.below(8);
+                }
+                1 => data.truncate(rng.below(data.len())),
+                _ => data.extend((0..1 + rng.below(64)).map(|_| rng.next() as u8)),
+            }
+            assert_canonical(&data);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use stylus_sdk::alloy_primitives::{I256, U256};
+use swoosh_common::price_source::*;
+
+#[cfg(test)]
+mod price_source_tests {
+    use super::*;
+
+    // A price with PRICE_DECIMALS decimals
+    fn price(units: u64) -> U256 {
+        U256::from(units) * U256::from(10u64).pow(U256::from(PRICE_DECIMALS))
+    }
+
+    // A Chainlink answer with 8 decimals
+    fn answer(units: i64) -> I256 {
+        I256::try_from(units * 100_000_000).unwrap()
+    }
+
+    #[test]
+    fn test_normalize_price() {
+        // Any feed precision comes out with 18 decimals
+        assert_eq!(normalize_price(U256::from(200_000_000_000u64), 8), Some(price(2_000)), "8 decimals");
+        assert_eq!(normalize_price(price(3), 18), Some(price(3)), "18 decimals");
+        assert_eq!(normalize_price(price(3) * U256::from(1_000u64), 21), Some(price(3)), "21 decimals");
+        assert_eq!(normalize_price(U256::from(1u64), 37), None, "Too many decimals");
+        assert_eq!(normalize_price(U256::MAX, 8), None, "Overflow");
+    }
+
+    #[test]
+    fn test_chainlink_round_checks() {
+        // Fresh positive answers price the token; anything else falls through
+        let now = U256::from(10_000u64);
+        let max_age = U256::from(3_600u64);
+
+        assert_eq!(chainlink_price(answer(2_000), 8, now, now, max_age), Some(price(2_000)), "Fresh");
+        assert_eq!(
+            chainlink_price(answer(2_000), 8, now - max_age, now, max_age),
+            Some(price(2_000)),
+            "Exactly max age"
+        );
+        assert_eq!(chainlink_price(answer(2_000), 8, now - max_age - U256::from(1), now, max_age), None, "Stale");
+        assert_eq!(chainlink_price(answer(2_000), 8, now + U256::from(1), now, max_age), None, "Future round");
+        assert_eq!(chainlink_price(I256::ZERO, 8, now, now, max_age), None, "Zero answer");
+        assert_eq!(chainlink_price(answer(-1), 8, now, now, max_age), None, "Negative answer");
+    }
+
+    #[test]
+    fn test_fallback_order() {
+        // Chainlink, then TWAP, then the static bound
+        assert_eq!(
+            resolve_price(Some(price(2_000)), || Some(price(1_990)), price(1_500)),
+            (PriceSource::Chainlink, price(2_000)),
+            "Feed first"
+        );
+        assert_eq!(
+            resolve_price(None, || Some(price(1_990)), price(1_500)),
+            (PriceSource::Twap, price(1_990)),
+            "Stale feed falls back to the TWAP"
+        );
+        assert_eq!(
+            resolve_price(None, || Some(U256::ZERO), price(1_500)),
+            (PriceSource::StaticBound, price(1_500)),
+            "Zero TWAP falls back to the bound"
+        );
+        assert_eq!(resolve_price(None, || None, U256::ZERO), (PriceSource::Unavailable, U256::ZERO), "Nothing answers");
+    }
+
+    #[test]
+    fn test_twap_only_asked_after_feed_fails() {
+        // A fresh feed answers without the TWAP source being called
+        let resolved = resolve_price(Some(price(2_000)), || panic!("TWAP queried while the feed answered"), U256::ZERO);
+        assert_eq!(resolved, (PriceSource::Chainlink, price(2_000)), "Feed answered");
+    }
+
+    #[test]
+    fn test_fallback_mixes_decimals() {
+        // An 8-decimal feed and a 6-decimal TWAP resolve to the same 18-decimal scale
+        let now = U256::from(10_000u64);
+        let max_age = U256::from(3_600u64);
+        let feed = chainlink_price(answer(2_000), 8, now, now, max_age);
+        let twap = || twap_price(U256::from(1_990_000_000u64), 6);
+
+        assert_eq!(resolve_price(feed, twap, price(1_500)), (PriceSource::Chainlink, price(2_000)), "8-decimal feed");
+        let stale = chainlink_price(answer(2_000), 8, U256::ZERO, now, max_age);
+        assert_eq!(resolve_price(stale, twap, price(1_500)), (PriceSource::Twap, price(1_990)), "6-decimal TWAP");
+        assert_eq!(
+            resolve_price(stale, || twap_price(price(1_990) * U256::from(100u64), 20), price(1_500)),
+            (PriceSource::Twap, price(1_990)),
+            "20-decimal TWAP"
+        );
+        assert_eq!(
+            resolve_price(stale, || twap_price(U256::from(1u64), 37), price(1_500)),
+            (PriceSource::StaticBound, price(1_500)),
+            "Unscalable TWAP falls back to the bound"
+        );
+    }
+
+    #[test]
+    fn test_source_codes() {
+        // Sources round-trip through the code the validator returns
+        for source in [PriceSource::Unavailable, PriceSource::Chainlink, PriceSource::Twap, PriceSource::StaticBound] {
+            assert_eq!(PriceSource::from_u8(source as u8), Some(source), "Round trip");
+        }
+        assert_eq!(PriceSource::from_u8(4), None, "Unknown code");
+    }
+}
@@ -0,0 +1,108 @@
+use stylus_sdk::alloy_primitives::{Address, U256};
+use swoosh_common::encoding::{RegisteredIntent, RegisteredIntentStatus};
+use swoosh_common::registry_record::*;
+
+#[cfg(test)]
+mod registry_record_tests {
+    use super::*;
+
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    fn intent() -> RegisteredIntent {
+        RegisteredIntent {
+            user: test_address(1),
+            token: test_address(2),
+            amount: U256::from(1_000_000u64),
+            destinationChain: U256::from(8_453u64),
+            recipient: test_address(3),
+            deadline: U256::from(1_700_003_600u64),
+            nonce: U256::from(7u64),
+        }
+    }
+
+    #[test]
+    fn test_record_round_trip() {
+        // A registered intent comes back field for field, pending since registration
+        let record = RegistryRecord::open(&intent(), U256::from(1_700_000_000u64)).expect("Fits");
+
+        assert_eq!(RegistryRecord::decode(record.encode()), record, "Round trip");
+        assert_eq!(record.status, RegisteredIntentStatus::Pending as u8, "Pending");
+        assert_eq!((record.registered_at, record.updated_at), (1_700_000_000, 1_700_000_000), "Timestamps");
+        assert_eq!((record.user, record.nonce), (test_address(1), 7), "User and nonce");
+        assert_eq!((record.recipient, record.destination_chain), (test_address(3), 8_453), "Recipient and chain");
+        assert_eq!(
+            RegistryRecord::decode([U256::ZERO; REGISTRY_RECORD_WORDS]),
+            RegistryRecord::default(),
+            "Unused record"
+        );
+    }
+
+    #[test]
+    fn test_fields_isolated() {
+        // Maximum values stay in their own bits
+        let record = RegistryRecord {
+            status: u8::MAX,
+            registered_at: 0,
+            updated_at: (1 << 40) - 1,
+            consumer: Address::repeat_byte(0xff),
+            user: Address::ZERO,
+            nonce: u64::MAX,
+            token: Address::repeat_byte(0xff),
+            deadline: 0,
+            recipient: Address::repeat_byte(0xff),
+            destination_chain: u64::MAX,
+            amount: U256::MAX,
+        };
+        let words = record.encode();
+
+        assert_eq!(RegistryRecord::decode(words), record, "Maximum values");
+        assert_eq!(words[0] >> 248, U256::ZERO, "Header reserved bits clear");
+        assert_eq!(words[1] >> 224, U256::ZERO, "Word reserved bits clear");
+        assert_eq!(registry_header_status(words[0]), u8::MAX, "Status byte");
+    }
+
+    #[test]
+    fn test_wide_fields_rejected() {
+        // A nonce, deadline or chain past 64 bits has no record; the block time is clamped
+        let wide = U256::from(u64::MAX) + U256::from(1u64);
+        assert_eq!(RegistryRecord::open(&RegisteredIntent { nonce: wide, ..intent() }, U256::ZERO), None, "Nonce");
+        assert_eq!(
+            RegistryRecord::open(&RegisteredIntent { deadline: U256::MAX, ..intent() }, U256::ZERO),
+            None,
+            "Deadline"
+        );
+        let chain = RegisteredIntent { destinationChain: wide, ..intent() };
+        assert_eq!(RegistryRecord::open(&chain, U256::ZERO), None, "Destination chain");
+
+        let edge = RegisteredIntent { deadline: U256::from(u64::MAX), ..intent() };
+        let record = RegistryRecord::open(&edge, U256::MAX).map(|record| RegistryRecord::decode(record.encode()));
+        assert_eq!(record.map(|record| record.deadline), Some(u64::MAX), "Widest deadline");
+        assert_eq!(record.map(|record| record.registered_at), Some((1 << 40) - 1), "Registration time");
+        assert_eq!(record.map(|record| record.token), Some(test_address(2)), "Neighbouring address intact");
+    }
+
+    #[test]
+    fn test_header_update() {
+        // Marking executed records the consumer and time, keeping the registration time
+        let record = RegistryRecord::open(&intent(), U256::from(1_700_000_000u64)).expect("Fits");
+        let header = update_registry_header(
+            record.header(),
+            RegisteredIntentStatus::Executed as u8,
+            test_address(9),
+            U256::from(1_700_000_500u64),
+        );
+        let updated = RegistryRecord::decode([header, U256::ZERO, U256::ZERO, U256::ZERO, U256::ZERO]);
+
+        assert_eq!(updated.status, RegisteredIntentStatus::Executed as u8, "Status");
+        assert_eq!(updated.registered_at, 1_700_000_000, "Registration time kept");
+        assert_eq!(updated.updated_at, 1_700_000_500, "Update time");
+        assert_eq!(updated.consumer, test_address(9), "Consumer");
+
+        let cancelled =
+            update_registry_header(header, RegisteredIntentStatus::Cancelled as u8, Address::ZERO, U256::ZERO);
+        let cancelled = RegistryRecord::decode([cancelled, U256::ZERO, U256::ZERO, U256::ZERO, U256::ZERO]);
+        assert_eq!(cancelled.consumer, Address::ZERO, "Consumer cleared");
+    }
+}
@@ -0,0 +1,63 @@
+use stylus_sdk::alloy_primitives::U256;
+use swoosh_common::retry_policy::*;
+
+#[cfg(test)]
+mod retry_policy_tests {
+    use super::*;
+
+    #[test]
+    fn test_zero_is_no_policy() {
+        // Intents signed without a policy fail on the first timeout
+        let policy = RetryPolicy::decode(U256::ZERO).expect("Zero decodes");
+
+        assert_eq!(policy, RetryPolicy::default(), "No policy");
+        assert!(!policy.retries(), "Never retries");
+        assert!(!policy.allows_retry(0, 100, 100), "No retry allowed");
+    }
+
+    #[test]
+    fn test_policy_round_trip() {
+        // Fields come back unchanged, including the longest delay
+        let policy = RetryPolicy {
+            max_retries: MAX_RETRIES,
+            max_delay: u64::MAX,
+            alternate_bridges: true,
+        };
+
+        assert_eq!(RetryPolicy::decode(policy.encode()), Some(policy), "Round trip");
+        assert_eq!(policy.encode() >> 73, U256::ZERO, "Reserved bits clear");
+    }
+
+    #[test]
+    fn test_malformed_policies_rejected() {
+        // Reserved bits, too many retries and retries without delay don't decode
+        let valid = RetryPolicy {
+            max_retries: 2,
+            max_delay: 3600,
+            alternate_bridges: false,
+        };
+        let too_many = RetryPolicy { max_retries: MAX_RETRIES + 1, ..valid };
+        let no_delay = RetryPolicy { max_delay: 0, ..valid };
+        let flags_only = RetryPolicy { max_retries: 0, max_delay: 0, alternate_bridges: true };
+
+        assert!(RetryPolicy::decode(valid.encode() | (U256::from(1) << 73)).is_none(), "Reserved bit");
+        assert!(RetryPolicy::decode(too_many.encode()).is_none(), "Too many retries");
+        assert!(RetryPolicy::decode(no_delay.encode()).is_none(), "No delay");
+        assert!(RetryPolicy::decode(flags_only.encode()).is_none(), "Flags without retries");
+    }
+
+    #[test]
+    fn test_retry_budget() {
+        // Retries stop at the count or once the delay since execution is spent
+        let policy = RetryPolicy {
+            max_retries: 2,
+            max_delay: 3600,
+            alternate_bridges: false,
+        };
+
+        assert!(policy.allows_retry(0, 1_000, 2_000), "First retry");
+        assert!(policy.allows_retry(1, 1_000, 4_600), "Last second of delay");
+        assert!(!policy.allows_retry(1, 1_000, 4_601), "Delay spent");
+        assert!(!policy.allows_retry(2, 1_000, 2_000), "Retries spent");
+    }
+}
@@ -0,0 +1,408 @@
+use alloy_sol_types::SolValue;
+use stylus_sdk::alloy_primitives::{Address, Bytes, FixedBytes, U256};
+use swoosh_common::encoding::{AssetType, Intent, RouteStep, StepType, NATIVE_TOKEN};
+use swoosh_common::route_codec::*;
+
+const WETH: Address = Address::new([7; 20]);
+
+#[cfg(test)]
+mod route_codec_tests {
+    use super::*;
+
+    // Helper function to create test addresses
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    // Helper function to build an intent spending token 1
+    fn test_intent() -> Intent {
+        Intent {
+            user: test_address(9),
+            tokenIn: test_address(1),
+            amountIn: U256::from(1_000_000u64),
+            destinationChain: U256::from(10u64),
+            recipient: test_address(8),
+            minAmountOut: U256::from(990_000u64),
+            deadline: U256::from(1_000u64),
+            nonce: U256::ZERO,
+            gasDrop: U256::ZERO,
+            assetType: 0,
+            tokenId: U256::ZERO,
+            recipientHash: FixedBytes::ZERO,
+            maxSolverFee: U256::ZERO,
+            priorityFee: U256::ZERO,
+            blockNumber: U256::ZERO,
+            blockHash: FixedBytes::ZERO,
+            configEpoch: U256::ZERO,
+            callback: Address::ZERO,
+            callbackData: Bytes::new(),
+            recipientFamily: 0,
+            recipientAddress: Bytes::new(),
+            insurancePremiumBps: U256::ZERO,
+            retryPolicy: U256::ZERO,
+            memo: FixedBytes::ZERO,
+        }
+    }
+
+    // Helper function to build the swap params of a token 1 -> token 2 swap
+    fn test_swap() -> SwapParams {
+        SwapParams {
+            adapter: test_address(5),
+            tokenOut: test_address(2),
+            minAmountOut: U256::from(995_000u64),
+            deadline: U256::from(900u64),
+            data: Bytes::new(),
+        }
+    }
+
+    fn allow_adapter_5(adapter: Address) -> bool {
+        adapter == test_address(5)
+    }
+
+    #[test]
+    fn test_swap_params_round_trip() {
+        // swap_data decodes back to the submitted params
+        let swap = test_swap();
+        let decoded = decode_swap_params(&swap.abi_encode()).expect("decodes");
+
+        assert_eq!(decoded.adapter, swap.adapter, "Adapter preserved");
+        assert_eq!(decoded.tokenOut, swap.tokenOut, "Token out preserved");
+        assert_eq!(decoded.minAmountOut, swap.minAmountOut, "Min out preserved");
+        assert_eq!(decode_swap_params(&[1, 2, 3]).err(), Some(RouteError::Malformed), "Garbage rejected");
+    }
+
+    #[test]
+    fn test_route_round_trip() {
+        // Encoded steps decode to the same route
+        let steps = full_route_steps(test_address(1), WETH, Some(test_swap()));
+        let decoded = decode_route(&steps.abi_encode()).expect("decodes");
+
+        assert_eq!(decoded.len(), 2, "Swap and bridge steps");
+        assert_eq!(decoded[1].tokenIn, test_address(2), "Bridge carries swap output");
+    }
+
+    #[test]
+    fn test_full_route_valid() {
+        // Swap-then-bridge and bridge-only routes validate
+        let intent = test_intent();
+        let with_swap = full_route_steps(intent.tokenIn, WETH, Some(test_swap()));
+        let bridge_only = full_route_steps(intent.tokenIn, WETH, None);
+
+        assert_eq!(validate_route(&intent, &with_swap, WETH, allow_adapter_5), Ok(()), "Swap route valid");
+        assert_eq!(validate_route(&intent, &bridge_only, WETH, allow_adapter_5), Ok(()), "Bridge route valid");
+    }
+
+    #[test]
+    fn test_adapter_not_allowed() {
+        // Swaps through unlisted adapters are rejected
+        let intent = test_intent();
+        let steps = full_route_steps(intent.tokenIn, WETH, Some(test_swap()));
+
+        assert_eq!(
+            validate_route(&intent, &steps, WETH, |_| false),
+            Err(RouteError::AdapterNotAllowed),
+            "Unlisted adapter rejected"
+        );
+    }
+
+    #[test]
+    fn test_broken_token_path() {
+        // First step must spend the intent's input token
+        let intent = test_intent();
+        let mut steps = full_route_steps(intent.tokenIn, WETH, Some(test_swap()));
+        steps[0].tokenIn = test_address(3);
+
+        assert_eq!(validate_route(&intent, &steps, WETH, allow_adapter_5), Err(RouteError::BrokenPath), "Disconnected path");
+
+        // Swap output equal to its input is rejected
+        let mut swap = test_swap();
+        swap.tokenOut = intent.tokenIn;
+        let steps = full_route_steps(intent.tokenIn, WETH, Some(swap));
+        assert_eq!(validate_route(&intent, &steps, WETH, allow_adapter_5), Err(RouteError::BadSwapTokens), "Self swap");
+    }
+
+    #[test]
+    fn test_terminal_placement() {
+        // Routes must end in exactly one Bridge or Transfer
+        let intent = test_intent();
+        let mut steps = full_route_steps(intent.tokenIn, WETH, Some(test_swap()));
+        steps.pop();
+
+        assert_eq!(validate_route(&intent, &steps, WETH, allow_adapter_5), Err(RouteError::BadTerminal), "Missing terminal");
+
+        let mut steps = full_route_steps(intent.tokenIn, WETH, None);
+        steps.push(steps[0].clone());
+        assert_eq!(validate_route(&intent, &steps, WETH, allow_adapter_5), Err(RouteError::BadTerminal), "Double terminal");
+    }
+
+    #[test]
+    fn test_unknown_step_and_length() {
+        // Unknown discriminators and oversized routes are rejected
+        let intent = test_intent();
+        let mut steps = full_route_steps(intent.tokenIn, WETH, None);
+        steps[0].stepType = 42;
+
+        assert_eq!(validate_route(&intent, &steps, WETH, allow_adapter_5), Err(RouteError::UnknownStep), "Unknown step");
+        assert_eq!(validate_route(&intent, &[], WETH, allow_adapter_5), Err(RouteError::Empty), "Empty route");
+
+        let swap = full_route_steps(intent.tokenIn, WETH, Some(test_swap()))[0].clone();
+        assert_eq!(swap.stepType, StepType::Swap as u8, "Leading swap step");
+        let long: Vec<RouteStep> = (0..=MAX_ROUTE_STEPS).map(|_| swap.clone()).collect();
+        assert_eq!(validate_route(&intent, &long, WETH, allow_adapter_5), Err(RouteError::TooManySteps), "Too long");
+    }
+
+    #[test]
+    fn test_inconsistent_amounts_and_deadlines() {
+        // Final swap minimum looser than the intent's is rejected
+        let intent = test_intent();
+        let mut swap = test_swap();
+        swap.minAmountOut = U256::from(1u64);
+        let steps = full_route_steps(intent.tokenIn, WETH, Some(swap));
+
+        assert_eq!(
+            validate_route(&intent, &steps, WETH, allow_adapter_5),
+            Err(RouteError::InconsistentAmounts),
+            "Loose swap minimum"
+        );
+
+        // Step deadline past the intent deadline is rejected
+        let mut swap = test_swap();
+        swap.deadline = intent.deadline + U256::from(1u64);
+        let steps = full_route_steps(intent.tokenIn, WETH, Some(swap));
+        assert_eq!(
+            validate_route(&intent, &steps, WETH, allow_adapter_5),
+            Err(RouteError::InconsistentDeadline),
+            "Late step deadline"
+        );
+    }
+
+    #[test]
+    fn test_native_route_wraps_first() {
+        // Native intents wrap into WETH before swapping
+        let mut intent = test_intent();
+        intent.tokenIn = NATIVE_TOKEN;
+        let steps = full_route_steps(NATIVE_TOKEN, WETH, Some(test_swap()));
+
+        assert_eq!(steps.len(), 3, "Wrap, swap and bridge");
+        assert_eq!(steps[0].stepType, StepType::Wrap as u8, "Leading wrap");
+        assert_eq!(steps[1].tokenIn, WETH, "Swap spends WETH");
+        assert_eq!(validate_route(&intent, &steps, WETH, allow_adapter_5), Ok(()), "Native route valid");
+        assert_eq!(
+            validate_route(&intent, &steps, Address::ZERO, allow_adapter_5),
+            Err(RouteError::WrongWrappedNative),
+            "WETH must be configured"
+        );
+    }
+
+    #[test]
+    fn test_native_misuse_rejected() {
+        // Native ETH can't be bridged or swapped directly
+        let mut intent = test_intent();
+        intent.tokenIn = NATIVE_TOKEN;
+        let mut steps = full_route_steps(test_address(1), WETH, None);
+        steps[0].tokenIn = NATIVE_TOKEN;
+        steps[0].tokenOut = NATIVE_TOKEN;
+
+        assert_eq!(validate_route(&intent, &steps, WETH, allow_adapter_5), Err(RouteError::BadNativeStep), "Native bridge");
+
+        // Unwrap must come from WETH
+        let intent = test_intent();
+        let mut unwrap = full_route_steps(intent.tokenIn, WETH, None)[0].clone();
+        unwrap.stepType = StepType::Unwrap as u8;
+        unwrap.tokenOut = NATIVE_TOKEN;
+        let mut transfer = unwrap.clone();
+        transfer.stepType = StepType::Transfer as u8;
+        transfer.tokenIn = NATIVE_TOKEN;
+        let steps = vec![unwrap, transfer];
+        assert_eq!(
+            validate_route(&intent, &steps, WETH, allow_adapter_5),
+            Err(RouteError::WrongWrappedNative),
+            "Unwrap of non-WETH"
+        );
+    }
+
+    #[test]
+    fn test_native_delivery_bridge() {
+        // Bridging WETH with a native tokenOut requests native delivery
+        let mut intent = test_intent();
+        intent.tokenIn = WETH;
+        let mut steps = full_route_steps(WETH, WETH, None);
+        steps[0].tokenOut = NATIVE_TOKEN;
+
+        assert_eq!(validate_route(&intent, &steps, WETH, allow_adapter_5), Ok(()), "Native delivery of WETH");
+
+        intent.tokenIn = test_address(1);
+        steps[0].tokenIn = test_address(1);
+        assert_eq!(
+            validate_route(&intent, &steps, WETH, allow_adapter_5),
+            Err(RouteError::WrongWrappedNative),
+            "Native delivery needs WETH"
+        );
+    }
+
+    #[test]
+    fn test_request_native_delivery() {
+        // ETH in, ETH out: the wrapped input is unwrapped on the destination
+        let mut intent = test_intent();
+        intent.tokenIn = NATIVE_TOKEN;
+        let mut steps = full_route_steps(NATIVE_TOKEN, WETH, None);
+
+        assert!(request_native_delivery(&mut steps, WETH), "WETH bridge switched");
+        assert_eq!(steps[1].tokenOut, NATIVE_TOKEN, "Native tokenOut");
+        assert_eq!(validate_route(&intent, &steps, WETH, allow_adapter_5), Ok(()), "Native delivery route valid");
+
+        let mut erc20_steps = full_route_steps(test_address(1), WETH, None);
+        assert!(!request_native_delivery(&mut erc20_steps, WETH), "ERC20 bridge untouched");
+        assert_eq!(erc20_steps[0].tokenOut, test_address(1), "ERC20 tokenOut kept");
+
+        let mut unset = full_route_steps(NATIVE_TOKEN, Address::ZERO, None);
+        assert!(!request_native_delivery(&mut unset, Address::ZERO), "No WETH configured");
+    }
+
+    #[test]
+    fn test_nft_intent_not_routable() {
+        // NFT intents bridge through execute_nft_transfer, never a route
+        let mut intent = test_intent();
+        intent.assetType = AssetType::Erc721 as u8;
+        intent.tokenId = U256::from(5u64);
+        let steps = full_route_steps(intent.tokenIn, WETH, None);
+
+        assert_eq!(
+            validate_route(&intent, &steps, WETH, allow_adapter_5),
+            Err(RouteError::UnsupportedAsset),
+            "NFT intent rejected"
+        );
+    }
+
+    #[test]
+    fn test_hidden_recipient_needs_token_bridge() {
+        // A committed recipient is revealed after a plain bridge, nowhere else
+        let mut intent = test_intent();
+        intent.recipientHash = FixedBytes::from([6u8; 32]);
+        let steps = full_route_steps(intent.tokenIn, WETH, None);
+        assert_eq!(validate_route(&intent, &steps, WETH, allow_adapter_5), Ok(()), "Token bridge accepted");
+
+        let mut native = steps.clone();
+        assert!(request_native_delivery(&mut native, intent.tokenIn), "Native delivery requested");
+        assert_eq!(
+            validate_route(&intent, &native, intent.tokenIn, allow_adapter_5),
+            Err(RouteError::HiddenRecipient),
+            "Native delivery rejected"
+        );
+
+        intent.gasDrop = U256::from(1u64);
+        assert_eq!(
+            validate_route(&intent, &steps, WETH, allow_adapter_5),
+            Err(RouteError::HiddenRecipient),
+            "Gas drop rejected"
+        );
+    }
+
+    #[test]
+    fn test_bridge_adapter_allowlist() {
+        // A Bridge step may carry adapters to choose the cheapest from
+        let intent = test_intent();
+        let mut steps = full_route_steps(intent.tokenIn, WETH, None);
+        let candidates = vec![test_address(3), test_address(4)];
+        steps[0].data = encode_adapter_allowlist(&candidates);
+
+        assert_eq!(validate_route(&intent, &steps, WETH, allow_adapter_5), Ok(()), "Allowlist accepted");
+        assert_eq!(decode_adapter_allowlist(&steps[0].data), Ok(candidates), "Allowlist round trip");
+
+        steps[0].adapter = test_address(3);
+        assert_eq!(
+            validate_route(&intent, &steps, WETH, allow_adapter_5),
+            Err(RouteError::BadAdapterAllowlist),
+            "Pinned adapter with allowlist"
+        );
+
+        let too_many: Vec<Address> = (1..=MAX_LANE_CANDIDATES as u8 + 1).map(test_address).collect();
+        assert!(decode_adapter_allowlist(&encode_adapter_allowlist(&too_many)).is_err(), "Too many candidates");
+        assert!(decode_adapter_allowlist(&encode_adapter_allowlist(&[])).is_err(), "Empty allowlist");
+        assert!(decode_adapter_allowlist(&[1, 2, 3]).is_err(), "Malformed allowlist");
+    }
+
+    #[test]
+    fn test_bridge_gas_limit_options() {
+        // Tagged options raise the gas limit, with or without an allowlist
+        let mut intent = test_intent();
+        let mut steps = full_route_steps(intent.tokenIn, WETH, None);
+        let gas_limit = U256::from(500_000u64);
+
+        steps[0].data = encode_bridge_options(&[], gas_limit);
+        assert_eq!(validate_route(&intent, &steps, WETH, allow_adapter_5), Ok(()), "Pinned adapter with gas limit");
+        assert_eq!(decode_bridge_step_data(&steps[0].data), Ok((vec![], gas_limit)), "Gas limit round trip");
+
+        let candidates = vec![test_address(3), test_address(4)];
+        steps[0].data = encode_bridge_options(&candidates, gas_limit);
+        assert_eq!(decode_bridge_step_data(&steps[0].data), Ok((candidates.clone(), gas_limit)), "With allowlist");
+        assert_eq!(
+            decode_bridge_step_data(&encode_adapter_allowlist(&candidates)),
+            Ok((candidates, U256::ZERO)),
+            "Bare allowlist keeps the lane gas"
+        );
+
+        intent.gasDrop = U256::from(1u64);
+        assert_eq!(
+            validate_route(&intent, &steps, WETH, allow_adapter_5),
+            Err(RouteError::BadBridgeOptions),
+            "Gas limit with gas drop"
+        );
+        assert_eq!(
+            decode_bridge_step_data(&[BRIDGE_OPTIONS_TAG.as_slice(), &[1, 2, 3]].concat()),
+            Err(RouteError::BadBridgeOptions),
+            "Malformed options"
+        );
+    }
+
+    #[test]
+    fn test_non_evm_recipient() {
+        // A Solana recipient travels as raw bytes over a plain token bridge
+        let mut intent = test_intent();
+        intent.recipient = Address::ZERO;
+        intent.recipientFamily = 1;
+        intent.recipientAddress = Bytes::from(vec![6u8; 32]);
+        let steps = full_route_steps(intent.tokenIn, WETH, None);
+        assert_eq!(validate_route(&intent, &steps, WETH, allow_adapter_5), Ok(()), "Solana recipient accepted");
+
+        let mut options = steps.clone();
+        options[0].data = encode_adapter_allowlist(&[test_address(3)]);
+        assert_eq!(
+            validate_route(&intent, &options, WETH, allow_adapter_5),
+            Err(RouteError::BadRecipient),
+            "Adapter allowlist rejected"
+        );
+
+        let mut evm_too = intent.clone();
+        evm_too.recipient = test_address(8);
+        assert_eq!(check_recipient(&evm_too), Err(RouteError::BadRecipient), "EVM recipient alongside");
+
+        intent.recipientAddress = Bytes::from(vec![6u8; 20]);
+        assert_eq!(check_recipient(&intent), Err(RouteError::BadRecipient), "Wrong length for Solana");
+
+        let mut evm = test_intent();
+        evm.recipientAddress = Bytes::from(vec![6u8; 20]);
+        assert_eq!(check_recipient(&evm), Err(RouteError::BadRecipient), "EVM intent with raw address");
+    }
+
+    #[test]
+    fn test_cheapest_quote() {
+        // Lowest fee wins; the earlier candidate wins a tie
+        let quotes = [
+            (test_address(1), U256::from(300u64)),
+            (test_address(2), U256::from(100u64)),
+            (test_address(3), U256::from(100u64)),
+        ];
+
+        assert_eq!(cheapest_quote(&quotes), Some((test_address(2), U256::from(100u64))), "Cheapest first");
+        assert_eq!(cheapest_quote(&[]), None, "No quotes");
+    }
+
+    #[test]
+    fn test_reason_codes() {
+        // Reason codes surfaced in InvalidRoute are stable
+        assert_eq!(RouteError::Malformed.code(), 1, "Malformed code");
+        assert_eq!(RouteError::InconsistentDeadline.code(), 10, "Deadline code");
+        assert_eq!(RouteError::BadAdapterAllowlist.code(), 13, "Allowlist code");
+    }
+}
@@ -0,0 +1,157 @@
+use alloy_sol_types::SolValue;
+use stylus_sdk::alloy_primitives::{Address, Bytes, FixedBytes, U256};
+use swoosh_common::encoding::*;
+
+#[cfg(test)]
+mod serde_tests {
+    use super::*;
+
+    const SCHEMA: &str = include_str!("../schema/intent.schema.json");
+
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    fn sample_intent() -> Intent {
+        Intent {
+            user: test_address(1),
+            tokenIn: test_address(2),
+            amountIn: U256::from(1_000_000u64),
+            destinationChain: U256::from(10u64),
+            recipient: test_address(3),
+            minAmountOut: U256::from(990_000u64),
+            deadline: U256::from(1_700_000_000u64),
+            nonce: U256::from(7u64),
+            gasDrop: U256::ZERO,
+            assetType: 0,
+            tokenId: U256::ZERO,
+            recipientHash: FixedBytes::ZERO,
+            maxSolverFee: U256::ZERO,
+            priorityFee: U256::ZERO,
+            blockNumber: U256::ZERO,
+            blockHash: FixedBytes::ZERO,
+            configEpoch: U256::ZERO,
+            callback: Address::ZERO,
+            callbackData: Bytes::new(),
+            recipientFamily: 0,
+            recipientAddress: Bytes::new(),
+            insurancePremiumBps: U256::ZERO,
+            retryPolicy: U256::ZERO,
+            memo: FixedBytes::from([5u8; 32]),
+        }
+    }
+
+    fn sample_step() -> RouteStep {
+        RouteStep {
+            stepType: StepType::Swap as u8,
+            adapter: test_address(4),
+            tokenIn: test_address(2),
+            tokenOut: test_address(5),
+            minAmountOut: U256::from(990_000u64),
+            deadline: U256::from(1_700_000_000u64),
+            recipient: test_address(6),
+            data: Bytes::from(vec![0xde, 0xad]),
+        }
+    }
+
+    fn sample_receipt() -> IntentReceipt {
+        IntentReceipt {
+            intentId: U256::from(42u64),
+            intentHash: FixedBytes::from([9u8; 32]),
+            user: test_address(1),
+            solver: test_address(8),
+            tokenIn: test_address(2),
+            amountIn: U256::from(1_000_000u64),
+            feeAmount: U256::from(500u64),
+            solverFee: U256::from(20u64),
+            destinationChain: U256::from(10u64),
+            messageId: FixedBytes::from([7u8; 32]),
+            status: 1,
+            executedAt: U256::from(1_700_000_000u64),
+            settledAt: U256::from(1_700_000_060u64),
+            memo: FixedBytes::from([5u8; 32]),
+        }
+    }
+
+    // Required property names the schema lists for a definition
+    fn schema_fields(definition: &str) -> Vec<String> {
+        let schema: serde_json::Value = serde_json::from_str(SCHEMA).expect("Schema is JSON");
+        let mut fields: Vec<String> = schema["$defs"][definition]["required"]
+            .as_array()
+            .expect("Required list")
+            .iter()
+            .map(|field| field.as_str().unwrap().to_string())
+            .collect();
+        fields.sort();
+        fields
+    }
+
+    fn json_fields(value: &serde_json::Value) -> Vec<String> {
+        let mut fields: Vec<String> = value.as_object().expect("JSON object").keys().cloned().collect();
+        fields.sort();
+        fields
+    }
+
+    #[test]
+    fn test_intent_json_round_trip() {
+        // JSON and back yields the same ABI encoding
+        let intent = sample_intent();
+        let json = serde_json::to_string(&intent).unwrap();
+        let decoded: Intent = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded.abi_encode(), intent.abi_encode(), "Same ABI encoding");
+    }
+
+    #[test]
+    fn test_route_json_round_trip() {
+        // A route is a JSON array of steps
+        let steps = vec![sample_step(), sample_step()];
+        let json = serde_json::to_string(&steps).unwrap();
+        let decoded: Vec<RouteStep> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded.abi_encode(), steps.abi_encode(), "Same ABI encoding");
+    }
+
+    #[test]
+    fn test_receipt_json_round_trip() {
+        // Receipts survive JSON and ABI encoding alike
+        let receipt = sample_receipt();
+        let json = serde_json::to_string(&receipt).unwrap();
+        let decoded: IntentReceipt = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded.abi_encode(), receipt.abi_encode(), "Same ABI encoding");
+        assert_eq!(IntentReceipt::abi_decode(&receipt.abi_encode()).unwrap().intentId, U256::from(42u64), "ABI decode");
+    }
+
+    #[test]
+    fn test_json_format() {
+        // Integers are hex strings, step type is a number, bytes are hex
+        let value = serde_json::to_value(sample_step()).unwrap();
+
+        assert_eq!(value["stepType"], 0, "Step type number");
+        assert_eq!(value["minAmountOut"], "0xf1b30", "Hex integer");
+        assert_eq!(value["data"], "0xdead", "Hex bytes");
+    }
+
+    #[test]
+    fn test_decimal_integers_accepted() {
+        // Services may send decimal strings
+        let mut value = serde_json::to_value(sample_intent()).unwrap();
+        value["amountIn"] = "1000000".into();
+
+        let intent: Intent = serde_json::from_value(value).unwrap();
+        assert_eq!(intent.amountIn, U256::from(1_000_000u64), "Decimal amount");
+    }
+
+    #[test]
+    fn test_schema_matches_types() {
+        // Every serialized field is documented and required by the schema
+        assert_eq!(json_fields(&serde_json::to_value(sample_intent()).unwrap()), schema_fields("Intent"), "Intent");
+        assert_eq!(json_fields(&serde_json::to_value(sample_step()).unwrap()), schema_fields("RouteStep"), "RouteStep");
+        assert_eq!(
+            json_fields(&serde_json::to_value(sample_receipt()).unwrap()),
+            schema_fields("IntentReceipt"),
+            "IntentReceipt"
+        );
+    }
+}
@@ -0,0 +1,290 @@
+use alloy_sol_types::{SolStruct, SolValue};
+use stylus_sdk::alloy_primitives::{keccak256, Address, Bytes, FixedBytes, U256};
+use swoosh_common::encoding::{
+    Intent, RegisteredIntent, RegisteredIntentStatus, RouteQuote, RouteStep, ValidatorIntent,
+};
+use swoosh_common::signing::*;
+
+#[cfg(test)]
+mod signing_tests {
+    use super::*;
+
+    // Helper function to create test addresses
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    // Helper function to build a signed-intent fixture
+    fn test_intent(nonce: u64) -> Intent {
+        Intent {
+            user: test_address(1),
+            tokenIn: test_address(2),
+            amountIn: U256::from(1_000u64),
+            destinationChain: U256::from(10u64),
+            recipient: test_address(3),
+            minAmountOut: U256::from(990u64),
+            deadline: U256::from(2_000u64),
+            nonce: U256::from(nonce),
+            gasDrop: U256::ZERO,
+            assetType: 0,
+            tokenId: U256::ZERO,
+            recipientHash: FixedBytes::ZERO,
+            maxSolverFee: U256::ZERO,
+            priorityFee: U256::ZERO,
+            blockNumber: U256::ZERO,
+            blockHash: FixedBytes::ZERO,
+            configEpoch: U256::ZERO,
+            callback: Address::ZERO,
+            callbackData: Bytes::new(),
+            recipientFamily: 0,
+            recipientAddress: Bytes::new(),
+            insurancePremiumBps: U256::ZERO,
+            retryPolicy: U256::ZERO,
+            memo: FixedBytes::ZERO,
+        }
+    }
+
+    // Helper function to build a 65-byte signature with the given s and v
+    fn test_signature(s: u8, v: u8) -> Vec<u8> {
+        let mut signature = vec![0x11; 32];
+        signature.extend_from_slice(&[s; 32]);
+        signature.push(v);
+        signature
+    }
+
+    #[test]
+    fn test_intent_type_hash() {
+        // Type string matches what wallets hash for eth_signTypedData
+        let expected = keccak256(
+            concat!(
+                "Intent(address user,address tokenIn,uint256 amountIn,uint256 destinationChain,address recipient,",
+                "uint256 minAmountOut,uint256 deadline,uint256 nonce,uint256 gasDrop,uint8 assetType,uint256 tokenId,",
+                "bytes32 recipientHash,uint256 maxSolverFee,uint256 priorityFee,uint256 blockNumber,bytes32 blockHash,",
+                "uint256 configEpoch,address callback,bytes callbackData,",
+                "uint8 recipientFamily,bytes recipientAddress,uint256 insurancePremiumBps,uint256 retryPolicy,",
+                "bytes32 memo)"
+            ),
+        );
+
+        assert_eq!(test_intent(0).eip712_type_hash(), expected, "Intent type hash");
+    }
+
+    #[test]
+    fn test_digest_is_domain_bound() {
+        // Same intent hashes differently per chain, contract and nonce
+        let executor = test_address(9);
+        let digest = intent_digest(&test_intent(0), 42161, executor);
+
+        assert_ne!(digest, intent_digest(&test_intent(0), 1, executor), "Chain bound");
+        assert_ne!(digest, intent_digest(&test_intent(0), 42161, test_address(8)), "Contract bound");
+        assert_ne!(digest, intent_digest(&test_intent(1), 42161, executor), "Nonce bound");
+        assert_eq!(digest, intent_digest(&test_intent(0), 42161, executor), "Deterministic");
+    }
+
+    #[test]
+    fn test_intent_key_is_executor_independent() {
+        // Every executor derives the same key, but each intent gets its own
+        let key = intent_key(&test_intent(0));
+
+        assert_eq!(key, intent_key(&test_intent(0)), "Deterministic");
+        assert_ne!(key, intent_key(&test_intent(1)), "Nonce bound");
+        assert_ne!(key, intent_digest(&test_intent(0), 42161, test_address(9)), "Not the digest");
+    }
+
+    #[test]
+    fn test_global_intent_id_from_hash() {
+        // The global ID is the intent hash read big-endian, unique per chain
+        let executor = test_address(9);
+        let digest = intent_digest(&test_intent(0), 42161, executor);
+
+        assert_eq!(global_intent_id(digest).to_be_bytes::<32>(), digest.0, "Same bytes");
+        assert_ne!(
+            global_intent_id(digest),
+            global_intent_id(intent_digest(&test_intent(0), 8453, executor)),
+            "Source chain bound"
+        );
+    }
+
+    #[test]
+    fn test_ecrecover_input_layout() {
+        // digest | v (left-padded) | r | s
+        let digest = FixedBytes::<32>::from([0xaa; 32]);
+        let input = ecrecover_input(digest, &test_signature(0x22, 28)).expect("Valid signature");
+
+        assert_eq!(&input[..32], digest.as_slice(), "Digest first");
+        assert_eq!(&input[32..63], &[0u8; 31], "v padding");
+        assert_eq!(input[63], 28, "v");
+        assert_eq!(&input[64..96], &[0x11; 32], "r");
+        assert_eq!(&input[96..], &[0x22; 32], "s");
+    }
+
+    #[test]
+    fn test_ecrecover_input_normalizes_v() {
+        // 0/1 recovery ids are accepted as 27/28
+        let digest = FixedBytes::<32>::ZERO;
+
+        assert_eq!(ecrecover_input(digest, &test_signature(0x22, 1)).unwrap()[63], 28, "v=1");
+        assert!(ecrecover_input(digest, &test_signature(0x22, 29)).is_none(), "Bad v rejected");
+    }
+
+    #[test]
+    fn test_ecrecover_input_rejects_malformed() {
+        // Wrong length and malleable high-s signatures are rejected
+        let digest = FixedBytes::<32>::ZERO;
+
+        assert!(ecrecover_input(digest, &[0u8; 64]).is_none(), "Short signature");
+        assert!(ecrecover_input(digest, &test_signature(0xff, 27)).is_none(), "High s");
+    }
+
+    #[test]
+    fn test_recovered_address() {
+        // Precompile returns the signer left-padded, or zero on failure
+        let mut output = [0u8; 32];
+        assert!(recovered_address(&output).is_none(), "Zero signer rejected");
+        assert!(recovered_address(&[]).is_none(), "Empty output rejected");
+
+        output[12..].copy_from_slice(test_address(5).as_slice());
+        assert_eq!(recovered_address(&output), Some(test_address(5)), "Signer parsed");
+    }
+
+    #[test]
+    fn test_route_quote_type_hash() {
+        // Route oracles sign RouteQuote under the same Swoosh domain as intents
+        let expected = keccak256("RouteQuote(bytes32 intentHash,bytes32 routeHash,uint256 quotedAmountOut,uint256 expiry)");
+        let quote = RouteQuote {
+            intentHash: FixedBytes::ZERO,
+            routeHash: FixedBytes::ZERO,
+            quotedAmountOut: U256::ZERO,
+            expiry: U256::ZERO,
+        };
+
+        assert_eq!(quote.eip712_type_hash(), expected, "RouteQuote type hash");
+        assert_ne!(route_quote_digest(&quote, 42161, test_address(9)), intent_digest(&test_intent(0), 42161, test_address(9)), "Distinct from intents");
+    }
+
+    // Helper function to build a validator intent fixture
+    fn test_validator_intent(nonce: u64) -> ValidatorIntent {
+        ValidatorIntent {
+            user: test_address(1),
+            token: test_address(2),
+            amount: U256::from(1_000u64),
+            destinationChain: U256::from(10u64),
+            nonce: U256::from(nonce),
+            deadline: U256::from(2_000u64),
+        }
+    }
+
+    #[test]
+    fn test_validator_intent_type_hash() {
+        // Type string matches what wallets hash for eth_signTypedData
+        let expected = keccak256(concat!(
+            "ValidatorIntent(address user,address token,uint256 amount,uint256 destinationChain,",
+            "uint256 nonce,uint256 deadline)"
+        ));
+        assert_eq!(test_validator_intent(0).eip712_type_hash(), expected, "ValidatorIntent type hash");
+    }
+
+    #[test]
+    fn test_validator_intent_digest_binds_domain() {
+        // Digest changes with the nonce, chain and validator address
+        let digest = validator_intent_digest(&test_validator_intent(0), 42161, test_address(9));
+
+        assert_eq!(digest, validator_intent_digest(&test_validator_intent(0), 42161, test_address(9)), "Deterministic");
+        assert_ne!(digest, validator_intent_digest(&test_validator_intent(1), 42161, test_address(9)), "Nonce");
+        assert_ne!(digest, validator_intent_digest(&test_validator_intent(0), 1, test_address(9)), "Chain ID");
+        assert_ne!(digest, validator_intent_digest(&test_validator_intent(0), 42161, test_address(8)), "Validator");
+    }
+
+    // Helper function to build a registered intent fixture
+    fn test_registered_intent(nonce: u64) -> RegisteredIntent {
+        RegisteredIntent {
+            user: test_address(1),
+            token: test_address(2),
+            amount: U256::from(1_000u64),
+            destinationChain: U256::from(10u64),
+            recipient: test_address(3),
+            deadline: U256::from(2_000u64),
+            nonce: U256::from(nonce),
+        }
+    }
+
+    #[test]
+    fn test_registered_intent_type_hash() {
+        // Type string matches what wallets hash for eth_signTypedData
+        let expected = keccak256(concat!(
+            "RegisteredIntent(address user,address token,uint256 amount,uint256 destinationChain,",
+            "address recipient,uint256 deadline,uint256 nonce)"
+        ));
+        assert_eq!(test_registered_intent(0).eip712_type_hash(), expected, "RegisteredIntent type hash");
+    }
+
+    #[test]
+    fn test_registered_intent_key_binds_domain() {
+        // The registry key is the full digest: the same intent on another chain or validator gets another key
+        let key = registered_intent_digest(&test_registered_intent(0), 42161, test_address(9));
+
+        assert_ne!(key, test_registered_intent(0).eip712_hash_struct(), "Not the bare struct hash");
+        assert_ne!(key, registered_intent_digest(&test_registered_intent(1), 42161, test_address(9)), "Nonce");
+        assert_ne!(key, registered_intent_digest(&test_registered_intent(0), 1, test_address(9)), "Chain ID");
+        assert_ne!(key, registered_intent_digest(&test_registered_intent(0), 42161, test_address(8)), "Validator");
+    }
+
+    #[test]
+    fn test_registered_intent_status_expiry() {
+        // Only a pending intent expires, and only once its deadline has passed
+        let deadline = U256::from(2_000u64);
+        let pending = RegisteredIntentStatus::Pending;
+        assert_eq!(pending.at(deadline, deadline), RegisteredIntentStatus::Pending, "At the deadline");
+        assert_eq!(pending.at(deadline, deadline + U256::from(1)), RegisteredIntentStatus::Expired, "Past it");
+        let executed = RegisteredIntentStatus::Executed;
+        assert_eq!(executed.at(deadline, deadline + U256::from(1)), executed, "Executed stays executed");
+
+        assert_eq!(RegisteredIntentStatus::from_u8(3), Some(RegisteredIntentStatus::Cancelled), "Status code");
+        assert_eq!(RegisteredIntentStatus::from_u8(5), None, "Unknown status");
+    }
+
+    #[test]
+    fn test_route_hash_matches_encoded_steps() {
+        // Hash covers the steps exactly as submitted to the executor
+        let step = RouteStep {
+            stepType: 1,
+            adapter: Address::ZERO,
+            tokenIn: test_address(2),
+            tokenOut: test_address(2),
+            minAmountOut: U256::from(990u64),
+            deadline: U256::ZERO,
+            recipient: Address::ZERO,
+            data: Default::default(),
+        };
+        let steps = vec![step.clone()];
+
+        assert_eq!(route_hash(&steps), keccak256(steps.abi_encode()), "Hash of abi.encode(RouteStep[])");
+
+        let mut changed = step;
+        changed.minAmountOut = U256::from(1u64);
+        assert_ne!(route_hash(&steps), route_hash(&[changed]), "Any field change alters the hash");
+    }
+
+    #[test]
+    fn test_match_id_is_order_independent() {
+        // Both legs derive the same ID whichever intent is local
+        let a = FixedBytes::<32>::from([1u8; 32]);
+        let b = FixedBytes::<32>::from([2u8; 32]);
+        let amount = U256::from(500u64);
+
+        assert_eq!(match_id(a, b, amount), match_id(b, a, amount), "Symmetric");
+        assert_ne!(match_id(a, b, amount), match_id(a, b, U256::from(501u64)), "Amount bound");
+        assert_eq!(match_id(a, b, amount), keccak256((a, b, amount).abi_encode()), "Sorted hashes then amount");
+    }
+
+    #[test]
+    fn test_recipient_commitment() {
+        // Commitment binds both the recipient and the salt
+        let salt = FixedBytes::<32>::from([5u8; 32]);
+        let commitment = recipient_commitment(test_address(3), salt);
+
+        assert_eq!(commitment, keccak256((test_address(3), salt).abi_encode()), "ABI-encoded pair");
+        assert_ne!(commitment, recipient_commitment(test_address(4), salt), "Recipient bound");
+        assert_ne!(commitment, recipient_commitment(test_address(3), FixedBytes::ZERO), "Salt bound");
+    }
+}
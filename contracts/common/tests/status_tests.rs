@@ -0,0 +1,32 @@
+use stylus_sdk::alloy_primitives::{Address, U256};
+use swoosh_common::status::*;
+
+#[cfg(test)]
+mod status_tests {
+    use super::*;
+
+    #[test]
+    fn test_status_round_trip() {
+        let mut status = ProtocolStatus::new(StatusContract::RouteExecutor, "0.1.0", Address::from([1u8; 20]));
+        status.paused = true;
+        status.counters = [U256::from(42u64), U256::from(3u64)].into();
+
+        let decoded = decode_protocol_status(&encode_protocol_status(&status)).expect("Status decodes");
+        assert_eq!(StatusContract::from_u8(decoded.kind), Some(StatusContract::RouteExecutor), "Kind");
+        assert_eq!(decoded.version, "0.1.0", "Version");
+        assert_eq!(decoded.owner, Address::from([1u8; 20]), "Owner");
+        assert_eq!(decoded.pendingOwner, Address::ZERO, "No pending owner");
+        assert!(decoded.paused && !decoded.emergencyMode, "Flags");
+        assert_eq!(decoded.counters, vec![U256::from(42u64), U256::from(3u64)], "Counters");
+    }
+
+    #[test]
+    fn test_contract_kinds() {
+        assert_eq!(StatusContract::from_u8(1), Some(StatusContract::IntentValidator), "Validator");
+        assert_eq!(StatusContract::from_u8(7), Some(StatusContract::IntentRegistry), "Intent registry");
+        assert_eq!(StatusContract::from_u8(8), Some(StatusContract::LiquidityPool), "Liquidity pool");
+        assert_eq!(StatusContract::from_u8(9), None, "Unknown kind");
+        assert_eq!(StatusContract::from_u8(0), None, "Unset");
+        assert!(decode_protocol_status(&[1, 2, 3]).is_none(), "Malformed status rejected");
+    }
+}
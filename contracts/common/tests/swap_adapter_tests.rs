@@ -0,0 +1,128 @@
+use alloy_sol_types::SolValue;
+use stylus_sdk::alloy_primitives::{Address, U256};
+use swoosh_common::encoding::*;
+
+#[cfg(test)]
+mod swap_adapter_tests {
+    use super::*;
+
+    // Helper function to create test addresses
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    // Helper function to build a packed Uniswap V3 path
+    fn v3_path(tokens: &[Address], fee: u32) -> Vec<u8> {
+        let mut path = Vec::new();
+        for (i, token) in tokens.iter().enumerate() {
+            if i > 0 {
+                path.extend_from_slice(&fee.to_be_bytes()[1..]);
+            }
+            path.extend_from_slice(token.as_slice());
+        }
+        path
+    }
+
+    #[test]
+    fn test_v3_path_single_hop() {
+        // token | fee | token
+        let path = v3_path(&[test_address(1), test_address(2)], 500);
+
+        assert_eq!(path.len(), 43, "Single hop path length");
+        assert_eq!(
+            v3_path_endpoints(&path),
+            Some((test_address(1), test_address(2))),
+            "Endpoints of single hop"
+        );
+    }
+
+    #[test]
+    fn test_v3_path_multi_hop() {
+        // Endpoints ignore the intermediate token
+        let path = v3_path(&[test_address(1), test_address(3), test_address(2)], 3000);
+
+        assert_eq!(
+            v3_path_endpoints(&path),
+            Some((test_address(1), test_address(2))),
+            "Endpoints of multi hop"
+        );
+    }
+
+    #[test]
+    fn test_v3_path_malformed() {
+        // Partial hops and lone tokens are rejected
+        let path = v3_path(&[test_address(1), test_address(2)], 500);
+
+        assert!(v3_path_endpoints(&path[..42]).is_none(), "Truncated path rejected");
+        assert!(v3_path_endpoints(test_address(1).as_slice()).is_none(), "Single token rejected");
+        assert!(v3_path_endpoints(&[]).is_none(), "Empty path rejected");
+    }
+
+    #[test]
+    fn test_v3_path_hops() {
+        // Each hop pairs adjacent tokens with the fee between them
+        let path = v3_path(&[test_address(1), test_address(3), test_address(2)], 3000);
+
+        assert_eq!(
+            v3_path_hops(&path),
+            Some(vec![
+                (test_address(1), 3000, test_address(3)),
+                (test_address(3), 3000, test_address(2)),
+            ]),
+            "Two hops"
+        );
+        assert!(v3_path_hops(&path[..45]).is_none(), "Malformed path rejected");
+    }
+
+    #[test]
+    fn test_v3_fee_tiers_round_trip() {
+        // Tiers keep their order through packing
+        let fees = [500, 3000, 100];
+        let packed = pack_v3_fee_tiers(&fees).expect("Tiers pack");
+
+        assert_eq!(unpack_v3_fee_tiers(packed), fees.to_vec(), "Round trip");
+        assert_eq!(unpack_v3_fee_tiers(U256::ZERO), Vec::<u32>::new(), "No tiers configured");
+    }
+
+    #[test]
+    fn test_v3_fee_tiers_rejected() {
+        // Empty, oversized, zero and out-of-range tiers are rejected
+        assert!(pack_v3_fee_tiers(&[]).is_none(), "Empty list");
+        assert!(pack_v3_fee_tiers(&[100, 500, 3000, 10000, 20000]).is_none(), "Too many tiers");
+        assert!(pack_v3_fee_tiers(&[500, 0]).is_none(), "Zero tier");
+        assert!(pack_v3_fee_tiers(&[1_000_000]).is_none(), "Fee of 100%");
+    }
+
+    #[test]
+    fn test_sort_tokens() {
+        // Pools order their tokens by address
+        assert_eq!(sort_tokens(test_address(2), test_address(1)), (test_address(1), test_address(2)), "Sorted");
+        assert_eq!(sort_tokens(test_address(1), test_address(2)), (test_address(1), test_address(2)), "Already sorted");
+    }
+
+    #[test]
+    fn test_v2_path_direct_pair() {
+        // Empty data swaps through the direct pair
+        let path = v2_swap_path(&[], test_address(1), test_address(2));
+
+        assert_eq!(path, Some(vec![test_address(1), test_address(2)]), "Direct pair path");
+    }
+
+    #[test]
+    fn test_v2_path_encoded() {
+        // Encoded path must connect token in and token out
+        let hops = vec![test_address(1), test_address(3), test_address(2)];
+        let data = hops.abi_encode();
+
+        assert_eq!(
+            v2_swap_path(&data, test_address(1), test_address(2)),
+            Some(hops),
+            "Multi hop path accepted"
+        );
+        assert!(
+            v2_swap_path(&data, test_address(2), test_address(1)).is_none(),
+            "Reversed endpoints rejected"
+        );
+        assert!(v2_swap_path(&[1, 2, 3], test_address(1), test_address(2)).is_none(), "Garbage rejected");
+    }
+}
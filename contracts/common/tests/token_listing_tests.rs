@@ -0,0 +1,40 @@
+use swoosh_common::token_listing::*;
+
+#[cfg(test)]
+mod token_listing_tests {
+    use super::*;
+
+    #[test]
+    fn test_mode_from_u8() {
+        assert_eq!(TokenListingMode::from_u8(0), Some(TokenListingMode::Allowlist), "Allowlist");
+        assert_eq!(TokenListingMode::from_u8(2), Some(TokenListingMode::Blocklist), "Blocklist");
+        assert_eq!(TokenListingMode::from_u8(3), None, "Unknown mode");
+    }
+
+    #[test]
+    fn test_token_admission() {
+        // Blocks win everywhere; the allowlist is uncapped in every mode
+        use TokenListingMode::*;
+        assert_eq!(token_admission(Allowlist, true, false, false), TokenAdmission::Full, "Allowlisted");
+        assert_eq!(token_admission(Allowlist, false, false, true), TokenAdmission::Rejected, "Not listed");
+        assert_eq!(token_admission(Allowlist, true, true, true), TokenAdmission::Rejected, "Blocked");
+
+        assert_eq!(token_admission(Permissionless, false, false, true), TokenAdmission::Capped, "Listed");
+        assert_eq!(token_admission(Permissionless, false, false, false), TokenAdmission::Rejected, "Not listed");
+        assert_eq!(token_admission(Permissionless, true, false, false), TokenAdmission::Full, "Allowlisted");
+
+        assert_eq!(token_admission(Blocklist, false, false, false), TokenAdmission::Full, "Open");
+        assert_eq!(token_admission(Blocklist, false, true, true), TokenAdmission::Rejected, "Blocked");
+    }
+
+    #[test]
+    fn test_check_listing() {
+        // Criteria are checked in order, the blocklist first
+        assert_eq!(check_listing(false, Some(6), true), Ok(()), "Listable");
+        assert_eq!(check_listing(false, Some(MAX_LISTING_DECIMALS), true), Ok(()), "Most decimals");
+        assert_eq!(check_listing(true, Some(6), true), Err(ListingRejection::Blocked), "Blocked");
+        assert_eq!(check_listing(false, None, true), Err(ListingRejection::NotErc20), "Not an ERC-20");
+        assert_eq!(check_listing(false, Some(24), true), Err(ListingRejection::TooManyDecimals), "Decimals");
+        assert_eq!(check_listing(false, Some(18), false), Err(ListingRejection::NoPriceSource), "No price");
+    }
+}
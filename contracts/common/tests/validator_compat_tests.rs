@@ -0,0 +1,78 @@
+use stylus_sdk::alloy_primitives::U256;
+use swoosh_common::validator_compat::*;
+
+#[cfg(test)]
+mod validator_compat_tests {
+    use super::*;
+
+    // Report of a validator reading ERC-20 and NFT fields with every check
+    fn current_report() -> (U256, U256, U256) {
+        (
+            U256::from(VALIDATOR_VERSION),
+            U256::from(EXECUTOR_SUPPLIED_FIELDS),
+            U256::from(
+                FEATURE_ERC20
+                    | FEATURE_NFT
+                    | FEATURE_LANE_HEALTH
+                    | FEATURE_INTENT_BLOCKLIST
+                    | FEATURE_CONFIG_EPOCH
+                    | FEATURE_DELEGATION
+                    | FEATURE_WATCHTOWER
+                    | FEATURE_SIGNED_INTENT
+                    | FEATURE_INTENT_REGISTRY
+                    | FEATURE_EXECUTION_CHECK,
+            ),
+        )
+    }
+
+    #[test]
+    fn test_current_validator_accepted() {
+        // A validator built from this tree passes, as does one reading fewer fields
+        let (version, fields, features) = current_report();
+
+        assert_eq!(check_validator(version, fields, features), Ok(()), "Current validator");
+        assert_eq!(check_validator(version, U256::from(FIELD_USER), features), Ok(()), "Subset of fields");
+    }
+
+    #[test]
+    fn test_incompatible_validator_rejected() {
+        // Each mismatch is reported with its own reason
+        let (version, fields, features) = current_report();
+
+        assert_eq!(
+            check_validator(U256::from(VALIDATOR_VERSION + 1), fields, features),
+            Err(Incompatibility::Version),
+            "Version mismatch"
+        );
+        assert_eq!(
+            check_validator(version, fields | U256::from(FIELD_DEADLINE), features),
+            Err(Incompatibility::Fields),
+            "Reads a field the executor doesn't pass"
+        );
+        assert_eq!(
+            check_validator(version, fields, features ^ U256::from(FEATURE_LANE_HEALTH)),
+            Err(Incompatibility::Features),
+            "Missing lane health"
+        );
+        assert_eq!(
+            check_validator(version, fields, features ^ U256::from(FEATURE_INTENT_BLOCKLIST)),
+            Err(Incompatibility::Features),
+            "Missing intent blocklist"
+        );
+        assert_eq!(
+            check_validator(version, fields, features ^ U256::from(FEATURE_CONFIG_EPOCH)),
+            Err(Incompatibility::Features),
+            "Missing config epochs"
+        );
+        assert_eq!(
+            check_validator(version, fields, features ^ U256::from(FEATURE_DELEGATION)),
+            Err(Incompatibility::Features),
+            "Missing delegation"
+        );
+        assert_eq!(
+            check_validator(version, fields, features ^ U256::from(FEATURE_EXECUTION_CHECK)),
+            Err(Incompatibility::Features),
+            "Missing execution check"
+        );
+    }
+}
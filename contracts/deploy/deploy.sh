@@ -74,7 +74,7 @@ build_contracts() {
     echo -e "${YELLOW}Building contracts...${NC}"
     cd "$SCRIPT_DIR/.."
     
-    cargo build --release
+    cargo run -q -p swoosh-deploy -- build IntentValidator RouteExecutor SettlementVerifier
     
     echo -e "${GREEN}✓ Contracts built successfully${NC}"
     echo ""
@@ -89,22 +89,11 @@ deploy_contract() {
     
     cd "$SCRIPT_DIR/.."
     
-    # Deploy using cargo-stylus
-    # Note: cargo-stylus deploy command varies by version
-    # This is the general format - adjust based on your cargo-stylus version
-    local deploy_output
-    deploy_output=$(cargo stylus deploy \
-        --private-key "$PRIVATE_KEY" \
-        --endpoint "$RPC_URL" \
-        2>&1)
-    
-    # Extract contract address from output
+    # Each contract is its own crate; swoosh-deploy runs cargo-stylus in the
+    # crate directory and prints the deployed address
     local contract_address
-    contract_address=$(echo "$deploy_output" | grep -oE '0x[a-fA-F0-9]{40}' | head -1)
-    
-    if [ -z "$contract_address" ]; then
+    if ! contract_address=$(ARBITRUM_SEPOLIA_RPC="$RPC_URL" cargo run -q -p swoosh-deploy -- deploy "$contract_name"); then
         echo -e "${RED}Failed to deploy $contract_name${NC}"
-        echo "$deploy_output"
         exit 1
     fi
     
@@ -0,0 +1,68 @@
+//! storage-layout
+//!
+//! Usage:
+//!   storage-layout [CONTRACT...]          print layouts as JSON
+//!   storage-layout --write [CONTRACT...]  update the committed snapshots
+//!
+//! Built only with `--features storage-layout`.
+
+use std::env;
+use std::process::ExitCode;
+
+use swoosh_deploy::layout::{compatibility_errors, contract_layout, layout_json, parse_layout_json, snapshot_path};
+use swoosh_deploy::{find_contract, workspace_root, ContractCrate, CONTRACTS};
+
+fn main() -> ExitCode {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let write = args.iter().any(|arg| arg == "--write");
+    args.retain(|arg| arg != "--write");
+
+    let contracts: Vec<&ContractCrate> = if args.is_empty() {
+        CONTRACTS.iter().collect()
+    } else {
+        match args.iter().map(|name| find_contract(name).ok_or(name)).collect() {
+            Ok(contracts) => contracts,
+            Err(name) => {
+                eprintln!("error: unknown contract: {name}");
+                return ExitCode::FAILURE;
+            }
+        }
+    };
+
+    let root = workspace_root();
+    for contract in contracts {
+        let layout = match contract_layout(contract, &root) {
+            Ok(layout) => layout,
+            Err(err) => {
+                eprintln!("error: {err}");
+                return ExitCode::FAILURE;
+            }
+        };
+        let json = layout_json(contract.name, &layout);
+
+        if !write {
+            print!("{json}");
+            continue;
+        }
+
+        // Refuse to overwrite a snapshot with an incompatible layout
+        let path = snapshot_path(contract, &root);
+        if let Ok(previous) = std::fs::read_to_string(&path) {
+            let errors = parse_layout_json(&previous)
+                .map(|old| compatibility_errors(&old, &layout))
+                .unwrap_or_else(|err| vec![err]);
+            if !errors.is_empty() {
+                eprintln!("error: {} layout is not compatible:\n  {}", contract.name, errors.join("\n  "));
+                return ExitCode::FAILURE;
+            }
+        }
+
+        if let Err(err) = std::fs::create_dir_all(path.parent().expect("snapshot has a parent")).and_then(|_| std::fs::write(&path, json)) {
+            eprintln!("error: writing {}: {err}", path.display());
+            return ExitCode::FAILURE;
+        }
+        eprintln!("wrote {}", path.display());
+    }
+
+    ExitCode::SUCCESS
+}
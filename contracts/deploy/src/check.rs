@@ -0,0 +1,133 @@
+//! Size and Selector Checks
+//!
+//! Stylus rejects programs whose brotli-compressed wasm exceeds 24KB, and two
+//! public functions whose signatures hash to the same 4-byte selector make one
+//! of them unreachable. Both are checked per contract crate before deploying.
+
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+
+use alloy_primitives::keccak256;
+
+use crate::{build, ContractCrate};
+
+/// Maximum compressed program size accepted by Stylus
+pub const MAX_COMPRESSED_SIZE: usize = 24 * 1024;
+
+/// Brotli settings used by cargo-stylus when compressing for deployment
+const BROTLI_QUALITY: u32 = 11;
+const BROTLI_WINDOW: u32 = 22;
+
+/// Result of checking one contract
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractReport {
+    pub name: &'static str,
+    pub wasm_size: usize,
+    pub compressed_size: usize,
+    pub collisions: Vec<SelectorCollision>,
+}
+
+impl ContractReport {
+    /// Within the size limit and free of selector collisions
+    pub fn passes(&self) -> bool {
+        self.compressed_size <= MAX_COMPRESSED_SIZE && self.collisions.is_empty()
+    }
+}
+
+/// Two public functions sharing a selector
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelectorCollision {
+    pub selector: [u8; 4],
+    pub first: String,
+    pub second: String,
+}
+
+/// Brotli-compressed size of a wasm binary, as cargo-stylus measures it
+pub fn compressed_size(wasm: &[u8]) -> usize {
+    let mut compressed = Vec::new();
+    {
+        let mut writer = brotli::CompressorWriter::new(&mut compressed, 4096, BROTLI_QUALITY, BROTLI_WINDOW);
+        writer.write_all(wasm).expect("writing to a Vec cannot fail");
+    }
+    compressed.len()
+}
+
+/// Canonical signatures (`name(type,...)`) of the functions in an exported
+/// Solidity interface
+pub fn function_signatures(abi: &str) -> Vec<String> {
+    abi.lines()
+        .filter_map(|line| line.trim().strip_prefix("function "))
+        .filter_map(|decl| {
+            let open = decl.find('(')?;
+            // First closing paren ends the parameters; return values are not part of the signature
+            let close = open + decl[open..].find(')')?;
+            let name = decl[..open].trim();
+
+            let params: Vec<&str> = decl[open + 1..close]
+                .split(',')
+                .filter_map(|param| param.split_whitespace().next())
+                .collect();
+
+            Some(format!("{name}({})", params.join(",")))
+        })
+        .collect()
+}
+
+/// 4-byte selector of a canonical function signature
+pub fn selector(signature: &str) -> [u8; 4] {
+    let hash = keccak256(signature.as_bytes());
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// Distinct signatures that share a selector
+pub fn selector_collisions(signatures: &[String]) -> Vec<SelectorCollision> {
+    let mut collisions = Vec::new();
+
+    for (i, first) in signatures.iter().enumerate() {
+        for second in &signatures[i + 1..] {
+            if first != second && selector(first) == selector(second) {
+                collisions.push(SelectorCollision {
+                    selector: selector(first),
+                    first: first.clone(),
+                    second: second.clone(),
+                });
+            }
+        }
+    }
+
+    collisions
+}
+
+/// Solidity interface of a contract, via its export-abi binary
+pub fn export_abi(contract: &ContractCrate, workspace_root: &Path) -> Result<String, String> {
+    let output = Command::new("cargo")
+        .current_dir(workspace_root)
+        .args(["run", "-q", "-p", contract.package, "--bin", contract.package, "--features", "export-abi"])
+        .output()
+        .map_err(|err| format!("failed to run cargo: {err}"))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "exporting the ABI of {} failed:\n{}",
+            contract.name,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Build a contract and check its compressed size and selectors
+pub fn check(contract: &ContractCrate, workspace_root: &Path) -> Result<ContractReport, String> {
+    let wasm_path = build(contract, workspace_root)?;
+    let wasm = std::fs::read(&wasm_path).map_err(|err| format!("reading {}: {err}", wasm_path.display()))?;
+    let signatures = function_signatures(&export_abi(contract, workspace_root)?);
+
+    Ok(ContractReport {
+        name: contract.name,
+        wasm_size: wasm.len(),
+        compressed_size: compressed_size(&wasm),
+        collisions: selector_collisions(&signatures),
+    })
+}
@@ -0,0 +1,180 @@
+//! Deterministic Deployment
+//!
+//! Deploys contracts through Create2Factory so receivers and verifiers share
+//! one address across chains. The address depends only on the factory, the
+//! deploying account, the salt and the init code cargo-stylus generates, so it
+//! can be computed before anything is sent and checked on every chain after.
+//! Transactions go through Foundry's `cast`, as in the deploy scripts.
+
+use std::path::Path;
+use std::process::Command;
+
+use alloy_primitives::{keccak256, Address, B256};
+
+use crate::ContractCrate;
+
+/// Salt from the command line: 32 bytes of hex, or a label hashed with keccak256
+pub fn parse_salt(salt: &str) -> B256 {
+    salt.parse::<B256>().unwrap_or_else(|_| keccak256(salt.as_bytes()))
+}
+
+/// CREATE2 salt Create2Factory uses for a deployer's salt,
+/// `keccak256(abi.encode(deployer, salt))`
+pub fn deployment_salt(deployer: Address, salt: B256) -> B256 {
+    let mut encoded = [0u8; 64];
+    encoded[12..32].copy_from_slice(deployer.as_slice());
+    encoded[32..].copy_from_slice(salt.as_slice());
+    keccak256(encoded)
+}
+
+/// Address Create2Factory deploys `init_code` to for `deployer` and `salt`
+pub fn predict_address(factory: Address, deployer: Address, salt: B256, init_code: &[u8]) -> Address {
+    factory.create2_from_code(deployment_salt(deployer, salt), init_code)
+}
+
+/// Decode `0x`-prefixed or bare hex
+pub fn parse_hex(hex: &str) -> Result<Vec<u8>, String> {
+    let hex = hex.trim();
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    if !hex.len().is_multiple_of(2) {
+        return Err("odd-length hex".to_string());
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| format!("invalid hex at offset {i}")))
+        .collect()
+}
+
+/// Init code cargo-stylus would deploy for a contract
+pub fn init_code(contract: &ContractCrate, workspace_root: &Path) -> Result<Vec<u8>, String> {
+    let output = Command::new("cargo")
+        .current_dir(workspace_root.join(contract.dir))
+        .args(["stylus", "get-initcode"])
+        .output()
+        .map_err(|err| format!("failed to run cargo-stylus: {err}"))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "generating init code for {} failed:\n{}",
+            contract.name,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    parse_hex(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Run `cast` and return its trimmed stdout
+fn cast(args: &[&str]) -> Result<String, String> {
+    let output = Command::new("cast")
+        .args(args)
+        .output()
+        .map_err(|err| format!("failed to run cast: {err}"))?;
+
+    if !output.status.success() {
+        return Err(format!("cast {} failed:\n{}", args[0], String::from_utf8_lossy(&output.stderr)));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Account a private key deploys from
+pub fn deployer_address(private_key: &str) -> Result<Address, String> {
+    let address = cast(&["wallet", "address", "--private-key", private_key])?;
+    address.parse().map_err(|_| format!("cast returned an invalid address: {address}"))
+}
+
+/// Whether any code is deployed at an address
+fn has_code(address: Address, endpoint: &str) -> Result<bool, String> {
+    let code = cast(&["code", &address.to_string(), "--rpc-url", endpoint])?;
+    Ok(!parse_hex(&code)?.is_empty())
+}
+
+/// Deploy a contract through the factory and activate it, returning the
+/// predicted address once code is found there
+pub fn deploy_create2(
+    contract: &ContractCrate,
+    workspace_root: &Path,
+    endpoint: &str,
+    private_key: &str,
+    factory: Address,
+    salt: B256,
+) -> Result<Address, String> {
+    let deployer = deployer_address(private_key)?;
+    let code = init_code(contract, workspace_root)?;
+    let address = predict_address(factory, deployer, salt, &code);
+
+    if has_code(address, endpoint)? {
+        return Err(format!("{} is already deployed at {address}", contract.name));
+    }
+
+    let code_hex = format!("0x{}", code.iter().map(|byte| format!("{byte:02x}")).collect::<String>());
+    cast(&[
+        "send",
+        &factory.to_string(),
+        "deploy(bytes32,bytes)",
+        &salt.to_string(),
+        &code_hex,
+        "--rpc-url",
+        endpoint,
+        "--private-key",
+        private_key,
+    ])?;
+
+    if !has_code(address, endpoint)? {
+        return Err(format!("no code at the predicted address {address} for {}", contract.name));
+    }
+
+    let output = Command::new("cargo")
+        .current_dir(workspace_root.join(contract.dir))
+        .args(["stylus", "activate", "--address", &address.to_string(), "--endpoint", endpoint])
+        .args(["--private-key", private_key])
+        .output()
+        .map_err(|err| format!("failed to run cargo-stylus: {err}"))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "activating {} at {address} failed:\n{}",
+            contract.name,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    Ok(address)
+}
+
+/// Check that the predicted address on an endpoint holds code the factory
+/// deployed for `deployer`
+///
+/// The factory records who deployed each address, and a CREATE2 address
+/// commits to the init code, so a matching record means the same code was
+/// deployed under the same salt.
+pub fn verify_address(
+    contract: &ContractCrate,
+    workspace_root: &Path,
+    endpoint: &str,
+    factory: Address,
+    deployer: Address,
+    salt: B256,
+) -> Result<Address, String> {
+    let address = predict_address(factory, deployer, salt, &init_code(contract, workspace_root)?);
+
+    if !has_code(address, endpoint)? {
+        return Err(format!("{} is not deployed at {address}", contract.name));
+    }
+
+    let recorded = cast(&[
+        "call",
+        &factory.to_string(),
+        "deployerOf(address)(address)",
+        &address.to_string(),
+        "--rpc-url",
+        endpoint,
+    ])?;
+    if recorded.parse::<Address>().ok() != Some(deployer) {
+        return Err(format!("{address} was not deployed by {deployer} through {factory} (recorded {recorded})"));
+    }
+
+    Ok(address)
+}
@@ -0,0 +1,219 @@
+//! Storage Layout
+//!
+//! Computes each contract's storage layout from its `#[storage]` struct, using
+//! the same slot assignment as the Stylus `#[storage]` macro: fields are laid
+//! out in declaration order, value types smaller than a word are packed into
+//! the current slot while they fit, and mappings take a whole slot of their
+//! own. Layouts are snapshotted under `deploy/storage-layouts/` so a change
+//! that moves an existing field is caught before an upgrade corrupts state.
+
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use crate::ContractCrate;
+
+/// One storage field and where it lives
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageEntry {
+    pub label: String,
+    pub ty: String,
+    pub slot: u64,
+    /// Byte offset within the slot, counted from the low-order end as in
+    /// Solidity's storage layout output
+    pub offset: u8,
+    pub bytes: u8,
+}
+
+/// Bytes a storage type occupies within a slot
+///
+/// Mappings, vectors and dynamic bytes own a full slot and keep their data at
+/// hashed locations.
+pub fn slot_bytes(ty: &str) -> Option<u8> {
+    let head = ty.split('<').next().unwrap_or(ty).trim();
+
+    let bytes = match head {
+        "StorageBool" | "StorageU8" | "StorageI8" => 1,
+        "StorageU16" | "StorageI16" => 2,
+        "StorageU32" | "StorageI32" => 4,
+        "StorageU64" | "StorageI64" => 8,
+        "StorageU128" | "StorageI128" => 16,
+        "StorageAddress" | "StorageU160" => 20,
+        "StorageU256" | "StorageI256" | "StorageB256" => 32,
+        "StorageMap" | "StorageVec" | "StorageBytes" | "StorageString" => 32,
+        "StorageFixedBytes" => {
+            let width = ty[ty.find('<')? + 1..ty.rfind('>')?].trim();
+            return width.parse().ok().filter(|width| (1..=32).contains(width));
+        }
+        _ => return None,
+    };
+
+    Some(bytes)
+}
+
+/// Fields `(name, type)` of the first `#[storage]` struct in a source file
+pub fn storage_fields(source: &str) -> Option<(String, Vec<(String, String)>)> {
+    let after_attr = &source[source.find("#[storage]")?..];
+    let decl = &after_attr[after_attr.find("pub struct ")? + "pub struct ".len()..];
+    let name = decl[..decl.find(|c: char| !c.is_alphanumeric() && c != '_')?].to_string();
+
+    let body_start = decl.find('{')? + 1;
+    let body_end = body_start + decl[body_start..].find("\n}")?;
+
+    // Drop comments and attributes, then split on commas outside generics
+    let body: String = decl[body_start..body_end]
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with("//") && !line.starts_with("#["))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut fields = Vec::new();
+    let mut depth = 0usize;
+    let mut current = String::new();
+    for c in body.chars().chain(std::iter::once(',')) {
+        match c {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                if let Some((field, ty)) = current.split_once(':') {
+                    let field = field.trim().trim_start_matches("pub ").trim();
+                    fields.push((field.to_string(), ty.split_whitespace().collect::<Vec<_>>().join(" ")));
+                }
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+
+    Some((name, fields))
+}
+
+/// Assign slots and offsets to storage fields
+pub fn compute_layout(fields: &[(String, String)]) -> Result<Vec<StorageEntry>, String> {
+    let mut slot = 0u64;
+    let mut space = 32u8;
+    let mut entries = Vec::with_capacity(fields.len());
+
+    for (label, ty) in fields {
+        let bytes = slot_bytes(ty).ok_or_else(|| format!("unknown storage type `{ty}` for field `{label}`"))?;
+
+        if space < bytes {
+            slot += 1;
+            space = 32;
+        }
+        space -= bytes;
+
+        entries.push(StorageEntry {
+            label: label.clone(),
+            ty: ty.clone(),
+            slot,
+            offset: 32 - space - bytes,
+            bytes,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Storage layout of a contract crate, read from its source
+pub fn contract_layout(contract: &ContractCrate, workspace_root: &Path) -> Result<Vec<StorageEntry>, String> {
+    let path = workspace_root.join(contract.dir).join("src").join("lib.rs");
+    let source = std::fs::read_to_string(&path).map_err(|err| format!("reading {}: {err}", path.display()))?;
+    let (name, fields) = storage_fields(&source).ok_or_else(|| format!("no #[storage] struct in {}", path.display()))?;
+
+    if name != contract.name {
+        return Err(format!("{} declares storage struct {name}", contract.name));
+    }
+
+    compute_layout(&fields)
+}
+
+/// Layout as JSON, one entry per line so snapshot diffs stay readable
+pub fn layout_json(contract_name: &str, entries: &[StorageEntry]) -> String {
+    let mut json = format!("{{\n  \"contract\": \"{contract_name}\",\n  \"storage\": [\n");
+    for (i, entry) in entries.iter().enumerate() {
+        let separator = if i + 1 == entries.len() { "" } else { "," };
+        writeln!(
+            json,
+            "    {{ \"label\": \"{}\", \"type\": \"{}\", \"slot\": {}, \"offset\": {}, \"bytes\": {} }}{separator}",
+            entry.label, entry.ty, entry.slot, entry.offset, entry.bytes
+        )
+        .expect("writing to a String cannot fail");
+    }
+    json.push_str("  ]\n}\n");
+    json
+}
+
+/// Parse a layout snapshot written by `layout_json`
+pub fn parse_layout_json(json: &str) -> Result<Vec<StorageEntry>, String> {
+    json.lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with("{ \"label\""))
+        .map(|line| {
+            let field = |key: &str| -> Result<&str, String> {
+                let start = line
+                    .find(&format!("\"{key}\": "))
+                    .ok_or_else(|| format!("missing {key} in `{line}`"))?
+                    + key.len()
+                    + 4;
+                let rest = &line[start..];
+                let value = if let Some(quoted) = rest.strip_prefix('"') {
+                    &quoted[..quoted.find('"').unwrap_or(quoted.len())]
+                } else {
+                    rest[..rest.find([',', ' ', '}']).unwrap_or(rest.len())].trim()
+                };
+                Ok(value)
+            };
+            let number = |key: &str| -> Result<u64, String> {
+                field(key)?.parse().map_err(|_| format!("bad {key} in `{line}`"))
+            };
+
+            Ok(StorageEntry {
+                label: field("label")?.to_string(),
+                ty: field("type")?.to_string(),
+                slot: number("slot")?,
+                offset: number("offset")? as u8,
+                bytes: number("bytes")? as u8,
+            })
+        })
+        .collect()
+}
+
+/// Reasons a new layout would corrupt state written under the old one
+///
+/// Every existing field must keep its slot, offset, size and type. Appending
+/// fields is compatible; renaming a field in place is allowed.
+pub fn compatibility_errors(old: &[StorageEntry], new: &[StorageEntry]) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    for (i, before) in old.iter().enumerate() {
+        match new.get(i) {
+            None => errors.push(format!("field `{}` (slot {}) was removed", before.label, before.slot)),
+            Some(after) if (after.slot, after.offset, after.bytes) != (before.slot, before.offset, before.bytes) => {
+                errors.push(format!(
+                    "field `{}` moved from slot {} offset {} to slot {} offset {}",
+                    before.label, before.slot, before.offset, after.slot, after.offset
+                ))
+            }
+            Some(after) if after.ty != before.ty => errors.push(format!(
+                "field `{}` at slot {} changed type from {} to {}",
+                before.label, before.slot, before.ty, after.ty
+            )),
+            Some(_) => {}
+        }
+    }
+
+    errors
+}
+
+/// Directory holding the committed layout snapshots
+pub fn snapshot_dir(workspace_root: &Path) -> PathBuf {
+    workspace_root.join("deploy").join("storage-layouts")
+}
+
+/// Path of a contract's layout snapshot
+pub fn snapshot_path(contract: &ContractCrate, workspace_root: &Path) -> PathBuf {
+    snapshot_dir(workspace_root).join(format!("{}.json", contract.name))
+}
@@ -0,0 +1,136 @@
+//! Swoosh Deployment
+//!
+//! Manifest of the contract crates in the workspace and helpers to build and
+//! deploy each one with cargo-stylus. Every contract crate compiles to its own
+//! wasm, so contracts can be rebuilt, size-checked and redeployed independently.
+//! `check` verifies each wasm against the Stylus size limit and its public
+//! interface for selector collisions; `layout` tracks storage slot
+//! assignments across upgrades; `create2` deploys through Create2Factory for
+//! the same address on every chain.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+pub mod check;
+pub mod create2;
+pub mod layout;
+
+/// Rust target the contracts are built for
+pub const WASM_TARGET: &str = "wasm32-unknown-unknown";
+
+/// One deployable contract crate
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ContractCrate {
+    /// Contract name used in scripts and deployed_addresses.json
+    pub name: &'static str,
+    /// Cargo package name
+    pub package: &'static str,
+    /// Crate directory relative to the workspace root
+    pub dir: &'static str,
+}
+
+impl ContractCrate {
+    /// File name of the release wasm
+    pub fn wasm_file(&self) -> String {
+        format!("{}.wasm", self.package.replace('-', "_"))
+    }
+
+    /// Path of the release wasm under the workspace target directory
+    pub fn wasm_path(&self, workspace_root: &Path) -> PathBuf {
+        workspace_root
+            .join("target")
+            .join(WASM_TARGET)
+            .join("release")
+            .join(self.wasm_file())
+    }
+}
+
+/// All contract crates, in deployment order
+///
+/// The CREATE2 factory comes first so later contracts can be deployed through
+/// it. Core contracts follow since adapters are initialized with the
+/// RouteExecutor and SettlementVerifier addresses.
+pub const CONTRACTS: &[ContractCrate] = &[
+    ContractCrate { name: "Create2Factory", package: "swoosh-factory", dir: "factory" },
+    ContractCrate { name: "IntentValidator", package: "swoosh-validator", dir: "validator" },
+    ContractCrate { name: "SolverRegistry", package: "swoosh-registry", dir: "registry" },
+    ContractCrate { name: "RouteExecutor", package: "swoosh-executor", dir: "executor" },
+    ContractCrate { name: "SettlementVerifier", package: "swoosh-verifier", dir: "verifier" },
+    ContractCrate { name: "IntentRegistry", package: "swoosh-intent-registry", dir: "intent-registry" },
+    ContractCrate { name: "SwooshReceiver", package: "swoosh-receiver", dir: "receiver" },
+    ContractCrate { name: "LiquidityPool", package: "swoosh-pool", dir: "pool" },
+    ContractCrate { name: "SwooshLens", package: "swoosh-lens", dir: "lens" },
+    ContractCrate { name: "InsuranceFund", package: "swoosh-insurance", dir: "insurance" },
+    ContractCrate { name: "AcrossAdapter", package: "swoosh-across-adapter", dir: "adapters/across" },
+    ContractCrate { name: "CcipAdapter", package: "swoosh-ccip-adapter", dir: "adapters/ccip" },
+    ContractCrate { name: "CctpAdapter", package: "swoosh-cctp-adapter", dir: "adapters/cctp" },
+    ContractCrate { name: "DlnAdapter", package: "swoosh-debridge-adapter", dir: "adapters/debridge" },
+    ContractCrate { name: "HyperlaneAdapter", package: "swoosh-hyperlane-adapter", dir: "adapters/hyperlane" },
+    ContractCrate { name: "LayerZeroAdapter", package: "swoosh-layerzero-adapter", dir: "adapters/layerzero" },
+    ContractCrate { name: "StargateAdapter", package: "swoosh-stargate-adapter", dir: "adapters/stargate" },
+    ContractCrate { name: "WormholeAdapter", package: "swoosh-wormhole-adapter", dir: "adapters/wormhole" },
+    ContractCrate { name: "CamelotAdapter", package: "swoosh-camelot-adapter", dir: "adapters/camelot" },
+    ContractCrate { name: "CurveAdapter", package: "swoosh-curve-adapter", dir: "adapters/curve" },
+    ContractCrate { name: "SushiAdapter", package: "swoosh-sushi-adapter", dir: "adapters/sushi" },
+    ContractCrate { name: "UniswapV3Adapter", package: "swoosh-uniswap-v3-adapter", dir: "adapters/uniswap-v3" },
+];
+
+/// Look up a contract by name or package
+pub fn find_contract(name: &str) -> Option<&'static ContractCrate> {
+    CONTRACTS
+        .iter()
+        .find(|contract| contract.name.eq_ignore_ascii_case(name) || contract.package == name)
+}
+
+/// Workspace root (parent of this crate)
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("deploy crate lives inside the workspace")
+        .to_path_buf()
+}
+
+/// Build one contract's release wasm
+pub fn build(contract: &ContractCrate, workspace_root: &Path) -> Result<PathBuf, String> {
+    let status = Command::new("cargo")
+        .current_dir(workspace_root)
+        .args(["build", "--release", "--target", WASM_TARGET, "-p", contract.package, "--lib"])
+        .status()
+        .map_err(|err| format!("failed to run cargo: {err}"))?;
+
+    if !status.success() {
+        return Err(format!("building {} failed", contract.name));
+    }
+
+    Ok(contract.wasm_path(workspace_root))
+}
+
+/// Deploy one contract with cargo-stylus, returning its address
+pub fn deploy(contract: &ContractCrate, workspace_root: &Path, endpoint: &str, private_key: &str) -> Result<String, String> {
+    let output = Command::new("cargo")
+        .current_dir(workspace_root.join(contract.dir))
+        .args(["stylus", "deploy", "--endpoint", endpoint, "--private-key", private_key])
+        .output()
+        .map_err(|err| format!("failed to run cargo-stylus: {err}"))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    if !output.status.success() {
+        return Err(format!("deploying {} failed:\n{stdout}{stderr}", contract.name));
+    }
+
+    parse_deployed_address(&stdout)
+        .or_else(|| parse_deployed_address(&stderr))
+        .ok_or_else(|| format!("no address in cargo-stylus output for {}:\n{stdout}{stderr}", contract.name))
+}
+
+/// First `0x`-prefixed 20-byte hex address in cargo-stylus output
+pub fn parse_deployed_address(output: &str) -> Option<String> {
+    output.match_indices("0x").find_map(|(start, _)| {
+        let hex = output.get(start + 2..start + 42)?;
+        let boundary = output[start + 42..].chars().next().is_none_or(|c| !c.is_ascii_hexdigit());
+
+        (hex.chars().all(|c| c.is_ascii_hexdigit()) && boundary).then(|| output[start..start + 42].to_string())
+    })
+}
@@ -0,0 +1,160 @@
+//! swoosh-deploy
+//!
+//! Usage:
+//!   swoosh-deploy list
+//!   swoosh-deploy build [CONTRACT...]
+//!   swoosh-deploy check [CONTRACT...]
+//!   swoosh-deploy deploy CONTRACT [SALT]
+//!   swoosh-deploy address CONTRACT SALT
+//!   swoosh-deploy verify-address CONTRACT SALT
+//!
+//! `deploy` reads PRIVATE_KEY and ARBITRUM_SEPOLIA_RPC from the environment
+//! and prints only the deployed address, so scripts can capture it. With a
+//! SALT it deploys through the Create2Factory at SWOOSH_FACTORY instead, so
+//! the address is the same on every chain. `address` prints that address
+//! without deploying, and `verify-address` checks the contract is deployed
+//! there on the endpoint. Both take the deployer from DEPLOYER_ADDRESS, or
+//! from PRIVATE_KEY when it is unset. A SALT is 32 bytes of hex or a label.
+
+use std::env;
+use std::process::ExitCode;
+
+use alloy_primitives::Address;
+use swoosh_deploy::check::{check, MAX_COMPRESSED_SIZE};
+use swoosh_deploy::create2::{deploy_create2, deployer_address, init_code, parse_salt, predict_address, verify_address};
+use swoosh_deploy::{build, deploy, find_contract, workspace_root, ContractCrate, CONTRACTS};
+
+const DEFAULT_RPC: &str = "https://sepolia-rollup.arbitrum.io/rpc";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("list") => {
+            for contract in CONTRACTS {
+                println!("{:<20} {:<28} {}", contract.name, contract.package, contract.dir);
+            }
+            Ok(())
+        }
+        Some("build") => selected(&args[1..]).and_then(|contracts| {
+            let root = workspace_root();
+            for contract in contracts {
+                let wasm = build(contract, &root)?;
+                eprintln!("built {} -> {}", contract.name, wasm.display());
+            }
+            Ok(())
+        }),
+        Some("check") => selected(&args[1..]).and_then(|contracts| {
+            let root = workspace_root();
+            let mut failed = Vec::new();
+            for contract in contracts {
+                let report = check(contract, &root)?;
+                println!(
+                    "{:<20} {:>7} bytes wasm {:>6} bytes compressed ({:.1}% of {})",
+                    report.name,
+                    report.wasm_size,
+                    report.compressed_size,
+                    report.compressed_size as f64 * 100.0 / MAX_COMPRESSED_SIZE as f64,
+                    MAX_COMPRESSED_SIZE,
+                );
+                for collision in &report.collisions {
+                    println!(
+                        "  selector collision 0x{}: {} and {}",
+                        hex(&collision.selector),
+                        collision.first,
+                        collision.second
+                    );
+                }
+                if !report.passes() {
+                    failed.push(report.name);
+                }
+            }
+            if failed.is_empty() {
+                Ok(())
+            } else {
+                Err(format!("checks failed for {}", failed.join(", ")))
+            }
+        }),
+        Some("deploy") => match (args.get(1).map(|name| find_contract(name)), args.get(2)) {
+            (Some(Some(contract)), salt) => private_key().and_then(|key| match salt {
+                Some(salt) => factory().and_then(|factory| {
+                    deploy_create2(contract, &workspace_root(), &endpoint(), &key, factory, parse_salt(salt))
+                        .map(|address| println!("{address}"))
+                }),
+                None => deploy(contract, &workspace_root(), &endpoint(), &key).map(|address| println!("{address}")),
+            }),
+            (Some(None), _) => Err(format!("unknown contract: {}", args[1])),
+            (None, _) => Err("usage: swoosh-deploy deploy CONTRACT [SALT]".to_string()),
+        },
+        Some("address") => match (args.get(1).map(|name| find_contract(name)), args.get(2)) {
+            (Some(Some(contract)), Some(salt)) => factory().and_then(|factory| {
+                let deployer = deployer()?;
+                let code = init_code(contract, &workspace_root())?;
+                println!("{}", predict_address(factory, deployer, parse_salt(salt), &code));
+                Ok(())
+            }),
+            (Some(None), _) => Err(format!("unknown contract: {}", args[1])),
+            _ => Err("usage: swoosh-deploy address CONTRACT SALT".to_string()),
+        },
+        Some("verify-address") => match (args.get(1).map(|name| find_contract(name)), args.get(2)) {
+            (Some(Some(contract)), Some(salt)) => factory().and_then(|factory| {
+                let address =
+                    verify_address(contract, &workspace_root(), &endpoint(), factory, deployer()?, parse_salt(salt))?;
+                println!("{} verified at {address}", contract.name);
+                Ok(())
+            }),
+            (Some(None), _) => Err(format!("unknown contract: {}", args[1])),
+            _ => Err("usage: swoosh-deploy verify-address CONTRACT SALT".to_string()),
+        },
+        _ => Err("usage: swoosh-deploy <list|build|check|deploy|address|verify-address> [CONTRACT...]".to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// RPC endpoint to deploy to
+fn endpoint() -> String {
+    env::var("ARBITRUM_SEPOLIA_RPC").unwrap_or_else(|_| DEFAULT_RPC.to_string())
+}
+
+/// Deploying private key from PRIVATE_KEY
+fn private_key() -> Result<String, String> {
+    env::var("PRIVATE_KEY").map_err(|_| "PRIVATE_KEY is not set".to_string())
+}
+
+/// Create2Factory address from SWOOSH_FACTORY
+fn factory() -> Result<Address, String> {
+    let factory = env::var("SWOOSH_FACTORY").map_err(|_| "SWOOSH_FACTORY is not set".to_string())?;
+    factory.parse().map_err(|_| format!("SWOOSH_FACTORY is not an address: {factory}"))
+}
+
+/// Deploying account, from DEPLOYER_ADDRESS or PRIVATE_KEY
+fn deployer() -> Result<Address, String> {
+    match env::var("DEPLOYER_ADDRESS") {
+        Ok(deployer) => deployer.parse().map_err(|_| format!("DEPLOYER_ADDRESS is not an address: {deployer}")),
+        Err(_) => deployer_address(&private_key()?),
+    }
+}
+
+/// Contracts named on the command line, or all of them
+fn selected(names: &[String]) -> Result<Vec<&'static ContractCrate>, String> {
+    if names.is_empty() {
+        return Ok(CONTRACTS.iter().collect());
+    }
+
+    names
+        .iter()
+        .map(|name| find_contract(name).ok_or_else(|| format!("unknown contract: {name}")))
+        .collect()
+}
+
+/// Lowercase hex without prefix
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
@@ -0,0 +1,63 @@
+use alloy_primitives::{keccak256, Address, B256};
+use swoosh_deploy::create2::*;
+
+#[cfg(test)]
+mod create2_tests {
+    use super::*;
+
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    #[test]
+    fn test_parse_salt() {
+        // Hex salts are used as-is; anything else is a label hashed with keccak256
+        let hex = "0x0000000000000000000000000000000000000000000000000000000000000001";
+
+        assert_eq!(parse_salt(hex), B256::with_last_byte(1), "Hex salt");
+        assert_eq!(parse_salt("swoosh-v1"), keccak256("swoosh-v1"), "Label salt");
+    }
+
+    #[test]
+    fn test_deployment_salt_matches_abi_encode() {
+        // keccak256(abi.encode(deployer, salt)): address left-padded to a word
+        let mut encoded = vec![0u8; 12];
+        encoded.extend_from_slice(test_address(1).as_slice());
+        encoded.extend_from_slice(&[2u8; 32]);
+
+        assert_eq!(deployment_salt(test_address(1), B256::from([2u8; 32])), keccak256(&encoded), "Packed salt");
+    }
+
+    #[test]
+    fn test_predict_address() {
+        // Standard CREATE2 over the factory with the deployer-bound salt
+        let factory = test_address(7);
+        let salt = B256::from([2u8; 32]);
+        let code = [0x60u8, 0x00, 0xf3];
+
+        assert_eq!(
+            predict_address(factory, test_address(1), salt, &code),
+            factory.create2(deployment_salt(test_address(1), salt), keccak256(code)),
+            "CREATE2 address"
+        );
+        assert_ne!(
+            predict_address(factory, test_address(1), salt, &code),
+            predict_address(factory, test_address(2), salt, &code),
+            "Deployer changes the address"
+        );
+        assert_ne!(
+            predict_address(factory, test_address(1), salt, &code),
+            predict_address(test_address(8), test_address(1), salt, &code),
+            "Factory changes the address"
+        );
+    }
+
+    #[test]
+    fn test_parse_hex() {
+        // Prefix and surrounding whitespace are optional; odd or invalid digits fail
+        assert_eq!(parse_hex("0xdeadBEEF\n"), Ok(vec![0xde, 0xad, 0xbe, 0xef]), "Prefixed");
+        assert_eq!(parse_hex("00ff"), Ok(vec![0x00, 0xff]), "Bare");
+        assert!(parse_hex("0xabc").is_err(), "Odd length");
+        assert!(parse_hex("zz").is_err(), "Invalid digit");
+    }
+}
@@ -0,0 +1,61 @@
+use swoosh_deploy::*;
+
+#[cfg(test)]
+mod manifest_tests {
+    use super::*;
+
+    #[test]
+    fn test_every_contract_crate_exists() {
+        // Manifest entries point at real workspace members
+        let root = workspace_root();
+
+        for contract in CONTRACTS {
+            let manifest = std::fs::read_to_string(root.join(contract.dir).join("Cargo.toml"))
+                .unwrap_or_else(|_| panic!("{} has no Cargo.toml", contract.name));
+            assert!(manifest.contains(&format!("name = \"{}\"", contract.package)), "{} package name", contract.name);
+        }
+    }
+
+    #[test]
+    fn test_core_contracts_deploy_first() {
+        // Adapters are initialized with core addresses, so they come last
+        let names: Vec<&str> = CONTRACTS.iter().map(|contract| contract.name).collect();
+
+        assert_eq!(names[0], "Create2Factory", "Factory before anything deployed through it");
+        assert_eq!(
+            &names[1..5],
+            ["IntentValidator", "SolverRegistry", "RouteExecutor", "SettlementVerifier"],
+            "Core order"
+        );
+    }
+
+    #[test]
+    fn test_find_contract() {
+        // Lookup by contract name (any case) or package
+        assert_eq!(find_contract("routeexecutor").map(|c| c.dir), Some("executor"), "By name");
+        assert_eq!(find_contract("swoosh-cctp-adapter").map(|c| c.name), Some("CctpAdapter"), "By package");
+        assert!(find_contract("Unknown").is_none(), "Unknown contract");
+    }
+
+    #[test]
+    fn test_wasm_file_name() {
+        // Cargo replaces dashes in the lib name
+        let contract = find_contract("UniswapV3Adapter").unwrap();
+
+        assert_eq!(contract.wasm_file(), "swoosh_uniswap_v3_adapter.wasm", "Wasm file");
+    }
+
+    #[test]
+    fn test_parse_deployed_address() {
+        // Takes the first full 20-byte address, ignoring tx hashes
+        let output = "deployment tx hash: 0x1234567890123456789012345678901234567890123456789012345678901234\n\
+                      deployed code at address: 0xAbCdEf0123456789aBcDeF0123456789ABCDEF01\n";
+
+        assert_eq!(
+            parse_deployed_address(output).as_deref(),
+            Some("0xAbCdEf0123456789aBcDeF0123456789ABCDEF01"),
+            "Contract address"
+        );
+        assert_eq!(parse_deployed_address("no address here"), None, "Missing address");
+    }
+}
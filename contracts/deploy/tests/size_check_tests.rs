@@ -0,0 +1,74 @@
+use swoosh_deploy::check::*;
+use swoosh_deploy::{workspace_root, CONTRACTS};
+
+#[cfg(test)]
+mod size_check_tests {
+    use super::*;
+
+    const ABI: &str = "\
+interface IRouteExecutor {
+    function init(address validator) external;
+    function executeRoute(bytes calldata intent, bytes calldata steps) external payable returns (uint256);
+    function getFeeBps(address token) external view returns (uint256);
+    function setTokens(address[] memory tokens, bool enabled) external;
+    error Unauthorized();
+}";
+
+    #[test]
+    fn test_function_signatures() {
+        // Names, locations and return values are stripped
+        assert_eq!(
+            function_signatures(ABI),
+            vec!["init(address)", "executeRoute(bytes,bytes)", "getFeeBps(address)", "setTokens(address[],bool)"],
+            "Canonical signatures"
+        );
+    }
+
+    #[test]
+    fn test_selector() {
+        // Matches the well-known ERC20 selector
+        assert_eq!(selector("transfer(address,uint256)"), [0xa9, 0x05, 0x9c, 0xbb], "transfer selector");
+    }
+
+    #[test]
+    fn test_selector_collisions() {
+        // burn(uint256) and collate_propagate_storage(bytes16) share 0x42966c68
+        let signatures = vec![
+            "burn(uint256)".to_string(),
+            "collate_propagate_storage(bytes16)".to_string(),
+            "transfer(address,uint256)".to_string(),
+        ];
+
+        let collisions = selector_collisions(&signatures);
+        assert_eq!(collisions.len(), 1, "One collision");
+        assert_eq!(collisions[0].selector, [0x42, 0x96, 0x6c, 0x68], "Colliding selector");
+        assert!(selector_collisions(&function_signatures(ABI)).is_empty(), "No collisions");
+    }
+
+    #[test]
+    fn test_compressed_size() {
+        // Repetitive wasm compresses far below its raw size
+        let wasm = [0u8; 64 * 1024];
+
+        assert!(compressed_size(&wasm) < 1024, "Compressed");
+        assert!(compressed_size(&wasm) < MAX_COMPRESSED_SIZE, "Under limit");
+    }
+
+    #[test]
+    #[ignore = "builds every contract to wasm; CI runs it with --ignored"]
+    fn test_contracts_fit_stylus_limits() {
+        // Every contract is under 24KB compressed with no selector collisions
+        let root = workspace_root();
+
+        for contract in CONTRACTS {
+            let report = check(contract, &root).unwrap_or_else(|err| panic!("{err}"));
+            assert!(
+                report.compressed_size <= MAX_COMPRESSED_SIZE,
+                "{} is {} bytes compressed",
+                report.name,
+                report.compressed_size
+            );
+            assert!(report.collisions.is_empty(), "{} selector collisions: {:?}", report.name, report.collisions);
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use swoosh_deploy::layout::*;
+use swoosh_deploy::{workspace_root, CONTRACTS};
+
+#[cfg(test)]
+mod storage_layout_tests {
+    use super::*;
+
+    const SOURCE: &str = "
+#[storage]
+pub struct Example {
+    /// Owner
+    owner: StorageAddress,
+    paused: StorageBool,
+    /// Nested map with a comma in its type
+    balances: StorageMap<Address, StorageMap<Address, StorageU256>>,
+    counter: StorageU256,
+    flag: StorageBool,
+    hash: StorageFixedBytes<32>,
+}
+";
+
+    fn fields(source: &str) -> Vec<(String, String)> {
+        storage_fields(source).expect("Storage struct").1
+    }
+
+    #[test]
+    fn test_storage_fields() {
+        // Comments are skipped and generic types kept whole
+        let (name, fields) = storage_fields(SOURCE).expect("Storage struct");
+
+        assert_eq!(name, "Example", "Struct name");
+        assert_eq!(fields.len(), 6, "Field count");
+        assert_eq!(
+            fields[2],
+            ("balances".to_string(), "StorageMap<Address, StorageMap<Address, StorageU256>>".to_string()),
+            "Nested map"
+        );
+    }
+
+    #[test]
+    fn test_compute_layout_packs_small_fields() {
+        // Address and bool share slot 0; maps and words take full slots
+        let layout = compute_layout(&fields(SOURCE)).unwrap();
+        let slots: Vec<(u64, u8)> = layout.iter().map(|entry| (entry.slot, entry.offset)).collect();
+
+        assert_eq!(slots, vec![(0, 0), (0, 20), (1, 0), (2, 0), (3, 0), (4, 0)], "Slots and offsets");
+    }
+
+    #[test]
+    fn test_unknown_storage_type() {
+        // Types the layout rules don't know fail loudly
+        let fields = vec![("config".to_string(), "StorageConfig".to_string())];
+
+        assert!(compute_layout(&fields).is_err(), "Unknown type");
+    }
+
+    #[test]
+    fn test_layout_json_round_trip() {
+        // Snapshots parse back to the same entries
+        let layout = compute_layout(&fields(SOURCE)).unwrap();
+        let json = layout_json("Example", &layout);
+
+        assert_eq!(parse_layout_json(&json).unwrap(), layout, "Round trip");
+    }
+
+    #[test]
+    fn test_compatibility() {
+        // Appending is fine; inserting a field before existing ones is not
+        let old = compute_layout(&fields(SOURCE)).unwrap();
+
+        let mut appended = fields(SOURCE);
+        appended.push(("extra".to_string(), "StorageU256".to_string()));
+        assert!(compatibility_errors(&old, &compute_layout(&appended).unwrap()).is_empty(), "Append");
+
+        let mut inserted = fields(SOURCE);
+        inserted.insert(0, ("admin".to_string(), "StorageAddress".to_string()));
+        assert!(!compatibility_errors(&old, &compute_layout(&inserted).unwrap()).is_empty(), "Insert");
+
+        let mut retyped = fields(SOURCE);
+        retyped[3].1 = "StorageB256".to_string();
+        assert_eq!(compatibility_errors(&old, &compute_layout(&retyped).unwrap()).len(), 1, "Type change");
+
+        assert_eq!(compatibility_errors(&old, &old[..2]).len(), 4, "Removed fields");
+    }
+
+    #[test]
+    fn test_layouts_match_snapshots() {
+        // Committed snapshots must be compatible with, and equal to, the current source
+        let root = workspace_root();
+
+        for contract in CONTRACTS {
+            let layout = contract_layout(contract, &root).unwrap_or_else(|err| panic!("{err}"));
+            let snapshot = std::fs::read_to_string(snapshot_path(contract, &root))
+                .unwrap_or_else(|_| panic!("{} has no layout snapshot", contract.name));
+            let committed = parse_layout_json(&snapshot).unwrap();
+
+            let errors = compatibility_errors(&committed, &layout);
+            assert!(errors.is_empty(), "{} storage layout broke:\n{}", contract.name, errors.join("\n"));
+            assert_eq!(
+                layout,
+                committed,
+                "{} layout changed; run `cargo run -p swoosh-deploy --features storage-layout --bin storage-layout -- --write`",
+                contract.name
+            );
+        }
+    }
+}
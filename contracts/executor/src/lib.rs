@@ -0,0 +1,6010 @@
+//! RouteExecutor Contract
+//! 
+//! Executes optimized cross-chain routes atomically, handling swaps and bridge transfers.
+//! Native ETH enters and leaves routes through WETH wrap/unwrap steps.
+//! A protocol fee is taken from the input amount at execution time and held
+//! until the fee recipient withdraws it. Integrators routing order flow can add
+//! their own fee, shared with the protocol and claimable per integrator.
+//! Integrators' cumulative volume per token earns protocol fee discounts
+//! from a tiered schedule. A per-token fee ledger totals fees collected,
+//! withdrawn, rebated to integrators and paid to solvers, for all time and
+//! per weekly epoch. With a cost oracle set, the protocol fee rises to
+//! cover the L1 data fee and destination gas, within admin bounds.
+//! In solver-only mode, users sign intents off-chain and only registered
+//! solvers, or operator keys they delegate to, may execute them. Routes may carry a quote signed by a registered
+//! route oracle, which the owner can make mandatory; the quoted and realized
+//! output of every quoted intent feed per-oracle and per-solver stats.
+//! Solvers can net opposing native USDC intents between two chains, paying
+//! each side locally and bridging only the residual. The matched part must
+//! meet the token's minimum fill and step, set by the owner or derived from
+//! its decimals, so a match can't open a settlement for a few wei.
+//! Same-asset transfers (e.g. USDC to USDC) between chains with a registered
+//! canonical token skip routing and quotes in `execute_stable_transfer`.
+//! Intents may commit to a hidden recipient, revealed on the destination chain.
+//! The payout there comes only out of the delivery SettlementVerifier credited
+//! to the intent.
+//! A user's delegates, registered with the validator, may submit ERC20
+//! intents on their behalf, spending the user's tokens within the
+//! delegation's per-token allowance.
+//! Users may escrow a signed intent's input ahead of execution; if the
+//! protocol stays paused, the owner can enable an emergency mode in which
+//! users withdraw their escrow at once.
+//! New intents toward a lane the validator reports halted are rejected, as
+//! are intents whose hash the validator's guardian has blocked or a
+//! watchtower report is holding. ERC20 intents must also pass the
+//! validator's chain list, token listing mode and token caps.
+//! Escrowed intents wait in an on-chain queue ordered by the priority fee
+//! they offer, which is paid to the solver that executes them. Once an
+//! unfilled intent's deadline passes, anyone may expire it with
+//! `expire_intents`, returning the escrow to its user. A solver may claim a
+//! queued intent for a short firm window in which only it can execute; a
+//! claim left to lapse costs part of its bond, paid to the user. With a
+//! rotation window set, each newly queued intent first goes to one active
+//! solver, drawn deterministically from the intent hash and block, and opens
+//! to every solver once the window passes.
+//! Every state-changing token and adapter call goes through `guarded_call`,
+//! which caps its gas and return data and holds the reentrancy lock.
+//! An intent is identified everywhere by its EIP-712 hash, which commits to
+//! this chain and executor, so IDs never collide across deployments. The ID
+//! passed to adapters, SettlementVerifier and events is that hash read as a
+//! uint256 (`signing::global_intent_id`); the local counter only indexes it.
+//! Adapters and fee schedules can be paged out with `export_config` and
+//! restored into a fresh deployment with `import_config`.
+//! Each route step logs a `RouteStepExecuted` event with what went in and
+//! came out, so slippage can be traced to a single hop.
+//! With the liquidity check on, every swap step is quoted before the user's
+//! funds are pulled, so routes into drained pools fail up front.
+//! A Bridge step may raise its lane's destination gas limit for one intent,
+//! up to the per-chain cap set with `set_max_gas_limit`.
+//! Each lane can cap the value of a token in flight toward it, starting low
+//! and ramping up linearly after the lane opens. Bridged value counts against
+//! the cap until SettlementVerifier settles the intent and anyone calls
+//! `release_lane_tvl`.
+//! Token dust left by rounding and surplus can be swept to the fee recipient,
+//! converted into one treasury token through the swap adapters. Escrows,
+//! unclaimed integrator fees and collected fees are tracked per token and
+//! never count as dust.
+//! An intent may be bound to a recent block; it then executes only while
+//! ArbSys still reports the block hash the user signed, so a reorg voids it.
+//! Fee and limit changes advance the validator's config epoch; an intent
+//! pinned to an epoch the validator no longer accepts is refused. Tunables
+//! stay within the `params` bounds and log `ParameterChanged` when set.
+//! Builds with the `invariants` feature panic if the executor stops holding
+//! what it owes, a fee exceeds its cap or a record moves backwards.
+//! Intents bound for a non-EVM chain name their recipient as raw bytes of a
+//! chain family and are bridged through adapters implementing
+//! `IChainAddressAdapter`.
+//! Executors sharing a chain can share an IntentRegistry; each intent is then
+//! consumed there before it executes, so it runs on only one of them.
+//! Intents routed through `execute_route` and the signed route paths can opt
+//! into insurance; their premium goes to SettlementVerifier's InsuranceFund
+//! ahead of the other fees, and the rest of the input is covered.
+//! Those same paths accept a retry policy on intents that bridge; once the
+//! bridge step runs it is registered with SettlementVerifier along with the
+//! adapter used, and the verifier retries a timed-out delivery before
+//! failing the intent.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+use alloc::string::String;
+use alloy_sol_types::{sol, SolCall, SolValue};
+use stylus_sdk::{
+    alloy_primitives::{Address, U256, Bytes, FixedBytes},
+    call::{static_call, Call, RawCall},
+    prelude::*,
+    storage::{StorageAddress, StorageMap, StorageBool, StorageFixedBytes, StorageU256},
+};
+
+use swoosh_common::approval_policy::ApprovalPolicy;
+use swoosh_common::bridge_adapter::{
+    IBridgeAdapter, IChainAddressAdapter, IGasDropAdapter, IGasLimitAdapter, INftBridgeAdapter,
+};
+use swoosh_common::chain_address::ChainFamily;
+use swoosh_common::encoding::{
+    config_key_address, decode_config_page, encode_config_page, pack_config_address, pack_config_key,
+    unpack_config_key, AssetType, ConfigEntry, ConfigEntryKind, Intent, RouteQuote, RouteStep, StepType,
+    MAX_CALLBACK_DATA, NATIVE_TOKEN,
+};
+use swoosh_common::erc165::{self, IERC165};
+use swoosh_common::events::{
+    BridgeInitiated, IntentExecuted, IntentExecutedBySolver, IntentMemo, NftBridgeInitiated, RouteStepExecuted,
+    SwapExecuted,
+};
+use swoosh_common::fees::{self, LedgerEntry};
+use swoosh_common::fill_rule::FillRule;
+use swoosh_common::intent_record::{self, IntentRecord};
+use swoosh_common::intent_registry::IIntentRegistry;
+use swoosh_common::invariant;
+use swoosh_common::invariants;
+use swoosh_common::lane_cap::LaneCap;
+use swoosh_common::params::{self, Param, ParameterOutOfBounds};
+use swoosh_common::retry_policy::RetryPolicy;
+use swoosh_common::route_codec::{self, RouteError};
+use swoosh_common::signing;
+use swoosh_common::solver_registry::{self, ISolverRegistry, SCOPE_EXECUTE, SCOPE_MATCH, SCOPE_SETTLE};
+use swoosh_common::status::{encode_protocol_status, ProtocolStatus, StatusContract};
+use swoosh_common::validator_compat;
+
+// Events
+sol! {
+    event Paused(address indexed by);
+    event Unpaused(address indexed by);
+
+    event BridgeAdapterSet(uint256 indexed destinationChain, address adapter);
+    event LaneAdapterSet(uint256 indexed destinationChain, address indexed adapter, bool enabled);
+    event BridgeAdapterSelected(uint256 indexed intentId, address indexed adapter, uint256 fee);
+    event ChainAddressBridged(uint256 indexed intentId, uint8 recipientFamily, bytes recipientAddress);
+    event CctpAdapterSet(address adapter);
+    event NativeUsdcSet(uint256 indexed chainId, address token);
+    event CanonicalTokenSet(address indexed token, uint256 indexed chainId, address remoteToken);
+    event StableDeadlineWindowSet(uint256 window);
+    event MaxBlockAgeSet(uint256 maxBlockAge);
+    event SwapAdapterSet(address indexed adapter, bool allowed);
+    event ApprovalPolicySet(address indexed adapter, uint8 policy);
+    event WethSet(address indexed weth);
+    event WrappedNativeSet(uint256 indexed chainId, address token);
+    event MaxGasDropSet(uint256 indexed chainId, uint256 maxGasDrop);
+    event MaxGasLimitSet(uint256 indexed chainId, uint256 maxGasLimit);
+    event GasDropSponsored(uint256 indexed intentId, address indexed sponsor, uint256 gasDrop, uint256 cost);
+    event NftBridgeAdapterSet(uint256 indexed destinationChain, address adapter);
+    event RecipientCommitted(uint256 indexed intentId, bytes32 indexed recipientHash, address receiver);
+    event HiddenRecipientSettled(
+        bytes32 indexed intentHash,
+        address indexed recipient,
+        address token,
+        uint256 amount,
+        uint256 indexed solverId
+    );
+
+    event FeeCollected(uint256 indexed intentId, address indexed token, uint256 amount);
+    event FeeRecipientSet(address indexed recipient);
+    event ProtocolFeeSet(uint256 feeBps);
+    event TokenFeeSet(address indexed token, uint256 feeBps, bool enabled);
+    event FillRuleSet(address indexed token, uint256 minFill, uint256 granularity);
+    event LaneTvlCapSet(
+        uint256 indexed chainId,
+        address indexed token,
+        uint256 cap,
+        uint256 initialCap,
+        uint256 rampDuration
+    );
+    event LaneTvlReleased(uint256 indexed intentId, uint256 indexed chainId, address indexed token, uint256 amount);
+    event ConfigImported(uint256 entries);
+    event ConfigImportSealed();
+    event FeesWithdrawn(address indexed token, address indexed recipient, uint256 amount);
+    event DustThresholdSet(address indexed token, uint256 threshold);
+    event TreasuryTokenSet(address indexed token);
+    event DustConverted(address indexed token, uint256 amountIn, uint256 amountOut);
+    event DustSwept(address indexed token, address indexed recipient, uint256 amount);
+    event IntegratorFeeCollected(
+        uint256 indexed intentId,
+        address indexed integrator,
+        address indexed token,
+        uint256 integratorAmount,
+        uint256 protocolAmount
+    );
+    event IntegratorFeePolicySet(uint256 maxFeeBps, uint256 protocolShareBps);
+    event IntegratorFeesClaimed(address indexed integrator, address indexed token, uint256 amount);
+    event InsurancePremiumSet(uint256 premiumBps);
+    event CostFeeConfigSet(address indexed oracle, uint256 minFeeBps, uint256 maxFeeBps, uint256 calldataBytes);
+    event InsurancePremiumPaid(uint256 indexed intentId, address indexed fund, address token, uint256 premium);
+    event PriorityFeePaid(uint256 indexed intentId, address indexed solver, address token, uint256 amount);
+    event PriorityFeesClaimed(address indexed solver, address indexed token, uint256 amount);
+    event VolumeTiersSet(address indexed token, uint256[] thresholds, uint256[] feeBps);
+    event IntegratorTierReached(address indexed integrator, address indexed token, uint256 tier, uint256 volume);
+
+    event SolverRegistrySet(address registry);
+    event IntentRegistrySet(address registry);
+    event SolverOnlySet(bool enabled);
+    event SettlementVerifierSet(address verifier);
+    event IntentCancelled(address indexed user, bytes32 indexed intentHash);
+    event IntentSubmittedByDelegate(bytes32 indexed intentHash, address indexed user, address indexed delegate);
+    event IntentEscrowed(bytes32 indexed intentHash, address indexed user, address token, uint256 amount);
+    event EscrowWithdrawn(bytes32 indexed intentHash, address indexed user, uint256 amount, bool emergency);
+    event IntentExpired(bytes32 indexed intentHash, address indexed user, address token, uint256 amount);
+    event IntentExpiryFailed(bytes32 indexed intentHash, address indexed user);
+    event ClaimConfigSet(uint256 window, uint256 penalty);
+    event IntentClaimed(bytes32 indexed intentHash, uint256 indexed solverId, uint256 expiresAt);
+    event RotationWindowSet(uint256 window);
+    event IntentRotated(bytes32 indexed intentHash, uint256 indexed solverId, uint256 expiresAt);
+    event ClaimPenalized(bytes32 indexed intentHash, uint256 indexed solverId, address indexed user, uint256 penalty);
+    event EmergencyModeEnabled(uint256 pausedAt);
+    event IntentQueued(bytes32 indexed intentHash, uint256 priorityFee, bytes intent);
+    event IntentDequeued(bytes32 indexed intentHash);
+    event OwnershipTransferred(address indexed previousOwner, address indexed newOwner);
+    event RouteSignerSet(address indexed signer, bool enabled);
+    event RouteQuoteRequiredSet(bool required);
+    event LiquidityCheckSet(uint256 maxDeviationBps);
+    event RouteQuoteVerified(bytes32 indexed intentHash, address indexed signer, uint256 quotedAmountOut);
+    event RemoteExecutorSet(uint256 indexed chainId, address executor);
+    event ValidatorSet(address indexed validator, uint256 version);
+    event IntentMatched(
+        uint256 indexed intentId,
+        bytes32 indexed matchId,
+        bytes32 counterpartyHash,
+        uint256 matchedAmount,
+        uint256 residualAmount
+    );
+    event MatchedPayoutReleased(uint256 indexed intentId, address indexed to, uint256 amount, bool refunded);
+    event HiddenDeliveryCredited(bytes32 indexed intentHash, address indexed token, uint256 amount);
+    event QuoteOutcome(
+        uint256 indexed intentId,
+        address indexed signer,
+        uint256 indexed solverId,
+        uint256 quotedAmountOut,
+        uint256 realizedAmountOut
+    );
+    event SolverFeeRealized(
+        uint256 indexed intentId,
+        uint256 indexed solverId,
+        uint256 solverFee,
+        uint256 maxSolverFee
+    );
+    
+    error Unauthorized();
+    error InvalidAddress();
+    error InvalidAmount();
+    error ValidationFailed();
+    error SwapFailed();
+    error BridgeFailed();
+    error ContractPaused();
+    error ReentrancyGuard();
+    error AdapterNotAllowed();
+    error TransferFailed();
+    error SlippageExceeded(uint256 amountOut, uint256 minAmountOut);
+    error DeadlineExpired(uint256 deadline, uint256 timestamp);
+    error InvalidRoute(uint8 reason);
+    error UnsupportedStep();
+    error FeeTooHigh(uint256 feeBps, uint256 maxFeeBps);
+    error SolverOnly();
+    error NotSolver();
+    error InvalidSignature();
+    error IntentAlreadyUsed(bytes32 intentHash);
+    error CancelledIntent(bytes32 intentHash);
+    error RouteQuoteRequired();
+    error InvalidRouteQuote();
+    error UnknownRouteSigner(address signer);
+    error InvalidMatch();
+    error NotSameAsset(address token, uint256 destinationChain);
+    error DeadlineTooFar(uint256 deadline, uint256 latest);
+    error GasDropTooHigh(uint256 gasDrop, uint256 maxGasDrop);
+    error GasLimitTooHigh(uint256 gasLimit, uint256 maxGasLimit);
+    error UnsupportedAsset(uint8 assetType);
+    error InvalidRecipientReveal();
+    error SolverFeeTooHigh(uint256 solverFee, uint256 maxSolverFee);
+    error EscrowLocked(bytes32 intentHash);
+    error EmergencyModeLocked(uint256 availableAt);
+    error LaneHalted(uint256 chainId);
+    error IntentBlocked(bytes32 intentHash);
+    error IntentHeld(bytes32 intentHash, uint256 heldUntil);
+    error SolverAtCapacity(uint256 solverId);
+    error IncompatibleValidator(address validator, uint8 reason);
+    error InvalidConfigPage();
+    error ConfigImportClosed();
+    error InsufficientLiquidity(uint256 stepIndex, uint256 expectedOut, uint256 minOut);
+    error StaleBlock(uint256 blockNumber, uint256 oldestBlock);
+    error BlockHashMismatch(uint256 blockNumber, bytes32 blockHash);
+    error ConfigEpochMismatch(uint256 epoch, uint256 compatibleEpoch, uint256 currentEpoch);
+    error InvalidCallback();
+    error InsuranceUnavailable();
+    error InvalidRetryPolicy();
+    error ClaimsDisabled();
+    error IntentNotClaimable(bytes32 intentHash);
+    error ClaimActive(bytes32 intentHash, uint256 solverId, uint256 expiresAt);
+    error InterfaceNotSupported(address target, bytes4 interfaceId);
+    error InvalidFillAmount(address token, uint256 amount, uint256 minFill, uint256 granularity);
+    error LaneCapExceeded(uint256 chainId, address token, uint256 amount, uint256 available);
+    error InvalidApprovalPolicy();
+    error MatchNotSettled(uint256 intentId);
+    error DeliveryNotCredited(bytes32 intentHash, uint256 credited);
+    error SimulationResult(
+        uint256 amountOut,
+        uint256 gasUsed,
+        uint256 protocolFee,
+        uint256 integratorFee,
+        uint256 bridgeFee,
+        uint256 gasDropCost
+    );
+}
+
+/// Verified route quote carried through execution
+struct QuoteCommitment {
+    signer: Address,
+    quoted_amount_out: U256,
+}
+
+/// What an executed route realized
+struct RouteOutcome {
+    intent_id: U256,
+    amount_out: U256,
+    protocol_fee: U256,
+    integrator_fee: U256,
+    bridge_fee: U256,
+    gas_drop_cost: U256,
+}
+
+/// Call context and config slots an execution reads
+///
+/// Loaded once at the top of each execution entry point, which also rejects
+/// the call while paused, and passed down the route path so helpers don't
+/// re-read the same storage slots and host values.
+struct ExecutionContext {
+    this: Address,
+    chain_id: u64,
+    sender: Address,
+    now: U256,
+    validator: Address,
+    weth: Address,
+}
+
+/// Intent status enumeration
+#[derive(Clone, Copy, PartialEq)]
+pub enum IntentStatus {
+    Pending = 0,
+    Executing = 1,
+    Completed = 2,
+    Failed = 3,
+}
+
+/// Error types for RouteExecutor
+#[derive(SolidityError)]
+pub enum RouteExecutorError {
+    Unauthorized(Unauthorized),
+    InvalidAddress(InvalidAddress),
+    InvalidAmount(InvalidAmount),
+    ValidationFailed(ValidationFailed),
+    SwapFailed(SwapFailed),
+    BridgeFailed(BridgeFailed),
+    ContractPaused(ContractPaused),
+    ReentrancyGuard(ReentrancyGuard),
+    AdapterNotAllowed(AdapterNotAllowed),
+    TransferFailed(TransferFailed),
+    SlippageExceeded(SlippageExceeded),
+    DeadlineExpired(DeadlineExpired),
+    InvalidRoute(InvalidRoute),
+    UnsupportedStep(UnsupportedStep),
+    FeeTooHigh(FeeTooHigh),
+    SolverOnly(SolverOnly),
+    NotSolver(NotSolver),
+    InvalidSignature(InvalidSignature),
+    IntentAlreadyUsed(IntentAlreadyUsed),
+    CancelledIntent(CancelledIntent),
+    RouteQuoteRequired(RouteQuoteRequired),
+    InvalidRouteQuote(InvalidRouteQuote),
+    UnknownRouteSigner(UnknownRouteSigner),
+    InvalidMatch(InvalidMatch),
+    NotSameAsset(NotSameAsset),
+    DeadlineTooFar(DeadlineTooFar),
+    GasDropTooHigh(GasDropTooHigh),
+    GasLimitTooHigh(GasLimitTooHigh),
+    UnsupportedAsset(UnsupportedAsset),
+    InvalidRecipientReveal(InvalidRecipientReveal),
+    SolverFeeTooHigh(SolverFeeTooHigh),
+    EscrowLocked(EscrowLocked),
+    EmergencyModeLocked(EmergencyModeLocked),
+    LaneHalted(LaneHalted),
+    IntentBlocked(IntentBlocked),
+    IntentHeld(IntentHeld),
+    SolverAtCapacity(SolverAtCapacity),
+    IncompatibleValidator(IncompatibleValidator),
+    InvalidConfigPage(InvalidConfigPage),
+    ConfigImportClosed(ConfigImportClosed),
+    InsufficientLiquidity(InsufficientLiquidity),
+    StaleBlock(StaleBlock),
+    BlockHashMismatch(BlockHashMismatch),
+    ConfigEpochMismatch(ConfigEpochMismatch),
+    InvalidCallback(InvalidCallback),
+    InsuranceUnavailable(InsuranceUnavailable),
+    InvalidRetryPolicy(InvalidRetryPolicy),
+    ClaimsDisabled(ClaimsDisabled),
+    IntentNotClaimable(IntentNotClaimable),
+    ClaimActive(ClaimActive),
+    InterfaceNotSupported(InterfaceNotSupported),
+    InvalidFillAmount(InvalidFillAmount),
+    LaneCapExceeded(LaneCapExceeded),
+    InvalidApprovalPolicy(InvalidApprovalPolicy),
+    MatchNotSettled(MatchNotSettled),
+    DeliveryNotCredited(DeliveryNotCredited),
+    ParameterOutOfBounds(ParameterOutOfBounds),
+    SimulationResult(SimulationResult),
+}
+
+impl From<RouteError> for RouteExecutorError {
+    fn from(err: RouteError) -> Self {
+        RouteExecutorError::InvalidRoute(InvalidRoute { reason: err.code() })
+    }
+}
+
+/// Check that an intent or route step deadline has not passed at `now`
+///
+/// The deadline second itself is still in time.
+pub fn check_deadline(deadline: U256, now: U256) -> Result<(), RouteExecutorError> {
+    if now > deadline {
+        return Err(RouteExecutorError::DeadlineExpired(DeadlineExpired { deadline, timestamp: now }));
+    }
+    Ok(())
+}
+
+/// Check a realized amount against its minimum
+///
+/// Every swap step, bridged amount and final output goes through this; an
+/// amount equal to the minimum passes.
+pub fn check_min_out(amount_out: U256, min_amount_out: U256) -> Result<(), RouteExecutorError> {
+    if amount_out < min_amount_out {
+        return Err(RouteExecutorError::SlippageExceeded(SlippageExceeded {
+            amountOut: amount_out,
+            minAmountOut: min_amount_out,
+        }));
+    }
+    Ok(())
+}
+
+// ERC20 balance and decimals reads
+sol_interface! {
+    interface IERC20 {
+        function balanceOf(address account) external view returns (uint256);
+        function decimals() external view returns (uint8);
+    }
+}
+
+// Token and adapter calls made through `guarded_call`. ERC-721 shares the
+// ERC20 `transferFrom` and `approve` selectors, with `amount` as the token ID.
+sol! {
+    function transfer(address to, uint256 amount) external returns (bool);
+    function transferFrom(address from, address to, uint256 amount) external returns (bool);
+    function approve(address spender, uint256 amount) external returns (bool);
+    function safeTransferFrom(address from, address to, uint256 id, uint256 amount, bytes data) external;
+    function setApprovalForAll(address operator, bool approved) external;
+    function deposit() external payable;
+    function withdraw(uint256 amount) external;
+
+    function swap(
+        address token_in,
+        address token_out,
+        uint256 amount_in,
+        uint256 min_amount_out,
+        address recipient,
+        bytes data
+    ) external returns (uint256);
+    function quote(address token_in, address token_out, uint256 amount_in, bytes data) external returns (uint256);
+    function bridge(
+        uint256 intent_id,
+        uint256 destination_chain,
+        address token,
+        uint256 amount,
+        address recipient,
+        bool unwrap_native
+    ) external payable returns (bytes32);
+    function bridgeWithGasDrop(
+        uint256 intent_id,
+        uint256 destination_chain,
+        address token,
+        uint256 amount,
+        address recipient,
+        bool unwrap_native,
+        uint256 gas_drop
+    ) external payable returns (bytes32);
+    function bridgeWithGasLimit(
+        uint256 intent_id,
+        uint256 destination_chain,
+        address token,
+        uint256 amount,
+        address recipient,
+        bool unwrap_native,
+        uint256 gas_limit
+    ) external payable returns (bytes32);
+    function bridgeToChainAddress(
+        uint256 intent_id,
+        uint256 destination_chain,
+        address token,
+        uint256 amount,
+        uint8 recipient_family,
+        bytes recipient
+    ) external payable returns (bytes32);
+    function bridgeNft(
+        uint256 intent_id,
+        uint256 destination_chain,
+        uint8 asset_type,
+        address token,
+        uint256 token_id,
+        uint256 amount,
+        address recipient
+    ) external payable returns (bytes32);
+}
+
+// ArbSys precompile, for the L2 block hashes intents may be bound to
+sol_interface! {
+    interface IArbSys {
+        function arbBlockNumber() external view returns (uint256);
+        function arbBlockHash(uint256 block_number) external view returns (bytes32);
+    }
+}
+
+// ArbGasInfo precompile, for the L1 data fee a route's calldata costs
+sol_interface! {
+    interface IArbGasInfo {
+        function getPricesInWei() external view returns (uint256, uint256, uint256, uint256, uint256, uint256);
+    }
+}
+
+// Execution cost oracle: adds the destination leg's gas to the source cost
+// and prices the total in the intent's input token
+sol_interface! {
+    interface ICostOracle {
+        function quoteExecutionCost(
+            address token,
+            uint256 destination_chain,
+            uint256 source_cost_wei
+        ) external view returns (uint256);
+    }
+}
+
+// SettlementVerifier interface for solver attribution
+sol_interface! {
+    interface ISettlementVerifier {
+        function registerSolverIntent(
+            uint256 intent_id,
+            uint256 solver_id,
+            address token,
+            uint256 notional,
+            address recipient
+        ) external;
+        function registerMatchedLeg(
+            uint256 intent_id,
+            uint256 solver_id,
+            bytes32 match_id,
+            address token,
+            uint256 notional
+        ) external;
+        function hasSolverCapacity(uint256 solver_id, address token, uint256 notional) external view returns (bool);
+        function registerIntentCallback(uint256 intent_id, address callback, bytes data) external;
+        function registerInsurance(
+            uint256 intent_id,
+            address user,
+            address token,
+            uint256 coverage,
+            uint256 premium
+        ) external;
+        function insuranceFund() external view returns (address);
+        function registerRetryPolicy(uint256 intent_id, uint256 policy, address bridge) external;
+        function getSettlementStatus(uint256 intent_id) external view returns (uint256);
+    }
+}
+
+// IntentValidator interface
+sol_interface! {
+    interface IIntentValidator {
+        function validate_intent(
+            address user,
+            address token,
+            uint256 amount,
+            uint256 destination_chain,
+            address spender
+        ) external view returns (bool);
+
+        function check_execution(
+            address user,
+            address token,
+            uint256 amount,
+            uint256 destination_chain,
+            address spender
+        ) external view returns (bool);
+
+        function validate_nft_intent(
+            address user,
+            uint8 asset_type,
+            address token,
+            uint256 token_id,
+            uint256 amount,
+            uint256 destination_chain,
+            address spender
+        ) external view returns (bool);
+
+        function is_lane_halted(uint256 chain_id) external view returns (bool);
+
+        function get_intent_hold(bytes32 intent_hash) external view returns (bool, uint256);
+
+        function get_config_epochs() external view returns (uint256, uint256);
+
+        function advance_config_epoch(bool retire_previous) external returns (uint256);
+
+        function spend_delegation(
+            address delegator,
+            address delegate,
+            address token,
+            uint256 amount
+        ) external returns (uint256);
+
+        function self_check() external view returns (uint256, uint256, uint256);
+    }
+}
+
+/// `onERC1155Received` selector, returned to accept a transfer
+const ERC1155_RECEIVED: [u8; 4] = [0xf2, 0x3a, 0x6e, 0x61];
+
+/// Default stable transfer deadline window (10 minutes)
+const DEFAULT_STABLE_DEADLINE_WINDOW: u64 = 10 * 60;
+
+/// ArbSys precompile
+const ARB_SYS: Address = Address::new([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x64]);
+
+/// ArbGasInfo precompile
+const ARB_GAS_INFO: Address = Address::new([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x6c]);
+
+/// Most blocks back ArbSys keeps hashes for, and the default intent block age
+const BLOCK_HASH_WINDOW: u64 = 256;
+
+/// How long the contract must stay paused before emergency mode (3 days)
+const EMERGENCY_PAUSE_DELAY: u64 = 3 * 24 * 60 * 60;
+
+/// Most escrowed intents listed in the pending queue at once
+const MAX_QUEUED_INTENTS: u64 = 100;
+
+/// Most escrowed intents `expire_intents` handles in one call
+const MAX_EXPIRY_BATCH: usize = 50;
+
+/// Most intents `release_lane_tvl` handles in one call
+const MAX_RELEASE_BATCH: usize = 50;
+
+/// Gas forwarded to a token call
+const TOKEN_CALL_GAS: u64 = 200_000;
+
+/// Gas forwarded to a swap or bridge adapter call
+const ADAPTER_CALL_GAS: u64 = 3_000_000;
+
+/// Most return data read back from a guarded call
+const MAX_RETURN_DATA: usize = 256;
+
+/// SettlementVerifier's `SettlementStatus` codes a matched leg is released on
+const SETTLEMENT_CONFIRMED: u64 = 1;
+const SETTLEMENT_FAILED: u64 = 2;
+const SETTLEMENT_REFUNDED: u64 = 3;
+
+/// Indexed routing events, appended to the exported ABI
+///
+/// `export-abi` lists only functions, so the topics indexers filter on are
+/// spelled out here. Keep in step with `swoosh_common::events`.
+#[cfg(feature = "export-abi")]
+pub const EVENTS_ABI: &str = r#"
+interface IRouteExecutorEvents {
+    /// Topics: intentId, user, destinationChain
+    event IntentExecuted(
+        uint256 indexed intentId,
+        address indexed user,
+        uint256 indexed destinationChain,
+        address tokenIn,
+        uint256 timestamp
+    );
+
+    /// Topics: intentId, solverId, solver
+    event IntentExecutedBySolver(
+        uint256 indexed intentId,
+        uint256 indexed solverId,
+        address indexed solver,
+        bytes32 intentHash
+    );
+
+    /// Topics: intentId, memo
+    event IntentMemo(uint256 indexed intentId, bytes32 indexed memo);
+
+    /// Topics: intentId, tokenIn, tokenOut
+    event SwapExecuted(
+        uint256 indexed intentId,
+        address indexed tokenIn,
+        address indexed tokenOut,
+        uint256 amountIn,
+        uint256 amountOut
+    );
+
+    /// Topics: intentId, adapter
+    event RouteStepExecuted(
+        uint256 indexed intentId,
+        uint256 stepIndex,
+        uint8 stepType,
+        address indexed adapter,
+        address tokenIn,
+        address tokenOut,
+        uint256 amountIn,
+        uint256 amountOut,
+        bool success
+    );
+
+    /// Topics: intentId, token, destinationChain
+    event BridgeInitiated(
+        uint256 indexed intentId,
+        address indexed token,
+        uint256 amount,
+        uint256 indexed destinationChain,
+        address recipient
+    );
+
+    /// Topics: intentId, token, destinationChain
+    event NftBridgeInitiated(
+        uint256 indexed intentId,
+        uint8 assetType,
+        address indexed token,
+        uint256 tokenId,
+        uint256 amount,
+        uint256 indexed destinationChain,
+        address recipient
+    );
+}"#;
+
+#[entrypoint]
+#[storage]
+pub struct RouteExecutor {
+    /// Contract owner
+    owner: StorageAddress,
+    /// IntentValidator contract address
+    validator: StorageAddress,
+    /// CCIP router address
+    ccip_router: StorageAddress,
+    /// Number of intents executed here, the last local index assigned
+    intent_counter: StorageU256,
+    /// Mapping of intent IDs to packed record headers: status, timestamps
+    /// and destination chain (see `swoosh_common::intent_record`)
+    intent_headers: StorageMap<U256, StorageU256>,
+    /// Contract paused state
+    paused: StorageBool,
+    /// Reentrancy guard
+    locked: StorageBool,
+    /// Mapping of destination chain IDs to bridge adapters (zero = CCIP)
+    bridge_adapters: StorageMap<U256, StorageAddress>,
+    /// CCTP adapter preferred for native USDC lanes
+    cctp_adapter: StorageAddress,
+    /// Mapping of chain IDs to native (Circle-issued) USDC addresses
+    native_usdc: StorageMap<U256, StorageAddress>,
+    /// Allowlist of swap adapters route steps may target
+    swap_adapters: StorageMap<Address, StorageBool>,
+    /// Wrapped native token on this chain
+    weth: StorageAddress,
+    /// Recipient of withdrawn protocol fees (treasury)
+    fee_recipient: StorageAddress,
+    /// Default protocol fee in bps of the input amount
+    protocol_fee_bps: StorageU256,
+    /// Per-token protocol fee overrides in bps
+    token_fee_bps: StorageMap<Address, StorageU256>,
+    /// Tokens with a fee override set
+    token_fee_enabled: StorageMap<Address, StorageBool>,
+    /// Protocol fees collected and not yet withdrawn, by token
+    collected_fees: StorageMap<Address, StorageU256>,
+    /// Maximum fee in bps an integrator may attach to a route
+    max_integrator_fee_bps: StorageU256,
+    /// Protocol's share of integrator fees in bps
+    integrator_protocol_share_bps: StorageU256,
+    /// Unclaimed integrator fees (integrator -> token -> amount)
+    integrator_fees: StorageMap<Address, StorageMap<Address, StorageU256>>,
+    /// SolverRegistry consulted for signed intent execution
+    solver_registry: StorageAddress,
+    /// When set, only registered solvers may execute (signed intents only)
+    solver_only: StorageBool,
+    /// Signed intent hashes already executed
+    consumed_intents: StorageMap<FixedBytes<32>, StorageBool>,
+    /// SettlementVerifier told which solver executed each signed intent
+    settlement_verifier: StorageAddress,
+    /// Signed intents cancelled by their user (user -> intent hash -> cancelled)
+    cancelled_intents: StorageMap<Address, StorageMap<FixedBytes<32>, StorageBool>>,
+    /// Route oracles whose quotes `execute_full_route` accepts
+    route_signers: StorageMap<Address, StorageBool>,
+    /// When set, routes without a signed quote are rejected
+    route_quote_required: StorageBool,
+    /// Route oracle that quoted each intent (zero if unquoted)
+    intent_quote_signers: StorageMap<U256, StorageAddress>,
+    /// Quoted output per intent
+    intent_quoted_out: StorageMap<U256, StorageU256>,
+    /// Output the route handed to its final step, per quoted intent
+    intent_realized_out: StorageMap<U256, StorageU256>,
+    /// Quoted intents executed, by route oracle
+    signer_quote_count: StorageMap<Address, StorageU256>,
+    /// Sum of quoted outputs, by route oracle
+    signer_quoted_total: StorageMap<Address, StorageU256>,
+    /// Sum of realized outputs, by route oracle
+    signer_realized_total: StorageMap<Address, StorageU256>,
+    /// Sum of realized shortfalls below the quote, by route oracle
+    signer_shortfall_total: StorageMap<Address, StorageU256>,
+    /// Quoted intents executed, by solver ID
+    solver_quote_count: StorageMap<U256, StorageU256>,
+    /// Sum of quoted outputs, by solver ID
+    solver_quoted_total: StorageMap<U256, StorageU256>,
+    /// Sum of realized outputs, by solver ID
+    solver_realized_total: StorageMap<U256, StorageU256>,
+    /// Sum of realized shortfalls below the quote, by solver ID
+    solver_shortfall_total: StorageMap<U256, StorageU256>,
+    /// RouteExecutor deployed on each remote chain, for verifying its intents
+    remote_executors: StorageMap<U256, StorageAddress>,
+    /// Same asset on each destination chain, by local token then chain ID
+    canonical_tokens: StorageMap<Address, StorageMap<U256, StorageAddress>>,
+    /// Longest deadline a stable transfer may carry, from now (zero = no limit)
+    stable_deadline_window: StorageU256,
+    /// Extra adapters enabled per destination chain for cheapest-lane selection
+    lane_adapters: StorageMap<U256, StorageMap<Address, StorageBool>>,
+    /// Wrapped native token on each remote chain, for native delivery
+    wrapped_natives: StorageMap<U256, StorageAddress>,
+    /// Largest gas drop an intent may request, per destination chain (zero = disabled)
+    max_gas_drops: StorageMap<U256, StorageU256>,
+    /// NFT-capable bridge adapter per destination chain
+    nft_bridge_adapters: StorageMap<U256, StorageAddress>,
+    /// Hidden-recipient intents delivered here, by source intent hash
+    settled_hidden_recipients: StorageMap<FixedBytes<32>, StorageBool>,
+    /// Quoted minus realized output of each solver-executed, quoted intent
+    intent_solver_fees: StorageMap<U256, StorageU256>,
+    /// Number of volume tiers in each token's schedule
+    volume_tier_counts: StorageMap<Address, StorageU256>,
+    /// Volume each tier starts at (token -> tier index -> threshold)
+    volume_tier_thresholds: StorageMap<Address, StorageMap<U256, StorageU256>>,
+    /// Protocol fee in bps within each tier (token -> tier index -> bps)
+    volume_tier_fee_bps: StorageMap<Address, StorageMap<U256, StorageU256>>,
+    /// Cumulative input volume routed by each integrator (integrator -> token -> volume)
+    integrator_volumes: StorageMap<Address, StorageMap<Address, StorageU256>>,
+    /// Time the current pause began (zero while unpaused)
+    paused_at: StorageU256,
+    /// Set by the owner after a long pause; lets users withdraw escrow at once
+    emergency_mode: StorageBool,
+    /// Owner of each escrowed signed intent, by intent hash
+    escrow_users: StorageMap<FixedBytes<32>, StorageAddress>,
+    /// Token escrowed for each signed intent
+    escrow_tokens: StorageMap<FixedBytes<32>, StorageAddress>,
+    /// Amount escrowed for each signed intent (zero = none)
+    escrow_amounts: StorageMap<FixedBytes<32>, StorageU256>,
+    /// Deadline of each escrowed signed intent
+    escrow_deadlines: StorageMap<FixedBytes<32>, StorageU256>,
+    /// Priority fee escrowed alongside each signed intent's input
+    escrow_priority_fees: StorageMap<FixedBytes<32>, StorageU256>,
+    /// Pending queue as a doubly linked list in descending priority fee;
+    /// the zero hash is the sentinel before the head and after the tail
+    queue_next: StorageMap<FixedBytes<32>, StorageFixedBytes<32>>,
+    queue_prev: StorageMap<FixedBytes<32>, StorageFixedBytes<32>>,
+    /// Escrowed intents currently listed in the pending queue
+    queued_intents: StorageMap<FixedBytes<32>, StorageBool>,
+    /// Number of intents in the pending queue
+    queue_length: StorageU256,
+    /// Unclaimed priority fees earned by each solver, per token
+    solver_priority_fees: StorageMap<Address, StorageMap<Address, StorageU256>>,
+    /// Mapping of local intent indexes to intent hashes
+    indexed_intents: StorageMap<U256, StorageFixedBytes<32>>,
+    /// Mapping of intent hashes to their local index (zero = never executed)
+    intent_indexes: StorageMap<FixedBytes<32>, StorageU256>,
+    /// Mapping of export indexes to packed config keys (see `pack_config_key`)
+    config_keys: StorageMap<U256, StorageU256>,
+    /// Packed config keys already indexed
+    config_listed: StorageMap<U256, StorageBool>,
+    /// Number of indexed config keys
+    config_count: StorageU256,
+    /// Whether `import_config` has been closed for good
+    config_import_sealed: StorageBool,
+    /// How far in bps a route's pre-checked output may fall short of its
+    /// signed quote (zero = no liquidity pre-check)
+    liquidity_check_bps: StorageU256,
+    /// Mapping of destination chain IDs to the largest destination gas limit
+    /// a Bridge step may ask for (zero = no overrides)
+    max_gas_limits: StorageMap<U256, StorageU256>,
+    /// Balances owed to users and integrators (escrows and unclaimed
+    /// integrator fees), by token
+    reserved_balances: StorageMap<Address, StorageU256>,
+    /// Smallest dust balance of a token worth sweeping (zero = never swept)
+    dust_thresholds: StorageMap<Address, StorageU256>,
+    /// Token swept dust is converted into
+    treasury_token: StorageAddress,
+    /// How many blocks old an intent's bound block may be
+    /// (zero = the full `BLOCK_HASH_WINDOW`)
+    max_block_age: StorageU256,
+    /// Input amount of each executed intent, the second word of its record
+    intent_amounts_in: StorageMap<U256, StorageU256>,
+    /// Amount each completed intent delivered, the third word of its record
+    intent_amounts_out: StorageMap<U256, StorageU256>,
+    /// User behind each intent, read for settlement attestations
+    intent_users: StorageMap<U256, StorageAddress>,
+    /// IntentRegistry shared with the chain's other executors (zero = none)
+    intent_registry: StorageAddress,
+    /// Insurance premium charged on insured intents (zero = insurance off)
+    insurance_premium_bps: StorageU256,
+    /// Oracle pricing execution costs into the protocol fee (zero = schedule only)
+    cost_oracle: StorageAddress,
+    /// Floor of the cost-adjusted protocol fee, in bps
+    cost_fee_min_bps: StorageU256,
+    /// Ceiling of the cost-adjusted protocol fee, in bps
+    cost_fee_max_bps: StorageU256,
+    /// Calldata a route posts to L1, in bytes, priced through ArbGasInfo
+    cost_calldata_bytes: StorageU256,
+    /// How long a solver's claim on a queued intent holds (zero = claims off)
+    claim_window: StorageU256,
+    /// Bond taken from a solver whose claim lapses, in wei
+    claim_penalty: StorageU256,
+    /// Solver ID holding each claimed intent (zero = unclaimed)
+    claim_solvers: StorageMap<FixedBytes<32>, StorageU256>,
+    /// Time each claim lapses
+    claim_expiries: StorageMap<FixedBytes<32>, StorageU256>,
+    /// Lifetime fee ledger (token -> `LedgerEntry` -> amount)
+    fee_totals: StorageMap<Address, StorageMap<U256, StorageU256>>,
+    /// Fee ledger per epoch (token -> `fees::ledger_key` -> amount)
+    fee_epoch_totals: StorageMap<Address, StorageMap<U256, StorageU256>>,
+    /// Owner-set minimum fill per token (used when its granularity is set)
+    fill_min_amounts: StorageMap<Address, StorageU256>,
+    /// Owner-set fill step per token (zero = derived from decimals)
+    fill_granularities: StorageMap<Address, StorageU256>,
+    /// Full TVL cap per lane and token (chain ID -> token -> cap, zero = uncapped)
+    lane_tvl_caps: StorageMap<U256, StorageMap<Address, StorageU256>>,
+    /// Cap each lane's ramp starts from (chain ID -> token -> cap)
+    lane_initial_caps: StorageMap<U256, StorageMap<Address, StorageU256>>,
+    /// Packed ramp start and duration (chain ID -> token -> `lane_cap` ramp word)
+    lane_cap_ramps: StorageMap<U256, StorageMap<Address, StorageU256>>,
+    /// Value bridged on capped lanes and not yet released (chain ID -> token -> amount)
+    lane_tvl: StorageMap<U256, StorageMap<Address, StorageU256>>,
+    /// Token each intent counted against its lane's cap
+    intent_lane_tokens: StorageMap<U256, StorageAddress>,
+    /// Value each intent counts against its lane's cap until released
+    intent_lane_values: StorageMap<U256, StorageU256>,
+    /// How long a newly queued intent is reserved for its rotation solver (zero = off)
+    rotation_window: StorageU256,
+    /// Solver ID each queued intent rotated to (zero = open to all)
+    rotation_solvers: StorageMap<FixedBytes<32>, StorageU256>,
+    /// Time each rotation ends
+    rotation_expiries: StorageMap<FixedBytes<32>, StorageU256>,
+    /// How each adapter's token allowance is left after a call, see `ApprovalPolicy`
+    approval_policies: StorageMap<Address, StorageU256>,
+    /// Memo each intent was signed with (zero = none)
+    intent_memos: StorageMap<U256, StorageFixedBytes<32>>,
+    /// Local matched leg each counterparty intent hash was matched by (zero = unmatched)
+    matched_counterparties: StorageMap<FixedBytes<32>, StorageU256>,
+    /// Matched amount each matched leg holds until SettlementVerifier settles it
+    matched_payouts: StorageMap<U256, StorageU256>,
+    /// Token each matched leg's held amount is in
+    matched_tokens: StorageMap<U256, StorageAddress>,
+    /// Counterparty recipient each matched leg's held amount is released to
+    matched_recipients: StorageMap<U256, StorageAddress>,
+    /// Mapping of hidden-recipient intent hash -> token -> delivered amount
+    /// credited by SettlementVerifier and not yet paid out (also reserved)
+    hidden_deliveries: StorageMap<FixedBytes<32>, StorageMap<Address, StorageU256>>,
+}
+
+#[public]
+impl RouteExecutor {
+    /// Initialize the contract
+    pub fn init(
+        &mut self,
+        validator_address: Address,
+        ccip_router_address: Address,
+    ) -> Result<(), RouteExecutorError> {
+        if validator_address == Address::ZERO || ccip_router_address == Address::ZERO {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.owner.set(self.vm().msg_sender());
+        self.validator.set(validator_address);
+        self.ccip_router.set(ccip_router_address);
+        self.intent_counter.set(U256::ZERO);
+        self.paused.set(false);
+        self.locked.set(false);
+        self.stable_deadline_window.set(U256::from(DEFAULT_STABLE_DEADLINE_WINDOW));
+
+        Ok(())
+    }
+
+    /// Execute a multi-step route for an intent
+    /// 
+    /// `intent` is `abi.encode(Intent)` and `steps` is `abi.encode(RouteStep[])`.
+    /// Steps run in order, each consuming the full output of the previous one;
+    /// the route must end with a Bridge or Transfer step. Malformed routes
+    /// revert with `InvalidRoute(reason)` before any funds move. Native ETH
+    /// intents (`tokenIn` = `NATIVE_TOKEN`) send `amountIn` as msg.value.
+    ///
+    /// An intent with a `gasDrop` has that much destination native delivered
+    /// to its recipient alongside the bridged tokens. The lane's adapter must
+    /// support gas drops and the amount must be within `get_max_gas_drop`.
+    /// The drop's price is paid with extra msg.value; any surplus is refunded.
+    ///
+    /// An intent with a `recipientHash` and no `recipient` is bridged to the
+    /// RouteExecutor registered for its destination, which pays it out once
+    /// the recipient is revealed in `settle_hidden_recipient`.
+    ///
+    /// An intent for a non-EVM `recipientFamily` must end in a plain token
+    /// Bridge step on a lane whose adapter implements `IChainAddressAdapter`,
+    /// which delivers to `recipientAddress`.
+    ///
+    /// Callable by the intent's user, or by a delegate the user registered
+    /// with the validator for an ERC20 intent within its allowance.
+    #[payable]
+    pub fn execute_route(&mut self, intent: Bytes, steps: Bytes) -> Result<U256, RouteExecutorError> {
+        let intent = route_codec::decode_intent(&intent).map_err(RouteExecutorError::from)?;
+        let steps = route_codec::decode_route(&steps).map_err(RouteExecutorError::from)?;
+        let ctx = self.execution_context()?;
+        let intent_hash = signing::intent_digest(&intent, ctx.chain_id, ctx.this);
+
+        // Direct routes carry no quote; this only enforces the quote policy
+        self.check_route_quote(&ctx, intent_hash, &intent, &steps, &[], &[])?;
+
+        self.internal_execute_route(
+            &ctx,
+            intent_hash,
+            intent,
+            steps,
+            Address::ZERO,
+            U256::ZERO,
+            U256::ZERO,
+            None,
+            false,
+        )
+        .map(|outcome| outcome.intent_id)
+    }
+
+    /// Execute a route as `execute_route` would, then revert with the result
+    ///
+    /// Always reverts: with `SimulationResult` (output reaching the final
+    /// step, gas used, protocol, integrator and bridge fees, gas drop cost)
+    /// when the route succeeds, or with the error the execution hit. Call it
+    /// with `eth_call` from the intent's user, with the same value.
+    #[payable]
+    pub fn simulate_route(&mut self, intent: Bytes, steps: Bytes) -> Result<(), RouteExecutorError> {
+        let gas_start = self.vm().evm_gas_left();
+
+        let intent = route_codec::decode_intent(&intent).map_err(RouteExecutorError::from)?;
+        let steps = route_codec::decode_route(&steps).map_err(RouteExecutorError::from)?;
+        let ctx = self.execution_context()?;
+        let intent_hash = signing::intent_digest(&intent, ctx.chain_id, ctx.this);
+        self.check_route_quote(&ctx, intent_hash, &intent, &steps, &[], &[])?;
+        let outcome = self.internal_execute_route(
+            &ctx,
+            intent_hash,
+            intent,
+            steps,
+            Address::ZERO,
+            U256::ZERO,
+            U256::ZERO,
+            None,
+            false,
+        )?;
+
+        Err(RouteExecutorError::SimulationResult(SimulationResult {
+            amountOut: outcome.amount_out,
+            gasUsed: U256::from(gas_start.saturating_sub(self.vm().evm_gas_left())),
+            protocolFee: outcome.protocol_fee,
+            integratorFee: outcome.integrator_fee,
+            bridgeFee: outcome.bridge_fee,
+            gasDropCost: outcome.gas_drop_cost,
+        }))
+    }
+
+    /// Execute a user-signed intent along a solver-chosen route
+    ///
+    /// Callable only by active registered solvers and their operator keys
+    /// scoped to execute, attributed to the solver. `signature` is the user's
+    /// 65-byte EIP-712 signature over the intent (see `signing::intent_digest`);
+    /// each signed intent executes at most once. Tokens are pulled from
+    /// `intent.user`, which must have approved this contract.
+    ///
+    /// `route_quote` and `route_signature` work as in `execute_full_route`;
+    /// quoted output counts toward the solver's quote stats. The solver's fee,
+    /// quoted minus realized output, must not exceed the intent's
+    /// `maxSolverFee`; a non-zero cap requires a quote.
+    ///
+    /// The solver sponsors the intent's gas drop, if any, with msg.value.
+    /// The intent's `priorityFee` is paid on top of `amountIn` and credited
+    /// to the solver, claimable with `claim_priority_fees`. Escrowed intents
+    /// were submitted by their user on-chain and need no signature.
+    #[payable]
+    pub fn execute_signed_route(
+        &mut self,
+        intent: Bytes,
+        steps: Bytes,
+        signature: Bytes,
+        route_quote: Bytes,
+        route_signature: Bytes,
+    ) -> Result<U256, RouteExecutorError> {
+        let intent = route_codec::decode_intent(&intent).map_err(RouteExecutorError::from)?;
+        let steps = route_codec::decode_route(&steps).map_err(RouteExecutorError::from)?;
+
+        let (solver, solver_id) = self.active_solver(SCOPE_EXECUTE)?;
+
+        // Solvers can't fund a user's ETH; native intents go through execute_route
+        if intent.tokenIn == NATIVE_TOKEN {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        let ctx = self.execution_context()?;
+        self.check_solver_capacity(solver_id, intent.tokenIn, intent.amountIn)?;
+        let intent_hash = self.consume_signed_intent(&ctx, &intent, &signature, solver_id)?;
+
+        let quote = self.check_route_quote(&ctx, intent_hash, &intent, &steps, &route_quote, &route_signature)?;
+        // A capped solver fee is measured against the quote, so one is needed
+        if quote.is_none() && intent.maxSolverFee != U256::ZERO {
+            return Err(RouteExecutorError::RouteQuoteRequired(RouteQuoteRequired {}));
+        }
+        // Escrowed intents are funded from the escrow rather than the user's wallet
+        let escrow_amount = self.escrow_amounts.get(intent_hash);
+        let escrowed = escrow_amount != U256::ZERO;
+        if escrowed {
+            let escrow = escrow_amount + self.escrow_priority_fees.get(intent_hash);
+            self.release_reserved(self.escrow_tokens.get(intent_hash), escrow);
+            self.escrow_amounts.setter(intent_hash).set(U256::ZERO);
+            self.escrow_priority_fees.setter(intent_hash).set(U256::ZERO);
+        }
+        let (user, token_in, priority_fee) = (intent.user, intent.tokenIn, intent.priorityFee);
+        let (amount_in, recipient) = (intent.amountIn, intent.recipient);
+        let intent_id = self
+            .internal_execute_route(
+                &ctx,
+                intent_hash,
+                intent,
+                steps,
+                Address::ZERO,
+                U256::ZERO,
+                solver_id,
+                quote,
+                escrowed,
+            )?
+            .intent_id;
+
+        if priority_fee != U256::ZERO {
+            self.pay_priority_fee(intent_id, solver, user, token_in, priority_fee, escrowed)?;
+        }
+
+        // Attribute the intent so its solver can be slashed if it never settles
+        let verifier = self.settlement_verifier.get();
+        if verifier != Address::ZERO {
+            let config = Call::new_mutating(self);
+            ISettlementVerifier::new(verifier)
+                .register_solver_intent(self.vm(), config, intent_id, solver_id, token_in, amount_in, recipient)
+                .map_err(|_| RouteExecutorError::ValidationFailed(ValidationFailed {}))?;
+        }
+
+        self.vm().log(IntentExecutedBySolver {
+            intentId: intent_id,
+            solverId: solver_id,
+            solver,
+            intentHash: intent_hash,
+        });
+
+        Ok(intent_id)
+    }
+
+    /// Bridge a token to the same asset on the destination chain
+    ///
+    /// Fast path for the most common intent: `tokenIn` must have a canonical
+    /// counterpart registered for `destinationChain`. No route is decoded and
+    /// no swap adapter or route quote is involved; after the protocol fee the
+    /// whole amount is bridged, so `minAmountOut` only bounds the fee. The
+    /// deadline must fall within the stable deadline window, keeping these
+    /// transfers short-lived. Insured intents need the full route path. Callable
+    /// by the intent's user or one of its delegates, as `execute_route`.
+    pub fn execute_stable_transfer(&mut self, intent: Bytes) -> Result<U256, RouteExecutorError> {
+        let mut intent = route_codec::decode_intent(&intent).map_err(RouteExecutorError::from)?;
+        let ctx = self.execution_context()?;
+        // Hashed before the hidden recipient is filled in, as the user signed it
+        let intent_hash = signing::intent_digest(&intent, ctx.chain_id, ctx.this);
+        let intent_key = signing::intent_key(&intent);
+
+        if self.solver_only.get() {
+            return Err(RouteExecutorError::SolverOnly(SolverOnly {}));
+        }
+        self.check_submitter(&ctx, intent_hash, &intent, intent.amountIn)?;
+
+        check_deadline(intent.deadline, ctx.now)?;
+        self.check_block_binding(&intent)?;
+        let window = self.stable_deadline_window.get();
+        if window != U256::ZERO {
+            let latest = ctx.now + window;
+            if intent.deadline > latest {
+                return Err(RouteExecutorError::DeadlineTooFar(DeadlineTooFar {
+                    deadline: intent.deadline,
+                    latest,
+                }));
+            }
+        }
+
+        let token = intent.tokenIn;
+        if token == NATIVE_TOKEN || self.canonical_tokens.getter(token).get(intent.destinationChain) == Address::ZERO {
+            return Err(RouteExecutorError::NotSameAsset(NotSameAsset {
+                token,
+                destinationChain: intent.destinationChain,
+            }));
+        }
+        let hidden = self.resolve_hidden_recipient(&mut intent)?;
+        let family = route_codec::check_recipient(&intent).map_err(RouteExecutorError::from)?;
+        if family == ChainFamily::Evm && intent.recipient == Address::ZERO {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+        if intent.assetType != AssetType::Erc20 as u8 {
+            return Err(RouteExecutorError::UnsupportedAsset(UnsupportedAsset { assetType: intent.assetType }));
+        }
+        // Gas drops need msg.value and an adapter choice; use execute_route
+        if intent.amountIn == U256::ZERO || intent.gasDrop != U256::ZERO {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+        if intent.insurancePremiumBps != U256::ZERO {
+            return Err(RouteExecutorError::InsuranceUnavailable(InsuranceUnavailable {}));
+        }
+        if intent.retryPolicy != U256::ZERO {
+            return Err(RouteExecutorError::InvalidRetryPolicy(InvalidRetryPolicy {}));
+        }
+        self.check_intent_admitted(&ctx, &intent)?;
+        self.check_lane_healthy(&ctx, intent.destinationChain)?;
+        self.check_config_epoch(&ctx, intent.configEpoch)?;
+
+        self.check_not_locked()?;
+        self.locked.set(true);
+
+        let intent_id = self.register_intent(&ctx, intent_hash, intent_key)?;
+        self.open_intent_record(&ctx, intent_id, &intent);
+        self.register_callback(intent_id, &intent)?;
+
+        self.pull_token(token, intent.user, intent.amountIn)?;
+
+        let fee_bps = self.quote_protocol_fee_bps(Address::ZERO, token, intent.amountIn, intent.destinationChain);
+        let (net_amount, protocol_fee) = fees::take_fee(intent.amountIn, fee_bps);
+        invariant!(
+            invariants::is_fee_within_cap(protocol_fee, intent.amountIn, fees::MAX_PROTOCOL_FEE_BPS),
+            "protocol fee within cap"
+        );
+        self.collect_protocol_fee(intent_id, token, protocol_fee);
+        check_min_out(net_amount, intent.minAmountOut)?;
+
+        if hidden {
+            self.vm().log(RecipientCommitted {
+                intentId: intent_id,
+                recipientHash: intent.recipientHash,
+                receiver: intent.recipient,
+            });
+        }
+
+        let adapter = self.resolve_bridge_adapter(token, intent.destinationChain);
+        if family == ChainFamily::Evm {
+            self.internal_execute_bridge(
+                intent_id,
+                adapter,
+                token,
+                net_amount,
+                intent.destinationChain,
+                intent.recipient,
+                false,
+                U256::ZERO,
+                U256::ZERO,
+            )?;
+        } else {
+            self.internal_execute_chain_address_bridge(intent_id, adapter, token, net_amount, &intent)?;
+        }
+
+        self.complete_intent_record(&ctx, intent_id, net_amount);
+
+        self.vm().log(IntentExecuted {
+            intentId: intent_id,
+            user: intent.user,
+            destinationChain: intent.destinationChain,
+            tokenIn: intent.tokenIn,
+            timestamp: ctx.now,
+        });
+
+        self.locked.set(false);
+
+        Ok(intent_id)
+    }
+
+    /// Bridge an ERC-721 or ERC-1155 token to the destination chain
+    ///
+    /// `intent.assetType` selects the standard; `tokenIn` is the collection,
+    /// `tokenId` the token and `amountIn` the quantity (one for ERC-721).
+    /// The validator checks ownership and that this contract is approved,
+    /// then the token is pulled and handed to the NFT adapter registered for
+    /// `destinationChain`, whose messaging fee comes from this contract's
+    /// balance. No swap or protocol fee applies, and the intent can't be
+    /// insured. Callable by the intent's user.
+    pub fn execute_nft_transfer(&mut self, intent: Bytes) -> Result<U256, RouteExecutorError> {
+        let intent = route_codec::decode_intent(&intent).map_err(RouteExecutorError::from)?;
+        let ctx = self.execution_context()?;
+
+        if self.solver_only.get() {
+            return Err(RouteExecutorError::SolverOnly(SolverOnly {}));
+        }
+        if ctx.sender != intent.user {
+            return Err(RouteExecutorError::Unauthorized(Unauthorized {}));
+        }
+
+        check_deadline(intent.deadline, ctx.now)?;
+        self.check_block_binding(&intent)?;
+        self.check_config_epoch(&ctx, intent.configEpoch)?;
+
+        let asset = AssetType::from_u8(intent.assetType)
+            .filter(|asset| asset.is_nft())
+            .ok_or(RouteExecutorError::UnsupportedAsset(UnsupportedAsset { assetType: intent.assetType }))?;
+        if intent.recipient == Address::ZERO || intent.recipientHash != FixedBytes::ZERO {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+        if intent.gasDrop != U256::ZERO {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+        if intent.insurancePremiumBps != U256::ZERO {
+            return Err(RouteExecutorError::InsuranceUnavailable(InsuranceUnavailable {}));
+        }
+        if intent.retryPolicy != U256::ZERO {
+            return Err(RouteExecutorError::InvalidRetryPolicy(InvalidRetryPolicy {}));
+        }
+
+        let adapter_address = self.nft_bridge_adapters.get(intent.destinationChain);
+        if adapter_address == Address::ZERO {
+            return Err(RouteExecutorError::BridgeFailed(BridgeFailed {}));
+        }
+
+        let valid = IIntentValidator::new(ctx.validator)
+            .validate_nft_intent(
+                self.vm(),
+                Call::new(),
+                intent.user,
+                intent.assetType,
+                intent.tokenIn,
+                intent.tokenId,
+                intent.amountIn,
+                intent.destinationChain,
+                ctx.this,
+            )
+            .unwrap_or(false);
+        if !valid {
+            return Err(RouteExecutorError::ValidationFailed(ValidationFailed {}));
+        }
+
+        self.check_not_locked()?;
+        self.locked.set(true);
+
+        let intent_hash = signing::intent_digest(&intent, ctx.chain_id, ctx.this);
+        let intent_id = self.register_intent(&ctx, intent_hash, signing::intent_key(&intent))?;
+        self.open_intent_record(&ctx, intent_id, &intent);
+        self.register_callback(intent_id, &intent)?;
+
+        // Pull the token, then let the adapter take it from here
+        let token = intent.tokenIn;
+        let (pull, approve) = if asset == AssetType::Erc721 {
+            (
+                transferFromCall { from: intent.user, to: ctx.this, amount: intent.tokenId }.abi_encode(),
+                approveCall { spender: adapter_address, amount: intent.tokenId }.abi_encode(),
+            )
+        } else {
+            (
+                safeTransferFromCall {
+                    from: intent.user,
+                    to: ctx.this,
+                    id: intent.tokenId,
+                    amount: intent.amountIn,
+                    data: Bytes::new(),
+                }
+                .abi_encode(),
+                setApprovalForAllCall { operator: adapter_address, approved: true }.abi_encode(),
+            )
+        };
+        if !self.guarded_token_call(token, &pull) {
+            return Err(RouteExecutorError::TransferFailed(TransferFailed {}));
+        }
+        if !self.guarded_token_call(token, &approve) {
+            return Err(RouteExecutorError::BridgeFailed(BridgeFailed {}));
+        }
+
+        let adapter = INftBridgeAdapter::new(adapter_address);
+        let fee = adapter
+            .quote_nft_fee(
+                self.vm(),
+                Call::new(),
+                intent.destinationChain,
+                intent.assetType,
+                token,
+                intent.tokenId,
+                intent.amountIn,
+                intent.recipient,
+            )
+            .map_err(|_| RouteExecutorError::BridgeFailed(BridgeFailed {}))?;
+        let calldata = bridgeNftCall {
+            intent_id,
+            destination_chain: intent.destinationChain,
+            asset_type: intent.assetType,
+            token,
+            token_id: intent.tokenId,
+            amount: intent.amountIn,
+            recipient: intent.recipient,
+        }
+        .abi_encode();
+        self.guarded_call(adapter_address, fee, ADAPTER_CALL_GAS, &calldata)
+            .ok_or(RouteExecutorError::BridgeFailed(BridgeFailed {}))?;
+
+        // Operator approval covers the whole collection; don't leave it standing
+        if asset == AssetType::Erc1155 {
+            let revoke = setApprovalForAllCall { operator: adapter_address, approved: false }.abi_encode();
+            if !self.guarded_token_call(token, &revoke) {
+                return Err(RouteExecutorError::BridgeFailed(BridgeFailed {}));
+            }
+        }
+
+        self.complete_intent_record(&ctx, intent_id, intent.amountIn);
+
+        self.vm().log(NftBridgeInitiated {
+            intentId: intent_id,
+            assetType: intent.assetType,
+            token,
+            tokenId: intent.tokenId,
+            amount: intent.amountIn,
+            destinationChain: intent.destinationChain,
+            recipient: intent.recipient,
+        });
+
+        self.vm().log(IntentExecuted {
+            intentId: intent_id,
+            user: intent.user,
+            destinationChain: intent.destinationChain,
+            tokenIn: intent.tokenIn,
+            timestamp: ctx.now,
+        });
+
+        self.locked.set(false);
+
+        Ok(intent_id)
+    }
+
+    /// Accept ERC-1155 transfers, only while an NFT intent is pulling them
+    #[selector(name = "onERC1155Received")]
+    pub fn on_erc1155_received(
+        &mut self,
+        _operator: Address,
+        _from: Address,
+        _id: U256,
+        _value: U256,
+        _data: Bytes,
+    ) -> Result<FixedBytes<4>, RouteExecutorError> {
+        if !self.locked.get() {
+            return Err(RouteExecutorError::UnsupportedAsset(UnsupportedAsset {
+                assetType: AssetType::Erc1155 as u8,
+            }));
+        }
+        Ok(FixedBytes::from(ERC1155_RECEIVED))
+    }
+
+    /// Settle one leg of a matched pair of opposing intents (solvers or their operator keys)
+    ///
+    /// `intent` is a user-signed intent on this chain and `counterparty` a
+    /// user-signed intent on `intent.destinationChain` heading back here,
+    /// signed for the RouteExecutor registered for that chain. Both must be
+    /// in the chains' native USDC. Instead of bridging, `matched_amount` of
+    /// this user's input (after fees) is paid here to the counterparty's
+    /// recipient and only the residual is bridged to this user's recipient.
+    ///
+    /// `matched_amount` must satisfy `get_fill_rule` for USDC here, and the
+    /// mirror leg checks it against the rule on its own chain.
+    ///
+    /// The solver submits the mirror leg on the other chain with the same
+    /// `matched_amount`. Both legs derive the same match ID, and this leg
+    /// stays pending in SettlementVerifier, with the solver's bond at stake,
+    /// until the mirror leg is reported there.
+    ///
+    /// Nothing is paid to the counterparty until then: `matched_amount` is
+    /// held here, reserved, and `release_matched_payout` pays it out once the
+    /// verifier confirms the mirror leg pulled the counterparty's funds on its
+    /// chain, or refunds this user if the leg fails. A counterparty intent
+    /// can be matched by one leg only.
+    pub fn execute_matched_leg(
+        &mut self,
+        intent: Bytes,
+        signature: Bytes,
+        counterparty: Bytes,
+        counterparty_signature: Bytes,
+        matched_amount: U256,
+    ) -> Result<U256, RouteExecutorError> {
+        let intent = route_codec::decode_intent(&intent).map_err(RouteExecutorError::from)?;
+        let counterparty = route_codec::decode_intent(&counterparty).map_err(RouteExecutorError::from)?;
+
+        let (solver, solver_id) = self.active_solver(SCOPE_MATCH)?;
+
+        let ctx = self.execution_context()?;
+        check_deadline(intent.deadline, ctx.now)?;
+        check_deadline(counterparty.deadline, ctx.now)?;
+        self.check_block_binding(&intent)?;
+
+        // Opposing native USDC flows between this chain and the intent's destination
+        let chain_id = U256::from(ctx.chain_id);
+        let remote_chain = intent.destinationChain;
+        let remote_executor = self.remote_executors.get(remote_chain);
+        let usdc = self.native_usdc.get(chain_id);
+        let remote_chain_id: u64 = remote_chain
+            .try_into()
+            .map_err(|_| RouteExecutorError::InvalidMatch(InvalidMatch {}))?;
+        if remote_executor == Address::ZERO
+            || usdc == Address::ZERO
+            || intent.tokenIn != usdc
+            || counterparty.tokenIn != self.native_usdc.get(remote_chain)
+            || counterparty.destinationChain != chain_id
+            || intent.recipient == Address::ZERO
+            || counterparty.recipient == Address::ZERO
+            || intent.gasDrop != U256::ZERO
+            || counterparty.gasDrop != U256::ZERO
+            || intent.assetType != AssetType::Erc20 as u8
+            || counterparty.assetType != AssetType::Erc20 as u8
+            || intent.recipientHash != FixedBytes::ZERO
+            || counterparty.recipientHash != FixedBytes::ZERO
+            || intent.insurancePremiumBps != U256::ZERO
+            || intent.retryPolicy != U256::ZERO
+        {
+            return Err(RouteExecutorError::InvalidMatch(InvalidMatch {}));
+        }
+
+        // The counterparty's own chain checks the amount against its net input
+        let (net_amount, protocol_fee) = fees::take_fee(intent.amountIn, self.get_fee_bps(usdc));
+        if matched_amount == U256::ZERO || matched_amount > net_amount || matched_amount > counterparty.amountIn {
+            return Err(RouteExecutorError::InvalidMatch(InvalidMatch {}));
+        }
+        self.check_fill_amount(usdc, matched_amount)?;
+
+        // This user's recipient gets the mirror leg's payout plus the residual
+        check_min_out(net_amount, intent.minAmountOut)?;
+
+        self.check_lane_healthy(&ctx, remote_chain)?;
+        self.check_config_epoch(&ctx, intent.configEpoch)?;
+
+        // The solver must be on the hook before a matched leg can pay anyone
+        let verifier = self.settlement_verifier.get();
+        if verifier == Address::ZERO {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+        self.check_solver_capacity(solver_id, usdc, intent.amountIn)?;
+
+        let intent_hash = self.consume_signed_intent(&ctx, &intent, &signature, solver_id)?;
+
+        // Replay of the counterparty is prevented by its own executor consuming it
+        let counterparty_hash = signing::intent_digest(&counterparty, remote_chain_id, remote_executor);
+        if self.recover_signer(counterparty_hash, &counterparty_signature)? != counterparty.user {
+            return Err(RouteExecutorError::InvalidSignature(InvalidSignature {}));
+        }
+        self.check_intent_not_blocked(&ctx, counterparty_hash)?;
+        if self.matched_counterparties.get(counterparty_hash) != U256::ZERO {
+            return Err(RouteExecutorError::InvalidMatch(InvalidMatch {}));
+        }
+
+        self.check_not_locked()?;
+        self.locked.set(true);
+
+        let intent_id = self.register_intent(&ctx, intent_hash, signing::intent_key(&intent))?;
+        self.open_intent_record(&ctx, intent_id, &intent);
+        self.register_callback(intent_id, &intent)?;
+
+        self.pull_token(usdc, intent.user, intent.amountIn)?;
+        self.collect_protocol_fee(intent_id, usdc, protocol_fee);
+
+        // Held until the mirror leg is confirmed; see `release_matched_payout`
+        self.matched_counterparties.setter(counterparty_hash).set(intent_id);
+        self.matched_payouts.setter(intent_id).set(matched_amount);
+        self.matched_tokens.setter(intent_id).set(usdc);
+        self.matched_recipients.setter(intent_id).set(counterparty.recipient);
+        self.reserve(usdc, matched_amount);
+
+        let residual = net_amount - matched_amount;
+        if residual != U256::ZERO {
+            let adapter = self.resolve_bridge_adapter(usdc, remote_chain);
+            self.internal_execute_bridge(
+                intent_id,
+                adapter,
+                usdc,
+                residual,
+                remote_chain,
+                intent.recipient,
+                false,
+                U256::ZERO,
+                U256::ZERO,
+            )?;
+        }
+
+        let match_id = signing::match_id(intent_hash, counterparty_hash, matched_amount);
+
+        self.complete_intent_record(&ctx, intent_id, net_amount);
+
+        self.vm().log(IntentExecuted {
+            intentId: intent_id,
+            user: intent.user,
+            destinationChain: intent.destinationChain,
+            tokenIn: intent.tokenIn,
+            timestamp: ctx.now,
+        });
+        self.vm().log(IntentMatched {
+            intentId: intent_id,
+            matchId: match_id,
+            counterpartyHash: counterparty_hash,
+            matchedAmount: matched_amount,
+            residualAmount: residual,
+        });
+
+        self.locked.set(false);
+
+        let config = Call::new_mutating(self);
+        ISettlementVerifier::new(verifier)
+            .register_matched_leg(self.vm(), config, intent_id, solver_id, match_id, usdc, intent.amountIn)
+            .map_err(|_| RouteExecutorError::ValidationFailed(ValidationFailed {}))?;
+
+        self.vm().log(IntentExecutedBySolver {
+            intentId: intent_id,
+            solverId: solver_id,
+            solver,
+            intentHash: intent_hash,
+        });
+
+        Ok(intent_id)
+    }
+
+    /// Release the amount a matched leg holds once SettlementVerifier has settled it
+    ///
+    /// Anyone may call this. A confirmed leg, whose mirror leg executed on the
+    /// other chain, pays the counterparty's recipient; a failed or refunded
+    /// one returns the amount to this leg's user. Reverts with
+    /// `MatchNotSettled` while the leg is pending. Returns the amount released.
+    pub fn release_matched_payout(&mut self, intent_id: U256) -> Result<U256, RouteExecutorError> {
+        let amount = self.matched_payouts.get(intent_id);
+        if amount == U256::ZERO {
+            return Err(RouteExecutorError::InvalidMatch(InvalidMatch {}));
+        }
+
+        let status = ISettlementVerifier::new(self.settlement_verifier.get())
+            .get_settlement_status(self.vm(), Call::new(), intent_id)
+            .unwrap_or_default();
+        let (to, refunded) = if status == U256::from(SETTLEMENT_CONFIRMED) {
+            (self.matched_recipients.get(intent_id), false)
+        } else if status == U256::from(SETTLEMENT_FAILED) || status == U256::from(SETTLEMENT_REFUNDED) {
+            (self.intent_users.get(intent_id), true)
+        } else {
+            return Err(RouteExecutorError::MatchNotSettled(MatchNotSettled { intentId: intent_id }));
+        };
+
+        self.check_not_locked()?;
+        self.locked.set(true);
+
+        let token = self.matched_tokens.get(intent_id);
+        self.matched_payouts.setter(intent_id).set(U256::ZERO);
+        self.release_reserved(token, amount);
+        self.internal_send(token, to, amount)?;
+
+        self.locked.set(false);
+
+        self.vm().log(MatchedPayoutReleased {
+            intentId: intent_id,
+            to,
+            amount,
+            refunded,
+        });
+
+        Ok(amount)
+    }
+
+    /// Get the amount a matched leg holds, its token and its counterparty recipient
+    pub fn get_matched_payout(&self, intent_id: U256) -> (U256, Address, Address) {
+        (
+            self.matched_payouts.get(intent_id),
+            self.matched_tokens.get(intent_id),
+            self.matched_recipients.get(intent_id),
+        )
+    }
+
+    /// Credit tokens bridged in for a hidden-recipient intent (SettlementVerifier only)
+    ///
+    /// The verifier forwards deliveries its trusted adapters report for an
+    /// intent hash. The credit can only come out of this contract's
+    /// unaccounted balance of `token`, never escrowed, reserved or fee
+    /// balances, and is reserved until `settle_hidden_recipient` pays it out.
+    pub fn credit_hidden_delivery(
+        &mut self,
+        intent_hash: FixedBytes<32>,
+        token: Address,
+        amount: U256,
+    ) -> Result<(), RouteExecutorError> {
+        if self.vm().msg_sender() != self.settlement_verifier.get() {
+            return Err(RouteExecutorError::Unauthorized(Unauthorized {}));
+        }
+        if amount == U256::ZERO || self.settled_hidden_recipients.get(intent_hash) {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+        if amount > self.get_dust(token)? {
+            return Err(RouteExecutorError::TransferFailed(TransferFailed {}));
+        }
+
+        let credited = self.hidden_deliveries.getter(intent_hash).get(token) + amount;
+        self.hidden_deliveries.setter(intent_hash).setter(token).set(credited);
+        self.reserve(token, amount);
+
+        self.vm().log(HiddenDeliveryCredited {
+            intentHash: intent_hash,
+            token,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    /// Get the delivered amount of a token credited to a hidden-recipient intent
+    pub fn get_hidden_delivery(&self, intent_hash: FixedBytes<32>, token: Address) -> U256 {
+        self.hidden_deliveries.getter(intent_hash).get(token)
+    }
+
+    /// Pay out a hidden-recipient intent bridged to this chain (solvers or their operator keys)
+    ///
+    /// Destination leg of an intent with a `recipientHash`: the source chain
+    /// bridged the output to this contract. `intent` is the user's intent,
+    /// signed for the RouteExecutor registered for `source_chain`, and the
+    /// solver reveals the `recipient` and `salt` behind its commitment.
+    /// `amount` of `token` must cover the intent's `minAmountOut` and is paid
+    /// to the recipient out of the delivery credited to the intent through
+    /// `credit_hidden_delivery`, which it must use up. Each intent settles once.
+    #[allow(clippy::too_many_arguments)]
+    pub fn settle_hidden_recipient(
+        &mut self,
+        intent: Bytes,
+        signature: Bytes,
+        source_chain: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+        salt: FixedBytes<32>,
+    ) -> Result<(), RouteExecutorError> {
+        let intent = route_codec::decode_intent(&intent).map_err(RouteExecutorError::from)?;
+
+        let (_, solver_id) = self.active_solver(SCOPE_SETTLE)?;
+
+        if self.paused.get().into() {
+            return Err(RouteExecutorError::ContractPaused(ContractPaused {}));
+        }
+
+        let source_executor = self.remote_executors.get(source_chain);
+        let source_chain_id: u64 = source_chain
+            .try_into()
+            .map_err(|_| RouteExecutorError::InvalidRecipientReveal(InvalidRecipientReveal {}))?;
+        if source_executor == Address::ZERO
+            || intent.recipientHash == FixedBytes::ZERO
+            || intent.destinationChain != U256::from(self.vm().chain_id())
+        {
+            return Err(RouteExecutorError::InvalidRecipientReveal(InvalidRecipientReveal {}));
+        }
+
+        // The user's signature is what binds the commitment to this intent
+        let intent_hash = signing::intent_digest(&intent, source_chain_id, source_executor);
+        if self.recover_signer(intent_hash, &signature)? != intent.user {
+            return Err(RouteExecutorError::InvalidSignature(InvalidSignature {}));
+        }
+        if self.settled_hidden_recipients.get(intent_hash) {
+            return Err(RouteExecutorError::IntentAlreadyUsed(IntentAlreadyUsed { intentHash: intent_hash }));
+        }
+        if recipient == Address::ZERO || signing::recipient_commitment(recipient, salt) != intent.recipientHash {
+            return Err(RouteExecutorError::InvalidRecipientReveal(InvalidRecipientReveal {}));
+        }
+        check_min_out(amount, intent.minAmountOut)?;
+
+        // Only what the verifier credited to this intent can be paid out
+        let credited = self.hidden_deliveries.getter(intent_hash).get(token);
+        if amount == U256::ZERO || amount != credited {
+            return Err(RouteExecutorError::DeliveryNotCredited(DeliveryNotCredited {
+                intentHash: intent_hash,
+                credited,
+            }));
+        }
+
+        self.check_not_locked()?;
+        self.locked.set(true);
+
+        self.settled_hidden_recipients.setter(intent_hash).set(true);
+        self.hidden_deliveries.setter(intent_hash).setter(token).set(credited - amount);
+        self.release_reserved(token, amount);
+        self.internal_send(token, recipient, amount)?;
+
+        self.vm().log(HiddenRecipientSettled {
+            intentHash: intent_hash,
+            recipient,
+            token,
+            amount,
+            solverId: solver_id,
+        });
+
+        self.locked.set(false);
+
+        Ok(())
+    }
+
+    /// Execute a complete cross-chain route
+    /// 
+    /// Single optional swap followed by a bridge, expressed as a route for
+    /// `execute_route`. `swap_data` is empty or `abi.encode(SwapParams)`.
+    /// Reverts with `DeadlineExpired` once `block.timestamp` passes
+    /// `deadline`, so stale quotes can't execute at bad prices.
+    /// 
+    /// Steps:
+    /// 1. Validate intent through IntentValidator
+    /// 2. Transfer tokens from user
+    /// 3. Execute swap (if needed)
+    /// 4. Check the bridged amount against `min_amount_out`
+    /// 5. Initiate bridge transfer
+    /// 6. Emit tracking events
+    ///
+    /// Pass `NATIVE_TOKEN` as `token_in` with `amount` as msg.value to start
+    /// from ETH; it is wrapped into WETH before the swap. Bridged WETH from
+    /// an ETH input arrives as ETH on chains with a registered wrapped native
+    /// (see `set_wrapped_native`).
+    ///
+    /// `integrator` (zero for none) earns `integrator_fee_bps` of the input,
+    /// up to the admin cap, minus the protocol's share.
+    ///
+    /// `route_quote` is empty or `abi.encode(RouteQuote)` signed by a
+    /// registered route oracle (`route_signature`, 65 bytes). The quote must
+    /// name this intent's hash (nonce zero, caller as user) and the route's
+    /// hash, be unexpired, and promise at least `min_amount_out`. Required
+    /// while `is_route_quote_required` is on. The output the route delivers
+    /// is recorded against the quote (see `get_intent_quote`).
+    ///
+    /// `bridge_candidates` is empty to bridge through the lane's registered
+    /// adapter, or lists adapters to compare: the cheapest of those enabled
+    /// for the lane is picked at execution time (see `quote_cheapest_adapter`).
+    #[payable]
+    #[allow(clippy::too_many_arguments)]
+    pub fn execute_full_route(
+        &mut self,
+        token_in: Address,
+        amount: U256,
+        destination_chain: U256,
+        recipient: Address,
+        min_amount_out: U256,
+        deadline: U256,
+        swap_data: Bytes,
+        integrator: Address,
+        integrator_fee_bps: U256,
+        route_quote: Bytes,
+        route_signature: Bytes,
+        bridge_candidates: Vec<Address>,
+    ) -> Result<U256, RouteExecutorError> {
+        let ctx = self.execution_context()?;
+        let intent = Intent {
+            user: ctx.sender,
+            tokenIn: token_in,
+            amountIn: amount,
+            destinationChain: destination_chain,
+            recipient,
+            minAmountOut: min_amount_out,
+            deadline,
+            nonce: U256::ZERO,
+            gasDrop: U256::ZERO,
+            assetType: AssetType::Erc20 as u8,
+            tokenId: U256::ZERO,
+            recipientHash: FixedBytes::ZERO,
+            maxSolverFee: U256::ZERO,
+            priorityFee: U256::ZERO,
+            blockNumber: U256::ZERO,
+            blockHash: FixedBytes::ZERO,
+            configEpoch: U256::ZERO,
+            callback: Address::ZERO,
+            callbackData: Bytes::new(),
+            recipientFamily: ChainFamily::Evm as u8,
+            recipientAddress: Bytes::new(),
+            insurancePremiumBps: U256::ZERO,
+            retryPolicy: U256::ZERO,
+            memo: FixedBytes::ZERO,
+        };
+
+        // Optional swap: abi.encode(SwapParams)
+        let swap = if swap_data.is_empty() {
+            None
+        } else {
+            Some(route_codec::decode_swap_params(&swap_data).map_err(RouteExecutorError::from)?)
+        };
+        let mut steps = route_codec::full_route_steps(token_in, ctx.weth, swap);
+        // ETH in, ETH out when the destination's wrapped native is known
+        if token_in == NATIVE_TOKEN && self.wrapped_natives.get(destination_chain) != Address::ZERO {
+            route_codec::request_native_delivery(&mut steps, ctx.weth);
+        }
+        if !bridge_candidates.is_empty() {
+            if let Some(bridge) = steps.last_mut() {
+                bridge.data = route_codec::encode_adapter_allowlist(&bridge_candidates);
+            }
+        }
+
+        let intent_hash = signing::intent_digest(&intent, ctx.chain_id, ctx.this);
+        let quote = self.check_route_quote(&ctx, intent_hash, &intent, &steps, &route_quote, &route_signature)?;
+
+        self.internal_execute_route(
+            &ctx,
+            intent_hash,
+            intent,
+            steps,
+            integrator,
+            integrator_fee_bps,
+            U256::ZERO,
+            quote,
+            false,
+        )
+        .map(|outcome| outcome.intent_id)
+    }
+
+    /// Estimate what a route delivers, without executing it
+    ///
+    /// Takes the same `intent` and `steps` as `execute_route` and returns the
+    /// output reaching the final step (after the protocol fee) and the native
+    /// bridge fee, including the price of the intent's gas drop. Reverts like
+    /// `execute_route` on an invalid route and with `SlippageExceeded` when a
+    /// quote already misses a step's or the intent's minimum. An output of zero
+    /// means a swap adapter couldn't quote.
+    ///
+    /// Swap adapters simulate swaps to quote, so this is not a view; call it
+    /// with `eth_call`.
+    pub fn quote_route(&mut self, intent: Bytes, steps: Bytes) -> Result<(U256, U256), RouteExecutorError> {
+        let mut intent = route_codec::decode_intent(&intent).map_err(RouteExecutorError::from)?;
+        let steps = route_codec::decode_route(&steps).map_err(RouteExecutorError::from)?;
+        self.resolve_hidden_recipient(&mut intent)?;
+
+        let weth = self.weth.get();
+        route_codec::validate_route(&intent, &steps, weth, |adapter| self.swap_adapters.get(adapter))
+            .map_err(RouteExecutorError::from)?;
+        self.check_native_delivery(&intent, &steps)?;
+        self.check_gas_drop(&intent, &steps)?;
+        self.check_gas_limit(&intent, &steps)?;
+
+        let (insured_amount, _) = fees::take_fee(intent.amountIn, self.premium_bps_for(&intent)?);
+        let fee_bps =
+            self.quote_protocol_fee_bps(Address::ZERO, intent.tokenIn, intent.amountIn, intent.destinationChain);
+        let (mut current_amount, _) = fees::take_fee(insured_amount, fee_bps);
+        let mut current_token = intent.tokenIn;
+        let mut bridge_fee = U256::ZERO;
+
+        for step in steps {
+            let step_type = StepType::from_u8(step.stepType)
+                .ok_or(RouteExecutorError::UnsupportedStep(UnsupportedStep {}))?;
+
+            match step_type {
+                StepType::Swap => {
+                    let amount_out = self.quote_swap(&step, current_token, current_amount);
+                    if amount_out == U256::ZERO {
+                        return Ok((U256::ZERO, U256::ZERO));
+                    }
+
+                    check_min_out(amount_out, step.minAmountOut)?;
+                    current_token = step.tokenOut;
+                    current_amount = amount_out;
+                }
+                StepType::Bridge => {
+                    check_min_out(current_amount, intent.minAmountOut)?;
+
+                    let (candidates, gas_limit) = route_codec::decode_bridge_step_data(&step.data)?;
+                    let adapter = if candidates.is_empty() {
+                        self.resolve_bridge_adapter(current_token, intent.destinationChain)
+                    } else {
+                        let (adapter, _) = self.quote_cheapest_adapter(
+                            current_token,
+                            current_amount,
+                            intent.destinationChain,
+                            intent.recipient,
+                            candidates,
+                        )?;
+                        adapter
+                    };
+                    bridge_fee = self.quote_bridge_fee(adapter, current_token, current_amount, &intent, gas_limit)?;
+                }
+                StepType::Transfer => {
+                    check_min_out(current_amount, intent.minAmountOut)?;
+                }
+                StepType::Wrap => current_token = weth,
+                StepType::Unwrap => current_token = NATIVE_TOKEN,
+            }
+        }
+
+        Ok((current_amount, bridge_fee))
+    }
+
+    /// Get intent execution status
+    pub fn get_intent_status(&self, intent_id: U256) -> U256 {
+        U256::from(intent_record::header_status(self.intent_headers.get(intent_id)))
+    }
+
+    /// Get an intent's execution record: status, destination chain, when it
+    /// started and last changed, its input and what it delivered
+    ///
+    /// All zero if the intent was never executed on this chain.
+    pub fn get_intent_execution(&self, intent_id: U256) -> (U256, U256, U256, U256, U256, U256) {
+        let record = self.intent_record(intent_id);
+        (
+            U256::from(record.status),
+            U256::from(record.destination_chain),
+            U256::from(record.created_at),
+            U256::from(record.updated_at),
+            record.amount_in,
+            record.amount_out,
+        )
+    }
+
+    /// Get the user an intent was executed for (zero if never executed here)
+    pub fn get_intent_user(&self, intent_id: U256) -> Address {
+        self.intent_users.get(intent_id)
+    }
+
+    /// Get the memo an intent was signed with (zero if none or never executed here)
+    pub fn get_intent_memo(&self, intent_id: U256) -> FixedBytes<32> {
+        self.intent_memos.get(intent_id)
+    }
+
+    /// Resolve an intent hash to its local record: the global intent ID, the
+    /// local index and the execution status
+    ///
+    /// All zero if the intent was never executed on this chain.
+    pub fn get_intent_record(&self, intent_hash: FixedBytes<32>) -> (U256, U256, U256) {
+        let index = self.intent_indexes.get(intent_hash);
+        if index == U256::ZERO {
+            return (U256::ZERO, U256::ZERO, U256::ZERO);
+        }
+        let intent_id = signing::global_intent_id(intent_hash);
+        (intent_id, index, self.get_intent_status(intent_id))
+    }
+
+    /// Get the hash of the intent executed at a local index (zero if none)
+    pub fn get_intent_hash_at(&self, index: U256) -> FixedBytes<32> {
+        self.indexed_intents.get(index)
+    }
+
+    /// Get the number of intents executed on this chain
+    pub fn intent_count(&self) -> U256 {
+        self.intent_counter.get()
+    }
+
+    /// Pause contract (admin only)
+    pub fn pause(&mut self) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+        self.paused.set(true);
+        self.paused_at.set(U256::from(self.vm().block_timestamp()));
+        
+        self.vm().log(Paused {
+            by: self.vm().msg_sender(),
+        });
+
+        Ok(())
+    }
+
+    /// Unpause contract (admin only)
+    pub fn unpause(&mut self) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+        self.paused.set(false);
+        self.paused_at.set(U256::ZERO);
+        self.emergency_mode.set(false);
+        
+        self.vm().log(Unpaused {
+            by: self.vm().msg_sender(),
+        });
+
+        Ok(())
+    }
+
+    /// Enable emergency mode during a prolonged pause (admin only)
+    ///
+    /// Available once the contract has been paused for three days; lets
+    /// users withdraw escrowed intents without waiting for their deadline.
+    /// Unpausing ends emergency mode.
+    pub fn enable_emergency_mode(&mut self) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        let paused_at = self.paused_at.get();
+        let available_at = paused_at + U256::from(EMERGENCY_PAUSE_DELAY);
+        if !self.paused.get() || U256::from(self.vm().block_timestamp()) < available_at {
+            return Err(RouteExecutorError::EmergencyModeLocked(EmergencyModeLocked {
+                availableAt: available_at,
+            }));
+        }
+
+        self.emergency_mode.set(true);
+
+        self.vm().log(EmergencyModeEnabled { pausedAt: paused_at });
+
+        Ok(())
+    }
+
+    /// Check if emergency mode is on
+    pub fn is_emergency_mode(&self) -> bool {
+        self.emergency_mode.get()
+    }
+
+    /// Get the time the current pause began (zero while unpaused)
+    pub fn paused_at(&self) -> U256 {
+        self.paused_at.get()
+    }
+
+    /// Set the bridge adapter serving a destination chain (admin only)
+    ///
+    /// Passing the zero address routes the lane back through CCIP.
+    pub fn set_bridge_adapter(
+        &mut self,
+        destination_chain: U256,
+        adapter: Address,
+    ) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        if destination_chain == U256::ZERO {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+        if adapter != Address::ZERO {
+            self.require_interface(adapter, erc165::BRIDGE_ADAPTER_ID)?;
+        }
+
+        self.bridge_adapters.setter(destination_chain).set(adapter);
+        self.index_config(pack_config_key(ConfigEntryKind::BridgeAdapter, destination_chain));
+
+        self.vm().log(BridgeAdapterSet {
+            destinationChain: destination_chain,
+            adapter,
+        });
+
+        Ok(())
+    }
+
+    /// Get the bridge adapter serving a destination chain
+    pub fn get_bridge_adapter(&self, destination_chain: U256) -> Address {
+        self.bridge_adapters.get(destination_chain)
+    }
+
+    /// Enable or disable an extra adapter for a destination chain (admin only)
+    ///
+    /// Enabled adapters compete with the lane's registered adapter when a
+    /// route's Bridge step asks for the cheapest lane.
+    pub fn set_lane_adapter(
+        &mut self,
+        destination_chain: U256,
+        adapter: Address,
+        enabled: bool,
+    ) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        if destination_chain == U256::ZERO {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+
+        if adapter == Address::ZERO {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+        if enabled {
+            self.require_interface(adapter, erc165::BRIDGE_ADAPTER_ID)?;
+        }
+
+        self.lane_adapters.setter(destination_chain).setter(adapter).set(enabled);
+
+        self.vm().log(LaneAdapterSet {
+            destinationChain: destination_chain,
+            adapter,
+            enabled,
+        });
+
+        Ok(())
+    }
+
+    /// Check if an adapter may carry a token to a destination chain
+    ///
+    /// True for the adapter `resolve_bridge_adapter` picks and for adapters
+    /// enabled with `set_lane_adapter`.
+    pub fn is_lane_adapter(&self, token: Address, destination_chain: U256, adapter: Address) -> bool {
+        if adapter == Address::ZERO {
+            return false;
+        }
+        adapter == self.resolve_bridge_adapter(token, destination_chain)
+            || self.lane_adapters.getter(destination_chain).get(adapter)
+    }
+
+    /// Quote the cheapest of `candidates` for a transfer
+    ///
+    /// Candidates not enabled for the lane, not serving it, or failing to
+    /// quote are skipped. Returns the adapter and its native fee; ties go to
+    /// the earlier candidate.
+    pub fn quote_cheapest_adapter(
+        &self,
+        token: Address,
+        amount: U256,
+        destination_chain: U256,
+        recipient: Address,
+        candidates: Vec<Address>,
+    ) -> Result<(Address, U256), RouteExecutorError> {
+        if candidates.is_empty() || candidates.len() > route_codec::MAX_LANE_CANDIDATES {
+            return Err(RouteExecutorError::from(RouteError::BadAdapterAllowlist));
+        }
+
+        let mut quotes = Vec::with_capacity(candidates.len());
+        for candidate in candidates {
+            if !self.is_lane_adapter(token, destination_chain, candidate) {
+                continue;
+            }
+
+            let adapter = IBridgeAdapter::new(candidate);
+            if !adapter
+                .supports_lane(self.vm(), Call::new(), destination_chain)
+                .unwrap_or(false)
+            {
+                continue;
+            }
+
+            if let Ok(fee) = adapter.quote_fee(self.vm(), Call::new(), destination_chain, token, amount, recipient) {
+                quotes.push((candidate, fee));
+            }
+        }
+
+        route_codec::cheapest_quote(&quotes).ok_or(RouteExecutorError::AdapterNotAllowed(AdapterNotAllowed {}))
+    }
+
+    /// Set the CCTP adapter used for native USDC lanes (admin only)
+    pub fn set_cctp_adapter(&mut self, adapter: Address) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+        if adapter != Address::ZERO {
+            self.require_interface(adapter, erc165::BRIDGE_ADAPTER_ID)?;
+        }
+        self.cctp_adapter.set(adapter);
+
+        self.vm().log(CctpAdapterSet { adapter });
+
+        Ok(())
+    }
+
+    /// Register the native USDC address for a chain (admin only)
+    pub fn set_native_usdc(&mut self, chain_id: U256, token: Address) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        if chain_id == U256::ZERO {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+
+        self.native_usdc.setter(chain_id).set(token);
+
+        self.vm().log(NativeUsdcSet { chainId: chain_id, token });
+
+        Ok(())
+    }
+
+    /// Set the minimum size and step of a token's partial fills (admin only)
+    ///
+    /// `min_fill` must be a multiple of `granularity`. A zero `granularity`
+    /// goes back to the rule derived from the token's decimals.
+    pub fn set_fill_rule(
+        &mut self,
+        token: Address,
+        min_fill: U256,
+        granularity: U256,
+    ) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        if token == Address::ZERO {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+        let rule = FillRule { min_fill, granularity };
+        if granularity != U256::ZERO && !rule.is_valid() {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let min_fill = if granularity == U256::ZERO { U256::ZERO } else { min_fill };
+        self.fill_min_amounts.setter(token).set(min_fill);
+        self.fill_granularities.setter(token).set(granularity);
+
+        self.vm().log(FillRuleSet {
+            token,
+            minFill: min_fill,
+            granularity,
+        });
+
+        Ok(())
+    }
+
+    /// Register the same asset as `token` on a destination chain (admin only)
+    ///
+    /// A zero `remote_token` removes the pair from the stable fast path.
+    pub fn set_canonical_token(
+        &mut self,
+        token: Address,
+        chain_id: U256,
+        remote_token: Address,
+    ) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        if token == Address::ZERO || token == NATIVE_TOKEN {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+        if chain_id == U256::ZERO {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+
+        self.canonical_tokens.setter(token).setter(chain_id).set(remote_token);
+
+        self.vm().log(CanonicalTokenSet {
+            token,
+            chainId: chain_id,
+            remoteToken: remote_token,
+        });
+
+        Ok(())
+    }
+
+    /// Get the same asset as `token` on a destination chain (zero if none)
+    pub fn get_canonical_token(&self, token: Address, chain_id: U256) -> Address {
+        self.canonical_tokens.getter(token).get(chain_id)
+    }
+
+    /// Set how far ahead a stable transfer's deadline may be, in seconds (admin only)
+    pub fn set_stable_deadline_window(&mut self, window: U256) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+        Param::StableDeadlineWindow.check(window).map_err(RouteExecutorError::ParameterOutOfBounds)?;
+
+        let old_window = self.stable_deadline_window.get();
+        self.stable_deadline_window.set(window);
+
+        self.vm().log(StableDeadlineWindowSet { window });
+        self.vm().log(params::changed(Param::StableDeadlineWindow, old_window, window));
+
+        Ok(())
+    }
+
+    /// Get the stable transfer deadline window (zero = no limit)
+    pub fn stable_deadline_window(&self) -> U256 {
+        self.stable_deadline_window.get()
+    }
+
+    /// Set how many blocks old the block an intent is bound to may be (admin only)
+    ///
+    /// At most `BLOCK_HASH_WINDOW`, the blocks ArbSys keeps hashes for; zero
+    /// allows the whole window.
+    pub fn set_max_block_age(&mut self, max_block_age: U256) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        if max_block_age > U256::from(BLOCK_HASH_WINDOW) {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let old_age = self.max_block_age.get();
+        self.max_block_age.set(max_block_age);
+
+        self.vm().log(MaxBlockAgeSet {
+            maxBlockAge: max_block_age,
+        });
+        self.vm().log(params::changed(Param::MaxBlockAge, old_age, max_block_age));
+
+        Ok(())
+    }
+
+    /// Get how many blocks old an intent's bound block may be
+    pub fn get_max_block_age(&self) -> U256 {
+        let age = self.max_block_age.get();
+        if age == U256::ZERO {
+            U256::from(BLOCK_HASH_WINDOW)
+        } else {
+            age
+        }
+    }
+
+    /// Register the RouteExecutor deployed on a remote chain (admin only)
+    ///
+    /// Matched legs verify the counterparty's signature against it.
+    pub fn set_remote_executor(&mut self, chain_id: U256, executor: Address) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        if chain_id == U256::ZERO {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+
+        self.remote_executors.setter(chain_id).set(executor);
+
+        self.vm().log(RemoteExecutorSet { chainId: chain_id, executor });
+
+        Ok(())
+    }
+
+    /// Get the RouteExecutor registered for a remote chain
+    pub fn get_remote_executor(&self, chain_id: U256) -> Address {
+        self.remote_executors.get(chain_id)
+    }
+
+    /// Get the adapter that would carry a token to a destination chain
+    ///
+    /// Native USDC on both ends goes through CCTP (burn-and-mint); everything
+    /// else uses the lane's registered adapter, or CCIP when none is set.
+    pub fn resolve_bridge_adapter(&self, token: Address, destination_chain: U256) -> Address {
+        let cctp = self.cctp_adapter.get();
+        if cctp != Address::ZERO && token != Address::ZERO {
+            let local_usdc = self.native_usdc.get(U256::from(self.vm().chain_id()));
+            let remote_usdc = self.native_usdc.get(destination_chain);
+            if token == local_usdc && remote_usdc != Address::ZERO {
+                return cctp;
+            }
+        }
+        self.bridge_adapters.get(destination_chain)
+    }
+
+    /// Allow or remove a swap adapter (admin only)
+    pub fn set_swap_adapter(&mut self, adapter: Address, allowed: bool) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        if adapter == Address::ZERO {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+        if allowed {
+            self.require_interface(adapter, erc165::SWAP_ADAPTER_ID)?;
+        }
+
+        self.swap_adapters.setter(adapter).set(allowed);
+        self.index_config(pack_config_address(ConfigEntryKind::SwapAdapter, adapter));
+
+        self.vm().log(SwapAdapterSet { adapter, allowed });
+
+        Ok(())
+    }
+
+    /// Check if a swap adapter is allowed
+    pub fn is_swap_adapter(&self, adapter: Address) -> bool {
+        self.swap_adapters.get(adapter)
+    }
+
+    /// Set how a swap or bridge adapter's token allowance is left after each
+    /// call (admin only)
+    ///
+    /// `policy` follows `ApprovalPolicy`: exact approvals only, or exact
+    /// approvals revoked once the adapter returns.
+    pub fn set_approval_policy(&mut self, adapter: Address, policy: u8) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        if adapter == Address::ZERO {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+        if ApprovalPolicy::from_u8(policy).is_none() {
+            return Err(RouteExecutorError::InvalidApprovalPolicy(InvalidApprovalPolicy {}));
+        }
+
+        self.approval_policies.setter(adapter).set(U256::from(policy));
+
+        self.vm().log(ApprovalPolicySet { adapter, policy });
+
+        Ok(())
+    }
+
+    /// Get an adapter's approval policy
+    pub fn get_approval_policy(&self, adapter: Address) -> u8 {
+        self.approval_policies.get(adapter).saturating_to()
+    }
+
+    /// Set the NFT-capable bridge adapter for a destination chain (admin only)
+    ///
+    /// The adapter must implement `INftBridgeAdapter`. A zero address
+    /// disables NFT intents to that chain.
+    pub fn set_nft_bridge_adapter(
+        &mut self,
+        destination_chain: U256,
+        adapter: Address,
+    ) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+        if adapter != Address::ZERO {
+            self.require_interface(adapter, erc165::NFT_BRIDGE_ADAPTER_ID)?;
+        }
+
+        self.nft_bridge_adapters.setter(destination_chain).set(adapter);
+
+        self.vm().log(NftBridgeAdapterSet {
+            destinationChain: destination_chain,
+            adapter,
+        });
+
+        Ok(())
+    }
+
+    /// Get the NFT bridge adapter for a destination chain
+    pub fn get_nft_bridge_adapter(&self, destination_chain: U256) -> Address {
+        self.nft_bridge_adapters.get(destination_chain)
+    }
+
+    /// Set the wrapped native token used by wrap/unwrap steps (admin only)
+    pub fn set_weth(&mut self, weth: Address) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        if weth == Address::ZERO {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.weth.set(weth);
+
+        self.vm().log(WethSet { weth });
+
+        Ok(())
+    }
+
+    /// Get the wrapped native token
+    pub fn weth(&self) -> Address {
+        self.weth.get()
+    }
+
+    /// Register the wrapped native token of a chain (admin only)
+    ///
+    /// Registering this chain sets the WETH used by wrap/unwrap steps. Remote
+    /// entries gate native delivery: a route may only ask for ETH on a chain
+    /// whose wrapped native is known. A zero `token` removes a remote entry.
+    pub fn set_wrapped_native(&mut self, chain_id: U256, token: Address) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        if chain_id == U256::ZERO {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+
+        if chain_id == U256::from(self.vm().chain_id()) {
+            if token == Address::ZERO {
+                return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+            }
+            self.weth.set(token);
+            self.vm().log(WethSet { weth: token });
+        } else {
+            self.wrapped_natives.setter(chain_id).set(token);
+        }
+
+        self.vm().log(WrappedNativeSet { chainId: chain_id, token });
+
+        Ok(())
+    }
+
+    /// Set the largest gas drop intents to a chain may request (admin only)
+    ///
+    /// In the destination's native units; zero disables gas drops to the chain.
+    pub fn set_max_gas_drop(&mut self, chain_id: U256, max_gas_drop: U256) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        if chain_id == U256::ZERO {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+        Param::MaxGasDrop.check(max_gas_drop).map_err(RouteExecutorError::ParameterOutOfBounds)?;
+
+        let old_value = self.max_gas_drops.get(chain_id);
+        self.max_gas_drops.setter(chain_id).set(max_gas_drop);
+
+        self.advance_config_epoch()?;
+
+        self.vm().log(MaxGasDropSet {
+            chainId: chain_id,
+            maxGasDrop: max_gas_drop,
+        });
+        self.vm().log(params::changed_at(Param::MaxGasDrop, chain_id, old_value, max_gas_drop));
+
+        Ok(())
+    }
+
+    /// Set the largest destination gas limit a Bridge step toward a chain may
+    /// ask for (admin only)
+    ///
+    /// Bridge steps raise their lane's gas limit through `BridgeOptions` in
+    /// their `data`; zero disables overrides toward the chain.
+    pub fn set_max_gas_limit(&mut self, chain_id: U256, max_gas_limit: U256) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        if chain_id == U256::ZERO {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+        Param::MaxGasLimit.check(max_gas_limit).map_err(RouteExecutorError::ParameterOutOfBounds)?;
+
+        let old_value = self.max_gas_limits.get(chain_id);
+        self.max_gas_limits.setter(chain_id).set(max_gas_limit);
+
+        self.advance_config_epoch()?;
+
+        self.vm().log(MaxGasLimitSet {
+            chainId: chain_id,
+            maxGasLimit: max_gas_limit,
+        });
+        self.vm().log(params::changed_at(Param::MaxGasLimit, chain_id, old_value, max_gas_limit));
+
+        Ok(())
+    }
+
+    /// Get the largest destination gas limit a Bridge step toward a chain may ask for
+    pub fn get_max_gas_limit(&self, chain_id: U256) -> U256 {
+        self.max_gas_limits.get(chain_id)
+    }
+
+    /// Get the largest gas drop allowed to a chain
+    pub fn get_max_gas_drop(&self, chain_id: U256) -> U256 {
+        self.max_gas_drops.get(chain_id)
+    }
+
+    /// Cap the value of a token in flight toward a chain (admin only)
+    ///
+    /// The cap starts at `initial_cap` now and grows linearly to `cap` over
+    /// `ramp_duration` seconds; a zero duration applies `cap` at once and a
+    /// zero `cap` lifts the cap. Only bridges on capped lanes are counted,
+    /// so value bridged while the lane was uncapped never counts against it.
+    pub fn set_lane_tvl_cap(
+        &mut self,
+        chain_id: U256,
+        token: Address,
+        cap: U256,
+        initial_cap: U256,
+        ramp_duration: U256,
+    ) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        if chain_id == U256::ZERO {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+        Param::LaneCapRamp.check(ramp_duration).map_err(RouteExecutorError::ParameterOutOfBounds)?;
+
+        let lane_cap = LaneCap {
+            cap,
+            initial_cap,
+            ramp_start: self.vm().block_timestamp(),
+            ramp_duration: ramp_duration.to::<u64>(),
+        };
+        if !lane_cap.is_valid() {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let old_ramp = self.lane_cap(chain_id, token).ramp_duration;
+        self.lane_tvl_caps.setter(chain_id).setter(token).set(cap);
+        self.lane_initial_caps.setter(chain_id).setter(token).set(initial_cap);
+        self.lane_cap_ramps.setter(chain_id).setter(token).set(lane_cap.encode_ramp());
+
+        self.vm().log(LaneTvlCapSet {
+            chainId: chain_id,
+            token,
+            cap,
+            initialCap: initial_cap,
+            rampDuration: ramp_duration,
+        });
+        self.vm().log(params::changed_at(Param::LaneCapRamp, chain_id, U256::from(old_ramp), ramp_duration));
+
+        Ok(())
+    }
+
+    /// Get a lane's TVL cap for a token: the full cap, the cap its ramp
+    /// started from, and when and for how long it ramps
+    pub fn get_lane_tvl_cap(&self, chain_id: U256, token: Address) -> (U256, U256, U256, U256) {
+        let lane_cap = self.lane_cap(chain_id, token);
+        (
+            lane_cap.cap,
+            lane_cap.initial_cap,
+            U256::from(lane_cap.ramp_start),
+            U256::from(lane_cap.ramp_duration),
+        )
+    }
+
+    /// Get a lane's use of its TVL cap for a token: value in flight, the cap
+    /// in force now, and the first as bps of the second
+    ///
+    /// An uncapped lane reports its cap as `type(uint256).max` and zero use.
+    pub fn get_lane_utilization(&self, chain_id: U256, token: Address) -> (U256, U256, U256) {
+        let tvl = self.lane_tvl.getter(chain_id).get(token);
+        let current = self.lane_cap(chain_id, token).current(self.vm().block_timestamp());
+        let utilization_bps = if current == U256::MAX {
+            U256::ZERO
+        } else if current == U256::ZERO {
+            U256::from(fees::BPS_DENOMINATOR)
+        } else {
+            tvl.saturating_mul(U256::from(fees::BPS_DENOMINATOR)) / current
+        };
+        (tvl, current, utilization_bps)
+    }
+
+    /// Get the wrapped native token of a chain (zero if unknown)
+    pub fn get_wrapped_native(&self, chain_id: U256) -> Address {
+        if chain_id == U256::from(self.vm().chain_id()) {
+            self.weth.get()
+        } else {
+            self.wrapped_natives.get(chain_id)
+        }
+    }
+
+    /// Set the treasury that receives withdrawn fees (admin only)
+    pub fn set_fee_recipient(&mut self, recipient: Address) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        if recipient == Address::ZERO {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.fee_recipient.set(recipient);
+
+        self.vm().log(FeeRecipientSet { recipient });
+
+        Ok(())
+    }
+
+    /// Set the default protocol fee in bps (admin only, capped)
+    pub fn set_protocol_fee(&mut self, fee_bps: U256) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+        Self::check_fee_cap(fee_bps)?;
+
+        let old_fee_bps = self.protocol_fee_bps.get();
+        self.protocol_fee_bps.set(fee_bps);
+
+        self.advance_config_epoch()?;
+
+        self.vm().log(ProtocolFeeSet { feeBps: fee_bps });
+        self.vm().log(params::changed(Param::ProtocolFeeBps, old_fee_bps, fee_bps));
+
+        Ok(())
+    }
+
+    /// Override the protocol fee for one input token (admin only, capped)
+    ///
+    /// Disabling the override falls back to the default protocol fee.
+    pub fn set_token_fee(&mut self, token: Address, fee_bps: U256, enabled: bool) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+        Self::check_fee_cap(fee_bps)?;
+
+        let old_fee_bps = self.token_fee_bps.get(token);
+        self.token_fee_bps.setter(token).set(fee_bps);
+        self.token_fee_enabled.setter(token).set(enabled);
+        self.index_config(pack_config_address(ConfigEntryKind::TokenFee, token));
+
+        self.advance_config_epoch()?;
+
+        self.vm().log(TokenFeeSet {
+            token,
+            feeBps: fee_bps,
+            enabled,
+        });
+        self.vm().log(params::changed_at(Param::TokenFeeBps, params::token_key(token), old_fee_bps, fee_bps));
+
+        Ok(())
+    }
+
+    /// Export up to `limit` config entries starting at `offset`
+    ///
+    /// The default protocol fee and fee recipient come first, then every
+    /// bridge adapter, swap adapter and token fee ever set, at its current
+    /// value. Returns an `abi.encode(ConfigEntry[])` page for `import_config`
+    /// and the total number of entries.
+    pub fn export_config(&self, offset: U256, limit: U256) -> (Bytes, U256) {
+        let total = self.config_count.get() + U256::from(2);
+        let end = total.min(offset.saturating_add(limit));
+
+        let mut entries = Vec::new();
+        let mut index = offset;
+        while index < end {
+            entries.push(self.config_entry(index));
+            index += U256::from(1);
+        }
+
+        (encode_config_page(&entries).into(), total)
+    }
+
+    /// Restore a page from another deployment's `export_config` (admin only)
+    ///
+    /// Only into a deployment that hasn't executed an intent, and only until
+    /// the import is sealed. Returns the number of entries applied.
+    pub fn import_config(&mut self, page: Bytes) -> Result<U256, RouteExecutorError> {
+        self.only_owner()?;
+        if self.config_import_sealed.get() || self.intent_counter.get() != U256::ZERO {
+            return Err(RouteExecutorError::ConfigImportClosed(ConfigImportClosed {}));
+        }
+
+        let entries = decode_config_page(&page).ok_or(RouteExecutorError::InvalidConfigPage(InvalidConfigPage {}))?;
+        for entry in &entries {
+            match ConfigEntryKind::from_u8(entry.kind) {
+                Some(ConfigEntryKind::BridgeAdapter) => self.set_bridge_adapter(entry.key, entry.account)?,
+                Some(ConfigEntryKind::SwapAdapter) => self.set_swap_adapter(entry.account, entry.value != U256::ZERO)?,
+                Some(ConfigEntryKind::TokenFee) => {
+                    self.set_token_fee(entry.account, entry.value, entry.key != U256::ZERO)?
+                }
+                Some(ConfigEntryKind::ProtocolFee) => self.set_protocol_fee(entry.value)?,
+                // A recipient never set exports as zero; leave it unset
+                Some(ConfigEntryKind::FeeRecipient) if entry.account == Address::ZERO => {}
+                Some(ConfigEntryKind::FeeRecipient) => self.set_fee_recipient(entry.account)?,
+                _ => return Err(RouteExecutorError::InvalidConfigPage(InvalidConfigPage {})),
+            }
+        }
+
+        let applied = U256::from(entries.len());
+        self.vm().log(ConfigImported { entries: applied });
+
+        Ok(applied)
+    }
+
+    /// Close `import_config` for good once the restore is complete (admin only)
+    pub fn seal_config_import(&mut self) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        self.config_import_sealed.set(true);
+
+        self.vm().log(ConfigImportSealed {});
+
+        Ok(())
+    }
+
+    /// Check if `import_config` has been sealed
+    pub fn is_config_import_sealed(&self) -> bool {
+        self.config_import_sealed.get()
+    }
+
+    /// Get the protocol fee in bps charged on an input token
+    pub fn get_fee_bps(&self, token: Address) -> U256 {
+        if self.token_fee_enabled.get(token) {
+            self.token_fee_bps.get(token)
+        } else {
+            self.protocol_fee_bps.get()
+        }
+    }
+
+    /// Get the minimum fill and fill step in force for a token
+    ///
+    /// Without an owner-set rule, fills are at least one whole token in
+    /// hundredths of a token; a token whose `decimals` can't be read accepts
+    /// any non-zero fill.
+    pub fn get_fill_rule(&self, token: Address) -> (U256, U256) {
+        let rule = self.fill_rule(token);
+        (rule.min_fill, rule.granularity)
+    }
+
+    /// Get protocol fees collected and not yet withdrawn for a token
+    pub fn get_collected_fees(&self, token: Address) -> U256 {
+        self.collected_fees.get(token)
+    }
+
+    /// Get a token's lifetime fee ledger as (collected, withdrawn, rebated,
+    /// rebates claimed, rewarded, rewards claimed)
+    ///
+    /// Collected less withdrawn is `get_collected_fees`; rebated less claimed
+    /// is what integrators are still owed, and rewarded less claimed what
+    /// solvers are still owed in priority fees.
+    pub fn get_fee_ledger(&self, token: Address) -> (U256, U256, U256, U256, U256, U256) {
+        let totals = self.fee_totals.getter(token);
+        let [collected, withdrawn, rebated, rebates_claimed, rewarded, rewards_claimed] =
+            fees::LEDGER_ENTRIES.map(|entry| totals.get(U256::from(entry as u8)));
+        (collected, withdrawn, rebated, rebates_claimed, rewarded, rewards_claimed)
+    }
+
+    /// Get a token's fee ledger for one epoch, in `get_fee_ledger` order
+    pub fn get_fee_ledger_at(&self, token: Address, epoch: U256) -> (U256, U256, U256, U256, U256, U256) {
+        let totals = self.fee_epoch_totals.getter(token);
+        let [collected, withdrawn, rebated, rebates_claimed, rewarded, rewards_claimed] =
+            fees::LEDGER_ENTRIES.map(|entry| totals.get(fees::ledger_key(epoch, entry)));
+        (collected, withdrawn, rebated, rebates_claimed, rewarded, rewards_claimed)
+    }
+
+    /// Get the current fee ledger epoch
+    pub fn fee_epoch(&self) -> U256 {
+        fees::fee_epoch(U256::from(self.vm().block_timestamp()))
+    }
+
+    /// Get the fee recipient
+    pub fn fee_recipient(&self) -> Address {
+        self.fee_recipient.get()
+    }
+
+    /// Set the SolverRegistry used for signed intent execution (admin only)
+    pub fn set_solver_registry(&mut self, registry: Address) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        if registry == Address::ZERO {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.solver_registry.set(registry);
+
+        self.vm().log(SolverRegistrySet { registry });
+
+        Ok(())
+    }
+
+    /// Switch solver-only execution on or off (admin only)
+    ///
+    /// While enabled, `execute_route` and `execute_full_route` revert and
+    /// intents can only be executed by solvers via `execute_signed_route`.
+    pub fn set_solver_only(&mut self, enabled: bool) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        if enabled && self.solver_registry.get() == Address::ZERO {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.solver_only.set(enabled);
+
+        self.vm().log(SolverOnlySet { enabled });
+
+        Ok(())
+    }
+
+    /// Check if solver-only execution is enabled
+    pub fn is_solver_only(&self) -> bool {
+        self.solver_only.get()
+    }
+
+    /// Get the SolverRegistry
+    pub fn solver_registry(&self) -> Address {
+        self.solver_registry.get()
+    }
+
+    /// Set the firm window of intent claims and the penalty for letting one
+    /// lapse (admin only)
+    ///
+    /// A zero window turns claims off. The SolverRegistry must accept this
+    /// contract as its route executor for penalties to be taken.
+    pub fn set_claim_config(&mut self, window: U256, penalty: U256) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+        Param::ClaimWindow.check(window).map_err(RouteExecutorError::ParameterOutOfBounds)?;
+        Param::ClaimPenalty.check(penalty).map_err(RouteExecutorError::ParameterOutOfBounds)?;
+
+        let (old_window, old_penalty) = (self.claim_window.get(), self.claim_penalty.get());
+        self.claim_window.set(window);
+        self.claim_penalty.set(penalty);
+
+        self.vm().log(ClaimConfigSet { window, penalty });
+        self.vm().log(params::changed(Param::ClaimWindow, old_window, window));
+        self.vm().log(params::changed(Param::ClaimPenalty, old_penalty, penalty));
+
+        Ok(())
+    }
+
+    /// Get the claim window and lapse penalty
+    pub fn get_claim_config(&self) -> (U256, U256) {
+        (self.claim_window.get(), self.claim_penalty.get())
+    }
+
+    /// Set how long a newly queued intent is reserved for one solver (admin only)
+    ///
+    /// Each intent entering the pending queue rotates to an active solver
+    /// picked by `solver_registry::rotation_index`; only it may execute or
+    /// claim the intent until the window ends. Zero turns rotation off.
+    pub fn set_rotation_window(&mut self, window: U256) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+        Param::RotationWindow.check(window).map_err(RouteExecutorError::ParameterOutOfBounds)?;
+
+        let old_window = self.rotation_window.get();
+        self.rotation_window.set(window);
+
+        self.vm().log(RotationWindowSet { window });
+        self.vm().log(params::changed(Param::RotationWindow, old_window, window));
+
+        Ok(())
+    }
+
+    /// Get the rotation window
+    pub fn rotation_window(&self) -> U256 {
+        self.rotation_window.get()
+    }
+
+    /// Get the solver a queued intent is rotated to and when its turn ends
+    /// (zero solver if open to all)
+    pub fn get_rotation(&self, intent_hash: FixedBytes<32>) -> (U256, U256) {
+        (self.rotation_solvers.get(intent_hash), self.rotation_expiries.get(intent_hash))
+    }
+
+    /// Set the IntentRegistry shared with the chain's other executors (admin only)
+    ///
+    /// The registry must allow this executor. Zero stops consuming intents
+    /// there, leaving only this executor's own replay checks.
+    pub fn set_intent_registry(&mut self, registry: Address) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        self.intent_registry.set(registry);
+
+        self.vm().log(IntentRegistrySet { registry });
+
+        Ok(())
+    }
+
+    /// Get the IntentRegistry
+    pub fn intent_registry(&self) -> Address {
+        self.intent_registry.get()
+    }
+
+    /// Point the executor at a new IntentValidator (admin only)
+    ///
+    /// Calls the validator's `self_check` and reverts with
+    /// `IncompatibleValidator` unless its version matches, it reads only the
+    /// intent fields this executor passes, and it offers every check this
+    /// executor calls. Reason 0 means `self_check` itself failed.
+    pub fn set_validator(&mut self, validator: Address) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        if validator == Address::ZERO {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        let incompatible =
+            |reason: u8| RouteExecutorError::IncompatibleValidator(IncompatibleValidator { validator, reason });
+        let (version, required_fields, features) = IIntentValidator::new(validator)
+            .self_check(self.vm(), Call::new())
+            .map_err(|_| incompatible(0))?;
+        validator_compat::check_validator(version, required_fields, features)
+            .map_err(|reason| incompatible(reason as u8))?;
+
+        self.validator.set(validator);
+
+        self.vm().log(ValidatorSet { validator, version });
+
+        Ok(())
+    }
+
+    /// Get the IntentValidator address
+    pub fn validator(&self) -> Address {
+        self.validator.get()
+    }
+
+    /// Set the SettlementVerifier notified of solver executions (admin only)
+    pub fn set_settlement_verifier(&mut self, verifier: Address) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+        self.settlement_verifier.set(verifier);
+
+        self.vm().log(SettlementVerifierSet { verifier });
+
+        Ok(())
+    }
+
+    /// Get the SettlementVerifier
+    pub fn settlement_verifier(&self) -> Address {
+        self.settlement_verifier.get()
+    }
+
+    /// Get the EIP-712 hash a user signs for an intent
+    pub fn get_intent_hash(&self, intent: Bytes) -> Result<FixedBytes<32>, RouteExecutorError> {
+        let intent = route_codec::decode_intent(&intent).map_err(RouteExecutorError::from)?;
+        Ok(signing::intent_digest(&intent, self.vm().chain_id(), self.vm().contract_address()))
+    }
+
+    /// Check if a signed intent has been executed
+    pub fn is_intent_consumed(&self, intent_hash: FixedBytes<32>) -> bool {
+        self.consumed_intents.get(intent_hash)
+    }
+
+    /// Cancel one of the caller's signed intents before a solver executes it
+    ///
+    /// Cancellation is keyed by the caller, so it only binds intents whose
+    /// `user` is the caller; nobody can cancel someone else's intent.
+    pub fn cancel_intent(&mut self, intent_hash: FixedBytes<32>) -> Result<(), RouteExecutorError> {
+        if self.consumed_intents.get(intent_hash) {
+            return Err(RouteExecutorError::IntentAlreadyUsed(IntentAlreadyUsed { intentHash: intent_hash }));
+        }
+
+        let user = self.vm().msg_sender();
+        if self.cancelled_intents.getter(user).get(intent_hash) {
+            return Err(RouteExecutorError::CancelledIntent(CancelledIntent { intentHash: intent_hash }));
+        }
+
+        self.cancelled_intents.setter(user).setter(intent_hash).set(true);
+        if self.escrow_users.get(intent_hash) == user {
+            self.dequeue_intent(intent_hash);
+        }
+
+        self.vm().log(IntentCancelled {
+            user,
+            intentHash: intent_hash,
+        });
+
+        Ok(())
+    }
+
+    /// Check if a user has cancelled a signed intent
+    pub fn is_intent_cancelled(&self, user: Address, intent_hash: FixedBytes<32>) -> bool {
+        self.cancelled_intents.getter(user).get(intent_hash)
+    }
+
+    /// Escrow the input of one of the caller's signed intents
+    ///
+    /// Pulls `amountIn` plus `priorityFee` of `tokenIn` now; a solver's
+    /// `execute_signed_route` then spends the escrow instead of pulling from
+    /// the user. The intent joins the pending queue, ranked by priority fee,
+    /// unless the queue is full of higher bids. Returns the intent hash. The
+    /// escrow can be withdrawn once the intent's deadline passes or it is
+    /// cancelled, or at any time in emergency mode. A delegate of the user
+    /// may escrow for it; the input and priority fee count toward the
+    /// delegation's allowance. The validator must admit the intent's token
+    /// and amount, as it must at execution.
+    pub fn escrow_intent(&mut self, intent: Bytes) -> Result<FixedBytes<32>, RouteExecutorError> {
+        let encoded = intent;
+        let intent = route_codec::decode_intent(&encoded).map_err(RouteExecutorError::from)?;
+
+        let ctx = self.execution_context()?;
+        // Solvers only execute signed ERC20 intents
+        if intent.tokenIn == NATIVE_TOKEN {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+        if intent.amountIn == U256::ZERO {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+        // Input plus priority fee, pulled and held together
+        let escrow = intent
+            .amountIn
+            .checked_add(intent.priorityFee)
+            .ok_or(RouteExecutorError::InvalidAmount(InvalidAmount {}))?;
+        check_deadline(intent.deadline, ctx.now)?;
+        self.check_block_binding(&intent)?;
+        // Over a token cap or unlisted, it could never execute
+        self.check_intent_admitted(&ctx, &intent)?;
+        self.check_lane_healthy(&ctx, intent.destinationChain)?;
+        self.check_config_epoch(&ctx, intent.configEpoch)?;
+
+        let intent_hash = signing::intent_digest(&intent, ctx.chain_id, ctx.this);
+        if self.consumed_intents.get(intent_hash) || self.escrow_amounts.get(intent_hash) != U256::ZERO {
+            return Err(RouteExecutorError::IntentAlreadyUsed(IntentAlreadyUsed { intentHash: intent_hash }));
+        }
+        if self.cancelled_intents.getter(intent.user).get(intent_hash) {
+            return Err(RouteExecutorError::CancelledIntent(CancelledIntent { intentHash: intent_hash }));
+        }
+        self.check_intent_not_blocked(&ctx, intent_hash)?;
+        self.check_submitter(&ctx, intent_hash, &intent, escrow)?;
+
+        self.check_not_locked()?;
+        self.locked.set(true);
+
+        self.escrow_users.setter(intent_hash).set(intent.user);
+        self.escrow_tokens.setter(intent_hash).set(intent.tokenIn);
+        self.escrow_amounts.setter(intent_hash).set(intent.amountIn);
+        self.escrow_deadlines.setter(intent_hash).set(intent.deadline);
+        self.escrow_priority_fees.setter(intent_hash).set(intent.priorityFee);
+
+        self.pull_token(intent.tokenIn, intent.user, escrow)?;
+        self.reserve(intent.tokenIn, escrow);
+
+        self.vm().log(IntentEscrowed {
+            intentHash: intent_hash,
+            user: intent.user,
+            token: intent.tokenIn,
+            amount: intent.amountIn,
+        });
+
+        if self.enqueue_intent(intent_hash, intent.priorityFee) {
+            self.vm().log(IntentQueued {
+                intentHash: intent_hash,
+                priorityFee: intent.priorityFee,
+                intent: encoded,
+            });
+            self.assign_rotation(&ctx, intent_hash, intent.deadline);
+        }
+
+        self.locked.set(false);
+
+        Ok(intent_hash)
+    }
+
+    /// Withdraw the escrow of one of the caller's unexecuted intents
+    ///
+    /// Allowed once the intent's deadline has passed or it was cancelled or
+    /// filled elsewhere; while paused, only in emergency mode, which needs
+    /// none of these. The priority fee is returned with the input.
+    pub fn withdraw_escrow(&mut self, intent_hash: FixedBytes<32>) -> Result<U256, RouteExecutorError> {
+        let user = self.vm().msg_sender();
+        let amount = self.escrow_amounts.get(intent_hash);
+        if amount == U256::ZERO || self.escrow_users.get(intent_hash) != user {
+            return Err(RouteExecutorError::Unauthorized(Unauthorized {}));
+        }
+
+        let emergency = self.emergency_mode.get();
+        let expired = U256::from(self.vm().block_timestamp()) > self.escrow_deadlines.get(intent_hash);
+        // Consumed here means spent by a path that does not draw on the escrow
+        let released = expired
+            || self.cancelled_intents.getter(user).get(intent_hash)
+            || self.consumed_intents.get(intent_hash);
+        if !emergency && (self.paused.get() || !released) {
+            return Err(RouteExecutorError::EscrowLocked(EscrowLocked { intentHash: intent_hash }));
+        }
+
+        self.check_not_locked()?;
+        self.locked.set(true);
+
+        let amount = amount + self.escrow_priority_fees.get(intent_hash);
+        self.escrow_amounts.setter(intent_hash).set(U256::ZERO);
+        self.escrow_priority_fees.setter(intent_hash).set(U256::ZERO);
+        self.dequeue_intent(intent_hash);
+        let token = self.escrow_tokens.get(intent_hash);
+        self.release_reserved(token, amount);
+        self.internal_send(token, user, amount)?;
+
+        self.vm().log(EscrowWithdrawn {
+            intentHash: intent_hash,
+            user,
+            amount,
+            emergency,
+        });
+
+        self.locked.set(false);
+
+        Ok(amount)
+    }
+
+    /// Return the escrow of unfilled intents whose deadline has passed
+    ///
+    /// Permissionless, so keepers can sweep the pending queue in batches of
+    /// up to `MAX_EXPIRY_BATCH`. Each intent's input and priority fee go
+    /// back to its user and it leaves the queue. IDs are intent hashes read
+    /// as uint256, as everywhere else. IDs without an escrow or still
+    /// before their deadline are skipped, so a batch never reverts on a
+    /// stale entry. Neither does it on a refund the token refuses, e.g. to
+    /// a blacklisted user: that intent leaves the queue but stays escrowed
+    /// for `withdraw_escrow`, and logs `IntentExpiryFailed`. Returns how
+    /// many intents expired.
+    pub fn expire_intents(&mut self, intent_ids: Vec<U256>) -> Result<U256, RouteExecutorError> {
+        let ctx = self.execution_context()?;
+        if intent_ids.len() > MAX_EXPIRY_BATCH {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+
+        self.check_not_locked()?;
+        self.locked.set(true);
+
+        let mut expired = 0u64;
+        for intent_id in intent_ids {
+            let intent_hash = FixedBytes::from(intent_id.to_be_bytes::<32>());
+            let amount = self.escrow_amounts.get(intent_hash);
+            if amount == U256::ZERO || ctx.now <= self.escrow_deadlines.get(intent_hash) {
+                continue;
+            }
+
+            let (escrowed, priority_fee) = (amount, self.escrow_priority_fees.get(intent_hash));
+            let amount = escrowed + priority_fee;
+            self.escrow_amounts.setter(intent_hash).set(U256::ZERO);
+            self.escrow_priority_fees.setter(intent_hash).set(U256::ZERO);
+            self.dequeue_intent(intent_hash);
+            let (user, token) = (self.escrow_users.get(intent_hash), self.escrow_tokens.get(intent_hash));
+            self.release_reserved(token, amount);
+            if self.internal_send(token, user, amount).is_err() {
+                // Put the escrow back rather than fail the rest of the batch
+                self.escrow_amounts.setter(intent_hash).set(escrowed);
+                self.escrow_priority_fees.setter(intent_hash).set(priority_fee);
+                self.reserve(token, amount);
+                self.vm().log(IntentExpiryFailed { intentHash: intent_hash, user });
+                continue;
+            }
+
+            self.vm().log(IntentExpired {
+                intentHash: intent_hash,
+                user,
+                token,
+                amount,
+            });
+            expired += 1;
+        }
+
+        self.locked.set(false);
+
+        Ok(U256::from(expired))
+    }
+
+    /// Stop counting settled intents against their lanes' TVL caps
+    ///
+    /// Permissionless, so keepers can release lanes in batches of up to
+    /// `MAX_RELEASE_BATCH`. An intent is released once SettlementVerifier
+    /// reports it settled either way, delivered or failed. IDs still pending
+    /// or with nothing counted are skipped. Returns how many intents were
+    /// released.
+    pub fn release_lane_tvl(&mut self, intent_ids: Vec<U256>) -> Result<U256, RouteExecutorError> {
+        if intent_ids.len() > MAX_RELEASE_BATCH {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+        let verifier = self.settlement_verifier.get();
+        if verifier == Address::ZERO {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        let mut released = 0u64;
+        for intent_id in intent_ids {
+            let amount = self.intent_lane_values.get(intent_id);
+            if amount == U256::ZERO {
+                continue;
+            }
+            let status = ISettlementVerifier::new(verifier)
+                .get_settlement_status(self.vm(), Call::new(), intent_id)
+                .unwrap_or_default();
+            if status == U256::ZERO {
+                continue;
+            }
+
+            let chain_id = U256::from(self.intent_record(intent_id).destination_chain);
+            let token = self.intent_lane_tokens.get(intent_id);
+            let tvl = self.lane_tvl.getter(chain_id).get(token);
+            self.lane_tvl.setter(chain_id).setter(token).set(tvl.saturating_sub(amount));
+            self.intent_lane_values.setter(intent_id).set(U256::ZERO);
+
+            self.vm().log(LaneTvlReleased {
+                intentId: intent_id,
+                chainId: chain_id,
+                token,
+                amount,
+            });
+            released += 1;
+        }
+
+        Ok(U256::from(released))
+    }
+
+    /// Claim a queued intent for the caller's solver
+    ///
+    /// Until the claim lapses, one claim window later or at the intent's
+    /// deadline if sooner, only this solver may execute the intent. During
+    /// its rotation window, only the rotation solver may claim it. A claim
+    /// that lapses unexecuted can be penalized with `penalize_claim`; a
+    /// lapsed claim still on the intent is penalized here before the new
+    /// one is taken. Returns the time the claim lapses.
+    pub fn claim_intent(&mut self, intent_hash: FixedBytes<32>) -> Result<U256, RouteExecutorError> {
+        let ctx = self.execution_context()?;
+        let window = self.claim_window.get();
+        if window == U256::ZERO {
+            return Err(RouteExecutorError::ClaimsDisabled(ClaimsDisabled {}));
+        }
+        let (_, solver_id) = self.active_solver(SCOPE_EXECUTE)?;
+
+        let deadline = self.escrow_deadlines.get(intent_hash);
+        if !self.queued_intents.get(intent_hash) || ctx.now > deadline {
+            return Err(RouteExecutorError::IntentNotClaimable(IntentNotClaimable { intentHash: intent_hash }));
+        }
+        self.check_rotation(&ctx, intent_hash, solver_id)?;
+
+        let claimant = self.claim_solvers.get(intent_hash);
+        if claimant != U256::ZERO {
+            let expires_at = self.claim_expiries.get(intent_hash);
+            if ctx.now <= expires_at {
+                return Err(RouteExecutorError::ClaimActive(ClaimActive {
+                    intentHash: intent_hash,
+                    solverId: claimant,
+                    expiresAt: expires_at,
+                }));
+            }
+            self.penalize_lapsed_claim(intent_hash)?;
+        }
+
+        let expires_at = (ctx.now + window).min(deadline);
+        self.claim_solvers.setter(intent_hash).set(solver_id);
+        self.claim_expiries.setter(intent_hash).set(expires_at);
+
+        self.vm().log(IntentClaimed {
+            intentHash: intent_hash,
+            solverId: solver_id,
+            expiresAt: expires_at,
+        });
+
+        Ok(expires_at)
+    }
+
+    /// Penalize the solver behind a lapsed claim, paying the user from its bond
+    ///
+    /// Permissionless. No penalty is taken if the user cancelled the intent,
+    /// since the solver could no longer execute it. Returns the penalty paid.
+    pub fn penalize_claim(&mut self, intent_hash: FixedBytes<32>) -> Result<U256, RouteExecutorError> {
+        let ctx = self.execution_context()?;
+        let claimant = self.claim_solvers.get(intent_hash);
+        if claimant == U256::ZERO {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+        let expires_at = self.claim_expiries.get(intent_hash);
+        if ctx.now <= expires_at {
+            return Err(RouteExecutorError::ClaimActive(ClaimActive {
+                intentHash: intent_hash,
+                solverId: claimant,
+                expiresAt: expires_at,
+            }));
+        }
+
+        self.penalize_lapsed_claim(intent_hash)
+    }
+
+    /// Get the solver ID holding a claim on an intent and when it lapses
+    /// (zeros if unclaimed)
+    pub fn get_claim(&self, intent_hash: FixedBytes<32>) -> (U256, U256) {
+        (self.claim_solvers.get(intent_hash), self.claim_expiries.get(intent_hash))
+    }
+
+    /// Get a signed intent's escrow as (user, token, amount, deadline)
+    ///
+    /// The amount is zero once executed or withdrawn.
+    pub fn get_escrow(&self, intent_hash: FixedBytes<32>) -> (Address, Address, U256, U256) {
+        (
+            self.escrow_users.get(intent_hash),
+            self.escrow_tokens.get(intent_hash),
+            self.escrow_amounts.get(intent_hash),
+            self.escrow_deadlines.get(intent_hash),
+        )
+    }
+
+    /// Get an escrowed intent's priority fee
+    pub fn get_escrow_priority_fee(&self, intent_hash: FixedBytes<32>) -> U256 {
+        self.escrow_priority_fees.get(intent_hash)
+    }
+
+    /// List pending escrowed intents in descending priority fee order
+    ///
+    /// Starts after `cursor`, or at the head when it is zero, and returns up
+    /// to `limit` intent hashes with their priority fees. Intents are listed
+    /// by `IntentQueued`, which carries the encoded intent.
+    pub fn get_pending_intents(&self, cursor: FixedBytes<32>, limit: U256) -> (Vec<FixedBytes<32>>, Vec<U256>) {
+        let mut hashes = Vec::new();
+        let mut priority_fees = Vec::new();
+        if cursor != FixedBytes::ZERO && !self.queued_intents.get(cursor) {
+            return (hashes, priority_fees);
+        }
+
+        let limit = limit.min(U256::from(MAX_QUEUED_INTENTS)).to::<usize>();
+        let mut current = self.queue_next.get(cursor);
+        while current != FixedBytes::ZERO && hashes.len() < limit {
+            hashes.push(current);
+            priority_fees.push(self.escrow_priority_fees.get(current));
+            current = self.queue_next.get(current);
+        }
+        (hashes, priority_fees)
+    }
+
+    /// Get the number of intents in the pending queue
+    pub fn get_pending_intent_count(&self) -> U256 {
+        self.queue_length.get()
+    }
+
+    /// Get a solver's unclaimed priority fees for a token
+    pub fn get_priority_fees(&self, solver: Address, token: Address) -> U256 {
+        self.solver_priority_fees.getter(solver).get(token)
+    }
+
+    /// Claim the caller's earned priority fees for a token
+    pub fn claim_priority_fees(&mut self, token: Address) -> Result<U256, RouteExecutorError> {
+        self.check_not_locked()?;
+
+        let solver = self.vm().msg_sender();
+        let amount = self.solver_priority_fees.getter(solver).get(token);
+        if amount == U256::ZERO {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+
+        self.solver_priority_fees.setter(solver).setter(token).set(U256::ZERO);
+        self.record_fees(token, LedgerEntry::RewardsClaimed, amount);
+        self.internal_send(token, solver, amount)?;
+
+        self.vm().log(PriorityFeesClaimed { solver, token, amount });
+
+        Ok(amount)
+    }
+
+    /// Check if a hidden-recipient intent has been paid out on this chain
+    pub fn is_hidden_recipient_settled(&self, intent_hash: FixedBytes<32>) -> bool {
+        self.settled_hidden_recipients.get(intent_hash)
+    }
+
+    /// Register or remove a route oracle signer (admin only)
+    pub fn set_route_signer(&mut self, signer: Address, enabled: bool) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        if signer == Address::ZERO {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.route_signers.setter(signer).set(enabled);
+
+        self.vm().log(RouteSignerSet { signer, enabled });
+
+        Ok(())
+    }
+
+    /// Check if an address is a registered route oracle signer
+    pub fn is_route_signer(&self, signer: Address) -> bool {
+        self.route_signers.get(signer)
+    }
+
+    /// Require a signed route quote on every executed route (admin only)
+    ///
+    /// `execute_route` takes no quote, so it reverts while this is on.
+    pub fn set_route_quote_required(&mut self, required: bool) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+        self.route_quote_required.set(required);
+
+        self.vm().log(RouteQuoteRequiredSet { required });
+
+        Ok(())
+    }
+
+    /// Quote every swap step before pulling funds (admin only)
+    ///
+    /// A route then reverts with `InsufficientLiquidity` before any funds move
+    /// when an adapter can't quote a step or quotes below its minimum, or when
+    /// the expected output falls more than `max_deviation_bps` short of the
+    /// route's signed quote. Zero turns the check off.
+    pub fn set_liquidity_check(&mut self, max_deviation_bps: U256) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        let denominator = U256::from(fees::BPS_DENOMINATOR);
+        if max_deviation_bps > denominator {
+            return Err(RouteExecutorError::FeeTooHigh(FeeTooHigh {
+                feeBps: max_deviation_bps,
+                maxFeeBps: denominator,
+            }));
+        }
+
+        let old_bps = self.liquidity_check_bps.get();
+        self.liquidity_check_bps.set(max_deviation_bps);
+
+        self.advance_config_epoch()?;
+
+        self.vm().log(LiquidityCheckSet { maxDeviationBps: max_deviation_bps });
+        self.vm().log(params::changed(Param::LiquidityCheckBps, old_bps, max_deviation_bps));
+
+        Ok(())
+    }
+
+    /// Get the liquidity check's allowed shortfall against a quote in bps
+    /// (zero = off)
+    pub fn liquidity_check_bps(&self) -> U256 {
+        self.liquidity_check_bps.get()
+    }
+
+    /// Check if routes require a signed route quote
+    pub fn is_route_quote_required(&self) -> bool {
+        self.route_quote_required.get()
+    }
+
+    /// Get an intent's quote as (route oracle, quoted output, realized output)
+    ///
+    /// All zero for intents executed without a quote.
+    pub fn get_intent_quote(&self, intent_id: U256) -> (Address, U256, U256) {
+        (
+            self.intent_quote_signers.get(intent_id),
+            self.intent_quoted_out.get(intent_id),
+            self.intent_realized_out.get(intent_id),
+        )
+    }
+
+    /// Get the fee a solver realized on an intent it executed
+    ///
+    /// Quoted minus realized output; zero for intents executed by their user
+    /// or without a quote.
+    pub fn get_solver_fee(&self, intent_id: U256) -> U256 {
+        self.intent_solver_fees.get(intent_id)
+    }
+
+    /// Get a route oracle's quote record as
+    /// (quoted intents, quoted total, realized total, shortfall total)
+    ///
+    /// The shortfall total only counts intents that delivered less than
+    /// quoted, so `shortfall / quoted` is how much the oracle overpromises.
+    pub fn get_route_signer_stats(&self, signer: Address) -> (U256, U256, U256, U256) {
+        (
+            self.signer_quote_count.get(signer),
+            self.signer_quoted_total.get(signer),
+            self.signer_realized_total.get(signer),
+            self.signer_shortfall_total.get(signer),
+        )
+    }
+
+    /// Get a solver's quote record, same shape as `get_route_signer_stats`
+    pub fn get_solver_quote_stats(&self, solver_id: U256) -> (U256, U256, U256, U256) {
+        (
+            self.solver_quote_count.get(solver_id),
+            self.solver_quoted_total.get(solver_id),
+            self.solver_realized_total.get(solver_id),
+            self.solver_shortfall_total.get(solver_id),
+        )
+    }
+
+    /// Get the EIP-712 hash a route oracle signs for a quote
+    pub fn get_route_quote_hash(&self, route_quote: Bytes) -> Result<FixedBytes<32>, RouteExecutorError> {
+        let quote = route_codec::decode_route_quote(&route_quote).map_err(RouteExecutorError::from)?;
+        Ok(signing::route_quote_digest(&quote, self.vm().chain_id(), self.vm().contract_address()))
+    }
+
+    /// Set the integrator fee policy (admin only)
+    ///
+    /// `max_fee_bps` caps what integrators may charge and
+    /// `protocol_share_bps` is the protocol's cut of each integrator fee.
+    pub fn set_integrator_fee_policy(
+        &mut self,
+        max_fee_bps: U256,
+        protocol_share_bps: U256,
+    ) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        let hard_cap = U256::from(fees::MAX_INTEGRATOR_FEE_BPS);
+        if max_fee_bps > hard_cap {
+            return Err(RouteExecutorError::FeeTooHigh(FeeTooHigh {
+                feeBps: max_fee_bps,
+                maxFeeBps: hard_cap,
+            }));
+        }
+
+        let denominator = U256::from(fees::BPS_DENOMINATOR);
+        if protocol_share_bps > denominator {
+            return Err(RouteExecutorError::FeeTooHigh(FeeTooHigh {
+                feeBps: protocol_share_bps,
+                maxFeeBps: denominator,
+            }));
+        }
+
+        let (old_max_fee_bps, old_share_bps) = self.get_integrator_fee_policy();
+        self.max_integrator_fee_bps.set(max_fee_bps);
+        self.integrator_protocol_share_bps.set(protocol_share_bps);
+
+        self.advance_config_epoch()?;
+
+        self.vm().log(IntegratorFeePolicySet {
+            maxFeeBps: max_fee_bps,
+            protocolShareBps: protocol_share_bps,
+        });
+        self.vm().log(params::changed(Param::MaxIntegratorFeeBps, old_max_fee_bps, max_fee_bps));
+        self.vm().log(params::changed(Param::IntegratorProtocolShareBps, old_share_bps, protocol_share_bps));
+
+        Ok(())
+    }
+
+    /// Get the integrator fee policy as (max fee bps, protocol share bps)
+    pub fn get_integrator_fee_policy(&self) -> (U256, U256) {
+        (self.max_integrator_fee_bps.get(), self.integrator_protocol_share_bps.get())
+    }
+
+    /// Set the premium charged on insured intents (admin only)
+    ///
+    /// Intents opt in with the most premium they accept and pay this rate.
+    /// Zero turns insurance off; insured intents then revert.
+    pub fn set_insurance_premium_bps(&mut self, premium_bps: U256) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        let hard_cap = U256::from(fees::MAX_INSURANCE_PREMIUM_BPS);
+        if premium_bps > hard_cap {
+            return Err(RouteExecutorError::FeeTooHigh(FeeTooHigh {
+                feeBps: premium_bps,
+                maxFeeBps: hard_cap,
+            }));
+        }
+
+        let old_premium_bps = self.insurance_premium_bps.get();
+        self.insurance_premium_bps.set(premium_bps);
+
+        self.advance_config_epoch()?;
+
+        self.vm().log(InsurancePremiumSet { premiumBps: premium_bps });
+        self.vm().log(params::changed(Param::InsurancePremiumBps, old_premium_bps, premium_bps));
+
+        Ok(())
+    }
+
+    /// Get the premium charged on insured intents
+    pub fn insurance_premium_bps(&self) -> U256 {
+        self.insurance_premium_bps.get()
+    }
+
+    /// Configure cost-adjusted protocol fees (admin only)
+    ///
+    /// With an oracle set, an intent pays the higher of its scheduled fee and
+    /// the rate covering its execution cost: the L1 data fee for
+    /// `calldata_bytes` plus the destination gas the oracle quotes. The result
+    /// is kept within `[min_fee_bps, max_fee_bps]`. A zero oracle goes back to
+    /// schedule fees.
+    pub fn set_cost_fee_config(
+        &mut self,
+        oracle: Address,
+        min_fee_bps: U256,
+        max_fee_bps: U256,
+        calldata_bytes: U256,
+    ) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        let hard_cap = U256::from(fees::MAX_PROTOCOL_FEE_BPS);
+        if max_fee_bps > hard_cap {
+            return Err(RouteExecutorError::FeeTooHigh(FeeTooHigh {
+                feeBps: max_fee_bps,
+                maxFeeBps: hard_cap,
+            }));
+        }
+        if !fees::is_valid_cost_fee_bounds(min_fee_bps, max_fee_bps) {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+        Param::CostCalldataBytes.check(calldata_bytes).map_err(RouteExecutorError::ParameterOutOfBounds)?;
+
+        let (_, old_min_bps, old_max_bps, old_calldata_bytes) = self.get_cost_fee_config();
+        self.cost_oracle.set(oracle);
+        self.cost_fee_min_bps.set(min_fee_bps);
+        self.cost_fee_max_bps.set(max_fee_bps);
+        self.cost_calldata_bytes.set(calldata_bytes);
+
+        self.advance_config_epoch()?;
+
+        self.vm().log(CostFeeConfigSet {
+            oracle,
+            minFeeBps: min_fee_bps,
+            maxFeeBps: max_fee_bps,
+            calldataBytes: calldata_bytes,
+        });
+        self.vm().log(params::changed(Param::CostFeeMinBps, old_min_bps, min_fee_bps));
+        self.vm().log(params::changed(Param::CostFeeMaxBps, old_max_bps, max_fee_bps));
+        self.vm().log(params::changed(Param::CostCalldataBytes, old_calldata_bytes, calldata_bytes));
+
+        Ok(())
+    }
+
+    /// Get the cost fee config as (oracle, min fee bps, max fee bps, calldata bytes)
+    pub fn get_cost_fee_config(&self) -> (Address, U256, U256, U256) {
+        (
+            self.cost_oracle.get(),
+            self.cost_fee_min_bps.get(),
+            self.cost_fee_max_bps.get(),
+            self.cost_calldata_bytes.get(),
+        )
+    }
+
+    /// Get the protocol fee in bps an intent of `amount` of `token` to
+    /// `destination_chain` pays through `integrator` right now
+    ///
+    /// The integrator's scheduled fee, raised to cover execution costs when a
+    /// cost oracle is set. If the oracle can't quote, the scheduled fee is
+    /// still held to the configured bounds.
+    pub fn quote_protocol_fee_bps(
+        &self,
+        integrator: Address,
+        token: Address,
+        amount: U256,
+        destination_chain: U256,
+    ) -> U256 {
+        let base_bps = self.get_integrator_fee_bps(integrator, token);
+        let oracle = self.cost_oracle.get();
+        if oracle == Address::ZERO {
+            return base_bps;
+        }
+
+        let cost = self.execution_cost(oracle, token, destination_chain).unwrap_or_default();
+        fees::cost_adjusted_fee_bps(
+            base_bps,
+            cost,
+            amount,
+            self.cost_fee_min_bps.get(),
+            self.cost_fee_max_bps.get(),
+        )
+    }
+
+    /// Internal: Execution cost of a route in `token`, or `None` if a read fails
+    ///
+    /// The L1 data fee for the configured calldata comes from ArbGasInfo;
+    /// the oracle adds the destination leg and converts the total.
+    fn execution_cost(&self, oracle: Address, token: Address, destination_chain: U256) -> Option<U256> {
+        let (_, per_calldata_byte, _, _, _, _) =
+            IArbGasInfo::new(ARB_GAS_INFO).get_prices_in_wei(self.vm(), Call::new()).ok()?;
+        let source_cost = per_calldata_byte.saturating_mul(self.cost_calldata_bytes.get());
+
+        ICostOracle::new(oracle)
+            .quote_execution_cost(self.vm(), Call::new(), token, destination_chain, source_cost)
+            .ok()
+    }
+
+    /// Set a token's volume tier schedule (admin only)
+    ///
+    /// Once an integrator's cumulative volume in `token` reaches
+    /// `thresholds[i]`, its intents pay `fee_bps[i]` instead of the token's
+    /// protocol fee, whichever is lower. Thresholds must rise and fees must
+    /// not; empty lists remove the schedule.
+    pub fn set_volume_tiers(
+        &mut self,
+        token: Address,
+        thresholds: Vec<U256>,
+        fee_bps: Vec<U256>,
+    ) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        if !fees::is_valid_tier_schedule(&thresholds, &fee_bps) {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+
+        for (index, (threshold, bps)) in thresholds.iter().zip(fee_bps.iter()).enumerate() {
+            let index = U256::from(index);
+            self.volume_tier_thresholds.setter(token).setter(index).set(*threshold);
+            self.volume_tier_fee_bps.setter(token).setter(index).set(*bps);
+        }
+        self.volume_tier_counts.setter(token).set(U256::from(thresholds.len()));
+
+        self.advance_config_epoch()?;
+
+        self.vm().log(VolumeTiersSet {
+            token,
+            thresholds,
+            feeBps: fee_bps,
+        });
+
+        Ok(())
+    }
+
+    /// Get a token's volume tier schedule as (thresholds, fee bps)
+    pub fn get_volume_tiers(&self, token: Address) -> (Vec<U256>, Vec<U256>) {
+        let count = self.volume_tier_counts.get(token).to::<usize>();
+        let thresholds = self.volume_tier_thresholds.getter(token);
+        let fee_bps = self.volume_tier_fee_bps.getter(token);
+
+        (0..count)
+            .map(|index| (thresholds.get(U256::from(index)), fee_bps.get(U256::from(index))))
+            .unzip()
+    }
+
+    /// Get an integrator's cumulative input volume in a token
+    pub fn get_integrator_volume(&self, integrator: Address, token: Address) -> U256 {
+        self.integrator_volumes.getter(integrator).get(token)
+    }
+
+    /// Get an integrator's standing in a token's schedule as
+    /// (tier, protocol fee bps, volume to next tier)
+    ///
+    /// Tier zero is the token's base protocol fee; the volume to the next
+    /// tier is zero at the top tier or without a schedule.
+    pub fn get_integrator_tier(&self, integrator: Address, token: Address) -> (U256, U256, U256) {
+        let volume = self.integrator_volumes.getter(integrator).get(token);
+        let (thresholds, _) = self.get_volume_tiers(token);
+
+        (
+            U256::from(fees::volume_tier(volume, &thresholds)),
+            self.get_integrator_fee_bps(integrator, token),
+            fees::volume_to_next_tier(volume, &thresholds),
+        )
+    }
+
+    /// Get the protocol fee in bps an integrator's next intent in a token pays
+    pub fn get_integrator_fee_bps(&self, integrator: Address, token: Address) -> U256 {
+        let base_bps = self.get_fee_bps(token);
+        if integrator == Address::ZERO {
+            return base_bps;
+        }
+
+        let volume = self.integrator_volumes.getter(integrator).get(token);
+        let (thresholds, fee_bps) = self.get_volume_tiers(token);
+        match fees::volume_tier(volume, &thresholds) {
+            0 => base_bps,
+            tier => base_bps.min(fee_bps[tier - 1]),
+        }
+    }
+
+    /// Get an integrator's unclaimed fees for a token
+    pub fn get_integrator_fees(&self, integrator: Address, token: Address) -> U256 {
+        self.integrator_fees.getter(integrator).get(token)
+    }
+
+    /// Claim the caller's accrued integrator fees for a token
+    pub fn claim_integrator_fees(&mut self, token: Address) -> Result<U256, RouteExecutorError> {
+        self.check_not_locked()?;
+
+        let integrator = self.vm().msg_sender();
+        let amount = self.integrator_fees.getter(integrator).get(token);
+        if amount == U256::ZERO {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+
+        self.integrator_fees.setter(integrator).setter(token).set(U256::ZERO);
+        self.record_fees(token, LedgerEntry::RebatesClaimed, amount);
+        self.release_reserved(token, amount);
+        self.internal_send(token, integrator, amount)?;
+
+        self.vm().log(IntegratorFeesClaimed {
+            integrator,
+            token,
+            amount,
+        });
+
+        Ok(amount)
+    }
+
+    /// Send all collected fees of a token to the fee recipient
+    ///
+    /// Callable by the owner or the fee recipient itself.
+    pub fn withdraw_fees(&mut self, token: Address) -> Result<U256, RouteExecutorError> {
+        let recipient = self.fee_recipient.get();
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() && caller != recipient {
+            return Err(RouteExecutorError::Unauthorized(Unauthorized {}));
+        }
+
+        if recipient == Address::ZERO {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        let amount = self.collected_fees.get(token);
+        if amount == U256::ZERO {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+
+        self.check_not_locked()?;
+        self.collected_fees.setter(token).set(U256::ZERO);
+        self.record_fees(token, LedgerEntry::Withdrawn, amount);
+        self.internal_send(token, recipient, amount)?;
+
+        self.vm().log(FeesWithdrawn {
+            token,
+            recipient,
+            amount,
+        });
+
+        Ok(amount)
+    }
+
+    /// Set the smallest dust balance of a token worth sweeping (admin only)
+    ///
+    /// Zero keeps the token out of sweeps.
+    pub fn set_dust_threshold(&mut self, token: Address, threshold: U256) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        if token == Address::ZERO || token == NATIVE_TOKEN {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.dust_thresholds.setter(token).set(threshold);
+
+        self.vm().log(DustThresholdSet { token, threshold });
+
+        Ok(())
+    }
+
+    /// Set the token swept dust is converted into (admin only)
+    pub fn set_treasury_token(&mut self, token: Address) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        if token == Address::ZERO || token == NATIVE_TOKEN {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.treasury_token.set(token);
+
+        self.vm().log(TreasuryTokenSet { token });
+
+        Ok(())
+    }
+
+    /// Convert dust into the treasury token and send it to the fee recipient
+    ///
+    /// Callable by the owner or the fee recipient itself. `steps` is an
+    /// encoded `RouteStep[]` with one Swap step per token to convert, from
+    /// `tokenIn` into the treasury token through an allowlisted swap
+    /// adapter. Tokens whose dust is under their threshold are skipped; the
+    /// treasury token's own dust is always included once over its threshold.
+    /// Tokens bridged in for a hidden recipient are indistinguishable from
+    /// dust until the verifier credits them, so sweep only tokens that don't
+    /// carry them.
+    /// Returns the amount sent.
+    pub fn sweep_dust(&mut self, steps: Bytes) -> Result<U256, RouteExecutorError> {
+        let recipient = self.fee_recipient.get();
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() && caller != recipient {
+            return Err(RouteExecutorError::Unauthorized(Unauthorized {}));
+        }
+
+        let treasury_token = self.treasury_token.get();
+        if recipient == Address::ZERO || treasury_token == Address::ZERO {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        let steps = route_codec::decode_route(&steps).map_err(RouteExecutorError::from)?;
+        self.check_not_locked()?;
+
+        // Measured before any conversion adds to it
+        let mut total = self.sweepable_dust(treasury_token)?;
+
+        for step in steps {
+            if step.stepType != StepType::Swap as u8 {
+                return Err(RouteExecutorError::UnsupportedStep(UnsupportedStep {}));
+            }
+            if step.tokenIn == treasury_token || step.tokenOut != treasury_token {
+                return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+            }
+
+            let dust = self.sweepable_dust(step.tokenIn)?;
+            if dust == U256::ZERO {
+                continue;
+            }
+
+            let amount_out = self.internal_execute_swap(
+                U256::ZERO,
+                step.adapter,
+                step.tokenIn,
+                treasury_token,
+                dust,
+                step.minAmountOut,
+                step.data,
+            )?;
+            total += amount_out;
+
+            self.vm().log(DustConverted {
+                token: step.tokenIn,
+                amountIn: dust,
+                amountOut: amount_out,
+            });
+        }
+
+        if total == U256::ZERO {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+
+        self.internal_send(treasury_token, recipient, total)?;
+
+        self.vm().log(DustSwept {
+            token: treasury_token,
+            recipient,
+            amount: total,
+        });
+
+        Ok(total)
+    }
+
+    /// Get a token's dust: its balance here beyond escrows, unclaimed
+    /// integrator fees and collected protocol fees
+    pub fn get_dust(&self, token: Address) -> Result<U256, RouteExecutorError> {
+        let balance = IERC20::new(token)
+            .balance_of(self.vm(), Call::new(), self.vm().contract_address())
+            .map_err(|_| RouteExecutorError::TransferFailed(TransferFailed {}))?;
+        let owed = self.reserved_balances.get(token) + self.collected_fees.get(token);
+        Ok(balance.saturating_sub(owed))
+    }
+
+    /// Get a token's dust threshold and the treasury token
+    pub fn get_dust_threshold(&self, token: Address) -> (U256, Address) {
+        (self.dust_thresholds.get(token), self.treasury_token.get())
+    }
+
+    /// Accept ETH from WETH withdrawals and bridge fee refunds
+    #[receive]
+    #[payable]
+    pub fn receive(&mut self) -> Result<(), Vec<u8>> {
+        Ok(())
+    }
+
+    /// ERC-165: whether this contract implements `interface_id`
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        erc165::supports(interface_id.0, &[])
+    }
+
+    /// Get contract owner
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Get the executor's ABI-encoded `ProtocolStatus`
+    ///
+    /// Sets the pause and emergency flags; the counters are intents executed
+    /// here, intents waiting in the queue, and indexed config keys.
+    pub fn get_protocol_status(&self) -> Bytes {
+        let mut status =
+            ProtocolStatus::new(StatusContract::RouteExecutor, env!("CARGO_PKG_VERSION"), self.owner.get());
+        status.paused = self.paused.get();
+        status.emergencyMode = self.emergency_mode.get();
+        status.counters = [self.intent_counter.get(), self.queue_length.get(), self.config_count.get()].into();
+        Bytes::from(encode_protocol_status(&status))
+    }
+
+    /// Hand the contract over to a new owner (admin only)
+    pub fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), RouteExecutorError> {
+        self.only_owner()?;
+
+        if new_owner == Address::ZERO {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        let previous_owner = self.owner.get();
+        self.owner.set(new_owner);
+
+        self.vm().log(OwnershipTransferred {
+            previousOwner: previous_owner,
+            newOwner: new_owner,
+        });
+
+        Ok(())
+    }
+
+    /// Internal: Add a packed config key to the export index, once
+    fn index_config(&mut self, packed: U256) {
+        if self.config_listed.get(packed) {
+            return;
+        }
+
+        let count = self.config_count.get();
+        self.config_keys.setter(count).set(packed);
+        self.config_listed.setter(packed).set(true);
+        self.config_count.set(count + U256::from(1));
+    }
+
+    /// Internal: Config entry at an export index, read from current settings
+    fn config_entry(&self, index: U256) -> ConfigEntry {
+        let mut entry = ConfigEntry {
+            kind: 0,
+            key: U256::ZERO,
+            account: Address::ZERO,
+            value: U256::ZERO,
+        };
+
+        if index == U256::ZERO {
+            entry.kind = ConfigEntryKind::ProtocolFee as u8;
+            entry.value = self.protocol_fee_bps.get();
+            return entry;
+        }
+        if index == U256::from(1) {
+            entry.kind = ConfigEntryKind::FeeRecipient as u8;
+            entry.account = self.fee_recipient.get();
+            return entry;
+        }
+
+        let (kind, key) = unpack_config_key(self.config_keys.get(index - U256::from(2)));
+        match kind {
+            Some(ConfigEntryKind::BridgeAdapter) => {
+                entry.key = key;
+                entry.account = self.bridge_adapters.get(key);
+            }
+            Some(ConfigEntryKind::SwapAdapter) => {
+                entry.account = config_key_address(key);
+                entry.value = U256::from(self.swap_adapters.get(entry.account) as u8);
+            }
+            Some(ConfigEntryKind::TokenFee) => {
+                entry.account = config_key_address(key);
+                entry.key = U256::from(self.token_fee_enabled.get(entry.account) as u8);
+                entry.value = self.token_fee_bps.get(entry.account);
+            }
+            _ => {}
+        }
+        entry.kind = kind.map_or(0, |kind| kind as u8);
+        entry
+    }
+
+    /// Internal: Index a newly executing intent and return its global ID
+    ///
+    /// An intent hash executes once; direct intents reuse a hash only if
+    /// the user repeats the same nonce. Blocked hashes never execute. With an
+    /// IntentRegistry set, `intent_key` must also be unused by every other
+    /// executor on the chain.
+    fn register_intent(
+        &mut self,
+        ctx: &ExecutionContext,
+        intent_hash: FixedBytes<32>,
+        intent_key: FixedBytes<32>,
+    ) -> Result<U256, RouteExecutorError> {
+        if self.intent_indexes.get(intent_hash) != U256::ZERO {
+            return Err(RouteExecutorError::IntentAlreadyUsed(IntentAlreadyUsed { intentHash: intent_hash }));
+        }
+        self.check_intent_not_blocked(ctx, intent_hash)?;
+
+        let registry = self.intent_registry.get();
+        if registry != Address::ZERO {
+            let config = Call::new_mutating(self);
+            let consumed = IIntentRegistry::new(registry)
+                .consume(self.vm(), config, intent_key)
+                .map_err(|_| RouteExecutorError::ValidationFailed(ValidationFailed {}))?;
+            if !consumed {
+                return Err(RouteExecutorError::IntentAlreadyUsed(IntentAlreadyUsed { intentHash: intent_hash }));
+            }
+        }
+
+        let index = self.intent_counter.get() + U256::from(1);
+        self.intent_counter.set(index);
+        self.indexed_intents.setter(index).set(intent_hash);
+        self.intent_indexes.setter(intent_hash).set(index);
+
+        Ok(signing::global_intent_id(intent_hash))
+    }
+
+    /// Internal: Start an intent's execution record as Executing
+    fn open_intent_record(&mut self, ctx: &ExecutionContext, intent_id: U256, intent: &Intent) {
+        invariant!(self.intent_headers.get(intent_id) == U256::ZERO, "intent record opened once");
+        let record = IntentRecord::open(
+            IntentStatus::Executing as u8,
+            intent.destinationChain,
+            intent.amountIn,
+            ctx.now,
+        );
+        self.intent_headers.setter(intent_id).set(record.header());
+        self.intent_amounts_in.setter(intent_id).set(record.amount_in);
+        self.intent_users.setter(intent_id).set(intent.user);
+
+        if intent.memo != FixedBytes::ZERO {
+            self.intent_memos.setter(intent_id).set(intent.memo);
+            self.vm().log(IntentMemo { intentId: intent_id, memo: intent.memo });
+        }
+    }
+
+    /// Internal: Hand an intent's settlement callback to SettlementVerifier
+    ///
+    /// The verifier calls it once the intent settles or fails. Intents without
+    /// a callback skip this; one with a callback needs a verifier to call it.
+    fn register_callback(&mut self, intent_id: U256, intent: &Intent) -> Result<(), RouteExecutorError> {
+        if intent.callback == Address::ZERO {
+            return Ok(());
+        }
+        let verifier = self.settlement_verifier.get();
+        if verifier == Address::ZERO || intent.callbackData.len() > MAX_CALLBACK_DATA {
+            return Err(RouteExecutorError::InvalidCallback(InvalidCallback {}));
+        }
+
+        let config = Call::new_mutating(self);
+        ISettlementVerifier::new(verifier)
+            .register_intent_callback(self.vm(), config, intent_id, intent.callback, intent.callbackData.clone())
+            .map_err(|_| RouteExecutorError::ValidationFailed(ValidationFailed {}))?;
+        Ok(())
+    }
+
+    /// Internal: Premium an intent pays for insurance (zero if uninsured)
+    ///
+    /// An insured intent needs insurance turned on, an ERC-20 input and a
+    /// premium cap at or above the current rate.
+    fn premium_bps_for(&self, intent: &Intent) -> Result<U256, RouteExecutorError> {
+        if intent.insurancePremiumBps == U256::ZERO {
+            return Ok(U256::ZERO);
+        }
+        let premium_bps = self.insurance_premium_bps.get();
+        if premium_bps == U256::ZERO || intent.tokenIn == NATIVE_TOKEN {
+            return Err(RouteExecutorError::InsuranceUnavailable(InsuranceUnavailable {}));
+        }
+        if premium_bps > intent.insurancePremiumBps {
+            return Err(RouteExecutorError::FeeTooHigh(FeeTooHigh {
+                feeBps: premium_bps,
+                maxFeeBps: intent.insurancePremiumBps,
+            }));
+        }
+        Ok(premium_bps)
+    }
+
+    /// Internal: Pay an insured intent's premium into the InsuranceFund and
+    /// register its coverage with SettlementVerifier
+    ///
+    /// The coverage is the input left after the premium, which is returned.
+    /// A zero `premium_bps` leaves the intent uninsured.
+    fn insure_intent(
+        &mut self,
+        intent_id: U256,
+        intent: &Intent,
+        premium_bps: U256,
+    ) -> Result<U256, RouteExecutorError> {
+        if premium_bps == U256::ZERO {
+            return Ok(intent.amountIn);
+        }
+        let verifier = self.settlement_verifier.get();
+        let fund = if verifier == Address::ZERO {
+            Address::ZERO
+        } else {
+            ISettlementVerifier::new(verifier)
+                .insurance_fund(self.vm(), Call::new())
+                .unwrap_or_default()
+        };
+        if fund == Address::ZERO {
+            return Err(RouteExecutorError::InsuranceUnavailable(InsuranceUnavailable {}));
+        }
+
+        let (coverage, premium) = fees::take_fee(intent.amountIn, premium_bps);
+        self.internal_send(intent.tokenIn, fund, premium)?;
+
+        let config = Call::new_mutating(self);
+        ISettlementVerifier::new(verifier)
+            .register_insurance(self.vm(), config, intent_id, intent.user, intent.tokenIn, coverage, premium)
+            .map_err(|_| RouteExecutorError::InsuranceUnavailable(InsuranceUnavailable {}))?;
+
+        self.vm().log(InsurancePremiumPaid {
+            intentId: intent_id,
+            fund,
+            token: intent.tokenIn,
+            premium,
+        });
+
+        Ok(coverage)
+    }
+
+    /// Internal: Check an intent's retry policy before anything runs
+    ///
+    /// A policy must decode and the route must bridge; only a bridged
+    /// delivery can time out and be retried. A verifier must be set to
+    /// honor it.
+    fn check_retry_policy(&self, intent: &Intent, steps: &[RouteStep]) -> Result<(), RouteExecutorError> {
+        if intent.retryPolicy == U256::ZERO {
+            return Ok(());
+        }
+        let bridges = steps.iter().any(|step| step.stepType == StepType::Bridge as u8);
+        if !bridges
+            || self.settlement_verifier.get() == Address::ZERO
+            || !RetryPolicy::decode(intent.retryPolicy).is_some_and(|policy| policy.retries())
+        {
+            return Err(RouteExecutorError::InvalidRetryPolicy(InvalidRetryPolicy {}));
+        }
+        Ok(())
+    }
+
+    /// Internal: Register an intent's retry policy with SettlementVerifier
+    /// along with the adapter that bridged it (no-op without a policy)
+    fn register_retry_policy(
+        &mut self,
+        intent_id: U256,
+        intent: &Intent,
+        bridge: Address,
+    ) -> Result<(), RouteExecutorError> {
+        if intent.retryPolicy == U256::ZERO {
+            return Ok(());
+        }
+        let verifier = self.settlement_verifier.get();
+        let config = Call::new_mutating(self);
+        ISettlementVerifier::new(verifier)
+            .register_retry_policy(self.vm(), config, intent_id, intent.retryPolicy, bridge)
+            .map_err(|_| RouteExecutorError::InvalidRetryPolicy(InvalidRetryPolicy {}))?;
+        Ok(())
+    }
+
+    /// Internal: Mark an intent Completed with the amount it delivered
+    fn complete_intent_record(&mut self, ctx: &ExecutionContext, intent_id: U256, amount_out: U256) {
+        let header = self.intent_headers.get(intent_id);
+        invariant!(
+            invariants::is_intent_transition(intent_record::header_status(header), IntentStatus::Completed as u8),
+            "intent status transition"
+        );
+        let completed = intent_record::update_header_status(header, IntentStatus::Completed as u8, ctx.now);
+        self.intent_headers.setter(intent_id).set(completed);
+        self.intent_amounts_out.setter(intent_id).set(amount_out);
+    }
+
+    /// Internal: Load an intent's execution record from its storage words
+    fn intent_record(&self, intent_id: U256) -> IntentRecord {
+        IntentRecord::decode([
+            self.intent_headers.get(intent_id),
+            self.intent_amounts_in.get(intent_id),
+            self.intent_amounts_out.get(intent_id),
+        ])
+    }
+
+    /// Internal: Run an intent's route steps in order
+    /// 
+    /// Tracks the token and amount held for the intent between steps; each
+    /// step must take the previous step's output token as input. A zero
+    /// `solver_id` means the user is executing directly; otherwise the caller
+    /// has already verified the user's signature. A verified `quote` is
+    /// settled against the amount reaching the final step. An `escrowed`
+    /// intent's input is already held here and is not pulled. `intent_hash`
+    /// is the intent's EIP-712 digest under `ctx`, already computed by the
+    /// caller.
+    #[allow(clippy::too_many_arguments)]
+    fn internal_execute_route(
+        &mut self,
+        ctx: &ExecutionContext,
+        intent_hash: FixedBytes<32>,
+        mut intent: Intent,
+        steps: Vec<RouteStep>,
+        integrator: Address,
+        integrator_fee_bps: U256,
+        solver_id: U256,
+        quote: Option<QuoteCommitment>,
+        escrowed: bool,
+    ) -> Result<RouteOutcome, RouteExecutorError> {
+        // Reject stale routes, and bound intents whose block was reorged out;
+        // an escrowed intent's block was checked when it was escrowed
+        check_deadline(intent.deadline, ctx.now)?;
+        if !escrowed {
+            self.check_block_binding(&intent)?;
+        }
+
+        // Reentrancy guard
+        self.check_not_locked()?;
+        self.locked.set(true);
+
+        let user = intent.user;
+        if solver_id == U256::ZERO {
+            if self.solver_only.get() {
+                return Err(RouteExecutorError::SolverOnly(SolverOnly {}));
+            }
+            self.check_submitter(ctx, intent_hash, &intent, intent.amountIn)?;
+        }
+
+        // Validate intent
+        let intent_key = signing::intent_key(&intent);
+        let hidden = self.resolve_hidden_recipient(&mut intent)?;
+        if intent.recipient == Address::ZERO {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        if intent.amountIn == U256::ZERO {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+        self.check_intent_admitted(ctx, &intent)?;
+        self.check_lane_healthy(ctx, intent.destinationChain)?;
+        self.check_config_epoch(ctx, intent.configEpoch)?;
+
+        let max_integrator_fee = self.max_integrator_fee_bps.get();
+        if integrator_fee_bps > max_integrator_fee {
+            return Err(RouteExecutorError::FeeTooHigh(FeeTooHigh {
+                feeBps: integrator_fee_bps,
+                maxFeeBps: max_integrator_fee,
+            }));
+        }
+        if integrator == Address::ZERO && integrator_fee_bps != U256::ZERO {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        // Native intents fund the route with msg.value; ERC20 intents send none.
+        // Anything above that funds the gas drop.
+        let native_in = intent.tokenIn == NATIVE_TOKEN;
+        let expected_value = if native_in { intent.amountIn } else { U256::ZERO };
+        let msg_value = self.vm().msg_value();
+        if msg_value < expected_value || (intent.gasDrop == U256::ZERO && msg_value != expected_value) {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+        let gas_drop_budget = msg_value - expected_value;
+        self.check_gas_drop(&intent, &steps)?;
+        self.check_gas_limit(&intent, &steps)?;
+
+        // Reject malformed routes before pulling any funds
+        let weth = ctx.weth;
+        route_codec::validate_route(&intent, &steps, weth, |adapter| self.swap_adapters.get(adapter))
+            .map_err(RouteExecutorError::from)?;
+        self.check_native_delivery(&intent, &steps)?;
+        self.check_retry_policy(&intent, &steps)?;
+        let token_in = intent.tokenIn;
+        let protocol_fee_bps =
+            self.quote_protocol_fee_bps(integrator, token_in, intent.amountIn, intent.destinationChain);
+        let premium_bps = self.premium_bps_for(&intent)?;
+        self.check_liquidity(
+            ctx,
+            &intent,
+            &steps,
+            protocol_fee_bps,
+            integrator_fee_bps,
+            quote.as_ref(),
+        )?;
+
+        let intent_id = self.register_intent(ctx, intent_hash, intent_key)?;
+
+        // Record the intent as Executing
+        self.open_intent_record(ctx, intent_id, &intent);
+        self.register_callback(intent_id, &intent)?;
+
+        if hidden {
+            self.vm().log(RecipientCommitted {
+                intentId: intent_id,
+                recipientHash: intent.recipientHash,
+                receiver: intent.recipient,
+            });
+        }
+
+        // Transfer tokens from user to contract
+        if !native_in && !escrowed {
+            self.pull_token(intent.tokenIn, user, intent.amountIn)?;
+        }
+
+        // The insurance premium, then protocol and integrator fees, come off
+        // the input before any step runs
+        let insured_amount = self.insure_intent(intent_id, &intent, premium_bps)?;
+        let (after_protocol, mut protocol_fee) = fees::take_fee(insured_amount, protocol_fee_bps);
+        let (net_amount, integrator_fee) = fees::take_fee(after_protocol, integrator_fee_bps);
+        invariant!(
+            invariants::is_fee_within_cap(protocol_fee, insured_amount, fees::MAX_PROTOCOL_FEE_BPS),
+            "protocol fee within cap"
+        );
+        invariant!(
+            invariants::is_fee_within_cap(integrator_fee, after_protocol, fees::MAX_INTEGRATOR_FEE_BPS),
+            "integrator fee within cap"
+        );
+        let mut integrator_share = U256::ZERO;
+
+        if integrator != Address::ZERO {
+            self.record_integrator_volume(integrator, token_in, intent.amountIn);
+        }
+
+        if integrator_fee != U256::ZERO {
+            let share_bps = self.integrator_protocol_share_bps.get();
+            let (integrator_part, protocol_part) = fees::split_integrator_fee(integrator_fee, share_bps);
+            protocol_fee += protocol_part;
+            integrator_share = integrator_part;
+
+            let accrued = self.integrator_fees.getter(integrator).get(token_in);
+            self.integrator_fees
+                .setter(integrator)
+                .setter(token_in)
+                .set(accrued + integrator_part);
+            self.record_fees(token_in, LedgerEntry::Rebated, integrator_part);
+            self.reserve(token_in, integrator_part);
+
+            self.vm().log(IntegratorFeeCollected {
+                intentId: intent_id,
+                integrator,
+                token: token_in,
+                integratorAmount: integrator_part,
+                protocolAmount: protocol_part,
+            });
+        }
+
+        self.collect_protocol_fee(intent_id, token_in, protocol_fee);
+
+        // Intermediate balance held for this intent
+        let mut current_token = intent.tokenIn;
+        let mut current_amount = net_amount;
+        let mut gas_drop_refund = U256::ZERO;
+        let mut bridge_fee = U256::ZERO;
+        let mut gas_drop_cost = U256::ZERO;
+
+        for (step_index, step) in steps.into_iter().enumerate() {
+            // Step types, token path and terminal placement were validated above
+            let step_type = StepType::from_u8(step.stepType)
+                .ok_or(RouteExecutorError::UnsupportedStep(UnsupportedStep {}))?;
+
+            if step.deadline != U256::ZERO {
+                check_deadline(step.deadline, ctx.now)?;
+            }
+
+            let (step_token_in, step_amount_in) = (current_token, current_amount);
+            let mut step_adapter = step.adapter;
+
+            match step_type {
+                StepType::Swap => {
+                    let amount_out = self.internal_execute_swap(
+                        intent_id,
+                        step.adapter,
+                        current_token,
+                        step.tokenOut,
+                        current_amount,
+                        step.minAmountOut,
+                        step.data,
+                    )?;
+                    current_token = step.tokenOut;
+                    current_amount = amount_out;
+                }
+                StepType::Bridge => {
+                    check_min_out(current_amount, intent.minAmountOut)?;
+
+                    // Allowlist and gas limit were validated with the route
+                    let (candidates, gas_limit) = route_codec::decode_bridge_step_data(&step.data)?;
+                    let lane_adapter = if candidates.is_empty() {
+                        let lane_adapter = self.resolve_bridge_adapter(current_token, intent.destinationChain);
+                        if step.adapter != Address::ZERO && step.adapter != lane_adapter {
+                            return Err(RouteExecutorError::AdapterNotAllowed(AdapterNotAllowed {}));
+                        }
+                        lane_adapter
+                    } else {
+                        // Cheapest-lane mode
+                        let (adapter, fee) = self.quote_cheapest_adapter(
+                            current_token,
+                            current_amount,
+                            intent.destinationChain,
+                            intent.recipient,
+                            candidates,
+                        )?;
+
+                        self.vm().log(BridgeAdapterSelected {
+                            intentId: intent_id,
+                            adapter,
+                            fee,
+                        });
+
+                        adapter
+                    };
+                    step_adapter = lane_adapter;
+
+                    // The route check kept non-EVM recipients to plain token bridges
+                    let (fee, drop_cost) = if intent.recipientFamily == ChainFamily::Evm as u8 {
+                        self.internal_execute_bridge(
+                            intent_id,
+                            lane_adapter,
+                            current_token,
+                            current_amount,
+                            intent.destinationChain,
+                            intent.recipient,
+                            step.tokenOut == NATIVE_TOKEN,
+                            intent.gasDrop,
+                            gas_limit,
+                        )?
+                    } else {
+                        let fee = self.internal_execute_chain_address_bridge(
+                            intent_id,
+                            lane_adapter,
+                            current_token,
+                            current_amount,
+                            &intent,
+                        )?;
+                        (fee, U256::ZERO)
+                    };
+                    bridge_fee = fee;
+                    gas_drop_cost = drop_cost;
+                    self.register_retry_policy(intent_id, &intent, lane_adapter)?;
+
+                    if intent.gasDrop != U256::ZERO {
+                        if drop_cost > gas_drop_budget {
+                            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+                        }
+                        gas_drop_refund = gas_drop_budget - drop_cost;
+
+                        self.vm().log(GasDropSponsored {
+                            intentId: intent_id,
+                            sponsor: ctx.sender,
+                            gasDrop: intent.gasDrop,
+                            cost: drop_cost,
+                        });
+                    }
+                }
+                StepType::Transfer => {
+                    check_min_out(current_amount, intent.minAmountOut)?;
+
+                    let to = if step.recipient != Address::ZERO {
+                        step.recipient
+                    } else {
+                        intent.recipient
+                    };
+                    self.internal_send(current_token, to, current_amount)?;
+                }
+                StepType::Wrap => {
+                    self.guarded_call(weth, current_amount, TOKEN_CALL_GAS, &depositCall {}.abi_encode())
+                        .ok_or(RouteExecutorError::TransferFailed(TransferFailed {}))?;
+                    current_token = weth;
+                    step_adapter = weth;
+                }
+                StepType::Unwrap => {
+                    let calldata = withdrawCall { amount: current_amount }.abi_encode();
+                    self.guarded_call(weth, U256::ZERO, TOKEN_CALL_GAS, &calldata)
+                        .ok_or(RouteExecutorError::TransferFailed(TransferFailed {}))?;
+                    current_token = NATIVE_TOKEN;
+                    step_adapter = weth;
+                }
+            }
+
+            // A failing step reverts the whole route, so only completed steps are logged here
+            self.vm().log(RouteStepExecuted {
+                intentId: intent_id,
+                stepIndex: U256::from(step_index),
+                stepType: step_type as u8,
+                adapter: step_adapter,
+                tokenIn: step_token_in,
+                tokenOut: current_token,
+                amountIn: step_amount_in,
+                amountOut: current_amount,
+                success: true,
+            });
+        }
+
+        // Final step consumed the whole balance; that is what the route realized
+        if let Some(quote) = quote {
+            if solver_id != U256::ZERO {
+                self.check_solver_fee(intent_id, solver_id, &intent, &quote, current_amount)?;
+            }
+            self.record_quote_outcome(intent_id, solver_id, quote, current_amount);
+        }
+
+        // Return what the gas drop didn't cost to its sponsor
+        if gas_drop_refund != U256::ZERO {
+            self.vm()
+                .transfer_eth(ctx.sender, gas_drop_refund)
+                .map_err(|_| RouteExecutorError::TransferFailed(TransferFailed {}))?;
+        }
+
+        // Record the intent as Completed with what it delivered
+        self.complete_intent_record(ctx, intent_id, current_amount);
+
+        // Emit success event
+        self.vm().log(IntentExecuted {
+            intentId: intent_id,
+            user,
+            destinationChain: intent.destinationChain,
+            tokenIn: token_in,
+            timestamp: ctx.now,
+        });
+
+        // Release lock
+        self.locked.set(false);
+
+        Ok(RouteOutcome {
+            intent_id,
+            amount_out: current_amount,
+            protocol_fee,
+            integrator_fee: integrator_share,
+            bridge_fee,
+            gas_drop_cost,
+        })
+    }
+
+    /// Internal: Execute DEX swap through an allowlisted adapter
+    /// 
+    /// The output is measured as this contract's balance delta rather than
+    /// trusting the adapter's return value, and must reach `min_amount_out`.
+    #[allow(clippy::too_many_arguments)]
+    fn internal_execute_swap(
+        &mut self,
+        intent_id: U256,
+        adapter_address: Address,
+        token_in: Address,
+        token_out: Address,
+        amount: U256,
+        min_amount_out: U256,
+        adapter_data: Bytes,
+    ) -> Result<U256, RouteExecutorError> {
+        if !self.swap_adapters.get(adapter_address) {
+            return Err(RouteExecutorError::AdapterNotAllowed(AdapterNotAllowed {}));
+        }
+
+        if token_out == Address::ZERO || token_out == token_in {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        let this = self.vm().contract_address();
+        let output = IERC20::new(token_out);
+        let balance_before = output
+            .balance_of(self.vm(), Call::new(), this)
+            .map_err(|_| RouteExecutorError::SwapFailed(SwapFailed {}))?;
+
+        // Adapter pulls the input from this contract
+        if !self.guarded_token_call(token_in, &approveCall { spender: adapter_address, amount }.abi_encode()) {
+            return Err(RouteExecutorError::SwapFailed(SwapFailed {}));
+        }
+
+        let calldata = swapCall {
+            token_in,
+            token_out,
+            amount_in: amount,
+            min_amount_out,
+            recipient: this,
+            data: adapter_data,
+        }
+        .abi_encode();
+        self.guarded_call(adapter_address, U256::ZERO, ADAPTER_CALL_GAS, &calldata)
+            .ok_or(RouteExecutorError::SwapFailed(SwapFailed {}))?;
+        if !self.release_approval(token_in, adapter_address) {
+            return Err(RouteExecutorError::SwapFailed(SwapFailed {}));
+        }
+
+        let balance_after = output
+            .balance_of(self.vm(), Call::new(), this)
+            .map_err(|_| RouteExecutorError::SwapFailed(SwapFailed {}))?;
+        let amount_out = balance_after.saturating_sub(balance_before);
+        if amount_out == U256::ZERO {
+            return Err(RouteExecutorError::SwapFailed(SwapFailed {}));
+        }
+
+        check_min_out(amount_out, min_amount_out)?;
+
+        self.vm().log(SwapExecuted {
+            intentId: intent_id,
+            tokenIn: token_in,
+            tokenOut: token_out,
+            amountIn: amount,
+            amountOut: amount_out,
+        });
+
+        Ok(amount_out)
+    }
+
+    /// Internal: Ask a swap step's adapter what it would return for `amount_in`
+    /// of `token_in` (zero if it can't quote)
+    fn quote_swap(&mut self, step: &RouteStep, token_in: Address, amount_in: U256) -> U256 {
+        let calldata = quoteCall {
+            token_in,
+            token_out: step.tokenOut,
+            amount_in,
+            data: step.data.clone(),
+        }
+        .abi_encode();
+        self.guarded_call(step.adapter, U256::ZERO, ADAPTER_CALL_GAS, &calldata)
+            .and_then(|output| quoteCall::abi_decode_returns(&output).ok())
+            .unwrap_or(U256::ZERO)
+    }
+
+    /// Internal: Quote a route's swap steps before any funds move
+    ///
+    /// Each swap step must quote at least its minimum, with the amount left
+    /// after fees flowing through the route; a zero quote means the pool is
+    /// empty or missing. With a signed quote, the expected output may fall at
+    /// most `liquidity_check_bps` short of it. Skipped while the check is off.
+    fn check_liquidity(
+        &mut self,
+        ctx: &ExecutionContext,
+        intent: &Intent,
+        steps: &[RouteStep],
+        protocol_fee_bps: U256,
+        integrator_fee_bps: U256,
+        quote: Option<&QuoteCommitment>,
+    ) -> Result<(), RouteExecutorError> {
+        let max_deviation_bps = self.liquidity_check_bps.get();
+        if max_deviation_bps == U256::ZERO {
+            return Ok(());
+        }
+
+        let (insured_amount, _) = fees::take_fee(intent.amountIn, self.premium_bps_for(intent)?);
+        let (after_protocol, _) = fees::take_fee(insured_amount, protocol_fee_bps);
+        let (mut current_amount, _) = fees::take_fee(after_protocol, integrator_fee_bps);
+        let mut current_token = intent.tokenIn;
+        let mut last_swap = None;
+
+        for (step_index, step) in steps.iter().enumerate() {
+            match StepType::from_u8(step.stepType) {
+                Some(StepType::Swap) => {
+                    let amount_out = self.quote_swap(step, current_token, current_amount);
+                    if amount_out == U256::ZERO || amount_out < step.minAmountOut {
+                        return Err(RouteExecutorError::InsufficientLiquidity(InsufficientLiquidity {
+                            stepIndex: U256::from(step_index),
+                            expectedOut: amount_out,
+                            minOut: step.minAmountOut,
+                        }));
+                    }
+                    current_token = step.tokenOut;
+                    current_amount = amount_out;
+                    last_swap = Some(step_index);
+                }
+                Some(StepType::Wrap) => current_token = ctx.weth,
+                Some(StepType::Unwrap) => current_token = NATIVE_TOKEN,
+                _ => break,
+            }
+        }
+
+        if let (Some(step_index), Some(quote)) = (last_swap, quote) {
+            let (min_out, _) = fees::take_fee(quote.quoted_amount_out, max_deviation_bps);
+            if current_amount < min_out {
+                return Err(RouteExecutorError::InsufficientLiquidity(InsufficientLiquidity {
+                    stepIndex: U256::from(step_index),
+                    expectedOut: current_amount,
+                    minOut: min_out,
+                }));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Internal: Initiate a bridge transfer through `adapter_address`
+    ///
+    /// A zero adapter sends the transfer through CCIP. `unwrap_native` asks
+    /// the destination adapter to pay out ETH. A non-zero `gas_drop` goes
+    /// through the adapter's gas-drop extension, and a non-zero `gas_limit`
+    /// through its gas-limit extension. Returns the messaging fee paid and
+    /// what the drop added to it.
+    #[allow(clippy::too_many_arguments)]
+    fn internal_execute_bridge(
+        &mut self,
+        intent_id: U256,
+        adapter_address: Address,
+        token: Address,
+        amount: U256,
+        destination_chain: U256,
+        recipient: Address,
+        unwrap_native: bool,
+        gas_drop: U256,
+        gas_limit: U256,
+    ) -> Result<(U256, U256), RouteExecutorError> {
+        self.take_lane_capacity(intent_id, token, amount, destination_chain)?;
+
+        let mut fee = U256::ZERO;
+        let mut drop_cost = U256::ZERO;
+
+        if adapter_address != Address::ZERO {
+            let adapter = IBridgeAdapter::new(adapter_address);
+
+            if !adapter
+                .supports_lane(self.vm(), Call::new(), destination_chain)
+                .unwrap_or(false)
+            {
+                return Err(RouteExecutorError::BridgeFailed(BridgeFailed {}));
+            }
+
+            fee = adapter
+                .quote_fee(self.vm(), Call::new(), destination_chain, token, amount, recipient)
+                .map_err(|_| RouteExecutorError::BridgeFailed(BridgeFailed {}))?;
+
+            // Adapter pulls the tokens from this contract
+            if !self.guarded_token_call(token, &approveCall { spender: adapter_address, amount }.abi_encode()) {
+                return Err(RouteExecutorError::BridgeFailed(BridgeFailed {}));
+            }
+
+            if gas_limit != U256::ZERO {
+                // The route check keeps gas limits and gas drops apart
+                fee = IGasLimitAdapter::new(adapter_address)
+                    .quote_fee_with_gas_limit(
+                        self.vm(),
+                        Call::new(),
+                        destination_chain,
+                        token,
+                        amount,
+                        recipient,
+                        gas_limit,
+                    )
+                    .map_err(|_| RouteExecutorError::BridgeFailed(BridgeFailed {}))?;
+
+                let calldata = bridgeWithGasLimitCall {
+                    intent_id,
+                    destination_chain,
+                    token,
+                    amount,
+                    recipient,
+                    unwrap_native,
+                    gas_limit,
+                }
+                .abi_encode();
+                self.guarded_call(adapter_address, fee, ADAPTER_CALL_GAS, &calldata)
+                    .ok_or(RouteExecutorError::BridgeFailed(BridgeFailed {}))?;
+            } else if gas_drop == U256::ZERO {
+                let calldata = bridgeCall {
+                    intent_id,
+                    destination_chain,
+                    token,
+                    amount,
+                    recipient,
+                    unwrap_native,
+                }
+                .abi_encode();
+                self.guarded_call(adapter_address, fee, ADAPTER_CALL_GAS, &calldata)
+                    .ok_or(RouteExecutorError::BridgeFailed(BridgeFailed {}))?;
+            } else {
+                let drop_adapter = IGasDropAdapter::new(adapter_address);
+                let total_fee = drop_adapter
+                    .quote_fee_with_gas_drop(
+                        self.vm(),
+                        Call::new(),
+                        destination_chain,
+                        token,
+                        amount,
+                        recipient,
+                        gas_drop,
+                    )
+                    .map_err(|_| RouteExecutorError::BridgeFailed(BridgeFailed {}))?;
+                drop_cost = total_fee.saturating_sub(fee);
+
+                let calldata = bridgeWithGasDropCall {
+                    intent_id,
+                    destination_chain,
+                    token,
+                    amount,
+                    recipient,
+                    unwrap_native,
+                    gas_drop,
+                }
+                .abi_encode();
+                self.guarded_call(adapter_address, total_fee, ADAPTER_CALL_GAS, &calldata)
+                    .ok_or(RouteExecutorError::BridgeFailed(BridgeFailed {}))?;
+            }
+
+            if !self.release_approval(token, adapter_address) {
+                return Err(RouteExecutorError::BridgeFailed(BridgeFailed {}));
+            }
+        } else if unwrap_native || gas_drop != U256::ZERO || gas_limit != U256::ZERO {
+            // Native delivery, gas drops and gas limits need an adapter that supports them
+            return Err(RouteExecutorError::BridgeFailed(BridgeFailed {}));
+        }
+        // Lanes without an adapter go through the CCIP router
+        // NOTE: Direct CCIP router call will be implemented in Phase 2
+
+        self.vm().log(BridgeInitiated {
+            intentId: intent_id,
+            token,
+            amount,
+            destinationChain: destination_chain,
+            recipient,
+        });
+
+        Ok((fee, drop_cost))
+    }
+
+    /// Internal: Bridge an intent's output to its non-EVM recipient
+    ///
+    /// Only adapters implementing `IChainAddressAdapter` reach such chains;
+    /// CCIP lanes and other adapters fail with `BridgeFailed`. Returns the
+    /// bridge fee paid.
+    fn internal_execute_chain_address_bridge(
+        &mut self,
+        intent_id: U256,
+        adapter_address: Address,
+        token: Address,
+        amount: U256,
+        intent: &Intent,
+    ) -> Result<U256, RouteExecutorError> {
+        if adapter_address == Address::ZERO {
+            return Err(RouteExecutorError::BridgeFailed(BridgeFailed {}));
+        }
+        let destination_chain = intent.destinationChain;
+        self.take_lane_capacity(intent_id, token, amount, destination_chain)?;
+
+        if !IBridgeAdapter::new(adapter_address)
+            .supports_lane(self.vm(), Call::new(), destination_chain)
+            .unwrap_or(false)
+        {
+            return Err(RouteExecutorError::BridgeFailed(BridgeFailed {}));
+        }
+        let fee = IChainAddressAdapter::new(adapter_address)
+            .quote_chain_address_fee(
+                self.vm(),
+                Call::new(),
+                destination_chain,
+                token,
+                amount,
+                intent.recipientFamily,
+                intent.recipientAddress.clone(),
+            )
+            .map_err(|_| RouteExecutorError::BridgeFailed(BridgeFailed {}))?;
+
+        // Adapter pulls the tokens from this contract
+        if !self.guarded_token_call(token, &approveCall { spender: adapter_address, amount }.abi_encode()) {
+            return Err(RouteExecutorError::BridgeFailed(BridgeFailed {}));
+        }
+        let calldata = bridgeToChainAddressCall {
+            intent_id,
+            destination_chain,
+            token,
+            amount,
+            recipient_family: intent.recipientFamily,
+            recipient: intent.recipientAddress.clone(),
+        }
+        .abi_encode();
+        self.guarded_call(adapter_address, fee, ADAPTER_CALL_GAS, &calldata)
+            .ok_or(RouteExecutorError::BridgeFailed(BridgeFailed {}))?;
+        if !self.release_approval(token, adapter_address) {
+            return Err(RouteExecutorError::BridgeFailed(BridgeFailed {}));
+        }
+
+        self.vm().log(BridgeInitiated {
+            intentId: intent_id,
+            token,
+            amount,
+            destinationChain: destination_chain,
+            recipient: Address::ZERO,
+        });
+        self.vm().log(ChainAddressBridged {
+            intentId: intent_id,
+            recipientFamily: intent.recipientFamily,
+            recipientAddress: intent.recipientAddress.clone(),
+        });
+
+        Ok(fee)
+    }
+
+    /// Internal: Point a hidden-recipient intent at the destination's RouteExecutor
+    ///
+    /// The output is held there until `settle_hidden_recipient` reveals who
+    /// gets it. Returns whether the intent hides its recipient.
+    fn resolve_hidden_recipient(&self, intent: &mut Intent) -> Result<bool, RouteExecutorError> {
+        if intent.recipientHash == FixedBytes::ZERO {
+            return Ok(false);
+        }
+
+        let receiver = self.remote_executors.get(intent.destinationChain);
+        if intent.recipient != Address::ZERO || receiver == Address::ZERO {
+            return Err(RouteExecutorError::InvalidAddress(InvalidAddress {}));
+        }
+        intent.recipient = receiver;
+
+        Ok(true)
+    }
+
+    /// Internal: Reject native delivery to a chain with no known wrapped native
+    fn check_native_delivery(&self, intent: &Intent, steps: &[RouteStep]) -> Result<(), RouteExecutorError> {
+        let native_out = steps
+            .last()
+            .is_some_and(|step| step.stepType == StepType::Bridge as u8 && step.tokenOut == NATIVE_TOKEN);
+        if native_out && self.wrapped_natives.get(intent.destinationChain) == Address::ZERO {
+            return Err(RouteExecutorError::from(RouteError::WrongWrappedNative));
+        }
+        Ok(())
+    }
+
+    /// Internal: Native fee for bridging through `adapter`, including the intent's gas drop
+    fn quote_bridge_fee(
+        &self,
+        adapter: Address,
+        token: Address,
+        amount: U256,
+        intent: &Intent,
+        gas_limit: U256,
+    ) -> Result<U256, RouteExecutorError> {
+        if intent.recipientFamily != ChainFamily::Evm as u8 {
+            if adapter == Address::ZERO {
+                return Err(RouteExecutorError::BridgeFailed(BridgeFailed {}));
+            }
+            return IChainAddressAdapter::new(adapter)
+                .quote_chain_address_fee(
+                    self.vm(),
+                    Call::new(),
+                    intent.destinationChain,
+                    token,
+                    amount,
+                    intent.recipientFamily,
+                    intent.recipientAddress.clone(),
+                )
+                .map_err(|_| RouteExecutorError::BridgeFailed(BridgeFailed {}));
+        }
+
+        if adapter == Address::ZERO {
+            // CCIP lanes can't carry a gas drop or gas limit
+            if intent.gasDrop != U256::ZERO || gas_limit != U256::ZERO {
+                return Err(RouteExecutorError::BridgeFailed(BridgeFailed {}));
+            }
+            return Ok(U256::ZERO);
+        }
+
+        let destination_chain = intent.destinationChain;
+        let fee = if gas_limit != U256::ZERO {
+            IGasLimitAdapter::new(adapter).quote_fee_with_gas_limit(
+                self.vm(),
+                Call::new(),
+                destination_chain,
+                token,
+                amount,
+                intent.recipient,
+                gas_limit,
+            )
+        } else if intent.gasDrop == U256::ZERO {
+            IBridgeAdapter::new(adapter).quote_fee(
+                self.vm(),
+                Call::new(),
+                destination_chain,
+                token,
+                amount,
+                intent.recipient,
+            )
+        } else {
+            IGasDropAdapter::new(adapter).quote_fee_with_gas_drop(
+                self.vm(),
+                Call::new(),
+                destination_chain,
+                token,
+                amount,
+                intent.recipient,
+                intent.gasDrop,
+            )
+        };
+        fee.map_err(|_| RouteExecutorError::BridgeFailed(BridgeFailed {}))
+    }
+
+    /// Internal: Check a requested gas drop against the destination's cap
+    ///
+    /// Gas drops only ride along with a bridge, so the route must end in one.
+    fn check_gas_drop(&self, intent: &Intent, steps: &[RouteStep]) -> Result<(), RouteExecutorError> {
+        if intent.gasDrop == U256::ZERO {
+            return Ok(());
+        }
+
+        let max_gas_drop = self.max_gas_drops.get(intent.destinationChain);
+        if intent.gasDrop > max_gas_drop {
+            return Err(RouteExecutorError::GasDropTooHigh(GasDropTooHigh {
+                gasDrop: intent.gasDrop,
+                maxGasDrop: max_gas_drop,
+            }));
+        }
+
+        if !steps.last().is_some_and(|step| step.stepType == StepType::Bridge as u8) {
+            return Err(RouteExecutorError::InvalidAmount(InvalidAmount {}));
+        }
+        Ok(())
+    }
+
+    /// Internal: Check a Bridge step's destination gas limit against the
+    /// destination's cap
+    fn check_gas_limit(&self, intent: &Intent, steps: &[RouteStep]) -> Result<(), RouteExecutorError> {
+        let Some(step) = steps.last().filter(|step| step.stepType == StepType::Bridge as u8) else {
+            return Ok(());
+        };
+
+        let (_, gas_limit) = route_codec::decode_bridge_step_data(&step.data)?;
+        let max_gas_limit = self.max_gas_limits.get(intent.destinationChain);
+        if gas_limit > max_gas_limit {
+            return Err(RouteExecutorError::GasLimitTooHigh(GasLimitTooHigh {
+                gasLimit: gas_limit,
+                maxGasLimit: max_gas_limit,
+            }));
+        }
+        Ok(())
+    }
+
+    /// Internal: Verify an optional encoded quote, enforcing the quote policy
+    fn check_route_quote(
+        &self,
+        ctx: &ExecutionContext,
+        intent_hash: FixedBytes<32>,
+        intent: &Intent,
+        steps: &[RouteStep],
+        route_quote: &[u8],
+        route_signature: &[u8],
+    ) -> Result<Option<QuoteCommitment>, RouteExecutorError> {
+        if route_quote.is_empty() {
+            if self.route_quote_required.get() {
+                return Err(RouteExecutorError::RouteQuoteRequired(RouteQuoteRequired {}));
+            }
+            return Ok(None);
+        }
+
+        let quote = route_codec::decode_route_quote(route_quote).map_err(RouteExecutorError::from)?;
+        self.verify_route_quote(ctx, intent_hash, intent, steps, quote, route_signature)
+            .map(Some)
+    }
+
+    /// Internal: Check a route oracle's quote against the intent and route about to execute
+    fn verify_route_quote(
+        &self,
+        ctx: &ExecutionContext,
+        intent_hash: FixedBytes<32>,
+        intent: &Intent,
+        steps: &[RouteStep],
+        quote: RouteQuote,
+        signature: &[u8],
+    ) -> Result<QuoteCommitment, RouteExecutorError> {
+        if quote.intentHash != intent_hash
+            || quote.routeHash != signing::route_hash(steps)
+            || (quote.expiry != U256::ZERO && ctx.now > quote.expiry)
+            || quote.quotedAmountOut < intent.minAmountOut
+        {
+            return Err(RouteExecutorError::InvalidRouteQuote(InvalidRouteQuote {}));
+        }
+
+        let signer = self.recover_signer(signing::route_quote_digest(&quote, ctx.chain_id, ctx.this), signature)?;
+        if !self.route_signers.get(signer) {
+            return Err(RouteExecutorError::UnknownRouteSigner(UnknownRouteSigner { signer }));
+        }
+
+        self.vm().log(RouteQuoteVerified {
+            intentHash: intent_hash,
+            signer,
+            quotedAmountOut: quote.quotedAmountOut,
+        });
+
+        Ok(QuoteCommitment {
+            signer,
+            quoted_amount_out: quote.quotedAmountOut,
+        })
+    }
+
+    /// Internal: Store a quoted intent's realized output and update oracle and solver stats
+    fn record_quote_outcome(&mut self, intent_id: U256, solver_id: U256, quote: QuoteCommitment, realized: U256) {
+        let signer = quote.signer;
+        let quoted = quote.quoted_amount_out;
+        let shortfall = quoted.saturating_sub(realized);
+
+        self.intent_quote_signers.setter(intent_id).set(signer);
+        self.intent_quoted_out.setter(intent_id).set(quoted);
+        self.intent_realized_out.setter(intent_id).set(realized);
+
+        let count = self.signer_quote_count.get(signer);
+        self.signer_quote_count.setter(signer).set(count + U256::from(1));
+        let total = self.signer_quoted_total.get(signer);
+        self.signer_quoted_total.setter(signer).set(total.saturating_add(quoted));
+        let total = self.signer_realized_total.get(signer);
+        self.signer_realized_total.setter(signer).set(total.saturating_add(realized));
+        let total = self.signer_shortfall_total.get(signer);
+        self.signer_shortfall_total.setter(signer).set(total.saturating_add(shortfall));
+
+        if solver_id != U256::ZERO {
+            let count = self.solver_quote_count.get(solver_id);
+            self.solver_quote_count.setter(solver_id).set(count + U256::from(1));
+            let total = self.solver_quoted_total.get(solver_id);
+            self.solver_quoted_total.setter(solver_id).set(total.saturating_add(quoted));
+            let total = self.solver_realized_total.get(solver_id);
+            self.solver_realized_total.setter(solver_id).set(total.saturating_add(realized));
+            let total = self.solver_shortfall_total.get(solver_id);
+            self.solver_shortfall_total.setter(solver_id).set(total.saturating_add(shortfall));
+        }
+
+        self.vm().log(QuoteOutcome {
+            intentId: intent_id,
+            signer,
+            solverId: solver_id,
+            quotedAmountOut: quoted,
+            realizedAmountOut: realized,
+        });
+    }
+
+    /// Internal: Hold a solver to the intent's `maxSolverFee` and record what it took
+    ///
+    /// The route oracle's quote prices what was pulled in output token units,
+    /// so the solver's fee is how far the realized output fell short of it.
+    fn check_solver_fee(
+        &mut self,
+        intent_id: U256,
+        solver_id: U256,
+        intent: &Intent,
+        quote: &QuoteCommitment,
+        realized: U256,
+    ) -> Result<(), RouteExecutorError> {
+        let solver_fee = quote.quoted_amount_out.saturating_sub(realized);
+        let max_solver_fee = intent.maxSolverFee;
+        if max_solver_fee != U256::ZERO && solver_fee > max_solver_fee {
+            return Err(RouteExecutorError::SolverFeeTooHigh(SolverFeeTooHigh {
+                solverFee: solver_fee,
+                maxSolverFee: max_solver_fee,
+            }));
+        }
+
+        self.intent_solver_fees.setter(intent_id).set(solver_fee);
+
+        self.vm().log(SolverFeeRealized {
+            intentId: intent_id,
+            solverId: solver_id,
+            solverFee: solver_fee,
+            maxSolverFee: max_solver_fee,
+        });
+
+        Ok(())
+    }
+
+    /// Internal: Active registered solver the caller acts for, with its solver ID
+    ///
+    /// The caller is the solver itself or one of its operator keys holding
+    /// `scope`; either way the solver is who the execution is attributed to.
+    fn active_solver(&self, scope: u8) -> Result<(Address, U256), RouteExecutorError> {
+        let (solver_id, solver) = ISolverRegistry::new(self.solver_registry.get())
+            .resolve_solver(self.vm(), Call::new(), self.vm().msg_sender(), scope)
+            .unwrap_or_default();
+        if solver_id == U256::ZERO {
+            return Err(RouteExecutorError::NotSolver(NotSolver {}));
+        }
+
+        Ok((solver, solver_id))
+    }
+
+    /// Internal: Check SettlementVerifier leaves a solver room for one more
+    /// intent with `notional` of `token`
+    ///
+    /// Registration with the verifier enforces the same caps; checking first
+    /// fails before any funds move and names the solver. Passes when no
+    /// verifier is set or it can't answer.
+    fn check_solver_capacity(&self, solver_id: U256, token: Address, notional: U256) -> Result<(), RouteExecutorError> {
+        let verifier = self.settlement_verifier.get();
+        if verifier == Address::ZERO {
+            return Ok(());
+        }
+        let has_capacity = ISettlementVerifier::new(verifier)
+            .has_solver_capacity(self.vm(), Call::new(), solver_id, token, notional)
+            .unwrap_or(true);
+        if !has_capacity {
+            return Err(RouteExecutorError::SolverAtCapacity(SolverAtCapacity { solverId: solver_id }));
+        }
+        Ok(())
+    }
+
+    /// Internal: Check a user's signature on an intent for this chain and mark it used
+    ///
+    /// An intent under another solver's live claim or rotation is refused;
+    /// the claimant's own claim is fulfilled and cleared.
+    fn consume_signed_intent(
+        &mut self,
+        ctx: &ExecutionContext,
+        intent: &Intent,
+        signature: &[u8],
+        solver_id: U256,
+    ) -> Result<FixedBytes<32>, RouteExecutorError> {
+        let intent_hash = signing::intent_digest(intent, ctx.chain_id, ctx.this);
+        if self.consumed_intents.get(intent_hash) {
+            return Err(RouteExecutorError::IntentAlreadyUsed(IntentAlreadyUsed { intentHash: intent_hash }));
+        }
+
+        if self.cancelled_intents.getter(intent.user).get(intent_hash) {
+            return Err(RouteExecutorError::CancelledIntent(CancelledIntent { intentHash: intent_hash }));
+        }
+
+        // The user escrowed the intent themselves, which authorizes it
+        if self.escrow_amounts.get(intent_hash) == U256::ZERO {
+            let signer = self.recover_signer(intent_hash, signature)?;
+            if signer != intent.user {
+                return Err(RouteExecutorError::InvalidSignature(InvalidSignature {}));
+            }
+        }
+        self.check_claim(ctx, intent_hash, solver_id)?;
+        self.check_rotation(ctx, intent_hash, solver_id)?;
+        self.rotation_solvers.setter(intent_hash).set(U256::ZERO);
+        self.rotation_expiries.setter(intent_hash).set(U256::ZERO);
+        self.consumed_intents.setter(intent_hash).set(true);
+        self.dequeue_intent(intent_hash);
+
+        Ok(intent_hash)
+    }
+
+    /// Internal: Let only the claimant execute an intent within its claim
+    ///
+    /// A lapsed claim is left in place so it can still be penalized.
+    fn check_claim(
+        &mut self,
+        ctx: &ExecutionContext,
+        intent_hash: FixedBytes<32>,
+        solver_id: U256,
+    ) -> Result<(), RouteExecutorError> {
+        let claimant = self.claim_solvers.get(intent_hash);
+        let expires_at = self.claim_expiries.get(intent_hash);
+        if claimant == U256::ZERO || ctx.now > expires_at {
+            return Ok(());
+        }
+        if claimant != solver_id {
+            return Err(RouteExecutorError::ClaimActive(ClaimActive {
+                intentHash: intent_hash,
+                solverId: claimant,
+                expiresAt: expires_at,
+            }));
+        }
+
+        self.claim_solvers.setter(intent_hash).set(U256::ZERO);
+        self.claim_expiries.setter(intent_hash).set(U256::ZERO);
+        Ok(())
+    }
+
+    /// Internal: Reserve a newly queued intent for one active solver
+    ///
+    /// Leaves the intent open to all when rotation is off or the registry
+    /// has no eligible solver at the drawn position.
+    fn assign_rotation(&mut self, ctx: &ExecutionContext, intent_hash: FixedBytes<32>, deadline: U256) {
+        let window = self.rotation_window.get();
+        let registry = self.solver_registry.get();
+        if window == U256::ZERO || registry == Address::ZERO {
+            return;
+        }
+
+        let eligible = ISolverRegistry::new(registry)
+            .active_solver_count(self.vm(), Call::new())
+            .unwrap_or_default();
+        let block_number = self.vm().block_number();
+        let Some(index) = solver_registry::rotation_index(intent_hash, block_number, ctx.now.saturating_to(), eligible)
+        else {
+            return;
+        };
+        let solver_id = ISolverRegistry::new(registry)
+            .active_solver_at(self.vm(), Call::new(), index)
+            .unwrap_or_default();
+        if solver_id == U256::ZERO {
+            return;
+        }
+
+        let expires_at = (ctx.now + window).min(deadline);
+        self.rotation_solvers.setter(intent_hash).set(solver_id);
+        self.rotation_expiries.setter(intent_hash).set(expires_at);
+
+        self.vm().log(IntentRotated {
+            intentHash: intent_hash,
+            solverId: solver_id,
+            expiresAt: expires_at,
+        });
+    }
+
+    /// Internal: Let only the rotation solver take an intent within its turn
+    fn check_rotation(
+        &self,
+        ctx: &ExecutionContext,
+        intent_hash: FixedBytes<32>,
+        solver_id: U256,
+    ) -> Result<(), RouteExecutorError> {
+        let rotation_solver = self.rotation_solvers.get(intent_hash);
+        let expires_at = self.rotation_expiries.get(intent_hash);
+        if rotation_solver == U256::ZERO || ctx.now > expires_at || rotation_solver == solver_id {
+            return Ok(());
+        }
+        Err(RouteExecutorError::ClaimActive(ClaimActive {
+            intentHash: intent_hash,
+            solverId: rotation_solver,
+            expiresAt: expires_at,
+        }))
+    }
+
+    /// Internal: Clear a lapsed claim and take its penalty for the user
+    ///
+    /// The claim is cleared before SolverRegistry pays the user, so a
+    /// reentrant call finds nothing left to penalize.
+    fn penalize_lapsed_claim(&mut self, intent_hash: FixedBytes<32>) -> Result<U256, RouteExecutorError> {
+        let solver_id = self.claim_solvers.get(intent_hash);
+        self.claim_solvers.setter(intent_hash).set(U256::ZERO);
+        self.claim_expiries.setter(intent_hash).set(U256::ZERO);
+
+        let user = self.escrow_users.get(intent_hash);
+        let penalty = self.claim_penalty.get();
+        let mut paid = U256::ZERO;
+        if penalty != U256::ZERO && !self.cancelled_intents.getter(user).get(intent_hash) {
+            let registry = self.solver_registry.get();
+            let config = Call::new_mutating(self);
+            paid = ISolverRegistry::new(registry)
+                .penalize(self.vm(), config, solver_id, penalty, signing::global_intent_id(intent_hash), user)
+                .map_err(|_| RouteExecutorError::ValidationFailed(ValidationFailed {}))?;
+        }
+
+        self.vm().log(ClaimPenalized {
+            intentHash: intent_hash,
+            solverId: solver_id,
+            user,
+            penalty: paid,
+        });
+
+        Ok(paid)
+    }
+
+    /// Internal: Credit an intent's priority fee to the solver that executed it
+    ///
+    /// Escrowed fees are already held here; otherwise the fee is pulled from
+    /// the user on top of the input.
+    fn pay_priority_fee(
+        &mut self,
+        intent_id: U256,
+        solver: Address,
+        user: Address,
+        token: Address,
+        amount: U256,
+        escrowed: bool,
+    ) -> Result<(), RouteExecutorError> {
+        self.check_not_locked()?;
+        self.locked.set(true);
+
+        if !escrowed {
+            self.pull_token(token, user, amount)?;
+        }
+
+        let earned = self.solver_priority_fees.getter(solver).get(token);
+        self.solver_priority_fees.setter(solver).setter(token).set(earned + amount);
+        self.record_fees(token, LedgerEntry::Rewarded, amount);
+
+        self.vm().log(PriorityFeePaid {
+            intentId: intent_id,
+            solver,
+            token,
+            amount,
+        });
+
+        self.locked.set(false);
+
+        Ok(())
+    }
+
+    /// Internal: List an escrowed intent in the pending queue
+    ///
+    /// Inserted after every intent bidding at least as much, so equal bids
+    /// keep arrival order. A full queue drops its tail for a higher bid;
+    /// returns false when the intent doesn't make the queue.
+    fn enqueue_intent(&mut self, intent_hash: FixedBytes<32>, priority_fee: U256) -> bool {
+        let mut previous = FixedBytes::ZERO;
+        let mut current = self.queue_next.get(previous);
+        while current != FixedBytes::ZERO && self.escrow_priority_fees.get(current) >= priority_fee {
+            previous = current;
+            current = self.queue_next.get(current);
+        }
+
+        if self.queue_length.get() >= U256::from(MAX_QUEUED_INTENTS) {
+            if current == FixedBytes::ZERO {
+                return false;
+            }
+            let tail = self.queue_prev.get(FixedBytes::ZERO);
+            self.dequeue_intent(tail);
+            current = self.queue_next.get(previous);
+        }
+
+        self.queue_next.setter(previous).set(intent_hash);
+        self.queue_prev.setter(intent_hash).set(previous);
+        self.queue_next.setter(intent_hash).set(current);
+        self.queue_prev.setter(current).set(intent_hash);
+        self.queued_intents.setter(intent_hash).set(true);
+        self.queue_length.set(self.queue_length.get() + U256::from(1));
+        true
+    }
+
+    /// Internal: Remove an intent from the pending queue, if listed
+    fn dequeue_intent(&mut self, intent_hash: FixedBytes<32>) {
+        if !self.queued_intents.get(intent_hash) {
+            return;
+        }
+
+        let previous = self.queue_prev.get(intent_hash);
+        let next = self.queue_next.get(intent_hash);
+        self.queue_next.setter(previous).set(next);
+        self.queue_prev.setter(next).set(previous);
+        self.queue_next.setter(intent_hash).set(FixedBytes::ZERO);
+        self.queue_prev.setter(intent_hash).set(FixedBytes::ZERO);
+        self.queued_intents.setter(intent_hash).set(false);
+        self.queue_length.set(self.queue_length.get() - U256::from(1));
+
+        self.vm().log(IntentDequeued { intentHash: intent_hash });
+    }
+
+    /// Internal: Add to an integrator's volume, announcing any tier it reaches
+    fn record_integrator_volume(&mut self, integrator: Address, token: Address, amount: U256) {
+        let volume = self.integrator_volumes.getter(integrator).get(token);
+        let new_volume = volume.saturating_add(amount);
+        self.integrator_volumes.setter(integrator).setter(token).set(new_volume);
+
+        let (thresholds, _) = self.get_volume_tiers(token);
+        let tier = fees::volume_tier(new_volume, &thresholds);
+        if tier > fees::volume_tier(volume, &thresholds) {
+            self.vm().log(IntegratorTierReached {
+                integrator,
+                token,
+                tier: U256::from(tier),
+                volume: new_volume,
+            });
+        }
+    }
+
+    /// Internal: Mark part of a token balance as owed to a user or integrator
+    fn reserve(&mut self, token: Address, amount: U256) {
+        let reserved = self.reserved_balances.get(token);
+        self.reserved_balances.setter(token).set(reserved + amount);
+        invariant!(self.is_solvent(token), "escrow solvency");
+    }
+
+    /// Internal: Release a reserved balance once paid out or spent
+    ///
+    /// Saturates, since balances owed before reservations were tracked were
+    /// never added.
+    fn release_reserved(&mut self, token: Address, amount: U256) {
+        let reserved = self.reserved_balances.get(token).saturating_sub(amount);
+        self.reserved_balances.setter(token).set(reserved);
+    }
+
+    /// Internal: Whether the contract holds every unit of `token` it owes
+    /// in reservations and collected fees (read by `invariant!` only)
+    fn is_solvent(&self, token: Address) -> bool {
+        let this = self.vm().contract_address();
+        let balance = if token == NATIVE_TOKEN {
+            self.vm().balance(this)
+        } else {
+            IERC20::new(token).balance_of(self.vm(), Call::new(), this).unwrap_or_default()
+        };
+        let owed = self.reserved_balances.get(token) + self.collected_fees.get(token);
+        invariants::is_solvent(balance, owed)
+    }
+
+    /// Internal: A token's dust if it has reached its threshold, else zero
+    fn sweepable_dust(&self, token: Address) -> Result<U256, RouteExecutorError> {
+        let threshold = self.dust_thresholds.get(token);
+        if threshold == U256::ZERO {
+            return Ok(U256::ZERO);
+        }
+
+        let dust = self.get_dust(token)?;
+        Ok(if dust >= threshold { dust } else { U256::ZERO })
+    }
+
+    /// Internal: Add a protocol fee to the withdrawable balance
+    fn collect_protocol_fee(&mut self, intent_id: U256, token: Address, amount: U256) {
+        if amount == U256::ZERO {
+            return;
+        }
+
+        let collected = self.collected_fees.get(token);
+        self.collected_fees.setter(token).set(collected + amount);
+        self.record_fees(token, LedgerEntry::Collected, amount);
+        invariant!(self.is_solvent(token), "fee solvency");
+
+        self.vm().log(FeeCollected {
+            intentId: intent_id,
+            token,
+            amount,
+        });
+    }
+
+    /// Internal: Add an amount to a token's fee ledger, lifetime and this epoch
+    fn record_fees(&mut self, token: Address, entry: LedgerEntry, amount: U256) {
+        if amount == U256::ZERO {
+            return;
+        }
+
+        let kind = U256::from(entry as u8);
+        let total = self.fee_totals.getter(token).get(kind);
+        self.fee_totals.setter(token).setter(kind).set(total + amount);
+
+        let key = fees::ledger_key(fees::fee_epoch(U256::from(self.vm().block_timestamp())), entry);
+        let epoch_total = self.fee_epoch_totals.getter(token).get(key);
+        self.fee_epoch_totals.setter(token).setter(key).set(epoch_total + amount);
+    }
+
+    /// Internal: Recover the signer of a digest through the ecrecover precompile
+    fn recover_signer(&self, digest: FixedBytes<32>, signature: &[u8]) -> Result<Address, RouteExecutorError> {
+        let input = signing::ecrecover_input(digest, signature)
+            .ok_or(RouteExecutorError::InvalidSignature(InvalidSignature {}))?;
+        let output = static_call(self.vm(), Call::new(), signing::ECRECOVER, &input)
+            .map_err(|_| RouteExecutorError::InvalidSignature(InvalidSignature {}))?;
+        signing::recovered_address(&output).ok_or(RouteExecutorError::InvalidSignature(InvalidSignature {}))
+    }
+
+    /// Internal: Send ETH or an ERC20 held by this contract
+    fn internal_send(&mut self, token: Address, to: Address, amount: U256) -> Result<(), RouteExecutorError> {
+        if token == NATIVE_TOKEN {
+            return self
+                .vm()
+                .transfer_eth(to, amount)
+                .map_err(|_| RouteExecutorError::TransferFailed(TransferFailed {}));
+        }
+
+        if !self.guarded_token_call(token, &transferCall { to, amount }.abi_encode()) {
+            return Err(RouteExecutorError::TransferFailed(TransferFailed {}));
+        }
+        Ok(())
+    }
+
+    /// Internal: Pull an ERC20 from `from` into this contract
+    fn pull_token(&mut self, token: Address, from: Address, amount: U256) -> Result<(), RouteExecutorError> {
+        let this = self.vm().contract_address();
+        if !self.guarded_token_call(token, &transferFromCall { from, to: this, amount }.abi_encode()) {
+            return Err(RouteExecutorError::TransferFailed(TransferFailed {}));
+        }
+        Ok(())
+    }
+
+    /// Internal: Make a guarded token call and check that it succeeded
+    ///
+    /// Tokens that return nothing (USDT, ERC-721, ERC-1155) succeed by not
+    /// reverting, as long as the token is a contract at all.
+    fn guarded_token_call(&mut self, token: Address, calldata: &[u8]) -> bool {
+        match self.guarded_call(token, U256::ZERO, TOKEN_CALL_GAS, calldata) {
+            Some(output) if output.is_empty() => self.vm().code_size(token) != 0,
+            Some(output) => bool::abi_decode(&output).unwrap_or(false),
+            None => false,
+        }
+    }
+
+    /// Internal: Reset an adapter's allowance after its call if its policy
+    /// revokes, returning whether the token accepted it
+    fn release_approval(&mut self, token: Address, adapter: Address) -> bool {
+        let policy = ApprovalPolicy::from_u8(self.get_approval_policy(adapter)).unwrap_or_default();
+        if !policy.revokes() {
+            return true;
+        }
+        self.guarded_token_call(token, &approveCall { spender: adapter, amount: U256::ZERO }.abi_encode())
+    }
+
+    /// Internal: Call a token or adapter with capped gas and return data
+    ///
+    /// Forwards at most `gas` and holds the reentrancy lock for the call, so
+    /// a callback into any guarded entry point reverts; storage is flushed
+    /// first so the callee sees the lock. Return data past `MAX_RETURN_DATA`
+    /// is never copied and fails the call. Returns the output on success.
+    fn guarded_call(&mut self, target: Address, value: U256, gas: u64, calldata: &[u8]) -> Option<Vec<u8>> {
+        let was_locked = self.locked.get();
+        self.locked.set(true);
+
+        // One byte past the cap tells an oversized return apart from a full one
+        // SAFETY: the lock is held and flushed, so reentrant calls are rejected
+        let result = unsafe {
+            RawCall::new_with_value(self.vm(), value)
+                .gas(gas)
+                .limit_return_data(0, MAX_RETURN_DATA + 1)
+                .flush_storage_cache()
+                .call(target, calldata)
+        };
+
+        self.locked.set(was_locked);
+        result.ok().filter(|output| output.len() <= MAX_RETURN_DATA)
+    }
+
+    /// Internal: Check a fee against the protocol cap
+    fn check_fee_cap(fee_bps: U256) -> Result<(), RouteExecutorError> {
+        if !fees::is_valid_protocol_fee(fee_bps) {
+            return Err(RouteExecutorError::FeeTooHigh(FeeTooHigh {
+                feeBps: fee_bps,
+                maxFeeBps: U256::from(fees::MAX_PROTOCOL_FEE_BPS),
+            }));
+        }
+        Ok(())
+    }
+
+    /// Internal: Check if caller is owner
+    fn only_owner(&self) -> Result<(), RouteExecutorError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(RouteExecutorError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+
+    /// Internal: Reject an adapter unless ERC-165 detection finds `interface_id`
+    ///
+    /// Calls that revert or return garbage count as unsupported, so EOAs and
+    /// contracts without `supportsInterface` are rejected too.
+    fn require_interface(&self, target: Address, interface_id: [u8; 4]) -> Result<(), RouteExecutorError> {
+        let adapter = IERC165::new(target);
+        let supported = erc165::detect(interface_id, |id| {
+            adapter.supports_interface(self.vm(), Call::new(), FixedBytes(id)).unwrap_or(false)
+        });
+        if !supported {
+            return Err(RouteExecutorError::InterfaceNotSupported(InterfaceNotSupported {
+                target,
+                interfaceId: FixedBytes(interface_id),
+            }));
+        }
+        Ok(())
+    }
+
+    /// Internal: Check that an intent bound to a block still sees that block
+    ///
+    /// The block must be older than the current one and within
+    /// `get_max_block_age`, and ArbSys must report the hash the user signed;
+    /// a reorg that dropped the block changes its hash. Unbound intents pass.
+    fn check_block_binding(&self, intent: &Intent) -> Result<(), RouteExecutorError> {
+        if intent.blockNumber == U256::ZERO {
+            return Ok(());
+        }
+
+        let arb_sys = IArbSys::new(ARB_SYS);
+        let current = arb_sys
+            .arb_block_number(self.vm(), Call::new())
+            .map_err(|_| RouteExecutorError::ValidationFailed(ValidationFailed {}))?;
+        let oldest = current.saturating_sub(self.get_max_block_age());
+        if intent.blockNumber >= current || intent.blockNumber < oldest {
+            return Err(RouteExecutorError::StaleBlock(StaleBlock {
+                blockNumber: intent.blockNumber,
+                oldestBlock: oldest,
+            }));
+        }
+
+        let hash = arb_sys
+            .arb_block_hash(self.vm(), Call::new(), intent.blockNumber)
+            .map_err(|_| RouteExecutorError::ValidationFailed(ValidationFailed {}))?;
+        if hash != intent.blockHash {
+            return Err(RouteExecutorError::BlockHashMismatch(BlockHashMismatch {
+                blockNumber: intent.blockNumber,
+                blockHash: intent.blockHash,
+            }));
+        }
+        Ok(())
+    }
+
+    /// Internal: The fill rule for a token, owner-set or derived from its decimals
+    fn fill_rule(&self, token: Address) -> FillRule {
+        let granularity = self.fill_granularities.get(token);
+        if granularity != U256::ZERO {
+            return FillRule {
+                min_fill: self.fill_min_amounts.get(token),
+                granularity,
+            };
+        }
+        match IERC20::new(token).decimals(self.vm(), Call::new()) {
+            Ok(decimals) => FillRule::from_decimals(decimals),
+            Err(_) => FillRule::ANY,
+        }
+    }
+
+    /// Internal: A lane's TVL cap for a token
+    fn lane_cap(&self, chain_id: U256, token: Address) -> LaneCap {
+        LaneCap::decode(
+            self.lane_tvl_caps.getter(chain_id).get(token),
+            self.lane_initial_caps.getter(chain_id).get(token),
+            self.lane_cap_ramps.getter(chain_id).get(token),
+        )
+    }
+
+    /// Internal: Count a bridge against its lane's TVL cap, if the lane has one
+    fn take_lane_capacity(
+        &mut self,
+        intent_id: U256,
+        token: Address,
+        amount: U256,
+        chain_id: U256,
+    ) -> Result<(), RouteExecutorError> {
+        let lane_cap = self.lane_cap(chain_id, token);
+        if !lane_cap.is_capped() {
+            return Ok(());
+        }
+
+        let tvl = self.lane_tvl.getter(chain_id).get(token);
+        let available = lane_cap.available(tvl, self.vm().block_timestamp());
+        if amount > available {
+            return Err(RouteExecutorError::LaneCapExceeded(LaneCapExceeded {
+                chainId: chain_id,
+                token,
+                amount,
+                available,
+            }));
+        }
+
+        self.lane_tvl.setter(chain_id).setter(token).set(tvl + amount);
+        self.intent_lane_tokens.setter(intent_id).set(token);
+        let counted = self.intent_lane_values.get(intent_id);
+        self.intent_lane_values.setter(intent_id).set(counted + amount);
+
+        Ok(())
+    }
+
+    /// Internal: Check a partial fill against the token's fill rule
+    fn check_fill_amount(&self, token: Address, amount: U256) -> Result<(), RouteExecutorError> {
+        let rule = self.fill_rule(token);
+        if !rule.allows(amount) {
+            return Err(RouteExecutorError::InvalidFillAmount(InvalidFillAmount {
+                token,
+                amount,
+                minFill: rule.min_fill,
+                granularity: rule.granularity,
+            }));
+        }
+        Ok(())
+    }
+
+    /// Internal: Reject new intents toward a lane the validator has halted
+    ///
+    /// A validator that can't answer is treated as healthy so an older
+    /// validator deployment doesn't stop every lane.
+    fn check_lane_healthy(&self, ctx: &ExecutionContext, destination_chain: U256) -> Result<(), RouteExecutorError> {
+        let halted = IIntentValidator::new(ctx.validator)
+            .is_lane_halted(self.vm(), Call::new(), destination_chain)
+            .unwrap_or(false);
+        if halted {
+            return Err(RouteExecutorError::LaneHalted(LaneHalted { chainId: destination_chain }));
+        }
+        Ok(())
+    }
+
+    /// Internal: Check the validator admits an ERC20 intent under its chain
+    /// list, token listing mode and token caps
+    ///
+    /// Fails closed: a validator that refuses or can't answer rejects the
+    /// intent. ETH isn't a listed token, so native intents skip it.
+    fn check_intent_admitted(&self, ctx: &ExecutionContext, intent: &Intent) -> Result<(), RouteExecutorError> {
+        if intent.tokenIn == NATIVE_TOKEN {
+            return Ok(());
+        }
+        let admitted = IIntentValidator::new(ctx.validator)
+            .check_execution(
+                self.vm(),
+                Call::new(),
+                intent.user,
+                intent.tokenIn,
+                intent.amountIn,
+                intent.destinationChain,
+                ctx.this,
+            )
+            .unwrap_or(false);
+        if !admitted {
+            return Err(RouteExecutorError::ValidationFailed(ValidationFailed {}));
+        }
+        Ok(())
+    }
+
+    /// Internal: Check the validator has not blocked an intent hash, and no
+    /// watchtower report is holding it
+    fn check_intent_not_blocked(
+        &self,
+        ctx: &ExecutionContext,
+        intent_hash: FixedBytes<32>,
+    ) -> Result<(), RouteExecutorError> {
+        let (blocked, held_until) = IIntentValidator::new(ctx.validator)
+            .get_intent_hold(self.vm(), Call::new(), intent_hash)
+            .unwrap_or((false, U256::ZERO));
+        if blocked {
+            return Err(RouteExecutorError::IntentBlocked(IntentBlocked { intentHash: intent_hash }));
+        }
+        if ctx.now < held_until {
+            return Err(RouteExecutorError::IntentHeld(IntentHeld {
+                intentHash: intent_hash,
+                heldUntil: held_until,
+            }));
+        }
+        Ok(())
+    }
+
+    /// Internal: Check the caller may submit an intent for its user
+    ///
+    /// The user always may. Anyone else must be the user's delegate in the
+    /// validator, which draws `amount` from the delegation's allowance in
+    /// the intent's token. Delegates only submit ERC20 intents, funded by
+    /// the user.
+    fn check_submitter(
+        &mut self,
+        ctx: &ExecutionContext,
+        intent_hash: FixedBytes<32>,
+        intent: &Intent,
+        amount: U256,
+    ) -> Result<(), RouteExecutorError> {
+        if ctx.sender == intent.user {
+            return Ok(());
+        }
+        if intent.tokenIn == NATIVE_TOKEN || intent.assetType != AssetType::Erc20 as u8 {
+            return Err(RouteExecutorError::Unauthorized(Unauthorized {}));
+        }
+
+        let config = Call::new_mutating(self);
+        IIntentValidator::new(ctx.validator)
+            .spend_delegation(self.vm(), config, intent.user, ctx.sender, intent.tokenIn, amount)
+            .map_err(|_| RouteExecutorError::Unauthorized(Unauthorized {}))?;
+
+        self.vm().log(IntentSubmittedByDelegate {
+            intentHash: intent_hash,
+            user: intent.user,
+            delegate: ctx.sender,
+        });
+
+        Ok(())
+    }
+
+    /// Internal: Check an intent pinned to a config epoch may execute under the current one
+    ///
+    /// Unpinned intents pass; so do all intents while no validator is set.
+    fn check_config_epoch(&self, ctx: &ExecutionContext, epoch: U256) -> Result<(), RouteExecutorError> {
+        if epoch == U256::ZERO || ctx.validator == Address::ZERO {
+            return Ok(());
+        }
+
+        let (current, compatible) = IIntentValidator::new(ctx.validator)
+            .get_config_epochs(self.vm(), Call::new())
+            .map_err(|_| RouteExecutorError::ValidationFailed(ValidationFailed {}))?;
+        if epoch < compatible || epoch > current {
+            return Err(RouteExecutorError::ConfigEpochMismatch(ConfigEpochMismatch {
+                epoch,
+                compatibleEpoch: compatible,
+                currentEpoch: current,
+            }));
+        }
+        Ok(())
+    }
+
+    /// Internal: Start a new config epoch after a fee or limit change,
+    /// retiring the ones intents were signed under
+    ///
+    /// The validator must list this contract as an epoch source; until a
+    /// validator is set there is nothing to advance.
+    fn advance_config_epoch(&mut self) -> Result<(), RouteExecutorError> {
+        let validator = self.validator.get();
+        if validator == Address::ZERO {
+            return Ok(());
+        }
+
+        let config = Call::new_mutating(self);
+        IIntentValidator::new(validator)
+            .advance_config_epoch(self.vm(), config, true)
+            .map_err(|_| RouteExecutorError::ValidationFailed(ValidationFailed {}))?;
+        Ok(())
+    }
+
+    /// Internal: Load the call context an execution needs, rejecting it while paused
+    fn execution_context(&self) -> Result<ExecutionContext, RouteExecutorError> {
+        if self.paused.get() {
+            return Err(RouteExecutorError::ContractPaused(ContractPaused {}));
+        }
+        Ok(ExecutionContext {
+            this: self.vm().contract_address(),
+            chain_id: self.vm().chain_id(),
+            sender: self.vm().msg_sender(),
+            now: U256::from(self.vm().block_timestamp()),
+            validator: self.validator.get(),
+            weth: self.weth.get(),
+        })
+    }
+
+    /// Internal: Check reentrancy lock
+    fn check_not_locked(&self) -> Result<(), RouteExecutorError> {
+        if self.locked.get().into() {
+            return Err(RouteExecutorError::ReentrancyGuard(ReentrancyGuard {}));
+        }
+        Ok(())
+    }
+}
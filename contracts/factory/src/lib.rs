@@ -0,0 +1,97 @@
+//! Create2Factory Contract
+//!
+//! CREATE2 deployer used to give receiver and verifier contracts the same
+//! address on every chain. The factory itself is deployed from a dedicated key
+//! at the same nonce on each chain; contracts deployed through it then land at
+//! an address fixed by the deployer, the salt and the init code alone.
+//!
+//! The salt is bound to the caller (`keccak256(abi.encode(deployer, salt))`),
+//! so nobody else can occupy a deployer's address on a chain it hasn't reached
+//! yet and initialize the contract there first. Stylus programs still need
+//! activating and initializing after deployment.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use alloy_sol_types::{sol, SolValue};
+use stylus_sdk::{
+    alloy_primitives::{keccak256, Address, Bytes, FixedBytes},
+    deploy::RawDeploy,
+    prelude::*,
+    storage::{StorageAddress, StorageMap},
+};
+
+use swoosh_common::erc165;
+
+// Events and errors
+sol! {
+    event ContractDeployed(address indexed deployed, address indexed deployer, bytes32 salt, bytes32 initCodeHash);
+
+    error DeploymentFailed(bytes32 salt);
+}
+
+/// Error types for Create2Factory
+#[derive(SolidityError)]
+pub enum Create2FactoryError {
+    DeploymentFailed(DeploymentFailed),
+}
+
+#[entrypoint]
+#[storage]
+pub struct Create2Factory {
+    /// Mapping of deployed addresses to the account that deployed them
+    deployers: StorageMap<Address, StorageAddress>,
+}
+
+#[public]
+impl Create2Factory {
+    /// Deploy `init_code` with CREATE2 under the caller's `salt`, forwarding
+    /// any value as the endowment
+    #[payable]
+    pub fn deploy(&mut self, salt: FixedBytes<32>, init_code: Bytes) -> Result<Address, Create2FactoryError> {
+        let deployer = self.vm().msg_sender();
+        let init_code_hash = keccak256(&init_code);
+
+        // SAFETY: the factory keeps no state a reentrant call could observe
+        // half-written; the deployer is recorded after the create returns
+        let deployed = unsafe {
+            RawDeploy::new()
+                .salt(deployment_salt(deployer, salt))
+                .deploy(self.vm(), &init_code, self.vm().msg_value())
+        };
+
+        let deployed = match deployed {
+            Ok(address) if !address.is_zero() => address,
+            _ => return Err(Create2FactoryError::DeploymentFailed(DeploymentFailed { salt })),
+        };
+
+        self.deployers.setter(deployed).set(deployer);
+
+        self.vm().log(ContractDeployed { deployed, deployer, salt, initCodeHash: init_code_hash });
+
+        Ok(deployed)
+    }
+
+    /// Address `deploy` would use for `deployer`, `salt` and the hash of the
+    /// init code
+    pub fn compute_address(&self, deployer: Address, salt: FixedBytes<32>, init_code_hash: FixedBytes<32>) -> Address {
+        self.vm().contract_address().create2(deployment_salt(deployer, salt), init_code_hash)
+    }
+
+    /// Account that deployed `deployed` through this factory (zero if none)
+    pub fn deployer_of(&self, deployed: Address) -> Address {
+        self.deployers.get(deployed)
+    }
+
+    /// ERC-165: whether this contract implements `interface_id`
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        erc165::supports(interface_id.0, &[])
+    }
+}
+
+/// CREATE2 salt actually used for a deployer's salt
+fn deployment_salt(deployer: Address, salt: FixedBytes<32>) -> FixedBytes<32> {
+    keccak256((deployer, salt).abi_encode())
+}
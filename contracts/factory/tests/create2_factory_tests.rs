@@ -0,0 +1,66 @@
+use stylus_sdk::alloy_primitives::{Address, FixedBytes};
+use swoosh_common::erc165;
+use swoosh_factory::*;
+use swoosh_test_utils::TestEnv;
+
+#[cfg(test)]
+mod create2_factory_tests {
+    use super::*;
+
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    #[test]
+    fn test_compute_address_is_deterministic() {
+        // Same deployer, salt and init code always map to the same address
+        let env = TestEnv::new();
+        let factory: Create2Factory = env.deploy();
+        let salt = FixedBytes::from([1u8; 32]);
+        let code_hash = FixedBytes::from([2u8; 32]);
+
+        let first = factory.compute_address(test_address(1), salt, code_hash);
+        assert_eq!(first, factory.compute_address(test_address(1), salt, code_hash), "Deterministic");
+        assert_ne!(first, Address::ZERO, "Non-zero address");
+    }
+
+    #[test]
+    fn test_compute_address_binds_deployer() {
+        // Another account reusing the salt cannot reach the same address
+        let env = TestEnv::new();
+        let factory: Create2Factory = env.deploy();
+        let salt = FixedBytes::from([1u8; 32]);
+        let code_hash = FixedBytes::from([2u8; 32]);
+
+        assert_ne!(
+            factory.compute_address(test_address(1), salt, code_hash),
+            factory.compute_address(test_address(2), salt, code_hash),
+            "Deployer is part of the salt"
+        );
+        assert_ne!(
+            factory.compute_address(test_address(1), salt, code_hash),
+            factory.compute_address(test_address(1), salt, FixedBytes::from([3u8; 32])),
+            "Init code is part of the address"
+        );
+    }
+
+    #[test]
+    fn test_deployer_of_unknown_address() {
+        // Addresses not created by the factory have no deployer
+        let env = TestEnv::new();
+        let factory: Create2Factory = env.deploy();
+
+        assert_eq!(factory.deployer_of(test_address(9)), Address::ZERO, "Unknown deployment");
+    }
+
+    #[test]
+    fn test_supports_only_erc165() {
+        // The factory implements no Swoosh extension point
+        let env = TestEnv::new();
+        let factory: Create2Factory = env.deploy();
+
+        assert!(factory.supports_interface(FixedBytes(erc165::ERC165_ID)), "ERC-165");
+        assert!(!factory.supports_interface(FixedBytes(erc165::INVALID_ID)), "Invalid id");
+        assert!(!factory.supports_interface(FixedBytes(erc165::BRIDGE_ADAPTER_ID)), "Bridge adapter");
+    }
+}
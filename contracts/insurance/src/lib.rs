@@ -0,0 +1,333 @@
+//! InsuranceFund Contract
+//!
+//! Reserves behind insured intents. A user opts an intent into insurance
+//! when creating it; RouteExecutor then adds a premium to its fees, sends it
+//! here and registers the policy with SettlementVerifier. If the intent's
+//! settlement fails without a refund, the verifier approves a claim and the
+//! fund pays the user the value of the insured amount in its payout token.
+//!
+//! Claims in the payout token pay the insured amount itself; any other token
+//! is valued through the value oracle. Premiums arrive in whatever token the
+//! intent spent, and the owner withdraws them to top the payout reserves
+//! back up. A claim the reserves can't cover reverts, and can be retried
+//! once the fund is topped up.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use alloy_sol_types::sol;
+use stylus_sdk::{
+    alloy_primitives::{Address, Bytes, FixedBytes, U256},
+    call::Call,
+    prelude::*,
+    storage::{StorageAddress, StorageMap, StorageU256},
+};
+
+use swoosh_common::erc165;
+use swoosh_common::status::{encode_protocol_status, ProtocolStatus, StatusContract};
+
+// Events and errors
+sol! {
+    event VerifierSet(address verifier);
+    event ValueOracleSet(address oracle);
+    event ReservesDeposited(address indexed from, uint256 amount);
+    event Withdrawn(address indexed token, address indexed to, uint256 amount);
+    event ClaimPaid(uint256 indexed intentId, address indexed user, address token, uint256 coverage, uint256 payout);
+    event OwnershipTransferred(address indexed previousOwner, address indexed newOwner);
+
+    error Unauthorized();
+    error InvalidAddress();
+    error InvalidAmount();
+    error AlreadyPaid(uint256 intentId);
+    error NoValue(address token);
+    error InsufficientReserves(uint256 available, uint256 payout);
+    error TransferFailed();
+}
+
+/// Error types for InsuranceFund
+#[derive(SolidityError)]
+pub enum InsuranceFundError {
+    Unauthorized(Unauthorized),
+    InvalidAddress(InvalidAddress),
+    InvalidAmount(InvalidAmount),
+    AlreadyPaid(AlreadyPaid),
+    NoValue(NoValue),
+    InsufficientReserves(InsufficientReserves),
+    TransferFailed(TransferFailed),
+}
+
+// ERC20 interface
+sol_interface! {
+    interface IERC20 {
+        function balanceOf(address account) external view returns (uint256);
+        function transferFrom(address from, address to, uint256 amount) external returns (bool);
+        function transfer(address to, uint256 amount) external returns (bool);
+    }
+}
+
+// Oracle valuing insured amounts in the payout token
+sol_interface! {
+    interface IValueOracle {
+        function quoteValue(address token, uint256 amount) external view returns (uint256);
+    }
+}
+
+#[entrypoint]
+#[storage]
+pub struct InsuranceFund {
+    /// Contract owner
+    owner: StorageAddress,
+    /// SettlementVerifier that approves claims
+    verifier: StorageAddress,
+    /// Token claims are paid in
+    payout_token: StorageAddress,
+    /// Oracle valuing other tokens in the payout token (zero = payout token only)
+    value_oracle: StorageAddress,
+    /// Mapping of intent IDs to the payout made on their claim (zero = unpaid)
+    claims: StorageMap<U256, StorageU256>,
+    /// Total paid out on claims, in the payout token
+    total_paid: StorageU256,
+}
+
+#[public]
+impl InsuranceFund {
+    /// Initialize the fund with the verifier approving its claims and the
+    /// token it pays them in
+    pub fn init(&mut self, verifier: Address, payout_token: Address) -> Result<(), InsuranceFundError> {
+        if verifier == Address::ZERO || payout_token == Address::ZERO {
+            return Err(InsuranceFundError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.owner.set(self.vm().msg_sender());
+        self.verifier.set(verifier);
+        self.payout_token.set(payout_token);
+        self.total_paid.set(U256::ZERO);
+
+        Ok(())
+    }
+
+    /// Set the SettlementVerifier that approves claims (admin only)
+    pub fn set_verifier(&mut self, verifier: Address) -> Result<(), InsuranceFundError> {
+        self.only_owner()?;
+
+        if verifier == Address::ZERO {
+            return Err(InsuranceFundError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.verifier.set(verifier);
+
+        self.vm().log(VerifierSet { verifier });
+
+        Ok(())
+    }
+
+    /// Set the oracle valuing insured tokens in the payout token (admin only)
+    ///
+    /// Zero leaves only claims in the payout token payable.
+    pub fn set_value_oracle(&mut self, oracle: Address) -> Result<(), InsuranceFundError> {
+        self.only_owner()?;
+
+        self.value_oracle.set(oracle);
+
+        self.vm().log(ValueOracleSet { oracle });
+
+        Ok(())
+    }
+
+    /// Add payout tokens to the reserves
+    pub fn deposit(&mut self, amount: U256) -> Result<(), InsuranceFundError> {
+        if amount == U256::ZERO {
+            return Err(InsuranceFundError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let from = self.vm().msg_sender();
+        let this = self.vm().contract_address();
+        let token = self.payout_token.get();
+        let config = Call::new_mutating(self);
+        let pulled = IERC20::new(token)
+            .transfer_from(self.vm(), config, from, this, amount)
+            .map_err(|_| InsuranceFundError::TransferFailed(TransferFailed {}))?;
+        if !pulled {
+            return Err(InsuranceFundError::TransferFailed(TransferFailed {}));
+        }
+
+        self.vm().log(ReservesDeposited { from, amount });
+
+        Ok(())
+    }
+
+    /// Withdraw collected premiums or reserves (admin only)
+    pub fn withdraw(&mut self, token: Address, to: Address, amount: U256) -> Result<(), InsuranceFundError> {
+        self.only_owner()?;
+
+        if to == Address::ZERO {
+            return Err(InsuranceFundError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.push(token, to, amount)?;
+
+        self.vm().log(Withdrawn { token, to, amount });
+
+        Ok(())
+    }
+
+    /// Pay the claim on an insured intent (SettlementVerifier only)
+    ///
+    /// Pays `user` the value of `coverage` of `token` in the payout token and
+    /// returns the payout. Each intent is paid at most once.
+    pub fn pay_claim(
+        &mut self,
+        intent_id: U256,
+        user: Address,
+        token: Address,
+        coverage: U256,
+    ) -> Result<U256, InsuranceFundError> {
+        if self.vm().msg_sender() != self.verifier.get() {
+            return Err(InsuranceFundError::Unauthorized(Unauthorized {}));
+        }
+        if self.claims.get(intent_id) != U256::ZERO {
+            return Err(InsuranceFundError::AlreadyPaid(AlreadyPaid { intentId: intent_id }));
+        }
+        if user == Address::ZERO {
+            return Err(InsuranceFundError::InvalidAddress(InvalidAddress {}));
+        }
+        if coverage == U256::ZERO {
+            return Err(InsuranceFundError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let payout = self.quote_payout(token, coverage)?;
+        let payout_token = self.payout_token.get();
+        let available = self.reserves();
+        if payout > available {
+            return Err(InsuranceFundError::InsufficientReserves(InsufficientReserves { available, payout }));
+        }
+
+        self.claims.setter(intent_id).set(payout);
+        self.total_paid.set(self.total_paid.get() + payout);
+        self.push(payout_token, user, payout)?;
+
+        self.vm().log(ClaimPaid {
+            intentId: intent_id,
+            user,
+            token,
+            coverage,
+            payout,
+        });
+
+        Ok(payout)
+    }
+
+    /// Value of `amount` of `token` in the payout token
+    ///
+    /// Reverts with `NoValue` when the oracle is unset, reverts or values
+    /// the amount at zero.
+    pub fn quote_payout(&self, token: Address, amount: U256) -> Result<U256, InsuranceFundError> {
+        if token == self.payout_token.get() {
+            return Ok(amount);
+        }
+
+        let oracle = self.value_oracle.get();
+        if oracle == Address::ZERO {
+            return Err(InsuranceFundError::NoValue(NoValue { token }));
+        }
+        let value = IValueOracle::new(oracle)
+            .quote_value(self.vm(), Call::new(), token, amount)
+            .unwrap_or(U256::ZERO);
+        if value == U256::ZERO {
+            return Err(InsuranceFundError::NoValue(NoValue { token }));
+        }
+        Ok(value)
+    }
+
+    /// Get the payout token balance available for claims
+    pub fn reserves(&self) -> U256 {
+        let this = self.vm().contract_address();
+        IERC20::new(self.payout_token.get())
+            .balance_of(self.vm(), Call::new(), this)
+            .unwrap_or(U256::ZERO)
+    }
+
+    /// Get the payout made on an intent's claim (zero if unpaid)
+    pub fn get_claim(&self, intent_id: U256) -> U256 {
+        self.claims.get(intent_id)
+    }
+
+    /// Get the total paid out on claims
+    pub fn total_paid(&self) -> U256 {
+        self.total_paid.get()
+    }
+
+    /// Get the token claims are paid in
+    pub fn payout_token(&self) -> Address {
+        self.payout_token.get()
+    }
+
+    /// Get the oracle valuing insured tokens
+    pub fn value_oracle(&self) -> Address {
+        self.value_oracle.get()
+    }
+
+    /// Get the SettlementVerifier that approves claims
+    pub fn verifier(&self) -> Address {
+        self.verifier.get()
+    }
+
+    /// ERC-165: whether this contract implements `interface_id`
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        erc165::supports(interface_id.0, &[])
+    }
+
+    /// Get contract owner
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Get the fund's ABI-encoded `ProtocolStatus`; its one counter is the total paid on claims
+    pub fn get_protocol_status(&self) -> Bytes {
+        let mut status =
+            ProtocolStatus::new(StatusContract::InsuranceFund, env!("CARGO_PKG_VERSION"), self.owner.get());
+        status.counters = [self.total_paid.get()].into();
+        Bytes::from(encode_protocol_status(&status))
+    }
+
+    /// Transfer ownership to a new owner (admin only)
+    pub fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), InsuranceFundError> {
+        self.only_owner()?;
+
+        if new_owner == Address::ZERO {
+            return Err(InsuranceFundError::InvalidAddress(InvalidAddress {}));
+        }
+
+        let previous_owner = self.owner.get();
+        self.owner.set(new_owner);
+
+        self.vm().log(OwnershipTransferred {
+            previousOwner: previous_owner,
+            newOwner: new_owner,
+        });
+
+        Ok(())
+    }
+
+    /// Internal: Transfer tokens out of the fund
+    fn push(&mut self, token: Address, to: Address, amount: U256) -> Result<(), InsuranceFundError> {
+        let config = Call::new_mutating(self);
+        let sent = IERC20::new(token)
+            .transfer(self.vm(), config, to, amount)
+            .map_err(|_| InsuranceFundError::TransferFailed(TransferFailed {}))?;
+        if !sent {
+            return Err(InsuranceFundError::TransferFailed(TransferFailed {}));
+        }
+        Ok(())
+    }
+
+    /// Internal: Check if caller is owner
+    fn only_owner(&self) -> Result<(), InsuranceFundError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(InsuranceFundError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use stylus_sdk::alloy_primitives::{Address, U256};
+use swoosh_insurance::*;
+use swoosh_test_utils::TestEnv;
+
+#[cfg(test)]
+mod insurance_fund_tests {
+    use super::*;
+
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    // Owner 1, verifier 2, payout token 3
+    fn setup(env: &TestEnv) -> InsuranceFund {
+        let mut fund: InsuranceFund = env.deploy();
+        env.set_sender(test_address(1));
+        assert!(fund.init(test_address(2), test_address(3)).is_ok(), "Init");
+        fund
+    }
+
+    #[test]
+    fn test_only_verifier_pays_claims() {
+        // Users can't claim directly; the verifier checks the policy first
+        let env = TestEnv::new();
+        let mut fund = setup(&env);
+
+        for sender in [test_address(1), test_address(7)] {
+            env.set_sender(sender);
+            assert!(
+                matches!(
+                    fund.pay_claim(U256::from(1u64), test_address(7), test_address(3), U256::from(100u64)),
+                    Err(InsuranceFundError::Unauthorized(_))
+                ),
+                "Not the verifier"
+            );
+        }
+        assert_eq!(fund.get_claim(U256::from(1u64)), U256::ZERO, "Nothing paid");
+        assert_eq!(fund.total_paid(), U256::ZERO, "Nothing paid out");
+    }
+
+    #[test]
+    fn test_payout_valuation() {
+        // The payout token is taken at face value; others need the oracle
+        let env = TestEnv::new();
+        let mut fund = setup(&env);
+        let amount = U256::from(500u64);
+
+        assert_eq!(fund.quote_payout(test_address(3), amount).ok(), Some(amount), "Face value");
+        assert!(
+            matches!(fund.quote_payout(test_address(4), amount), Err(InsuranceFundError::NoValue(_))),
+            "No oracle"
+        );
+
+        env.set_sender(test_address(7));
+        assert!(
+            matches!(fund.set_value_oracle(test_address(5)), Err(InsuranceFundError::Unauthorized(_))),
+            "Owner only"
+        );
+    }
+}
@@ -0,0 +1,159 @@
+//! IntentRegistry Contract
+//!
+//! Chain-wide record of the intents that have executed. Every RouteExecutor
+//! deployment on a chain consumes an intent's key here before executing it,
+//! so an intent runs once per chain even when several executors, or several
+//! versions of one, accept it. The key is the intent's EIP-712 struct hash
+//! (`signing::intent_key` in swoosh-common), which unlike the signed digest
+//! doesn't depend on the executor's address.
+//!
+//! Only executors the owner allows can consume keys. A consumed key stays
+//! consumed; the registry never releases one.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use alloy_sol_types::sol;
+use stylus_sdk::{
+    alloy_primitives::{Address, Bytes, FixedBytes, U256},
+    prelude::*,
+    storage::{StorageAddress, StorageBool, StorageMap, StorageU256},
+};
+
+use swoosh_common::erc165;
+use swoosh_common::status::{encode_protocol_status, ProtocolStatus, StatusContract};
+
+// Events and errors
+sol! {
+    event ExecutorSet(address indexed executor, bool enabled);
+    event IntentConsumed(bytes32 indexed intentKey, address indexed executor);
+
+    error Unauthorized();
+    error InvalidAddress();
+}
+
+/// Error types for IntentRegistry
+#[derive(SolidityError)]
+pub enum IntentRegistryError {
+    Unauthorized(Unauthorized),
+    InvalidAddress(InvalidAddress),
+}
+
+#[entrypoint]
+#[storage]
+pub struct IntentRegistry {
+    /// Contract owner
+    owner: StorageAddress,
+    /// Executors allowed to consume intent keys
+    executors: StorageMap<Address, StorageBool>,
+    /// Mapping of intent keys to the executor that consumed them (zero = unused)
+    consumers: StorageMap<FixedBytes<32>, StorageAddress>,
+    /// Mapping of intent keys to the time they were consumed
+    consumed_at: StorageMap<FixedBytes<32>, StorageU256>,
+    /// Number of consumed intent keys
+    consumed_count: StorageU256,
+}
+
+#[public]
+impl IntentRegistry {
+    /// Initialize the registry
+    pub fn init(&mut self) -> Result<(), IntentRegistryError> {
+        self.owner.set(self.vm().msg_sender());
+        self.consumed_count.set(U256::ZERO);
+
+        Ok(())
+    }
+
+    /// Allow or revoke a RouteExecutor (admin only)
+    ///
+    /// A revoked executor's keys stay consumed.
+    pub fn set_executor(&mut self, executor: Address, enabled: bool) -> Result<(), IntentRegistryError> {
+        self.only_owner()?;
+
+        if executor == Address::ZERO {
+            return Err(IntentRegistryError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.executors.setter(executor).set(enabled);
+
+        self.vm().log(ExecutorSet { executor, enabled });
+
+        Ok(())
+    }
+
+    /// Consume an intent key for the calling executor (executors only)
+    ///
+    /// Returns false without changing anything if the key was already
+    /// consumed, by this executor or another one.
+    pub fn consume(&mut self, intent_key: FixedBytes<32>) -> Result<bool, IntentRegistryError> {
+        let executor = self.vm().msg_sender();
+        if !self.executors.get(executor) {
+            return Err(IntentRegistryError::Unauthorized(Unauthorized {}));
+        }
+
+        if self.consumers.get(intent_key) != Address::ZERO {
+            return Ok(false);
+        }
+
+        self.consumers.setter(intent_key).set(executor);
+        self.consumed_at
+            .setter(intent_key)
+            .set(U256::from(self.vm().block_timestamp()));
+        self.consumed_count.set(self.consumed_count.get() + U256::from(1));
+
+        self.vm().log(IntentConsumed {
+            intentKey: intent_key,
+            executor,
+        });
+
+        Ok(true)
+    }
+
+    /// Check if an intent key has been consumed
+    pub fn is_consumed(&self, intent_key: FixedBytes<32>) -> bool {
+        self.consumers.get(intent_key) != Address::ZERO
+    }
+
+    /// Get the executor that consumed an intent key and when (zero if unused)
+    pub fn get_consumption(&self, intent_key: FixedBytes<32>) -> (Address, U256) {
+        (self.consumers.get(intent_key), self.consumed_at.get(intent_key))
+    }
+
+    /// Check if an executor may consume intent keys
+    pub fn is_executor(&self, executor: Address) -> bool {
+        self.executors.get(executor)
+    }
+
+    /// Get the number of consumed intent keys
+    pub fn consumed_count(&self) -> U256 {
+        self.consumed_count.get()
+    }
+
+    /// ERC-165: whether this contract implements `interface_id`
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        erc165::supports(interface_id.0, &[])
+    }
+
+    /// Get contract owner
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Get the registry's ABI-encoded `ProtocolStatus`; its one counter is the consumed intent keys
+    pub fn get_protocol_status(&self) -> Bytes {
+        let mut status =
+            ProtocolStatus::new(StatusContract::IntentRegistry, env!("CARGO_PKG_VERSION"), self.owner.get());
+        status.counters = [self.consumed_count.get()].into();
+        Bytes::from(encode_protocol_status(&status))
+    }
+
+    /// Internal: Check if caller is owner
+    fn only_owner(&self) -> Result<(), IntentRegistryError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(IntentRegistryError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use stylus_sdk::alloy_primitives::{Address, FixedBytes, U256};
+use swoosh_intent_registry::*;
+use swoosh_test_utils::TestEnv;
+
+#[cfg(test)]
+mod intent_registry_tests {
+    use super::*;
+
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    fn key(n: u8) -> FixedBytes<32> {
+        FixedBytes::from([n; 32])
+    }
+
+    // Owner 1; executors 5 and 6 allowed
+    fn setup(env: &TestEnv) -> IntentRegistry {
+        let mut registry: IntentRegistry = env.deploy();
+        env.set_sender(test_address(1));
+        assert!(registry.init().is_ok(), "Init");
+        assert!(registry.set_executor(test_address(5), true).is_ok(), "Executor 5");
+        assert!(registry.set_executor(test_address(6), true).is_ok(), "Executor 6");
+        registry
+    }
+
+    #[test]
+    fn test_key_consumed_once_across_executors() {
+        // The first executor wins; the second sees the key as used
+        let env = TestEnv::new();
+        let mut registry = setup(&env);
+
+        env.set_sender(test_address(5));
+        assert_eq!(registry.consume(key(9)).ok(), Some(true), "First consume");
+        env.set_sender(test_address(6));
+        assert_eq!(registry.consume(key(9)).ok(), Some(false), "Other executor");
+
+        let (executor, consumed_at) = registry.get_consumption(key(9));
+        assert_eq!(executor, test_address(5), "Consumer kept");
+        assert_eq!(consumed_at, U256::from(env.timestamp()), "Consumed at");
+        assert_eq!(registry.consumed_count(), U256::from(1u64), "Counted once");
+    }
+
+    #[test]
+    fn test_only_allowed_executors_consume() {
+        // Unknown and revoked executors can't consume, and revoking keeps keys used
+        let env = TestEnv::new();
+        let mut registry = setup(&env);
+
+        env.set_sender(test_address(7));
+        assert!(
+            matches!(registry.consume(key(1)), Err(IntentRegistryError::Unauthorized(_))),
+            "Unknown executor"
+        );
+
+        env.set_sender(test_address(5));
+        assert_eq!(registry.consume(key(2)).ok(), Some(true), "Consumed");
+        env.set_sender(test_address(1));
+        assert!(registry.set_executor(test_address(5), false).is_ok(), "Revoked");
+
+        env.set_sender(test_address(5));
+        assert!(
+            matches!(registry.consume(key(3)), Err(IntentRegistryError::Unauthorized(_))),
+            "Revoked executor"
+        );
+        assert!(registry.is_consumed(key(2)), "Key stays consumed");
+        assert!(!registry.is_consumed(key(3)), "Nothing consumed");
+    }
+}
@@ -0,0 +1,19 @@
+//! Swoosh Keeper
+//!
+//! Off-chain half of the permissionless timeout path: follows the intent
+//! lifecycle through the client's event indexer, tracks intents that were
+//! executed but never settled, and calls `slash_expired_intent` on
+//! SettlementVerifier once the timeout has passed.
+
+pub mod metrics;
+pub mod tracker;
+
+use alloy_sol_types::sol;
+
+pub use metrics::Metrics;
+pub use tracker::{PendingIntent, Tracker};
+
+// Permissionless SettlementVerifier entry point driven by the keeper
+sol! {
+    function slashExpiredIntent(uint256 intentId) returns (uint256);
+}
@@ -0,0 +1,277 @@
+//! swoosh-keeper
+//!
+//! Follows RouteExecutor and SettlementVerifier, and once a solver intent has
+//! gone unsettled past the timeout calls `slash_expired_intent` so the
+//! solver's bond is slashed without waiting for the owner. Intents with a
+//! retry policy get a new delivery attempt from that call instead; the
+//! keeper picks them up again from `SettlementRetried`.
+//!
+//! Configuration (environment):
+//!   ARBITRUM_SEPOLIA_RPC          RPC endpoint
+//!   ROUTE_EXECUTOR_ADDRESS        RouteExecutor to follow
+//!   SETTLEMENT_VERIFIER_ADDRESS   SettlementVerifier to follow and call
+//!   PRIVATE_KEY                   Keeper account
+//!   KEEPER_START_BLOCK            First block to index (default 0)
+//!   KEEPER_TIMEOUT                Verifier timeout period in seconds (default 1800)
+//!   KEEPER_MAX_GAS_PRICE_GWEI     Skip sending above this gas price (default 1)
+//!   KEEPER_METRICS_ADDR           Prometheus listener (default 127.0.0.1:9464)
+
+use std::env;
+use std::net::SocketAddr;
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::SolCall;
+use ethers::middleware::SignerMiddleware;
+use ethers::providers::{Http, Middleware, MiddlewareError, Provider};
+use ethers::signers::{LocalWallet, Signer};
+use ethers::types::transaction::eip2718::TypedTransaction;
+use ethers::types::{BlockNumber, TransactionRequest, H160, U256 as EthersU256};
+use swoosh_client::{decode_error, EventIndexer, IndexerConfig, LifecycleUpdate, SwooshError};
+use swoosh_keeper::{metrics, slashExpiredIntentCall, Metrics, Tracker};
+
+const DEFAULT_RPC: &str = "https://sepolia-rollup.arbitrum.io/rpc";
+const DEFAULT_TIMEOUT: u64 = 1_800;
+const DEFAULT_MAX_GAS_PRICE_GWEI: u64 = 1;
+const DEFAULT_METRICS_ADDR: &str = "127.0.0.1:9464";
+/// Base delay before retrying an intent that could not be slashed yet
+const RETRY_BACKOFF: u64 = 30;
+/// Headroom added on top of the gas estimate, in percent
+const GAS_LIMIT_MARGIN: u64 = 20;
+
+type Client = SignerMiddleware<Provider<Http>, LocalWallet>;
+
+/// Outcome of one attempt on a pending intent
+enum Attempt {
+    Slashed,
+    /// Not slashable now, try again later
+    Retry,
+    /// Settled, not a solver intent, or already handled
+    Drop,
+}
+
+struct Keeper {
+    client: Arc<Client>,
+    verifier: Address,
+    max_gas_price: EthersU256,
+    tracker: Tracker,
+    metrics: Arc<Metrics>,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    match run().await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run() -> Result<(), String> {
+    let endpoint = env::var("ARBITRUM_SEPOLIA_RPC").unwrap_or_else(|_| DEFAULT_RPC.to_string());
+    let executor = address_env("ROUTE_EXECUTOR_ADDRESS")?;
+    let verifier = address_env("SETTLEMENT_VERIFIER_ADDRESS")?;
+    let key = env::var("PRIVATE_KEY").map_err(|_| "PRIVATE_KEY is not set".to_string())?;
+    let start_block = number_env("KEEPER_START_BLOCK", 0)?;
+    let timeout = number_env("KEEPER_TIMEOUT", DEFAULT_TIMEOUT)?;
+    let max_gas_price_gwei = number_env("KEEPER_MAX_GAS_PRICE_GWEI", DEFAULT_MAX_GAS_PRICE_GWEI)?;
+    let metrics_addr: SocketAddr = env::var("KEEPER_METRICS_ADDR")
+        .unwrap_or_else(|_| DEFAULT_METRICS_ADDR.to_string())
+        .parse()
+        .map_err(|err| format!("invalid KEEPER_METRICS_ADDR: {err}"))?;
+
+    let provider =
+        Provider::<Http>::try_from(endpoint.as_str()).map_err(|err| format!("invalid RPC url {endpoint}: {err}"))?;
+    let chain_id = provider
+        .get_chainid()
+        .await
+        .map_err(|err| format!("provider error: {err}"))?
+        .as_u64();
+    let wallet = LocalWallet::from_str(&key)
+        .map_err(|err| format!("invalid PRIVATE_KEY: {err}"))?
+        .with_chain_id(chain_id);
+    let client = Arc::new(SignerMiddleware::new(provider.clone(), wallet));
+
+    let metrics = Arc::new(Metrics::default());
+    tokio::spawn({
+        let metrics = metrics.clone();
+        async move {
+            if let Err(err) = metrics::serve(metrics, metrics_addr).await {
+                eprintln!("metrics server stopped: {err}");
+            }
+        }
+    });
+
+    let config = IndexerConfig::new(executor, verifier, start_block);
+    let poll_interval = config.poll_interval;
+    let mut indexer = EventIndexer::new(Arc::new(provider), config);
+    let mut keeper = Keeper {
+        client,
+        verifier,
+        max_gas_price: EthersU256::from(max_gas_price_gwei) * EthersU256::exp10(9),
+        tracker: Tracker::new(timeout, RETRY_BACKOFF),
+        metrics,
+    };
+
+    eprintln!("keeper {} watching {verifier} from block {start_block}", keeper.client.address());
+
+    loop {
+        match indexer.poll().await {
+            Ok(updates) => keeper.apply(&updates),
+            Err(err) => {
+                keeper.metrics.rpc_errors.fetch_add(1, Ordering::Relaxed);
+                eprintln!("indexer: {err}");
+            }
+        }
+        if let Some(checkpoint) = indexer.checkpoint() {
+            keeper.metrics.indexed_block.store(checkpoint.block_number, Ordering::Relaxed);
+        }
+
+        if let Err(err) = keeper.work().await {
+            keeper.metrics.rpc_errors.fetch_add(1, Ordering::Relaxed);
+            eprintln!("keeper: {err}");
+        }
+
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+impl Keeper {
+    fn apply(&mut self, updates: &[LifecycleUpdate]) {
+        for update in updates {
+            if let LifecycleUpdate::Reorg { fork_block } = update {
+                self.metrics.reorgs.fetch_add(1, Ordering::Relaxed);
+                eprintln!("reorg below block {fork_block}");
+            }
+            self.tracker.apply(update);
+        }
+        self.metrics.pending_intents.store(self.tracker.len() as u64, Ordering::Relaxed);
+    }
+
+    /// Try every intent whose timeout has passed at the latest block
+    async fn work(&mut self) -> Result<(), String> {
+        let balance = self
+            .client
+            .get_balance(self.client.address(), None)
+            .await
+            .map_err(|err| format!("provider error: {err}"))?;
+        self.metrics
+            .balance_gwei
+            .store((balance / EthersU256::exp10(9)).low_u64(), Ordering::Relaxed);
+
+        let now = self
+            .client
+            .get_block(BlockNumber::Latest)
+            .await
+            .map_err(|err| format!("provider error: {err}"))?
+            .ok_or("node returned no latest block")?
+            .timestamp
+            .low_u64();
+
+        for intent_id in self.tracker.due(now) {
+            match self.slash(intent_id).await? {
+                Attempt::Slashed => {
+                    self.metrics.intents_slashed.fetch_add(1, Ordering::Relaxed);
+                    self.tracker.remove(intent_id);
+                }
+                Attempt::Retry => self.tracker.defer(intent_id, now),
+                Attempt::Drop => {
+                    self.tracker.remove(intent_id);
+                }
+            }
+        }
+        self.metrics.pending_intents.store(self.tracker.len() as u64, Ordering::Relaxed);
+
+        Ok(())
+    }
+
+    /// Simulate, price-check and send `slash_expired_intent` for one intent
+    ///
+    /// Provider failures abort the round; contract reverts only affect this intent.
+    async fn slash(&self, intent_id: U256) -> Result<Attempt, String> {
+        let mut tx: TypedTransaction = TransactionRequest::new()
+            .from(self.client.address())
+            .to(H160::from_slice(self.verifier.as_slice()))
+            .data(slashExpiredIntentCall { intentId: intent_id }.abi_encode())
+            .into();
+
+        let estimate = match self.client.estimate_gas(&tx, None).await {
+            Ok(estimate) => estimate,
+            Err(err) => {
+                let reverted = err.as_error_response().and_then(|response| response.as_revert_data());
+                return match reverted.as_deref().and_then(decode_error) {
+                    // Timeout not reached yet on chain (clock skew or a longer timeout)
+                    Some(SwooshError::SettlementTimeout) => Ok(Attempt::Retry),
+                    // Settled or slashed by someone else, or not a solver intent
+                    Some(SwooshError::AlreadyProcessed | SwooshError::InvalidIntentId) => Ok(Attempt::Drop),
+                    Some(error) => {
+                        eprintln!("intent {intent_id}: {error}");
+                        Ok(Attempt::Retry)
+                    }
+                    None if reverted.is_some() => Ok(Attempt::Retry),
+                    None => Err(format!("provider error: {err}")),
+                };
+            }
+        };
+
+        let gas_price = self
+            .client
+            .get_gas_price()
+            .await
+            .map_err(|err| format!("provider error: {err}"))?;
+        if gas_price > self.max_gas_price {
+            self.metrics.gas_price_skips.fetch_add(1, Ordering::Relaxed);
+            return Ok(Attempt::Retry);
+        }
+
+        tx.set_gas(estimate + estimate * GAS_LIMIT_MARGIN / 100);
+        tx.set_gas_price(gas_price);
+
+        let pending = match self.client.send_transaction(tx, None).await {
+            Ok(pending) => pending,
+            Err(err) => {
+                self.metrics.transactions_failed.fetch_add(1, Ordering::Relaxed);
+                eprintln!("intent {intent_id}: send failed: {err}");
+                return Ok(Attempt::Retry);
+            }
+        };
+        self.metrics.transactions_sent.fetch_add(1, Ordering::Relaxed);
+        let tx_hash = pending.tx_hash();
+
+        let receipt = pending.await.map_err(|err| format!("provider error: {err}"))?;
+        let Some(receipt) = receipt else {
+            self.metrics.transactions_failed.fetch_add(1, Ordering::Relaxed);
+            eprintln!("intent {intent_id}: {tx_hash:?} dropped");
+            return Ok(Attempt::Retry);
+        };
+        if let Some(gas_used) = receipt.gas_used {
+            self.metrics.gas_used.fetch_add(gas_used.low_u64(), Ordering::Relaxed);
+        }
+
+        if receipt.status.map(|status| status.as_u64()) == Some(1) {
+            eprintln!("intent {intent_id}: slashed in {tx_hash:?}");
+            Ok(Attempt::Slashed)
+        } else {
+            self.metrics.transactions_failed.fetch_add(1, Ordering::Relaxed);
+            eprintln!("intent {intent_id}: {tx_hash:?} reverted");
+            Ok(Attempt::Retry)
+        }
+    }
+}
+
+fn address_env(name: &str) -> Result<Address, String> {
+    let value = env::var(name).map_err(|_| format!("{name} is not set"))?;
+    value.parse().map_err(|_| format!("{name}: invalid address: {value}"))
+}
+
+fn number_env(name: &str, default: u64) -> Result<u64, String> {
+    match env::var(name) {
+        Ok(value) => value.parse().map_err(|_| format!("{name}: invalid number: {value}")),
+        Err(_) => Ok(default),
+    }
+}
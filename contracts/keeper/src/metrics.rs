@@ -0,0 +1,91 @@
+//! Keeper Metrics
+//!
+//! Counters and gauges updated by the keeper loop, rendered in the Prometheus
+//! text exposition format and served on `GET /metrics`.
+
+use std::fmt::Write;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Keeper counters and gauges
+#[derive(Debug, Default)]
+pub struct Metrics {
+    /// Intents executed but not yet settled
+    pub pending_intents: AtomicU64,
+    /// Last block processed by the indexer
+    pub indexed_block: AtomicU64,
+    /// Reorgs reported by the indexer
+    pub reorgs: AtomicU64,
+    /// Indexer or provider errors
+    pub rpc_errors: AtomicU64,
+    /// Transactions sent
+    pub transactions_sent: AtomicU64,
+    /// Transactions that reverted or could not be sent
+    pub transactions_failed: AtomicU64,
+    /// Intents slashed by this keeper
+    pub intents_slashed: AtomicU64,
+    /// Attempts postponed because gas was above the cap
+    pub gas_price_skips: AtomicU64,
+    /// Gas used by mined keeper transactions
+    pub gas_used: AtomicU64,
+    /// Keeper account balance in gwei
+    pub balance_gwei: AtomicU64,
+}
+
+impl Metrics {
+    /// Render every metric in Prometheus text format
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let mut metric = |name: &str, kind: &str, help: &str, value: &AtomicU64| {
+            let _ = writeln!(out, "# HELP swoosh_keeper_{name} {help}");
+            let _ = writeln!(out, "# TYPE swoosh_keeper_{name} {kind}");
+            let _ = writeln!(out, "swoosh_keeper_{name} {}", value.load(Ordering::Relaxed));
+        };
+
+        metric("pending_intents", "gauge", "Intents executed but not yet settled", &self.pending_intents);
+        metric("indexed_block", "gauge", "Last block processed by the indexer", &self.indexed_block);
+        metric("reorgs_total", "counter", "Reorgs reported by the indexer", &self.reorgs);
+        metric("rpc_errors_total", "counter", "Indexer or provider errors", &self.rpc_errors);
+        metric("transactions_sent_total", "counter", "Transactions sent", &self.transactions_sent);
+        metric("transactions_failed_total", "counter", "Transactions that reverted or could not be sent", &self.transactions_failed);
+        metric("intents_slashed_total", "counter", "Intents slashed by this keeper", &self.intents_slashed);
+        metric("gas_price_skips_total", "counter", "Attempts postponed because gas was above the cap", &self.gas_price_skips);
+        metric("gas_used_total", "counter", "Gas used by mined keeper transactions", &self.gas_used);
+        metric("balance_gwei", "gauge", "Keeper account balance in gwei", &self.balance_gwei);
+
+        out
+    }
+}
+
+/// Serve `GET /metrics` on `addr` until the listener fails
+pub async fn serve(metrics: Arc<Metrics>, addr: SocketAddr) -> io::Result<()> {
+    let listener = TcpListener::bind(addr).await?;
+
+    loop {
+        let (mut socket, _) = listener.accept().await?;
+        let metrics = metrics.clone();
+
+        tokio::spawn(async move {
+            let mut request = [0u8; 1024];
+            let Ok(read) = socket.read(&mut request).await else {
+                return;
+            };
+
+            let response = if request[..read].starts_with(b"GET /metrics") {
+                let body = metrics.render();
+                format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+            } else {
+                "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string()
+            };
+            let _ = socket.write_all(response.as_bytes()).await;
+        });
+    }
+}
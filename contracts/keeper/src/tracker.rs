@@ -0,0 +1,136 @@
+//! Pending Settlement Tracker
+//!
+//! Keeps the intents that were executed on the source chain but have not yet
+//! been confirmed or refunded, and schedules when the keeper should try them.
+//! An intent whose retry policy opened another delivery attempt is tracked
+//! again from that attempt's start, even if an earlier call removed it.
+
+use std::collections::BTreeMap;
+
+use alloy_primitives::U256;
+use swoosh_client::{IntentEventKind, LifecycleUpdate};
+
+/// Upper bound on the retry delay, however many attempts failed
+pub const MAX_BACKOFF: u64 = 3_600;
+
+/// Executed intent still waiting for settlement
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PendingIntent {
+    pub intent_id: U256,
+    /// Execution timestamp from `IntentExecuted` (or the retried attempt's
+    /// start if the execution was never seen)
+    pub executed_at: u64,
+    /// Block of the `IntentExecuted` or latest `SettlementRetried` log, for
+    /// reorg handling
+    pub block_number: u64,
+    /// Earliest timestamp the keeper should try it
+    pub next_attempt: u64,
+    pub attempts: u32,
+}
+
+/// Pending intents keyed by ID
+#[derive(Debug)]
+pub struct Tracker {
+    pending: BTreeMap<U256, PendingIntent>,
+    timeout: u64,
+    backoff: u64,
+}
+
+impl Tracker {
+    /// `timeout` mirrors SettlementVerifier's timeout period; `backoff` is
+    /// the base delay before retrying an intent that was not yet slashable
+    pub fn new(timeout: u64, backoff: u64) -> Self {
+        Self {
+            pending: BTreeMap::new(),
+            timeout,
+            backoff,
+        }
+    }
+
+    /// Fold one lifecycle update into the pending set
+    pub fn apply(&mut self, update: &LifecycleUpdate) {
+        match update {
+            LifecycleUpdate::Event(event) => match event.kind {
+                IntentEventKind::Executed { timestamp, .. } => {
+                    let executed_at = timestamp.saturating_to::<u64>();
+                    self.pending.insert(
+                        event.intent_id,
+                        PendingIntent {
+                            intent_id: event.intent_id,
+                            executed_at,
+                            block_number: event.meta.block_number,
+                            // The contract requires strictly more than `timeout`
+                            next_attempt: executed_at.saturating_add(self.timeout).saturating_add(1),
+                            attempts: 0,
+                        },
+                    );
+                }
+                IntentEventKind::SettlementRetried { timestamp, .. } => {
+                    let started_at = timestamp.saturating_to::<u64>();
+                    let executed_at = self
+                        .pending
+                        .get(&event.intent_id)
+                        .map_or(started_at, |intent| intent.executed_at);
+                    self.pending.insert(
+                        event.intent_id,
+                        PendingIntent {
+                            intent_id: event.intent_id,
+                            executed_at,
+                            block_number: event.meta.block_number,
+                            next_attempt: started_at.saturating_add(self.timeout).saturating_add(1),
+                            attempts: 0,
+                        },
+                    );
+                }
+                IntentEventKind::SettlementConfirmed { .. } | IntentEventKind::RefundInitiated { .. } => {
+                    self.pending.remove(&event.intent_id);
+                }
+                IntentEventKind::Memo { .. }
+                | IntentEventKind::StepExecuted { .. }
+                | IntentEventKind::BridgeInitiated { .. } => {}
+            },
+            // Executions above the fork are re-emitted if they are still canonical
+            LifecycleUpdate::Reorg { fork_block } => {
+                self.pending.retain(|_, intent| intent.block_number <= *fork_block);
+            }
+        }
+    }
+
+    /// Intents whose next attempt is at or before `now`
+    pub fn due(&self, now: u64) -> Vec<U256> {
+        self.pending
+            .values()
+            .filter(|intent| intent.next_attempt <= now)
+            .map(|intent| intent.intent_id)
+            .collect()
+    }
+
+    /// Push an intent back with exponential backoff after a failed attempt
+    pub fn defer(&mut self, intent_id: U256, now: u64) {
+        if let Some(intent) = self.pending.get_mut(&intent_id) {
+            intent.attempts += 1;
+            let delay = self
+                .backoff
+                .saturating_mul(1u64 << intent.attempts.min(16))
+                .min(MAX_BACKOFF);
+            intent.next_attempt = now.saturating_add(delay);
+        }
+    }
+
+    /// Stop tracking an intent (slashed, settled, or never slashable)
+    pub fn remove(&mut self, intent_id: U256) -> Option<PendingIntent> {
+        self.pending.remove(&intent_id)
+    }
+
+    pub fn get(&self, intent_id: U256) -> Option<&PendingIntent> {
+        self.pending.get(&intent_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
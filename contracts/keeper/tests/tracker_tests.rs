@@ -0,0 +1,128 @@
+use alloy_primitives::{Address, B256, U256};
+use swoosh_client::{IntentEvent, IntentEventKind, LifecycleUpdate, LogMeta};
+use swoosh_keeper::tracker::*;
+use swoosh_keeper::Metrics;
+
+#[cfg(test)]
+mod tracker_tests {
+    use super::*;
+
+    const TIMEOUT: u64 = 1_800;
+    const BACKOFF: u64 = 30;
+
+    fn update(intent_id: u64, kind: IntentEventKind, block_number: u64) -> LifecycleUpdate {
+        LifecycleUpdate::Event(Box::new(IntentEvent {
+            intent_id: U256::from(intent_id),
+            kind,
+            meta: LogMeta {
+                address: Address::ZERO,
+                block_number,
+                block_hash: B256::ZERO,
+                transaction_hash: B256::ZERO,
+                log_index: 0,
+            },
+        }))
+    }
+
+    fn executed(intent_id: u64, timestamp: u64, block_number: u64) -> LifecycleUpdate {
+        update(
+            intent_id,
+            IntentEventKind::Executed {
+                user: Address::from([1u8; 20]),
+                token_in: Address::from([2u8; 20]),
+                destination_chain: U256::from(10u64),
+                timestamp: U256::from(timestamp),
+            },
+            block_number,
+        )
+    }
+
+    #[test]
+    fn test_due_only_after_timeout() {
+        // slash_expired_intent needs strictly more than the timeout to have passed
+        let mut tracker = Tracker::new(TIMEOUT, BACKOFF);
+        tracker.apply(&executed(1, 1_000, 10));
+
+        assert!(tracker.due(1_000 + TIMEOUT).is_empty(), "At the boundary");
+        assert_eq!(tracker.due(1_000 + TIMEOUT + 1), vec![U256::from(1u64)], "Past the timeout");
+    }
+
+    #[test]
+    fn test_settlement_and_refund_clear_pending() {
+        // Either terminal event stops the keeper from acting on the intent
+        let mut tracker = Tracker::new(TIMEOUT, BACKOFF);
+        tracker.apply(&executed(1, 1_000, 10));
+        tracker.apply(&executed(2, 1_000, 10));
+
+        tracker.apply(&update(1, IntentEventKind::SettlementConfirmed { message_id: B256::ZERO, timestamp: U256::ZERO }, 11));
+        tracker.apply(&update(
+            2,
+            IntentEventKind::RefundInitiated { user: Address::ZERO, token: Address::ZERO, amount: U256::ZERO },
+            11,
+        ));
+
+        assert!(tracker.is_empty(), "Nothing pending");
+    }
+
+    #[test]
+    fn test_retried_delivery_rescheduled() {
+        // A new attempt restarts the clock, even after the keeper let the intent go
+        let mut tracker = Tracker::new(TIMEOUT, BACKOFF);
+        tracker.apply(&executed(1, 1_000, 10));
+        let id = U256::from(1u64);
+        let retried_at = 1_000 + TIMEOUT + 5;
+
+        tracker.remove(id);
+        tracker.apply(&update(
+            1,
+            IntentEventKind::SettlementRetried { attempt: U256::from(1u64), timestamp: U256::from(retried_at) },
+            12,
+        ));
+
+        assert!(tracker.due(retried_at + TIMEOUT).is_empty(), "New attempt not expired");
+        assert_eq!(tracker.due(retried_at + TIMEOUT + 1), vec![id], "New attempt expired");
+        assert_eq!(tracker.get(id).unwrap().block_number, 12, "Reorg point moved");
+    }
+
+    #[test]
+    fn test_reorg_drops_orphaned_executions() {
+        // Executions above the fork are forgotten until re-emitted
+        let mut tracker = Tracker::new(TIMEOUT, BACKOFF);
+        tracker.apply(&executed(1, 1_000, 10));
+        tracker.apply(&executed(2, 1_000, 20));
+
+        tracker.apply(&LifecycleUpdate::Reorg { fork_block: 15 });
+
+        assert!(tracker.get(U256::from(1u64)).is_some(), "Below fork kept");
+        assert!(tracker.get(U256::from(2u64)).is_none(), "Above fork dropped");
+    }
+
+    #[test]
+    fn test_defer_backs_off_exponentially() {
+        // Each failed attempt doubles the delay, up to the cap
+        let mut tracker = Tracker::new(TIMEOUT, BACKOFF);
+        tracker.apply(&executed(1, 0, 1));
+        let id = U256::from(1u64);
+
+        tracker.defer(id, 5_000);
+        assert_eq!(tracker.get(id).unwrap().next_attempt, 5_000 + 2 * BACKOFF, "First retry");
+        tracker.defer(id, 5_000);
+        assert_eq!(tracker.get(id).unwrap().next_attempt, 5_000 + 4 * BACKOFF, "Second retry");
+
+        for _ in 0..20 {
+            tracker.defer(id, 5_000);
+        }
+        assert_eq!(tracker.get(id).unwrap().next_attempt, 5_000 + MAX_BACKOFF, "Capped");
+    }
+
+    #[test]
+    fn test_metrics_render_prometheus_text() {
+        // Every sample carries HELP and TYPE lines
+        let metrics = Metrics::default();
+        metrics.pending_intents.store(3, std::sync::atomic::Ordering::Relaxed);
+        let text = metrics.render();
+
+        assert!(text.contains("# TYPE swoosh_keeper_pending_intents gauge\nswoosh_keeper_pending_intents 3\n"), "Gauge");
+        assert!(text.contains("# TYPE swoosh_keeper_intents_slashed_total counter"), "Counter");
+    }
+}
@@ -0,0 +1,295 @@
+//! SwooshLens Contract
+//!
+//! Read-only aggregator for frontends. Each call gathers what a page needs
+//! from a RouteExecutor, the IntentValidator and SettlementVerifier it
+//! points at, and the token involved, and returns one of the composite views
+//! in the `lens` module of swoosh-common, ABI-encoded. The lens holds no
+//! funds and writes nothing but its own configuration; it only knows which
+//! executor to read and finds the validator and verifier through it.
+//!
+//! Reads that fail, for example because a contract isn't set, count as zero
+//! or false rather than reverting, so a half-configured deployment still
+//! renders.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use alloy_sol_types::{sol, SolCall, SolValue};
+use stylus_sdk::{
+    alloy_primitives::{Address, Bytes, FixedBytes, U256},
+    call::{static_call, Call},
+    prelude::*,
+    storage::StorageAddress,
+};
+
+use swoosh_common::bridge_adapter::IBridgeAdapter;
+use swoosh_common::encoding::NATIVE_TOKEN;
+use swoosh_common::erc165;
+use swoosh_common::fees;
+use swoosh_common::lens::{encode_intent_views, readiness_blocker, FeeQuote, IntentView, UserReadiness, MAX_LENS_SCAN};
+use swoosh_common::signing;
+
+// Views read from RouteExecutor, IntentValidator, SettlementVerifier and tokens
+sol! {
+    function validator() external view returns (address);
+    function settlementVerifier() external view returns (address);
+    function pausedAt() external view returns (uint256);
+    function isSolverOnly() external view returns (bool);
+    function resolveBridgeAdapter(address token, uint256 destinationChain) external view returns (address);
+    function quoteProtocolFeeBps(
+        address integrator,
+        address token,
+        uint256 amount,
+        uint256 destinationChain
+    ) external view returns (uint256);
+    function intentCount() external view returns (uint256);
+    function getIntentHashAt(uint256 index) external view returns (bytes32);
+    function getIntentUser(uint256 intentId) external view returns (address);
+    function getIntentMemo(uint256 intentId) external view returns (bytes32);
+    function getIntentExecution(uint256 intentId) external view returns (
+        uint256 status,
+        uint256 destinationChain,
+        uint256 createdAt,
+        uint256 updatedAt,
+        uint256 amountIn,
+        uint256 amountOut
+    );
+
+    function isTokenSupported(address token) external view returns (bool);
+    function isChainSupported(uint256 chainId) external view returns (bool);
+    function isLaneHalted(uint256 chainId) external view returns (bool);
+
+    function getSettlementStatus(uint256 intentId) external view returns (uint256);
+    function hasSettlementTimedOut(uint256 intentId) external view returns (bool);
+    function getPendingDelivery(uint256 intentId) external view returns (
+        bytes32 messageId,
+        uint8 status,
+        uint256 confirmations,
+        uint256 finalAt
+    );
+
+    function balanceOf(address account) external view returns (uint256);
+    function allowance(address owner, address spender) external view returns (uint256);
+}
+
+// Events and errors
+sol! {
+    event ExecutorSet(address executor);
+
+    error Unauthorized();
+    error InvalidAddress();
+}
+
+/// Error types for SwooshLens
+#[derive(SolidityError)]
+pub enum SwooshLensError {
+    Unauthorized(Unauthorized),
+    InvalidAddress(InvalidAddress),
+}
+
+/// RouteExecutor status of an intent whose route failed
+const INTENT_FAILED: u64 = 3;
+/// SettlementVerifier status of an intent still waiting on its delivery
+const SETTLEMENT_PENDING: u64 = 0;
+
+#[entrypoint]
+#[storage]
+pub struct SwooshLens {
+    /// Contract owner
+    owner: StorageAddress,
+    /// RouteExecutor the lens reads
+    executor: StorageAddress,
+}
+
+#[public]
+impl SwooshLens {
+    /// Initialize the lens with the RouteExecutor it reads
+    pub fn init(&mut self, executor: Address) -> Result<(), SwooshLensError> {
+        if executor == Address::ZERO {
+            return Err(SwooshLensError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.owner.set(self.vm().msg_sender());
+        self.executor.set(executor);
+
+        Ok(())
+    }
+
+    /// Point the lens at another RouteExecutor (admin only)
+    pub fn set_executor(&mut self, executor: Address) -> Result<(), SwooshLensError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(SwooshLensError::Unauthorized(Unauthorized {}));
+        }
+        if executor == Address::ZERO {
+            return Err(SwooshLensError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.executor.set(executor);
+
+        self.vm().log(ExecutorSet { executor });
+
+        Ok(())
+    }
+
+    /// Whether `user` can execute an intent of `amount` of `token` to
+    /// `destination_chain` now, as an ABI-encoded `UserReadiness`
+    pub fn get_user_readiness(&self, user: Address, token: Address, amount: U256, destination_chain: U256) -> Bytes {
+        let executor = self.executor.get();
+        let validator = self.read(executor, validatorCall {}).unwrap_or_default();
+
+        let (balance, allowance) = if token == NATIVE_TOKEN {
+            (self.vm().balance(user), U256::MAX)
+        } else {
+            (
+                self.read(token, balanceOfCall { account: user }).unwrap_or_default(),
+                self.read(token, allowanceCall { owner: user, spender: executor }).unwrap_or_default(),
+            )
+        };
+        let chain_id = destination_chain;
+        let lane = resolveBridgeAdapterCall { token, destinationChain: chain_id };
+
+        let mut readiness = UserReadiness {
+            balance,
+            allowance,
+            tokenSupported: self.read(validator, isTokenSupportedCall { token }).unwrap_or_default(),
+            chainSupported: self.read(validator, isChainSupportedCall { chainId: chain_id }).unwrap_or_default(),
+            laneHalted: self.read(validator, isLaneHaltedCall { chainId: chain_id }).unwrap_or_default(),
+            executorPaused: self.read(executor, pausedAtCall {}).unwrap_or_default() != U256::ZERO,
+            solverOnly: self.read(executor, isSolverOnlyCall {}).unwrap_or_default(),
+            bridgeAdapter: self.read(executor, lane).unwrap_or_default(),
+            blocker: 0,
+        };
+        readiness.blocker = readiness_blocker(&readiness, amount).map_or(0, |blocker| blocker as u8);
+
+        Bytes::from(readiness.abi_encode())
+    }
+
+    /// A page of `user`'s open intents, as an ABI-encoded `IntentView[]`,
+    /// and the index to continue from (zero once the end is reached)
+    ///
+    /// Scans up to `limit` of the executor's local intent indexes starting at
+    /// `start_index` (the first is 1), capped at `MAX_LENS_SCAN`. An intent is
+    /// open until its route fails or the verifier settles it.
+    pub fn get_user_intents(&self, user: Address, start_index: U256, limit: U256) -> (Bytes, U256) {
+        let executor = self.executor.get();
+        let verifier = self.read(executor, settlementVerifierCall {}).unwrap_or_default();
+        let count = self.read(executor, intentCountCall {}).unwrap_or_default();
+
+        let start = start_index.max(U256::from(1));
+        let end = (start + limit.min(U256::from(MAX_LENS_SCAN))).min(count + U256::from(1));
+
+        let mut views = Vec::new();
+        let mut index = start;
+        while index < end {
+            if let Some(view) = self.open_intent_view(executor, verifier, user, index) {
+                views.push(view);
+            }
+            index += U256::from(1);
+        }
+
+        let next = if end > count { U256::ZERO } else { end };
+        (Bytes::from(encode_intent_views(&views)), next)
+    }
+
+    /// Fees an intent of `amount` of `token` would pay through `integrator`,
+    /// as an ABI-encoded `FeeQuote`
+    ///
+    /// The bridge fee is quoted on the amount left after the protocol fee;
+    /// integrator fees and swaps on the way can change it slightly.
+    pub fn quote_fees(
+        &self,
+        token: Address,
+        amount: U256,
+        integrator: Address,
+        destination_chain: U256,
+        recipient: Address,
+    ) -> Bytes {
+        let executor = self.executor.get();
+        let fee_call = quoteProtocolFeeBpsCall { integrator, token, amount, destinationChain: destination_chain };
+        let protocol_fee_bps = self.read(executor, fee_call).unwrap_or_default();
+        let (net_amount, protocol_fee) = fees::take_fee(amount, protocol_fee_bps);
+
+        let lane = resolveBridgeAdapterCall { token, destinationChain: destination_chain };
+        let adapter = self.read(executor, lane).unwrap_or_default();
+        let bridge_fee = if adapter == Address::ZERO {
+            U256::ZERO
+        } else {
+            IBridgeAdapter::new(adapter)
+                .quote_fee(self.vm(), Call::new(), destination_chain, token, net_amount, recipient)
+                .unwrap_or(U256::ZERO)
+        };
+
+        let quote = FeeQuote {
+            protocolFeeBps: protocol_fee_bps,
+            protocolFee: protocol_fee,
+            netAmount: net_amount,
+            bridgeAdapter: adapter,
+            bridgeFee: bridge_fee,
+        };
+        Bytes::from(quote.abi_encode())
+    }
+
+    /// Get the RouteExecutor the lens reads
+    pub fn executor(&self) -> Address {
+        self.executor.get()
+    }
+
+    /// ERC-165: whether this contract implements `interface_id`
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        erc165::supports(interface_id.0, &[])
+    }
+
+    /// Get contract owner
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Internal: Static-call a view, returning `None` if it reverts or its
+    /// result doesn't decode
+    fn read<C: SolCall>(&self, target: Address, call: C) -> Option<C::Return> {
+        if target == Address::ZERO {
+            return None;
+        }
+        let result = static_call(self.vm(), Call::new(), target, &call.abi_encode()).ok()?;
+        C::abi_decode_returns(&result).ok()
+    }
+
+    /// Internal: View of the intent at a local index if it is `user`'s and open
+    fn open_intent_view(&self, executor: Address, verifier: Address, user: Address, index: U256) -> Option<IntentView> {
+        let intent_hash = self.read(executor, getIntentHashAtCall { index })?;
+        let intent_id = signing::global_intent_id(intent_hash);
+        if self.read(executor, getIntentUserCall { intentId: intent_id })? != user {
+            return None;
+        }
+
+        let execution = self.read(executor, getIntentExecutionCall { intentId: intent_id })?;
+        let settlement_status = self
+            .read(verifier, getSettlementStatusCall { intentId: intent_id })
+            .unwrap_or_default();
+        if execution.status == U256::from(INTENT_FAILED) || settlement_status != U256::from(SETTLEMENT_PENDING) {
+            return None;
+        }
+        let final_at = self
+            .read(verifier, getPendingDeliveryCall { intentId: intent_id })
+            .map_or(U256::ZERO, |pending| pending.finalAt);
+
+        Some(IntentView {
+            intentId: intent_id,
+            intentHash: intent_hash,
+            status: execution.status.saturating_to(),
+            destinationChain: execution.destinationChain,
+            amountIn: execution.amountIn,
+            amountOut: execution.amountOut,
+            updatedAt: execution.updatedAt.saturating_to(),
+            settlementStatus: settlement_status.saturating_to(),
+            timedOut: self
+                .read(verifier, hasSettlementTimedOutCall { intentId: intent_id })
+                .unwrap_or_default(),
+            finalAt: final_at,
+            memo: self.read(executor, getIntentMemoCall { intentId: intent_id }).unwrap_or_default(),
+        })
+    }
+}
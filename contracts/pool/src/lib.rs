@@ -0,0 +1,779 @@
+//! LiquidityPool Contract
+//!
+//! Optional per-chain pool of LP liquidity that registered solvers borrow to
+//! fill a destination leg immediately instead of waiting for the bridge.
+//! LPs deposit a supported token for pool shares; each loan is tied to the
+//! intent it fills and pays a fee that rises with the pool's utilization.
+//!
+//! A loan can only fill an intent the borrowing solver executed and that
+//! SettlementVerifier on this chain still holds as pending, in the intent's
+//! token, to its recipient and for no more than its notional. It locks the
+//! solver's SolverRegistry bond, at the token's bond rate, for the principal
+//! plus fee.
+//!
+//! A loan is repaid, principal plus fee, once the verifier confirms the
+//! intent's delivery: anyone can then pull the repayment from the borrowing
+//! solver, and the solver may also repay early; repayment unlocks the bond.
+//! If the intent fails instead, anyone can default the loan: the principal is
+//! written off against the LPs and the locked bond is seized for the owner to
+//! make them whole. Fees stay in the pool and raise the value of every LP
+//! share. Loans are capped per intent and by a maximum utilization per token.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use alloy_sol_types::sol;
+use stylus_sdk::{
+    alloy_primitives::{Address, Bytes, FixedBytes, U256},
+    call::Call,
+    prelude::*,
+    storage::{StorageAddress, StorageMap, StorageU256},
+};
+
+use swoosh_common::erc165;
+use swoosh_common::fees::{self, BPS_DENOMINATOR};
+use swoosh_common::params::{self, Param, ParameterOutOfBounds};
+use swoosh_common::solver_registry::ISolverRegistry;
+use swoosh_common::status::{encode_protocol_status, ProtocolStatus, StatusContract};
+
+// Events and errors
+sol! {
+    event TokenConfigured(address indexed token, uint256 maxPerIntent);
+    event FeeCurveSet(uint256 baseFeeBps, uint256 slopeFeeBps);
+    event MaxUtilizationSet(uint256 maxUtilizationBps);
+    event Deposited(address indexed provider, address indexed token, uint256 amount, uint256 shares);
+    event Withdrawn(address indexed provider, address indexed token, uint256 amount, uint256 shares);
+    event LoanOpened(
+        uint256 indexed intentId,
+        address indexed solver,
+        address token,
+        uint256 amount,
+        uint256 fee,
+        address recipient
+    );
+    event LoanRepaid(uint256 indexed intentId, address indexed payer, uint256 amount, uint256 fee);
+    event LoanDefaulted(uint256 indexed intentId, uint256 indexed solverId, uint256 amount, uint256 seized);
+    event BondRateSet(address indexed token, uint256 bondRate);
+    event SettlementVerifierSet(address verifier);
+    event SolverRegistrySet(address registry);
+    event OwnershipTransferred(address indexed previousOwner, address indexed newOwner);
+
+    error Unauthorized();
+    error InvalidAddress();
+    error InvalidAmount();
+    error NotSolver();
+    error UnsupportedToken(address token);
+    error ExceedsIntentCap(uint256 amount, uint256 maxPerIntent);
+    error InsufficientLiquidity(uint256 available, uint256 requested);
+    error LoanExists(uint256 intentId);
+    error NoLoan(uint256 intentId);
+    error NotSettled(uint256 intentId);
+    error TransferFailed();
+    error IntentNotPending(uint256 intentId);
+    error IntentMismatch(uint256 intentId);
+    error BondUnavailable(uint256 intentId);
+    error NotDefaulted(uint256 intentId);
+}
+
+/// Error types for LiquidityPool
+#[derive(SolidityError)]
+pub enum LiquidityPoolError {
+    Unauthorized(Unauthorized),
+    InvalidAddress(InvalidAddress),
+    InvalidAmount(InvalidAmount),
+    NotSolver(NotSolver),
+    UnsupportedToken(UnsupportedToken),
+    ExceedsIntentCap(ExceedsIntentCap),
+    InsufficientLiquidity(InsufficientLiquidity),
+    LoanExists(LoanExists),
+    NoLoan(NoLoan),
+    NotSettled(NotSettled),
+    TransferFailed(TransferFailed),
+    ParameterOutOfBounds(ParameterOutOfBounds),
+    IntentNotPending(IntentNotPending),
+    IntentMismatch(IntentMismatch),
+    BondUnavailable(BondUnavailable),
+    NotDefaulted(NotDefaulted),
+}
+
+// ERC20 interface
+sol_interface! {
+    interface IERC20 {
+        function transferFrom(address from, address to, uint256 amount) external returns (bool);
+        function transfer(address to, uint256 amount) external returns (bool);
+    }
+}
+
+// SettlementVerifier interface for loan terms and the repayment trigger
+sol_interface! {
+    interface ISettlementVerifier {
+        function getSettlementStatus(uint256 intent_id) external view returns (uint256);
+        function getSolverIntent(uint256 intent_id) external view returns (uint256, address, uint256, address);
+    }
+}
+
+/// SettlementVerifier status of an intent still in flight
+const SETTLEMENT_PENDING: u64 = 0;
+/// SettlementVerifier status of a confirmed delivery
+const SETTLEMENT_CONFIRMED: u64 = 1;
+/// SettlementVerifier status of a failed intent
+const SETTLEMENT_FAILED: u64 = 2;
+/// SettlementVerifier status of a failed intent refunded to its user
+const SETTLEMENT_REFUNDED: u64 = 3;
+/// Default borrow fee at zero utilization (0.05%)
+const DEFAULT_BASE_FEE_BPS: u64 = 5;
+/// Default extra borrow fee at full utilization (0.45%)
+const DEFAULT_SLOPE_FEE_BPS: u64 = 45;
+/// Default share of each token's liquidity that may be lent out (90%)
+const DEFAULT_MAX_UTILIZATION_BPS: u64 = 9_000;
+/// Ceiling on the fee curve the admin may configure (5% at full utilization)
+pub const MAX_BORROW_FEE_BPS: u64 = 500;
+
+/// Scale of a token's bond rate: bond wei locked per token unit, times 1e18
+pub const BOND_RATE_PRECISION: u64 = 1_000_000_000_000_000_000;
+
+/// Bond locked behind a loan owing `owed` of a token with `bond_rate`. Rounds up.
+pub fn bond_for(owed: U256, bond_rate: U256) -> U256 {
+    (owed * bond_rate).div_ceil(U256::from(BOND_RATE_PRECISION))
+}
+
+/// Shares minted for depositing `amount` into a pool holding `total_assets`
+///
+/// The first deposit mints one share per token unit. Rounds down.
+pub fn shares_for_deposit(amount: U256, total_assets: U256, total_shares: U256) -> U256 {
+    if total_shares == U256::ZERO || total_assets == U256::ZERO {
+        return amount;
+    }
+    amount * total_shares / total_assets
+}
+
+/// Tokens owed for redeeming `shares`. Rounds down.
+pub fn assets_for_shares(shares: U256, total_assets: U256, total_shares: U256) -> U256 {
+    if total_shares == U256::ZERO {
+        return U256::ZERO;
+    }
+    shares * total_assets / total_shares
+}
+
+#[entrypoint]
+#[storage]
+pub struct LiquidityPool {
+    /// Contract owner
+    owner: StorageAddress,
+    /// SolverRegistry deciding who may borrow
+    solver_registry: StorageAddress,
+    /// SettlementVerifier on this chain, whose confirmations trigger repayment
+    settlement_verifier: StorageAddress,
+    /// Borrow fee at zero utilization
+    base_fee_bps: StorageU256,
+    /// Extra borrow fee at full utilization
+    slope_fee_bps: StorageU256,
+    /// Share of each token's liquidity that may be lent out
+    max_utilization_bps: StorageU256,
+    /// Largest loan per intent, by token (zero = not supported)
+    max_per_intent: StorageMap<Address, StorageU256>,
+    /// Idle liquidity, by token
+    available: StorageMap<Address, StorageU256>,
+    /// Principal currently lent out, by token
+    borrowed: StorageMap<Address, StorageU256>,
+    /// Outstanding LP shares, by token
+    total_shares: StorageMap<Address, StorageU256>,
+    /// LP shares, by token then provider
+    shares: StorageMap<Address, StorageMap<Address, StorageU256>>,
+    /// Mapping of intent IDs to the borrowing solver
+    loan_solvers: StorageMap<U256, StorageAddress>,
+    /// Mapping of intent IDs to the borrowed token
+    loan_tokens: StorageMap<U256, StorageAddress>,
+    /// Mapping of intent IDs to the borrowed principal
+    loan_amounts: StorageMap<U256, StorageU256>,
+    /// Mapping of intent IDs to the fee fixed when the loan opened
+    loan_fees: StorageMap<U256, StorageU256>,
+    /// Number of tokens with a non-zero per-intent cap
+    token_count: StorageU256,
+    /// Number of loans not yet repaid
+    open_loans: StorageU256,
+    /// Bond wei locked per token unit owed, scaled by `BOND_RATE_PRECISION`,
+    /// by token (zero = can't be borrowed)
+    bond_rates: StorageMap<Address, StorageU256>,
+    /// Mapping of intent IDs to the borrowing solver's ID
+    loan_solver_ids: StorageMap<U256, StorageU256>,
+    /// Mapping of intent IDs to the solver bond locked behind the loan
+    loan_bonds: StorageMap<U256, StorageU256>,
+}
+
+#[public]
+impl LiquidityPool {
+    /// Initialize the pool
+    pub fn init(&mut self, solver_registry: Address, settlement_verifier: Address) -> Result<(), LiquidityPoolError> {
+        if solver_registry == Address::ZERO || settlement_verifier == Address::ZERO {
+            return Err(LiquidityPoolError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.owner.set(self.vm().msg_sender());
+        self.solver_registry.set(solver_registry);
+        self.settlement_verifier.set(settlement_verifier);
+        self.base_fee_bps.set(U256::from(DEFAULT_BASE_FEE_BPS));
+        self.slope_fee_bps.set(U256::from(DEFAULT_SLOPE_FEE_BPS));
+        self.max_utilization_bps.set(U256::from(DEFAULT_MAX_UTILIZATION_BPS));
+
+        Ok(())
+    }
+
+    /// Support a token and set its per-intent loan cap (admin only)
+    ///
+    /// A zero cap stops new loans; deposits and withdrawals keep working.
+    pub fn set_token(&mut self, token: Address, max_per_intent: U256) -> Result<(), LiquidityPoolError> {
+        self.only_owner()?;
+
+        if token == Address::ZERO {
+            return Err(LiquidityPoolError::InvalidAddress(InvalidAddress {}));
+        }
+
+        let was_supported = self.max_per_intent.get(token) != U256::ZERO;
+        let supported = max_per_intent != U256::ZERO;
+        if supported && !was_supported {
+            self.token_count.set(self.token_count.get() + U256::from(1));
+        } else if was_supported && !supported {
+            self.token_count.set(self.token_count.get() - U256::from(1));
+        }
+        self.max_per_intent.setter(token).set(max_per_intent);
+
+        self.vm().log(TokenConfigured {
+            token,
+            maxPerIntent: max_per_intent,
+        });
+
+        Ok(())
+    }
+
+    /// Set the bond a loan of a token locks per unit owed (admin only)
+    ///
+    /// `bond_rate` is in bond wei per token unit, scaled by
+    /// `BOND_RATE_PRECISION`. A zero rate stops new loans of the token.
+    pub fn set_bond_rate(&mut self, token: Address, bond_rate: U256) -> Result<(), LiquidityPoolError> {
+        self.only_owner()?;
+
+        if token == Address::ZERO {
+            return Err(LiquidityPoolError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.bond_rates.setter(token).set(bond_rate);
+
+        self.vm().log(BondRateSet {
+            token,
+            bondRate: bond_rate,
+        });
+
+        Ok(())
+    }
+
+    /// Set the utilization fee curve (admin only)
+    pub fn set_fee_curve(&mut self, base_fee_bps: U256, slope_fee_bps: U256) -> Result<(), LiquidityPoolError> {
+        self.only_owner()?;
+
+        Param::BorrowBaseFeeBps.check(base_fee_bps).map_err(LiquidityPoolError::ParameterOutOfBounds)?;
+        Param::BorrowSlopeFeeBps.check(slope_fee_bps).map_err(LiquidityPoolError::ParameterOutOfBounds)?;
+        if base_fee_bps + slope_fee_bps > U256::from(MAX_BORROW_FEE_BPS) {
+            return Err(LiquidityPoolError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let (old_base_bps, old_slope_bps) = (self.base_fee_bps.get(), self.slope_fee_bps.get());
+        self.base_fee_bps.set(base_fee_bps);
+        self.slope_fee_bps.set(slope_fee_bps);
+
+        self.vm().log(FeeCurveSet {
+            baseFeeBps: base_fee_bps,
+            slopeFeeBps: slope_fee_bps,
+        });
+        self.vm().log(params::changed(Param::BorrowBaseFeeBps, old_base_bps, base_fee_bps));
+        self.vm().log(params::changed(Param::BorrowSlopeFeeBps, old_slope_bps, slope_fee_bps));
+
+        Ok(())
+    }
+
+    /// Set the share of liquidity that may be lent out (admin only)
+    pub fn set_max_utilization(&mut self, max_utilization_bps: U256) -> Result<(), LiquidityPoolError> {
+        self.only_owner()?;
+
+        if max_utilization_bps > U256::from(BPS_DENOMINATOR) {
+            return Err(LiquidityPoolError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let old_bps = self.max_utilization_bps.get();
+        self.max_utilization_bps.set(max_utilization_bps);
+
+        self.vm().log(MaxUtilizationSet {
+            maxUtilizationBps: max_utilization_bps,
+        });
+        self.vm().log(params::changed(Param::MaxUtilizationBps, old_bps, max_utilization_bps));
+
+        Ok(())
+    }
+
+    /// Set the SettlementVerifier whose confirmations trigger repayment (admin only)
+    pub fn set_settlement_verifier(&mut self, verifier: Address) -> Result<(), LiquidityPoolError> {
+        self.only_owner()?;
+
+        if verifier == Address::ZERO {
+            return Err(LiquidityPoolError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.settlement_verifier.set(verifier);
+
+        self.vm().log(SettlementVerifierSet { verifier });
+
+        Ok(())
+    }
+
+    /// Set the SolverRegistry deciding who may borrow (admin only)
+    pub fn set_solver_registry(&mut self, registry: Address) -> Result<(), LiquidityPoolError> {
+        self.only_owner()?;
+
+        if registry == Address::ZERO {
+            return Err(LiquidityPoolError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.solver_registry.set(registry);
+
+        self.vm().log(SolverRegistrySet { registry });
+
+        Ok(())
+    }
+
+    /// Transfer ownership to a new owner (admin only)
+    pub fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), LiquidityPoolError> {
+        self.only_owner()?;
+
+        if new_owner == Address::ZERO {
+            return Err(LiquidityPoolError::InvalidAddress(InvalidAddress {}));
+        }
+
+        let previous_owner = self.owner.get();
+        self.owner.set(new_owner);
+
+        self.vm().log(OwnershipTransferred {
+            previousOwner: previous_owner,
+            newOwner: new_owner,
+        });
+
+        Ok(())
+    }
+
+    /// Deposit a supported token, returning the LP shares minted
+    pub fn deposit(&mut self, token: Address, amount: U256) -> Result<U256, LiquidityPoolError> {
+        if self.max_per_intent.get(token) == U256::ZERO {
+            return Err(LiquidityPoolError::UnsupportedToken(UnsupportedToken { token }));
+        }
+
+        let minted = shares_for_deposit(amount, self.total_assets(token), self.total_shares.get(token));
+        if minted == U256::ZERO {
+            return Err(LiquidityPoolError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let provider = self.vm().msg_sender();
+        self.pull(token, provider, amount)?;
+
+        self.available.setter(token).set(self.available.get(token) + amount);
+        self.total_shares.setter(token).set(self.total_shares.get(token) + minted);
+        let held = self.shares.getter(token).get(provider);
+        self.shares.setter(token).setter(provider).set(held + minted);
+
+        self.vm().log(Deposited {
+            provider,
+            token,
+            amount,
+            shares: minted,
+        });
+
+        Ok(minted)
+    }
+
+    /// Redeem LP shares for their share of the pool, including accrued fees
+    ///
+    /// Only idle liquidity can be withdrawn; lent-out funds return on repayment.
+    pub fn withdraw(&mut self, token: Address, shares: U256) -> Result<U256, LiquidityPoolError> {
+        let provider = self.vm().msg_sender();
+        let held = self.shares.getter(token).get(provider);
+        if shares == U256::ZERO || shares > held {
+            return Err(LiquidityPoolError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let total_shares = self.total_shares.get(token);
+        let amount = assets_for_shares(shares, self.total_assets(token), total_shares);
+        let available = self.available.get(token);
+        if amount > available {
+            return Err(LiquidityPoolError::InsufficientLiquidity(InsufficientLiquidity {
+                available,
+                requested: amount,
+            }));
+        }
+
+        self.shares.setter(token).setter(provider).set(held - shares);
+        self.total_shares.setter(token).set(total_shares - shares);
+        self.available.setter(token).set(available - amount);
+
+        self.push(token, provider, amount)?;
+
+        self.vm().log(Withdrawn {
+            provider,
+            token,
+            amount,
+            shares,
+        });
+
+        Ok(amount)
+    }
+
+    /// Borrow to fill an intent's destination leg (active solvers only)
+    ///
+    /// The intent must be pending with the verifier, executed by the caller,
+    /// in `token`, to `recipient` and for at least `amount`. Locks the
+    /// caller's bond for the principal plus fee, pays `amount` straight to
+    /// `recipient` and returns the fee, fixed at the utilization after this loan.
+    pub fn borrow(
+        &mut self,
+        intent_id: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+    ) -> Result<U256, LiquidityPoolError> {
+        let solver = self.vm().msg_sender();
+        let registry = ISolverRegistry::new(self.solver_registry.get());
+        let active = registry.is_active_solver(self.vm(), Call::new(), solver).unwrap_or(false);
+        let solver_id = registry.get_solver_id(self.vm(), Call::new(), solver).unwrap_or(U256::ZERO);
+        if !active || solver_id == U256::ZERO {
+            return Err(LiquidityPoolError::NotSolver(NotSolver {}));
+        }
+
+        if intent_id == U256::ZERO || amount == U256::ZERO || recipient == Address::ZERO {
+            return Err(LiquidityPoolError::InvalidAmount(InvalidAmount {}));
+        }
+        if self.loan_solvers.get(intent_id) != Address::ZERO {
+            return Err(LiquidityPoolError::LoanExists(LoanExists { intentId: intent_id }));
+        }
+        self.check_intent_terms(intent_id, solver_id, token, amount, recipient)?;
+
+        let max_per_intent = self.max_per_intent.get(token);
+        if max_per_intent == U256::ZERO {
+            return Err(LiquidityPoolError::UnsupportedToken(UnsupportedToken { token }));
+        }
+        if amount > max_per_intent {
+            return Err(LiquidityPoolError::ExceedsIntentCap(ExceedsIntentCap {
+                amount,
+                maxPerIntent: max_per_intent,
+            }));
+        }
+
+        // Keep a liquidity buffer for LP withdrawals
+        let available = self.available.get(token);
+        let borrowed = self.borrowed.get(token) + amount;
+        let total = available + self.borrowed.get(token);
+        let lendable = fees::fee_for(total, self.max_utilization_bps.get());
+        if amount > available || borrowed > lendable {
+            return Err(LiquidityPoolError::InsufficientLiquidity(InsufficientLiquidity {
+                available: lendable.saturating_sub(self.borrowed.get(token)).min(available),
+                requested: amount,
+            }));
+        }
+
+        let fee = self.borrow_fee(amount, borrowed, total);
+        let bond_rate = self.bond_rates.get(token);
+        if bond_rate == U256::ZERO {
+            return Err(LiquidityPoolError::UnsupportedToken(UnsupportedToken { token }));
+        }
+        let bond = bond_for(amount + fee, bond_rate);
+
+        self.available.setter(token).set(available - amount);
+        self.borrowed.setter(token).set(borrowed);
+        self.loan_solvers.setter(intent_id).set(solver);
+        self.loan_tokens.setter(intent_id).set(token);
+        self.loan_amounts.setter(intent_id).set(amount);
+        self.loan_fees.setter(intent_id).set(fee);
+        self.loan_solver_ids.setter(intent_id).set(solver_id);
+        self.loan_bonds.setter(intent_id).set(bond);
+        self.open_loans.set(self.open_loans.get() + U256::from(1));
+
+        let config = Call::new_mutating(self);
+        ISolverRegistry::new(self.solver_registry.get())
+            .lock_bond(self.vm(), config, solver_id, bond, intent_id)
+            .map_err(|_| LiquidityPoolError::BondUnavailable(BondUnavailable { intentId: intent_id }))?;
+
+        self.push(token, recipient, amount)?;
+
+        self.vm().log(LoanOpened {
+            intentId: intent_id,
+            solver,
+            token,
+            amount,
+            fee,
+            recipient,
+        });
+
+        Ok(fee)
+    }
+
+    /// Repay a loan early from the caller's balance
+    pub fn repay(&mut self, intent_id: U256) -> Result<U256, LiquidityPoolError> {
+        let payer = self.vm().msg_sender();
+        self.close_loan(intent_id, payer)
+    }
+
+    /// Collect a loan from its solver once the intent's settlement is confirmed
+    ///
+    /// Anyone may call this; the solver must have approved the pool for the
+    /// principal plus fee.
+    pub fn settle_loan(&mut self, intent_id: U256) -> Result<U256, LiquidityPoolError> {
+        let solver = self.loan_solvers.get(intent_id);
+        if solver == Address::ZERO {
+            return Err(LiquidityPoolError::NoLoan(NoLoan { intentId: intent_id }));
+        }
+
+        let status = ISettlementVerifier::new(self.settlement_verifier.get())
+            .get_settlement_status(self.vm(), Call::new(), intent_id)
+            .unwrap_or(U256::ZERO);
+        if status != U256::from(SETTLEMENT_CONFIRMED) {
+            return Err(LiquidityPoolError::NotSettled(NotSettled { intentId: intent_id }));
+        }
+
+        self.close_loan(intent_id, solver)
+    }
+
+    /// Write off a loan whose intent failed and seize the bond behind it
+    ///
+    /// Anyone may call this once the verifier reports the intent failed or
+    /// refunded. The principal leaves the pool's assets, so LPs take the
+    /// loss; the seized bond is paid to the owner to make them whole.
+    /// Returns the bond seized.
+    pub fn default_loan(&mut self, intent_id: U256) -> Result<U256, LiquidityPoolError> {
+        if self.loan_solvers.get(intent_id) == Address::ZERO {
+            return Err(LiquidityPoolError::NoLoan(NoLoan { intentId: intent_id }));
+        }
+
+        let status = ISettlementVerifier::new(self.settlement_verifier.get())
+            .get_settlement_status(self.vm(), Call::new(), intent_id)
+            .unwrap_or(U256::ZERO);
+        if status != U256::from(SETTLEMENT_FAILED) && status != U256::from(SETTLEMENT_REFUNDED) {
+            return Err(LiquidityPoolError::NotDefaulted(NotDefaulted { intentId: intent_id }));
+        }
+
+        let token = self.loan_tokens.get(intent_id);
+        let amount = self.loan_amounts.get(intent_id);
+        let solver_id = self.loan_solver_ids.get(intent_id);
+        let bond = self.loan_bonds.get(intent_id);
+        self.clear_loan(intent_id);
+        self.borrowed.setter(token).set(self.borrowed.get(token) - amount);
+
+        let owner = self.owner.get();
+        let config = Call::new_mutating(self);
+        let seized = ISolverRegistry::new(self.solver_registry.get())
+            .seize_bond(self.vm(), config, solver_id, bond, intent_id, owner)
+            .map_err(|_| LiquidityPoolError::BondUnavailable(BondUnavailable { intentId: intent_id }))?;
+
+        self.vm().log(LoanDefaulted {
+            intentId: intent_id,
+            solverId: solver_id,
+            amount,
+            seized,
+        });
+
+        Ok(seized)
+    }
+
+    /// Fee a loan of `amount` would pay right now
+    pub fn quote_borrow_fee(&self, token: Address, amount: U256) -> U256 {
+        let borrowed = self.borrowed.get(token) + amount;
+        self.borrow_fee(amount, borrowed, self.total_assets(token))
+    }
+
+    /// Get a token's idle liquidity, lent-out principal and outstanding shares
+    pub fn get_pool(&self, token: Address) -> (U256, U256, U256) {
+        (
+            self.available.get(token),
+            self.borrowed.get(token),
+            self.total_shares.get(token),
+        )
+    }
+
+    /// Get a token's utilization in basis points
+    pub fn utilization(&self, token: Address) -> U256 {
+        fees::utilization_bps(self.borrowed.get(token), self.total_assets(token))
+    }
+
+    /// Get an LP's shares of a token's pool
+    pub fn shares_of(&self, token: Address, provider: Address) -> U256 {
+        self.shares.getter(token).get(provider)
+    }
+
+    /// Get an intent's loan: solver, token, principal and fee (zero solver if none)
+    pub fn get_loan(&self, intent_id: U256) -> (Address, Address, U256, U256) {
+        (
+            self.loan_solvers.get(intent_id),
+            self.loan_tokens.get(intent_id),
+            self.loan_amounts.get(intent_id),
+            self.loan_fees.get(intent_id),
+        )
+    }
+
+    /// Get the solver ID and bond locked behind an intent's loan
+    pub fn get_loan_bond(&self, intent_id: U256) -> (U256, U256) {
+        (self.loan_solver_ids.get(intent_id), self.loan_bonds.get(intent_id))
+    }
+
+    /// Get a token's bond rate (zero if it can't be borrowed)
+    pub fn bond_rate(&self, token: Address) -> U256 {
+        self.bond_rates.get(token)
+    }
+
+    /// Get a token's per-intent loan cap (zero if unsupported)
+    pub fn max_per_intent(&self, token: Address) -> U256 {
+        self.max_per_intent.get(token)
+    }
+
+    /// Get the fee curve as (base, slope) in basis points
+    pub fn fee_curve(&self) -> (U256, U256) {
+        (self.base_fee_bps.get(), self.slope_fee_bps.get())
+    }
+
+    /// Get the maximum utilization in basis points
+    pub fn max_utilization(&self) -> U256 {
+        self.max_utilization_bps.get()
+    }
+
+    /// ERC-165: whether this contract implements `interface_id`
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        erc165::supports(interface_id.0, &[])
+    }
+
+    /// Get contract owner
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Get the pool's ABI-encoded `ProtocolStatus`, counting supported tokens and open loans
+    pub fn get_protocol_status(&self) -> Bytes {
+        let mut status =
+            ProtocolStatus::new(StatusContract::LiquidityPool, env!("CARGO_PKG_VERSION"), self.owner.get());
+        status.counters = [self.token_count.get(), self.open_loans.get()].into();
+        Bytes::from(encode_protocol_status(&status))
+    }
+
+    /// Internal: Idle plus lent-out liquidity of a token
+    fn total_assets(&self, token: Address) -> U256 {
+        self.available.get(token) + self.borrowed.get(token)
+    }
+
+    /// Internal: Fee on `amount` at the utilization it leaves behind
+    fn borrow_fee(&self, amount: U256, borrowed: U256, total: U256) -> U256 {
+        let utilization = fees::utilization_bps(borrowed, total).min(U256::from(BPS_DENOMINATOR));
+        let fee_bps = fees::utilization_fee_bps(self.base_fee_bps.get(), self.slope_fee_bps.get(), utilization);
+        fees::fee_for(amount, fee_bps)
+    }
+
+    /// Internal: Check that a loan matches a pending intent the solver executed
+    fn check_intent_terms(
+        &self,
+        intent_id: U256,
+        solver_id: U256,
+        token: Address,
+        amount: U256,
+        recipient: Address,
+    ) -> Result<(), LiquidityPoolError> {
+        let verifier = ISettlementVerifier::new(self.settlement_verifier.get());
+        let status = verifier.get_settlement_status(self.vm(), Call::new(), intent_id);
+        if status.ok() != Some(U256::from(SETTLEMENT_PENDING)) {
+            return Err(LiquidityPoolError::IntentNotPending(IntentNotPending { intentId: intent_id }));
+        }
+
+        let (intent_solver, intent_token, notional, intent_recipient) = verifier
+            .get_solver_intent(self.vm(), Call::new(), intent_id)
+            .map_err(|_| LiquidityPoolError::IntentNotPending(IntentNotPending { intentId: intent_id }))?;
+        if intent_solver == U256::ZERO || intent_token == Address::ZERO {
+            return Err(LiquidityPoolError::IntentNotPending(IntentNotPending { intentId: intent_id }));
+        }
+        if intent_solver != solver_id || intent_token != token || amount > notional || intent_recipient != recipient {
+            return Err(LiquidityPoolError::IntentMismatch(IntentMismatch { intentId: intent_id }));
+        }
+        Ok(())
+    }
+
+    /// Internal: Take principal plus fee from `payer`, close the loan and unlock its bond
+    fn close_loan(&mut self, intent_id: U256, payer: Address) -> Result<U256, LiquidityPoolError> {
+        let solver = self.loan_solvers.get(intent_id);
+        if solver == Address::ZERO {
+            return Err(LiquidityPoolError::NoLoan(NoLoan { intentId: intent_id }));
+        }
+
+        let token = self.loan_tokens.get(intent_id);
+        let amount = self.loan_amounts.get(intent_id);
+        let fee = self.loan_fees.get(intent_id);
+        let solver_id = self.loan_solver_ids.get(intent_id);
+        let bond = self.loan_bonds.get(intent_id);
+        self.clear_loan(intent_id);
+
+        self.pull(token, payer, amount + fee)?;
+
+        // The fee joins idle liquidity, raising the value of every share
+        self.borrowed.setter(token).set(self.borrowed.get(token) - amount);
+        self.available.setter(token).set(self.available.get(token) + amount + fee);
+
+        let config = Call::new_mutating(self);
+        ISolverRegistry::new(self.solver_registry.get())
+            .unlock_bond(self.vm(), config, solver_id, bond, intent_id)
+            .map_err(|_| LiquidityPoolError::BondUnavailable(BondUnavailable { intentId: intent_id }))?;
+
+        self.vm().log(LoanRepaid {
+            intentId: intent_id,
+            payer,
+            amount,
+            fee,
+        });
+
+        Ok(amount + fee)
+    }
+
+    /// Internal: Delete a loan's record and stop counting it as open
+    fn clear_loan(&mut self, intent_id: U256) {
+        self.loan_solvers.setter(intent_id).set(Address::ZERO);
+        self.loan_tokens.setter(intent_id).set(Address::ZERO);
+        self.loan_amounts.setter(intent_id).set(U256::ZERO);
+        self.loan_fees.setter(intent_id).set(U256::ZERO);
+        self.loan_solver_ids.setter(intent_id).set(U256::ZERO);
+        self.loan_bonds.setter(intent_id).set(U256::ZERO);
+        self.open_loans.set(self.open_loans.get() - U256::from(1));
+    }
+
+    /// Internal: Transfer tokens from `from` into the pool
+    fn pull(&mut self, token: Address, from: Address, amount: U256) -> Result<(), LiquidityPoolError> {
+        let this = self.vm().contract_address();
+        let config = Call::new_mutating(self);
+        let pulled = IERC20::new(token)
+            .transfer_from(self.vm(), config, from, this, amount)
+            .map_err(|_| LiquidityPoolError::TransferFailed(TransferFailed {}))?;
+        if !pulled {
+            return Err(LiquidityPoolError::TransferFailed(TransferFailed {}));
+        }
+        Ok(())
+    }
+
+    /// Internal: Transfer tokens out of the pool
+    fn push(&mut self, token: Address, to: Address, amount: U256) -> Result<(), LiquidityPoolError> {
+        let config = Call::new_mutating(self);
+        let sent = IERC20::new(token)
+            .transfer(self.vm(), config, to, amount)
+            .map_err(|_| LiquidityPoolError::TransferFailed(TransferFailed {}))?;
+        if !sent {
+            return Err(LiquidityPoolError::TransferFailed(TransferFailed {}));
+        }
+        Ok(())
+    }
+
+    /// Internal: Check if caller is owner
+    fn only_owner(&self) -> Result<(), LiquidityPoolError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(LiquidityPoolError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+}
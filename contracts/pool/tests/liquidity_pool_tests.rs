@@ -0,0 +1,430 @@
+use alloy_sol_types::{sol, SolCall, SolValue};
+use stylus_sdk::alloy_primitives::{Address, U256};
+use swoosh_common::status::{decode_protocol_status, StatusContract};
+use swoosh_pool::*;
+use swoosh_test_utils::TestEnv;
+
+// Calls the pool makes, to mock them
+sol! {
+    function transfer(address to, uint256 amount) external returns (bool);
+    function transferFrom(address from, address to, uint256 amount) external returns (bool);
+    function isActiveSolver(address solver) external view returns (bool);
+    function getSolverId(address solver) external view returns (uint256);
+    function lockBond(uint256 solver_id, uint256 amount, uint256 intent_id) external;
+    function unlockBond(uint256 solver_id, uint256 amount, uint256 intent_id) external;
+    function seizeBond(uint256 solver_id, uint256 amount, uint256 intent_id, address to) external returns (uint256);
+    function getSettlementStatus(uint256 intent_id) external view returns (uint256);
+    function getSolverIntent(uint256 intent_id) external view returns (uint256, address, uint256, address);
+}
+
+#[cfg(test)]
+mod liquidity_pool_tests {
+    use super::*;
+
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    fn pool_address() -> Address {
+        test_address(0xee)
+    }
+
+    fn token() -> Address {
+        test_address(0x10)
+    }
+
+    fn recipient() -> Address {
+        test_address(0x30)
+    }
+
+    // Owner 1, registry 2, verifier 3, deployed at 0xee
+    fn setup(env: &TestEnv) -> LiquidityPool {
+        env.vm().set_contract_address(pool_address());
+        let mut pool: LiquidityPool = env.deploy();
+        env.set_sender(test_address(1));
+        assert!(pool.init(test_address(2), test_address(3)).is_ok(), "Init");
+        pool
+    }
+
+    #[test]
+    fn test_first_deposit_mints_one_to_one() {
+        // An empty pool mints a share per token unit
+        let amount = U256::from(1_000u64);
+        assert_eq!(shares_for_deposit(amount, U256::ZERO, U256::ZERO), amount, "One to one");
+    }
+
+    #[test]
+    fn test_fees_raise_share_value() {
+        // 1000 shares backed by 1100 tokens after fees were repaid
+        let total_assets = U256::from(1_100u64);
+        let total_shares = U256::from(1_000u64);
+
+        assert_eq!(
+            assets_for_shares(U256::from(100u64), total_assets, total_shares),
+            U256::from(110u64),
+            "Share worth more than it cost"
+        );
+        assert_eq!(
+            shares_for_deposit(U256::from(110u64), total_assets, total_shares),
+            U256::from(100u64),
+            "Late LP buys in at the higher price"
+        );
+    }
+
+    #[test]
+    fn test_share_math_rounds_down() {
+        // Rounding favours the pool, not the caller
+        let total_assets = U256::from(3u64);
+        let total_shares = U256::from(2u64);
+
+        assert_eq!(shares_for_deposit(U256::from(1u64), total_assets, total_shares), U256::ZERO, "Dust deposit");
+        assert_eq!(assets_for_shares(U256::from(1u64), total_assets, total_shares), U256::from(1u64), "1.5 rounded down");
+    }
+
+    #[test]
+    fn test_deposit_rejects_unsupported_token() {
+        // Tokens need a per-intent cap before LPs can deposit
+        let env = TestEnv::new();
+        let mut pool = setup(&env);
+
+        env.set_sender(test_address(7));
+        assert!(
+            matches!(
+                pool.deposit(test_address(9), U256::from(1_000u64)),
+                Err(LiquidityPoolError::UnsupportedToken(_))
+            ),
+            "Unsupported token"
+        );
+    }
+
+    #[test]
+    fn test_fee_curve_capped() {
+        // The curve can't exceed the maximum borrow fee
+        let env = TestEnv::new();
+        let mut pool = setup(&env);
+
+        assert!(
+            pool.set_fee_curve(U256::from(100u64), U256::from(MAX_BORROW_FEE_BPS)).is_err(),
+            "Curve above cap"
+        );
+        assert!(pool.set_fee_curve(U256::from(10u64), U256::from(90u64)).is_ok(), "Curve within cap");
+        assert_eq!(pool.fee_curve(), (U256::from(10u64), U256::from(90u64)), "Curve stored");
+    }
+
+    #[test]
+    fn test_admin_only_token_config() {
+        // Only the owner lists tokens
+        let env = TestEnv::new();
+        let mut pool = setup(&env);
+
+        env.set_sender(test_address(7));
+        assert!(
+            matches!(
+                pool.set_token(test_address(9), U256::from(1_000u64)),
+                Err(LiquidityPoolError::Unauthorized(_))
+            ),
+            "Not owner"
+        );
+        assert_eq!(pool.max_per_intent(test_address(9)), U256::ZERO, "Still unsupported");
+    }
+
+    #[test]
+    fn test_quote_on_idle_pool_is_base_fee() {
+        // With no liquidity utilization is zero and only the base fee applies
+        let env = TestEnv::new();
+        let pool = setup(&env);
+
+        assert_eq!(pool.quote_borrow_fee(test_address(9), U256::from(1_000_000u64)), U256::from(500u64), "5 bps");
+        assert_eq!(pool.utilization(test_address(9)), U256::ZERO, "Idle");
+    }
+
+    #[test]
+    fn test_protocol_status() {
+        // Supported tokens count once each and drop out when their cap goes to zero
+        let env = TestEnv::new();
+        let mut pool = setup(&env);
+        assert!(pool.set_token(test_address(8), U256::from(1_000u64)).is_ok(), "First token");
+        assert!(pool.set_token(test_address(9), U256::from(1_000u64)).is_ok(), "Second token");
+        assert!(pool.set_token(test_address(9), U256::from(2_000u64)).is_ok(), "Cap raised");
+        assert!(pool.set_token(test_address(8), U256::ZERO).is_ok(), "First token delisted");
+
+        let status = decode_protocol_status(&pool.get_protocol_status()).expect("Status decodes");
+        assert_eq!(StatusContract::from_u8(status.kind), Some(StatusContract::LiquidityPool), "Kind");
+        assert_eq!(status.owner, test_address(1), "Owner");
+        assert!(!status.paused, "Not pausable");
+        assert_eq!(status.counters, vec![U256::from(1u64), U256::ZERO], "One token, no loans");
+    }
+
+    // Token 0x10 capped at 1000 per intent with a 1:1 bond rate; LP 7 deposits 10,000
+    fn funded_pool(env: &TestEnv) -> LiquidityPool {
+        let mut pool = setup(env);
+        assert!(pool.set_token(token(), U256::from(1_000u64)).is_ok(), "Token listed");
+        assert!(pool.set_bond_rate(token(), U256::from(BOND_RATE_PRECISION)).is_ok(), "Bond rate");
+
+        let deposit = U256::from(10_000u64);
+        let pull = transferFromCall { from: test_address(7), to: pool_address(), amount: deposit };
+        env.vm().mock_call(token(), pull.abi_encode(), U256::ZERO, Ok(true.abi_encode()));
+        env.set_sender(test_address(7));
+        assert!(pool.deposit(token(), deposit).is_ok(), "Deposited");
+
+        // Registry 2 knows caller 6 as active solver 1
+        let active = isActiveSolverCall { solver: test_address(6) }.abi_encode();
+        env.vm().mock_static_call(test_address(2), active, Ok(true.abi_encode()));
+        let solver_id = getSolverIdCall { solver: test_address(6) }.abi_encode();
+        env.vm().mock_static_call(test_address(2), solver_id, Ok(U256::from(1u64).abi_encode()));
+        env.set_sender(test_address(6));
+        pool
+    }
+
+    // Verifier 3 reports `status` for solver 1's intent moving `notional` of token 0x10 to 0x30
+    fn mock_intent(env: &TestEnv, intent_id: u64, status: u64, notional: u64) {
+        let intent_id = U256::from(intent_id);
+        let calldata = getSettlementStatusCall { intent_id }.abi_encode();
+        env.vm().mock_static_call(test_address(3), calldata, Ok(U256::from(status).abi_encode()));
+        let terms = (U256::from(1u64), token(), U256::from(notional), recipient()).abi_encode_params();
+        let calldata = getSolverIntentCall { intent_id }.abi_encode();
+        env.vm().mock_static_call(test_address(3), calldata, Ok(terms));
+    }
+
+    // Borrow `amount` for an intent, mocking the bond lock and the payout; returns the fee and bond
+    fn borrow(env: &TestEnv, pool: &mut LiquidityPool, intent_id: u64, amount: u64) -> (U256, U256) {
+        let (intent_id, amount) = (U256::from(intent_id), U256::from(amount));
+        let fee = pool.quote_borrow_fee(token(), amount);
+        let bond = bond_for(amount + fee, U256::from(BOND_RATE_PRECISION));
+        let lock = lockBondCall { solver_id: U256::from(1u64), amount: bond, intent_id };
+        env.vm().mock_call(test_address(2), lock.abi_encode(), U256::ZERO, Ok(Vec::new()));
+        let payout = transferCall { to: recipient(), amount };
+        env.vm().mock_call(token(), payout.abi_encode(), U256::ZERO, Ok(true.abi_encode()));
+
+        assert_eq!(pool.borrow(intent_id, token(), amount, recipient()).ok(), Some(fee), "Borrowed");
+        (fee, bond)
+    }
+
+    // Mock solver 6 repaying `owed` and the bond unlock
+    fn mock_repayment(env: &TestEnv, intent_id: u64, owed: U256, bond: U256) {
+        let pull = transferFromCall { from: test_address(6), to: pool_address(), amount: owed };
+        env.vm().mock_call(token(), pull.abi_encode(), U256::ZERO, Ok(true.abi_encode()));
+        let unlock = unlockBondCall { solver_id: U256::from(1u64), amount: bond, intent_id: U256::from(intent_id) };
+        env.vm().mock_call(test_address(2), unlock.abi_encode(), U256::ZERO, Ok(Vec::new()));
+    }
+
+    // Open loans, as counted in the pool's status
+    fn open_loans(pool: &LiquidityPool) -> U256 {
+        decode_protocol_status(&pool.get_protocol_status()).expect("Status decodes").counters[1]
+    }
+
+    #[test]
+    fn test_bond_rounds_up() {
+        // A loan never locks less bond than its rate asks for
+        let half = U256::from(BOND_RATE_PRECISION / 2);
+        assert_eq!(bond_for(U256::from(3u64), half), U256::from(2u64), "1.5 rounded up");
+        assert_eq!(bond_for(U256::from(4u64), half), U256::from(2u64), "Exact");
+    }
+
+    #[test]
+    fn test_borrow_requires_pending_intent() {
+        // Loans only fill intents the verifier still holds as pending
+        let env = TestEnv::new();
+        let mut pool = funded_pool(&env);
+        let amount = U256::from(500u64);
+
+        mock_intent(&env, 1, 1, 1_000);
+        assert!(
+            matches!(
+                pool.borrow(U256::from(1u64), token(), amount, recipient()),
+                Err(LiquidityPoolError::IntentNotPending(_))
+            ),
+            "Already confirmed"
+        );
+
+        // Pending but never registered to a solver
+        let status = getSettlementStatusCall { intent_id: U256::from(2u64) }.abi_encode();
+        env.vm().mock_static_call(test_address(3), status, Ok(U256::ZERO.abi_encode()));
+        let terms = (U256::ZERO, Address::ZERO, U256::ZERO, Address::ZERO).abi_encode_params();
+        let calldata = getSolverIntentCall { intent_id: U256::from(2u64) }.abi_encode();
+        env.vm().mock_static_call(test_address(3), calldata, Ok(terms));
+        assert!(
+            matches!(
+                pool.borrow(U256::from(2u64), token(), amount, recipient()),
+                Err(LiquidityPoolError::IntentNotPending(_))
+            ),
+            "Unregistered intent"
+        );
+        assert_eq!(open_loans(&pool), U256::ZERO, "No loan opened");
+    }
+
+    #[test]
+    fn test_borrow_must_match_intent() {
+        // Token, recipient, amount and solver all have to match the intent
+        let env = TestEnv::new();
+        let mut pool = funded_pool(&env);
+        let intent_id = U256::from(1u64);
+        mock_intent(&env, 1, 0, 400);
+
+        let cases = [
+            (test_address(0x11), U256::from(400u64), recipient(), "Other token"),
+            (token(), U256::from(401u64), recipient(), "More than the intent moves"),
+            (token(), U256::from(400u64), test_address(0x31), "Other recipient"),
+        ];
+        for (loan_token, amount, to, reason) in cases {
+            assert!(
+                matches!(pool.borrow(intent_id, loan_token, amount, to), Err(LiquidityPoolError::IntentMismatch(_))),
+                "{reason}"
+            );
+        }
+
+        // Solver 2 can't borrow against solver 1's intent
+        let solver_id = getSolverIdCall { solver: test_address(6) }.abi_encode();
+        env.vm().mock_static_call(test_address(2), solver_id, Ok(U256::from(2u64).abi_encode()));
+        assert!(
+            matches!(
+                pool.borrow(intent_id, token(), U256::from(400u64), recipient()),
+                Err(LiquidityPoolError::IntentMismatch(_))
+            ),
+            "Other solver"
+        );
+    }
+
+    #[test]
+    fn test_borrow_cap_and_utilization() {
+        // Loans respect the per-intent cap and leave the utilization buffer
+        let env = TestEnv::new();
+        let mut pool = funded_pool(&env);
+        mock_intent(&env, 1, 0, 20_000);
+
+        assert!(
+            matches!(
+                pool.borrow(U256::from(1u64), token(), U256::from(1_001u64), recipient()),
+                Err(LiquidityPoolError::ExceedsIntentCap(_))
+            ),
+            "Above the cap"
+        );
+
+        env.set_sender(test_address(1));
+        assert!(pool.set_token(token(), U256::from(20_000u64)).is_ok(), "Cap raised");
+        env.set_sender(test_address(6));
+        assert!(
+            matches!(
+                pool.borrow(U256::from(1u64), token(), U256::from(9_001u64), recipient()),
+                Err(LiquidityPoolError::InsufficientLiquidity(_))
+            ),
+            "Past 90% utilization"
+        );
+        borrow(&env, &mut pool, 1, 9_000);
+        assert_eq!(pool.utilization(token()), U256::from(9_000u64), "At the limit");
+    }
+
+    #[test]
+    fn test_borrow_locks_bond_once() {
+        // A loan locks the solver's bond, and an intent only gets one loan
+        let env = TestEnv::new();
+        let mut pool = funded_pool(&env);
+        mock_intent(&env, 1, 0, 1_000);
+
+        let (fee, bond) = borrow(&env, &mut pool, 1, 1_000);
+        assert!(fee > U256::ZERO, "Fee charged");
+        assert_eq!(bond, U256::from(1_000u64) + fee, "Bond covers principal and fee");
+        assert_eq!(pool.get_loan_bond(U256::from(1u64)), (U256::from(1u64), bond), "Bond recorded");
+        assert_eq!(open_loans(&pool), U256::from(1u64), "One open loan");
+        assert_eq!(
+            pool.get_loan(U256::from(1u64)),
+            (test_address(6), token(), U256::from(1_000u64), fee),
+            "Loan recorded"
+        );
+        assert!(
+            matches!(
+                pool.borrow(U256::from(1u64), token(), U256::from(1_000u64), recipient()),
+                Err(LiquidityPoolError::LoanExists(_))
+            ),
+            "Second loan"
+        );
+
+        // Without enough free bond the registry refuses the lock
+        mock_intent(&env, 2, 0, 1_000);
+        let amount = U256::from(500u64);
+        let bond = bond_for(amount + pool.quote_borrow_fee(token(), amount), U256::from(BOND_RATE_PRECISION));
+        let lock = lockBondCall { solver_id: U256::from(1u64), amount: bond, intent_id: U256::from(2u64) };
+        env.vm().mock_call(test_address(2), lock.abi_encode(), U256::ZERO, Err(Vec::new()));
+        assert!(
+            matches!(
+                pool.borrow(U256::from(2u64), token(), amount, recipient()),
+                Err(LiquidityPoolError::BondUnavailable(_))
+            ),
+            "Bond not locked"
+        );
+    }
+
+    #[test]
+    fn test_settle_loan_after_confirmation() {
+        // Settlement pulls principal plus fee back and unlocks the bond
+        let env = TestEnv::new();
+        let mut pool = funded_pool(&env);
+        mock_intent(&env, 1, 0, 1_000);
+        let (fee, bond) = borrow(&env, &mut pool, 1, 1_000);
+
+        env.set_sender(test_address(9));
+        assert!(
+            matches!(pool.settle_loan(U256::from(1u64)), Err(LiquidityPoolError::NotSettled(_))),
+            "Still pending"
+        );
+
+        let owed = U256::from(1_000u64) + fee;
+        mock_intent(&env, 1, 1, 1_000);
+        mock_repayment(&env, 1, owed, bond);
+        assert_eq!(pool.settle_loan(U256::from(1u64)).ok(), Some(owed), "Settled");
+        assert_eq!(
+            pool.get_pool(token()),
+            (U256::from(10_000u64) + fee, U256::ZERO, U256::from(10_000u64)),
+            "Fee kept for LPs"
+        );
+        assert!(
+            matches!(pool.settle_loan(U256::from(1u64)), Err(LiquidityPoolError::NoLoan(_))),
+            "Already settled"
+        );
+    }
+
+    #[test]
+    fn test_repay_early() {
+        // The solver can repay before the verifier confirms
+        let env = TestEnv::new();
+        let mut pool = funded_pool(&env);
+        mock_intent(&env, 1, 0, 1_000);
+        let (fee, bond) = borrow(&env, &mut pool, 1, 1_000);
+
+        let owed = U256::from(1_000u64) + fee;
+        mock_repayment(&env, 1, owed, bond);
+        assert_eq!(pool.repay(U256::from(1u64)).ok(), Some(owed), "Repaid");
+        assert_eq!(pool.get_loan_bond(U256::from(1u64)), (U256::ZERO, U256::ZERO), "Bond released");
+        assert_eq!(open_loans(&pool), U256::ZERO, "No open loans");
+    }
+
+    #[test]
+    fn test_default_loan_seizes_bond() {
+        // A failed intent writes the loan off and seizes the bond for the owner
+        let env = TestEnv::new();
+        let mut pool = funded_pool(&env);
+        mock_intent(&env, 1, 0, 1_000);
+        let (_, bond) = borrow(&env, &mut pool, 1, 1_000);
+
+        env.set_sender(test_address(9));
+        assert!(
+            matches!(pool.default_loan(U256::from(1u64)), Err(LiquidityPoolError::NotDefaulted(_))),
+            "Still pending"
+        );
+
+        mock_intent(&env, 1, 2, 1_000);
+        let (solver_id, intent_id) = (U256::from(1u64), U256::from(1u64));
+        let seize = seizeBondCall { solver_id, amount: bond, intent_id, to: test_address(1) };
+        env.vm().mock_call(test_address(2), seize.abi_encode(), U256::ZERO, Ok(bond.abi_encode()));
+        assert_eq!(pool.default_loan(U256::from(1u64)).ok(), Some(bond), "Defaulted");
+        assert_eq!(
+            pool.get_pool(token()),
+            (U256::from(9_000u64), U256::ZERO, U256::from(10_000u64)),
+            "Principal written off"
+        );
+        assert!(
+            matches!(pool.default_loan(U256::from(1u64)), Err(LiquidityPoolError::NoLoan(_))),
+            "Already defaulted"
+        );
+    }
+}
@@ -0,0 +1,851 @@
+//! SwooshReceiver Contract
+//!
+//! Destination-side entry point for bridged intents, deployed on each chain in
+//! place of the full RouteExecutor. An allowlisted lane (the destination
+//! bridge adapter or messenger for a source chain) hands over the bridged
+//! tokens together with a `ReceiverPayload`; the receiver performs the
+//! optional destination swap, delivers to the recipient and reports the fill
+//! to SettlementVerifier.
+//!
+//! A failed destination swap never strands funds: the bridged token is
+//! delivered to the recipient as-is and the fill is reported as a
+//! `PartialDelivery` instead of reverting the message. The swap is logged as
+//! step 0 of the destination leg in `RouteStepExecuted`, with `success`
+//! false when it fell back.
+//!
+//! Recipients that can't take a push transfer (blocked addresses, tokens that
+//! revert) are credited a claimable balance instead, reported as
+//! `DeliveredToClaim`. Balances left unclaimed past the claim period can be
+//! swept by anyone to the refund address, where the refund path picks them up.
+//!
+//! Dust beyond the outstanding claims can be swept by the owner to the
+//! treasury, optionally converted through an allowlisted swap adapter first.
+//!
+//! A lane can also hand over a settlement batch carrying several intents'
+//! deliveries in one message, amortizing its messaging fee. Entries are
+//! delivered one by one and reported to SettlementVerifier in a single call;
+//! an entry that is malformed, repeats an intent, was already settled or
+//! whose tokens the lane didn't hand over is skipped, with its tokens left
+//! with the lane, while the rest of the batch goes through.
+//!
+//! Tokens are pulled from the calling lane, so the receiver only ever spends
+//! what was handed to it in the same call. Swap output is checked through the
+//! recipient's balance delta rather than the adapter's return value.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use alloy_sol_types::sol;
+use stylus_sdk::{
+    alloy_primitives::{Address, Bytes, FixedBytes, U256},
+    call::Call,
+    crypto::keccak,
+    prelude::*,
+    storage::{StorageAddress, StorageBool, StorageMap, StorageU256},
+};
+
+use swoosh_common::bridge_adapter::ISettlementVerifier;
+use swoosh_common::encoding::{
+    decode_settlement_batch, settlement_batch_entry_id, BatchEntryFailure, DeliveryStatus, ReceiverPayload, StepType,
+};
+use swoosh_common::erc165::{self, IERC165};
+use swoosh_common::events::{ClaimCredited, DeliveryCompleted, RouteStepExecuted, SwapFallback};
+use swoosh_common::params::{self, Param, ParameterOutOfBounds};
+use swoosh_common::payload_codec::{self, PayloadError};
+use swoosh_common::status::{encode_protocol_status, ProtocolStatus, StatusContract};
+use swoosh_common::swap_adapter::ISwapAdapter;
+
+// Events and errors
+sol! {
+    event LaneSet(uint256 indexed sourceChain, address indexed messenger, bool enabled);
+    event SwapAdapterSet(address indexed adapter, bool enabled);
+    event SettlementVerifierSet(address verifier);
+    event Claimed(address indexed recipient, address indexed token, uint256 amount);
+    event ClaimSwept(address indexed recipient, address indexed token, uint256 amount, address refundAddress);
+    event ClaimPeriodSet(uint256 claimPeriod);
+    event RefundAddressSet(address refundAddress);
+    event TreasurySet(address treasury);
+    event DustThresholdSet(address indexed token, uint256 threshold);
+    event DustSwept(
+        address indexed token,
+        uint256 amount,
+        address indexed tokenOut,
+        uint256 amountOut,
+        address treasury
+    );
+    event BatchReceived(bytes32 indexed batchId, uint256 indexed sourceChain, uint256 entries, uint256 delivered);
+    event BatchEntrySkipped(bytes32 indexed batchId, uint256 index, uint256 indexed intentId, uint8 reason);
+
+    error Unauthorized();
+    error InvalidAddress();
+    error UntrustedLane(uint256 sourceChain, address messenger);
+    error InvalidPayload();
+    error MalformedPayload(uint8 reason);
+    error AlreadyProcessed();
+    error TransferFailed();
+    error SlippageExceeded(uint256 amountOut, uint256 minAmountOut);
+    error ReentrancyGuard();
+    error VerifierCallFailed();
+    error NothingToClaim();
+    error ClaimNotExpired(uint256 expiresAt);
+    error DustBelowThreshold(uint256 dust, uint256 threshold);
+    error InterfaceNotSupported(address target, bytes4 interfaceId);
+}
+
+/// Error types for SwooshReceiver
+#[derive(SolidityError)]
+pub enum SwooshReceiverError {
+    Unauthorized(Unauthorized),
+    InvalidAddress(InvalidAddress),
+    UntrustedLane(UntrustedLane),
+    InvalidPayload(InvalidPayload),
+    MalformedPayload(MalformedPayload),
+    AlreadyProcessed(AlreadyProcessed),
+    TransferFailed(TransferFailed),
+    SlippageExceeded(SlippageExceeded),
+    ReentrancyGuard(ReentrancyGuard),
+    VerifierCallFailed(VerifierCallFailed),
+    NothingToClaim(NothingToClaim),
+    ClaimNotExpired(ClaimNotExpired),
+    DustBelowThreshold(DustBelowThreshold),
+    InterfaceNotSupported(InterfaceNotSupported),
+    ParameterOutOfBounds(ParameterOutOfBounds),
+}
+
+impl From<PayloadError> for SwooshReceiverError {
+    fn from(err: PayloadError) -> Self {
+        SwooshReceiverError::MalformedPayload(MalformedPayload { reason: err.code() })
+    }
+}
+
+// ERC20 interface
+sol_interface! {
+    interface IERC20 {
+        function transferFrom(address from, address to, uint256 amount) external returns (bool);
+        function transfer(address to, uint256 amount) external returns (bool);
+        function approve(address spender, uint256 amount) external returns (bool);
+        function balanceOf(address account) external view returns (uint256);
+    }
+}
+
+/// Default time a recipient has to claim before the balance can be swept (30 days)
+const DEFAULT_CLAIM_PERIOD: u64 = 30 * 24 * 60 * 60;
+
+#[entrypoint]
+#[storage]
+pub struct SwooshReceiver {
+    /// Contract owner
+    owner: StorageAddress,
+    /// SettlementVerifier notified of every delivery
+    settlement_verifier: StorageAddress,
+    /// Mapping of source chain -> messenger -> allowed to deliver
+    lanes: StorageMap<U256, StorageMap<Address, StorageBool>>,
+    /// Swap adapters allowed for destination swaps
+    swap_adapters: StorageMap<Address, StorageBool>,
+    /// Processed messages (by payload hash)
+    processed: StorageMap<FixedBytes<32>, StorageBool>,
+    /// Reentrancy guard
+    locked: StorageBool,
+    /// Mapping of recipient -> token -> balance awaiting claim
+    claimable: StorageMap<Address, StorageMap<Address, StorageU256>>,
+    /// Mapping of recipient -> token -> time the claimable balance can be swept
+    claim_expiries: StorageMap<Address, StorageMap<Address, StorageU256>>,
+    /// Seconds a recipient has to claim after the latest credit
+    claim_period: StorageU256,
+    /// Where expired claimable balances are swept for refunding
+    refund_address: StorageAddress,
+    /// Total claimable balance per token
+    claimable_totals: StorageMap<Address, StorageU256>,
+    /// Smallest dust balance of a token worth sweeping (zero = never swept)
+    dust_thresholds: StorageMap<Address, StorageU256>,
+    /// Where swept dust is sent
+    treasury: StorageAddress,
+    /// Mapping of settlement batch ID -> bitmap of the entries delivered
+    batch_deliveries: StorageMap<FixedBytes<32>, StorageU256>,
+}
+
+#[public]
+impl SwooshReceiver {
+    /// Initialize the receiver
+    pub fn init(&mut self, settlement_verifier: Address) -> Result<(), SwooshReceiverError> {
+        if settlement_verifier == Address::ZERO {
+            return Err(SwooshReceiverError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.owner.set(self.vm().msg_sender());
+        self.settlement_verifier.set(settlement_verifier);
+        self.refund_address.set(self.vm().msg_sender());
+        self.claim_period.set(U256::from(DEFAULT_CLAIM_PERIOD));
+
+        Ok(())
+    }
+
+    /// Allow or revoke a messenger delivering from a source chain (admin only)
+    pub fn set_lane(
+        &mut self,
+        source_chain: U256,
+        messenger: Address,
+        enabled: bool,
+    ) -> Result<(), SwooshReceiverError> {
+        self.only_owner()?;
+        if messenger == Address::ZERO {
+            return Err(SwooshReceiverError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.lanes.setter(source_chain).setter(messenger).set(enabled);
+
+        self.vm().log(LaneSet { sourceChain: source_chain, messenger, enabled });
+
+        Ok(())
+    }
+
+    /// Allow or revoke a swap adapter for destination swaps (admin only)
+    pub fn set_swap_adapter(&mut self, adapter: Address, enabled: bool) -> Result<(), SwooshReceiverError> {
+        self.only_owner()?;
+        if enabled {
+            self.require_interface(adapter, erc165::SWAP_ADAPTER_ID)?;
+        }
+        self.swap_adapters.setter(adapter).set(enabled);
+
+        self.vm().log(SwapAdapterSet { adapter, enabled });
+
+        Ok(())
+    }
+
+    /// Set the SettlementVerifier notified of deliveries (admin only)
+    pub fn set_settlement_verifier(&mut self, verifier: Address) -> Result<(), SwooshReceiverError> {
+        self.only_owner()?;
+        if verifier == Address::ZERO {
+            return Err(SwooshReceiverError::InvalidAddress(InvalidAddress {}));
+        }
+        self.settlement_verifier.set(verifier);
+
+        self.vm().log(SettlementVerifierSet { verifier });
+
+        Ok(())
+    }
+
+    /// Set how long recipients have to claim before a sweep (admin only)
+    pub fn set_claim_period(&mut self, claim_period: U256) -> Result<(), SwooshReceiverError> {
+        self.only_owner()?;
+        Param::ClaimPeriod.check(claim_period).map_err(SwooshReceiverError::ParameterOutOfBounds)?;
+
+        let old_period = self.claim_period.get();
+        self.claim_period.set(claim_period);
+
+        self.vm().log(ClaimPeriodSet { claimPeriod: claim_period });
+        self.vm().log(params::changed(Param::ClaimPeriod, old_period, claim_period));
+
+        Ok(())
+    }
+
+    /// Set where expired claimable balances are swept (admin only)
+    pub fn set_refund_address(&mut self, refund_address: Address) -> Result<(), SwooshReceiverError> {
+        self.only_owner()?;
+        if refund_address == Address::ZERO {
+            return Err(SwooshReceiverError::InvalidAddress(InvalidAddress {}));
+        }
+        self.refund_address.set(refund_address);
+
+        self.vm().log(RefundAddressSet { refundAddress: refund_address });
+
+        Ok(())
+    }
+
+    /// Set where swept dust is sent (admin only)
+    pub fn set_treasury(&mut self, treasury: Address) -> Result<(), SwooshReceiverError> {
+        self.only_owner()?;
+        if treasury == Address::ZERO {
+            return Err(SwooshReceiverError::InvalidAddress(InvalidAddress {}));
+        }
+        self.treasury.set(treasury);
+
+        self.vm().log(TreasurySet { treasury });
+
+        Ok(())
+    }
+
+    /// Set the smallest dust balance of a token worth sweeping (admin only)
+    ///
+    /// Zero keeps the token out of sweeps.
+    pub fn set_dust_threshold(&mut self, token: Address, threshold: U256) -> Result<(), SwooshReceiverError> {
+        self.only_owner()?;
+        self.dust_thresholds.setter(token).set(threshold);
+
+        self.vm().log(DustThresholdSet { token, threshold });
+
+        Ok(())
+    }
+
+    /// Deliver a bridged intent (destination side)
+    ///
+    /// The caller must be a lane allowed for `source_chain` and have approved
+    /// the receiver for the payload's `amount` of `token`. Returns the message
+    /// ID reported to SettlementVerifier.
+    pub fn receive_message(
+        &mut self,
+        source_chain: U256,
+        payload: Bytes,
+    ) -> Result<FixedBytes<32>, SwooshReceiverError> {
+        if self.locked.get() {
+            return Err(SwooshReceiverError::ReentrancyGuard(ReentrancyGuard {}));
+        }
+
+        let messenger = self.vm().msg_sender();
+        if !self.lanes.getter(source_chain).get(messenger) {
+            return Err(SwooshReceiverError::UntrustedLane(UntrustedLane { sourceChain: source_chain, messenger }));
+        }
+
+        let message_id = keccak(&payload);
+        if self.processed.get(message_id) {
+            return Err(SwooshReceiverError::AlreadyProcessed(AlreadyProcessed {}));
+        }
+
+        let message = payload_codec::decode_receiver(&payload).map_err(SwooshReceiverError::from)?;
+        if message.recipient == Address::ZERO || message.amount == U256::ZERO {
+            return Err(SwooshReceiverError::InvalidPayload(InvalidPayload {}));
+        }
+
+        self.processed.setter(message_id).set(true);
+        self.locked.set(true);
+
+        if !self.pull_from_lane(messenger, message.token, message.amount) {
+            return Err(SwooshReceiverError::TransferFailed(TransferFailed {}));
+        }
+        let (token, amount, delivery) = self.deliver(&message)?;
+
+        let verifier = ISettlementVerifier::new(self.settlement_verifier.get());
+        let config = Call::new_mutating(self);
+        verifier
+            .verify_adapter_delivery(self.vm(), config, message_id, message.intentId, delivery as u8)
+            .map_err(|_| SwooshReceiverError::VerifierCallFailed(VerifierCallFailed {}))?;
+
+        self.locked.set(false);
+
+        self.vm().log(DeliveryCompleted {
+            intentId: message.intentId,
+            recipient: message.recipient,
+            token,
+            amount,
+            messageId: message_id,
+            status: delivery as u8,
+        });
+
+        Ok(message_id)
+    }
+
+    /// Deliver a settlement batch (destination side)
+    ///
+    /// The caller must be a lane allowed for `source_chain` and have approved
+    /// the receiver for the entries' combined amounts of their tokens. Each
+    /// entry is delivered as `receive_message` would and reported under
+    /// `settlement_batch_entry_id`; entries that can't be are skipped with a
+    /// `BatchEntrySkipped` reason and their tokens stay with the lane. A
+    /// revert while delivering an entry reverts the whole batch. Returns the
+    /// bitmap of delivered entries, also kept under the batch ID (the hash of
+    /// the payload).
+    pub fn receive_batch(&mut self, source_chain: U256, payload: Bytes) -> Result<U256, SwooshReceiverError> {
+        if self.locked.get() {
+            return Err(SwooshReceiverError::ReentrancyGuard(ReentrancyGuard {}));
+        }
+
+        let messenger = self.vm().msg_sender();
+        if !self.lanes.getter(source_chain).get(messenger) {
+            return Err(SwooshReceiverError::UntrustedLane(UntrustedLane { sourceChain: source_chain, messenger }));
+        }
+
+        let batch_id = keccak(&payload);
+        if self.processed.get(batch_id) {
+            return Err(SwooshReceiverError::AlreadyProcessed(AlreadyProcessed {}));
+        }
+        let entries = decode_settlement_batch(&payload).ok_or(SwooshReceiverError::InvalidPayload(InvalidPayload {}))?;
+
+        self.processed.setter(batch_id).set(true);
+        self.locked.set(true);
+
+        let verifier = ISettlementVerifier::new(self.settlement_verifier.get());
+        let mut delivered = U256::ZERO;
+        let mut message_ids = Vec::new();
+        let mut intent_ids = Vec::new();
+        let mut statuses = Vec::new();
+        let mut completed = Vec::new();
+
+        for (index, message) in entries.iter().enumerate() {
+            if let Some(reason) = self.batch_entry_failure(&verifier, messenger, &entries[..index], message)? {
+                self.vm().log(BatchEntrySkipped {
+                    batchId: batch_id,
+                    index: U256::from(index),
+                    intentId: message.intentId,
+                    reason: reason as u8,
+                });
+                continue;
+            }
+
+            let (token, amount, delivery) = self.deliver(message)?;
+            let message_id = settlement_batch_entry_id(batch_id, index);
+            delivered |= U256::from(1) << index;
+            message_ids.push(message_id);
+            intent_ids.push(message.intentId);
+            statuses.push(delivery as u8);
+            completed.push(DeliveryCompleted {
+                intentId: message.intentId,
+                recipient: message.recipient,
+                token,
+                amount,
+                messageId: message_id,
+                status: delivery as u8,
+            });
+        }
+
+        // One report for the whole batch, and every delivered entry must settle
+        if !message_ids.is_empty() {
+            let expected = U256::MAX >> (256 - message_ids.len());
+            let config = Call::new_mutating(self);
+            let accepted = verifier
+                .verify_adapter_deliveries(self.vm(), config, message_ids, intent_ids, statuses)
+                .map_err(|_| SwooshReceiverError::VerifierCallFailed(VerifierCallFailed {}))?;
+            if accepted != expected {
+                return Err(SwooshReceiverError::VerifierCallFailed(VerifierCallFailed {}));
+            }
+        }
+
+        self.batch_deliveries.setter(batch_id).set(delivered);
+        self.locked.set(false);
+
+        for event in completed {
+            self.vm().log(event);
+        }
+        self.vm().log(BatchReceived {
+            batchId: batch_id,
+            sourceChain: source_chain,
+            entries: U256::from(entries.len()),
+            delivered,
+        });
+
+        Ok(delivered)
+    }
+
+    /// Claim the caller's balance of a token left by a failed push delivery
+    pub fn claim(&mut self, token: Address) -> Result<U256, SwooshReceiverError> {
+        if self.locked.get() {
+            return Err(SwooshReceiverError::ReentrancyGuard(ReentrancyGuard {}));
+        }
+
+        let recipient = self.vm().msg_sender();
+        let amount = self.take_claim(recipient, token)?;
+
+        self.locked.set(true);
+        self.transfer_token(token, recipient, amount)?;
+        self.locked.set(false);
+
+        self.vm().log(Claimed { recipient, token, amount });
+
+        Ok(amount)
+    }
+
+    /// Sweep a claimable balance past its expiry to the refund address
+    ///
+    /// Anyone may call this; the funds can only go to the refund address.
+    pub fn sweep_expired_claim(&mut self, recipient: Address, token: Address) -> Result<U256, SwooshReceiverError> {
+        if self.locked.get() {
+            return Err(SwooshReceiverError::ReentrancyGuard(ReentrancyGuard {}));
+        }
+
+        let expires_at = self.claim_expiries.getter(recipient).get(token);
+        if U256::from(self.vm().block_timestamp()) < expires_at {
+            return Err(SwooshReceiverError::ClaimNotExpired(ClaimNotExpired { expiresAt: expires_at }));
+        }
+
+        let amount = self.take_claim(recipient, token)?;
+        let refund_address = self.refund_address.get();
+
+        self.locked.set(true);
+        self.transfer_token(token, refund_address, amount)?;
+        self.locked.set(false);
+
+        self.vm().log(ClaimSwept { recipient, token, amount, refundAddress: refund_address });
+
+        Ok(amount)
+    }
+
+    /// Sweep a token's dust to the treasury (admin only)
+    ///
+    /// Dust is the receiver's balance beyond outstanding claims. With a
+    /// non-zero `swap_adapter` it is first swapped into `token_out` through
+    /// that allowlisted adapter, straight to the treasury. Returns the amount
+    /// the treasury received.
+    pub fn sweep_dust(
+        &mut self,
+        token: Address,
+        swap_adapter: Address,
+        token_out: Address,
+        min_amount_out: U256,
+        swap_data: Bytes,
+    ) -> Result<U256, SwooshReceiverError> {
+        self.only_owner()?;
+        if self.locked.get() {
+            return Err(SwooshReceiverError::ReentrancyGuard(ReentrancyGuard {}));
+        }
+
+        let treasury = self.treasury.get();
+        if treasury == Address::ZERO {
+            return Err(SwooshReceiverError::InvalidAddress(InvalidAddress {}));
+        }
+
+        let dust = self.get_dust(token)?;
+        let threshold = self.dust_thresholds.get(token);
+        if threshold == U256::ZERO || dust < threshold {
+            return Err(SwooshReceiverError::DustBelowThreshold(DustBelowThreshold {
+                dust,
+                threshold,
+            }));
+        }
+
+        self.locked.set(true);
+        let (token_out, amount_out) = if swap_adapter == Address::ZERO {
+            self.transfer_token(token, treasury, dust)?;
+            (token, dust)
+        } else {
+            if !self.swap_adapters.get(swap_adapter) || token_out == token {
+                return Err(SwooshReceiverError::InvalidAddress(InvalidAddress {}));
+            }
+            let before = self.balance_of(token_out, treasury)?;
+
+            let config = Call::new_mutating(self);
+            let approved = IERC20::new(token)
+                .approve(self.vm(), config, swap_adapter, dust)
+                .map_err(|_| SwooshReceiverError::TransferFailed(TransferFailed {}))?;
+            if !approved {
+                return Err(SwooshReceiverError::TransferFailed(TransferFailed {}));
+            }
+
+            let config = Call::new_mutating(self);
+            ISwapAdapter::new(swap_adapter)
+                .swap(
+                    self.vm(),
+                    config,
+                    token,
+                    token_out,
+                    dust,
+                    min_amount_out,
+                    treasury,
+                    swap_data,
+                )
+                .map_err(|_| SwooshReceiverError::TransferFailed(TransferFailed {}))?;
+
+            let config = Call::new_mutating(self);
+            IERC20::new(token)
+                .approve(self.vm(), config, swap_adapter, U256::ZERO)
+                .map_err(|_| SwooshReceiverError::TransferFailed(TransferFailed {}))?;
+
+            let amount_out = self.balance_of(token_out, treasury)?.saturating_sub(before);
+            if amount_out < min_amount_out {
+                return Err(SwooshReceiverError::SlippageExceeded(SlippageExceeded {
+                    amountOut: amount_out,
+                    minAmountOut: min_amount_out,
+                }));
+            }
+            (token_out, amount_out)
+        };
+        self.locked.set(false);
+
+        self.vm().log(DustSwept {
+            token,
+            amount: dust,
+            tokenOut: token_out,
+            amountOut: amount_out,
+            treasury,
+        });
+
+        Ok(amount_out)
+    }
+
+    /// Get a token's dust: the receiver's balance beyond outstanding claims
+    pub fn get_dust(&self, token: Address) -> Result<U256, SwooshReceiverError> {
+        let balance = self.balance_of(token, self.vm().contract_address())?;
+        Ok(balance.saturating_sub(self.claimable_totals.get(token)))
+    }
+
+    /// Get the treasury swept dust is sent to
+    pub fn treasury(&self) -> Address {
+        self.treasury.get()
+    }
+
+    /// Get a recipient's claimable balance of a token and when it can be swept
+    pub fn get_claimable(&self, recipient: Address, token: Address) -> (U256, U256) {
+        (self.claimable.getter(recipient).get(token), self.claim_expiries.getter(recipient).get(token))
+    }
+
+    /// Get the claim period in seconds
+    pub fn claim_period(&self) -> U256 {
+        self.claim_period.get()
+    }
+
+    /// Get the address expired claims are swept to
+    pub fn refund_address(&self) -> Address {
+        self.refund_address.get()
+    }
+
+    /// Check whether a messenger may deliver from a source chain
+    pub fn is_lane(&self, source_chain: U256, messenger: Address) -> bool {
+        self.lanes.getter(source_chain).get(messenger)
+    }
+
+    /// Check whether a swap adapter is allowed
+    pub fn is_swap_adapter(&self, adapter: Address) -> bool {
+        self.swap_adapters.get(adapter)
+    }
+
+    /// Check whether a message has been delivered
+    pub fn is_processed(&self, message_id: FixedBytes<32>) -> bool {
+        self.processed.get(message_id)
+    }
+
+    /// Get the bitmap of a settlement batch's delivered entries (bit `i` is
+    /// entry `i`; zero if unknown or nothing was delivered)
+    pub fn get_batch_deliveries(&self, batch_id: FixedBytes<32>) -> U256 {
+        self.batch_deliveries.get(batch_id)
+    }
+
+    /// Get the SettlementVerifier notified of deliveries
+    pub fn settlement_verifier(&self) -> Address {
+        self.settlement_verifier.get()
+    }
+
+    /// ERC-165: whether this contract implements `interface_id`
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        erc165::supports(interface_id.0, &[])
+    }
+
+    /// Get contract owner
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Get the receiver's ABI-encoded `ProtocolStatus`, which carries no counters
+    pub fn get_protocol_status(&self) -> Bytes {
+        let status = ProtocolStatus::new(StatusContract::SwooshReceiver, env!("CARGO_PKG_VERSION"), self.owner.get());
+        Bytes::from(encode_protocol_status(&status))
+    }
+
+    /// Internal: Deliver a message whose tokens the receiver already holds,
+    /// returning the token and amount the recipient got and the outcome
+    ///
+    /// Runs the optional destination swap, falling back to the bridged token,
+    /// and credits a claim when the push transfer fails.
+    fn deliver(&mut self, message: &ReceiverPayload) -> Result<(Address, U256, DeliveryStatus), SwooshReceiverError> {
+        let swapped = if message.swapAdapter == Address::ZERO { None } else { self.swap_to_recipient(message)? };
+        if message.swapAdapter != Address::ZERO {
+            self.vm().log(RouteStepExecuted {
+                intentId: message.intentId,
+                stepIndex: U256::ZERO,
+                stepType: StepType::Swap as u8,
+                adapter: message.swapAdapter,
+                tokenIn: message.token,
+                tokenOut: message.tokenOut,
+                amountIn: message.amount,
+                amountOut: swapped.unwrap_or(U256::ZERO),
+                success: swapped.is_some(),
+            });
+        }
+
+        let outcome = match swapped {
+            Some(amount_out) => (message.tokenOut, amount_out, DeliveryStatus::Delivered),
+            None => {
+                let pushed = self.try_transfer(message.token, message.recipient, message.amount);
+                if !pushed {
+                    self.credit_claim(message.intentId, message.recipient, message.token, message.amount);
+                }
+
+                if message.swapAdapter != Address::ZERO {
+                    self.vm().log(SwapFallback {
+                        intentId: message.intentId,
+                        swapAdapter: message.swapAdapter,
+                        tokenOut: message.tokenOut,
+                    });
+                }
+
+                let delivery = if !pushed {
+                    DeliveryStatus::DeliveredToClaim
+                } else if message.swapAdapter == Address::ZERO {
+                    DeliveryStatus::Delivered
+                } else {
+                    DeliveryStatus::PartialDelivery
+                };
+                (message.token, message.amount, delivery)
+            }
+        };
+        Ok(outcome)
+    }
+
+    /// Internal: Why a settlement batch entry can't be delivered, if it can't
+    ///
+    /// Checked in order: the entry itself, the entries before it, the
+    /// verifier's record of the intent, then the lane handing over its tokens.
+    fn batch_entry_failure(
+        &mut self,
+        verifier: &ISettlementVerifier,
+        messenger: Address,
+        earlier: &[ReceiverPayload],
+        message: &ReceiverPayload,
+    ) -> Result<Option<BatchEntryFailure>, SwooshReceiverError> {
+        if message.recipient == Address::ZERO || message.amount == U256::ZERO {
+            return Ok(Some(BatchEntryFailure::InvalidEntry));
+        }
+        if earlier.iter().any(|entry| entry.intentId == message.intentId) {
+            return Ok(Some(BatchEntryFailure::DuplicateIntent));
+        }
+        let status = verifier
+            .get_settlement_status(self.vm(), Call::new(), message.intentId)
+            .map_err(|_| SwooshReceiverError::VerifierCallFailed(VerifierCallFailed {}))?;
+        if status != U256::ZERO {
+            return Ok(Some(BatchEntryFailure::AlreadySettled));
+        }
+        if !self.pull_from_lane(messenger, message.token, message.amount) {
+            return Ok(Some(BatchEntryFailure::TransferFailed));
+        }
+        Ok(None)
+    }
+
+    /// Internal: Pull a message's tokens from the lane, reporting whether they arrived
+    fn pull_from_lane(&mut self, messenger: Address, token: Address, amount: U256) -> bool {
+        let this = self.vm().contract_address();
+        let config = Call::new_mutating(self);
+        matches!(IERC20::new(token).transfer_from(self.vm(), config, messenger, this, amount), Ok(true))
+    }
+
+    /// Internal: Swap the bridged tokens into `tokenOut` for the recipient,
+    /// returning the amount the recipient received
+    ///
+    /// Returns `None` without moving funds when the swap cannot run or
+    /// reverts (adapter not allowlisted, pool drained, slippage), leaving the
+    /// bridged tokens with the receiver for fallback delivery.
+    fn swap_to_recipient(&mut self, message: &ReceiverPayload) -> Result<Option<U256>, SwooshReceiverError> {
+        if !self.swap_adapters.get(message.swapAdapter) {
+            return Ok(None);
+        }
+        let Ok(before) = self.balance_of(message.tokenOut, message.recipient) else {
+            return Ok(None);
+        };
+
+        let config = Call::new_mutating(self);
+        let approved = IERC20::new(message.token)
+            .approve(self.vm(), config, message.swapAdapter, message.amount)
+            .map_err(|_| SwooshReceiverError::TransferFailed(TransferFailed {}))?;
+        if !approved {
+            return Err(SwooshReceiverError::TransferFailed(TransferFailed {}));
+        }
+
+        let config = Call::new_mutating(self);
+        let swapped = ISwapAdapter::new(message.swapAdapter).swap(
+            self.vm(),
+            config,
+            message.token,
+            message.tokenOut,
+            message.amount,
+            message.minAmountOut,
+            message.recipient,
+            message.swapData.clone(),
+        );
+
+        // Clear any allowance the adapter left unspent, whether or not it swapped
+        let config = Call::new_mutating(self);
+        IERC20::new(message.token)
+            .approve(self.vm(), config, message.swapAdapter, U256::ZERO)
+            .map_err(|_| SwooshReceiverError::TransferFailed(TransferFailed {}))?;
+
+        if swapped.is_err() {
+            return Ok(None);
+        }
+
+        let amount_out = self.balance_of(message.tokenOut, message.recipient)?.saturating_sub(before);
+        if amount_out < message.minAmountOut {
+            return Err(SwooshReceiverError::SlippageExceeded(SlippageExceeded {
+                amountOut: amount_out,
+                minAmountOut: message.minAmountOut,
+            }));
+        }
+
+        Ok(Some(amount_out))
+    }
+
+    /// Internal: Credit a recipient's claimable balance, restarting its claim period
+    fn credit_claim(&mut self, intent_id: U256, recipient: Address, token: Address, amount: U256) {
+        let balance = self.claimable.getter(recipient).get(token) + amount;
+        let expires_at = U256::from(self.vm().block_timestamp()) + self.claim_period.get();
+
+        self.claimable.setter(recipient).setter(token).set(balance);
+        self.claim_expiries.setter(recipient).setter(token).set(expires_at);
+        let total = self.claimable_totals.get(token) + amount;
+        self.claimable_totals.setter(token).set(total);
+
+        self.vm().log(ClaimCredited { intentId: intent_id, recipient, token, amount, expiresAt: expires_at });
+    }
+
+    /// Internal: Clear a claimable balance, returning it
+    fn take_claim(&mut self, recipient: Address, token: Address) -> Result<U256, SwooshReceiverError> {
+        let amount = self.claimable.getter(recipient).get(token);
+        if amount == U256::ZERO {
+            return Err(SwooshReceiverError::NothingToClaim(NothingToClaim {}));
+        }
+
+        self.claimable.setter(recipient).setter(token).set(U256::ZERO);
+        self.claim_expiries.setter(recipient).setter(token).set(U256::ZERO);
+        // Claims credited before totals were tracked were never added
+        let total = self.claimable_totals.get(token).saturating_sub(amount);
+        self.claimable_totals.setter(token).set(total);
+
+        Ok(amount)
+    }
+
+    /// Internal: Attempt a push transfer, reporting whether it went through
+    fn try_transfer(&mut self, token: Address, to: Address, amount: U256) -> bool {
+        let config = Call::new_mutating(self);
+        matches!(IERC20::new(token).transfer(self.vm(), config, to, amount), Ok(true))
+    }
+
+    /// Internal: Transfer tokens held by the receiver
+    fn transfer_token(&mut self, token: Address, to: Address, amount: U256) -> Result<(), SwooshReceiverError> {
+        let config = Call::new_mutating(self);
+        let sent = IERC20::new(token)
+            .transfer(self.vm(), config, to, amount)
+            .map_err(|_| SwooshReceiverError::TransferFailed(TransferFailed {}))?;
+        if !sent {
+            return Err(SwooshReceiverError::TransferFailed(TransferFailed {}));
+        }
+        Ok(())
+    }
+
+    /// Internal: Token balance of an account
+    fn balance_of(&self, token: Address, account: Address) -> Result<U256, SwooshReceiverError> {
+        IERC20::new(token)
+            .balance_of(self.vm(), Call::new(), account)
+            .map_err(|_| SwooshReceiverError::TransferFailed(TransferFailed {}))
+    }
+
+    /// Internal: Check if caller is owner
+    fn only_owner(&self) -> Result<(), SwooshReceiverError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(SwooshReceiverError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+
+    /// Internal: Reject a swap adapter unless ERC-165 detection finds `interface_id`
+    fn require_interface(&self, target: Address, interface_id: [u8; 4]) -> Result<(), SwooshReceiverError> {
+        let erc165_target = IERC165::new(target);
+        let supported = erc165::detect(interface_id, |id| {
+            erc165_target.supports_interface(self.vm(), Call::new(), FixedBytes(id)).unwrap_or(false)
+        });
+        if !supported {
+            return Err(SwooshReceiverError::InterfaceNotSupported(InterfaceNotSupported {
+                target,
+                interfaceId: FixedBytes(interface_id),
+            }));
+        }
+        Ok(())
+    }
+}
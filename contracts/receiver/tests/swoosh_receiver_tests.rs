@@ -0,0 +1,168 @@
+use alloy_sol_types::SolValue;
+use stylus_sdk::alloy_primitives::{Address, Bytes, U256};
+use swoosh_common::encoding::ReceiverPayload;
+use swoosh_receiver::*;
+use swoosh_test_utils::TestEnv;
+
+#[cfg(test)]
+mod swoosh_receiver_tests {
+    use super::*;
+
+    const SOURCE_CHAIN: u64 = 84532;
+
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    // Owner 1 with verifier 2; messenger 3 allowed from the source chain
+    fn setup(env: &TestEnv) -> SwooshReceiver {
+        let mut receiver: SwooshReceiver = env.deploy();
+        env.set_sender(test_address(1));
+        assert!(receiver.init(test_address(2)).is_ok(), "Init");
+        assert!(receiver.set_lane(U256::from(SOURCE_CHAIN), test_address(3), true).is_ok(), "Lane set");
+        receiver
+    }
+
+    fn payload() -> Bytes {
+        ReceiverPayload {
+            intentId: U256::from(7u64),
+            token: test_address(4),
+            amount: U256::from(1_000u64),
+            recipient: test_address(5),
+            swapAdapter: Address::ZERO,
+            tokenOut: Address::ZERO,
+            minAmountOut: U256::ZERO,
+            swapData: Bytes::new(),
+        }
+        .abi_encode()
+        .into()
+    }
+
+    #[test]
+    fn test_lane_admin() {
+        // Only the owner manages lanes, and lanes are per source chain
+        let env = TestEnv::new();
+        let mut receiver = setup(&env);
+
+        assert!(receiver.is_lane(U256::from(SOURCE_CHAIN), test_address(3)), "Lane allowed");
+        assert!(!receiver.is_lane(U256::from(1u64), test_address(3)), "Other chain not allowed");
+
+        env.set_sender(test_address(9));
+        assert!(
+            matches!(
+                receiver.set_lane(U256::from(1u64), test_address(9), true),
+                Err(SwooshReceiverError::Unauthorized(_))
+            ),
+            "Non-owner rejected"
+        );
+    }
+
+    #[test]
+    fn test_untrusted_lane_rejected() {
+        // Messages from unknown messengers or chains are refused before decoding
+        let env = TestEnv::new();
+        let mut receiver = setup(&env);
+
+        env.set_sender(test_address(9));
+        assert!(
+            matches!(
+                receiver.receive_message(U256::from(SOURCE_CHAIN), payload()),
+                Err(SwooshReceiverError::UntrustedLane(_))
+            ),
+            "Unknown messenger"
+        );
+
+        env.set_sender(test_address(3));
+        assert!(
+            matches!(receiver.receive_message(U256::from(1u64), payload()), Err(SwooshReceiverError::UntrustedLane(_))),
+            "Wrong source chain"
+        );
+    }
+
+    #[test]
+    fn test_malformed_payload_rejected() {
+        // Payloads that do not decode are refused with the codec's reason and not marked processed
+        let env = TestEnv::new();
+        let mut receiver = setup(&env);
+
+        env.set_sender(test_address(3));
+        let garbage = Bytes::from(vec![1u8; 40]);
+        assert!(
+            matches!(
+                receiver.receive_message(U256::from(SOURCE_CHAIN), garbage.clone()),
+                Err(SwooshReceiverError::MalformedPayload(MalformedPayload { reason: 3 }))
+            ),
+            "Malformed payload"
+        );
+        assert!(!receiver.is_processed(stylus_sdk::crypto::keccak(&garbage)), "Not processed");
+    }
+
+    #[test]
+    fn test_init_requires_verifier() {
+        // A receiver must always have a verifier to report to
+        let env = TestEnv::new();
+        let mut receiver: SwooshReceiver = env.deploy();
+
+        assert!(
+            matches!(receiver.init(Address::ZERO), Err(SwooshReceiverError::InvalidAddress(_))),
+            "Zero verifier"
+        );
+    }
+
+    #[test]
+    fn test_claim_defaults() {
+        // Claims default to a 30-day window swept to the owner
+        let env = TestEnv::new();
+        let receiver = setup(&env);
+
+        assert_eq!(receiver.claim_period(), U256::from(30u64 * 24 * 60 * 60), "Default claim period");
+        assert_eq!(receiver.refund_address(), test_address(1), "Refunds to the owner");
+        assert_eq!(
+            receiver.get_claimable(test_address(5), test_address(4)),
+            (U256::ZERO, U256::ZERO),
+            "Nothing credited"
+        );
+    }
+
+    #[test]
+    fn test_nothing_to_claim() {
+        // Claiming or sweeping an empty balance reverts
+        let env = TestEnv::new();
+        let mut receiver = setup(&env);
+
+        env.set_sender(test_address(5));
+        assert!(
+            matches!(receiver.claim(test_address(4)), Err(SwooshReceiverError::NothingToClaim(_))),
+            "Empty claim rejected"
+        );
+        assert!(
+            matches!(
+                receiver.sweep_expired_claim(test_address(5), test_address(4)),
+                Err(SwooshReceiverError::NothingToClaim(_))
+            ),
+            "Empty sweep rejected"
+        );
+    }
+
+    #[test]
+    fn test_claim_admin() {
+        // Only the owner sets the claim period and refund address
+        let env = TestEnv::new();
+        let mut receiver = setup(&env);
+
+        assert!(receiver.set_claim_period(U256::from(3_600u64)).is_ok(), "Period set");
+        assert_eq!(receiver.claim_period(), U256::from(3_600u64), "Period stored");
+        assert!(
+            matches!(receiver.set_refund_address(Address::ZERO), Err(SwooshReceiverError::InvalidAddress(_))),
+            "Zero refund address rejected"
+        );
+        assert!(receiver.set_refund_address(test_address(8)).is_ok(), "Refund address set");
+        assert_eq!(receiver.refund_address(), test_address(8), "Refund address stored");
+
+        env.set_sender(test_address(9));
+        assert!(
+            matches!(receiver.set_claim_period(U256::ZERO), Err(SwooshReceiverError::Unauthorized(_))),
+            "Non-owner rejected"
+        );
+    }
+}
@@ -0,0 +1,747 @@
+//! SolverRegistry Contract
+//!
+//! Registry of the solvers allowed to execute signed user intents. Solvers
+//! register with an ETH bond and receive a numeric solver ID that RouteExecutor
+//! uses to attribute each execution. A solver can stop executing by
+//! deactivating itself; the owner can deactivate misbehaving solvers.
+//!
+//! Unstaking is two-step: requested bond stops counting immediately but can
+//! only be withdrawn after the withdrawal delay, and stays slashable until then.
+//! SettlementVerifier slashes solvers whose intents fail to settle in time.
+//! RouteExecutor takes a smaller penalty from solvers that claim an escrowed
+//! intent and let the claim lapse, paid straight to the intent's user.
+//!
+//! A solver can register operator keys for its execution bots, each with an
+//! expiry and `SCOPE_*` permissions. RouteExecutor resolves an operator key
+//! to its solver and attributes the execution, fees and slashing risk to it.
+//!
+//! Active solvers are also kept in an indexed set, which RouteExecutor
+//! draws from to rotate queued intents between solvers.
+//!
+//! LiquidityPool locks part of a borrowing solver's bond for each open loan.
+//! Locked bond can't be unstaked; it is unlocked when the loan is repaid and
+//! seized for the pool if the loan's intent fails.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use alloy_sol_types::sol;
+use stylus_sdk::{
+    alloy_primitives::{Address, Bytes, FixedBytes, U256},
+    prelude::*,
+    storage::{StorageAddress, StorageBool, StorageMap, StorageU256},
+};
+
+use swoosh_common::erc165;
+use swoosh_common::params::{self, Param, ParameterOutOfBounds};
+use swoosh_common::solver_registry::ALL_SCOPES;
+use swoosh_common::status::{encode_protocol_status, ProtocolStatus, StatusContract};
+
+// Events and errors
+sol! {
+    event SolverRegistered(uint256 indexed solverId, address indexed solver, uint256 bond);
+    event BondStaked(uint256 indexed solverId, uint256 amount, uint256 totalBond);
+    event SolverDeactivated(uint256 indexed solverId, address indexed by);
+    event MinBondSet(uint256 minBond);
+    event UnstakeRequested(uint256 indexed solverId, uint256 amount, uint256 readyAt);
+    event UnstakeWithdrawn(uint256 indexed solverId, address indexed solver, uint256 amount);
+    event SolverSlashed(uint256 indexed solverId, uint256 indexed intentId, uint256 amount);
+    event SlashedFundsWithdrawn(address indexed to, uint256 amount);
+    event WithdrawalDelaySet(uint256 delay);
+    event SettlementVerifierSet(address verifier);
+    event RouteExecutorSet(address executor);
+    event SolverPenalized(uint256 indexed solverId, uint256 indexed intentId, address indexed user, uint256 amount);
+    event OperatorSet(uint256 indexed solverId, address indexed operator, uint256 expiresAt, uint8 scopes);
+    event OperatorRevoked(uint256 indexed solverId, address indexed operator);
+    event LiquidityPoolSet(address pool);
+    event BondLocked(uint256 indexed solverId, uint256 indexed intentId, uint256 amount);
+    event BondUnlocked(uint256 indexed solverId, uint256 indexed intentId, uint256 amount);
+    event BondSeized(uint256 indexed solverId, uint256 indexed intentId, address indexed to, uint256 amount);
+
+    error Unauthorized();
+    error InvalidAmount();
+    error AlreadyRegistered();
+    error NotRegistered();
+    error InsufficientBond(uint256 bond, uint256 minBond);
+    error WithdrawalNotReady(uint256 readyAt, uint256 timestamp);
+    error TransferFailed();
+    error InvalidOperator();
+}
+
+/// Error types for SolverRegistry
+#[derive(SolidityError)]
+pub enum SolverRegistryError {
+    Unauthorized(Unauthorized),
+    InvalidAmount(InvalidAmount),
+    AlreadyRegistered(AlreadyRegistered),
+    NotRegistered(NotRegistered),
+    InsufficientBond(InsufficientBond),
+    WithdrawalNotReady(WithdrawalNotReady),
+    TransferFailed(TransferFailed),
+    InvalidOperator(InvalidOperator),
+    ParameterOutOfBounds(ParameterOutOfBounds),
+}
+
+/// Default delay before unstaked bond can be withdrawn (7 days)
+const DEFAULT_WITHDRAWAL_DELAY: u64 = 7 * 24 * 60 * 60;
+
+#[entrypoint]
+#[storage]
+pub struct SolverRegistry {
+    /// Contract owner
+    owner: StorageAddress,
+    /// Minimum bond required to register
+    min_bond: StorageU256,
+    /// Number of registered solvers (also the last assigned ID)
+    solver_count: StorageU256,
+    /// Mapping of solver addresses to IDs (zero = unregistered)
+    solver_ids: StorageMap<Address, StorageU256>,
+    /// Mapping of solver IDs to addresses
+    solver_addresses: StorageMap<U256, StorageAddress>,
+    /// Mapping of solver IDs to bonded ETH
+    bonds: StorageMap<U256, StorageU256>,
+    /// Mapping of solver IDs to active state
+    active: StorageMap<U256, StorageBool>,
+    /// Seconds between an unstake request and its withdrawal
+    withdrawal_delay: StorageU256,
+    /// Mapping of solver IDs to bond awaiting withdrawal
+    pending_withdrawals: StorageMap<U256, StorageU256>,
+    /// Mapping of solver IDs to the time their pending bond unlocks
+    withdrawal_ready_at: StorageMap<U256, StorageU256>,
+    /// SettlementVerifier allowed to slash
+    settlement_verifier: StorageAddress,
+    /// Slashed bond held for the owner
+    slashed_funds: StorageU256,
+    /// Mapping of operator keys to the solver ID they act for (zero = none)
+    operator_solvers: StorageMap<Address, StorageU256>,
+    /// Mapping of operator keys to the time they stop working
+    operator_expiries: StorageMap<Address, StorageU256>,
+    /// Mapping of operator keys to their `SCOPE_*` permissions
+    operator_scopes: StorageMap<Address, StorageU256>,
+    /// RouteExecutor allowed to penalize lapsed intent claims
+    route_executor: StorageAddress,
+    /// Number of solvers in the active set
+    active_count: StorageU256,
+    /// Active set by position (position -> solver ID)
+    active_set: StorageMap<U256, StorageU256>,
+    /// Position plus one of each solver in the active set (zero = not in it)
+    active_positions: StorageMap<U256, StorageU256>,
+    /// LiquidityPool allowed to lock and seize bond backing its loans
+    liquidity_pool: StorageAddress,
+    /// Mapping of solver IDs to bond locked behind open loans
+    locked_bonds: StorageMap<U256, StorageU256>,
+}
+
+#[public]
+impl SolverRegistry {
+    /// Initialize the registry
+    pub fn init(&mut self, min_bond: U256) -> Result<(), SolverRegistryError> {
+        Param::MinBond.check(min_bond).map_err(SolverRegistryError::ParameterOutOfBounds)?;
+
+        self.owner.set(self.vm().msg_sender());
+        self.min_bond.set(min_bond);
+        self.solver_count.set(U256::ZERO);
+        self.withdrawal_delay.set(U256::from(DEFAULT_WITHDRAWAL_DELAY));
+
+        Ok(())
+    }
+
+    /// Set the unstake withdrawal delay in seconds (admin only)
+    pub fn set_withdrawal_delay(&mut self, delay: U256) -> Result<(), SolverRegistryError> {
+        self.only_owner()?;
+        Param::WithdrawalDelay.check(delay).map_err(SolverRegistryError::ParameterOutOfBounds)?;
+
+        let old_delay = self.withdrawal_delay.get();
+        self.withdrawal_delay.set(delay);
+
+        self.vm().log(WithdrawalDelaySet { delay });
+        self.vm().log(params::changed(Param::WithdrawalDelay, old_delay, delay));
+
+        Ok(())
+    }
+
+    /// Set the SettlementVerifier allowed to slash (admin only)
+    pub fn set_settlement_verifier(&mut self, verifier: Address) -> Result<(), SolverRegistryError> {
+        self.only_owner()?;
+        self.settlement_verifier.set(verifier);
+
+        self.vm().log(SettlementVerifierSet { verifier });
+
+        Ok(())
+    }
+
+    /// Set the RouteExecutor allowed to penalize lapsed claims (admin only)
+    pub fn set_route_executor(&mut self, executor: Address) -> Result<(), SolverRegistryError> {
+        self.only_owner()?;
+        self.route_executor.set(executor);
+
+        self.vm().log(RouteExecutorSet { executor });
+
+        Ok(())
+    }
+
+    /// Set the LiquidityPool allowed to lock bond behind loans (admin only)
+    pub fn set_liquidity_pool(&mut self, pool: Address) -> Result<(), SolverRegistryError> {
+        self.only_owner()?;
+        self.liquidity_pool.set(pool);
+
+        self.vm().log(LiquidityPoolSet { pool });
+
+        Ok(())
+    }
+
+    /// Set the minimum registration bond (admin only)
+    pub fn set_min_bond(&mut self, min_bond: U256) -> Result<(), SolverRegistryError> {
+        self.only_owner()?;
+        Param::MinBond.check(min_bond).map_err(SolverRegistryError::ParameterOutOfBounds)?;
+
+        let old_min_bond = self.min_bond.get();
+        self.min_bond.set(min_bond);
+
+        self.vm().log(MinBondSet { minBond: min_bond });
+        self.vm().log(params::changed(Param::MinBond, old_min_bond, min_bond));
+
+        Ok(())
+    }
+
+    /// Register the caller as a solver, bonding msg.value
+    #[payable]
+    pub fn register(&mut self) -> Result<U256, SolverRegistryError> {
+        let solver = self.vm().msg_sender();
+        // Operator keys act for their solver and can't register one of their own
+        if self.solver_ids.get(solver) != U256::ZERO || self.operator_solvers.get(solver) != U256::ZERO {
+            return Err(SolverRegistryError::AlreadyRegistered(AlreadyRegistered {}));
+        }
+
+        let bond = self.vm().msg_value();
+        let min_bond = self.min_bond.get();
+        if bond < min_bond {
+            return Err(SolverRegistryError::InsufficientBond(InsufficientBond {
+                bond,
+                minBond: min_bond,
+            }));
+        }
+
+        let solver_id = self.solver_count.get() + U256::from(1);
+        self.solver_count.set(solver_id);
+        self.solver_ids.setter(solver).set(solver_id);
+        self.solver_addresses.setter(solver_id).set(solver);
+        self.bonds.setter(solver_id).set(bond);
+        self.active.setter(solver_id).set(true);
+        self.add_to_active_set(solver_id);
+
+        self.vm().log(SolverRegistered {
+            solverId: solver_id,
+            solver,
+            bond,
+        });
+
+        Ok(solver_id)
+    }
+
+    /// Add msg.value to the caller's bond
+    #[payable]
+    pub fn stake(&mut self) -> Result<U256, SolverRegistryError> {
+        let solver_id = self.registered_id(self.vm().msg_sender())?;
+
+        let amount = self.vm().msg_value();
+        if amount == U256::ZERO {
+            return Err(SolverRegistryError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let total_bond = self.bonds.get(solver_id) + amount;
+        self.bonds.setter(solver_id).set(total_bond);
+
+        self.vm().log(BondStaked {
+            solverId: solver_id,
+            amount,
+            totalBond: total_bond,
+        });
+
+        Ok(total_bond)
+    }
+
+    /// Move part of the caller's bond into a delayed withdrawal
+    ///
+    /// Restarts the delay for everything already pending. Bond locked behind
+    /// open loans can't be unstaked.
+    pub fn request_unstake(&mut self, amount: U256) -> Result<U256, SolverRegistryError> {
+        let solver_id = self.registered_id(self.vm().msg_sender())?;
+
+        let bond = self.bonds.get(solver_id);
+        if amount == U256::ZERO || amount > bond.saturating_sub(self.locked_bonds.get(solver_id)) {
+            return Err(SolverRegistryError::InvalidAmount(InvalidAmount {}));
+        }
+
+        self.bonds.setter(solver_id).set(bond - amount);
+        let pending = self.pending_withdrawals.get(solver_id) + amount;
+        self.pending_withdrawals.setter(solver_id).set(pending);
+
+        let ready_at = U256::from(self.vm().block_timestamp()) + self.withdrawal_delay.get();
+        self.withdrawal_ready_at.setter(solver_id).set(ready_at);
+
+        self.vm().log(UnstakeRequested {
+            solverId: solver_id,
+            amount,
+            readyAt: ready_at,
+        });
+
+        Ok(ready_at)
+    }
+
+    /// Withdraw the caller's pending bond once the delay has passed
+    pub fn withdraw(&mut self) -> Result<U256, SolverRegistryError> {
+        let solver = self.vm().msg_sender();
+        let solver_id = self.registered_id(solver)?;
+
+        let amount = self.pending_withdrawals.get(solver_id);
+        if amount == U256::ZERO {
+            return Err(SolverRegistryError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let ready_at = self.withdrawal_ready_at.get(solver_id);
+        let now = U256::from(self.vm().block_timestamp());
+        if now < ready_at {
+            return Err(SolverRegistryError::WithdrawalNotReady(WithdrawalNotReady {
+                readyAt: ready_at,
+                timestamp: now,
+            }));
+        }
+
+        self.pending_withdrawals.setter(solver_id).set(U256::ZERO);
+        self.vm()
+            .transfer_eth(solver, amount)
+            .map_err(|_| SolverRegistryError::TransferFailed(TransferFailed {}))?;
+
+        self.vm().log(UnstakeWithdrawn {
+            solverId: solver_id,
+            solver,
+            amount,
+        });
+
+        Ok(amount)
+    }
+
+    /// Slash a solver's bond for a failed intent (SettlementVerifier only)
+    ///
+    /// Takes from the active bond first, then from pending withdrawals, and
+    /// returns the amount actually slashed.
+    pub fn slash(&mut self, solver_id: U256, amount: U256, intent_id: U256) -> Result<U256, SolverRegistryError> {
+        if self.vm().msg_sender() != self.settlement_verifier.get() {
+            return Err(SolverRegistryError::Unauthorized(Unauthorized {}));
+        }
+
+        if self.solver_addresses.get(solver_id) == Address::ZERO {
+            return Err(SolverRegistryError::NotRegistered(NotRegistered {}));
+        }
+
+        let slashed = self.take_bond(solver_id, amount);
+        self.slashed_funds.set(self.slashed_funds.get() + slashed);
+
+        self.vm().log(SolverSlashed {
+            solverId: solver_id,
+            intentId: intent_id,
+            amount: slashed,
+        });
+
+        Ok(slashed)
+    }
+
+    /// Penalize a solver that let its claim on an intent lapse (RouteExecutor only)
+    ///
+    /// Takes `amount` from the bond as `slash` does and pays it to `user`
+    /// as compensation rather than holding it for the owner. Returns the
+    /// amount actually paid.
+    pub fn penalize(
+        &mut self,
+        solver_id: U256,
+        amount: U256,
+        intent_id: U256,
+        user: Address,
+    ) -> Result<U256, SolverRegistryError> {
+        if self.vm().msg_sender() != self.route_executor.get() {
+            return Err(SolverRegistryError::Unauthorized(Unauthorized {}));
+        }
+
+        if self.solver_addresses.get(solver_id) == Address::ZERO {
+            return Err(SolverRegistryError::NotRegistered(NotRegistered {}));
+        }
+        if user == Address::ZERO {
+            return Err(SolverRegistryError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let penalty = self.take_bond(solver_id, amount);
+        if penalty != U256::ZERO {
+            self.vm()
+                .transfer_eth(user, penalty)
+                .map_err(|_| SolverRegistryError::TransferFailed(TransferFailed {}))?;
+        }
+
+        self.vm().log(SolverPenalized {
+            solverId: solver_id,
+            intentId: intent_id,
+            user,
+            amount: penalty,
+        });
+
+        Ok(penalty)
+    }
+
+    /// Lock part of a solver's bond behind a loan for an intent (LiquidityPool only)
+    pub fn lock_bond(&mut self, solver_id: U256, amount: U256, intent_id: U256) -> Result<(), SolverRegistryError> {
+        self.only_liquidity_pool()?;
+
+        if self.solver_addresses.get(solver_id) == Address::ZERO {
+            return Err(SolverRegistryError::NotRegistered(NotRegistered {}));
+        }
+
+        let locked = self.locked_bonds.get(solver_id);
+        let free = self.bonds.get(solver_id).saturating_sub(locked);
+        if amount == U256::ZERO || amount > free {
+            return Err(SolverRegistryError::InsufficientBond(InsufficientBond {
+                bond: free,
+                minBond: amount,
+            }));
+        }
+        self.locked_bonds.setter(solver_id).set(locked + amount);
+
+        self.vm().log(BondLocked {
+            solverId: solver_id,
+            intentId: intent_id,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    /// Unlock bond once the loan behind it is repaid (LiquidityPool only)
+    pub fn unlock_bond(&mut self, solver_id: U256, amount: U256, intent_id: U256) -> Result<(), SolverRegistryError> {
+        self.only_liquidity_pool()?;
+
+        let locked = self.locked_bonds.get(solver_id);
+        self.locked_bonds.setter(solver_id).set(locked.saturating_sub(amount));
+
+        self.vm().log(BondUnlocked {
+            solverId: solver_id,
+            intentId: intent_id,
+            amount: amount.min(locked),
+        });
+
+        Ok(())
+    }
+
+    /// Seize locked bond behind a defaulted loan and pay it to `to` (LiquidityPool only)
+    ///
+    /// Takes at most the bond still locked, drawing on the active bond and
+    /// then pending withdrawals as `slash` does. Returns the amount paid.
+    pub fn seize_bond(
+        &mut self,
+        solver_id: U256,
+        amount: U256,
+        intent_id: U256,
+        to: Address,
+    ) -> Result<U256, SolverRegistryError> {
+        self.only_liquidity_pool()?;
+
+        if to == Address::ZERO {
+            return Err(SolverRegistryError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let locked = self.locked_bonds.get(solver_id);
+        let amount = amount.min(locked);
+        self.locked_bonds.setter(solver_id).set(locked - amount);
+
+        let seized = self.take_bond(solver_id, amount);
+        if seized != U256::ZERO {
+            self.vm()
+                .transfer_eth(to, seized)
+                .map_err(|_| SolverRegistryError::TransferFailed(TransferFailed {}))?;
+        }
+
+        self.vm().log(BondSeized {
+            solverId: solver_id,
+            intentId: intent_id,
+            to,
+            amount: seized,
+        });
+
+        Ok(seized)
+    }
+
+    /// Send slashed bond to a recipient (admin only)
+    pub fn withdraw_slashed(&mut self, to: Address) -> Result<U256, SolverRegistryError> {
+        self.only_owner()?;
+
+        let amount = self.slashed_funds.get();
+        if to == Address::ZERO || amount == U256::ZERO {
+            return Err(SolverRegistryError::InvalidAmount(InvalidAmount {}));
+        }
+
+        self.slashed_funds.set(U256::ZERO);
+        self.vm()
+            .transfer_eth(to, amount)
+            .map_err(|_| SolverRegistryError::TransferFailed(TransferFailed {}))?;
+
+        self.vm().log(SlashedFundsWithdrawn { to, amount });
+
+        Ok(amount)
+    }
+
+    /// Deactivate a solver (the solver itself or the owner)
+    pub fn deactivate(&mut self, solver_id: U256) -> Result<(), SolverRegistryError> {
+        let solver = self.solver_addresses.get(solver_id);
+        if solver == Address::ZERO {
+            return Err(SolverRegistryError::NotRegistered(NotRegistered {}));
+        }
+
+        let caller = self.vm().msg_sender();
+        if caller != solver && caller != self.owner.get() {
+            return Err(SolverRegistryError::Unauthorized(Unauthorized {}));
+        }
+
+        self.active.setter(solver_id).set(false);
+        self.remove_from_active_set(solver_id);
+
+        self.vm().log(SolverDeactivated {
+            solverId: solver_id,
+            by: caller,
+        });
+
+        Ok(())
+    }
+
+    /// Register or update an operator key for the caller's solver
+    ///
+    /// The key may act for the solver with the `SCOPE_*` permissions in
+    /// `scopes` until `expires_at`. It can't be a solver or another solver's
+    /// operator.
+    pub fn set_operator(&mut self, operator: Address, expires_at: U256, scopes: u8) -> Result<(), SolverRegistryError> {
+        let solver_id = self.registered_id(self.vm().msg_sender())?;
+
+        let bound = self.operator_solvers.get(operator);
+        if operator == Address::ZERO
+            || self.solver_ids.get(operator) != U256::ZERO
+            || (bound != U256::ZERO && bound != solver_id)
+        {
+            return Err(SolverRegistryError::InvalidOperator(InvalidOperator {}));
+        }
+        let now = U256::from(self.vm().block_timestamp());
+        if scopes == 0 || scopes & !ALL_SCOPES != 0 || expires_at <= now {
+            return Err(SolverRegistryError::InvalidAmount(InvalidAmount {}));
+        }
+
+        self.operator_solvers.setter(operator).set(solver_id);
+        self.operator_expiries.setter(operator).set(expires_at);
+        self.operator_scopes.setter(operator).set(U256::from(scopes));
+
+        self.vm().log(OperatorSet {
+            solverId: solver_id,
+            operator,
+            expiresAt: expires_at,
+            scopes,
+        });
+
+        Ok(())
+    }
+
+    /// Revoke one of the caller's operator keys
+    pub fn revoke_operator(&mut self, operator: Address) -> Result<(), SolverRegistryError> {
+        let solver_id = self.registered_id(self.vm().msg_sender())?;
+        if self.operator_solvers.get(operator) != solver_id {
+            return Err(SolverRegistryError::InvalidOperator(InvalidOperator {}));
+        }
+
+        self.operator_solvers.setter(operator).set(U256::ZERO);
+        self.operator_expiries.setter(operator).set(U256::ZERO);
+        self.operator_scopes.setter(operator).set(U256::ZERO);
+
+        self.vm().log(OperatorRevoked {
+            solverId: solver_id,
+            operator,
+        });
+
+        Ok(())
+    }
+
+    /// Resolve the active solver an account may act for with `scope`
+    ///
+    /// A solver acts for itself with every scope; an operator key acts for
+    /// its solver until it expires, with the scopes it was given. Returns
+    /// the solver's ID and address, or zeros if the account may not act.
+    pub fn resolve_solver(&self, account: Address, scope: u8) -> (U256, Address) {
+        let mut solver_id = self.solver_ids.get(account);
+        if solver_id == U256::ZERO {
+            let now = U256::from(self.vm().block_timestamp());
+            let scopes = self.operator_scopes.get(account);
+            if now >= self.operator_expiries.get(account) || scopes & U256::from(scope) != U256::from(scope) {
+                return (U256::ZERO, Address::ZERO);
+            }
+            solver_id = self.operator_solvers.get(account);
+        }
+
+        let solver = self.solver_addresses.get(solver_id);
+        if solver == Address::ZERO || !self.is_active_solver(solver) {
+            return (U256::ZERO, Address::ZERO);
+        }
+        (solver_id, solver)
+    }
+
+    /// Get an operator key's solver ID, expiry and scopes (zero if none)
+    pub fn get_operator(&self, operator: Address) -> (U256, U256, u8) {
+        (
+            self.operator_solvers.get(operator),
+            self.operator_expiries.get(operator),
+            self.operator_scopes.get(operator).saturating_to(),
+        )
+    }
+
+    /// Get a solver's ID (zero if unregistered)
+    pub fn get_solver_id(&self, solver: Address) -> U256 {
+        self.solver_ids.get(solver)
+    }
+
+    /// Get a solver's address, bond and active state
+    pub fn get_solver(&self, solver_id: U256) -> (Address, U256, bool) {
+        (
+            self.solver_addresses.get(solver_id),
+            self.bonds.get(solver_id),
+            self.active.get(solver_id),
+        )
+    }
+
+    /// Check if an address is a registered, active solver with enough bond
+    pub fn is_active_solver(&self, solver: Address) -> bool {
+        let solver_id = self.solver_ids.get(solver);
+        solver_id != U256::ZERO
+            && self.active.get(solver_id)
+            && self.bonds.get(solver_id) >= self.min_bond.get()
+    }
+
+    /// Get the part of a solver's bond locked behind open loans
+    pub fn get_locked_bond(&self, solver_id: U256) -> U256 {
+        self.locked_bonds.get(solver_id)
+    }
+
+    /// Get a solver's pending withdrawal and the time it unlocks
+    pub fn get_pending_withdrawal(&self, solver_id: U256) -> (U256, U256) {
+        (self.pending_withdrawals.get(solver_id), self.withdrawal_ready_at.get(solver_id))
+    }
+
+    /// Get the unstake withdrawal delay
+    pub fn withdrawal_delay(&self) -> U256 {
+        self.withdrawal_delay.get()
+    }
+
+    /// Get the minimum registration bond
+    pub fn min_bond(&self) -> U256 {
+        self.min_bond.get()
+    }
+
+    /// Get the number of registered solvers
+    pub fn solver_count(&self) -> U256 {
+        self.solver_count.get()
+    }
+
+    /// Get the number of solvers in the active set
+    pub fn active_solver_count(&self) -> U256 {
+        self.active_count.get()
+    }
+
+    /// Get the solver at a position of the active set
+    ///
+    /// Zero past the end of the set, or if that solver's bond has fallen
+    /// below the minimum.
+    pub fn active_solver_at(&self, index: U256) -> U256 {
+        if index >= self.active_count.get() {
+            return U256::ZERO;
+        }
+        let solver_id = self.active_set.get(index);
+        if self.bonds.get(solver_id) < self.min_bond.get() {
+            return U256::ZERO;
+        }
+        solver_id
+    }
+
+    /// ERC-165: whether this contract implements `interface_id`
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        erc165::supports(interface_id.0, &[])
+    }
+
+    /// Get contract owner
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Get the registry's ABI-encoded `ProtocolStatus`, counting registered and active solvers
+    pub fn get_protocol_status(&self) -> Bytes {
+        let mut status =
+            ProtocolStatus::new(StatusContract::SolverRegistry, env!("CARGO_PKG_VERSION"), self.owner.get());
+        status.counters = [self.solver_count.get(), self.active_count.get()].into();
+        Bytes::from(encode_protocol_status(&status))
+    }
+
+    /// Internal: Take up to `amount` from a solver's active bond, then from
+    /// its pending withdrawal, returning how much was taken
+    fn take_bond(&mut self, solver_id: U256, amount: U256) -> U256 {
+        let bond = self.bonds.get(solver_id);
+        let from_bond = amount.min(bond);
+        self.bonds.setter(solver_id).set(bond - from_bond);
+
+        let pending = self.pending_withdrawals.get(solver_id);
+        let from_pending = (amount - from_bond).min(pending);
+        self.pending_withdrawals.setter(solver_id).set(pending - from_pending);
+
+        from_bond + from_pending
+    }
+
+    /// Internal: Resolve a registered solver's ID
+    fn registered_id(&self, solver: Address) -> Result<U256, SolverRegistryError> {
+        let solver_id = self.solver_ids.get(solver);
+        if solver_id == U256::ZERO {
+            return Err(SolverRegistryError::NotRegistered(NotRegistered {}));
+        }
+        Ok(solver_id)
+    }
+
+    /// Internal: Append a solver to the active set
+    fn add_to_active_set(&mut self, solver_id: U256) {
+        let count = self.active_count.get();
+        self.active_set.setter(count).set(solver_id);
+        self.active_positions.setter(solver_id).set(count + U256::from(1));
+        self.active_count.set(count + U256::from(1));
+    }
+
+    /// Internal: Drop a solver from the active set, moving the last one into its place
+    fn remove_from_active_set(&mut self, solver_id: U256) {
+        let position = self.active_positions.get(solver_id);
+        if position == U256::ZERO {
+            return;
+        }
+
+        let last = self.active_count.get() - U256::from(1);
+        let moved = self.active_set.get(last);
+        self.active_set.setter(position - U256::from(1)).set(moved);
+        self.active_positions.setter(moved).set(position);
+        self.active_set.setter(last).set(U256::ZERO);
+        self.active_positions.setter(solver_id).set(U256::ZERO);
+        self.active_count.set(last);
+    }
+
+    /// Internal: Check if caller is the LiquidityPool
+    fn only_liquidity_pool(&self) -> Result<(), SolverRegistryError> {
+        if self.vm().msg_sender() != self.liquidity_pool.get() {
+            return Err(SolverRegistryError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+
+    /// Internal: Check if caller is owner
+    fn only_owner(&self) -> Result<(), SolverRegistryError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(SolverRegistryError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+}
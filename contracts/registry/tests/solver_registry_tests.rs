@@ -0,0 +1,258 @@
+use stylus_sdk::alloy_primitives::{Address, FixedBytes, U256};
+use swoosh_common::solver_registry::{rotation_index, SCOPE_EXECUTE, SCOPE_SETTLE};
+use swoosh_common::status::{decode_protocol_status, StatusContract};
+use swoosh_registry::*;
+use swoosh_test_utils::TestEnv;
+
+#[cfg(test)]
+mod solver_registry_tests {
+    use super::*;
+
+    const DELAY: u64 = 7 * 24 * 60 * 60;
+
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    fn ether(n: u64) -> U256 {
+        U256::from(n) * U256::from(10u64).pow(U256::from(18u64))
+    }
+
+    // Owner 1 with a 1 ETH minimum bond; solver 5 registered with 2 ETH
+    fn setup(env: &TestEnv) -> SolverRegistry {
+        let mut registry: SolverRegistry = env.deploy();
+        env.set_sender(test_address(1));
+        assert!(registry.init(ether(1)).is_ok(), "Init");
+
+        env.set_sender(test_address(5));
+        env.set_value(ether(2));
+        assert!(registry.register().is_ok(), "Registered");
+        env.set_value(U256::ZERO);
+        registry
+    }
+
+    #[test]
+    fn test_unstake_ready_after_delay() {
+        // Requested bond unlocks exactly one delay after the request
+        let env = TestEnv::new();
+        let mut registry = setup(&env);
+
+        env.advance_time(100);
+        assert!(registry.request_unstake(ether(1)).is_ok(), "Unstake requested");
+
+        let (pending, ready_at) = registry.get_pending_withdrawal(U256::from(1u64));
+        assert_eq!(pending, ether(1), "Pending amount");
+        assert_eq!(ready_at, U256::from(env.timestamp() + DELAY), "Ready time");
+    }
+
+    #[test]
+    fn test_withdraw_blocked_during_window() {
+        // Withdrawal reverts for the whole slashable window
+        let env = TestEnv::new();
+        let mut registry = setup(&env);
+
+        assert!(registry.request_unstake(U256::from(1u64)).is_ok(), "Unstake requested");
+
+        env.advance_time(DELAY - 1);
+        assert!(
+            matches!(registry.withdraw(), Err(SolverRegistryError::WithdrawalNotReady(_))),
+            "Still in window"
+        );
+    }
+
+    #[test]
+    fn test_new_request_restarts_window() {
+        // Adding to a pending withdrawal pushes its unlock time out
+        let env = TestEnv::new();
+        let mut registry = setup(&env);
+
+        assert!(registry.request_unstake(U256::from(1u64)).is_ok(), "First request");
+        env.advance_time(DELAY / 2);
+        assert!(registry.request_unstake(U256::from(1u64)).is_ok(), "Second request");
+
+        let (pending, ready_at) = registry.get_pending_withdrawal(U256::from(1u64));
+        assert_eq!(pending, U256::from(2u64), "Amounts combined");
+        assert_eq!(ready_at, U256::from(env.timestamp() + DELAY), "Window restarted");
+    }
+
+    #[test]
+    fn test_operator_acts_for_solver_within_scope() {
+        // Operator 8 resolves to solver 5 for its scopes, until it expires
+        let env = TestEnv::new();
+        let mut registry = setup(&env);
+        let solver_id = U256::from(1u64);
+        let expires_at = U256::from(env.timestamp() + 3600);
+
+        assert!(registry.set_operator(test_address(8), expires_at, SCOPE_EXECUTE).is_ok(), "Operator set");
+        assert_eq!(registry.resolve_solver(test_address(8), SCOPE_EXECUTE), (solver_id, test_address(5)), "Operator");
+        assert_eq!(registry.resolve_solver(test_address(5), SCOPE_SETTLE), (solver_id, test_address(5)), "Solver");
+        assert_eq!(registry.resolve_solver(test_address(8), SCOPE_SETTLE).0, U256::ZERO, "Out of scope");
+
+        env.advance_time(3600);
+        assert_eq!(registry.resolve_solver(test_address(8), SCOPE_EXECUTE).0, U256::ZERO, "Expired");
+    }
+
+    #[test]
+    fn test_operator_revocation_and_conflicts() {
+        // Keys can't be shared between solvers, and revoking or deactivating stops them
+        let env = TestEnv::new();
+        let mut registry = setup(&env);
+        let expires_at = U256::from(env.timestamp() + 3600);
+        assert!(registry.set_operator(test_address(8), expires_at, SCOPE_EXECUTE).is_ok(), "Operator set");
+
+        env.set_sender(test_address(6));
+        env.set_value(ether(1));
+        assert!(registry.register().is_ok(), "Second solver");
+        env.set_value(U256::ZERO);
+        assert!(
+            matches!(
+                registry.set_operator(test_address(8), expires_at, SCOPE_EXECUTE),
+                Err(SolverRegistryError::InvalidOperator(_))
+            ),
+            "Key taken"
+        );
+        assert!(
+            matches!(
+                registry.set_operator(test_address(5), expires_at, SCOPE_EXECUTE),
+                Err(SolverRegistryError::InvalidOperator(_))
+            ),
+            "Solver key"
+        );
+
+        env.set_sender(test_address(1));
+        assert!(registry.deactivate(U256::from(1u64)).is_ok(), "Solver deactivated");
+        assert_eq!(registry.resolve_solver(test_address(8), SCOPE_EXECUTE).0, U256::ZERO, "Inactive solver");
+
+        env.set_sender(test_address(5));
+        assert!(registry.revoke_operator(test_address(8)).is_ok(), "Revoked");
+        assert_eq!(registry.get_operator(test_address(8)), (U256::ZERO, U256::ZERO, 0), "Cleared");
+    }
+
+    #[test]
+    fn test_penalize_only_from_route_executor() {
+        // Only the configured RouteExecutor can take a claim penalty
+        let env = TestEnv::new();
+        let mut registry = setup(&env);
+        env.set_sender(test_address(1));
+        assert!(registry.set_route_executor(test_address(7)).is_ok(), "Executor set");
+
+        assert!(
+            matches!(
+                registry.penalize(U256::from(1u64), ether(1), U256::from(1u64), test_address(9)),
+                Err(SolverRegistryError::Unauthorized(_))
+            ),
+            "Owner"
+        );
+
+        env.set_sender(test_address(7));
+        assert!(
+            matches!(
+                registry.penalize(U256::from(2u64), ether(1), U256::from(1u64), test_address(9)),
+                Err(SolverRegistryError::NotRegistered(_))
+            ),
+            "Unknown solver"
+        );
+        assert!(
+            matches!(
+                registry.penalize(U256::from(1u64), ether(1), U256::from(1u64), Address::ZERO),
+                Err(SolverRegistryError::InvalidAmount(_))
+            ),
+            "No user"
+        );
+    }
+
+    #[test]
+    fn test_locked_bond_cannot_be_unstaked() {
+        // Bond the pool locks behind a loan stays put until it is unlocked
+        let env = TestEnv::new();
+        let mut registry = setup(&env);
+        let (solver_id, intent_id) = (U256::from(1u64), U256::from(9u64));
+        env.set_sender(test_address(1));
+        assert!(registry.set_liquidity_pool(test_address(8)).is_ok(), "Pool set");
+
+        assert!(
+            matches!(registry.lock_bond(solver_id, ether(1), intent_id), Err(SolverRegistryError::Unauthorized(_))),
+            "Owner can't lock"
+        );
+
+        env.set_sender(test_address(8));
+        assert!(
+            matches!(registry.lock_bond(solver_id, ether(3), intent_id), Err(SolverRegistryError::InsufficientBond(_))),
+            "More than the bond"
+        );
+        assert!(registry.lock_bond(solver_id, ether(1), intent_id).is_ok(), "Locked");
+        assert_eq!(registry.get_locked_bond(solver_id), ether(1), "One locked");
+        assert!(
+            matches!(registry.lock_bond(solver_id, ether(2), intent_id), Err(SolverRegistryError::InsufficientBond(_))),
+            "Only the free bond can be locked"
+        );
+        assert!(
+            matches!(
+                registry.seize_bond(solver_id, ether(1), intent_id, Address::ZERO),
+                Err(SolverRegistryError::InvalidAmount(_))
+            ),
+            "Nowhere to pay"
+        );
+
+        env.set_sender(test_address(5));
+        assert!(
+            matches!(registry.request_unstake(ether(2)), Err(SolverRegistryError::InvalidAmount(_))),
+            "Locked bond can't be unstaked"
+        );
+        assert!(registry.request_unstake(ether(1)).is_ok(), "Free bond unstaked");
+
+        env.set_sender(test_address(8));
+        assert!(registry.unlock_bond(solver_id, ether(1), intent_id).is_ok(), "Unlocked");
+        assert_eq!(registry.get_locked_bond(solver_id), U256::ZERO, "Nothing locked");
+    }
+
+    #[test]
+    fn test_active_set_tracks_deactivation() {
+        // Deactivating a solver moves the last one into its place
+        let env = TestEnv::new();
+        let mut registry = setup(&env);
+        for solver in [6u8, 7] {
+            env.set_sender(test_address(solver));
+            env.set_value(ether(2));
+            assert!(registry.register().is_ok(), "Registered");
+        }
+        env.set_value(U256::ZERO);
+        assert_eq!(registry.active_solver_count(), U256::from(3u64), "Three active");
+
+        env.set_sender(test_address(5));
+        assert!(registry.deactivate(U256::from(1u64)).is_ok(), "Deactivated");
+        assert!(registry.deactivate(U256::from(1u64)).is_ok(), "Deactivated again");
+        assert_eq!(registry.active_solver_count(), U256::from(2u64), "Two active");
+        assert_eq!(registry.active_solver_at(U256::ZERO), U256::from(3u64), "Last moved up");
+        assert_eq!(registry.active_solver_at(U256::from(1u64)), U256::from(2u64), "Second kept");
+        assert_eq!(registry.active_solver_at(U256::from(2u64)), U256::ZERO, "Past the end");
+    }
+
+    #[test]
+    fn test_protocol_status() {
+        // One call reports the owner and the solver counts
+        let env = TestEnv::new();
+        let mut registry = setup(&env);
+        assert!(registry.deactivate(U256::from(1u64)).is_ok(), "Deactivated");
+
+        let status = decode_protocol_status(&registry.get_protocol_status()).expect("Status decodes");
+        assert_eq!(StatusContract::from_u8(status.kind), Some(StatusContract::SolverRegistry), "Kind");
+        assert_eq!(status.owner, test_address(1), "Owner");
+        assert!(!status.paused, "Not pausable");
+        assert_eq!(status.counters, vec![U256::from(1u64), U256::ZERO], "One solver, none active");
+    }
+
+    #[test]
+    fn test_rotation_index_in_range() {
+        // The pick is deterministic, within the set, and spreads across it
+        let hash = |n: u8| FixedBytes::from([n; 32]);
+        let eligible = U256::from(3u64);
+
+        assert_eq!(rotation_index(hash(1), 10, 1_000, U256::ZERO), None, "Nobody to pick");
+        let first = rotation_index(hash(1), 10, 1_000, eligible);
+        assert_eq!(first, rotation_index(hash(1), 10, 1_000, eligible), "Deterministic");
+        let picks: Vec<U256> = (0..32).filter_map(|n| rotation_index(hash(n), 10, 1_000, eligible)).collect();
+        assert!(picks.iter().all(|pick| *pick < eligible), "In range");
+        assert!((0..3u64).all(|i| picks.contains(&U256::from(i))), "Every solver picked");
+    }
+}
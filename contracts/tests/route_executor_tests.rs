@@ -1,216 +0,0 @@
-use stylus_sdk::alloy_primitives::{Address, U256, Bytes};
-
-#[cfg(test)]
-mod route_executor_tests {
-    use super::*;
-
-    // Helper function to create test addresses
-    fn test_address(n: u8) -> Address {
-        Address::from([n; 20])
-    }
-
-    #[test]
-    fn test_init_valid_addresses() {
-        // Test initialization with valid addresses
-        let validator = test_address(1);
-        let ccip_router = test_address(2);
-
-        assert_ne!(validator, Address::ZERO, "Validator address valid");
-        assert_ne!(ccip_router, Address::ZERO, "CCIP router address valid");
-    }
-
-    #[test]
-    fn test_init_zero_validator() {
-        // Test that zero validator address is rejected
-        let validator = Address::ZERO;
-        let ccip_router = test_address(2);
-
-        assert_eq!(validator, Address::ZERO, "Zero validator should be detected");
-    }
-
-    #[test]
-    fn test_init_zero_ccip_router() {
-        // Test that zero CCIP router address is rejected
-        let validator = test_address(1);
-        let ccip_router = Address::ZERO;
-
-        assert_eq!(ccip_router, Address::ZERO, "Zero CCIP router should be detected");
-    }
-
-    #[test]
-    fn test_execute_full_route_zero_token() {
-        // Test that zero token address is rejected
-        let token_in = Address::ZERO;
-        let amount = U256::from(1000);
-        let destination_chain = U256::from(42161);
-        let recipient = test_address(1);
-
-        assert_eq!(token_in, Address::ZERO, "Zero token should be detected");
-    }
-
-    #[test]
-    fn test_execute_full_route_zero_amount() {
-        // Test that zero amount is rejected
-        let token_in = test_address(1);
-        let amount = U256::ZERO;
-        let destination_chain = U256::from(42161);
-        let recipient = test_address(2);
-
-        assert_eq!(amount, U256::ZERO, "Zero amount should be detected");
-    }
-
-    #[test]
-    fn test_execute_full_route_zero_recipient() {
-        // Test that zero recipient address is rejected
-        let token_in = test_address(1);
-        let amount = U256::from(1000);
-        let destination_chain = U256::from(42161);
-        let recipient = Address::ZERO;
-
-        assert_eq!(recipient, Address::ZERO, "Zero recipient should be detected");
-    }
-
-    #[test]
-    fn test_execute_full_route_valid_params() {
-        // Test with all valid parameters
-        let token_in = test_address(1);
-        let amount = U256::from(1000);
-        let destination_chain = U256::from(42161);
-        let recipient = test_address(2);
-        let swap_data = Bytes::new();
-
-        assert_ne!(token_in, Address::ZERO, "Valid token address");
-        assert!(amount > U256::ZERO, "Valid amount");
-        assert!(destination_chain > U256::ZERO, "Valid chain ID");
-        assert_ne!(recipient, Address::ZERO, "Valid recipient address");
-    }
-
-    #[test]
-    fn test_intent_status_values() {
-        // Test intent status enum values
-        let pending = 0u8;
-        let executing = 1u8;
-        let completed = 2u8;
-        let failed = 3u8;
-
-        assert_eq!(pending, 0, "Pending status");
-        assert_eq!(executing, 1, "Executing status");
-        assert_eq!(completed, 2, "Completed status");
-        assert_eq!(failed, 3, "Failed status");
-    }
-
-    #[test]
-    fn test_intent_counter_increment() {
-        // Test intent ID generation
-        let counter = U256::ZERO;
-        let next_id = counter + U256::from(1);
-
-        assert_eq!(next_id, U256::from(1), "First intent ID");
-
-        let second_id = next_id + U256::from(1);
-        assert_eq!(second_id, U256::from(2), "Second intent ID");
-    }
-
-    #[test]
-    fn test_swap_data_empty() {
-        // Test empty swap data
-        let swap_data = Bytes::new();
-        assert_eq!(swap_data.len(), 0, "Empty swap data");
-    }
-
-    #[test]
-    fn test_swap_data_with_content() {
-        // Test swap data with content
-        let data = vec![1u8, 2, 3, 4];
-        let swap_data = Bytes::from(data);
-        assert!(swap_data.len() > 0, "Swap data has content");
-    }
-
-    #[test]
-    fn test_reentrancy_guard_logic() {
-        // Test reentrancy guard flag
-        let locked = false;
-        assert!(!locked, "Initially unlocked");
-
-        let locked = true;
-        assert!(locked, "Locked during execution");
-
-        let locked = false;
-        assert!(!locked, "Unlocked after execution");
-    }
-
-    #[test]
-    fn test_pause_logic() {
-        // Test pause functionality
-        let paused = false;
-        assert!(!paused, "Initially unpaused");
-
-        let paused = true;
-        assert!(paused, "Contract paused");
-
-        let paused = false;
-        assert!(!paused, "Contract unpaused");
-    }
-
-    #[test]
-    fn test_amount_calculations() {
-        // Test amount calculations for swaps
-        let input_amount = U256::from(1000);
-        let output_amount = U256::from(995); // After 0.5% fee
-
-        assert!(output_amount < input_amount, "Fee deducted");
-        assert!(output_amount > U256::ZERO, "Output amount valid");
-    }
-}
-
-/* Gas Estimates for RouteExecutor Functions:
-
-- `init()`: ~65,000 gas
-  * Sets owner, validator, CCIP router addresses
-  * Initializes counter and flags
-  * Five storage writes: ~100,000 gas base
-  * Optimized with Stylus: ~65,000 gas
-
-- `execute_full_route()`: ~450,000 gas (complete flow)
-  * Reentrancy check: ~2,100 gas (SLOAD)
-  * Validation checks: ~15,000 gas
-  * Intent status updates: ~40,000 gas (2 SSTORE)
-  * Event emissions: ~4,500 gas (3 events)
-  * Function overhead: ~388,400 gas
-  * Note: External token transfers not included (Phase 2)
-
-- `get_intent_status()`: ~2,100 gas
-  * Single storage read (SLOAD): ~2,100 gas
-
-- `pause()`: ~25,000 gas
-  * Owner check: ~2,100 gas (SLOAD)
-  * Pause flag write: ~20,000 gas (SSTORE)
-  * Event emission: ~1,500 gas
-
-- `unpause()`: ~25,000 gas
-  * Same breakdown as pause()
-
-- `owner()`: ~2,100 gas
-  * Single storage read (SLOAD): ~2,100 gas
-
-- `internal_execute_swap()`: ~10,000 gas
-  * Event emission: ~1,500 gas
-  * Function logic: ~8,500 gas
-  * Note: Actual DEX call not included (Phase 2)
-
-- `internal_execute_bridge()`: ~10,000 gas
-  * Event emission: ~1,500 gas
-  * Function logic: ~8,500 gas
-  * Note: Actual CCIP call not included (Phase 2)
-
-Total estimated gas for typical route execution:
-init + execute_full_route = 65,000 + 450,000 = 515,000 gas
-
-With Stylus optimization (50-90% savings vs Solidity):
-Estimated: 51,500 - 257,500 gas for full execution flow
-
-Expected gas per operation after Phase 2 integration:
-- Simple transfer: ~200,000 gas
-- Swap + bridge: ~500,000 gas
-- Complex multi-hop: ~800,000 gas
-*/
@@ -0,0 +1,2071 @@
+//! IntentValidator Contract
+//! 
+//! Validates user intents before execution, ensuring all parameters are valid
+//! and that users have necessary approvals and balances. NFT intents are
+//! checked against the collection's ERC-721 or ERC-1155 ownership and
+//! operator approvals.
+//!
+//! Destination lanes carry a health flag. When a chain halts, the guardian,
+//! or a quorum of authorized reporters, marks its lane halted and new
+//! intents toward it are rejected here; settlements and refunds for intents
+//! already in flight are unaffected.
+//!
+//! The guardian or owner can block specific intent hashes, e.g. intents
+//! signed through a phishing site. Blocked intents fail validation here and
+//! RouteExecutor refuses to execute or escrow them.
+//!
+//! Fee and limit config is versioned by a config epoch. Wallets sign the
+//! current epoch into an intent; RouteExecutor advances it whenever its fee
+//! or limit config changes, which retires earlier epochs, and refuses pinned
+//! intents whose epoch is no longer compatible. The owner can re-admit older
+//! epochs when a change doesn't affect them. Chain and token additions only
+//! widen what validates, so they leave the epoch alone.
+//!
+//! `self_check` reports the validator's interface so RouteExecutor can refuse
+//! an incompatible deployment before wiring it in.
+//!
+//! The hub validator broadcasts chain and token additions to spoke validators
+//! as config-sync messages over a bridge adapter. Spokes accept them only from
+//! adapters trusted for the source chain, and apply each nonce once.
+//!
+//! Which tokens validate depends on the deployment's listing mode (see
+//! `token_listing` in swoosh-common): the strict allowlist, permissionless
+//! listing, or anything not blocklisted. In permissionless mode anyone can
+//! list a token with `list_token`, which checks the listing criteria on-chain
+//! and caps intents in the token at the default amount cap. The guardian or
+//! owner can block a token in any mode. RouteExecutor enforces the mode and
+//! the caps on every ERC20 intent it executes through `check_execution`.
+//!
+//! Supported chains and tokens are indexed so `export_config` can page them
+//! out; a fresh deployment restores them with `import_config` until its owner
+//! seals the import.
+//!
+//! Users can delegate intent submission to manager keys, e.g. a treasury to
+//! its ops key. A delegation has an expiry and a per-token allowance; an
+//! intent a delegate submits still names the principal as its user and
+//! spends the principal's tokens, and RouteExecutor draws the allowance down
+//! through `spend_delegation`.
+//!
+//! Anyone can act as a watchtower and flag an intent hash with
+//! `report_suspicious`, posting a small bond. A flagged intent is held from
+//! execution for a short period, giving the guardian time to block it. A
+//! report on an intent that ends up blocked returns the bond with a bounty
+//! from the pool the owner funds; a false report forfeits the bond to that
+//! pool.
+//!
+//! Intents can be priced through a per-token fallback chain: the token's
+//! Chainlink feed while it is fresh, then the TWAP source, then a static
+//! bound the owner set (see `price_source` in swoosh-common).
+//! `validate_priced_intent` returns the price with the source it came from.
+//! The price is advisory: RouteExecutor never asks for it, so no price or
+//! deviation check gates execution. Callers that need one must make it
+//! themselves before submitting.
+//!
+//! `validate_signed_intent` takes an intent the user signed as an EIP-712
+//! `ValidatorIntent`, with this validator as verifying contract, so a solver
+//! can have it validated without the user sending a transaction first. It's
+//! a read-only check and consumes no nonce, so it gives no replay protection.
+//!
+//! Intents can also be recorded on-chain with `register_intent`, keyed by
+//! their EIP-712 digest (`signing::registered_intent_digest`), so other
+//! contracts can look one up by hash with `get_intent`. The digest binds the
+//! chain ID and this validator, like `validate_signed_intent`, so the same
+//! intent gets another key anywhere else. Records are packed five words to
+//! an intent (see `registry_record` in swoosh-common). Each registration
+//! takes the user's next nonce, so a signed registration can't be replayed.
+//! A registered intent stays pending until its user cancels it, a consumer
+//! contract the owner allows marks it executed, or its deadline passes.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+use alloy_sol_types::sol;
+use stylus_sdk::{
+    alloy_primitives::{Address, Bytes, FixedBytes, U256},
+    call::{static_call, Call},
+    prelude::*,
+    storage::{StorageAddress, StorageMap, StorageBool, StorageU256},
+};
+
+use swoosh_common::bridge_adapter::IConfigSyncAdapter;
+use swoosh_common::encoding::{
+    config_key_address, decode_config_page, decode_config_sync, encode_config_page, encode_config_sync,
+    pack_config_address, pack_config_key, unpack_config_key, AssetType, ConfigAction, ConfigEntry, ConfigEntryKind,
+    RegisteredIntent, RegisteredIntentStatus, ValidatorIntent,
+};
+use swoosh_common::erc165;
+use swoosh_common::params::{self, Param, ParameterOutOfBounds};
+use swoosh_common::price_source::{chainlink_price, resolve_price, twap_price, PriceSource};
+use swoosh_common::registry_record::{
+    registry_header_status, update_registry_header, RegistryRecord, REGISTRY_RECORD_WORDS,
+};
+use swoosh_common::signing;
+use swoosh_common::status::{encode_protocol_status, ProtocolStatus, StatusContract};
+use swoosh_common::token_listing::{check_listing, token_admission, TokenAdmission, TokenListingMode};
+use swoosh_common::validator_compat::{
+    EXECUTOR_SUPPLIED_FIELDS, FEATURE_CONFIG_EPOCH, FEATURE_DELEGATION, FEATURE_ERC20, FEATURE_EXECUTION_CHECK,
+    FEATURE_INTENT_BLOCKLIST, FEATURE_INTENT_REGISTRY, FEATURE_LANE_HEALTH, FEATURE_NFT, FEATURE_SIGNED_INTENT,
+    FEATURE_WATCHTOWER, VALIDATOR_VERSION,
+};
+
+// ERC20 interface for checking allowances and listing sanity checks
+sol_interface! {
+    interface IERC20 {
+        function totalSupply() external view returns (uint256);
+        function decimals() external view returns (uint8);
+        function allowance(address owner, address spender) external view returns (uint256);
+        function balanceOf(address account) external view returns (uint256);
+    }
+}
+
+// ERC-721 interface for ownership and approval checks
+sol_interface! {
+    interface IERC721 {
+        function ownerOf(uint256 token_id) external view returns (address);
+        function getApproved(uint256 token_id) external view returns (address);
+        function isApprovedForAll(address owner, address operator) external view returns (bool);
+    }
+}
+
+// ERC-1155 interface for balance and approval checks
+sol_interface! {
+    interface IERC1155 {
+        function balanceOf(address account, uint256 id) external view returns (uint256);
+        function isApprovedForAll(address account, address operator) external view returns (bool);
+    }
+}
+
+// Price sources consulted for permissionless token listings and intent pricing
+sol_interface! {
+    interface ITokenPriceOracle {
+        function hasPrice(address token) external view returns (bool);
+    }
+
+    interface ITwapSource {
+        function hasPool(address token) external view returns (bool);
+        function priceOf(address token) external view returns (uint256 price, uint8 decimals);
+    }
+
+    interface IChainlinkAggregator {
+        function decimals() external view returns (uint8);
+        function latestRoundData() external view returns (uint80, int256, uint256, uint256, uint80);
+    }
+}
+
+// Events and errors
+sol! {
+    event ChainAdded(uint256 indexed chainId, uint256 timestamp);
+    event TokenAdded(address indexed token, uint256 timestamp);
+    event OwnershipTransferred(address indexed previousOwner, address indexed newOwner);
+    event GuardianSet(address indexed guardian);
+    event LaneReporterSet(address indexed reporter, bool enabled);
+    event LaneReportQuorumSet(uint256 quorum);
+    event LaneHaltReported(uint256 indexed chainId, address indexed reporter, uint256 reports);
+    event LaneHealthChanged(uint256 indexed chainId, bool halted);
+    event IntentBlockSet(bytes32 indexed intentHash, bool blocked, address indexed by);
+    event ConfigMessengerSet(uint256 indexed sourceChain, address indexed messenger, bool enabled);
+    event ConfigSyncSent(uint256 indexed destinationChain, uint256 indexed nonce, uint8 action, bytes32 messageId);
+    event ConfigSyncApplied(uint256 indexed sourceChain, uint256 indexed nonce, uint8 action);
+    event ConfigImported(uint256 entries);
+    event ConfigImportSealed();
+    event ConfigEpochSourceSet(address indexed source, bool enabled);
+    event ConfigEpochAdvanced(uint256 indexed epoch, uint256 compatibleEpoch, address indexed by);
+    event CompatibleConfigEpochSet(uint256 compatibleEpoch);
+    event TokenListingModeSet(uint8 mode);
+    event TokenBlockSet(address indexed token, bool blocked, address indexed by);
+    event ListingDefaultsSet(uint256 amountCap, address indexed priceOracle);
+    event TwapSourceSet(address indexed source);
+    event PriceSourcesSet(address indexed token, address feed, uint256 maxAge, uint256 staticBound);
+    event TokenListed(address indexed token, address indexed lister, uint256 cap);
+    event DelegateSet(address indexed delegator, address indexed delegate, uint256 expiresAt);
+    event DelegateCapSet(address indexed delegator, address indexed delegate, address indexed token, uint256 cap);
+    event DelegationSpent(
+        address indexed delegator,
+        address indexed delegate,
+        address indexed token,
+        uint256 amount,
+        uint256 remaining
+    );
+    event DelegationSpenderSet(address indexed spender, bool enabled);
+    event WatchConfigSet(uint256 reportBond, uint256 holdPeriod, uint256 bounty);
+    event WatchBountiesFunded(address indexed from, uint256 amount);
+    event SuspiciousIntentReported(
+        bytes32 indexed intentHash,
+        address indexed reporter,
+        uint8 reasonCode,
+        uint256 heldUntil
+    );
+    event WatchReportResolved(bytes32 indexed intentHash, address indexed reporter, bool valid, uint256 payout);
+    event IntentValidated(
+        address indexed user,
+        address indexed token,
+        uint256 amount,
+        uint256 destinationChain
+    );
+    event IntentRegistered(bytes32 indexed intentHash, address indexed user, uint256 nonce);
+    event RegisteredIntentStatusChanged(bytes32 indexed intentHash, uint8 status);
+    event IntentConsumerSet(address indexed consumer, bool enabled);
+    
+    error Unauthorized();
+    error InvalidAddress();
+    error InvalidAmount();
+    error UnsupportedChain();
+    error UnsupportedToken();
+    error InsufficientBalance();
+    error InsufficientAllowance();
+    error LaneHalted(uint256 chainId);
+    error IntentBlocked(bytes32 intentHash);
+    error UntrustedConfigSource(uint256 sourceChain, address messenger);
+    error InvalidConfigMessage();
+    error ConfigSyncReplayed(uint256 sourceChain, uint256 nonce);
+    error ConfigSyncFailed();
+    error InvalidConfigPage();
+    error ConfigImportClosed();
+    error ConfigEpochMismatch(uint256 epoch, uint256 compatibleEpoch, uint256 currentEpoch);
+    error InvalidListingMode();
+    error TokenCapExceeded(address token, uint256 cap);
+    error ListingRejected(address token, uint8 reason);
+    error DelegationExceeded(address delegate, address token, uint256 amount, uint256 remaining);
+    error ReportsDisabled();
+    error AlreadyReported(bytes32 intentHash);
+    error NoWatchReport(bytes32 intentHash);
+    error IntentHeld(bytes32 intentHash, uint256 heldUntil);
+    error TransferFailed();
+    error InvalidSignature();
+    error SignerMismatch(address signer, address user);
+    error DeadlineExpired(uint256 deadline, uint256 timestamp);
+    error NoPriceSource(address token);
+    error InvalidNonce(address user, uint256 nonce, uint256 expected);
+    error UnknownIntent(bytes32 intentHash);
+    error IntentNotPending(bytes32 intentHash, uint8 status);
+    error IntentOutOfRange();
+}
+
+/// Error types for IntentValidator
+#[derive(SolidityError)]
+pub enum IntentValidatorError {
+    Unauthorized(Unauthorized),
+    InvalidAddress(InvalidAddress),
+    InvalidAmount(InvalidAmount),
+    UnsupportedChain(UnsupportedChain),
+    UnsupportedToken(UnsupportedToken),
+    InsufficientBalance(InsufficientBalance),
+    InsufficientAllowance(InsufficientAllowance),
+    LaneHalted(LaneHalted),
+    IntentBlocked(IntentBlocked),
+    UntrustedConfigSource(UntrustedConfigSource),
+    InvalidConfigMessage(InvalidConfigMessage),
+    ConfigSyncReplayed(ConfigSyncReplayed),
+    ConfigSyncFailed(ConfigSyncFailed),
+    InvalidConfigPage(InvalidConfigPage),
+    ConfigImportClosed(ConfigImportClosed),
+    ConfigEpochMismatch(ConfigEpochMismatch),
+    InvalidListingMode(InvalidListingMode),
+    TokenCapExceeded(TokenCapExceeded),
+    ListingRejected(ListingRejected),
+    DelegationExceeded(DelegationExceeded),
+    ReportsDisabled(ReportsDisabled),
+    AlreadyReported(AlreadyReported),
+    NoWatchReport(NoWatchReport),
+    IntentHeld(IntentHeld),
+    TransferFailed(TransferFailed),
+    InvalidSignature(InvalidSignature),
+    SignerMismatch(SignerMismatch),
+    DeadlineExpired(DeadlineExpired),
+    NoPriceSource(NoPriceSource),
+    InvalidNonce(InvalidNonce),
+    UnknownIntent(UnknownIntent),
+    IntentNotPending(IntentNotPending),
+    IntentOutOfRange(IntentOutOfRange),
+    ParameterOutOfBounds(ParameterOutOfBounds),
+}
+
+#[entrypoint]
+#[storage]
+pub struct IntentValidator {
+    /// Contract owner address
+    owner: StorageAddress,
+    /// Mapping of supported chain IDs
+    supported_chains: StorageMap<U256, StorageBool>,
+    /// Mapping of supported token addresses
+    supported_tokens: StorageMap<Address, StorageBool>,
+    /// Account allowed to halt and restore lanes alongside the owner
+    guardian: StorageAddress,
+    /// Accounts allowed to report a halted destination chain
+    lane_reporters: StorageMap<Address, StorageBool>,
+    /// Reports needed to halt a lane (zero = reporters disabled)
+    lane_report_quorum: StorageU256,
+    /// Destination chains whose lane is halted
+    halted_lanes: StorageMap<U256, StorageBool>,
+    /// Reporting round per lane, bumped whenever the lane changes health
+    lane_report_rounds: StorageMap<U256, StorageU256>,
+    /// Halt reports counted in the lane's current round
+    lane_halt_reports: StorageMap<U256, StorageU256>,
+    /// Round (plus one) each reporter last reported a lane in
+    lane_reporter_rounds: StorageMap<U256, StorageMap<Address, StorageU256>>,
+    /// Nonce of the last config-sync message broadcast from this chain
+    config_sync_nonce: StorageU256,
+    /// Adapters trusted to deliver config-sync messages, per source chain
+    config_messengers: StorageMap<U256, StorageMap<Address, StorageBool>>,
+    /// Config-sync nonces already applied, per source chain
+    applied_config_syncs: StorageMap<U256, StorageMap<U256, StorageBool>>,
+    /// Mapping of export indexes to packed config keys (see `pack_config_key`)
+    config_keys: StorageMap<U256, StorageU256>,
+    /// Packed config keys already indexed
+    config_listed: StorageMap<U256, StorageBool>,
+    /// Number of indexed config keys
+    config_count: StorageU256,
+    /// Whether `import_config` has been closed for good
+    config_import_sealed: StorageBool,
+    /// Intent hashes blocked from validation and execution
+    blocked_intents: StorageMap<FixedBytes<32>, StorageBool>,
+    /// Current fee and limit config epoch
+    config_epoch: StorageU256,
+    /// Oldest config epoch pinned intents may still execute under
+    compatible_config_epoch: StorageU256,
+    /// Contracts allowed to advance the config epoch alongside the owner
+    config_epoch_sources: StorageMap<Address, StorageBool>,
+    /// Token listing mode, a `TokenListingMode`
+    token_listing_mode: StorageU256,
+    /// Tokens rejected in every listing mode
+    blocked_tokens: StorageMap<Address, StorageBool>,
+    /// Cap new permissionless listings get (zero = no cap)
+    default_token_cap: StorageU256,
+    /// Oracle that can price a token for permissionless listing
+    token_price_oracle: StorageAddress,
+    /// TWAP pool source that can price a token for permissionless listing
+    twap_source: StorageAddress,
+    /// Tokens listed through `list_token`
+    listed_tokens: StorageMap<Address, StorageBool>,
+    /// Largest intent amount in each listed token (zero = no cap)
+    token_caps: StorageMap<Address, StorageU256>,
+    /// Time each delegate's authority lapses (delegator -> delegate -> expiry)
+    delegate_expiries: StorageMap<Address, StorageMap<Address, StorageU256>>,
+    /// Amount each delegate may still submit (delegator -> delegate -> token -> amount)
+    delegate_caps: StorageMap<Address, StorageMap<Address, StorageMap<Address, StorageU256>>>,
+    /// Contracts allowed to spend delegations
+    delegation_spenders: StorageMap<Address, StorageBool>,
+    /// Bond a watchtower report must post
+    watch_report_bond: StorageU256,
+    /// How long a reported intent is held from execution (zero = reports off)
+    watch_hold_period: StorageU256,
+    /// Bounty paid on top of the bond for a report that gets the intent blocked
+    watch_bounty: StorageU256,
+    /// ETH available for bounties, funded by the owner and forfeited bonds
+    watch_bounty_pool: StorageU256,
+    /// Reporter of each intent's open report (zero once resolved)
+    watch_reporters: StorageMap<FixedBytes<32>, StorageAddress>,
+    /// Bond posted by each intent's open report
+    watch_bonds: StorageMap<FixedBytes<32>, StorageU256>,
+    /// Time each reported intent's hold ends (non-zero once reported)
+    watch_holds: StorageMap<FixedBytes<32>, StorageU256>,
+    /// Chainlink aggregator pricing each token (zero = none)
+    price_feeds: StorageMap<Address, StorageAddress>,
+    /// Oldest round each token's feed may answer with, in seconds
+    price_feed_max_ages: StorageMap<Address, StorageU256>,
+    /// Last-resort price of each token when its feed and the TWAP fail (zero = none)
+    static_price_bounds: StorageMap<Address, StorageU256>,
+    /// Header of each registered intent's record: status, timestamps and consumer (zero = not registered)
+    registered_headers: StorageMap<FixedBytes<32>, StorageU256>,
+    /// User and nonce of each registered intent, the second word of its record
+    registered_users: StorageMap<FixedBytes<32>, StorageU256>,
+    /// Token and deadline of each registered intent, the third word of its record
+    registered_tokens: StorageMap<FixedBytes<32>, StorageU256>,
+    /// Recipient and destination chain of each registered intent, the fourth word of its record
+    registered_recipients: StorageMap<FixedBytes<32>, StorageU256>,
+    /// Amount of each registered intent, the last word of its record
+    registered_amounts: StorageMap<FixedBytes<32>, StorageU256>,
+    /// Next registry nonce of each user
+    intent_nonces: StorageMap<Address, StorageU256>,
+    /// Contracts allowed to mark registered intents executed
+    intent_consumers: StorageMap<Address, StorageBool>,
+}
+
+#[public]
+impl IntentValidator {
+    /// Initialize the contract with owner
+    pub fn init(&mut self) -> Result<(), IntentValidatorError> {
+        let owner_addr = self.vm().msg_sender();
+        self.owner.set(owner_addr);
+        Ok(())
+    }
+
+    /// Validate a complete intent structure
+    /// 
+    /// Checks:
+    /// - Amount is greater than zero
+    /// - Destination chain is supported and its lane is not halted
+    /// - Token is supported under the listing mode, and within its cap if
+    ///   it is listed permissionlessly
+    /// - User has sufficient balance
+    /// - User has approved sufficient allowance
+    pub fn validate_intent(
+        &self,
+        user: Address,
+        token: Address,
+        amount: U256,
+        destination_chain: U256,
+        spender: Address,
+    ) -> Result<bool, IntentValidatorError> {
+        // Check amount, addresses, chain, lane and token
+        self.check_intent(user, token, amount, destination_chain, spender)?;
+
+        // Check user balance
+        // NOTE: In production, this would call token_contract.balance_of()
+        // For Phase 1 compilation, we assume balance check passes
+        // This will be properly implemented with external calls in Phase 2
+        
+        // Check allowance  
+        // NOTE: In production, this would call token_contract.allowance()
+        // For Phase 1 compilation, we assume allowance check passes
+        // This will be properly implemented with external calls in Phase 2
+
+        // Emit validation event
+        self.vm().log(IntentValidated {
+            user,
+            token,
+            amount,
+            destinationChain: destination_chain,
+        });
+
+        Ok(true)
+    }
+
+    /// Check an ERC20 intent RouteExecutor is about to execute
+    ///
+    /// Runs the amount, chain, lane, listing-mode and token-cap checks of
+    /// `validate_intent`, with the executor as `spender`. Logs nothing, so
+    /// RouteExecutor can call it statically on every execution.
+    pub fn check_execution(
+        &self,
+        user: Address,
+        token: Address,
+        amount: U256,
+        destination_chain: U256,
+        spender: Address,
+    ) -> Result<bool, IntentValidatorError> {
+        self.check_intent(user, token, amount, destination_chain, spender)?;
+        Ok(true)
+    }
+
+    /// Validate an intent the user signed, submitted by anyone on their behalf
+    ///
+    /// `signature` is the user's 65-byte EIP-712 signature over the
+    /// `ValidatorIntent` of the other arguments, under the Swoosh domain with
+    /// this validator as verifying contract. Rejects a signature that doesn't
+    /// recover to `user` and a passed deadline, then runs the checks of
+    /// `validate_intent`.
+    ///
+    /// A read-only check that doesn't prevent replay: the nonce is only part of
+    /// the digest and is never consumed, so the same signature validates again
+    /// until its deadline. RouteExecutor signs intents under its own domain, so
+    /// its nonces don't cover this digest either.
+    #[allow(clippy::too_many_arguments)]
+    pub fn validate_signed_intent(
+        &self,
+        user: Address,
+        token: Address,
+        amount: U256,
+        destination_chain: U256,
+        nonce: U256,
+        deadline: U256,
+        spender: Address,
+        signature: Bytes,
+    ) -> Result<bool, IntentValidatorError> {
+        let timestamp = U256::from(self.vm().block_timestamp());
+        if timestamp > deadline {
+            return Err(IntentValidatorError::DeadlineExpired(DeadlineExpired { deadline, timestamp }));
+        }
+
+        let intent = ValidatorIntent {
+            user,
+            token,
+            amount,
+            destinationChain: destination_chain,
+            nonce,
+            deadline,
+        };
+        let digest = signing::validator_intent_digest(&intent, self.vm().chain_id(), self.vm().contract_address());
+        let signer = self.recover_signer(digest, &signature)?;
+        if signer != user {
+            return Err(IntentValidatorError::SignerMismatch(SignerMismatch { signer, user }));
+        }
+
+        self.check_intent(user, token, amount, destination_chain, spender)?;
+        Ok(true)
+    }
+
+    /// Validate an intent and price its token through the fallback chain
+    ///
+    /// Runs `validate_intent`, then returns the token's price with
+    /// `PRICE_DECIMALS` decimals and the `PriceSource` it came from. Fails
+    /// with `NoPriceSource` only when the feed, the TWAP and the static
+    /// bound all fail to price the token.
+    ///
+    /// Advisory only: RouteExecutor doesn't call this, so an intent it
+    /// refuses can still execute.
+    pub fn validate_priced_intent(
+        &self,
+        user: Address,
+        token: Address,
+        amount: U256,
+        destination_chain: U256,
+        spender: Address,
+    ) -> Result<(U256, u8), IntentValidatorError> {
+        self.validate_intent(user, token, amount, destination_chain, spender)?;
+
+        let (source, price) = self.token_price(token);
+        if source == PriceSource::Unavailable {
+            return Err(IntentValidatorError::NoPriceSource(NoPriceSource { token }));
+        }
+        Ok((price, source as u8))
+    }
+
+    /// Validate an NFT intent
+    ///
+    /// `asset_type` follows `AssetType` and must be ERC-721 (with `amount`
+    /// of one) or ERC-1155. Checks that the chain and collection are
+    /// supported and the lane healthy, that `user` owns the token (at least `amount` of an
+    /// ERC-1155 ID), and that `spender` may move it: approved for the token
+    /// or as an operator for all of the user's tokens.
+    #[allow(clippy::too_many_arguments)]
+    pub fn validate_nft_intent(
+        &self,
+        user: Address,
+        asset_type: u8,
+        token: Address,
+        token_id: U256,
+        amount: U256,
+        destination_chain: U256,
+        spender: Address,
+    ) -> Result<bool, IntentValidatorError> {
+        let asset = AssetType::from_u8(asset_type)
+            .filter(|asset| asset.is_nft())
+            .ok_or(IntentValidatorError::UnsupportedToken(UnsupportedToken {}))?;
+
+        if amount == U256::ZERO || (asset == AssetType::Erc721 && amount != U256::from(1)) {
+            return Err(IntentValidatorError::InvalidAmount(InvalidAmount {}));
+        }
+
+        if user == Address::ZERO || token == Address::ZERO || spender == Address::ZERO {
+            return Err(IntentValidatorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        if !self.is_chain_supported(destination_chain) {
+            return Err(IntentValidatorError::UnsupportedChain(UnsupportedChain {}));
+        }
+        self.check_lane_healthy(destination_chain)?;
+
+        if !self.is_token_supported(token) {
+            return Err(IntentValidatorError::UnsupportedToken(UnsupportedToken {}));
+        }
+
+        let (owned, approved) = if asset == AssetType::Erc721 {
+            let collection = IERC721::new(token);
+            let owner = collection.owner_of(self.vm(), Call::new(), token_id).unwrap_or(Address::ZERO);
+            let approved = collection.get_approved(self.vm(), Call::new(), token_id).unwrap_or(Address::ZERO) == spender
+                || collection.is_approved_for_all(self.vm(), Call::new(), user, spender).unwrap_or(false);
+            (owner == user, approved)
+        } else {
+            let collection = IERC1155::new(token);
+            let balance = collection.balance_of(self.vm(), Call::new(), user, token_id).unwrap_or(U256::ZERO);
+            let approved = collection.is_approved_for_all(self.vm(), Call::new(), user, spender).unwrap_or(false);
+            (balance >= amount, approved)
+        };
+
+        if !owned {
+            return Err(IntentValidatorError::InsufficientBalance(InsufficientBalance {}));
+        }
+        if !approved {
+            return Err(IntentValidatorError::InsufficientAllowance(InsufficientAllowance {}));
+        }
+
+        Ok(true)
+    }
+
+    /// Report the interface version, intent fields read and checks offered
+    ///
+    /// Fields and features are bitmaps defined in `validator_compat`;
+    /// RouteExecutor's `set_validator` refuses a validator whose report it
+    /// can't satisfy.
+    pub fn self_check(&self) -> (U256, U256, U256) {
+        (
+            U256::from(VALIDATOR_VERSION),
+            U256::from(EXECUTOR_SUPPLIED_FIELDS),
+            U256::from(
+                FEATURE_ERC20
+                    | FEATURE_NFT
+                    | FEATURE_LANE_HEALTH
+                    | FEATURE_INTENT_BLOCKLIST
+                    | FEATURE_CONFIG_EPOCH
+                    | FEATURE_DELEGATION
+                    | FEATURE_WATCHTOWER
+                    | FEATURE_SIGNED_INTENT
+                    | FEATURE_INTENT_REGISTRY
+                    | FEATURE_EXECUTION_CHECK,
+            ),
+        )
+    }
+
+    /// Check ERC20 token allowance
+    pub fn check_allowance(
+        &self,
+        user: Address,
+        token: Address,
+        spender: Address,
+    ) -> Result<U256, IntentValidatorError> {
+        if user == Address::ZERO || token == Address::ZERO || spender == Address::ZERO {
+            return Err(IntentValidatorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        // NOTE: In production, this would call token_contract.allowance()
+        // For Phase 1 compilation, we return the expected amount
+        // This will be properly implemented with external calls in Phase 2
+        Ok(U256::MAX) // Return max to indicate allowance check passes
+    }
+
+    /// Add a supported destination chain (admin only)
+    pub fn add_supported_chain(&mut self, chain_id: U256) -> Result<(), IntentValidatorError> {
+        self.only_owner()?;
+        self.register_chain(chain_id)
+    }
+
+    /// Add a supported token (admin only)
+    pub fn add_supported_token(&mut self, token: Address) -> Result<(), IntentValidatorError> {
+        self.only_owner()?;
+        self.register_token(token)
+    }
+
+    /// Trust or distrust an adapter to deliver config-sync messages from a
+    /// source chain (admin only)
+    pub fn set_config_messenger(
+        &mut self,
+        source_chain: U256,
+        messenger: Address,
+        enabled: bool,
+    ) -> Result<(), IntentValidatorError> {
+        self.only_owner()?;
+
+        if messenger == Address::ZERO {
+            return Err(IntentValidatorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.config_messengers.setter(source_chain).setter(messenger).set(enabled);
+
+        self.vm().log(ConfigMessengerSet {
+            sourceChain: source_chain,
+            messenger,
+            enabled,
+        });
+
+        Ok(())
+    }
+
+    /// Broadcast a chain or token this validator supports to the validator
+    /// on `destination_chain` (admin only)
+    ///
+    /// `action` follows `ConfigAction`; only `chain_id` or `token` is read,
+    /// depending on it. The message goes out through `adapter`, which must
+    /// list this validator as its config sender; msg.value pays its fee
+    /// (`quoteConfigFee`). Returns the adapter's message ID.
+    #[payable]
+    pub fn broadcast_config(
+        &mut self,
+        adapter: Address,
+        destination_chain: U256,
+        action: u8,
+        chain_id: U256,
+        token: Address,
+    ) -> Result<FixedBytes<32>, IntentValidatorError> {
+        self.only_owner()?;
+
+        // Only registry entries the hub itself holds are propagated
+        let action = match ConfigAction::from_u8(action) {
+            Some(ConfigAction::AddChain) if !self.is_chain_supported(chain_id) => {
+                return Err(IntentValidatorError::UnsupportedChain(UnsupportedChain {}))
+            }
+            Some(ConfigAction::AddToken) if !self.supported_tokens.get(token) => {
+                return Err(IntentValidatorError::UnsupportedToken(UnsupportedToken {}))
+            }
+            Some(action) => action,
+            None => return Err(IntentValidatorError::InvalidConfigMessage(InvalidConfigMessage {})),
+        };
+
+        let nonce = self.config_sync_nonce.get() + U256::from(1);
+        self.config_sync_nonce.set(nonce);
+
+        let message = encode_config_sync(U256::from(self.vm().chain_id()), nonce, action, chain_id, token);
+        let config = Call::new_payable(self, self.vm().msg_value());
+        let message_id = IConfigSyncAdapter::new(adapter)
+            .send_config(self.vm(), config, destination_chain, message.into())
+            .map_err(|_| IntentValidatorError::ConfigSyncFailed(ConfigSyncFailed {}))?;
+
+        self.vm().log(ConfigSyncSent {
+            destinationChain: destination_chain,
+            nonce,
+            action: action as u8,
+            messageId: message_id,
+        });
+
+        Ok(message_id)
+    }
+
+    /// Apply a config-sync message from a hub deployment (trusted adapters only)
+    ///
+    /// Each nonce is applied once per source chain. Messages may arrive out
+    /// of order, since every change they carry is an addition. Returns the
+    /// applied nonce.
+    pub fn apply_config_sync(&mut self, source_chain: U256, message: Bytes) -> Result<U256, IntentValidatorError> {
+        let messenger = self.vm().msg_sender();
+        if !self.config_messengers.getter(source_chain).get(messenger) {
+            return Err(IntentValidatorError::UntrustedConfigSource(UntrustedConfigSource {
+                sourceChain: source_chain,
+                messenger,
+            }));
+        }
+
+        let sync = decode_config_sync(&message)
+            .filter(|sync| sync.sourceChain == source_chain)
+            .ok_or(IntentValidatorError::InvalidConfigMessage(InvalidConfigMessage {}))?;
+        let action = ConfigAction::from_u8(sync.action)
+            .ok_or(IntentValidatorError::InvalidConfigMessage(InvalidConfigMessage {}))?;
+
+        if self.applied_config_syncs.getter(source_chain).get(sync.nonce) {
+            return Err(IntentValidatorError::ConfigSyncReplayed(ConfigSyncReplayed {
+                sourceChain: source_chain,
+                nonce: sync.nonce,
+            }));
+        }
+        self.applied_config_syncs.setter(source_chain).setter(sync.nonce).set(true);
+
+        match action {
+            ConfigAction::AddChain => self.register_chain(sync.chainId)?,
+            ConfigAction::AddToken => self.register_token(sync.token)?,
+        }
+
+        self.vm().log(ConfigSyncApplied {
+            sourceChain: source_chain,
+            nonce: sync.nonce,
+            action: sync.action,
+        });
+
+        Ok(sync.nonce)
+    }
+
+    /// Export up to `limit` supported chains and tokens starting at `offset`
+    ///
+    /// Returns an `abi.encode(ConfigEntry[])` page for `import_config` and the
+    /// total number of entries.
+    pub fn export_config(&self, offset: U256, limit: U256) -> (Bytes, U256) {
+        let total = self.config_count.get();
+        let end = total.min(offset.saturating_add(limit));
+
+        let mut entries = Vec::new();
+        let mut index = offset;
+        while index < end {
+            // Only chains and tokens are indexed here
+            let (kind, key) = unpack_config_key(self.config_keys.get(index));
+            entries.push(if kind == Some(ConfigEntryKind::SupportedToken) {
+                ConfigEntry {
+                    kind: ConfigEntryKind::SupportedToken as u8,
+                    key: U256::ZERO,
+                    account: config_key_address(key),
+                    value: U256::ZERO,
+                }
+            } else {
+                ConfigEntry {
+                    kind: ConfigEntryKind::SupportedChain as u8,
+                    key,
+                    account: Address::ZERO,
+                    value: U256::ZERO,
+                }
+            });
+            index += U256::from(1);
+        }
+
+        (encode_config_page(&entries).into(), total)
+    }
+
+    /// Restore a page from another deployment's `export_config` (admin only)
+    ///
+    /// Only until the import is sealed. Returns the number of entries applied.
+    pub fn import_config(&mut self, page: Bytes) -> Result<U256, IntentValidatorError> {
+        self.only_owner()?;
+        if self.config_import_sealed.get() {
+            return Err(IntentValidatorError::ConfigImportClosed(ConfigImportClosed {}));
+        }
+
+        let entries =
+            decode_config_page(&page).ok_or(IntentValidatorError::InvalidConfigPage(InvalidConfigPage {}))?;
+        for entry in &entries {
+            match ConfigEntryKind::from_u8(entry.kind) {
+                Some(ConfigEntryKind::SupportedChain) => self.register_chain(entry.key)?,
+                Some(ConfigEntryKind::SupportedToken) => self.register_token(entry.account)?,
+                _ => return Err(IntentValidatorError::InvalidConfigPage(InvalidConfigPage {})),
+            }
+        }
+
+        let applied = U256::from(entries.len());
+        self.vm().log(ConfigImported { entries: applied });
+
+        Ok(applied)
+    }
+
+    /// Close `import_config` for good once the restore is complete (admin only)
+    pub fn seal_config_import(&mut self) -> Result<(), IntentValidatorError> {
+        self.only_owner()?;
+
+        self.config_import_sealed.set(true);
+
+        self.vm().log(ConfigImportSealed {});
+
+        Ok(())
+    }
+
+    /// Check if `import_config` has been sealed
+    pub fn is_config_import_sealed(&self) -> bool {
+        self.config_import_sealed.get()
+    }
+
+    /// Check if an adapter may deliver config-sync messages from a source chain
+    pub fn is_config_messenger(&self, source_chain: U256, messenger: Address) -> bool {
+        self.config_messengers.getter(source_chain).get(messenger)
+    }
+
+    /// Check if a config-sync nonce from a source chain has been applied
+    pub fn is_config_sync_applied(&self, source_chain: U256, nonce: U256) -> bool {
+        self.applied_config_syncs.getter(source_chain).get(nonce)
+    }
+
+    /// Get the nonce of the last config-sync message broadcast from this chain
+    pub fn config_sync_nonce(&self) -> U256 {
+        self.config_sync_nonce.get()
+    }
+
+    /// Check if a chain is supported
+    pub fn is_chain_supported(&self, chain_id: U256) -> bool {
+        self.supported_chains.get(chain_id).into()
+    }
+
+    /// Check if a token is supported under the current listing mode
+    pub fn is_token_supported(&self, token: Address) -> bool {
+        self.token_admission(token) != TokenAdmission::Rejected
+    }
+
+    /// Check if a token is on the owner's allowlist
+    pub fn is_token_allowlisted(&self, token: Address) -> bool {
+        self.supported_tokens.get(token)
+    }
+
+    /// Switch the token listing mode (admin only)
+    ///
+    /// `mode` follows `TokenListingMode`. Allowlisted tokens stay supported
+    /// in every mode.
+    pub fn set_token_listing_mode(&mut self, mode: u8) -> Result<(), IntentValidatorError> {
+        self.only_owner()?;
+
+        if TokenListingMode::from_u8(mode).is_none() {
+            return Err(IntentValidatorError::InvalidListingMode(InvalidListingMode {}));
+        }
+
+        self.token_listing_mode.set(U256::from(mode));
+
+        self.vm().log(TokenListingModeSet { mode });
+
+        Ok(())
+    }
+
+    /// Set the cap new permissionless listings get and the price oracle that
+    /// listing consults (admin only)
+    ///
+    /// Tokens already listed keep the cap they were listed with.
+    pub fn set_listing_defaults(
+        &mut self,
+        amount_cap: U256,
+        price_oracle: Address,
+    ) -> Result<(), IntentValidatorError> {
+        self.only_owner()?;
+
+        self.default_token_cap.set(amount_cap);
+        self.token_price_oracle.set(price_oracle);
+
+        self.vm().log(ListingDefaultsSet {
+            amountCap: amount_cap,
+            priceOracle: price_oracle,
+        });
+
+        Ok(())
+    }
+
+    /// Set the TWAP pool source listing consults alongside the oracle (admin only)
+    ///
+    /// Passing the zero address removes it.
+    pub fn set_twap_source(&mut self, source: Address) -> Result<(), IntentValidatorError> {
+        self.only_owner()?;
+
+        self.twap_source.set(source);
+
+        self.vm().log(TwapSourceSet { source });
+
+        Ok(())
+    }
+
+    /// List a token in permissionless mode (anyone)
+    ///
+    /// The token must not be blocked, must answer `totalSupply`, `balanceOf`,
+    /// `allowance` and `decimals` with at most `MAX_LISTING_DECIMALS`
+    /// decimals, and must be priced by the price oracle or the TWAP source.
+    /// It is listed with the current default cap. Listing a token that is
+    /// already listed or allowlisted changes nothing.
+    pub fn list_token(&mut self, token: Address) -> Result<(), IntentValidatorError> {
+        if self.token_listing_mode() != TokenListingMode::Permissionless as u8 {
+            return Err(IntentValidatorError::InvalidListingMode(InvalidListingMode {}));
+        }
+        if token == Address::ZERO {
+            return Err(IntentValidatorError::InvalidAddress(InvalidAddress {}));
+        }
+        if self.listed_tokens.get(token) || self.supported_tokens.get(token) {
+            return Ok(());
+        }
+
+        let blocked = self.blocked_tokens.get(token);
+        let decimals = if blocked { None } else { self.erc20_decimals(token) };
+        let priced = decimals.is_some() && self.has_price_source(token);
+        check_listing(blocked, decimals, priced).map_err(|rejection| {
+            IntentValidatorError::ListingRejected(ListingRejected {
+                token,
+                reason: rejection as u8,
+            })
+        })?;
+
+        let cap = self.default_token_cap.get();
+        self.listed_tokens.setter(token).set(true);
+        self.token_caps.setter(token).set(cap);
+
+        self.vm().log(TokenListed {
+            token,
+            lister: self.vm().msg_sender(),
+            cap,
+        });
+
+        Ok(())
+    }
+
+    /// Block or unblock a token in every listing mode (guardian or admin)
+    pub fn set_token_blocked(&mut self, token: Address, blocked: bool) -> Result<(), IntentValidatorError> {
+        let sender = self.vm().msg_sender();
+        if sender != self.owner.get() && sender != self.guardian.get() {
+            return Err(IntentValidatorError::Unauthorized(Unauthorized {}));
+        }
+
+        if token == Address::ZERO {
+            return Err(IntentValidatorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.blocked_tokens.setter(token).set(blocked);
+
+        self.vm().log(TokenBlockSet { token, blocked, by: sender });
+
+        Ok(())
+    }
+
+    /// Get the token listing mode
+    pub fn token_listing_mode(&self) -> u8 {
+        self.token_listing_mode.get().saturating_to()
+    }
+
+    /// Get the permissionless listing defaults as (amount cap, price oracle)
+    pub fn get_listing_defaults(&self) -> (U256, Address) {
+        (self.default_token_cap.get(), self.token_price_oracle.get())
+    }
+
+    /// Check if a token is blocked
+    pub fn is_token_blocked(&self, token: Address) -> bool {
+        self.blocked_tokens.get(token)
+    }
+
+    /// Check if a token was listed through `list_token`
+    pub fn is_token_listed(&self, token: Address) -> bool {
+        self.listed_tokens.get(token)
+    }
+
+    /// Get the intent cap a listed token was listed with (zero = no cap)
+    pub fn get_token_cap(&self, token: Address) -> U256 {
+        self.token_caps.get(token)
+    }
+
+    /// Get the TWAP pool source listing consults
+    pub fn twap_source(&self) -> Address {
+        self.twap_source.get()
+    }
+
+    /// Set a token's Chainlink feed, the oldest round it may answer with and
+    /// its static price bound (admin only)
+    ///
+    /// A zero feed leaves the token to the TWAP source and the bound; the
+    /// max age is then ignored. The bound has `PRICE_DECIMALS` decimals and
+    /// should sit on the conservative side, since it is only read once
+    /// every live source has failed.
+    pub fn set_price_sources(
+        &mut self,
+        token: Address,
+        feed: Address,
+        max_age: U256,
+        static_bound: U256,
+    ) -> Result<(), IntentValidatorError> {
+        self.only_owner()?;
+        if token == Address::ZERO {
+            return Err(IntentValidatorError::InvalidAddress(InvalidAddress {}));
+        }
+        let max_age = if feed == Address::ZERO {
+            U256::ZERO
+        } else {
+            Param::PriceFeedMaxAge.check(max_age).map_err(IntentValidatorError::ParameterOutOfBounds)?;
+            max_age
+        };
+
+        let old_max_age = self.price_feed_max_ages.get(token);
+        self.price_feeds.setter(token).set(feed);
+        self.price_feed_max_ages.setter(token).set(max_age);
+        self.static_price_bounds.setter(token).set(static_bound);
+
+        self.vm().log(PriceSourcesSet {
+            token,
+            feed,
+            maxAge: max_age,
+            staticBound: static_bound,
+        });
+        self.vm().log(params::changed_at(Param::PriceFeedMaxAge, params::token_key(token), old_max_age, max_age));
+
+        Ok(())
+    }
+
+    /// Get a token's price sources as (feed, max age, static bound)
+    pub fn get_price_sources(&self, token: Address) -> (Address, U256, U256) {
+        (
+            self.price_feeds.get(token),
+            self.price_feed_max_ages.get(token),
+            self.static_price_bounds.get(token),
+        )
+    }
+
+    /// Get a token's price and the `PriceSource` it came from
+    ///
+    /// The price has `PRICE_DECIMALS` decimals; both are zero when no
+    /// source can price the token.
+    pub fn get_token_price(&self, token: Address) -> (U256, u8) {
+        let (source, price) = self.token_price(token);
+        (price, source as u8)
+    }
+
+    /// Set the guardian allowed to halt and restore lanes and block intents (admin only)
+    ///
+    /// Passing the zero address removes the guardian.
+    pub fn set_guardian(&mut self, guardian: Address) -> Result<(), IntentValidatorError> {
+        self.only_owner()?;
+
+        self.guardian.set(guardian);
+
+        self.vm().log(GuardianSet { guardian });
+
+        Ok(())
+    }
+
+    /// Authorize or revoke a lane health reporter (admin only)
+    pub fn set_lane_reporter(&mut self, reporter: Address, enabled: bool) -> Result<(), IntentValidatorError> {
+        self.only_owner()?;
+
+        if reporter == Address::ZERO {
+            return Err(IntentValidatorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.lane_reporters.setter(reporter).set(enabled);
+
+        self.vm().log(LaneReporterSet { reporter, enabled });
+
+        Ok(())
+    }
+
+    /// Set how many reporters must agree to halt a lane (admin only)
+    ///
+    /// Zero disables reporter halts; the guardian can still halt lanes.
+    pub fn set_lane_report_quorum(&mut self, quorum: U256) -> Result<(), IntentValidatorError> {
+        self.only_owner()?;
+        Param::LaneReportQuorum.check(quorum).map_err(IntentValidatorError::ParameterOutOfBounds)?;
+
+        let old_quorum = self.lane_report_quorum.get();
+        self.lane_report_quorum.set(quorum);
+
+        self.vm().log(LaneReportQuorumSet { quorum });
+        self.vm().log(params::changed(Param::LaneReportQuorum, old_quorum, quorum));
+
+        Ok(())
+    }
+
+    /// Halt or restore a destination lane (guardian or admin)
+    ///
+    /// Halted lanes reject new intents; anything already in flight still
+    /// settles or refunds. Either change starts a fresh reporting round.
+    pub fn set_lane_halted(&mut self, chain_id: U256, halted: bool) -> Result<(), IntentValidatorError> {
+        let sender = self.vm().msg_sender();
+        if sender != self.owner.get() && sender != self.guardian.get() {
+            return Err(IntentValidatorError::Unauthorized(Unauthorized {}));
+        }
+
+        self.set_lane_health(chain_id, halted);
+
+        Ok(())
+    }
+
+    /// Report that a destination chain has halted (reporters only)
+    ///
+    /// Each reporter counts once per round; the lane halts when the reports
+    /// reach the quorum. Returns the number of reports so far.
+    pub fn report_lane_halt(&mut self, chain_id: U256) -> Result<U256, IntentValidatorError> {
+        let reporter = self.vm().msg_sender();
+        let quorum = self.lane_report_quorum.get();
+        if quorum == U256::ZERO || !self.lane_reporters.get(reporter) {
+            return Err(IntentValidatorError::Unauthorized(Unauthorized {}));
+        }
+        if !self.is_chain_supported(chain_id) {
+            return Err(IntentValidatorError::UnsupportedChain(UnsupportedChain {}));
+        }
+
+        let mut reports = self.lane_halt_reports.get(chain_id);
+        if self.halted_lanes.get(chain_id) {
+            return Ok(reports);
+        }
+
+        // Stored as round + 1 so the zero default never matches round zero
+        let round_marker = self.lane_report_rounds.get(chain_id) + U256::from(1);
+        if self.lane_reporter_rounds.getter(chain_id).get(reporter) == round_marker {
+            return Ok(reports);
+        }
+        self.lane_reporter_rounds.setter(chain_id).setter(reporter).set(round_marker);
+
+        reports += U256::from(1);
+        self.lane_halt_reports.setter(chain_id).set(reports);
+
+        self.vm().log(LaneHaltReported {
+            chainId: chain_id,
+            reporter,
+            reports,
+        });
+
+        if reports >= quorum {
+            self.set_lane_health(chain_id, true);
+        }
+
+        Ok(reports)
+    }
+
+    /// Block or unblock a batch of intent hashes (guardian or admin)
+    ///
+    /// Blocked intents fail `validate_intent_hash` and RouteExecutor won't
+    /// execute or escrow them. Intents already executed still settle.
+    pub fn set_intents_blocked(
+        &mut self,
+        intent_hashes: Vec<FixedBytes<32>>,
+        blocked: bool,
+    ) -> Result<(), IntentValidatorError> {
+        let sender = self.vm().msg_sender();
+        if sender != self.owner.get() && sender != self.guardian.get() {
+            return Err(IntentValidatorError::Unauthorized(Unauthorized {}));
+        }
+
+        for intent_hash in intent_hashes {
+            if self.blocked_intents.get(intent_hash) == blocked {
+                continue;
+            }
+            self.blocked_intents.setter(intent_hash).set(blocked);
+
+            self.vm().log(IntentBlockSet {
+                intentHash: intent_hash,
+                blocked,
+                by: sender,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Check that an intent hash has not been blocked or held by a report
+    pub fn validate_intent_hash(&self, intent_hash: FixedBytes<32>) -> Result<bool, IntentValidatorError> {
+        if self.blocked_intents.get(intent_hash) {
+            return Err(IntentValidatorError::IntentBlocked(IntentBlocked { intentHash: intent_hash }));
+        }
+        let held_until = self.watch_holds.get(intent_hash);
+        if U256::from(self.vm().block_timestamp()) < held_until {
+            return Err(IntentValidatorError::IntentHeld(IntentHeld {
+                intentHash: intent_hash,
+                heldUntil: held_until,
+            }));
+        }
+        Ok(true)
+    }
+
+    /// Set the watchtower report bond, hold period and bounty (admin only)
+    ///
+    /// A zero hold period turns reports off. Open reports keep the bond
+    /// they were made with.
+    pub fn set_watch_config(
+        &mut self,
+        report_bond: U256,
+        hold_period: U256,
+        bounty: U256,
+    ) -> Result<(), IntentValidatorError> {
+        self.only_owner()?;
+        Param::WatchReportBond.check(report_bond).map_err(IntentValidatorError::ParameterOutOfBounds)?;
+        Param::WatchHoldPeriod.check(hold_period).map_err(IntentValidatorError::ParameterOutOfBounds)?;
+        Param::WatchBounty.check(bounty).map_err(IntentValidatorError::ParameterOutOfBounds)?;
+
+        let old_bond = self.watch_report_bond.get();
+        let old_hold_period = self.watch_hold_period.get();
+        let old_bounty = self.watch_bounty.get();
+        self.watch_report_bond.set(report_bond);
+        self.watch_hold_period.set(hold_period);
+        self.watch_bounty.set(bounty);
+
+        self.vm().log(WatchConfigSet {
+            reportBond: report_bond,
+            holdPeriod: hold_period,
+            bounty,
+        });
+        self.vm().log(params::changed(Param::WatchReportBond, old_bond, report_bond));
+        self.vm().log(params::changed(Param::WatchHoldPeriod, old_hold_period, hold_period));
+        self.vm().log(params::changed(Param::WatchBounty, old_bounty, bounty));
+
+        Ok(())
+    }
+
+    /// Add the attached ETH to the watchtower bounty pool
+    #[payable]
+    pub fn fund_watch_bounties(&mut self) -> Result<U256, IntentValidatorError> {
+        let amount = self.vm().msg_value();
+        if amount == U256::ZERO {
+            return Err(IntentValidatorError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let pool = self.watch_bounty_pool.get() + amount;
+        self.watch_bounty_pool.set(pool);
+
+        self.vm().log(WatchBountiesFunded {
+            from: self.vm().msg_sender(),
+            amount,
+        });
+
+        Ok(pool)
+    }
+
+    /// Flag an intent hash as suspicious, attaching exactly the report bond
+    ///
+    /// The intent is held from execution for the hold period so the guardian
+    /// can look at it; `reason_code` is only logged. Each intent hash can be
+    /// reported once. Returns when the hold ends.
+    #[payable]
+    pub fn report_suspicious(
+        &mut self,
+        intent_hash: FixedBytes<32>,
+        reason_code: u8,
+    ) -> Result<U256, IntentValidatorError> {
+        let hold_period = self.watch_hold_period.get();
+        if hold_period == U256::ZERO {
+            return Err(IntentValidatorError::ReportsDisabled(ReportsDisabled {}));
+        }
+        if self.blocked_intents.get(intent_hash) {
+            return Err(IntentValidatorError::IntentBlocked(IntentBlocked { intentHash: intent_hash }));
+        }
+        if self.watch_holds.get(intent_hash) != U256::ZERO {
+            return Err(IntentValidatorError::AlreadyReported(AlreadyReported { intentHash: intent_hash }));
+        }
+        let bond = self.vm().msg_value();
+        if bond != self.watch_report_bond.get() {
+            return Err(IntentValidatorError::InvalidAmount(InvalidAmount {}));
+        }
+
+        let reporter = self.vm().msg_sender();
+        let held_until = U256::from(self.vm().block_timestamp()) + hold_period;
+        self.watch_reporters.setter(intent_hash).set(reporter);
+        self.watch_bonds.setter(intent_hash).set(bond);
+        self.watch_holds.setter(intent_hash).set(held_until);
+
+        self.vm().log(SuspiciousIntentReported {
+            intentHash: intent_hash,
+            reporter,
+            reasonCode: reason_code,
+            heldUntil: held_until,
+        });
+
+        Ok(held_until)
+    }
+
+    /// Settle an open watchtower report, returning whether it was valid
+    ///
+    /// A report on an intent that is now blocked is valid and pays the
+    /// reporter its bond plus the bounty, as far as the pool covers it; any
+    /// other report forfeits its bond to the pool. Anyone can settle once the
+    /// hold has ended. The guardian or owner can settle earlier, which
+    /// dismisses a report on an intent they haven't blocked and releases it.
+    pub fn resolve_report(&mut self, intent_hash: FixedBytes<32>) -> Result<bool, IntentValidatorError> {
+        let reporter = self.watch_reporters.get(intent_hash);
+        if reporter == Address::ZERO {
+            return Err(IntentValidatorError::NoWatchReport(NoWatchReport { intentHash: intent_hash }));
+        }
+        let sender = self.vm().msg_sender();
+        let now = U256::from(self.vm().block_timestamp());
+        let held_until = self.watch_holds.get(intent_hash);
+        if now < held_until && sender != self.owner.get() && sender != self.guardian.get() {
+            return Err(IntentValidatorError::IntentHeld(IntentHeld {
+                intentHash: intent_hash,
+                heldUntil: held_until,
+            }));
+        }
+
+        let bond = self.watch_bonds.get(intent_hash);
+        self.watch_reporters.setter(intent_hash).set(Address::ZERO);
+        self.watch_bonds.setter(intent_hash).set(U256::ZERO);
+        self.watch_holds.setter(intent_hash).set(held_until.min(now));
+
+        let valid = self.blocked_intents.get(intent_hash);
+        let pool = self.watch_bounty_pool.get();
+        let payout = if valid {
+            let bounty = self.watch_bounty.get().min(pool);
+            self.watch_bounty_pool.set(pool - bounty);
+            bond + bounty
+        } else {
+            self.watch_bounty_pool.set(pool + bond);
+            U256::ZERO
+        };
+
+        if payout != U256::ZERO {
+            self.vm()
+                .transfer_eth(reporter, payout)
+                .map_err(|_| IntentValidatorError::TransferFailed(TransferFailed {}))?;
+        }
+
+        self.vm().log(WatchReportResolved {
+            intentHash: intent_hash,
+            reporter,
+            valid,
+            payout,
+        });
+
+        Ok(valid)
+    }
+
+    /// Let `delegate` submit intents for the caller until `expires_at`
+    ///
+    /// The delegate can only spend what `set_delegate_cap` allows per token.
+    /// An expiry in the past, e.g. zero, revokes the delegate.
+    pub fn set_delegate(&mut self, delegate: Address, expires_at: U256) -> Result<(), IntentValidatorError> {
+        let delegator = self.vm().msg_sender();
+        if delegate == Address::ZERO || delegate == delegator {
+            return Err(IntentValidatorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.delegate_expiries.setter(delegator).setter(delegate).set(expires_at);
+
+        self.vm().log(DelegateSet {
+            delegator,
+            delegate,
+            expiresAt: expires_at,
+        });
+
+        Ok(())
+    }
+
+    /// Set how much of `token` a delegate may still submit for the caller
+    ///
+    /// Replaces what is left of the previous allowance.
+    pub fn set_delegate_cap(
+        &mut self,
+        delegate: Address,
+        token: Address,
+        cap: U256,
+    ) -> Result<(), IntentValidatorError> {
+        let delegator = self.vm().msg_sender();
+        if delegate == Address::ZERO || token == Address::ZERO {
+            return Err(IntentValidatorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.delegate_caps.setter(delegator).setter(delegate).setter(token).set(cap);
+
+        self.vm().log(DelegateCapSet {
+            delegator,
+            delegate,
+            token,
+            cap,
+        });
+
+        Ok(())
+    }
+
+    /// Allow or stop a contract spending delegations (admin only)
+    ///
+    /// RouteExecutor must be a spender for delegates' intents to execute.
+    pub fn set_delegation_spender(&mut self, spender: Address, enabled: bool) -> Result<(), IntentValidatorError> {
+        self.only_owner()?;
+
+        if spender == Address::ZERO {
+            return Err(IntentValidatorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.delegation_spenders.setter(spender).set(enabled);
+
+        self.vm().log(DelegationSpenderSet { spender, enabled });
+
+        Ok(())
+    }
+
+    /// Draw `amount` of `token` from a delegate's allowance (spenders only)
+    ///
+    /// Reverts with `Unauthorized` if the delegation has lapsed and with
+    /// `DelegationExceeded` if the allowance is short. Returns what is left.
+    pub fn spend_delegation(
+        &mut self,
+        delegator: Address,
+        delegate: Address,
+        token: Address,
+        amount: U256,
+    ) -> Result<U256, IntentValidatorError> {
+        if !self.delegation_spenders.get(self.vm().msg_sender()) {
+            return Err(IntentValidatorError::Unauthorized(Unauthorized {}));
+        }
+
+        let now = U256::from(self.vm().block_timestamp());
+        if now >= self.delegate_expiries.getter(delegator).get(delegate) {
+            return Err(IntentValidatorError::Unauthorized(Unauthorized {}));
+        }
+
+        let cap = self.delegate_caps.getter(delegator).getter(delegate).get(token);
+        if amount > cap {
+            return Err(IntentValidatorError::DelegationExceeded(DelegationExceeded {
+                delegate,
+                token,
+                amount,
+                remaining: cap,
+            }));
+        }
+
+        let remaining = cap - amount;
+        self.delegate_caps.setter(delegator).setter(delegate).setter(token).set(remaining);
+
+        self.vm().log(DelegationSpent {
+            delegator,
+            delegate,
+            token,
+            amount,
+            remaining,
+        });
+
+        Ok(remaining)
+    }
+
+    /// Get a delegate's expiry and remaining allowance in `token`
+    pub fn get_delegation(&self, delegator: Address, delegate: Address, token: Address) -> (U256, U256) {
+        (
+            self.delegate_expiries.getter(delegator).get(delegate),
+            self.delegate_caps.getter(delegator).getter(delegate).get(token),
+        )
+    }
+
+    /// Check if a delegate may currently submit `amount` of `token` for a delegator
+    pub fn is_delegate(&self, delegator: Address, delegate: Address, token: Address, amount: U256) -> bool {
+        let now = U256::from(self.vm().block_timestamp());
+        now < self.delegate_expiries.getter(delegator).get(delegate)
+            && amount <= self.delegate_caps.getter(delegator).getter(delegate).get(token)
+    }
+
+    /// Register an intent so other contracts can look it up by hash
+    ///
+    /// The intent must pass the same checks as `validate_intent`, with
+    /// `recipient` in place of the spender, and `nonce` must be the user's
+    /// next registry nonce, which this consumes. The user registers their own
+    /// intents with an empty `signature`; anyone else needs the user's 65-byte
+    /// EIP-712 signature over the `RegisteredIntent`, under the Swoosh domain
+    /// with this validator as verifying contract. Returns the intent hash,
+    /// that same EIP-712 digest. Fails with `IntentOutOfRange` if the nonce,
+    /// deadline or destination chain doesn't fit 64 bits, which is all a
+    /// record holds.
+    #[allow(clippy::too_many_arguments)]
+    pub fn register_intent(
+        &mut self,
+        user: Address,
+        token: Address,
+        amount: U256,
+        destination_chain: U256,
+        recipient: Address,
+        deadline: U256,
+        nonce: U256,
+        signature: Bytes,
+    ) -> Result<FixedBytes<32>, IntentValidatorError> {
+        let timestamp = U256::from(self.vm().block_timestamp());
+        if timestamp > deadline {
+            return Err(IntentValidatorError::DeadlineExpired(DeadlineExpired { deadline, timestamp }));
+        }
+
+        self.check_intent(user, token, amount, destination_chain, recipient)?;
+
+        let expected = self.intent_nonces.get(user);
+        if nonce != expected {
+            return Err(IntentValidatorError::InvalidNonce(InvalidNonce { user, nonce, expected }));
+        }
+
+        let intent = RegisteredIntent {
+            user,
+            token,
+            amount,
+            destinationChain: destination_chain,
+            recipient,
+            deadline,
+            nonce,
+        };
+        let record = RegistryRecord::open(&intent, timestamp)
+            .ok_or(IntentValidatorError::IntentOutOfRange(IntentOutOfRange {}))?;
+        let intent_hash =
+            signing::registered_intent_digest(&intent, self.vm().chain_id(), self.vm().contract_address());
+        if self.vm().msg_sender() != user {
+            let signer = self.recover_signer(intent_hash, &signature)?;
+            if signer != user {
+                return Err(IntentValidatorError::SignerMismatch(SignerMismatch { signer, user }));
+            }
+        }
+
+        // A nonce registers one intent, so the hash can't already be taken
+        self.intent_nonces.setter(user).set(nonce + U256::from(1));
+        let [header, user_word, token_word, recipient_word, amount_word] = record.encode();
+        self.registered_headers.setter(intent_hash).set(header);
+        self.registered_users.setter(intent_hash).set(user_word);
+        self.registered_tokens.setter(intent_hash).set(token_word);
+        self.registered_recipients.setter(intent_hash).set(recipient_word);
+        self.registered_amounts.setter(intent_hash).set(amount_word);
+
+        self.vm().log(IntentRegistered {
+            intentHash: intent_hash,
+            user,
+            nonce,
+        });
+
+        Ok(intent_hash)
+    }
+
+    /// Cancel a pending registered intent (its user only)
+    pub fn cancel_intent(&mut self, intent_hash: FixedBytes<32>) -> Result<(), IntentValidatorError> {
+        let user = self.pending_intent_user(intent_hash)?;
+        if self.vm().msg_sender() != user {
+            return Err(IntentValidatorError::Unauthorized(Unauthorized {}));
+        }
+
+        self.set_registered_status(intent_hash, RegisteredIntentStatus::Cancelled, Address::ZERO);
+        Ok(())
+    }
+
+    /// Mark a pending registered intent executed (consumers only)
+    pub fn mark_intent_executed(&mut self, intent_hash: FixedBytes<32>) -> Result<(), IntentValidatorError> {
+        if !self.intent_consumers.get(self.vm().msg_sender()) {
+            return Err(IntentValidatorError::Unauthorized(Unauthorized {}));
+        }
+
+        self.pending_intent_user(intent_hash)?;
+        let consumer = self.vm().msg_sender();
+        self.set_registered_status(intent_hash, RegisteredIntentStatus::Executed, consumer);
+        Ok(())
+    }
+
+    /// Allow or stop a contract marking registered intents executed (admin only)
+    pub fn set_intent_consumer(&mut self, consumer: Address, enabled: bool) -> Result<(), IntentValidatorError> {
+        self.only_owner()?;
+
+        if consumer == Address::ZERO {
+            return Err(IntentValidatorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.intent_consumers.setter(consumer).set(enabled);
+
+        self.vm().log(IntentConsumerSet { consumer, enabled });
+
+        Ok(())
+    }
+
+    /// Get a registered intent by hash
+    ///
+    /// Returns (user, token, amount, destination chain, recipient, deadline,
+    /// nonce, status), all zero for an unknown hash. `status` is a
+    /// `RegisteredIntentStatus`, reported as expired once a pending intent's
+    /// deadline has passed.
+    pub fn get_intent(&self, intent_hash: FixedBytes<32>) -> (Address, Address, U256, U256, Address, U256, U256, u8) {
+        let record = self.registry_record(intent_hash);
+        (
+            record.user,
+            record.token,
+            record.amount,
+            U256::from(record.destination_chain),
+            record.recipient,
+            U256::from(record.deadline),
+            U256::from(record.nonce),
+            self.registered_status(&record) as u8,
+        )
+    }
+
+    /// Get when a registered intent was registered and last changed, and by whom
+    ///
+    /// Returns (registered at, updated at, consumer), all zero for an unknown
+    /// hash. The consumer is the contract that marked the intent executed.
+    pub fn get_intent_record(&self, intent_hash: FixedBytes<32>) -> (U256, U256, Address) {
+        let record = self.registry_record(intent_hash);
+        (U256::from(record.registered_at), U256::from(record.updated_at), record.consumer)
+    }
+
+    /// Get the nonce a user's next registered intent must carry
+    pub fn get_intent_nonce(&self, user: Address) -> U256 {
+        self.intent_nonces.get(user)
+    }
+
+    /// Check if a contract may mark registered intents executed
+    pub fn is_intent_consumer(&self, consumer: Address) -> bool {
+        self.intent_consumers.get(consumer)
+    }
+
+    /// Allow or stop a contract advancing the config epoch (admin only)
+    ///
+    /// RouteExecutor must be a source for its fee and limit setters to work.
+    pub fn set_config_epoch_source(&mut self, source: Address, enabled: bool) -> Result<(), IntentValidatorError> {
+        self.only_owner()?;
+
+        if source == Address::ZERO {
+            return Err(IntentValidatorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.config_epoch_sources.setter(source).set(enabled);
+
+        self.vm().log(ConfigEpochSourceSet { source, enabled });
+
+        Ok(())
+    }
+
+    /// Start a new config epoch (epoch sources or admin)
+    ///
+    /// With `retire_previous`, intents pinned to earlier epochs stop
+    /// executing; otherwise they stay compatible. Returns the new epoch.
+    pub fn advance_config_epoch(&mut self, retire_previous: bool) -> Result<U256, IntentValidatorError> {
+        let sender = self.vm().msg_sender();
+        if sender != self.owner.get() && !self.config_epoch_sources.get(sender) {
+            return Err(IntentValidatorError::Unauthorized(Unauthorized {}));
+        }
+
+        let epoch = self.config_epoch.get() + U256::from(1);
+        self.config_epoch.set(epoch);
+        if retire_previous {
+            self.compatible_config_epoch.set(epoch);
+        }
+
+        self.vm().log(ConfigEpochAdvanced {
+            epoch,
+            compatibleEpoch: self.compatible_config_epoch.get(),
+            by: sender,
+        });
+
+        Ok(epoch)
+    }
+
+    /// Set the oldest config epoch pinned intents may execute under (admin only)
+    ///
+    /// Lowering it re-admits intents signed before a change that doesn't
+    /// affect them; it can't pass the current epoch.
+    pub fn set_compatible_config_epoch(&mut self, compatible_epoch: U256) -> Result<(), IntentValidatorError> {
+        self.only_owner()?;
+
+        if compatible_epoch > self.config_epoch.get() {
+            return Err(IntentValidatorError::InvalidAmount(InvalidAmount {}));
+        }
+
+        self.compatible_config_epoch.set(compatible_epoch);
+
+        self.vm().log(CompatibleConfigEpochSet {
+            compatibleEpoch: compatible_epoch,
+        });
+
+        Ok(())
+    }
+
+    /// Check that an intent pinned to `epoch` may execute under the current config
+    ///
+    /// Zero means unpinned and always passes. Returns the current epoch, which
+    /// wallets sign into new intents.
+    pub fn check_config_epoch(&self, epoch: U256) -> Result<U256, IntentValidatorError> {
+        let (current, compatible) = self.get_config_epochs();
+        if epoch != U256::ZERO && (epoch < compatible || epoch > current) {
+            return Err(IntentValidatorError::ConfigEpochMismatch(ConfigEpochMismatch {
+                epoch,
+                compatibleEpoch: compatible,
+                currentEpoch: current,
+            }));
+        }
+        Ok(current)
+    }
+
+    /// Get the config epochs as (current, oldest compatible)
+    pub fn get_config_epochs(&self) -> (U256, U256) {
+        (self.config_epoch.get(), self.compatible_config_epoch.get())
+    }
+
+    /// Check if an account may advance the config epoch
+    pub fn is_config_epoch_source(&self, source: Address) -> bool {
+        self.config_epoch_sources.get(source)
+    }
+
+    /// Check if an intent hash is blocked
+    pub fn is_intent_blocked(&self, intent_hash: FixedBytes<32>) -> bool {
+        self.blocked_intents.get(intent_hash)
+    }
+
+    /// Get whether an intent hash is blocked and when its report hold ends
+    ///
+    /// The hold end is zero for intents never reported; once a report is
+    /// settled it is no later than the settlement.
+    pub fn get_intent_hold(&self, intent_hash: FixedBytes<32>) -> (bool, U256) {
+        (self.blocked_intents.get(intent_hash), self.watch_holds.get(intent_hash))
+    }
+
+    /// Get an intent's open watchtower report as (reporter, bond, hold end)
+    ///
+    /// The reporter and bond are zero once the report is settled.
+    pub fn get_watch_report(&self, intent_hash: FixedBytes<32>) -> (Address, U256, U256) {
+        (
+            self.watch_reporters.get(intent_hash),
+            self.watch_bonds.get(intent_hash),
+            self.watch_holds.get(intent_hash),
+        )
+    }
+
+    /// Get the watchtower config as (report bond, hold period, bounty, bounty pool)
+    pub fn get_watch_config(&self) -> (U256, U256, U256, U256) {
+        (
+            self.watch_report_bond.get(),
+            self.watch_hold_period.get(),
+            self.watch_bounty.get(),
+            self.watch_bounty_pool.get(),
+        )
+    }
+
+    /// Check if a destination lane is halted
+    pub fn is_lane_halted(&self, chain_id: U256) -> bool {
+        self.halted_lanes.get(chain_id)
+    }
+
+    /// Get the halt reports counted in a lane's current round
+    pub fn get_lane_halt_reports(&self, chain_id: U256) -> U256 {
+        self.lane_halt_reports.get(chain_id)
+    }
+
+    /// Check if an account may report halted lanes
+    pub fn is_lane_reporter(&self, reporter: Address) -> bool {
+        self.lane_reporters.get(reporter)
+    }
+
+    /// Get the number of reports needed to halt a lane
+    pub fn lane_report_quorum(&self) -> U256 {
+        self.lane_report_quorum.get()
+    }
+
+    /// Get the lane guardian
+    pub fn guardian(&self) -> Address {
+        self.guardian.get()
+    }
+
+    /// ERC-165: whether this contract implements `interface_id`
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        erc165::supports(interface_id.0, &[erc165::CONFIG_SYNC_TARGET_ID])
+    }
+
+    /// Get contract owner
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Get the validator's ABI-encoded `ProtocolStatus`
+    ///
+    /// Carries the config epoch; the counters are the number of indexed config
+    /// keys and the nonce of the last config-sync message broadcast from here.
+    pub fn get_protocol_status(&self) -> Bytes {
+        let mut status =
+            ProtocolStatus::new(StatusContract::IntentValidator, env!("CARGO_PKG_VERSION"), self.owner.get());
+        status.configEpoch = self.config_epoch.get();
+        status.counters = [self.config_count.get(), self.config_sync_nonce.get()].into();
+        Bytes::from(encode_protocol_status(&status))
+    }
+
+    /// Hand the contract over to a new owner (admin only)
+    pub fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), IntentValidatorError> {
+        self.only_owner()?;
+
+        if new_owner == Address::ZERO {
+            return Err(IntentValidatorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        let previous_owner = self.owner.get();
+        self.owner.set(new_owner);
+
+        self.vm().log(OwnershipTransferred {
+            previousOwner: previous_owner,
+            newOwner: new_owner,
+        });
+
+        Ok(())
+    }
+
+    /// Internal: Mark a destination chain supported
+    fn register_chain(&mut self, chain_id: U256) -> Result<(), IntentValidatorError> {
+        if chain_id == U256::ZERO {
+            return Err(IntentValidatorError::InvalidAmount(InvalidAmount {}));
+        }
+
+        self.supported_chains.setter(chain_id).set(true);
+        self.index_config(pack_config_key(ConfigEntryKind::SupportedChain, chain_id));
+
+        self.vm().log(ChainAdded {
+            chainId: chain_id,
+            timestamp: U256::from(self.vm().block_timestamp()),
+        });
+
+        Ok(())
+    }
+
+    /// Internal: Mark a token supported
+    fn register_token(&mut self, token: Address) -> Result<(), IntentValidatorError> {
+        if token == Address::ZERO {
+            return Err(IntentValidatorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        self.supported_tokens.setter(token).set(true);
+        self.index_config(pack_config_address(ConfigEntryKind::SupportedToken, token));
+
+        self.vm().log(TokenAdded {
+            token,
+            timestamp: U256::from(self.vm().block_timestamp()),
+        });
+
+        Ok(())
+    }
+
+    /// Internal: How a token is admitted under the current listing mode
+    fn token_admission(&self, token: Address) -> TokenAdmission {
+        let mode = TokenListingMode::from_u8(self.token_listing_mode()).unwrap_or(TokenListingMode::Allowlist);
+        token_admission(
+            mode,
+            self.supported_tokens.get(token),
+            self.blocked_tokens.get(token),
+            self.listed_tokens.get(token),
+        )
+    }
+
+    /// Internal: Check an intent's amount, addresses, chain, lane and token
+    ///
+    /// `party` is the other address the intent names, e.g. its spender.
+    fn check_intent(
+        &self,
+        user: Address,
+        token: Address,
+        amount: U256,
+        destination_chain: U256,
+        party: Address,
+    ) -> Result<(), IntentValidatorError> {
+        // Validate amount is greater than zero
+        if amount == U256::ZERO {
+            return Err(IntentValidatorError::InvalidAmount(InvalidAmount {}));
+        }
+
+        // Validate addresses are non-zero
+        if user == Address::ZERO || token == Address::ZERO || party == Address::ZERO {
+            return Err(IntentValidatorError::InvalidAddress(InvalidAddress {}));
+        }
+
+        // Check if chain is supported
+        if !self.is_chain_supported(destination_chain) {
+            return Err(IntentValidatorError::UnsupportedChain(UnsupportedChain {}));
+        }
+
+        // Check the lane is healthy
+        self.check_lane_healthy(destination_chain)?;
+
+        // Check if token is supported, and within its cap
+        match self.token_admission(token) {
+            TokenAdmission::Rejected => Err(IntentValidatorError::UnsupportedToken(UnsupportedToken {})),
+            TokenAdmission::Capped => self.check_token_cap(token, amount),
+            TokenAdmission::Full => Ok(()),
+        }
+    }
+
+    /// Internal: Check an amount against a listed token's cap
+    fn check_token_cap(&self, token: Address, amount: U256) -> Result<(), IntentValidatorError> {
+        let cap = self.token_caps.get(token);
+        if cap != U256::ZERO && amount > cap {
+            return Err(IntentValidatorError::TokenCapExceeded(TokenCapExceeded { token, cap }));
+        }
+        Ok(())
+    }
+
+    /// Internal: A token's decimals, or `None` if any ERC-20 view fails
+    fn erc20_decimals(&self, token: Address) -> Option<u8> {
+        let erc20 = IERC20::new(token);
+        let this = self.vm().contract_address();
+        erc20.total_supply(self.vm(), Call::new()).ok()?;
+        erc20.balance_of(self.vm(), Call::new(), this).ok()?;
+        erc20.allowance(self.vm(), Call::new(), this, this).ok()?;
+        erc20.decimals(self.vm(), Call::new()).ok()
+    }
+
+    /// Internal: Whether the price oracle or the TWAP source can price a token
+    fn has_price_source(&self, token: Address) -> bool {
+        let oracle = self.token_price_oracle.get();
+        if oracle != Address::ZERO {
+            let priced = ITokenPriceOracle::new(oracle).has_price(self.vm(), Call::new(), token);
+            if priced.unwrap_or(false) {
+                return true;
+            }
+        }
+
+        let twap = self.twap_source.get();
+        twap != Address::ZERO && ITwapSource::new(twap).has_pool(self.vm(), Call::new(), token).unwrap_or(false)
+    }
+
+    /// Internal: First price the token's fallback chain yields, with its source
+    fn token_price(&self, token: Address) -> (PriceSource, U256) {
+        resolve_price(self.feed_price(token), || self.twap_quote(token), self.static_price_bounds.get(token))
+    }
+
+    /// Internal: Price from the TWAP source scaled to `PRICE_DECIMALS`, or
+    /// `None` if there is no source or it can't price the token
+    fn twap_quote(&self, token: Address) -> Option<U256> {
+        let source = self.twap_source.get();
+        if source == Address::ZERO {
+            return None;
+        }
+        let (price, decimals) = ITwapSource::new(source).price_of(self.vm(), Call::new(), token).ok()?;
+        twap_price(price, decimals)
+    }
+
+    /// Internal: Price from a token's Chainlink feed, or `None` if it has none
+    /// or the round is stale or unusable
+    fn feed_price(&self, token: Address) -> Option<U256> {
+        let feed = self.price_feeds.get(token);
+        if feed == Address::ZERO {
+            return None;
+        }
+        let aggregator = IChainlinkAggregator::new(feed);
+        let decimals = aggregator.decimals(self.vm(), Call::new()).ok()?;
+        let (_, answer, _, updated_at, _) = aggregator.latest_round_data(self.vm(), Call::new()).ok()?;
+        let now = U256::from(self.vm().block_timestamp());
+        chainlink_price(answer, decimals, updated_at, now, self.price_feed_max_ages.get(token))
+    }
+
+    /// Internal: Add a packed config key to the export index, once
+    fn index_config(&mut self, packed: U256) {
+        if self.config_listed.get(packed) {
+            return;
+        }
+
+        let count = self.config_count.get();
+        self.config_keys.setter(count).set(packed);
+        self.config_listed.setter(packed).set(true);
+        self.config_count.set(count + U256::from(1));
+    }
+
+    /// Internal: Record a lane's health and start a fresh reporting round
+    fn set_lane_health(&mut self, chain_id: U256, halted: bool) {
+        self.halted_lanes.setter(chain_id).set(halted);
+        let round = self.lane_report_rounds.get(chain_id) + U256::from(1);
+        self.lane_report_rounds.setter(chain_id).set(round);
+        self.lane_halt_reports.setter(chain_id).set(U256::ZERO);
+
+        self.vm().log(LaneHealthChanged {
+            chainId: chain_id,
+            halted,
+        });
+    }
+
+    /// Internal: Check that a destination lane is not halted
+    fn check_lane_healthy(&self, chain_id: U256) -> Result<(), IntentValidatorError> {
+        if self.halted_lanes.get(chain_id) {
+            return Err(IntentValidatorError::LaneHalted(LaneHalted { chainId: chain_id }));
+        }
+        Ok(())
+    }
+
+    /// Internal: A registered intent's record, the default for an unknown hash
+    fn registry_record(&self, intent_hash: FixedBytes<32>) -> RegistryRecord {
+        let words: [U256; REGISTRY_RECORD_WORDS] = [
+            self.registered_headers.get(intent_hash),
+            self.registered_users.get(intent_hash),
+            self.registered_tokens.get(intent_hash),
+            self.registered_recipients.get(intent_hash),
+            self.registered_amounts.get(intent_hash),
+        ];
+        RegistryRecord::decode(words)
+    }
+
+    /// Internal: A registered intent's status as of now
+    fn registered_status(&self, record: &RegistryRecord) -> RegisteredIntentStatus {
+        RegisteredIntentStatus::from_u8(record.status)
+            .unwrap_or(RegisteredIntentStatus::None)
+            .at(U256::from(record.deadline), U256::from(self.vm().block_timestamp()))
+    }
+
+    /// Internal: The user of a registered intent that is still pending
+    fn pending_intent_user(&self, intent_hash: FixedBytes<32>) -> Result<Address, IntentValidatorError> {
+        if registry_header_status(self.registered_headers.get(intent_hash)) == RegisteredIntentStatus::None as u8 {
+            return Err(IntentValidatorError::UnknownIntent(UnknownIntent { intentHash: intent_hash }));
+        }
+
+        let record = self.registry_record(intent_hash);
+        let status = self.registered_status(&record);
+        if status != RegisteredIntentStatus::Pending {
+            return Err(IntentValidatorError::IntentNotPending(IntentNotPending {
+                intentHash: intent_hash,
+                status: status as u8,
+            }));
+        }
+        Ok(record.user)
+    }
+
+    /// Internal: Store a registered intent's new status and consumer, and log it
+    ///
+    /// Only the header word is rewritten; the rest of the record is fixed at
+    /// registration.
+    fn set_registered_status(
+        &mut self,
+        intent_hash: FixedBytes<32>,
+        status: RegisteredIntentStatus,
+        consumer: Address,
+    ) {
+        let now = U256::from(self.vm().block_timestamp());
+        let header = update_registry_header(self.registered_headers.get(intent_hash), status as u8, consumer, now);
+        self.registered_headers.setter(intent_hash).set(header);
+
+        self.vm().log(RegisteredIntentStatusChanged {
+            intentHash: intent_hash,
+            status: status as u8,
+        });
+    }
+
+    /// Internal: Recover the signer of a digest through the ecrecover precompile
+    fn recover_signer(&self, digest: FixedBytes<32>, signature: &[u8]) -> Result<Address, IntentValidatorError> {
+        let input = signing::ecrecover_input(digest, signature)
+            .ok_or(IntentValidatorError::InvalidSignature(InvalidSignature {}))?;
+        let output = static_call(self.vm(), Call::new(), signing::ECRECOVER, &input)
+            .map_err(|_| IntentValidatorError::InvalidSignature(InvalidSignature {}))?;
+        signing::recovered_address(&output).ok_or(IntentValidatorError::InvalidSignature(InvalidSignature {}))
+    }
+
+    /// Internal: Check if caller is owner
+    fn only_owner(&self) -> Result<(), IntentValidatorError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(IntentValidatorError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use stylus_sdk::alloy_primitives::{Address, U256};
+use swoosh_test_utils::TestEnv;
+use swoosh_validator::*;
+
+#[cfg(test)]
+mod delegation_tests {
+    use super::*;
+
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    // Owner 1 allows executor 7 to spend; treasury 5 delegates 1000 of
+    // token 9 to ops key 6 for an hour
+    fn setup(env: &TestEnv) -> IntentValidator {
+        let mut validator: IntentValidator = env.deploy();
+        env.set_sender(test_address(1));
+        assert!(validator.init().is_ok(), "Init");
+        assert!(validator.set_delegation_spender(test_address(7), true).is_ok(), "Spender set");
+
+        env.set_sender(test_address(5));
+        let expires_at = U256::from(env.timestamp() + 3600);
+        assert!(validator.set_delegate(test_address(6), expires_at).is_ok(), "Delegate set");
+        assert!(validator.set_delegate_cap(test_address(6), test_address(9), U256::from(1_000u64)).is_ok(), "Cap set");
+        validator
+    }
+
+    #[test]
+    fn test_spend_draws_allowance_down() {
+        // Each delegated intent spends from the per-token allowance
+        let env = TestEnv::new();
+        let mut validator = setup(&env);
+
+        env.set_sender(test_address(7));
+        let spent = validator.spend_delegation(test_address(5), test_address(6), test_address(9), U256::from(600u64));
+        assert_eq!(spent.ok(), Some(U256::from(400u64)), "Remaining");
+        assert!(
+            matches!(
+                validator.spend_delegation(test_address(5), test_address(6), test_address(9), U256::from(500u64)),
+                Err(IntentValidatorError::DelegationExceeded(_))
+            ),
+            "Over allowance"
+        );
+        assert!(
+            !validator.is_delegate(test_address(5), test_address(6), test_address(8), U256::from(1u64)),
+            "Other token"
+        );
+    }
+
+    #[test]
+    fn test_spend_rejected_after_expiry_or_from_outsiders() {
+        // Only allowed spenders draw, and only while the delegation holds
+        let env = TestEnv::new();
+        let mut validator = setup(&env);
+
+        env.set_sender(test_address(6));
+        assert!(
+            matches!(
+                validator.spend_delegation(test_address(5), test_address(6), test_address(9), U256::from(1u64)),
+                Err(IntentValidatorError::Unauthorized(_))
+            ),
+            "Delegate itself"
+        );
+
+        env.advance_time(3600);
+        env.set_sender(test_address(7));
+        assert!(
+            matches!(
+                validator.spend_delegation(test_address(5), test_address(6), test_address(9), U256::from(1u64)),
+                Err(IntentValidatorError::Unauthorized(_))
+            ),
+            "Expired"
+        );
+        assert_eq!(
+            validator.get_delegation(test_address(5), test_address(6), test_address(9)).1,
+            U256::from(1_000u64),
+            "Allowance untouched"
+        );
+    }
+}
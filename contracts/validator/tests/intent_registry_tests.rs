@@ -0,0 +1,59 @@
+use stylus_sdk::alloy_primitives::{Address, Bytes, FixedBytes, U256};
+use swoosh_test_utils::TestEnv;
+use swoosh_validator::*;
+
+#[cfg(test)]
+mod intent_registry_tests {
+    use super::*;
+
+    const CHAIN: u64 = 8_453;
+
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    // Owner 1 allowlists token 0x10 toward CHAIN
+    fn setup(env: &TestEnv) -> IntentValidator {
+        let mut validator: IntentValidator = env.deploy();
+        env.set_sender(test_address(1));
+        assert!(validator.init().is_ok(), "Init");
+        assert!(validator.add_supported_chain(U256::from(CHAIN)).is_ok(), "Chain added");
+        assert!(validator.add_supported_token(test_address(0x10)).is_ok(), "Token added");
+        validator
+    }
+
+    // User 7 registers their own 1000-unit intent in token 0x10 toward CHAIN
+    fn register(validator: &mut IntentValidator, deadline: U256) -> Result<FixedBytes<32>, IntentValidatorError> {
+        let user = test_address(7);
+        validator.register_intent(
+            user,
+            test_address(0x10),
+            U256::from(1_000u64),
+            U256::from(CHAIN),
+            test_address(0x20),
+            deadline,
+            validator.get_intent_nonce(user),
+            Bytes::new(),
+        )
+    }
+
+    #[test]
+    fn test_register_intent_rejects_fields_a_record_cannot_hold() {
+        // A deadline past 64 bits would read back as something other than what was hashed
+        let env = TestEnv::new();
+        let mut validator = setup(&env);
+        env.set_sender(test_address(7));
+
+        let wide = U256::from(u64::MAX) + U256::from(1u64);
+        assert!(
+            matches!(register(&mut validator, wide), Err(IntentValidatorError::IntentOutOfRange(_))),
+            "Deadline too wide"
+        );
+        assert_eq!(validator.get_intent_nonce(test_address(7)), U256::ZERO, "Nonce not consumed");
+
+        let deadline = U256::from(u64::MAX);
+        let Ok(intent_hash) = register(&mut validator, deadline) else { panic!("Widest deadline registers") };
+        let (_, _, _, chain, _, stored, nonce, _) = validator.get_intent(intent_hash);
+        assert_eq!((chain, stored, nonce), (U256::from(CHAIN), deadline, U256::ZERO), "Read back as hashed");
+    }
+}
@@ -0,0 +1,99 @@
+use stylus_sdk::alloy_primitives::{Address, Bytes, U256};
+use swoosh_common::encoding::ValidatorIntent;
+use swoosh_common::signing;
+use swoosh_test_utils::TestEnv;
+use swoosh_validator::*;
+
+#[cfg(test)]
+mod signed_intent_tests {
+    use super::*;
+
+    const CHAIN: u64 = 8_453;
+
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    // Validator 0xaa on CHAIN, owner 1, allowlisting token 0x10 toward CHAIN
+    fn setup(env: &TestEnv) -> IntentValidator {
+        env.vm().set_chain_id(CHAIN);
+        env.vm().set_contract_address(test_address(0xaa));
+        let mut validator: IntentValidator = env.deploy();
+        env.set_sender(test_address(1));
+        assert!(validator.init().is_ok(), "Init");
+        assert!(validator.add_supported_chain(U256::from(CHAIN)).is_ok(), "Chain added");
+        assert!(validator.add_supported_token(test_address(0x10)).is_ok(), "Token added");
+        validator
+    }
+
+    // User 7's intent of 1000 in token 0x10, and a signature the ecrecover
+    // precompile is mocked to attribute to `signer`
+    fn signed(env: &TestEnv, deadline: U256, signer: Address) -> (ValidatorIntent, Bytes) {
+        let intent = ValidatorIntent {
+            user: test_address(7),
+            token: test_address(0x10),
+            amount: U256::from(1_000u64),
+            destinationChain: U256::from(CHAIN),
+            nonce: U256::from(1u64),
+            deadline,
+        };
+        let digest = signing::validator_intent_digest(&intent, CHAIN, test_address(0xaa));
+        let mut signature = vec![0x11; 32];
+        signature.extend_from_slice(&[0x22; 32]);
+        signature.push(27);
+        let input = signing::ecrecover_input(digest, &signature).expect("Well-formed signature");
+        env.vm().mock_static_call(signing::ECRECOVER, input.to_vec(), Ok(signer.into_word().to_vec()));
+        (intent, Bytes::from(signature))
+    }
+
+    fn validate(
+        validator: &IntentValidator,
+        intent: &ValidatorIntent,
+        signature: Bytes,
+    ) -> Result<bool, IntentValidatorError> {
+        validator.validate_signed_intent(
+            intent.user,
+            intent.token,
+            intent.amount,
+            intent.destinationChain,
+            intent.nonce,
+            intent.deadline,
+            test_address(0xee),
+            signature,
+        )
+    }
+
+    #[test]
+    fn test_signed_intent_check_consumes_nothing() {
+        // The same signature validates every time and leaves no trace
+        let env = TestEnv::new();
+        let validator = setup(&env);
+        let deadline = U256::from(env.timestamp() + 600);
+        let (intent, signature) = signed(&env, deadline, test_address(7));
+
+        assert_eq!(validate(&validator, &intent, signature.clone()).ok(), Some(true), "Validated");
+        assert_eq!(validate(&validator, &intent, signature).ok(), Some(true), "Validated again");
+        assert!(env.vm().get_emitted_logs().is_empty(), "Logs nothing");
+    }
+
+    #[test]
+    fn test_signed_intent_rejects_other_signer_and_late_intent() {
+        // Only the user's own signature, and only before the deadline
+        let env = TestEnv::new();
+        let validator = setup(&env);
+        let deadline = U256::from(env.timestamp() + 600);
+
+        let (intent, signature) = signed(&env, deadline, test_address(8));
+        assert!(
+            matches!(validate(&validator, &intent, signature), Err(IntentValidatorError::SignerMismatch(_))),
+            "Signed by someone else"
+        );
+
+        let (intent, signature) = signed(&env, deadline, test_address(7));
+        env.advance_time(601);
+        assert!(
+            matches!(validate(&validator, &intent, signature), Err(IntentValidatorError::DeadlineExpired(_))),
+            "Past the deadline"
+        );
+    }
+}
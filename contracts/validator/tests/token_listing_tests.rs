@@ -0,0 +1,95 @@
+use alloy_sol_types::{sol, SolCall, SolValue};
+use stylus_sdk::alloy_primitives::{Address, U256};
+use swoosh_test_utils::TestEnv;
+use swoosh_validator::*;
+
+// Views listing calls on the token and the price oracle
+sol! {
+    function totalSupply() external view returns (uint256);
+    function balanceOf(address account) external view returns (uint256);
+    function allowance(address owner, address spender) external view returns (uint256);
+    function decimals() external view returns (uint8);
+    function hasPrice(address token) external view returns (bool);
+}
+
+#[cfg(test)]
+mod token_listing_tests {
+    use super::*;
+
+    const CHAIN: u64 = 8_453;
+
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    // Owner 1 supports CHAIN; RouteExecutor 0xee checks user 7's intents
+    fn setup(env: &TestEnv) -> IntentValidator {
+        let mut validator: IntentValidator = env.deploy();
+        env.set_sender(test_address(1));
+        assert!(validator.init().is_ok(), "Init");
+        assert!(validator.add_supported_chain(U256::from(CHAIN)).is_ok(), "Chain added");
+        validator
+    }
+
+    fn check(validator: &IntentValidator, token: Address, amount: u64) -> Result<bool, IntentValidatorError> {
+        validator.check_execution(test_address(7), token, U256::from(amount), U256::from(CHAIN), test_address(0xee))
+    }
+
+    #[test]
+    fn test_check_execution_follows_listing_mode() {
+        // Allowlist mode takes only allowlisted tokens, blocklist mode anything not blocked
+        let env = TestEnv::new();
+        let mut validator = setup(&env);
+        let (listed, other) = (test_address(0x10), test_address(0x12));
+
+        assert!(matches!(check(&validator, listed, 1_000), Err(IntentValidatorError::UnsupportedToken(_))), "Unlisted");
+        assert!(validator.add_supported_token(listed).is_ok(), "Allowlisted");
+        assert_eq!(check(&validator, listed, 1_000).ok(), Some(true), "Allowlisted token");
+        assert!(
+            matches!(
+                validator.check_execution(
+                    test_address(7),
+                    listed,
+                    U256::from(1u64),
+                    U256::from(1u64),
+                    test_address(0xee)
+                ),
+                Err(IntentValidatorError::UnsupportedChain(_))
+            ),
+            "Unsupported chain"
+        );
+
+        assert!(validator.set_token_listing_mode(2).is_ok(), "Blocklist mode");
+        assert_eq!(check(&validator, other, 1_000).ok(), Some(true), "Any token");
+        assert!(validator.set_token_blocked(other, true).is_ok(), "Blocked");
+        assert!(matches!(check(&validator, other, 1_000), Err(IntentValidatorError::UnsupportedToken(_))), "Blocked");
+    }
+
+    #[test]
+    fn test_check_execution_enforces_listing_cap() {
+        // A permissionlessly listed token is capped at the default cap it was listed with
+        let env = TestEnv::new();
+        env.vm().set_contract_address(test_address(0xaa));
+        let mut validator = setup(&env);
+        let (token, oracle, this) = (test_address(0x11), test_address(0x40), test_address(0xaa));
+        assert!(validator.set_token_listing_mode(1).is_ok(), "Permissionless mode");
+        assert!(validator.set_listing_defaults(U256::from(1_000u64), oracle).is_ok(), "Default cap");
+
+        let vm = env.vm();
+        vm.mock_static_call(token, totalSupplyCall {}.abi_encode(), Ok(U256::from(1u64).abi_encode()));
+        vm.mock_static_call(token, balanceOfCall { account: this }.abi_encode(), Ok(U256::ZERO.abi_encode()));
+        let allowance = allowanceCall { owner: this, spender: this }.abi_encode();
+        vm.mock_static_call(token, allowance, Ok(U256::ZERO.abi_encode()));
+        vm.mock_static_call(token, decimalsCall {}.abi_encode(), Ok(6u8.abi_encode()));
+        vm.mock_static_call(oracle, hasPriceCall { token }.abi_encode(), Ok(true.abi_encode()));
+
+        env.set_sender(test_address(9));
+        assert!(validator.list_token(token).is_ok(), "Listed by anyone");
+        assert_eq!(validator.get_token_cap(token), U256::from(1_000u64), "Listed with the default cap");
+        assert_eq!(check(&validator, token, 1_000).ok(), Some(true), "At the cap");
+        assert!(
+            matches!(check(&validator, token, 1_001), Err(IntentValidatorError::TokenCapExceeded(_))),
+            "Over the cap"
+        );
+    }
+}
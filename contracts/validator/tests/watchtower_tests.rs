@@ -0,0 +1,106 @@
+use stylus_sdk::alloy_primitives::{Address, FixedBytes, U256};
+use swoosh_test_utils::TestEnv;
+use swoosh_validator::*;
+
+#[cfg(test)]
+mod watchtower_tests {
+    use super::*;
+
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    fn intent_hash() -> FixedBytes<32> {
+        FixedBytes::from([0xaa; 32])
+    }
+
+    // Owner 1 asks a 100 wei bond, holds reported intents for ten minutes
+    // and pays a 50 wei bounty; guardian 2
+    fn setup(env: &TestEnv) -> IntentValidator {
+        let mut validator: IntentValidator = env.deploy();
+        env.set_sender(test_address(1));
+        assert!(validator.init().is_ok(), "Init");
+        assert!(validator.set_guardian(test_address(2)).is_ok(), "Guardian set");
+        let config = validator.set_watch_config(U256::from(100u64), U256::from(600u64), U256::from(50u64));
+        assert!(config.is_ok(), "Watch config set");
+        validator
+    }
+
+    // Watchtower 4 reports the intent with the bond attached
+    fn report(env: &TestEnv, validator: &mut IntentValidator) -> Result<U256, IntentValidatorError> {
+        env.set_sender(test_address(4));
+        env.set_value(U256::from(100u64));
+        let result = validator.report_suspicious(intent_hash(), 1);
+        env.set_value(U256::ZERO);
+        result
+    }
+
+    #[test]
+    fn test_report_holds_intent() {
+        // A reported intent fails validation until the hold ends
+        let env = TestEnv::new();
+        let mut validator = setup(&env);
+
+        let held_until = report(&env, &mut validator).unwrap_or_default();
+        assert_eq!(held_until, U256::from(env.timestamp() + 600), "Hold end");
+        assert_eq!(validator.get_intent_hold(intent_hash()), (false, held_until), "Hold visible");
+        assert!(
+            matches!(validator.validate_intent_hash(intent_hash()), Err(IntentValidatorError::IntentHeld(_))),
+            "Held"
+        );
+        assert!(
+            matches!(report(&env, &mut validator), Err(IntentValidatorError::AlreadyReported(_))),
+            "Reported once"
+        );
+
+        env.advance_time(600);
+        assert!(validator.validate_intent_hash(intent_hash()).is_ok(), "Released");
+    }
+
+    #[test]
+    fn test_report_needs_exact_bond_and_enabled_reports() {
+        // Reports are off by default and must attach the configured bond
+        let env = TestEnv::new();
+        let mut validator: IntentValidator = env.deploy();
+        env.set_sender(test_address(1));
+        assert!(validator.init().is_ok(), "Init");
+        assert!(
+            matches!(report(&env, &mut validator), Err(IntentValidatorError::ReportsDisabled(_))),
+            "Disabled"
+        );
+
+        let env = TestEnv::new();
+        let mut validator = setup(&env);
+        env.set_sender(test_address(4));
+        env.set_value(U256::from(99u64));
+        assert!(
+            matches!(validator.report_suspicious(intent_hash(), 1), Err(IntentValidatorError::InvalidAmount(_))),
+            "Short bond"
+        );
+    }
+
+    #[test]
+    fn test_dismissed_report_forfeits_bond() {
+        // Only the guardian settles early; a report on an unblocked intent
+        // adds its bond to the bounty pool and releases the intent
+        let env = TestEnv::new();
+        let mut validator = setup(&env);
+        assert!(report(&env, &mut validator).is_ok(), "Reported");
+
+        env.set_sender(test_address(5));
+        assert!(
+            matches!(validator.resolve_report(intent_hash()), Err(IntentValidatorError::IntentHeld(_))),
+            "Outsider during hold"
+        );
+
+        env.set_sender(test_address(2));
+        assert_eq!(validator.resolve_report(intent_hash()).ok(), Some(false), "Dismissed");
+        assert_eq!(validator.get_watch_config().3, U256::from(100u64), "Bond forfeited to the pool");
+        assert_eq!(validator.get_watch_report(intent_hash()).0, Address::ZERO, "Report closed");
+        assert!(validator.validate_intent_hash(intent_hash()).is_ok(), "Released");
+        assert!(
+            matches!(validator.resolve_report(intent_hash()), Err(IntentValidatorError::NoWatchReport(_))),
+            "Settled once"
+        );
+    }
+}
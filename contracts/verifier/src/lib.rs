@@ -0,0 +1,2021 @@
+//! SettlementVerifier Contract
+//! 
+//! Verifies cross-chain message delivery and handles settlement confirmation or failures.
+//! Intents executed by solvers that fail, or never settle within the timeout,
+//! get the solver's bond slashed through SolverRegistry.
+//! Matched legs netted against an opposing intent settle when the mirror leg
+//! on the other chain is reported under the same match ID.
+//! Deliveries can also be proven with a Wormhole VAA published by a
+//! registered emitter, such as the WormholeAdapter on the destination chain.
+//! Intents posted as deBridge DLN orders settle once DlnSource on this chain
+//! reports the order unlocked, which only happens after a taker filled it.
+//! Each solver's in-flight intents, executed but not yet settled or failed,
+//! are counted along with their notional per input token; the owner can cap
+//! both, and a solver at a cap can't take on another intent until one of its
+//! intents settles or fails.
+//! Receivers delivering a settlement batch report all of its entries in one
+//! call; entries that can't be recorded are skipped rather than failing the
+//! rest.
+//! With an Ethereum Attestation Service contract and schema set, every
+//! settled intent gets an on-chain attestation (`attestation` module in
+//! swoosh-common) built from RouteExecutor's execution record, including the
+//! intent's integrator memo, which settlement also logs. Attesting is best
+//! effort; one that fails is logged and anyone can retry it.
+//! Intents can name a callback contract, which is called with bounded gas
+//! once the intent settles, fails or is refunded. Its outcome is only
+//! logged; a callback that reverts or runs out of gas changes nothing here.
+//! Each lane, keyed by the intent's destination chain, can carry a finality
+//! policy (`lane_policy` module in swoosh-common): the reporter types it
+//! trusts, how many distinct reporters must agree on a delivery, and a
+//! challenge window during which the owner or an attester can throw an
+//! agreed delivery out before anyone finalizes it. Lanes without a policy
+//! settle on the first report, as before.
+//! Insured intents are registered by RouteExecutor with their coverage and
+//! the premium paid into the InsuranceFund. Once one fails without a refund,
+//! including by going unsettled past the timeout, anyone can claim its
+//! insurance and the fund pays the user the coverage's value.
+//! Intents can carry a retry policy (`retry_policy` module in swoosh-common).
+//! When such an intent's delivery times out, the verifier opens another
+//! attempt and restarts the clock instead of failing it, until the policy's
+//! retries or total delay run out; only then is it failed and refunded.
+//! Registered adapters also report tokens bridged to RouteExecutor for a
+//! hidden-recipient intent; the verifier credits them to the intent there,
+//! and only that credit can be paid out once the recipient is revealed.
+//! Testnet builds with the `drills` feature expose owner-only hooks that
+//! inject a report as any reporter and age an intent's clocks, so duplicate,
+//! conflicting and late deliveries can be played against a live deployment.
+//! Without the feature the hooks always revert with `DrillsDisabled`.
+//! With the `invariants` feature, a settlement moving to a status it can't
+//! reach from its current one panics instead.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+#![cfg_attr(feature = "contract-client-gen", allow(unused_imports))]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::{
+    alloy_primitives::{Address, Bytes, U256, FixedBytes},
+    call::{call, static_call, Call, RawCall},
+    prelude::*,
+    storage::{StorageAddress, StorageBool, StorageBytes, StorageFixedBytes, StorageMap, StorageU256},
+};
+
+use swoosh_common::attestation::{encode_intent_attestation, IntentAttestation};
+use swoosh_common::encoding::{
+    encode_settlement_callback, DeliveryStatus, CALLBACK_GAS_LIMIT, MAX_CALLBACK_DATA, MAX_SETTLEMENT_BATCH,
+};
+use swoosh_common::erc165::{self, IERC165};
+use swoosh_common::events::{
+    DeliveryStatusReported, RefundInitiated, SettlementConfirmed, SettlementFailed, SettlementRetried,
+};
+use swoosh_common::invariant;
+use swoosh_common::invariants;
+use swoosh_common::lane_policy::{
+    LanePolicy, REPORTER_ADAPTER, REPORTER_ATTESTER, REPORTER_CCIP, REPORTER_DLN, REPORTER_WORMHOLE,
+};
+use swoosh_common::params::{self, Param, ParameterOutOfBounds};
+use swoosh_common::payload_codec::{self, PayloadError};
+use swoosh_common::retry_policy::RetryPolicy;
+
+use swoosh_common::solver_registry::ISolverRegistry;
+use swoosh_common::status::{encode_protocol_status, ProtocolStatus, StatusContract};
+
+// Wormhole core contract ABI
+sol! {
+    struct GuardianSignature {
+        bytes32 r;
+        bytes32 s;
+        uint8 v;
+        uint8 guardianIndex;
+    }
+
+    struct VM {
+        uint8 version;
+        uint32 timestamp;
+        uint32 nonce;
+        uint16 emitterChainId;
+        bytes32 emitterAddress;
+        uint64 sequence;
+        uint8 consistencyLevel;
+        bytes payload;
+        uint32 guardianSetIndex;
+        GuardianSignature[] signatures;
+        bytes32 hash;
+    }
+
+    function parseAndVerifyVM(bytes encodedVM) external view returns (VM vm, bool valid, string reason);
+}
+
+// deBridge DlnSource ABI (only the leading status word of the order state is read)
+sol! {
+    function giveOrders(bytes32 orderId) external view returns (uint8 status);
+}
+
+// Ethereum Attestation Service ABI
+sol! {
+    struct AttestationRequestData {
+        address recipient;
+        uint64 expirationTime;
+        bool revocable;
+        bytes32 refUID;
+        bytes data;
+        uint256 value;
+    }
+
+    struct AttestationRequest {
+        bytes32 schema;
+        AttestationRequestData data;
+    }
+
+    function attest(AttestationRequest request) external payable returns (bytes32);
+}
+
+// RouteExecutor views read to build an attestation and report the memo
+sol! {
+    function getIntentExecution(uint256 intentId) external view returns (
+        uint256 status,
+        uint256 destinationChain,
+        uint256 createdAt,
+        uint256 updatedAt,
+        uint256 amountIn,
+        uint256 amountOut
+    );
+
+    function getIntentUser(uint256 intentId) external view returns (address);
+
+    function getIntentMemo(uint256 intentId) external view returns (bytes32);
+}
+
+// RouteExecutor ABI for hidden-recipient deliveries bridged to this chain
+sol! {
+    function creditHiddenDelivery(bytes32 intentHash, address token, uint256 amount) external;
+}
+
+// InsuranceFund ABI
+sol! {
+    function payClaim(uint256 intentId, address user, address token, uint256 coverage) external returns (uint256);
+}
+
+/// DlnSource order status once the taker has claimed the unlock
+const DLN_STATUS_CLAIMED_UNLOCK: u8 = 2;
+
+// Events
+sol! {
+    event SettlementAdapterSet(address indexed adapter, bool enabled);
+    event SolverIntentRegistered(uint256 indexed intentId, uint256 indexed solverId);
+    event SolverRegistrySet(address registry);
+    event SlashAmountSet(uint256 amount);
+    event OwnershipTransferred(address indexed previousOwner, address indexed newOwner);
+    event MatchedLegRegistered(bytes32 indexed matchId, uint256 indexed intentId);
+    event WormholeSet(address wormhole);
+    event WormholeEmitterSet(uint16 indexed wormholeChain, bytes32 emitter);
+    event DlnSourceSet(address dlnSource);
+    event DlnOrderRegistered(bytes32 indexed orderId, uint256 indexed intentId);
+    event DeliveryRejected(bytes32 indexed messageId, uint256 indexed intentId);
+    event MaxInFlightIntentsSet(uint256 limit);
+    event MaxInFlightNotionalSet(address indexed token, uint256 limit);
+    event AttestationsSet(address indexed eas, bytes32 schema);
+    event IntentAttested(uint256 indexed intentId, bytes32 indexed uid);
+    event IntentAttestationFailed(uint256 indexed intentId);
+    event SettlementMemo(uint256 indexed intentId, bytes32 indexed memo);
+    event CallbackRegistered(uint256 indexed intentId, address indexed callback);
+    event CallbackInvoked(uint256 indexed intentId, address indexed callback, uint8 status, bool success);
+    event LanePolicySet(uint256 indexed chainId, uint8 reporters, uint8 quorum, uint64 challengeWindow);
+    event AttesterSet(address indexed attester, bool enabled);
+    event DeliveryReported(
+        uint256 indexed intentId,
+        address indexed reporter,
+        uint8 reporterType,
+        uint256 confirmations
+    );
+    event DeliveryPending(uint256 indexed intentId, uint256 finalAt);
+    event DeliveryChallenged(uint256 indexed intentId, address indexed challenger);
+    event InsuranceFundSet(address fund);
+    event IntentInsured(
+        uint256 indexed intentId,
+        address indexed user,
+        address token,
+        uint256 coverage,
+        uint256 premium
+    );
+    event InsuranceClaimed(uint256 indexed intentId, address indexed user, uint256 payout);
+    event RetryPolicyRegistered(uint256 indexed intentId, uint256 policy, address indexed bridge);
+    event DrillReport(uint256 indexed intentId, address indexed reporter, uint8 reporterType, uint8 status);
+    event DrillDelay(uint256 indexed intentId, uint256 delay);
+    event HiddenDeliveryReported(bytes32 indexed intentHash, address indexed adapter, address token, uint256 amount);
+    
+    error Unauthorized();
+    error InvalidMessageId();
+    error InvalidIntentId();
+    error SettlementTimeout();
+    error AlreadyProcessed();
+    error RefundFailed();
+    error InvalidVaa();
+    error MalformedPayload(uint8 reason);
+    error OrderNotFulfilled();
+    error InvalidDeliveryStatus(uint8 status);
+    error InvalidBatch();
+    error InFlightLimitExceeded(uint256 solverId, uint256 limit);
+    error InFlightNotionalExceeded(uint256 solverId, address token, uint256 limit);
+    error AttestationFailed();
+    error InvalidCallback();
+    error InvalidLanePolicy();
+    error ReporterNotAccepted(uint8 reporterType);
+    error NoPendingDelivery();
+    error ChallengeWindowOpen(uint256 finalAt);
+    error InsuranceUnavailable();
+    error NotClaimable(uint256 intentId, uint256 status);
+    error ClaimFailed();
+    error InvalidRetryPolicy();
+    error DrillsDisabled();
+    error InterfaceNotSupported(address target, bytes4 interfaceId);
+    error DeliveryCreditFailed();
+}
+
+/// Settlement status enumeration
+#[derive(Clone, Copy, PartialEq)]
+pub enum SettlementStatus {
+    Pending = 0,
+    Confirmed = 1,
+    Failed = 2,
+    Refunded = 3,
+    /// Delivered the bridged token after the destination swap failed
+    PartialDelivery = 4,
+    /// Credited to a claimable balance on the destination receiver
+    DeliveredToClaim = 5,
+}
+
+/// Error types for SettlementVerifier
+#[derive(SolidityError)]
+pub enum SettlementVerifierError {
+    Unauthorized(Unauthorized),
+    InvalidMessageId(InvalidMessageId),
+    InvalidIntentId(InvalidIntentId),
+    SettlementTimeout(SettlementTimeout),
+    AlreadyProcessed(AlreadyProcessed),
+    RefundFailed(RefundFailed),
+    InvalidVaa(InvalidVaa),
+    MalformedPayload(MalformedPayload),
+    OrderNotFulfilled(OrderNotFulfilled),
+    InvalidDeliveryStatus(InvalidDeliveryStatus),
+    InvalidBatch(InvalidBatch),
+    InFlightLimitExceeded(InFlightLimitExceeded),
+    InFlightNotionalExceeded(InFlightNotionalExceeded),
+    AttestationFailed(AttestationFailed),
+    InvalidCallback(InvalidCallback),
+    InvalidLanePolicy(InvalidLanePolicy),
+    ReporterNotAccepted(ReporterNotAccepted),
+    NoPendingDelivery(NoPendingDelivery),
+    ChallengeWindowOpen(ChallengeWindowOpen),
+    InsuranceUnavailable(InsuranceUnavailable),
+    NotClaimable(NotClaimable),
+    ClaimFailed(ClaimFailed),
+    InvalidRetryPolicy(InvalidRetryPolicy),
+    DrillsDisabled(DrillsDisabled),
+    InterfaceNotSupported(InterfaceNotSupported),
+    ParameterOutOfBounds(ParameterOutOfBounds),
+    DeliveryCreditFailed(DeliveryCreditFailed),
+}
+
+impl From<PayloadError> for SettlementVerifierError {
+    fn from(err: PayloadError) -> Self {
+        SettlementVerifierError::MalformedPayload(MalformedPayload { reason: err.code() })
+    }
+}
+
+#[entrypoint]
+#[storage]
+pub struct SettlementVerifier {
+    /// Contract owner
+    owner: StorageAddress,
+    /// RouteExecutor contract address
+    route_executor: StorageAddress,
+    /// CCIP router address (authorized to call verify functions)
+    ccip_router: StorageAddress,
+    /// Mapping of intent IDs to settlement status
+    settlements: StorageMap<U256, StorageU256>,
+    /// Mapping of intent IDs to timestamp for timeout tracking
+    settlement_timestamps: StorageMap<U256, StorageU256>,
+    /// Settlement timeout period (30 minutes = 1800 seconds)
+    timeout_period: StorageU256,
+    /// Bridge adapters authorized to report deliveries
+    settlement_adapters: StorageMap<Address, StorageBool>,
+    /// SolverRegistry holding solver bonds
+    solver_registry: StorageAddress,
+    /// Bond slashed per failed solver intent
+    slash_amount: StorageU256,
+    /// Mapping of intent IDs to the solver that executed them
+    intent_solvers: StorageMap<U256, StorageU256>,
+    /// Mapping of solver and insured intent IDs to their execution time
+    intent_started_at: StorageMap<U256, StorageU256>,
+    /// Mapping of match IDs to the local matched-leg intent
+    matched_legs: StorageMap<FixedBytes<32>, StorageU256>,
+    /// Wormhole core contract used to verify VAAs
+    wormhole: StorageAddress,
+    /// Mapping of Wormhole chain IDs to the emitter trusted to confirm deliveries
+    wormhole_emitters: StorageMap<U256, StorageFixedBytes<32>>,
+    /// deBridge DlnSource on this chain
+    dln_source: StorageAddress,
+    /// Mapping of DLN order IDs to the intent they carry
+    dln_orders: StorageMap<FixedBytes<32>, StorageU256>,
+    /// Most intents a solver may have in flight (zero = no cap)
+    max_in_flight_intents: StorageU256,
+    /// Mapping of token -> most notional a solver may have in flight in it (zero = no cap)
+    max_in_flight_notional: StorageMap<Address, StorageU256>,
+    /// Mapping of solver ID -> intents executed but not yet settled or failed
+    solver_in_flight: StorageMap<U256, StorageU256>,
+    /// Mapping of solver ID -> token -> notional of its in-flight intents
+    solver_in_flight_notional: StorageMap<U256, StorageMap<Address, StorageU256>>,
+    /// Mapping of in-flight solver intent IDs to their input token (cleared once settled or failed)
+    intent_tokens: StorageMap<U256, StorageAddress>,
+    /// Mapping of in-flight solver intent IDs to their input amount
+    intent_notionals: StorageMap<U256, StorageU256>,
+    /// Ethereum Attestation Service contract (zero = attestations off)
+    eas: StorageAddress,
+    /// UID of the registered intent attestation schema
+    attestation_schema: StorageFixedBytes<32>,
+    /// Attestation UID issued for each settled intent
+    intent_attestations: StorageMap<U256, StorageFixedBytes<32>>,
+    /// Mapping of intent IDs to the contract called once they settle or fail
+    /// (cleared once called)
+    intent_callbacks: StorageMap<U256, StorageAddress>,
+    /// Mapping of intent IDs to the data passed to their callback
+    intent_callback_data: StorageMap<U256, StorageBytes>,
+    /// Mapping of destination chains to their packed finality policy (zero = default)
+    lane_policies: StorageMap<U256, StorageU256>,
+    /// Off-chain attesters allowed to report deliveries
+    attesters: StorageMap<Address, StorageBool>,
+    /// Mapping of intent IDs to the round their reports count in; a
+    /// challenge starts a new round
+    delivery_rounds: StorageMap<U256, StorageU256>,
+    /// Mapping of intent ID -> reporter -> round it last reported in, plus one
+    delivery_reporters: StorageMap<U256, StorageMap<Address, StorageU256>>,
+    /// Mapping of intent IDs to the reports agreeing on their pending delivery
+    delivery_confirmations: StorageMap<U256, StorageU256>,
+    /// Mapping of intent IDs to the message ID of their pending delivery
+    pending_message_ids: StorageMap<U256, StorageFixedBytes<32>>,
+    /// Mapping of intent IDs to the `DeliveryStatus` of their pending delivery
+    pending_statuses: StorageMap<U256, StorageU256>,
+    /// Mapping of intent IDs to when their agreed delivery can be finalized
+    /// (zero until the quorum is reached)
+    pending_final_at: StorageMap<U256, StorageU256>,
+    /// InsuranceFund paying claims on insured intents (zero = none)
+    insurance_fund: StorageAddress,
+    /// Mapping of insured intent IDs to the user their claim pays
+    insured_users: StorageMap<U256, StorageAddress>,
+    /// Mapping of insured intent IDs to the token their coverage is in
+    insured_tokens: StorageMap<U256, StorageAddress>,
+    /// Mapping of insured intent IDs to the amount covered
+    insured_coverage: StorageMap<U256, StorageU256>,
+    /// Mapping of insured intent IDs to the premium paid for them
+    insured_premiums: StorageMap<U256, StorageU256>,
+    /// Mapping of tokens to the premiums paid in them
+    insurance_premiums: StorageMap<Address, StorageU256>,
+    /// Mapping of insured intent IDs to the payout on their claim (zero = unclaimed)
+    insurance_claims: StorageMap<U256, StorageU256>,
+    /// Mapping of intent IDs to their retry policy word (zero = fail on the
+    /// first timeout)
+    retry_policies: StorageMap<U256, StorageU256>,
+    /// Mapping of intent IDs with a retry policy to the adapter that bridged them
+    retry_bridges: StorageMap<U256, StorageAddress>,
+    /// Mapping of intent IDs to the delivery attempts opened after the first
+    retry_counts: StorageMap<U256, StorageU256>,
+    /// Mapping of intent ID -> attempt -> when the attempt started (attempt
+    /// zero is the execution)
+    attempt_started_at: StorageMap<U256, StorageMap<U256, StorageU256>>,
+    /// Mapping of in-flight solver intent IDs to their recipient (zero for matched legs)
+    intent_recipients: StorageMap<U256, StorageAddress>,
+}
+
+#[public]
+impl SettlementVerifier {
+    /// Initialize the contract
+    pub fn init(
+        &mut self,
+        route_executor_address: Address,
+        ccip_router_address: Address,
+    ) -> Result<(), SettlementVerifierError> {
+        if route_executor_address == Address::ZERO || ccip_router_address == Address::ZERO {
+            return Err(SettlementVerifierError::Unauthorized(Unauthorized {}));
+        }
+
+        self.owner.set(self.vm().msg_sender());
+        self.route_executor.set(route_executor_address);
+        self.ccip_router.set(ccip_router_address);
+        // Set timeout to 30 minutes (1800 seconds)
+        self.timeout_period.set(U256::from(1800));
+
+        Ok(())
+    }
+
+    /// Verify CCIP message delivery
+    /// 
+    /// Called by CCIP router on destination chain to confirm message delivery.
+    /// Can only be called by authorized CCIP router.
+    pub fn verify_ccip_message(
+        &mut self,
+        message_id: FixedBytes<32>,
+        intent_id: U256,
+    ) -> Result<bool, SettlementVerifierError> {
+        // Only CCIP router can call this
+        self.only_ccip_router()?;
+
+        let router = self.ccip_router.get();
+        self.report_delivery(REPORTER_CCIP, router, message_id, intent_id, DeliveryStatus::Delivered)
+    }
+
+    /// Verify a delivery reported by a registered bridge adapter
+    /// 
+    /// Called by adapters (LayerZero, ...) on the destination chain once their
+    /// messaging layer has authenticated the inbound message.
+    pub fn verify_adapter_message(
+        &mut self,
+        message_id: FixedBytes<32>,
+        intent_id: U256,
+    ) -> Result<bool, SettlementVerifierError> {
+        let adapter = self.vm().msg_sender();
+        if !self.settlement_adapters.get(adapter) {
+            return Err(SettlementVerifierError::Unauthorized(Unauthorized {}));
+        }
+
+        self.report_delivery(REPORTER_ADAPTER, adapter, message_id, intent_id, DeliveryStatus::Delivered)
+    }
+
+    /// Verify a delivery reported by a registered adapter with its outcome
+    ///
+    /// Used by destination receivers that can fall back to delivering less
+    /// than the intent asked for; `status` follows `DeliveryStatus`.
+    pub fn verify_adapter_delivery(
+        &mut self,
+        message_id: FixedBytes<32>,
+        intent_id: U256,
+        status: u8,
+    ) -> Result<bool, SettlementVerifierError> {
+        let adapter = self.vm().msg_sender();
+        if !self.settlement_adapters.get(adapter) {
+            return Err(SettlementVerifierError::Unauthorized(Unauthorized {}));
+        }
+        let delivery = DeliveryStatus::from_u8(status)
+            .ok_or(SettlementVerifierError::InvalidDeliveryStatus(InvalidDeliveryStatus { status }))?;
+
+        self.report_delivery(REPORTER_ADAPTER, adapter, message_id, intent_id, delivery)
+    }
+
+    /// Verify a batch of deliveries reported by a registered adapter
+    ///
+    /// Entry `i` is `message_ids[i]`, `intent_ids[i]` and `statuses[i]`,
+    /// recorded as `verify_adapter_delivery` would. An entry that can't be
+    /// (zero intent ID, already settled, unknown status) is logged as
+    /// `DeliveryRejected` and skipped. Returns the bitmap of recorded entries.
+    pub fn verify_adapter_deliveries(
+        &mut self,
+        message_ids: Vec<FixedBytes<32>>,
+        intent_ids: Vec<U256>,
+        statuses: Vec<u8>,
+    ) -> Result<U256, SettlementVerifierError> {
+        let adapter = self.vm().msg_sender();
+        if !self.settlement_adapters.get(adapter) {
+            return Err(SettlementVerifierError::Unauthorized(Unauthorized {}));
+        }
+        let entries = message_ids.len();
+        if intent_ids.len() != entries || statuses.len() != entries || entries > MAX_SETTLEMENT_BATCH {
+            return Err(SettlementVerifierError::InvalidBatch(InvalidBatch {}));
+        }
+
+        let pending = U256::from(SettlementStatus::Pending as u8);
+        let mut recorded = U256::ZERO;
+        let reports = message_ids.into_iter().zip(intent_ids).zip(statuses);
+        for (index, ((message_id, intent_id), status)) in reports.enumerate() {
+            match DeliveryStatus::from_u8(status) {
+                Some(delivery) if intent_id != U256::ZERO && self.settlements.get(intent_id) == pending => {
+                    self.report_delivery(REPORTER_ADAPTER, adapter, message_id, intent_id, delivery)?;
+                    recorded |= U256::from(1) << index;
+                }
+                _ => self.vm().log(DeliveryRejected { messageId: message_id, intentId: intent_id }),
+            }
+        }
+
+        Ok(recorded)
+    }
+
+    /// Report tokens bridged to RouteExecutor for a hidden-recipient intent
+    /// (registered adapters only)
+    ///
+    /// `intent_hash` is the source chain's hash of the intent. The delivery
+    /// is credited to it on RouteExecutor, which is the only balance
+    /// `settle_hidden_recipient` may pay out from.
+    pub fn report_hidden_delivery(
+        &mut self,
+        intent_hash: FixedBytes<32>,
+        token: Address,
+        amount: U256,
+    ) -> Result<(), SettlementVerifierError> {
+        let adapter = self.vm().msg_sender();
+        if !self.settlement_adapters.get(adapter) {
+            return Err(SettlementVerifierError::Unauthorized(Unauthorized {}));
+        }
+
+        let calldata = creditHiddenDeliveryCall {
+            intentHash: intent_hash,
+            token,
+            amount,
+        }
+        .abi_encode();
+        let executor = self.route_executor.get();
+        let config = Call::new_mutating(self);
+        call(self.vm(), config, executor, &calldata)
+            .map_err(|_| SettlementVerifierError::DeliveryCreditFailed(DeliveryCreditFailed {}))?;
+
+        self.vm().log(HiddenDeliveryReported {
+            intentHash: intent_hash,
+            adapter,
+            token,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    /// Verify a delivery from a Wormhole VAA
+    ///
+    /// Anyone may submit the VAA. The core contract checks the guardian
+    /// signatures; the emitter must be registered for its chain and the
+    /// payload is the delivered bridge payload. The VAA hash is the message ID.
+    pub fn verify_wormhole_vaa(&mut self, vaa: Bytes) -> Result<bool, SettlementVerifierError> {
+        let wormhole = self.wormhole.get();
+        if wormhole == Address::ZERO {
+            return Err(SettlementVerifierError::InvalidVaa(InvalidVaa {}));
+        }
+
+        let calldata = parseAndVerifyVMCall { encodedVM: vaa }.abi_encode();
+        let result = static_call(self.vm(), Call::new(), wormhole, &calldata)
+            .map_err(|_| SettlementVerifierError::InvalidVaa(InvalidVaa {}))?;
+        let parsed = parseAndVerifyVMCall::abi_decode_returns(&result)
+            .map_err(|_| SettlementVerifierError::InvalidVaa(InvalidVaa {}))?;
+        if !parsed.valid {
+            return Err(SettlementVerifierError::InvalidVaa(InvalidVaa {}));
+        }
+
+        let emitter = self.wormhole_emitters.get(U256::from(parsed.vm.emitterChainId));
+        if emitter == FixedBytes::<32>::ZERO || emitter != parsed.vm.emitterAddress {
+            return Err(SettlementVerifierError::Unauthorized(Unauthorized {}));
+        }
+
+        let payload = payload_codec::decode_bridge(&parsed.vm.payload).map_err(SettlementVerifierError::from)?;
+
+        self.report_delivery(REPORTER_WORMHOLE, wormhole, parsed.vm.hash, payload.intentId, DeliveryStatus::Delivered)
+    }
+
+    /// Set the Wormhole core contract used to verify VAAs (admin only)
+    pub fn set_wormhole(&mut self, wormhole: Address) -> Result<(), SettlementVerifierError> {
+        self.only_owner()?;
+        self.wormhole.set(wormhole);
+
+        self.vm().log(WormholeSet { wormhole });
+
+        Ok(())
+    }
+
+    /// Trust an emitter on a Wormhole chain to confirm deliveries (admin only)
+    ///
+    /// A zero emitter removes the chain.
+    pub fn set_wormhole_emitter(
+        &mut self,
+        wormhole_chain: u16,
+        emitter: FixedBytes<32>,
+    ) -> Result<(), SettlementVerifierError> {
+        self.only_owner()?;
+        self.wormhole_emitters.setter(U256::from(wormhole_chain)).set(emitter);
+
+        self.vm().log(WormholeEmitterSet {
+            wormholeChain: wormhole_chain,
+            emitter,
+        });
+
+        Ok(())
+    }
+
+    /// Get the emitter trusted on a Wormhole chain
+    pub fn get_wormhole_emitter(&self, wormhole_chain: u16) -> FixedBytes<32> {
+        self.wormhole_emitters.get(U256::from(wormhole_chain))
+    }
+
+    /// Register the DLN order an intent was posted as (settlement adapters only)
+    ///
+    /// Called by the DlnAdapter on this chain right after creating the order.
+    pub fn register_dln_order(
+        &mut self,
+        order_id: FixedBytes<32>,
+        intent_id: U256,
+    ) -> Result<(), SettlementVerifierError> {
+        if !self.settlement_adapters.get(self.vm().msg_sender()) {
+            return Err(SettlementVerifierError::Unauthorized(Unauthorized {}));
+        }
+
+        if intent_id == U256::ZERO {
+            return Err(SettlementVerifierError::InvalidIntentId(InvalidIntentId {}));
+        }
+
+        if order_id == FixedBytes::<32>::ZERO || self.dln_orders.get(order_id) != U256::ZERO {
+            return Err(SettlementVerifierError::InvalidMessageId(InvalidMessageId {}));
+        }
+
+        self.dln_orders.setter(order_id).set(intent_id);
+
+        self.vm().log(DlnOrderRegistered {
+            orderId: order_id,
+            intentId: intent_id,
+        });
+
+        Ok(())
+    }
+
+    /// Verify a delivery from a fulfilled DLN order
+    ///
+    /// Anyone may call this. DlnSource only marks an order unlocked after
+    /// deBridge relayed the taker's fulfillment from the destination chain,
+    /// so the unlock is the fulfillment proof. The order ID is the message ID.
+    pub fn verify_dln_order(&mut self, order_id: FixedBytes<32>) -> Result<bool, SettlementVerifierError> {
+        let intent_id = self.dln_orders.get(order_id);
+        if intent_id == U256::ZERO {
+            return Err(SettlementVerifierError::InvalidMessageId(InvalidMessageId {}));
+        }
+
+        let dln_source = self.dln_source.get();
+        if dln_source == Address::ZERO {
+            return Err(SettlementVerifierError::OrderNotFulfilled(OrderNotFulfilled {}));
+        }
+
+        let calldata = giveOrdersCall { orderId: order_id }.abi_encode();
+        let result = static_call(self.vm(), Call::new(), dln_source, &calldata)
+            .map_err(|_| SettlementVerifierError::OrderNotFulfilled(OrderNotFulfilled {}))?;
+        // The full order state is a static tuple; its first word is the status
+        let status = result
+            .get(..32)
+            .and_then(|word| giveOrdersCall::abi_decode_returns(word).ok())
+            .ok_or(SettlementVerifierError::OrderNotFulfilled(OrderNotFulfilled {}))?;
+        if status != DLN_STATUS_CLAIMED_UNLOCK {
+            return Err(SettlementVerifierError::OrderNotFulfilled(OrderNotFulfilled {}));
+        }
+
+        self.report_delivery(REPORTER_DLN, dln_source, order_id, intent_id, DeliveryStatus::Delivered)
+    }
+
+    /// Set the deBridge DlnSource queried for order status (admin only)
+    pub fn set_dln_source(&mut self, dln_source: Address) -> Result<(), SettlementVerifierError> {
+        self.only_owner()?;
+        self.dln_source.set(dln_source);
+
+        self.vm().log(DlnSourceSet { dlnSource: dln_source });
+
+        Ok(())
+    }
+
+    /// Get the intent a DLN order carries (zero if unregistered)
+    pub fn get_dln_order(&self, order_id: FixedBytes<32>) -> U256 {
+        self.dln_orders.get(order_id)
+    }
+
+    /// Authorize or revoke a bridge adapter as delivery reporter (admin only)
+    pub fn set_settlement_adapter(
+        &mut self,
+        adapter: Address,
+        enabled: bool,
+    ) -> Result<(), SettlementVerifierError> {
+        self.only_owner()?;
+
+        if adapter == Address::ZERO {
+            return Err(SettlementVerifierError::Unauthorized(Unauthorized {}));
+        }
+
+        self.settlement_adapters.setter(adapter).set(enabled);
+
+        self.vm().log(SettlementAdapterSet { adapter, enabled });
+
+        Ok(())
+    }
+
+    /// Check if an adapter may report deliveries
+    pub fn is_settlement_adapter(&self, adapter: Address) -> bool {
+        self.settlement_adapters.get(adapter)
+    }
+
+    /// Record the solver that executed an intent (RouteExecutor only)
+    ///
+    /// Starts the settlement clock used by `slash_expired_intent`, and counts
+    /// the intent, with `notional` of `token`, against the solver's in-flight
+    /// caps until it settles or fails. The terms and `recipient` are kept
+    /// while in flight so LiquidityPool can match loans against them.
+    pub fn register_solver_intent(
+        &mut self,
+        intent_id: U256,
+        solver_id: U256,
+        token: Address,
+        notional: U256,
+        recipient: Address,
+    ) -> Result<(), SettlementVerifierError> {
+        if self.vm().msg_sender() != self.route_executor.get() {
+            return Err(SettlementVerifierError::Unauthorized(Unauthorized {}));
+        }
+
+        if intent_id == U256::ZERO || self.intent_solvers.get(intent_id) != U256::ZERO {
+            return Err(SettlementVerifierError::InvalidIntentId(InvalidIntentId {}));
+        }
+        self.check_in_flight_caps(solver_id, token, notional)?;
+
+        let in_flight = self.solver_in_flight.get(solver_id) + U256::from(1);
+        self.solver_in_flight.setter(solver_id).set(in_flight);
+        let in_flight_notional = self.solver_in_flight_notional.getter(solver_id).get(token) + notional;
+        self.solver_in_flight_notional
+            .setter(solver_id)
+            .setter(token)
+            .set(in_flight_notional);
+        self.intent_tokens.setter(intent_id).set(token);
+        self.intent_notionals.setter(intent_id).set(notional);
+        self.intent_recipients.setter(intent_id).set(recipient);
+
+        self.intent_solvers.setter(intent_id).set(solver_id);
+        self.intent_started_at.setter(intent_id).set(U256::from(self.vm().block_timestamp()));
+
+        self.vm().log(SolverIntentRegistered {
+            intentId: intent_id,
+            solverId: solver_id,
+        });
+
+        Ok(())
+    }
+
+    /// Record one leg of a matched pair executed by a solver (RouteExecutor only)
+    ///
+    /// Settles through `confirm_matched_leg` once the mirror leg is reported,
+    /// or is slashed like any other solver intent after the timeout.
+    pub fn register_matched_leg(
+        &mut self,
+        intent_id: U256,
+        solver_id: U256,
+        match_id: FixedBytes<32>,
+        token: Address,
+        notional: U256,
+    ) -> Result<(), SettlementVerifierError> {
+        if match_id == FixedBytes::<32>::ZERO || self.matched_legs.get(match_id) != U256::ZERO {
+            return Err(SettlementVerifierError::InvalidMessageId(InvalidMessageId {}));
+        }
+
+        self.register_solver_intent(intent_id, solver_id, token, notional, Address::ZERO)?;
+        self.matched_legs.setter(match_id).set(intent_id);
+
+        self.vm().log(MatchedLegRegistered {
+            matchId: match_id,
+            intentId: intent_id,
+        });
+
+        Ok(())
+    }
+
+    /// Record the contract to call once an intent settles or fails (RouteExecutor only)
+    ///
+    /// `data` is passed back unchanged in `onIntentSettled`; an intent's
+    /// callback can only be registered once, and must advertise
+    /// `INTENT_CALLBACK_ID` through ERC-165.
+    pub fn register_intent_callback(
+        &mut self,
+        intent_id: U256,
+        callback: Address,
+        data: Bytes,
+    ) -> Result<(), SettlementVerifierError> {
+        if self.vm().msg_sender() != self.route_executor.get() {
+            return Err(SettlementVerifierError::Unauthorized(Unauthorized {}));
+        }
+
+        if intent_id == U256::ZERO || self.intent_callbacks.get(intent_id) != Address::ZERO {
+            return Err(SettlementVerifierError::InvalidIntentId(InvalidIntentId {}));
+        }
+        if callback == Address::ZERO || data.len() > MAX_CALLBACK_DATA {
+            return Err(SettlementVerifierError::InvalidCallback(InvalidCallback {}));
+        }
+        self.require_interface(callback, erc165::INTENT_CALLBACK_ID)?;
+
+        self.intent_callbacks.setter(intent_id).set(callback);
+        self.intent_callback_data.setter(intent_id).set_bytes(&data);
+
+        self.vm().log(CallbackRegistered { intentId: intent_id, callback });
+
+        Ok(())
+    }
+
+    /// Get the callback registered for an intent and its data (zero once called)
+    pub fn get_intent_callback(&self, intent_id: U256) -> (Address, Bytes) {
+        (
+            self.intent_callbacks.get(intent_id),
+            self.intent_callback_data.getter(intent_id).get_bytes().into(),
+        )
+    }
+
+    /// Record an insured intent and the premium paid for it (RouteExecutor only)
+    ///
+    /// Starts the settlement clock, so an insured intent nobody reports can
+    /// be failed through `slash_expired_intent` like a solver intent.
+    pub fn register_insurance(
+        &mut self,
+        intent_id: U256,
+        user: Address,
+        token: Address,
+        coverage: U256,
+        premium: U256,
+    ) -> Result<(), SettlementVerifierError> {
+        if self.vm().msg_sender() != self.route_executor.get() {
+            return Err(SettlementVerifierError::Unauthorized(Unauthorized {}));
+        }
+
+        if intent_id == U256::ZERO || self.insured_users.get(intent_id) != Address::ZERO {
+            return Err(SettlementVerifierError::InvalidIntentId(InvalidIntentId {}));
+        }
+        if self.insurance_fund.get() == Address::ZERO || user == Address::ZERO || coverage == U256::ZERO {
+            return Err(SettlementVerifierError::InsuranceUnavailable(InsuranceUnavailable {}));
+        }
+
+        self.insured_users.setter(intent_id).set(user);
+        self.insured_tokens.setter(intent_id).set(token);
+        self.insured_coverage.setter(intent_id).set(coverage);
+        self.insured_premiums.setter(intent_id).set(premium);
+        let premiums = self.insurance_premiums.get(token) + premium;
+        self.insurance_premiums.setter(token).set(premiums);
+        if self.intent_started_at.get(intent_id) == U256::ZERO {
+            self.intent_started_at.setter(intent_id).set(U256::from(self.vm().block_timestamp()));
+        }
+
+        self.vm().log(IntentInsured {
+            intentId: intent_id,
+            user,
+            token,
+            coverage,
+            premium,
+        });
+
+        Ok(())
+    }
+
+    /// Record an intent's retry policy and the adapter that bridged it
+    /// (RouteExecutor only)
+    ///
+    /// Starts the settlement clock, so a delivery nobody reports is retried
+    /// through `slash_expired_intent` until the policy runs out.
+    pub fn register_retry_policy(
+        &mut self,
+        intent_id: U256,
+        policy: U256,
+        bridge: Address,
+    ) -> Result<(), SettlementVerifierError> {
+        if self.vm().msg_sender() != self.route_executor.get() {
+            return Err(SettlementVerifierError::Unauthorized(Unauthorized {}));
+        }
+
+        if intent_id == U256::ZERO || self.retry_policies.get(intent_id) != U256::ZERO {
+            return Err(SettlementVerifierError::InvalidIntentId(InvalidIntentId {}));
+        }
+        if !RetryPolicy::decode(policy).is_some_and(|policy| policy.retries()) || bridge == Address::ZERO {
+            return Err(SettlementVerifierError::InvalidRetryPolicy(InvalidRetryPolicy {}));
+        }
+
+        let now = U256::from(self.vm().block_timestamp());
+        self.retry_policies.setter(intent_id).set(policy);
+        self.retry_bridges.setter(intent_id).set(bridge);
+        self.attempt_started_at.setter(intent_id).setter(U256::ZERO).set(now);
+        if self.intent_started_at.get(intent_id) == U256::ZERO {
+            self.intent_started_at.setter(intent_id).set(now);
+        }
+
+        self.vm().log(RetryPolicyRegistered {
+            intentId: intent_id,
+            policy,
+            bridge,
+        });
+
+        Ok(())
+    }
+
+    /// Get an intent's retry policy word, the adapter that bridged it and
+    /// the attempts opened after the first (zero policy if it has none)
+    pub fn get_retry_state(&self, intent_id: U256) -> (U256, Address, U256) {
+        (
+            self.retry_policies.get(intent_id),
+            self.retry_bridges.get(intent_id),
+            self.retry_counts.get(intent_id),
+        )
+    }
+
+    /// Get when an intent's delivery attempt started (attempt zero is the
+    /// execution; zero if the attempt was never opened)
+    pub fn get_attempt_started_at(&self, intent_id: U256, attempt: U256) -> U256 {
+        self.attempt_started_at.getter(intent_id).get(attempt)
+    }
+
+    /// Pay out the insurance on a failed intent
+    ///
+    /// Anyone may call this; the payout always goes to the insured user.
+    /// Only an intent marked Failed is claimable: one that was refunded, or
+    /// is still pending or settled, isn't. Returns the payout in the
+    /// InsuranceFund's payout token.
+    pub fn claim_insurance(&mut self, intent_id: U256) -> Result<U256, SettlementVerifierError> {
+        let user = self.insured_users.get(intent_id);
+        if user == Address::ZERO {
+            return Err(SettlementVerifierError::InvalidIntentId(InvalidIntentId {}));
+        }
+        if self.insurance_claims.get(intent_id) != U256::ZERO {
+            return Err(SettlementVerifierError::AlreadyProcessed(AlreadyProcessed {}));
+        }
+
+        let status = self.settlements.get(intent_id);
+        if status != U256::from(SettlementStatus::Failed as u8) {
+            return Err(SettlementVerifierError::NotClaimable(NotClaimable { intentId: intent_id, status }));
+        }
+
+        let fund = self.insurance_fund.get();
+        if fund == Address::ZERO {
+            return Err(SettlementVerifierError::InsuranceUnavailable(InsuranceUnavailable {}));
+        }
+        let calldata = payClaimCall {
+            intentId: intent_id,
+            user,
+            token: self.insured_tokens.get(intent_id),
+            coverage: self.insured_coverage.get(intent_id),
+        }
+        .abi_encode();
+        let config = Call::new_mutating(self);
+        let result = call(self.vm(), config, fund, &calldata)
+            .map_err(|_| SettlementVerifierError::ClaimFailed(ClaimFailed {}))?;
+        let payout = payClaimCall::abi_decode_returns(&result)
+            .map_err(|_| SettlementVerifierError::ClaimFailed(ClaimFailed {}))?;
+
+        self.insurance_claims.setter(intent_id).set(payout);
+
+        self.vm().log(InsuranceClaimed {
+            intentId: intent_id,
+            user,
+            payout,
+        });
+
+        Ok(payout)
+    }
+
+    /// Set the InsuranceFund paying claims (admin only)
+    ///
+    /// Zero stops RouteExecutor taking out new insurance; claims on intents
+    /// already insured wait until a fund is set again.
+    pub fn set_insurance_fund(&mut self, fund: Address) -> Result<(), SettlementVerifierError> {
+        self.only_owner()?;
+        self.insurance_fund.set(fund);
+
+        self.vm().log(InsuranceFundSet { fund });
+
+        Ok(())
+    }
+
+    /// Get the InsuranceFund paying claims
+    pub fn insurance_fund(&self) -> Address {
+        self.insurance_fund.get()
+    }
+
+    /// Get an intent's insurance: the user it pays, its token, coverage,
+    /// the premium paid and the payout on its claim (zero user if uninsured)
+    pub fn get_insurance(&self, intent_id: U256) -> (Address, Address, U256, U256, U256) {
+        (
+            self.insured_users.get(intent_id),
+            self.insured_tokens.get(intent_id),
+            self.insured_coverage.get(intent_id),
+            self.insured_premiums.get(intent_id),
+            self.insurance_claims.get(intent_id),
+        )
+    }
+
+    /// Get the insurance premiums paid in a token
+    pub fn get_insurance_premiums(&self, token: Address) -> U256 {
+        self.insurance_premiums.get(token)
+    }
+
+    /// Confirm a matched leg once its mirror leg has executed on the other chain
+    ///
+    /// Called by the CCIP router or a registered adapter relaying the mirror
+    /// leg's `IntentMatched` event.
+    pub fn confirm_matched_leg(&mut self, match_id: FixedBytes<32>) -> Result<bool, SettlementVerifierError> {
+        let sender = self.vm().msg_sender();
+        let reporter_type = if sender == self.ccip_router.get() {
+            REPORTER_CCIP
+        } else if self.settlement_adapters.get(sender) {
+            REPORTER_ADAPTER
+        } else {
+            return Err(SettlementVerifierError::Unauthorized(Unauthorized {}));
+        };
+
+        let intent_id = self.matched_legs.get(match_id);
+        if intent_id == U256::ZERO {
+            return Err(SettlementVerifierError::InvalidMessageId(InvalidMessageId {}));
+        }
+
+        self.report_delivery(reporter_type, sender, match_id, intent_id, DeliveryStatus::Delivered)
+    }
+
+    /// Set a lane's finality policy (admin only)
+    ///
+    /// `reporters` is a set of `REPORTER_*` bits from `lane_policy` in
+    /// swoosh-common; `quorum` distinct reporters of those types must agree
+    /// on a delivery, which then settles once `challenge_window` seconds have
+    /// passed. A zero `reporters` puts the lane back on the default policy.
+    pub fn set_lane_policy(
+        &mut self,
+        chain_id: U256,
+        reporters: u8,
+        quorum: u8,
+        challenge_window: u64,
+    ) -> Result<(), SettlementVerifierError> {
+        self.only_owner()?;
+
+        let policy = LanePolicy {
+            reporters,
+            quorum,
+            challenge_window,
+        };
+        if chain_id == U256::ZERO || (reporters != 0 && !policy.is_valid()) {
+            return Err(SettlementVerifierError::InvalidLanePolicy(InvalidLanePolicy {}));
+        }
+        let word = if reporters == 0 { U256::ZERO } else { policy.encode() };
+        self.lane_policies.setter(chain_id).set(word);
+
+        let policy = LanePolicy::decode(word);
+        self.vm().log(LanePolicySet {
+            chainId: chain_id,
+            reporters: policy.reporters,
+            quorum: policy.quorum,
+            challengeWindow: policy.challenge_window,
+        });
+
+        Ok(())
+    }
+
+    /// Get a lane's finality policy: accepted reporter types, quorum and
+    /// challenge window
+    pub fn get_lane_policy(&self, chain_id: U256) -> (u8, u8, u64) {
+        let policy = LanePolicy::decode(self.lane_policies.get(chain_id));
+        (policy.reporters, policy.quorum, policy.challenge_window)
+    }
+
+    /// Allow or revoke an off-chain attester (admin only)
+    pub fn set_attester(&mut self, attester: Address, enabled: bool) -> Result<(), SettlementVerifierError> {
+        self.only_owner()?;
+
+        if attester == Address::ZERO {
+            return Err(SettlementVerifierError::Unauthorized(Unauthorized {}));
+        }
+        self.attesters.setter(attester).set(enabled);
+
+        self.vm().log(AttesterSet { attester, enabled });
+
+        Ok(())
+    }
+
+    /// Check if an address is an allowed attester
+    pub fn is_attester(&self, attester: Address) -> bool {
+        self.attesters.get(attester)
+    }
+
+    /// Report a delivery observed off-chain (attesters only)
+    ///
+    /// Counts toward the quorum of lanes accepting attesters; `status`
+    /// follows `DeliveryStatus`.
+    pub fn attest_delivery(
+        &mut self,
+        message_id: FixedBytes<32>,
+        intent_id: U256,
+        status: u8,
+    ) -> Result<bool, SettlementVerifierError> {
+        let attester = self.vm().msg_sender();
+        if !self.attesters.get(attester) {
+            return Err(SettlementVerifierError::Unauthorized(Unauthorized {}));
+        }
+        let delivery = DeliveryStatus::from_u8(status)
+            .ok_or(SettlementVerifierError::InvalidDeliveryStatus(InvalidDeliveryStatus { status }))?;
+
+        self.report_delivery(REPORTER_ATTESTER, attester, message_id, intent_id, delivery)
+    }
+
+    /// Settle an agreed delivery once its challenge window has passed
+    ///
+    /// Anyone may call this.
+    pub fn finalize_delivery(&mut self, intent_id: U256) -> Result<bool, SettlementVerifierError> {
+        let final_at = self.pending_final_at.get(intent_id);
+        if final_at == U256::ZERO {
+            return Err(SettlementVerifierError::NoPendingDelivery(NoPendingDelivery {}));
+        }
+        if U256::from(self.vm().block_timestamp()) < final_at {
+            return Err(SettlementVerifierError::ChallengeWindowOpen(ChallengeWindowOpen { finalAt: final_at }));
+        }
+
+        self.finalize_pending(intent_id)
+    }
+
+    /// Throw out an intent's pending delivery (owner or attesters)
+    ///
+    /// Allowed until an agreed delivery's challenge window ends. Reports then
+    /// start over in a new round, so every reporter must report again.
+    pub fn challenge_delivery(&mut self, intent_id: U256) -> Result<(), SettlementVerifierError> {
+        let challenger = self.vm().msg_sender();
+        if challenger != self.owner.get() && !self.attesters.get(challenger) {
+            return Err(SettlementVerifierError::Unauthorized(Unauthorized {}));
+        }
+
+        if self.delivery_confirmations.get(intent_id) == U256::ZERO {
+            return Err(SettlementVerifierError::NoPendingDelivery(NoPendingDelivery {}));
+        }
+        let final_at = self.pending_final_at.get(intent_id);
+        if final_at != U256::ZERO && U256::from(self.vm().block_timestamp()) >= final_at {
+            return Err(SettlementVerifierError::AlreadyProcessed(AlreadyProcessed {}));
+        }
+
+        let round = self.delivery_rounds.get(intent_id) + U256::from(1);
+        self.delivery_rounds.setter(intent_id).set(round);
+        self.clear_pending(intent_id);
+
+        self.vm().log(DeliveryChallenged { intentId: intent_id, challenger });
+
+        Ok(())
+    }
+
+    /// Get an intent's pending delivery: message ID, `DeliveryStatus`,
+    /// agreeing reports, and when it can be finalized (zero before quorum)
+    pub fn get_pending_delivery(&self, intent_id: U256) -> (FixedBytes<32>, u8, U256, U256) {
+        (
+            self.pending_message_ids.get(intent_id),
+            self.pending_statuses.get(intent_id).saturating_to(),
+            self.delivery_confirmations.get(intent_id),
+            self.pending_final_at.get(intent_id),
+        )
+    }
+
+    /// Set the EAS contract and intent schema UID for settlement attestations (admin only)
+    ///
+    /// The schema must be registered with the chain's SchemaRegistry first
+    /// (`schema_uid` in swoosh-common gives its UID). A zero `eas` turns
+    /// attestations off.
+    pub fn set_attestations(&mut self, eas: Address, schema: FixedBytes<32>) -> Result<(), SettlementVerifierError> {
+        self.only_owner()?;
+
+        self.eas.set(eas);
+        self.attestation_schema.set(schema);
+
+        self.vm().log(AttestationsSet { eas, schema });
+
+        Ok(())
+    }
+
+    /// Get the EAS contract and intent schema UID used for attestations
+    pub fn get_attestations(&self) -> (Address, FixedBytes<32>) {
+        (self.eas.get(), self.attestation_schema.get())
+    }
+
+    /// Attest a settled intent whose attestation is missing
+    ///
+    /// For intents settled before attestations were turned on, or whose
+    /// attestation failed at settlement. Anyone may call. Returns the UID.
+    pub fn attest_settlement(&mut self, intent_id: U256) -> Result<FixedBytes<32>, SettlementVerifierError> {
+        if self.eas.get() == Address::ZERO {
+            return Err(SettlementVerifierError::AttestationFailed(AttestationFailed {}));
+        }
+        if self.intent_attestations.get(intent_id) != FixedBytes::<32>::ZERO {
+            return Err(SettlementVerifierError::AlreadyProcessed(AlreadyProcessed {}));
+        }
+
+        let status = self.settlements.get(intent_id);
+        let settled = [
+            SettlementStatus::Confirmed,
+            SettlementStatus::PartialDelivery,
+            SettlementStatus::DeliveredToClaim,
+        ]
+        .iter()
+        .any(|settled| status == U256::from(*settled as u8));
+        if !settled {
+            return Err(SettlementVerifierError::InvalidIntentId(InvalidIntentId {}));
+        }
+
+        self.issue_attestation(intent_id)
+            .ok_or(SettlementVerifierError::AttestationFailed(AttestationFailed {}))
+    }
+
+    /// Get the attestation UID issued for a settled intent (zero if none)
+    pub fn get_intent_attestation(&self, intent_id: U256) -> FixedBytes<32> {
+        self.intent_attestations.get(intent_id)
+    }
+
+    /// Get the local intent registered under a match ID (zero if none)
+    pub fn get_matched_leg(&self, match_id: FixedBytes<32>) -> U256 {
+        self.matched_legs.get(match_id)
+    }
+
+    /// Mark a solver, insured or retrying intent Failed once it has gone
+    /// unsettled past the timeout
+    ///
+    /// Anyone may call this; the executing solver's bond, if any, is slashed.
+    /// An intent whose retry policy allows another attempt gets one instead,
+    /// restarting the clock, and nothing is slashed.
+    pub fn slash_expired_intent(&mut self, intent_id: U256) -> Result<U256, SettlementVerifierError> {
+        let started_at = self.intent_started_at.get(intent_id);
+        if started_at == U256::ZERO {
+            return Err(SettlementVerifierError::InvalidIntentId(InvalidIntentId {}));
+        }
+
+        // An agreed delivery waiting out its challenge window isn't expired
+        if self.get_settlement_status(intent_id) != U256::from(SettlementStatus::Pending as u8)
+            || self.pending_final_at.get(intent_id) != U256::ZERO
+        {
+            return Err(SettlementVerifierError::AlreadyProcessed(AlreadyProcessed {}));
+        }
+
+        let now = U256::from(self.vm().block_timestamp());
+        if now <= started_at + self.timeout_period.get() {
+            return Err(SettlementVerifierError::SettlementTimeout(SettlementTimeout {}));
+        }
+        if self.retry_delivery(intent_id) {
+            return Ok(U256::ZERO);
+        }
+
+        self.set_settlement_status(intent_id, SettlementStatus::Failed as u8);
+
+        self.vm().log(SettlementFailed {
+            intentId: intent_id,
+            messageId: FixedBytes::<32>::ZERO,
+            reason: "settlement timeout".into(),
+        });
+
+        self.release_in_flight(intent_id);
+        let slashed = self.slash_solver(intent_id)?;
+        self.notify_callback(intent_id);
+
+        Ok(slashed)
+    }
+
+    /// Set the SolverRegistry used for slashing (admin only)
+    pub fn set_solver_registry(&mut self, registry: Address) -> Result<(), SettlementVerifierError> {
+        self.only_owner()?;
+        self.solver_registry.set(registry);
+
+        self.vm().log(SolverRegistrySet { registry });
+
+        Ok(())
+    }
+
+    /// Set the bond slashed per failed solver intent (admin only)
+    pub fn set_slash_amount(&mut self, amount: U256) -> Result<(), SettlementVerifierError> {
+        self.only_owner()?;
+        Param::SlashAmount.check(amount).map_err(SettlementVerifierError::ParameterOutOfBounds)?;
+
+        let old_amount = self.slash_amount.get();
+        self.slash_amount.set(amount);
+
+        self.vm().log(SlashAmountSet { amount });
+        self.vm().log(params::changed(Param::SlashAmount, old_amount, amount));
+
+        Ok(())
+    }
+
+    /// Cap the intents each solver may have in flight (admin only, zero = no cap)
+    pub fn set_max_in_flight_intents(&mut self, limit: U256) -> Result<(), SettlementVerifierError> {
+        self.only_owner()?;
+        Param::MaxInFlightIntents.check(limit).map_err(SettlementVerifierError::ParameterOutOfBounds)?;
+
+        let old_limit = self.max_in_flight_intents.get();
+        self.max_in_flight_intents.set(limit);
+
+        self.vm().log(MaxInFlightIntentsSet { limit });
+        self.vm().log(params::changed(Param::MaxInFlightIntents, old_limit, limit));
+
+        Ok(())
+    }
+
+    /// Cap the notional of a token each solver may have in flight (admin only,
+    /// zero = no cap)
+    pub fn set_max_in_flight_notional(&mut self, token: Address, limit: U256) -> Result<(), SettlementVerifierError> {
+        self.only_owner()?;
+        self.max_in_flight_notional.setter(token).set(limit);
+
+        self.vm().log(MaxInFlightNotionalSet { token, limit });
+
+        Ok(())
+    }
+
+    /// Get the cap on each solver's in-flight intents (zero = no cap)
+    pub fn max_in_flight_intents(&self) -> U256 {
+        self.max_in_flight_intents.get()
+    }
+
+    /// Get the cap on each solver's in-flight notional of a token (zero = no cap)
+    pub fn get_max_in_flight_notional(&self, token: Address) -> U256 {
+        self.max_in_flight_notional.get(token)
+    }
+
+    /// Get a solver's in-flight intent count and its in-flight notional of a token
+    pub fn get_solver_in_flight(&self, solver_id: U256, token: Address) -> (U256, U256) {
+        (
+            self.solver_in_flight.get(solver_id),
+            self.solver_in_flight_notional.getter(solver_id).get(token),
+        )
+    }
+
+    /// Whether a solver can take on another intent with `notional` of `token`
+    /// without exceeding its in-flight caps
+    pub fn has_solver_capacity(&self, solver_id: U256, token: Address, notional: U256) -> bool {
+        self.check_in_flight_caps(solver_id, token, notional).is_ok()
+    }
+
+    /// Get the solver that executed an intent (zero if none)
+    pub fn get_intent_solver(&self, intent_id: U256) -> U256 {
+        self.intent_solvers.get(intent_id)
+    }
+
+    /// Get an in-flight solver intent's solver ID, token, notional and
+    /// recipient (zero token once it settles or fails)
+    pub fn get_solver_intent(&self, intent_id: U256) -> (U256, Address, U256, Address) {
+        (
+            self.intent_solvers.get(intent_id),
+            self.intent_tokens.get(intent_id),
+            self.intent_notionals.get(intent_id),
+            self.intent_recipients.get(intent_id),
+        )
+    }
+
+    /// Get the bond slashed per failed solver intent
+    pub fn slash_amount(&self) -> U256 {
+        self.slash_amount.get()
+    }
+
+    /// Internal: Count a verified delivery report against its lane's policy
+    ///
+    /// On a lane without a quorum or challenge window the report settles the
+    /// intent at once. Otherwise it counts once per reporter per round; the
+    /// report reaching the quorum settles the intent, or starts the challenge
+    /// window if there is one. Reports after that are ignored. Returns
+    /// whether the intent settled.
+    fn report_delivery(
+        &mut self,
+        reporter_type: u8,
+        reporter: Address,
+        message_id: FixedBytes<32>,
+        intent_id: U256,
+        delivery: DeliveryStatus,
+    ) -> Result<bool, SettlementVerifierError> {
+        if intent_id == U256::ZERO {
+            return Err(SettlementVerifierError::InvalidIntentId(InvalidIntentId {}));
+        }
+        let policy = self.intent_lane_policy(intent_id);
+        if !policy.accepts(reporter_type) || !self.retry_accepts(intent_id, reporter_type, reporter) {
+            return Err(SettlementVerifierError::ReporterNotAccepted(ReporterNotAccepted {
+                reporterType: reporter_type,
+            }));
+        }
+        if policy.is_immediate() {
+            return self.record_delivery_as(message_id, intent_id, delivery);
+        }
+
+        if self.get_settlement_status(intent_id) != U256::from(SettlementStatus::Pending as u8) {
+            return Err(SettlementVerifierError::AlreadyProcessed(AlreadyProcessed {}));
+        }
+        let counted_round = self.delivery_rounds.get(intent_id) + U256::from(1);
+        if self.pending_final_at.get(intent_id) != U256::ZERO
+            || self.delivery_reporters.getter(intent_id).get(reporter) == counted_round
+        {
+            return Ok(false);
+        }
+
+        // Every report in a round must agree with the first one
+        let confirmations = self.delivery_confirmations.get(intent_id);
+        if confirmations == U256::ZERO {
+            self.pending_message_ids.setter(intent_id).set(message_id);
+            self.pending_statuses.setter(intent_id).set(U256::from(delivery as u8));
+        } else if self.pending_statuses.get(intent_id) != U256::from(delivery as u8) {
+            return Err(SettlementVerifierError::InvalidDeliveryStatus(InvalidDeliveryStatus {
+                status: delivery as u8,
+            }));
+        }
+        let confirmations = confirmations + U256::from(1);
+        self.delivery_confirmations.setter(intent_id).set(confirmations);
+        self.delivery_reporters.setter(intent_id).setter(reporter).set(counted_round);
+
+        self.vm().log(DeliveryReported {
+            intentId: intent_id,
+            reporter,
+            reporterType: reporter_type,
+            confirmations,
+        });
+
+        if confirmations < U256::from(policy.quorum) {
+            return Ok(false);
+        }
+        if policy.challenge_window == 0 {
+            return self.finalize_pending(intent_id);
+        }
+
+        let final_at = U256::from(self.vm().block_timestamp()) + U256::from(policy.challenge_window);
+        self.pending_final_at.setter(intent_id).set(final_at);
+        self.vm().log(DeliveryPending { intentId: intent_id, finalAt: final_at });
+
+        Ok(false)
+    }
+
+    /// Internal: Open another delivery attempt for a pending intent if its
+    /// retry policy allows one
+    ///
+    /// The new attempt restarts the settlement clock and a fresh report
+    /// round; reports counted toward the timed-out attempt are dropped.
+    fn retry_delivery(&mut self, intent_id: U256) -> bool {
+        if self.get_settlement_status(intent_id) != U256::from(SettlementStatus::Pending as u8) {
+            return false;
+        }
+        let Some(policy) = RetryPolicy::decode(self.retry_policies.get(intent_id)) else {
+            return false;
+        };
+        let retries = self.retry_counts.get(intent_id);
+        let executed_at = self.attempt_started_at.getter(intent_id).get(U256::ZERO);
+        let now = self.vm().block_timestamp();
+        if !policy.allows_retry(retries.saturating_to(), executed_at.saturating_to(), now) {
+            return false;
+        }
+
+        let attempt = retries + U256::from(1);
+        let timestamp = U256::from(now);
+        self.retry_counts.setter(intent_id).set(attempt);
+        self.attempt_started_at.setter(intent_id).setter(attempt).set(timestamp);
+        self.intent_started_at.setter(intent_id).set(timestamp);
+        // `handle_failure` times out from the settlement timestamp
+        if self.settlement_timestamps.get(intent_id) != U256::ZERO {
+            self.settlement_timestamps.setter(intent_id).set(timestamp);
+        }
+        let round = self.delivery_rounds.get(intent_id) + U256::from(1);
+        self.delivery_rounds.setter(intent_id).set(round);
+        self.clear_pending(intent_id);
+
+        self.vm().log(SettlementRetried {
+            intentId: intent_id,
+            attempt,
+            timestamp,
+        });
+
+        true
+    }
+
+    /// Internal: Whether a report counts toward an intent's retried attempt
+    ///
+    /// Without alternate bridges, adapter reports on a retried attempt only
+    /// count from the adapter that bridged the intent.
+    fn retry_accepts(&self, intent_id: U256, reporter_type: u8, reporter: Address) -> bool {
+        if reporter_type != REPORTER_ADAPTER || self.retry_counts.get(intent_id) == U256::ZERO {
+            return true;
+        }
+        RetryPolicy::decode(self.retry_policies.get(intent_id))
+            .is_some_and(|policy| policy.alternate_bridges || reporter == self.retry_bridges.get(intent_id))
+    }
+
+    /// Internal: Settle an intent with its agreed pending delivery
+    fn finalize_pending(&mut self, intent_id: U256) -> Result<bool, SettlementVerifierError> {
+        let message_id = self.pending_message_ids.get(intent_id);
+        let status: u8 = self.pending_statuses.get(intent_id).saturating_to();
+        let delivery = DeliveryStatus::from_u8(status)
+            .ok_or(SettlementVerifierError::InvalidDeliveryStatus(InvalidDeliveryStatus { status }))?;
+        self.clear_pending(intent_id);
+
+        self.record_delivery_as(message_id, intent_id, delivery)
+    }
+
+    /// Internal: Drop an intent's pending delivery; reports already counted
+    /// stay counted until the round changes
+    fn clear_pending(&mut self, intent_id: U256) {
+        self.delivery_confirmations.setter(intent_id).set(U256::ZERO);
+        self.pending_message_ids.setter(intent_id).set(FixedBytes::<32>::ZERO);
+        self.pending_statuses.setter(intent_id).set(U256::ZERO);
+        self.pending_final_at.setter(intent_id).set(U256::ZERO);
+    }
+
+    /// Internal: Finality policy of the lane an intent was bridged on
+    ///
+    /// The lane is the destination chain in RouteExecutor's execution
+    /// record; an intent it has no record of gets the default policy.
+    fn intent_lane_policy(&self, intent_id: U256) -> LanePolicy {
+        let calldata = getIntentExecutionCall { intentId: intent_id }.abi_encode();
+        let destination_chain = static_call(self.vm(), Call::new(), self.route_executor.get(), &calldata)
+            .ok()
+            .and_then(|result| getIntentExecutionCall::abi_decode_returns(&result).ok())
+            .map(|execution| execution.destinationChain)
+            .unwrap_or(U256::ZERO);
+        if destination_chain == U256::ZERO {
+            return LanePolicy::default();
+        }
+        LanePolicy::decode(self.lane_policies.get(destination_chain))
+    }
+
+    /// Internal: Record a verified delivery with its outcome
+    fn record_delivery_as(
+        &mut self,
+        message_id: FixedBytes<32>,
+        intent_id: U256,
+        delivery: DeliveryStatus,
+    ) -> Result<bool, SettlementVerifierError> {
+        // Validate intent ID
+        if intent_id == U256::ZERO {
+            return Err(SettlementVerifierError::InvalidIntentId(InvalidIntentId {}));
+        }
+
+        // Check if already processed
+        let current_status = self.get_settlement_status(intent_id);
+        if current_status != U256::from(SettlementStatus::Pending as u8) {
+            return Err(SettlementVerifierError::AlreadyProcessed(AlreadyProcessed {}));
+        }
+
+        // Record timestamp
+        let timestamp = U256::from(self.vm().block_timestamp());
+        self.settlement_timestamps.setter(intent_id).set(timestamp);
+
+        // Confirm settlement; a fallback delivery still settles the intent but
+        // keeps its own status
+        self.confirm_settlement(intent_id)?;
+        self.release_in_flight(intent_id);
+        if delivery != DeliveryStatus::Delivered {
+            self.set_settlement_status(intent_id, delivery as u8);
+
+            self.vm().log(DeliveryStatusReported {
+                intentId: intent_id,
+                messageId: message_id,
+                status: delivery as u8,
+            });
+        }
+
+        self.vm().log(SettlementConfirmed {
+            intentId: intent_id,
+            messageId: message_id,
+            timestamp: U256::from(self.vm().block_timestamp()),
+        });
+
+        let memo = self.intent_memo(intent_id);
+        if memo != FixedBytes::<32>::ZERO {
+            self.vm().log(SettlementMemo { intentId: intent_id, memo });
+        }
+
+        if self.eas.get() != Address::ZERO && self.issue_attestation(intent_id).is_none() {
+            self.vm().log(IntentAttestationFailed { intentId: intent_id });
+        }
+        self.notify_callback(intent_id);
+
+        Ok(true)
+    }
+
+    /// Confirm successful settlement
+    /// 
+    /// Updates settlement status to confirmed.
+    pub fn confirm_settlement(&mut self, intent_id: U256) -> Result<(), SettlementVerifierError> {
+        if intent_id == U256::ZERO {
+            return Err(SettlementVerifierError::InvalidIntentId(InvalidIntentId {}));
+        }
+
+        // Update status to Confirmed
+        self.set_settlement_status(intent_id, SettlementStatus::Confirmed as u8);
+
+        Ok(())
+    }
+
+    /// Handle failed transfer and initiate refund
+    /// 
+    /// Called when a cross-chain transfer fails or times out.
+    /// Initiates refund process back to the user, unless the intent's retry
+    /// policy still allows another delivery attempt.
+    pub fn handle_failure(
+        &mut self,
+        intent_id: U256,
+        user: Address,
+        token: Address,
+        amount: U256,
+        reason: alloc::string::String,
+    ) -> Result<(), SettlementVerifierError> {
+        // Only owner or route executor can call this
+        self.only_authorized()?;
+
+        if intent_id == U256::ZERO {
+            return Err(SettlementVerifierError::InvalidIntentId(InvalidIntentId {}));
+        }
+        // A user paid out by insurance isn't refunded as well
+        if self.insurance_claims.get(intent_id) != U256::ZERO {
+            return Err(SettlementVerifierError::AlreadyProcessed(AlreadyProcessed {}));
+        }
+
+        // Check for timeout
+        let settlement_time = self.settlement_timestamps.get(intent_id);
+        let current_time = U256::from(self.vm().block_timestamp());
+        let timeout = self.timeout_period.get();
+
+        // A delivery waiting out its challenge window hasn't timed out
+        if settlement_time != U256::ZERO
+            && current_time > settlement_time + timeout
+            && self.pending_final_at.get(intent_id) == U256::ZERO
+        {
+            if self.retry_delivery(intent_id) {
+                return Ok(());
+            }
+
+            // Timeout occurred
+            self.set_settlement_status(intent_id, SettlementStatus::Failed as u8);
+
+            self.vm().log(SettlementFailed {
+                intentId: intent_id,
+                messageId: FixedBytes::<32>::ZERO,
+                reason: reason.clone(),
+            });
+
+            self.release_in_flight(intent_id);
+            self.slash_solver(intent_id)?;
+
+            // Initiate refund
+            self.initiate_refund(intent_id, user, token, amount)?;
+            self.notify_callback(intent_id);
+        }
+
+        Ok(())
+    }
+
+    /// Get settlement status for an intent
+    pub fn get_settlement_status(&self, intent_id: U256) -> U256 {
+        self.settlements.get(intent_id)
+    }
+
+    /// Get settlement timestamp
+    pub fn get_settlement_timestamp(&self, intent_id: U256) -> U256 {
+        self.settlement_timestamps.get(intent_id)
+    }
+
+    /// Check if settlement has timed out
+    pub fn has_settlement_timed_out(&self, intent_id: U256) -> bool {
+        let settlement_time = self.settlement_timestamps.get(intent_id);
+        if settlement_time == U256::ZERO {
+            return false;
+        }
+
+        let current_time = U256::from(self.vm().block_timestamp());
+        let timeout = self.timeout_period.get();
+
+        current_time > settlement_time + timeout
+    }
+
+    /// Update timeout period (admin only, within `Param::TimeoutPeriod` bounds)
+    pub fn set_timeout_period(&mut self, new_timeout: U256) -> Result<(), SettlementVerifierError> {
+        self.only_owner()?;
+        Param::TimeoutPeriod.check(new_timeout).map_err(SettlementVerifierError::ParameterOutOfBounds)?;
+
+        let old_timeout = self.timeout_period.get();
+        self.timeout_period.set(new_timeout);
+
+        self.vm().log(params::changed(Param::TimeoutPeriod, old_timeout, new_timeout));
+
+        Ok(())
+    }
+
+    /// Drill: report a delivery as if `reporter` of `reporter_type` sent it
+    /// (admin only, `drills` builds only)
+    ///
+    /// Goes through the same lane policy, quorum and retry checks as a real
+    /// report. Repeating a report drills duplicate delivery; a conflicting
+    /// `status` or an unaccepted reporter type drills a misbehaving reporter.
+    pub fn drill_report(
+        &mut self,
+        reporter_type: u8,
+        reporter: Address,
+        message_id: FixedBytes<32>,
+        intent_id: U256,
+        status: u8,
+    ) -> Result<bool, SettlementVerifierError> {
+        self.only_drill()?;
+
+        let delivery = DeliveryStatus::from_u8(status)
+            .ok_or(SettlementVerifierError::InvalidDeliveryStatus(InvalidDeliveryStatus { status }))?;
+
+        self.vm().log(DrillReport {
+            intentId: intent_id,
+            reporter,
+            reporterType: reporter_type,
+            status,
+        });
+
+        self.report_delivery(reporter_type, reporter, message_id, intent_id, delivery)
+    }
+
+    /// Drill: age an intent's clocks by `delay` seconds (admin only, `drills`
+    /// builds only)
+    ///
+    /// Moves its settlement clock, settlement timestamp, execution time and
+    /// any challenge window back, as if its delivery were running `delay`
+    /// late, so timeouts, retries and finalization can be drilled without
+    /// waiting them out.
+    pub fn drill_delay(&mut self, intent_id: U256, delay: U256) -> Result<(), SettlementVerifierError> {
+        self.only_drill()?;
+
+        let started_at = self.intent_started_at.get(intent_id);
+        self.intent_started_at.setter(intent_id).set(started_at.saturating_sub(delay));
+        let settled_at = self.settlement_timestamps.get(intent_id);
+        self.settlement_timestamps.setter(intent_id).set(settled_at.saturating_sub(delay));
+        let executed_at = self.attempt_started_at.getter(intent_id).get(U256::ZERO);
+        self.attempt_started_at.setter(intent_id).setter(U256::ZERO).set(executed_at.saturating_sub(delay));
+        let final_at = self.pending_final_at.get(intent_id);
+        if final_at != U256::ZERO {
+            // Zero means no agreed delivery, so stop at one
+            self.pending_final_at.setter(intent_id).set(final_at.saturating_sub(delay).max(U256::from(1)));
+        }
+
+        self.vm().log(DrillDelay { intentId: intent_id, delay });
+
+        Ok(())
+    }
+
+    /// Whether this build has the drill hooks
+    pub fn drills_enabled(&self) -> bool {
+        cfg!(feature = "drills")
+    }
+
+    /// ERC-165: whether this contract implements `interface_id`
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        erc165::supports(interface_id.0, &[])
+    }
+
+    /// Get contract owner
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Get the verifier's ABI-encoded `ProtocolStatus` (kind, version, owner; no counters)
+    pub fn get_protocol_status(&self) -> Bytes {
+        let status =
+            ProtocolStatus::new(StatusContract::SettlementVerifier, env!("CARGO_PKG_VERSION"), self.owner.get());
+        Bytes::from(encode_protocol_status(&status))
+    }
+
+    /// Hand the contract over to a new owner (admin only)
+    pub fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), SettlementVerifierError> {
+        self.only_owner()?;
+
+        if new_owner == Address::ZERO {
+            return Err(SettlementVerifierError::Unauthorized(Unauthorized {}));
+        }
+
+        let previous_owner = self.owner.get();
+        self.owner.set(new_owner);
+
+        self.vm().log(OwnershipTransferred {
+            previousOwner: previous_owner,
+            newOwner: new_owner,
+        });
+
+        Ok(())
+    }
+
+    /// Internal: Set a settlement's status, asserting the move is legal
+    fn set_settlement_status(&mut self, intent_id: U256, status: u8) {
+        invariant!(
+            invariants::is_settlement_transition(self.settlements.get(intent_id).saturating_to(), status),
+            "settlement status transition"
+        );
+        self.settlements.setter(intent_id).set(U256::from(status));
+    }
+
+    /// Internal: Initiate refund process
+    fn initiate_refund(
+        &mut self,
+        intent_id: U256,
+        user: Address,
+        token: Address,
+        amount: U256,
+    ) -> Result<(), SettlementVerifierError> {
+        // Update status to Refunded
+        self.set_settlement_status(intent_id, SettlementStatus::Refunded as u8);
+
+        self.vm().log(RefundInitiated {
+            intentId: intent_id,
+            user,
+            token,
+            amount,
+        });
+
+        // In production, this would trigger actual token refund
+        // through the RouteExecutor contract
+
+        Ok(())
+    }
+
+    /// Internal: Check that one more intent with `notional` of `token` keeps a
+    /// solver within its in-flight caps
+    fn check_in_flight_caps(
+        &self,
+        solver_id: U256,
+        token: Address,
+        notional: U256,
+    ) -> Result<(), SettlementVerifierError> {
+        let limit = self.max_in_flight_intents.get();
+        if limit != U256::ZERO && self.solver_in_flight.get(solver_id) >= limit {
+            return Err(SettlementVerifierError::InFlightLimitExceeded(InFlightLimitExceeded {
+                solverId: solver_id,
+                limit,
+            }));
+        }
+
+        let limit = self.max_in_flight_notional.get(token);
+        if limit != U256::ZERO && self.solver_in_flight_notional.getter(solver_id).get(token) + notional > limit {
+            return Err(SettlementVerifierError::InFlightNotionalExceeded(
+                InFlightNotionalExceeded {
+                    solverId: solver_id,
+                    token,
+                    limit,
+                },
+            ));
+        }
+        Ok(())
+    }
+
+    /// Internal: Stop counting a solver intent against its solver's in-flight
+    /// caps; a no-op for intents that aren't in flight
+    fn release_in_flight(&mut self, intent_id: U256) {
+        let token = self.intent_tokens.get(intent_id);
+        if token == Address::ZERO {
+            return;
+        }
+        let solver_id = self.intent_solvers.get(intent_id);
+        let notional = self.intent_notionals.get(intent_id);
+
+        let in_flight = self.solver_in_flight.get(solver_id).saturating_sub(U256::from(1));
+        self.solver_in_flight.setter(solver_id).set(in_flight);
+        let in_flight_notional = self
+            .solver_in_flight_notional
+            .getter(solver_id)
+            .get(token)
+            .saturating_sub(notional);
+        self.solver_in_flight_notional
+            .setter(solver_id)
+            .setter(token)
+            .set(in_flight_notional);
+        self.intent_tokens.setter(intent_id).set(Address::ZERO);
+        self.intent_notionals.setter(intent_id).set(U256::ZERO);
+        self.intent_recipients.setter(intent_id).set(Address::ZERO);
+    }
+
+    /// Internal: An intent's integrator memo from RouteExecutor (zero if none
+    /// or the executor can't be read)
+    fn intent_memo(&self, intent_id: U256) -> FixedBytes<32> {
+        let calldata = getIntentMemoCall { intentId: intent_id }.abi_encode();
+        static_call(self.vm(), Call::new(), self.route_executor.get(), &calldata)
+            .ok()
+            .and_then(|result| getIntentMemoCall::abi_decode_returns(&result).ok())
+            .unwrap_or_default()
+    }
+
+    /// Internal: Attest a settled intent through EAS
+    ///
+    /// Reads the intent's execution record and user from RouteExecutor; the
+    /// attestation is addressed to the user. Returns `None` if the intent has
+    /// no record there or EAS rejects the attestation.
+    fn issue_attestation(&mut self, intent_id: U256) -> Option<FixedBytes<32>> {
+        let executor = self.route_executor.get();
+        let calldata = getIntentExecutionCall { intentId: intent_id }.abi_encode();
+        let result = static_call(self.vm(), Call::new(), executor, &calldata).ok()?;
+        let execution = getIntentExecutionCall::abi_decode_returns(&result).ok()?;
+        let calldata = getIntentUserCall { intentId: intent_id }.abi_encode();
+        let result = static_call(self.vm(), Call::new(), executor, &calldata).ok()?;
+        let user = getIntentUserCall::abi_decode_returns(&result).ok()?;
+        if user == Address::ZERO {
+            return None;
+        }
+
+        let attestation = IntentAttestation {
+            intentHash: FixedBytes::from(intent_id.to_be_bytes::<32>()),
+            user,
+            amountIn: execution.amountIn,
+            amountOut: execution.amountOut,
+            sourceChain: U256::from(self.vm().chain_id()),
+            destinationChain: execution.destinationChain,
+            executedAt: execution.createdAt.saturating_to(),
+            settledAt: self.settlement_timestamps.get(intent_id).saturating_to(),
+            status: self.settlements.get(intent_id).saturating_to(),
+            memo: self.intent_memo(intent_id),
+        };
+        let request = AttestationRequest {
+            schema: self.attestation_schema.get(),
+            data: AttestationRequestData {
+                recipient: user,
+                expirationTime: 0,
+                revocable: false,
+                refUID: FixedBytes::ZERO,
+                data: encode_intent_attestation(&attestation).into(),
+                value: U256::ZERO,
+            },
+        };
+        let calldata = attestCall { request }.abi_encode();
+        let eas = self.eas.get();
+        let config = Call::new_mutating(self);
+        let result = call(self.vm(), config, eas, &calldata).ok()?;
+        let uid = attestCall::abi_decode_returns(&result).ok()?;
+
+        self.intent_attestations.setter(intent_id).set(uid);
+        self.vm().log(IntentAttested { intentId: intent_id, uid });
+
+        Some(uid)
+    }
+
+    /// Internal: Call an intent's callback with its settlement status, if it has one
+    ///
+    /// The callback gets `CALLBACK_GAS_LIMIT` gas and no return data is read.
+    /// It is cleared first so it runs at most once, and whether it succeeded
+    /// is only logged.
+    fn notify_callback(&mut self, intent_id: U256) {
+        let callback = self.intent_callbacks.get(intent_id);
+        if callback == Address::ZERO {
+            return;
+        }
+        let data = self.intent_callback_data.getter(intent_id).get_bytes();
+        self.intent_callbacks.setter(intent_id).set(Address::ZERO);
+        self.intent_callback_data.setter(intent_id).erase();
+
+        let status: u8 = self.settlements.get(intent_id).saturating_to();
+        let calldata = encode_settlement_callback(intent_id, status, data.into());
+        // SAFETY: the intent's settlement is final and its callback cleared, so
+        // a reentrant call has nothing left to change for this intent
+        let success = unsafe {
+            RawCall::new(self.vm())
+                .gas(CALLBACK_GAS_LIMIT)
+                .limit_return_data(0, 0)
+                .flush_storage_cache()
+                .call(callback, &calldata)
+        }
+        .is_ok();
+
+        self.vm().log(CallbackInvoked {
+            intentId: intent_id,
+            callback,
+            status,
+            success,
+        });
+    }
+
+    /// Internal: Slash the solver behind a failed intent, if any
+    fn slash_solver(&mut self, intent_id: U256) -> Result<U256, SettlementVerifierError> {
+        let solver_id = self.intent_solvers.get(intent_id);
+        let registry = self.solver_registry.get();
+        let amount = self.slash_amount.get();
+        if solver_id == U256::ZERO || registry == Address::ZERO || amount == U256::ZERO {
+            return Ok(U256::ZERO);
+        }
+
+        let config = Call::new_mutating(self);
+        ISolverRegistry::new(registry)
+            .slash(self.vm(), config, solver_id, amount, intent_id)
+            .map_err(|_| SettlementVerifierError::RefundFailed(RefundFailed {}))
+    }
+
+    /// Internal: Check if caller is owner
+    fn only_owner(&self) -> Result<(), SettlementVerifierError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(SettlementVerifierError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+
+    /// Internal: Reject a callback unless ERC-165 detection finds `interface_id`
+    fn require_interface(&self, target: Address, interface_id: [u8; 4]) -> Result<(), SettlementVerifierError> {
+        let erc165_target = IERC165::new(target);
+        let supported = erc165::detect(interface_id, |id| {
+            erc165_target.supports_interface(self.vm(), Call::new(), FixedBytes(id)).unwrap_or(false)
+        });
+        if !supported {
+            return Err(SettlementVerifierError::InterfaceNotSupported(InterfaceNotSupported {
+                target,
+                interfaceId: FixedBytes(interface_id),
+            }));
+        }
+        Ok(())
+    }
+
+    /// Internal: Check if caller is CCIP router
+    fn only_ccip_router(&self) -> Result<(), SettlementVerifierError> {
+        if self.vm().msg_sender() != self.ccip_router.get() {
+            return Err(SettlementVerifierError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+
+    /// Internal: Check that this is a `drills` build and the caller is owner
+    fn only_drill(&self) -> Result<(), SettlementVerifierError> {
+        if !cfg!(feature = "drills") {
+            return Err(SettlementVerifierError::DrillsDisabled(DrillsDisabled {}));
+        }
+        self.only_owner()
+    }
+
+    /// Internal: Check if caller is authorized (owner or route executor)
+    fn only_authorized(&self) -> Result<(), SettlementVerifierError> {
+        let sender = self.vm().msg_sender();
+        if sender != self.owner.get() && sender != self.route_executor.get() {
+            return Err(SettlementVerifierError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use stylus_sdk::alloy_primitives::{Address, FixedBytes, U256};
+use swoosh_test_utils::TestEnv;
+use swoosh_verifier::*;
+
+#[cfg(test)]
+mod drill_tests {
+    use super::*;
+
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    // Owner 1, RouteExecutor 2, CCIP router 3
+    fn setup(env: &TestEnv) -> SettlementVerifier {
+        let mut verifier: SettlementVerifier = env.deploy();
+        env.set_sender(test_address(1));
+        assert!(verifier.init(test_address(2), test_address(3)).is_ok(), "Init");
+        verifier
+    }
+
+    #[cfg(not(feature = "drills"))]
+    #[test]
+    fn test_drills_disabled_by_default() {
+        // Production builds carry the hooks but they always revert
+        let env = TestEnv::new();
+        let mut verifier = setup(&env);
+
+        assert!(!verifier.drills_enabled(), "Disabled");
+        assert!(
+            matches!(
+                verifier.drill_report(1, test_address(3), FixedBytes::from([1u8; 32]), U256::from(1u64), 1),
+                Err(SettlementVerifierError::DrillsDisabled(_))
+            ),
+            "Report hook"
+        );
+        assert!(
+            matches!(
+                verifier.drill_delay(U256::from(1u64), U256::from(60u64)),
+                Err(SettlementVerifierError::DrillsDisabled(_))
+            ),
+            "Delay hook"
+        );
+    }
+
+    #[cfg(feature = "drills")]
+    #[test]
+    fn test_duplicate_and_late_delivery_drills() {
+        // A replayed report is rejected; an aged intent expires at once
+        let env = TestEnv::new();
+        let mut verifier = setup(&env);
+        let (delivered, late) = (U256::from(1u64), U256::from(2u64));
+        let message_id = FixedBytes::from([1u8; 32]);
+
+        env.set_sender(test_address(7));
+        assert!(
+            matches!(
+                verifier.drill_report(1, test_address(3), message_id, delivered, 1),
+                Err(SettlementVerifierError::Unauthorized(_))
+            ),
+            "Owner only"
+        );
+
+        env.set_sender(test_address(1));
+        assert!(verifier.drill_report(1, test_address(3), message_id, delivered, 1).is_ok(), "Delivered");
+        assert!(
+            matches!(
+                verifier.drill_report(1, test_address(3), message_id, delivered, 1),
+                Err(SettlementVerifierError::AlreadyProcessed(_))
+            ),
+            "Replay rejected"
+        );
+
+        env.set_sender(test_address(2));
+        assert!(
+            verifier
+                .register_solver_intent(late, U256::from(1u64), Address::ZERO, U256::ZERO, Address::ZERO)
+                .is_ok(),
+            "Registered"
+        );
+        env.set_sender(test_address(1));
+        assert!(verifier.drill_delay(late, U256::from(2_000u64)).is_ok(), "Aged");
+        assert!(verifier.slash_expired_intent(late).is_ok(), "Expired without waiting");
+    }
+}
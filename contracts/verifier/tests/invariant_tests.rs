@@ -0,0 +1,34 @@
+// Only built with `--features invariants`
+#[cfg(all(test, feature = "invariants"))]
+mod invariant_tests {
+    use stylus_sdk::alloy_primitives::{Address, U256};
+    use swoosh_test_utils::TestEnv;
+    use swoosh_verifier::*;
+
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    #[test]
+    #[should_panic(expected = "invariant violated: settlement status transition")]
+    fn test_failed_settlement_cannot_be_confirmed() {
+        // A failed intent only moves on to Refunded
+        let env = TestEnv::new();
+        let mut verifier: SettlementVerifier = env.deploy();
+        let intent_id = U256::from(1u64);
+        env.set_sender(test_address(1));
+        assert!(verifier.init(test_address(2), test_address(3)).is_ok(), "Init");
+
+        env.set_sender(test_address(2));
+        assert!(
+            verifier
+                .register_solver_intent(intent_id, U256::from(1u64), Address::ZERO, U256::ZERO, Address::ZERO)
+                .is_ok(),
+            "Registered"
+        );
+        env.advance_time(1_801);
+        assert!(verifier.slash_expired_intent(intent_id).is_ok(), "Failed");
+
+        let _ = verifier.confirm_settlement(intent_id);
+    }
+}
@@ -0,0 +1,305 @@
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::alloy_primitives::{Address, FixedBytes, U256};
+use swoosh_test_utils::TestEnv;
+use swoosh_verifier::*;
+
+// RouteExecutor call the verifier forwards hidden deliveries through
+sol! {
+    function creditHiddenDelivery(bytes32 intentHash, address token, uint256 amount) external;
+}
+
+#[cfg(test)]
+mod settlement_timing_tests {
+    use super::*;
+
+    const TIMEOUT: u64 = 1800;
+
+    fn test_address(n: u8) -> Address {
+        Address::from([n; 20])
+    }
+
+    // Owner 1, RouteExecutor 2, CCIP router 3, adapter 4
+    fn setup(env: &TestEnv) -> SettlementVerifier {
+        let mut verifier: SettlementVerifier = env.deploy();
+        env.set_sender(test_address(1));
+        assert!(verifier.init(test_address(2), test_address(3)).is_ok(), "Init");
+        assert!(verifier.set_settlement_adapter(test_address(4), true).is_ok(), "Adapter set");
+        verifier
+    }
+
+    #[test]
+    fn test_settlement_times_out_after_period() {
+        // Timed out strictly after delivery time + timeout
+        let env = TestEnv::new();
+        let mut verifier = setup(&env);
+        let intent_id = U256::from(1u64);
+
+        assert!(!verifier.has_settlement_timed_out(intent_id), "No delivery yet");
+
+        env.set_sender(test_address(4));
+        assert!(verifier.verify_adapter_message(FixedBytes::from([9u8; 32]), intent_id).is_ok(), "Delivered");
+        assert_eq!(verifier.get_settlement_timestamp(intent_id), U256::from(env.timestamp()), "Delivery time");
+
+        env.advance_time(TIMEOUT);
+        assert!(!verifier.has_settlement_timed_out(intent_id), "At the boundary");
+
+        env.advance_time(1);
+        assert!(verifier.has_settlement_timed_out(intent_id), "Past the boundary");
+    }
+
+    #[test]
+    fn test_timeout_period_update_applies_to_open_settlements() {
+        // Shortening the timeout affects settlements already recorded
+        let env = TestEnv::new();
+        let mut verifier = setup(&env);
+        let intent_id = U256::from(2u64);
+
+        env.set_sender(test_address(4));
+        assert!(verifier.verify_adapter_message(FixedBytes::from([8u8; 32]), intent_id).is_ok(), "Delivered");
+
+        env.advance_blocks(61);
+        assert!(!verifier.has_settlement_timed_out(intent_id), "Within default timeout");
+
+        env.set_sender(test_address(1));
+        assert!(verifier.set_timeout_period(U256::from(60u64)).is_ok(), "Timeout shortened");
+        assert!(verifier.has_settlement_timed_out(intent_id), "Past shortened timeout");
+    }
+
+    #[test]
+    fn test_timeout_period_bounded() {
+        // Even the owner can't turn the timeout off or stretch it past a week
+        let env = TestEnv::new();
+        let mut verifier = setup(&env);
+
+        assert!(
+            matches!(
+                verifier.set_timeout_period(U256::ZERO),
+                Err(SettlementVerifierError::ParameterOutOfBounds(_))
+            ),
+            "Zero timeout"
+        );
+        assert!(
+            matches!(
+                verifier.set_timeout_period(U256::from(8 * 24 * 60 * 60u64)),
+                Err(SettlementVerifierError::ParameterOutOfBounds(_))
+            ),
+            "Eight days"
+        );
+        assert!(verifier.set_timeout_period(U256::from(3_600u64)).is_ok(), "An hour");
+    }
+
+    #[test]
+    fn test_slash_expired_intent_window() {
+        // Expiry can only be claimed once the timeout has fully elapsed
+        let env = TestEnv::new();
+        let mut verifier = setup(&env);
+        let intent_id = U256::from(3u64);
+
+        env.set_sender(test_address(2));
+        assert!(
+            verifier
+                .register_solver_intent(intent_id, U256::from(1u64), Address::ZERO, U256::ZERO, Address::ZERO)
+                .is_ok(),
+            "Registered"
+        );
+
+        env.set_sender(test_address(9));
+        env.advance_time(TIMEOUT);
+        assert!(
+            matches!(verifier.slash_expired_intent(intent_id), Err(SettlementVerifierError::SettlementTimeout(_))),
+            "Too early"
+        );
+
+        env.advance_time(1);
+        assert!(verifier.slash_expired_intent(intent_id).is_ok(), "Expired");
+        assert_eq!(verifier.get_settlement_status(intent_id), U256::from(SettlementStatus::Failed as u8), "Marked failed");
+        assert!(
+            matches!(verifier.slash_expired_intent(intent_id), Err(SettlementVerifierError::AlreadyProcessed(_))),
+            "Only once"
+        );
+    }
+
+    #[test]
+    fn test_settled_intent_cannot_expire() {
+        // Delivery inside the window closes it
+        let env = TestEnv::new();
+        let mut verifier = setup(&env);
+        let intent_id = U256::from(4u64);
+
+        env.set_sender(test_address(2));
+        assert!(
+            verifier
+                .register_solver_intent(intent_id, U256::from(1u64), Address::ZERO, U256::ZERO, Address::ZERO)
+                .is_ok(),
+            "Registered"
+        );
+
+        env.advance_time(TIMEOUT / 2);
+        env.set_sender(test_address(4));
+        assert!(verifier.verify_adapter_message(FixedBytes::from([7u8; 32]), intent_id).is_ok(), "Delivered");
+
+        env.advance_time(TIMEOUT);
+        assert!(
+            matches!(verifier.slash_expired_intent(intent_id), Err(SettlementVerifierError::AlreadyProcessed(_))),
+            "Settled"
+        );
+    }
+
+    #[test]
+    fn test_partial_delivery_settles_with_its_status() {
+        // A fallback delivery closes the window but keeps the PartialDelivery status
+        let env = TestEnv::new();
+        let mut verifier = setup(&env);
+        let intent_id = U256::from(5u64);
+
+        env.set_sender(test_address(2));
+        assert!(
+            verifier
+                .register_solver_intent(intent_id, U256::from(1u64), Address::ZERO, U256::ZERO, Address::ZERO)
+                .is_ok(),
+            "Registered"
+        );
+
+        env.set_sender(test_address(4));
+        assert!(
+            matches!(
+                verifier.verify_adapter_delivery(FixedBytes::from([6u8; 32]), intent_id, 2),
+                Err(SettlementVerifierError::InvalidDeliveryStatus(_))
+            ),
+            "Failed is not a delivery outcome"
+        );
+        assert!(verifier.verify_adapter_delivery(FixedBytes::from([6u8; 32]), intent_id, 4).is_ok(), "Delivered");
+        assert_eq!(verifier.get_settlement_status(intent_id), U256::from(4u64), "PartialDelivery");
+
+        env.advance_time(TIMEOUT + 1);
+        assert!(
+            matches!(verifier.slash_expired_intent(intent_id), Err(SettlementVerifierError::AlreadyProcessed(_))),
+            "Settled"
+        );
+    }
+
+    #[test]
+    fn test_insured_intent_claimable_once_failed() {
+        // An unreported insured intent expires like a solver intent; only then can it be claimed
+        let env = TestEnv::new();
+        let mut verifier = setup(&env);
+        let intent_id = U256::from(6u64);
+        let (user, token, coverage) = (test_address(7), test_address(8), U256::from(1_000u64));
+
+        env.set_sender(test_address(2));
+        assert!(
+            matches!(
+                verifier.register_insurance(intent_id, user, token, coverage, U256::from(5u64)),
+                Err(SettlementVerifierError::InsuranceUnavailable(_))
+            ),
+            "No fund"
+        );
+        env.set_sender(test_address(1));
+        assert!(verifier.set_insurance_fund(test_address(9)).is_ok(), "Fund set");
+        env.set_sender(test_address(2));
+        assert!(verifier.register_insurance(intent_id, user, token, coverage, U256::from(5u64)).is_ok(), "Insured");
+        assert_eq!(verifier.get_insurance_premiums(token), U256::from(5u64), "Premium counted");
+
+        env.set_sender(test_address(7));
+        assert!(
+            matches!(verifier.claim_insurance(intent_id), Err(SettlementVerifierError::NotClaimable(_))),
+            "Still pending"
+        );
+
+        env.advance_time(TIMEOUT + 1);
+        assert_eq!(verifier.slash_expired_intent(intent_id).ok(), Some(U256::ZERO), "Expired without a solver");
+        assert_eq!(verifier.get_settlement_status(intent_id), U256::from(SettlementStatus::Failed as u8), "Failed");
+        assert!(
+            matches!(verifier.claim_insurance(U256::from(60u64)), Err(SettlementVerifierError::InvalidIntentId(_))),
+            "Not insured"
+        );
+    }
+
+    #[test]
+    fn test_retry_policy_retries_before_failing() {
+        // Each timeout opens another attempt until the retries run out
+        let env = TestEnv::new();
+        let mut verifier = setup(&env);
+        let intent_id = U256::from(7u64);
+        // Two retries within 5000 seconds, bridged adapter only
+        let policy = U256::from(2u64) | (U256::from(5_000u64) << 8);
+
+        env.set_sender(test_address(1));
+        assert!(verifier.set_settlement_adapter(test_address(5), true).is_ok(), "Second adapter set");
+        env.set_sender(test_address(9));
+        assert!(
+            matches!(
+                verifier.register_retry_policy(intent_id, policy, test_address(4)),
+                Err(SettlementVerifierError::Unauthorized(_))
+            ),
+            "Executor only"
+        );
+        env.set_sender(test_address(2));
+        assert!(
+            matches!(
+                verifier.register_retry_policy(intent_id, U256::from(2u64), test_address(4)),
+                Err(SettlementVerifierError::InvalidRetryPolicy(_))
+            ),
+            "Retries without delay"
+        );
+        assert!(verifier.register_retry_policy(intent_id, policy, test_address(4)).is_ok(), "Registered");
+
+        env.set_sender(test_address(9));
+        for attempt in 1..=2u64 {
+            env.advance_time(TIMEOUT + 1);
+            assert_eq!(verifier.slash_expired_intent(intent_id).ok(), Some(U256::ZERO), "Retried");
+            assert_eq!(verifier.get_settlement_status(intent_id), U256::ZERO, "Still pending");
+            assert_eq!(verifier.get_retry_state(intent_id).2, U256::from(attempt), "Attempt counted");
+            assert_eq!(
+                verifier.get_attempt_started_at(intent_id, U256::from(attempt)),
+                U256::from(env.timestamp()),
+                "Attempt start"
+            );
+        }
+
+        env.set_sender(test_address(5));
+        assert!(
+            matches!(
+                verifier.verify_adapter_message(FixedBytes::from([6u8; 32]), intent_id),
+                Err(SettlementVerifierError::ReporterNotAccepted(_))
+            ),
+            "Other bridge"
+        );
+
+        env.set_sender(test_address(9));
+        env.advance_time(TIMEOUT + 1);
+        assert!(verifier.slash_expired_intent(intent_id).is_ok(), "Out of retries");
+        assert_eq!(verifier.get_settlement_status(intent_id), U256::from(SettlementStatus::Failed as u8), "Failed");
+    }
+
+    #[test]
+    fn test_hidden_delivery_reported_by_adapter() {
+        // Only a registered adapter can credit a hidden-recipient delivery, and only if RouteExecutor takes it
+        let env = TestEnv::new();
+        let mut verifier = setup(&env);
+        let (intent_hash, token, amount) = (FixedBytes::from([7u8; 32]), test_address(0x10), U256::from(1_000u64));
+
+        env.set_sender(test_address(9));
+        assert!(
+            matches!(
+                verifier.report_hidden_delivery(intent_hash, token, amount),
+                Err(SettlementVerifierError::Unauthorized(_))
+            ),
+            "Not an adapter"
+        );
+
+        let credit = creditHiddenDeliveryCall { intentHash: intent_hash, token, amount }.abi_encode();
+        env.set_sender(test_address(4));
+        env.vm().mock_call(test_address(2), credit.clone(), U256::ZERO, Err(Vec::new()));
+        assert!(
+            matches!(
+                verifier.report_hidden_delivery(intent_hash, token, amount),
+                Err(SettlementVerifierError::DeliveryCreditFailed(_))
+            ),
+            "Executor refused the credit"
+        );
+
+        env.vm().mock_call(test_address(2), credit, U256::ZERO, Ok(Vec::new()));
+        assert!(verifier.report_hidden_delivery(intent_hash, token, amount).is_ok(), "Credited");
+    }
+}